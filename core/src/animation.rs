@@ -1,4 +1,10 @@
 //! Animate your applications.
+mod animated;
+mod interpolate;
+
+pub use animated::Animated;
+pub use interpolate::Interpolate;
+
 use crate::time::{Duration, Instant};
 
 pub use lilt::{Easing, FloatRepresentable as Float, Interpolable};
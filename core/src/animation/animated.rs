@@ -0,0 +1,154 @@
+use crate::Shell;
+use crate::animation::{Animation, Easing, Interpolate};
+use crate::time::{Duration, Instant};
+
+/// A value that smoothly transitions to a new target whenever it changes.
+///
+/// Unlike an [`Animation`], which tracks some state and needs to be
+/// projected into values, an [`Animated`] value interpolates the value
+/// itself. Any type that can [`Interpolate`] can be animated; like colors,
+/// sizes, paddings, or even whole widget styles.
+///
+/// An [`Animated`] value needs to be redrawn for as long as it
+/// [`is_animating`](Self::is_animating). Applications can subscribe to
+/// `window::frames` in the meantime, while widgets can simply
+/// [`request_redraw`](Self::request_redraw).
+///
+/// Any widget style—like the one of a `container`—can be animated by
+/// keeping an [`Animated`] style in the application state and returning
+/// its current value in the style function of the widget.
+#[derive(Debug, Clone)]
+pub struct Animated<T> {
+    from: T,
+    to: T,
+    progress: Animation<bool>,
+    template: Animation<bool>,
+}
+
+impl<T> Animated<T>
+where
+    T: Interpolate,
+{
+    /// Creates a new [`Animated`] value, resting at the given value.
+    pub fn new(value: T) -> Self {
+        let template = Animation::new(false);
+
+        Self {
+            from: value.clone(),
+            to: value,
+            progress: template.clone(),
+            template,
+        }
+    }
+
+    /// Sets the [`Easing`] function of the transitions of the [`Animated`]
+    /// value.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.template = self.template.easing(easing);
+        self
+    }
+
+    /// Sets the [`Duration`] of the transitions of the [`Animated`] value.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.template = self.template.duration(duration);
+        self
+    }
+
+    /// Sets a delay before every transition of the [`Animated`] value.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.template = self.template.delay(delay);
+        self
+    }
+
+    /// Transitions the [`Animated`] value from its current value to the
+    /// given target at the given time.
+    pub fn go(mut self, target: T, at: Instant) -> Self {
+        self.go_mut(target, at);
+        self
+    }
+
+    /// Transitions the [`Animated`] value from its current value to the
+    /// given target at the given time, by reference.
+    ///
+    /// Any transition in progress is interrupted smoothly, starting from
+    /// wherever it was at the given time.
+    pub fn go_mut(&mut self, target: T, at: Instant) {
+        self.from = self.value(at);
+        self.to = target;
+        self.progress = self.template.clone().go(true, at);
+    }
+
+    /// Sets the [`Animated`] value to the given value right away; without
+    /// any transition.
+    pub fn set(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.progress = self.template.clone();
+    }
+
+    /// Returns the interpolated value of the [`Animated`] value at the given
+    /// [`Instant`].
+    pub fn value(&self, at: Instant) -> T {
+        let ratio = self.progress.interpolate(0.0, 1.0, at);
+
+        self.from.interpolate(&self.to, ratio)
+    }
+
+    /// Returns the target of the [`Animated`] value; that is, the value it
+    /// will eventually settle at.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns true if the [`Animated`] value is transitioning at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.progress.is_animating(at)
+    }
+
+    /// Requests a redraw of the next frame to the given [`Shell`] if the
+    /// [`Animated`] value is transitioning at the given [`Instant`].
+    pub fn request_redraw<Message>(&self, shell: &mut Shell<'_, Message>, at: Instant) {
+        if self.is_animating(at) {
+            shell.request_redraw();
+        }
+    }
+}
+
+impl<T> Default for Animated<T>
+where
+    T: Interpolate + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Color;
+
+    #[test]
+    fn transitions_reach_their_target() {
+        let start = Instant::now();
+
+        let color = Animated::new(Color::BLACK)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(Color::WHITE, start);
+
+        assert_eq!(color.value(start), Color::BLACK);
+        assert!(color.is_animating(start + Duration::from_millis(50)));
+
+        let halfway = color.value(start + Duration::from_millis(50));
+        assert!(halfway.r > 0.4 && halfway.r < 0.6);
+
+        assert_eq!(
+            color.value(start + Duration::from_millis(200)),
+            Color::WHITE
+        );
+        assert!(!color.is_animating(start + Duration::from_millis(200)));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::border::{self, Border};
+use crate::{Background, Color, Padding, Pixels, Point, Shadow, Size, Vector};
+
+/// A value that can be interpolated, so it can be [`Animated`].
+///
+/// [`Animated`]: super::Animated
+pub trait Interpolate: Clone {
+    /// Interpolates between this value and the `other` one, where a ratio
+    /// of 0 produces this value and a ratio of 1 the `other` one.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolate for Pixels {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Pixels(self.0.interpolate(&other.0, ratio))
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, ratio),
+            g: self.g.interpolate(&other.g, ratio),
+            b: self.b.interpolate(&other.b, ratio),
+            a: self.a.interpolate(&other.a, ratio),
+        }
+    }
+}
+
+/// A missing [`Color`] fades in and out as a transparent version of the
+/// present one.
+impl Interpolate for Option<Color> {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, ratio)),
+            (None, Some(to)) => Some(Color { a: 0.0, ..*to }.interpolate(to, ratio)),
+            (Some(from), None) => Some(from.interpolate(&Color { a: 0.0, ..*from }, ratio)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Only solid backgrounds are interpolated; any other kind of
+/// [`Background`] switches halfway through.
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Background::Color(from), Background::Color(to)) => {
+                Background::Color(from.interpolate(to, ratio))
+            }
+            _ if ratio < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+/// A missing [`Background`] fades in and out as a transparent version of
+/// the present one.
+impl Interpolate for Option<Background> {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, ratio)),
+            (None, Some(to)) => Some(to.scale_alpha(ratio)),
+            (Some(from), None) => Some(from.scale_alpha(1.0 - ratio)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, ratio),
+            self.height.interpolate(&other.height, ratio),
+        )
+    }
+}
+
+impl Interpolate for Padding {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Padding {
+            top: self.top.interpolate(&other.top, ratio),
+            right: self.right.interpolate(&other.right, ratio),
+            bottom: self.bottom.interpolate(&other.bottom, ratio),
+            left: self.left.interpolate(&other.left, ratio),
+        }
+    }
+}
+
+impl Interpolate for border::Radius {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        border::Radius {
+            top_left: self.top_left.interpolate(&other.top_left, ratio),
+            top_right: self.top_right.interpolate(&other.top_right, ratio),
+            bottom_right: self.bottom_right.interpolate(&other.bottom_right, ratio),
+            bottom_left: self.bottom_left.interpolate(&other.bottom_left, ratio),
+        }
+    }
+}
+
+impl Interpolate for Border {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Border {
+            color: self.color.interpolate(&other.color, ratio),
+            width: self.width.interpolate(&other.width, ratio),
+            radius: self.radius.interpolate(&other.radius, ratio),
+        }
+    }
+}
+
+impl Interpolate for Shadow {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, ratio),
+            offset: self.offset.interpolate(&other.offset, ratio),
+            blur_radius: self.blur_radius.interpolate(&other.blur_radius, ratio),
+            spread: self.spread.interpolate(&other.spread, ratio),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::renderer;
 use crate::widget;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Border, Clipboard, Color, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+    Border, Clipboard, Color, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 use std::borrow::Borrow;
@@ -350,6 +350,16 @@ where
             .mouse_interaction(tree, layout, cursor, viewport, renderer)
     }
 
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        self.widget.hit_test(tree, layout, renderer, position)
+    }
+
     fn overlay<'b>(
         &'b mut self,
         tree: &'b mut Tree,
@@ -501,6 +511,18 @@ where
             .mouse_interaction(tree, layout, cursor, viewport, renderer)
     }
 
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        self.element
+            .widget
+            .hit_test(tree, layout, renderer, position)
+    }
+
     fn overlay<'b>(
         &'b mut self,
         tree: &'b mut Tree,
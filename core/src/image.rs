@@ -1,9 +1,19 @@
 //! Load and draw raster graphics.
+mod animation;
+mod blurhash;
+mod color_filter;
+mod thumbhash;
+
+pub use animation::{Animation, Frame};
+pub use color_filter::ColorFilter;
+
 use crate::border;
+use crate::time::Duration;
 use crate::{Bytes, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
 
+use std::any::Any;
 use std::hash::{Hash, Hasher};
 use std::io;
 use std::path::{Path, PathBuf};
@@ -36,6 +46,20 @@ pub struct Image<H = Handle> {
     /// This can avoid graphical glitches, specially when using
     /// [`FilterMethod::Nearest`].
     pub snap: bool,
+
+    /// The playback time of the [`Animation`] of the image, if animated.
+    ///
+    /// If `None`, the first frame of the image will be drawn.
+    pub time: Option<Duration>,
+
+    /// The [`ColorFilter`] applied to the pixels of the image.
+    pub color_filter: ColorFilter,
+
+    /// The region of the image to draw, if cropped.
+    ///
+    /// The region is normalized; `(0, 0)` is the top-left corner of the
+    /// image and `(1, 1)` its bottom-right corner.
+    pub region: Option<Rectangle>,
 }
 
 impl Image<Handle> {
@@ -48,6 +72,9 @@ impl Image<Handle> {
             border_radius: border::Radius::default(),
             opacity: 1.0,
             snap: false,
+            time: None,
+            color_filter: ColorFilter::IDENTITY,
+            region: None,
         }
     }
 
@@ -74,6 +101,38 @@ impl Image<Handle> {
         self.snap = snap;
         self
     }
+
+    /// Sets the playback time of the [`Animation`] of the [`Image`].
+    pub fn time(mut self, time: Duration) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the [`ColorFilter`] applied to the pixels of the [`Image`].
+    pub fn color_filter(mut self, color_filter: ColorFilter) -> Self {
+        self.color_filter = color_filter;
+        self
+    }
+
+    /// Crops the [`Image`] to the given normalized region.
+    ///
+    /// Only the pixels inside the region will be drawn, stretched to fill
+    /// the bounds of the [`Image`].
+    pub fn region(mut self, region: Rectangle) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Crops the [`Image`] to the given region in pixels, given the size
+    /// of the image.
+    pub fn crop(self, region: Rectangle<u32>, size: Size<u32>) -> Self {
+        self.region(Rectangle {
+            x: region.x as f32 / size.width.max(1) as f32,
+            y: region.y as f32 / size.height.max(1) as f32,
+            width: region.width as f32 / size.width.max(1) as f32,
+            height: region.height as f32 / size.height.max(1) as f32,
+        })
+    }
 }
 
 impl From<&Handle> for Image {
@@ -91,14 +150,14 @@ pub enum Handle {
     /// Use [`from_path`] to create this variant.
     ///
     /// [`from_path`]: Self::from_path
-    Path(Id, PathBuf),
+    Path(Id, PathBuf, Orientation),
 
     /// A handle pointing to some encoded image bytes in-memory.
     ///
     /// Use [`from_bytes`] to create this variant.
     ///
     /// [`from_bytes`]: Self::from_bytes
-    Bytes(Id, Bytes),
+    Bytes(Id, Bytes, Orientation),
 
     /// A handle pointing to decoded image pixels in RGBA format.
     ///
@@ -115,6 +174,22 @@ pub enum Handle {
         /// The pixels.
         pixels: Bytes,
     },
+
+    /// A handle pointing to a [`Texture`] owned by a renderer.
+    ///
+    /// Use [`from_texture`] to create this variant.
+    ///
+    /// [`from_texture`]: Self::from_texture
+    Texture {
+        /// The id of this handle.
+        id: Id,
+        /// The width of the texture.
+        width: u32,
+        /// The height of the texture.
+        height: u32,
+        /// The texture.
+        texture: Texture,
+    },
 }
 
 impl Handle {
@@ -124,7 +199,7 @@ impl Handle {
     pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
         let path = path.into();
 
-        Self::Path(Id::path(&path), path)
+        Self::Path(Id::path(&path), path, Orientation::default())
     }
 
     /// Creates an image [`Handle`] containing the encoded image data directly.
@@ -134,7 +209,7 @@ impl Handle {
     /// This is useful if you already have your image loaded in-memory, maybe
     /// because you downloaded or generated it procedurally.
     pub fn from_bytes(bytes: impl Into<Bytes>) -> Handle {
-        Self::Bytes(Id::unique(), bytes.into())
+        Self::Bytes(Id::unique(), bytes.into(), Orientation::default())
     }
 
     /// Creates an image [`Handle`] containing the decoded image pixels directly.
@@ -153,14 +228,119 @@ impl Handle {
         }
     }
 
+    /// Creates an image [`Handle`] pointing to an existing texture of a renderer.
+    ///
+    /// The texture is drawn directly, without copying its pixels; so this is
+    /// useful to display the contents of a video or some offscreen 3D rendering
+    /// that changes every frame. Since the [`Handle`] keeps pointing to the same
+    /// texture, there is no need to create a new one when its contents change.
+    ///
+    /// The type of the texture depends on the renderer. For instance, `iced_wgpu`
+    /// expects a `wgpu::Texture` with the `TEXTURE_BINDING` usage and a
+    /// filterable format created with the same `wgpu::Device`. Renderers that do
+    /// not support the given texture will fail to load it.
+    pub fn from_texture(width: u32, height: u32, texture: impl Any + Send + Sync) -> Handle {
+        Self::Texture {
+            id: Id::unique(),
+            width,
+            height,
+            texture: Texture(Arc::new(texture)),
+        }
+    }
+
+    /// Creates an image [`Handle`] out of a [BlurHash], decoded at the given size.
+    ///
+    /// BlurHashes are compact strings that describe a blurry version of an image.
+    /// They are a great way to display a pleasant placeholder while the actual
+    /// image loads; since decoding them is very cheap. A small size—like
+    /// 32x32—is usually enough, since the image will be blurry anyways.
+    ///
+    /// Returns `None` if the hash is invalid.
+    ///
+    /// [BlurHash]: https://blurha.sh
+    pub fn from_blurhash(hash: &str, width: u32, height: u32) -> Option<Handle> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let pixels = blurhash::decode(hash, width, height)?;
+
+        Some(Self::Rgba {
+            id: Id::hash(("blurhash", hash, width, height)),
+            width,
+            height,
+            pixels: Bytes::from(pixels),
+        })
+    }
+
+    /// Creates an image [`Handle`] out of a [ThumbHash].
+    ///
+    /// ThumbHashes are compact binary descriptions of a blurry version of an
+    /// image—including its aspect ratio and transparency. Like BlurHashes, they
+    /// are a great way to display a pleasant placeholder while the actual image
+    /// loads. The decoded image is at most 32 pixels wide or tall.
+    ///
+    /// Returns `None` if the hash is invalid.
+    ///
+    /// [ThumbHash]: https://evanw.github.io/thumbhash/
+    pub fn from_thumbhash(hash: &[u8]) -> Option<Handle> {
+        let (width, height, pixels) = thumbhash::decode(hash)?;
+
+        Some(Self::Rgba {
+            id: Id::hash(("thumbhash", hash)),
+            width,
+            height,
+            pixels: Bytes::from(pixels),
+        })
+    }
+
+    /// Sets the [`Orientation`] used to decode the image of the [`Handle`].
+    ///
+    /// By default, the EXIF orientation of the image is applied. Use
+    /// [`Orientation::Stored`] if you have already normalized it.
+    ///
+    /// This has no effect on [`Handle::Rgba`] and [`Handle::Texture`], since
+    /// their pixels are already decoded.
+    pub fn orientation(self, orientation: Orientation) -> Handle {
+        match self {
+            Self::Path(_, path, _) => {
+                let id = match orientation {
+                    Orientation::Exif => Id::path(&path),
+                    Orientation::Stored => Id::path(&path).derive(orientation),
+                };
+
+                Self::Path(id, path, orientation)
+            }
+            Self::Bytes(id, bytes, current) if current != orientation => {
+                Self::Bytes(id.derive(orientation), bytes, orientation)
+            }
+            handle => handle,
+        }
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> Id {
         match self {
-            Handle::Path(id, _) | Handle::Bytes(id, _) | Handle::Rgba { id, .. } => *id,
+            Handle::Path(id, ..)
+            | Handle::Bytes(id, ..)
+            | Handle::Rgba { id, .. }
+            | Handle::Texture { id, .. } => *id,
         }
     }
 }
 
+/// The strategy used to orient an image when decoding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Orientation {
+    /// The EXIF orientation of the image is applied; so photos taken
+    /// with a rotated camera are displayed upright.
+    #[default]
+    Exif,
+
+    /// The image is decoded as it is stored, ignoring its EXIF orientation.
+    Stored,
+}
+
 impl<T> From<T> for Handle
 where
     T: Into<PathBuf>,
@@ -179,17 +359,49 @@ impl From<&Handle> for Handle {
 impl std::fmt::Debug for Handle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Path(id, path) => write!(f, "Path({id:?}, {path:?})"),
-            Self::Bytes(id, _) => write!(f, "Bytes({id:?}, ...)"),
+            Self::Path(id, path, _) => write!(f, "Path({id:?}, {path:?})"),
+            Self::Bytes(id, ..) => write!(f, "Bytes({id:?}, ...)"),
             Self::Rgba {
                 id, width, height, ..
             } => {
                 write!(f, "Pixels({id:?}, {width} * {height})")
             }
+            Self::Texture {
+                id, width, height, ..
+            } => {
+                write!(f, "Texture({id:?}, {width} * {height})")
+            }
         }
     }
 }
 
+/// An opaque texture owned by a renderer.
+///
+/// Use [`Handle::from_texture`] to create an image [`Handle`] pointing to it.
+#[derive(Clone)]
+pub struct Texture(Arc<dyn Any + Send + Sync>);
+
+impl Texture {
+    /// Returns a reference to the texture, if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl PartialEq for Texture {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Texture {}
+
+impl std::fmt::Debug for Texture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Texture(...)")
+    }
+}
+
 /// The unique identifier of some [`Handle`] data.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Id(_Id);
@@ -210,9 +422,25 @@ impl Id {
     }
 
     pub(crate) fn path(path: impl AsRef<Path>) -> Self {
+        Self::hash(path.as_ref())
+    }
+
+    pub(crate) fn hash(data: impl Hash) -> Self {
+        let hash = {
+            let mut hasher = FxHasher::default();
+            data.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        Self(_Id::Hash(hash))
+    }
+
+    pub(crate) fn derive(self, data: impl Hash) -> Self {
         let hash = {
             let mut hasher = FxHasher::default();
-            path.as_ref().hash(&mut hasher);
+            self.hash(&mut hasher);
+            data.hash(&mut hasher);
 
             hasher.finish()
         };
@@ -229,6 +457,13 @@ pub enum FilterMethod {
     Linear,
     /// Nearest neighbor.
     Nearest,
+    /// Bilinear interpolation of the mipmap of the image that best fits
+    /// its drawn size.
+    ///
+    /// Mipmaps are generated by the renderer on demand, once an image is
+    /// drawn at less than half its size. This avoids the aliasing—or
+    /// shimmering—of thumbnails and other images that are scaled down.
+    Mipmap,
 }
 
 /// A memory allocation of a [`Handle`], often in GPU memory.
@@ -292,6 +527,38 @@ pub unsafe fn allocate(handle: &Handle, size: Size<u32>) -> Allocation {
     }))
 }
 
+/// The memory usage of the image caches of a renderer.
+///
+/// Rasterized SVGs are accounted separately, since the same SVG may be
+/// rasterized at many different sizes and colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// The amount of raster images in the caches.
+    pub images: usize,
+
+    /// The memory taken by raster images, in bytes.
+    pub image_bytes: usize,
+
+    /// The amount of rasterized SVGs in the caches.
+    pub vectors: usize,
+
+    /// The memory taken by rasterized SVGs, in bytes.
+    pub vector_bytes: usize,
+
+    /// The amount of pinned images and SVGs.
+    pub pinned: usize,
+
+    /// The memory budget for unused entries, in bytes.
+    pub budget: usize,
+}
+
+impl CacheStats {
+    /// Returns the total memory taken by the image caches, in bytes.
+    pub fn total_bytes(&self) -> usize {
+        self.image_bytes + self.vector_bytes
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -315,6 +582,39 @@ pub trait Renderer: crate::Renderer {
     /// If you need a measurement right away, consider using [`Renderer::load_image`].
     fn measure_image(&self, handle: &Self::Handle) -> Option<Size<u32>>;
 
+    /// Returns the total duration of the [`Animation`] of an image for the given
+    /// [`Handle`].
+    ///
+    /// Returns `None` if the image is not animated.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn measure_image_animation(&self, _handle: &Self::Handle) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the [`Error`] that occurred while loading the image of the given
+    /// [`Handle`], if any.
+    ///
+    /// This method does not block. It returns `None` if the image was loaded
+    /// successfully or if it is still being loaded.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn image_error(&self, _handle: &Self::Handle) -> Option<Error> {
+        None
+    }
+
+    /// Decodes the image of the given [`Handle`] into its RGBA pixels.
+    ///
+    /// The returned [`Handle`] is always a [`Handle::Rgba`]. This may be
+    /// expensive and it is not cached, so it should be done sparingly.
+    ///
+    /// By default, it returns `None`; meaning decoding is not supported.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn decode_image(&self, _handle: &Self::Handle) -> Option<Handle> {
+        None
+    }
+
     /// Draws an [`Image`] inside the provided `bounds`.
     ///
     /// If the image is not already loaded, the [`Renderer`] may choose to render
@@ -0,0 +1,84 @@
+use crate::image::Handle;
+use crate::time::Duration;
+
+use std::sync::Arc;
+
+/// The frames of an animated image—like a GIF, an APNG, or an animated WebP.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    frames: Arc<[Frame]>,
+    duration: Duration,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] with the given frames.
+    ///
+    /// Returns `None` if there are no frames.
+    pub fn new(frames: impl IntoIterator<Item = Frame>) -> Option<Self> {
+        let frames: Arc<[Frame]> = frames.into_iter().collect();
+
+        if frames.is_empty() {
+            return None;
+        }
+
+        let duration = frames.iter().map(|frame| frame.delay).sum();
+
+        Some(Self { frames, duration })
+    }
+
+    /// Returns the frames of the [`Animation`].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the total [`Duration`] of a single loop of the [`Animation`].
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the [`Frame`] of the [`Animation`] that is displayed at the
+    /// given time.
+    ///
+    /// The last [`Frame`] is displayed once the [`Animation`] is over.
+    pub fn frame(&self, time: Duration) -> &Frame {
+        let mut end = Duration::ZERO;
+
+        for frame in self.frames.iter() {
+            end += frame.delay;
+
+            if time < end {
+                return frame;
+            }
+        }
+
+        self.frames.last().expect("Animation has frames")
+    }
+}
+
+/// A frame of an [`Animation`].
+#[derive(Debug, Clone)]
+pub struct Frame {
+    handle: Handle,
+    delay: Duration,
+}
+
+impl Frame {
+    /// Creates a new [`Frame`] that displays the given [`Handle`] for the
+    /// given delay.
+    pub fn new(handle: impl Into<Handle>, delay: Duration) -> Self {
+        Self {
+            handle: handle.into(),
+            delay,
+        }
+    }
+
+    /// Returns the [`Handle`] of the [`Frame`].
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Returns the [`Duration`] the [`Frame`] is displayed for.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+}
@@ -0,0 +1,126 @@
+//! Decode [BlurHash] placeholders.
+//!
+//! [BlurHash]: https://blurha.sh
+use std::f32::consts::PI;
+
+const CHARACTERS: &[u8; 83] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Decodes a BlurHash into RGBA pixels of the given size.
+///
+/// Returns `None` if the hash is invalid.
+pub fn decode(hash: &str, width: u32, height: u32) -> Option<Vec<u8>> {
+    let hash = hash.as_bytes();
+
+    let size = base83(hash.get(..1)?)?;
+    let components_x = (size % 9 + 1) as usize;
+    let components_y = (size / 9 + 1) as usize;
+
+    if hash.len() != 4 + 2 * components_x * components_y {
+        return None;
+    }
+
+    let maximum = (base83(&hash[1..2])? + 1) as f32 / 166.0;
+
+    let mut colors = Vec::with_capacity(components_x * components_y);
+
+    let dc = base83(&hash[2..6])?;
+
+    colors.push([
+        srgb_to_linear(dc >> 16),
+        srgb_to_linear((dc >> 8) & 255),
+        srgb_to_linear(dc & 255),
+    ]);
+
+    for i in 1..components_x * components_y {
+        let ac = base83(&hash[4 + i * 2..6 + i * 2])?;
+
+        let component = |quantized: u32| sign_pow((quantized as f32 - 9.0) / 9.0, 2.0) * maximum;
+
+        colors.push([
+            component(ac / (19 * 19)),
+            component((ac / 19) % 19),
+            component(ac % 19),
+        ]);
+    }
+
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut pixel = [0.0; 3];
+
+            for j in 0..components_y {
+                let basis_y = (PI * y as f32 * j as f32 / height as f32).cos();
+
+                for i in 0..components_x {
+                    let basis = (PI * x as f32 * i as f32 / width as f32).cos() * basis_y;
+                    let color = colors[i + j * components_x];
+
+                    pixel[0] += color[0] * basis;
+                    pixel[1] += color[1] * basis;
+                    pixel[2] += color[2] * basis;
+                }
+            }
+
+            pixels.extend(pixel.map(linear_to_srgb));
+            pixels.push(255);
+        }
+    }
+
+    Some(pixels)
+}
+
+fn base83(characters: &[u8]) -> Option<u32> {
+    characters.iter().try_fold(0, |value, character| {
+        let digit = CHARACTERS.iter().position(|c| c == character)?;
+
+        Some(value * 83 + digit as u32)
+    })
+}
+
+fn srgb_to_linear(value: u32) -> f32 {
+    let value = value as f32 / 255.0;
+
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+
+    let srgb = if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+
+    (srgb * 255.0).round() as u8
+}
+
+fn sign_pow(value: f32, exponent: f32) -> f32 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_blurhash() {
+        let pixels = decode("LEHV6nWB2yk8pyo0adR*.7kCMdnj", 32, 24).unwrap();
+
+        assert_eq!(pixels.len(), 32 * 24 * 4);
+        assert!(pixels.chunks_exact(4).all(|pixel| pixel[3] == 255));
+    }
+
+    #[test]
+    fn rejects_invalid_blurhash() {
+        assert_eq!(decode("", 32, 32), None);
+        assert_eq!(decode("LEHV6nWB2yk8pyo0adR*.7kCMdn", 32, 32), None);
+        assert_eq!(decode("LEHV6nWB2yk8pyo0adR*.7kCMdn ", 32, 32), None);
+    }
+}
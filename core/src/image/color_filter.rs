@@ -0,0 +1,234 @@
+use crate::{Color, Radians};
+
+/// A color transformation applied to the pixels of an image.
+///
+/// Filters can be composed with [`ColorFilter::then`]; so, for instance,
+/// a grayscale and tinted avatar can be drawn without manipulating its
+/// pixels beforehand.
+///
+/// Filters are applied to the unpremultiplied, gamma-encoded color components
+/// of each pixel—like CSS filters. The alpha of the pixels is left untouched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorFilter {
+    matrix: [[f32; 4]; 3],
+}
+
+impl ColorFilter {
+    /// The [`ColorFilter`] that leaves colors untouched.
+    pub const IDENTITY: Self = Self {
+        matrix: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+
+    /// Creates a [`ColorFilter`] from a row-major color matrix.
+    ///
+    /// Each row produces a color component—red, green, and blue—by multiplying
+    /// the original red, green, blue, and `1.0` components by its entries.
+    pub const fn from_matrix(matrix: [[f32; 4]; 3]) -> Self {
+        Self { matrix }
+    }
+
+    /// Creates a [`ColorFilter`] that converts colors to grayscale.
+    ///
+    /// An `amount` of `1.0` is completely gray, while `0.0` leaves colors untouched.
+    pub fn grayscale(amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+
+        Self::IDENTITY.mix(
+            Self::from_matrix([
+                [0.2126, 0.7152, 0.0722, 0.0],
+                [0.2126, 0.7152, 0.0722, 0.0],
+                [0.2126, 0.7152, 0.0722, 0.0],
+            ]),
+            amount,
+        )
+    }
+
+    /// Creates a [`ColorFilter`] that converts colors to sepia.
+    ///
+    /// An `amount` of `1.0` is completely sepia, while `0.0` leaves colors untouched.
+    pub fn sepia(amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+
+        Self::IDENTITY.mix(
+            Self::from_matrix([
+                [0.393, 0.769, 0.189, 0.0],
+                [0.349, 0.686, 0.168, 0.0],
+                [0.272, 0.534, 0.131, 0.0],
+            ]),
+            amount,
+        )
+    }
+
+    /// Creates a [`ColorFilter`] that changes the saturation of colors.
+    ///
+    /// An `amount` of `0.0` is completely gray, `1.0` leaves colors untouched,
+    /// and values over `1.0` oversaturate them.
+    pub fn saturate(amount: f32) -> Self {
+        Self::grayscale(1.0).mix(Self::IDENTITY, amount.max(0.0))
+    }
+
+    /// Creates a [`ColorFilter`] that multiplies the brightness of colors.
+    ///
+    /// An `amount` of `0.0` is completely black, `1.0` leaves colors untouched,
+    /// and values over `1.0` brighten them.
+    pub fn brightness(amount: f32) -> Self {
+        let amount = amount.max(0.0);
+
+        Self::from_matrix([
+            [amount, 0.0, 0.0, 0.0],
+            [0.0, amount, 0.0, 0.0],
+            [0.0, 0.0, amount, 0.0],
+        ])
+    }
+
+    /// Creates a [`ColorFilter`] that changes the contrast of colors.
+    ///
+    /// An `amount` of `0.0` is completely gray, `1.0` leaves colors untouched,
+    /// and values over `1.0` increase the contrast.
+    pub fn contrast(amount: f32) -> Self {
+        let amount = amount.max(0.0);
+        let offset = 0.5 - 0.5 * amount;
+
+        Self::from_matrix([
+            [amount, 0.0, 0.0, offset],
+            [0.0, amount, 0.0, offset],
+            [0.0, 0.0, amount, offset],
+        ])
+    }
+
+    /// Creates a [`ColorFilter`] that rotates the hue of colors by the given angle.
+    pub fn hue_rotate(angle: impl Into<Radians>) -> Self {
+        let Radians(angle) = angle.into();
+        let (sin, cos) = angle.sin_cos();
+
+        Self::from_matrix([
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+                0.0,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+                0.0,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+                0.0,
+            ],
+        ])
+    }
+
+    /// Creates a [`ColorFilter`] that multiplies colors by the given [`Color`].
+    ///
+    /// The alpha of the [`Color`] is used as the strength of the tint.
+    pub fn tint(color: impl Into<Color>) -> Self {
+        let color = color.into();
+        let factor = |component: f32| 1.0 - color.a + color.a * component;
+
+        Self::from_matrix([
+            [factor(color.r), 0.0, 0.0, 0.0],
+            [0.0, factor(color.g), 0.0, 0.0],
+            [0.0, 0.0, factor(color.b), 0.0],
+        ])
+    }
+
+    /// Composes the [`ColorFilter`] with another one, which is applied after it.
+    pub fn then(self, next: Self) -> Self {
+        let a = self.matrix;
+        let b = next.matrix;
+
+        let mut matrix = [[0.0; 4]; 3];
+
+        for (row, output) in matrix.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..3).map(|i| b[row][i] * a[i][column]).sum();
+            }
+
+            output[3] += b[row][3];
+        }
+
+        Self { matrix }
+    }
+
+    /// Returns the row-major color matrix of the [`ColorFilter`].
+    pub fn matrix(&self) -> [[f32; 4]; 3] {
+        self.matrix
+    }
+
+    /// Returns `true` if the [`ColorFilter`] leaves colors untouched.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Applies the [`ColorFilter`] to the given red, green, and blue components.
+    ///
+    /// The resulting components are clamped to the [0.0, 1.0] range.
+    pub fn apply(&self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+        self.matrix
+            .map(|row| (row[0] * r + row[1] * g + row[2] * b + row[3]).clamp(0.0, 1.0))
+    }
+
+    fn mix(self, other: Self, amount: f32) -> Self {
+        let mut matrix = self.matrix;
+
+        for (row, other) in matrix.iter_mut().zip(other.matrix) {
+            for (value, other) in row.iter_mut().zip(other) {
+                *value = *value * (1.0 - amount) + other * amount;
+            }
+        }
+
+        Self { matrix }
+    }
+}
+
+impl Default for ColorFilter {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn then_composes_in_order() {
+        let filter = ColorFilter::brightness(0.5).then(ColorFilter::contrast(2.0));
+
+        // (0.8 * 0.5 - 0.5) * 2.0 + 0.5
+        let [r, g, b] = filter.apply([0.8, 0.8, 0.8]);
+
+        assert!((r - 0.3).abs() < 1e-6);
+        assert!((g - 0.3).abs() < 1e-6);
+        assert!((b - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn neutral_amounts_are_identity() {
+        for filter in [
+            ColorFilter::grayscale(0.0),
+            ColorFilter::sepia(0.0),
+            ColorFilter::saturate(1.0),
+            ColorFilter::brightness(1.0),
+            ColorFilter::contrast(1.0),
+            ColorFilter::tint(Color::TRANSPARENT),
+        ] {
+            assert_eq!(filter, ColorFilter::IDENTITY);
+        }
+
+        let [r, g, b] = ColorFilter::hue_rotate(0.0).apply([0.2, 0.4, 0.6]);
+
+        assert!((r - 0.2).abs() < 1e-3);
+        assert!((g - 0.4).abs() < 1e-3);
+        assert!((b - 0.6).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,165 @@
+//! Decode [ThumbHash] placeholders.
+//!
+//! [ThumbHash]: https://evanw.github.io/thumbhash/
+use std::f32::consts::PI;
+
+/// Decodes a ThumbHash into RGBA pixels; returning their width and height.
+///
+/// The image is at most 32 pixels wide or tall, keeping the approximate
+/// aspect ratio of the original image.
+///
+/// Returns `None` if the hash is invalid.
+pub fn decode(hash: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    let header24 = u32::from(*hash.first()?)
+        | (u32::from(*hash.get(1)?) << 8)
+        | (u32::from(*hash.get(2)?) << 16);
+
+    let header16 = u32::from(*hash.get(3)?) | (u32::from(*hash.get(4)?) << 8);
+
+    let l_dc = (header24 & 63) as f32 / 63.0;
+    let p_dc = ((header24 >> 6) & 63) as f32 / 31.5 - 1.0;
+    let q_dc = ((header24 >> 12) & 63) as f32 / 31.5 - 1.0;
+    let l_scale = ((header24 >> 18) & 31) as f32 / 31.0;
+    let has_alpha = (header24 >> 23) != 0;
+    let p_scale = ((header16 >> 3) & 63) as f32 / 63.0;
+    let q_scale = ((header16 >> 9) & 63) as f32 / 63.0;
+    let is_landscape = (header16 >> 15) != 0;
+
+    let (lx, ly) = match (is_landscape, has_alpha) {
+        (true, true) => (5, header16 & 7),
+        (true, false) => (7, header16 & 7),
+        (false, true) => (header16 & 7, 5),
+        (false, false) => (header16 & 7, 7),
+    };
+
+    let ratio = lx as f32 / ly as f32;
+    let (lx, ly) = (lx.max(3) as usize, ly.max(3) as usize);
+
+    let (a_dc, a_scale) = if has_alpha {
+        let byte = *hash.get(5)?;
+
+        (f32::from(byte & 15) / 15.0, f32::from(byte >> 4) / 15.0)
+    } else {
+        (1.0, 0.0)
+    };
+
+    let mut reader = Reader {
+        hash,
+        start: if has_alpha { 6 } else { 5 },
+        index: 0,
+    };
+
+    // Saturation is boosted to compensate for quantization
+    let l_ac = reader.channel(lx, ly, l_scale)?;
+    let p_ac = reader.channel(3, 3, p_scale * 1.25)?;
+    let q_ac = reader.channel(3, 3, q_scale * 1.25)?;
+    let a_ac = if has_alpha {
+        reader.channel(5, 5, a_scale)?
+    } else {
+        Vec::new()
+    };
+
+    let (width, height) = if ratio > 1.0 {
+        (32, (32.0 / ratio).round() as u32)
+    } else {
+        ((32.0 * ratio).round() as u32, 32)
+    };
+
+    let coefficients_x = lx.max(if has_alpha { 5 } else { 3 });
+    let coefficients_y = ly.max(if has_alpha { 5 } else { 3 });
+
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+    let mut fx = vec![0.0; coefficients_x];
+    let mut fy = vec![0.0; coefficients_y];
+
+    for y in 0..height {
+        for x in 0..width {
+            let (mut l, mut p, mut q, mut a) = (l_dc, p_dc, q_dc, a_dc);
+
+            for (cx, f) in fx.iter_mut().enumerate() {
+                *f = (PI / width as f32 * (x as f32 + 0.5) * cx as f32).cos();
+            }
+
+            for (cy, f) in fy.iter_mut().enumerate() {
+                *f = (PI / height as f32 * (y as f32 + 0.5) * cy as f32).cos();
+            }
+
+            for ((cx, cy), ac) in components(lx, ly).zip(&l_ac) {
+                l += ac * fx[cx] * fy[cy] * 2.0;
+            }
+
+            for ((cx, cy), (p_ac, q_ac)) in components(3, 3).zip(p_ac.iter().zip(&q_ac)) {
+                let f = fx[cx] * fy[cy] * 2.0;
+
+                p += p_ac * f;
+                q += q_ac * f;
+            }
+
+            for ((cx, cy), ac) in components(5, 5).zip(&a_ac) {
+                a += ac * fx[cx] * fy[cy] * 2.0;
+            }
+
+            let b = l - 2.0 / 3.0 * p;
+            let r = (3.0 * l - b + q) / 2.0;
+            let g = r - q;
+
+            pixels.extend([r, g, b, a].map(|channel| (channel.clamp(0.0, 1.0) * 255.0) as u8));
+        }
+    }
+
+    Some((width, height, pixels))
+}
+
+/// Returns the `(x, y)` indices of the AC components of a channel.
+fn components(nx: usize, ny: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..ny).flat_map(move |cy| {
+        (usize::from(cy == 0)..)
+            .take_while(move |cx| cx * ny < nx * (ny - cy))
+            .map(move |cx| (cx, cy))
+    })
+}
+
+struct Reader<'a> {
+    hash: &'a [u8],
+    start: usize,
+    index: usize,
+}
+
+impl Reader<'_> {
+    fn channel(&mut self, nx: usize, ny: usize, scale: f32) -> Option<Vec<f32>> {
+        components(nx, ny)
+            .map(|_| {
+                let byte = *self.hash.get(self.start + (self.index >> 1))?;
+                let nibble = (byte >> ((self.index & 1) << 2)) & 15;
+
+                self.index += 1;
+
+                Some((f32::from(nibble) / 7.5 - 1.0) * scale)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_thumbhash() {
+        let hash = [
+            0x1b, 0x08, 0x06, 0x0d, 0x82, 0x79, 0x77, 0x78, 0x87, 0x87, 0x77, 0x77, 0x87, 0x77,
+            0x87, 0x87, 0x77, 0x60, 0x57, 0x78, 0x87, 0x88, 0x77,
+        ];
+
+        let (width, height, pixels) = decode(&hash).unwrap();
+
+        assert!(width == 32 || height == 32);
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+    }
+
+    #[test]
+    fn rejects_truncated_thumbhash() {
+        assert_eq!(decode(&[0x1b, 0x08, 0x06]), None);
+        assert_eq!(decode(&[0x1b, 0x08, 0x06, 0x0d, 0x82, 0x79]), None);
+    }
+}
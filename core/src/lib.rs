@@ -51,7 +51,7 @@ mod vector;
 
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
-pub use animation::Animation;
+pub use animation::{Animated, Animation};
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -1,14 +1,23 @@
 //! Track mouse clicks.
 use crate::mouse::Button;
-use crate::time::Instant;
+use crate::time::{Duration, Instant};
 use crate::{Point, Transformation};
 
 use std::ops::Mul;
 
+/// The maximum time between consecutive clicks, following the default
+/// double-click interval of the current platform.
+pub const INTERVAL: Duration = if cfg!(any(target_os = "windows", target_os = "macos")) {
+    Duration::from_millis(500)
+} else {
+    Duration::from_millis(400)
+};
+
 /// A mouse click.
 #[derive(Debug, Clone, Copy)]
 pub struct Click {
     kind: Kind,
+    count: usize,
     button: Button,
     position: Point,
     time: Instant,
@@ -43,18 +52,18 @@ impl Click {
     pub fn new(position: Point, button: Button, previous: Option<Click>) -> Click {
         let time = Instant::now();
 
-        let kind = if let Some(previous) = previous {
-            if previous.is_consecutive(position, time) && button == previous.button {
-                previous.kind.next()
-            } else {
-                Kind::Single
+        let (kind, count) = match previous {
+            Some(previous)
+                if previous.is_consecutive(position, time) && button == previous.button =>
+            {
+                (previous.kind.next(), previous.count + 1)
             }
-        } else {
-            Kind::Single
+            _ => (Kind::Single, 1),
         };
 
         Click {
             kind,
+            count,
             button,
             position,
             time,
@@ -66,22 +75,24 @@ impl Click {
         self.kind
     }
 
+    /// Returns the amount of consecutive clicks, including this one.
+    ///
+    /// Unlike the [`Kind`], it keeps growing for as long as the clicks stay
+    /// consecutive.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
     /// Returns the position of the [`Click`].
     pub fn position(&self) -> Point {
         self.position
     }
 
     fn is_consecutive(&self, new_position: Point, time: Instant) -> bool {
-        let duration = if time > self.time {
-            Some(time - self.time)
-        } else {
-            None
-        };
-
         self.position.distance(new_position) < 6.0
-            && duration
-                .map(|duration| duration.as_millis() <= 300)
-                .unwrap_or(false)
+            && time
+                .checked_duration_since(self.time)
+                .is_some_and(|duration| duration <= INTERVAL)
     }
 }
 
@@ -91,9 +102,33 @@ impl Mul<Transformation> for Click {
     fn mul(self, transformation: Transformation) -> Click {
         Click {
             kind: self.kind,
+            count: self.count,
             button: self.button,
             position: self.position * transformation,
             time: self.time,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Click, Kind};
+    use crate::Point;
+    use crate::mouse::Button;
+
+    #[test]
+    fn consecutive_clicks_are_counted() {
+        let first = Click::new(Point::ORIGIN, Button::Left, None);
+        let second = Click::new(Point::new(1.0, 1.0), Button::Left, Some(first));
+        let third = Click::new(Point::new(1.0, 1.0), Button::Left, Some(second));
+        let fourth = Click::new(Point::new(1.0, 1.0), Button::Left, Some(third));
+
+        assert_eq!(fourth.count(), 4);
+        assert_eq!(fourth.kind(), Kind::Double);
+
+        let other = Click::new(Point::new(1.0, 1.0), Button::Right, Some(fourth));
+
+        assert_eq!(other.count(), 1);
+        assert_eq!(other.kind(), Kind::Single);
+    }
+}
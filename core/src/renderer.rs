@@ -2,7 +2,9 @@
 #[cfg(debug_assertions)]
 mod null;
 
+use crate::border;
 use crate::image;
+use crate::svg;
 use crate::{
     Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size, Transformation, Vector,
 };
@@ -53,6 +55,22 @@ pub trait Renderer {
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
+    /// Blurs whatever has been drawn beneath the given bounds so far, clipped
+    /// to the given [`border::Radius`].
+    ///
+    /// It should be called right after starting a new layer, so anything
+    /// drawn afterwards in the layer is drawn on top of the blur.
+    ///
+    /// By default, it does nothing; renderers unable to blur will simply
+    /// show whatever is drawn on top of it.
+    fn blur_backdrop(
+        &mut self,
+        _bounds: Rectangle,
+        _border_radius: border::Radius,
+        _blur_radius: f32,
+    ) {
+    }
+
     /// Creates an [`image::Allocation`] for the given [`image::Handle`] and calls the given callback with it.
     fn allocate_image(
         &mut self,
@@ -60,6 +78,42 @@ pub trait Renderer {
         callback: impl FnOnce(Result<image::Allocation, image::Error>) + Send + 'static,
     );
 
+    /// Prepares the SVG of the given [`svg::Handle`] and calls the given callback with its
+    /// dimensions once it is ready to be drawn.
+    fn prepare_svg(
+        &mut self,
+        handle: &svg::Handle,
+        callback: impl FnOnce(Size<u32>) + Send + 'static,
+    );
+
+    /// Sets the memory budget, in bytes, for the unused entries of the image caches
+    /// of the [`Renderer`].
+    ///
+    /// Images and rasterized SVGs that were not drawn in the last frame are kept
+    /// as long as they fit in the budget, evicting the least recently used first.
+    ///
+    /// By default, the budget is zero and unused entries are dropped right away.
+    fn set_image_cache_budget(&mut self, _budget: usize) {}
+
+    /// Pins or unpins the image or SVG with the given [`image::Id`].
+    ///
+    /// Pinned entries are never evicted from the image caches of the [`Renderer`],
+    /// even when unused.
+    fn pin_image(&mut self, _id: image::Id, _pinned: bool) {}
+
+    /// Evicts the unpinned entries of the image or SVG with the given [`image::Id`]
+    /// from the image caches of the [`Renderer`] at the end of the current frame.
+    fn evict_image(&mut self, _id: image::Id) {}
+
+    /// Evicts all the unused and unpinned entries from the image caches of the
+    /// [`Renderer`] at the end of the current frame, regardless of the budget.
+    fn trim_image_cache(&mut self) {}
+
+    /// Returns the [`image::CacheStats`] of the image caches of the [`Renderer`].
+    fn image_cache_stats(&self) -> image::CacheStats {
+        image::CacheStats::default()
+    }
+
     /// Provides hints to the [`Renderer`] about the rendering target.
     ///
     /// This may be used internally by the [`Renderer`] to perform optimizations
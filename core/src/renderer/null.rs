@@ -25,6 +25,14 @@ impl Renderer for () {
         callback(Ok(unsafe { image::allocate(handle, Size::new(100, 100)) }));
     }
 
+    fn prepare_svg(
+        &mut self,
+        _handle: &svg::Handle,
+        callback: impl FnOnce(Size<u32>) + Send + 'static,
+    ) {
+        callback(Size::default());
+    }
+
     fn hint(&mut self, _scale_factor: f32) {}
 
     fn scale_factor(&self) -> Option<f32> {
@@ -152,6 +160,14 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn range_bounds(&self, _start: usize, _end: usize) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -186,6 +202,18 @@ impl text::Editor for () {
         0
     }
 
+    fn range_bounds(
+        &self,
+        _start: text::editor::Position,
+        _end: text::editor::Position,
+    ) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        vec![]
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
     fn move_to(&mut self, _cursor: text::editor::Cursor) {}
@@ -11,4 +11,10 @@ pub struct Shadow {
 
     /// The blur radius of the shadow.
     pub blur_radius: f32,
+
+    /// The spread radius of the shadow.
+    ///
+    /// A positive spread grows the shadow beyond the bounds of its casting
+    /// shape, while a negative one shrinks it.
+    pub spread: f32,
 }
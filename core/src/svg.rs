@@ -1,7 +1,15 @@
 //! Load and draw vector graphics.
+mod animation;
+mod fragment;
+
+pub use animation::Animation;
+pub use fragment::extract_fragment;
+
+use crate::time::Duration;
 use crate::{Color, Radians, Rectangle, Size, image};
 
 use std::borrow::Cow;
+use std::fs;
 use std::hash::Hash;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,13 +25,16 @@ pub struct Svg<H = Handle> {
 
     /// The [`Color`] filter to be applied to the [`Svg`].
     ///
-    /// If some [`Color`] is set, the whole [`Svg`] will be
-    /// painted with it—ignoring any intrinsic colors.
+    /// If some [`Color`] is set, the [`Svg`] will be painted with it
+    /// according to its [`ColorMode`].
     ///
     /// This can be useful for coloring icons programmatically
     /// (e.g. with a theme).
     pub color: Option<Color>,
 
+    /// The [`ColorMode`] used to apply the [`Color`] filter of the [`Svg`].
+    pub color_mode: ColorMode,
+
     /// The rotation to be applied to the image; on its center.
     pub rotation: Radians,
 
@@ -31,6 +42,17 @@ pub struct Svg<H = Handle> {
     ///
     /// 0 means transparent. 1 means opaque.
     pub opacity: f32,
+
+    /// The playback time of the SMIL [`Animation`] of the [`Svg`].
+    ///
+    /// If `None`, the [`Svg`] will be drawn without any animations applied.
+    pub time: Option<Duration>,
+
+    /// The region of the [`Svg`] to draw, in the coordinate space of its
+    /// default dimensions.
+    ///
+    /// If `None`, the whole [`Svg`] will be drawn.
+    pub viewport: Option<Rectangle>,
 }
 
 impl Svg<Handle> {
@@ -39,8 +61,11 @@ impl Svg<Handle> {
         Self {
             handle: handle.into(),
             color: None,
+            color_mode: ColorMode::default(),
             rotation: Radians(0.0),
             opacity: 1.0,
+            time: None,
+            viewport: None,
         }
     }
 
@@ -50,6 +75,12 @@ impl Svg<Handle> {
         self
     }
 
+    /// Sets the [`ColorMode`] of the [`Svg`].
+    pub fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+
     /// Sets the rotation of the [`Svg`].
     pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
         self.rotation = rotation.into();
@@ -61,6 +92,24 @@ impl Svg<Handle> {
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the playback time of the SMIL [`Animation`] of the [`Svg`].
+    pub fn time(mut self, time: Duration) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the region of the [`Svg`] to draw.
+    pub fn viewport(mut self, viewport: Rectangle) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+}
+
+impl From<Handle> for Svg {
+    fn from(handle: Handle) -> Self {
+        Svg::new(handle)
+    }
 }
 
 impl From<&Handle> for Svg {
@@ -69,11 +118,31 @@ impl From<&Handle> for Svg {
     }
 }
 
+/// The strategy used to apply the [`Color`] filter of an [`Svg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// The whole [`Svg`] is painted with the [`Color`]—ignoring
+    /// any intrinsic colors.
+    #[default]
+    Filter,
+
+    /// Only the elements of the [`Svg`] using `currentColor` are
+    /// painted with the [`Color`]—keeping any other intrinsic colors.
+    ///
+    /// This is useful for multi-color icon sets designed for CSS theming.
+    ///
+    /// This mode has no effect on [`Handle`]s created with
+    /// [`Handle::from_tree`], since their colors are already resolved.
+    CurrentColor,
+}
+
 /// A handle of Svg data.
 #[derive(Debug, Clone)]
 pub struct Handle {
     id: Id,
     data: Arc<Data>,
+    fragment: Option<Arc<str>>,
+    styles: Option<Arc<str>>,
 }
 
 impl Handle {
@@ -83,6 +152,36 @@ impl Handle {
         Self::from_data(Data::Path(path.into()))
     }
 
+    /// Creates an SVG [`Handle`] pointing to the element with the given `id`
+    /// of the vector image of the given path.
+    ///
+    /// This lets you ship a large icon set as a single sprite sheet and
+    /// reference each icon—usually a `<symbol>`—by its `id`, with or without
+    /// a leading `#`:
+    ///
+    /// ```
+    /// # use iced_core::svg::Handle;
+    /// let save = Handle::from_path_with_id("icons.svg", "#save");
+    /// let open = Handle::from_path_with_id("icons.svg", "#open");
+    /// ```
+    ///
+    /// The file is shared by all of its fragments. See [`extract_fragment`]
+    /// for details on how the element is sized.
+    pub fn from_path_with_id(path: impl Into<PathBuf>, id: impl AsRef<str>) -> Handle {
+        Self::from_path(path).with_fragment(id.as_ref())
+    }
+
+    /// Creates an SVG [`Handle`] pointing to the element with the given `id`
+    /// of the vector image contained in the given bytes.
+    ///
+    /// See [`Handle::from_path_with_id`] for more details.
+    pub fn from_memory_with_id(
+        bytes: impl Into<Cow<'static, [u8]>>,
+        id: impl AsRef<str>,
+    ) -> Handle {
+        Self::from_memory(bytes).with_fragment(id.as_ref())
+    }
+
     /// Creates an SVG [`Handle`] from raw bytes containing either an SVG string
     /// or gzip compressed data.
     ///
@@ -105,9 +204,65 @@ impl Handle {
         Handle {
             id,
             data: Arc::new(data),
+            fragment: None,
+            styles: None,
         }
     }
 
+    fn with_fragment(self, id: &str) -> Handle {
+        let fragment = id.strip_prefix('#').unwrap_or(id);
+
+        Handle {
+            id: self.id.derive(format!("#{fragment}")),
+            fragment: Some(Arc::from(fragment)),
+            ..self
+        }
+    }
+
+    /// Returns a new [`Handle`] that injects the given CSS style sheet into
+    /// the SVG when it is parsed.
+    ///
+    /// This can be used to restyle specific elements of the SVG by their `id`
+    /// or `class`—like `.badge { fill: red }`—without shipping a variant of the
+    /// file for each color.
+    ///
+    /// Calling this method multiple times appends the style sheets in order.
+    ///
+    /// Styles have no effect on [`Handle`]s created with [`Handle::from_tree`],
+    /// since their styles are already resolved.
+    pub fn with_styles(self, styles: impl AsRef<str>) -> Handle {
+        let styles = match &self.styles {
+            Some(current) => format!("{current}\n{}", styles.as_ref()),
+            None => styles.as_ref().to_owned(),
+        };
+
+        Handle {
+            id: self.id.derive(&styles),
+            styles: Some(Arc::from(styles)),
+            ..self
+        }
+    }
+
+    /// Returns a new [`Handle`] that paints the elements of the SVG with the
+    /// given classes using their respective [`Color`].
+    ///
+    /// Both the `fill` and the `currentColor` of the matching elements are
+    /// replaced. See [`Handle::with_styles`] for full control.
+    pub fn with_colors<'a>(self, colors: impl IntoIterator<Item = (&'a str, Color)>) -> Handle {
+        use std::fmt::Write;
+
+        let mut styles = String::new();
+
+        for (class, color) in colors {
+            let [r, g, b, _] = color.into_rgba8();
+            let color = format!("rgba({r}, {g}, {b}, {})", color.a);
+
+            let _ = writeln!(styles, ".{class} {{ fill: {color}; color: {color} }}");
+        }
+
+        self.with_styles(styles)
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> Id {
         self.id
@@ -117,6 +272,18 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the `id` of the element of the SVG referenced by the [`Handle`],
+    /// if any.
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Returns the CSS style sheet injected into the SVG of the [`Handle`],
+    /// if any.
+    pub fn styles(&self) -> Option<&str> {
+        self.styles.as_deref()
+    }
 }
 
 impl<T> From<T> for Handle
@@ -156,6 +323,23 @@ pub enum Data {
     Tree(usvg::Tree),
 }
 
+impl Data {
+    /// Reads the SVG source of the [`Data`], decompressing it if necessary.
+    ///
+    /// Returns `None` if the source cannot be read or if the [`Data`] is an
+    /// already parsed tree.
+    pub fn source(&self) -> Option<String> {
+        match self {
+            Data::Path(path) => fs::read_to_string(path).ok(),
+            Data::Bytes(bytes) if bytes.starts_with(&[0x1f, 0x8b]) => {
+                String::from_utf8(usvg::decompress_svgz(bytes).ok()?).ok()
+            }
+            Data::Bytes(bytes) => String::from_utf8(bytes.to_vec()).ok(),
+            Data::Tree(_) => None,
+        }
+    }
+}
+
 impl std::fmt::Debug for Data {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -173,6 +357,42 @@ pub trait Renderer: crate::Renderer {
     /// Returns the default dimensions of an SVG for the given [`Handle`].
     fn measure_svg(&self, handle: &Handle) -> Size<u32>;
 
+    /// Returns the total duration of the SMIL [`Animation`] of an SVG for the given [`Handle`].
+    ///
+    /// Returns `None` if the SVG is not animated.
+    fn measure_svg_animation(&self, _handle: &Handle) -> Option<Duration> {
+        None
+    }
+
+    /// Returns whether the SVG of the given [`Handle`] is still being prepared
+    /// in the background.
+    ///
+    /// A [`Renderer`] may choose to prepare large SVGs concurrently. While
+    /// pending, [`Renderer::measure_svg`] returns a zero size and
+    /// [`Renderer::draw_svg`] draws nothing.
+    ///
+    /// If you need an SVG to be ready right away, consider using
+    /// [`Renderer::prepare_svg`](crate::Renderer::prepare_svg).
+    fn is_svg_pending(&self, _handle: &Handle) -> bool {
+        false
+    }
+
+    /// Returns whether the SVG of the given [`Handle`] could not be loaded;
+    /// either because its file is missing or because its data is invalid.
+    fn is_svg_invalid(&self, _handle: &Handle) -> bool {
+        false
+    }
+
+    /// Rasterizes an [`Svg`] into the RGBA pixels of an [`image::Handle`] of the
+    /// given size.
+    ///
+    /// This may be expensive and it is not cached, so it should be done sparingly.
+    ///
+    /// By default, it returns `None`; meaning rasterization is not supported.
+    fn rasterize_svg(&self, _svg: &Svg, _size: Size<u32>) -> Option<image::Handle> {
+        None
+    }
+
     /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
     fn draw_svg(&mut self, svg: Svg, bounds: Rectangle, clip_bounds: Rectangle);
 }
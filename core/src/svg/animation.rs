@@ -0,0 +1,835 @@
+use crate::Color;
+use crate::svg::Data;
+use crate::time::Duration;
+
+use std::ops::Range;
+
+const XLINK: &str = "http://www.w3.org/1999/xlink";
+
+/// The time between two frames of an [`Animation`], in nanoseconds.
+const FRAME_STEP: u64 = 1_000_000_000 / 60;
+
+/// The SMIL animations of some SVG document.
+///
+/// Only a subset of SMIL is supported: `<animate>`, `<set>`, and
+/// `<animateTransform>` elements with clock-based timing. Syncbase and
+/// event-based timing (e.g. `begin="click"`) are ignored.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    source: String,
+    targets: Vec<Target>,
+    animations: Vec<Animate>,
+    duration: Duration,
+    settle: u64,
+    period: Option<u64>,
+}
+
+impl Animation {
+    /// Loads the [`Animation`] of the given SVG [`Data`].
+    ///
+    /// Returns `None` if the data cannot be read or if it does not contain
+    /// any supported animations.
+    pub fn load(data: &Data) -> Option<Self> {
+        Self::parse(data.source()?)
+    }
+
+    /// Parses the [`Animation`] of the given SVG source.
+    ///
+    /// Returns `None` if the source does not contain any supported animations.
+    pub fn parse(source: impl Into<String>) -> Option<Self> {
+        let source = source.into();
+
+        if !source.contains("<animate") && !source.contains("<set") {
+            return None;
+        }
+
+        let document = roxmltree::Document::parse_with_options(
+            &source,
+            roxmltree::ParsingOptions {
+                allow_dtd: true,
+                ..roxmltree::ParsingOptions::default()
+            },
+        )
+        .ok()?;
+
+        let mut targets: Vec<(roxmltree::NodeId, Target)> = Vec::new();
+        let mut animations = Vec::new();
+
+        for node in document.descendants().filter(roxmltree::Node::is_element) {
+            let kind = match node.tag_name().name() {
+                "animate" | "set" => Kind::Attribute,
+                "animateTransform" => {
+                    Kind::Transform(node.attribute("type").unwrap_or("translate").to_owned())
+                }
+                _ => continue,
+            };
+
+            let Some(attribute) = node.attribute("attributeName") else {
+                continue;
+            };
+
+            let target = match node
+                .attribute("href")
+                .or_else(|| node.attribute((XLINK, "href")))
+            {
+                Some(href) => href.strip_prefix('#').and_then(|id| {
+                    document
+                        .descendants()
+                        .find(|node| node.attribute("id") == Some(id))
+                }),
+                None => node.parent_element(),
+            };
+
+            let Some(target) = target else {
+                continue;
+            };
+
+            let index = if let Some(index) = targets.iter().position(|(id, _)| *id == target.id()) {
+                index
+            } else {
+                let Some(parsed) = Target::parse(&source, target.range().start) else {
+                    continue;
+                };
+
+                targets.push((target.id(), parsed));
+                targets.len() - 1
+            };
+
+            let Some(animation) = Animate::parse(node, index, attribute, kind, &targets[index].1)
+            else {
+                continue;
+            };
+
+            animations.push(animation);
+        }
+
+        if animations.is_empty() {
+            return None;
+        }
+
+        // Targets are rewritten in document order
+        let mut order: Vec<usize> = (0..targets.len()).collect();
+        order.sort_by_key(|index| targets[*index].1.tag.start);
+
+        let mut remap = vec![0; targets.len()];
+
+        for (new, old) in order.iter().enumerate() {
+            remap[*old] = new;
+        }
+
+        for animation in &mut animations {
+            animation.target = remap[animation.target];
+        }
+
+        let mut targets: Vec<Option<Target>> = targets
+            .into_iter()
+            .map(|(_, target)| Some(target))
+            .collect();
+
+        let targets = order
+            .into_iter()
+            .filter_map(|index| targets[index].take())
+            .collect();
+
+        let end = animations
+            .iter()
+            .map(|animation| animation.begin + animation.active)
+            .fold(0.0, f64::max);
+
+        // Every animation either comes to rest or repeats forever, so the
+        // whole document does too once all of them have begun or ended
+        let settle = animations
+            .iter()
+            .map(|animation| {
+                if animation.active.is_finite() {
+                    animation.begin + animation.active
+                } else {
+                    animation.begin
+                }
+            })
+            .fold(0.0, f64::max);
+
+        let period = animations
+            .iter()
+            .filter(|animation| animation.active.is_infinite() && animation.duration.is_finite())
+            .try_fold(1, |period, animation| {
+                let step = FRAME_STEP as f64 / 1_000_000_000.0;
+                let frames = animation.duration / step;
+
+                // Only whole frames can be looped seamlessly
+                if frames < 0.5 || (frames - frames.round()).abs() > 0.01 {
+                    return None;
+                }
+
+                let frames = frames.round() as u64;
+
+                (period / gcd(period, frames)).checked_mul(frames)
+            });
+
+        Some(Self {
+            source,
+            targets,
+            animations,
+            duration: Duration::try_from_secs_f64(end).unwrap_or(Duration::MAX),
+            settle: Duration::try_from_secs_f64(settle).map_or(u64::MAX, frame_ceil),
+            period,
+        })
+    }
+
+    /// Returns the total [`Duration`] of the [`Animation`].
+    ///
+    /// An [`Animation`] that repeats indefinitely lasts [`Duration::MAX`].
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the time of the frame of the [`Animation`] shown at the given
+    /// time.
+    ///
+    /// Times are snapped to a fixed frame step; they stay put once the
+    /// [`Animation`] comes to rest, and they wrap around once it starts
+    /// looping. Therefore, the frames of the same time can be cached and
+    /// reused, instead of being parsed over and over.
+    pub fn frame_time(&self, time: Duration) -> Duration {
+        let frame = u64::try_from(time.as_nanos() / u128::from(FRAME_STEP)).unwrap_or(u64::MAX);
+
+        let frame = match self.period {
+            Some(period) if frame > self.settle => self.settle + (frame - self.settle) % period,
+            _ => frame,
+        };
+
+        Duration::from_nanos(frame.saturating_mul(FRAME_STEP))
+    }
+
+    /// Returns the SVG source of the [`Animation`] at the given time.
+    pub fn frame(&self, time: Duration) -> String {
+        let time = time.as_secs_f64();
+        let mut overrides: Vec<Vec<(&str, String)>> = vec![Vec::new(); self.targets.len()];
+
+        for animation in &self.animations {
+            let Some(value) = animation.value_at(time) else {
+                continue;
+            };
+
+            let target = &self.targets[animation.target];
+            let overrides = &mut overrides[animation.target];
+
+            let value = match &animation.kind {
+                Kind::Attribute => value,
+                Kind::Transform(kind) => {
+                    let transform = format!("{kind}({value})");
+
+                    if animation.additive {
+                        let base = overrides
+                            .iter()
+                            .rev()
+                            .find(|(attribute, _)| *attribute == animation.attribute)
+                            .map(|(_, value)| value.as_str())
+                            .or_else(|| target.attribute(&animation.attribute));
+
+                        match base {
+                            Some(base) => format!("{base} {transform}"),
+                            None => transform,
+                        }
+                    } else {
+                        transform
+                    }
+                }
+            };
+
+            overrides.push((animation.attribute.as_str(), value));
+        }
+
+        let mut frame = String::with_capacity(self.source.len());
+        let mut cursor = 0;
+
+        for (target, overrides) in self.targets.iter().zip(&overrides) {
+            if overrides.is_empty() {
+                continue;
+            }
+
+            frame.push_str(&self.source[cursor..target.tag.start]);
+            target.write(&mut frame, overrides);
+
+            cursor = target.tag.end;
+        }
+
+        frame.push_str(&self.source[cursor..]);
+        frame
+    }
+}
+
+/// The start tag of an animated element.
+#[derive(Debug, Clone)]
+struct Target {
+    tag: Range<usize>,
+    name: String,
+    attributes: Vec<Attribute>,
+    self_closing: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Attribute {
+    name: String,
+    value: String,
+    quote: char,
+}
+
+impl Target {
+    fn parse(source: &str, start: usize) -> Option<Self> {
+        let bytes = source.as_bytes();
+
+        if bytes.get(start) != Some(&b'<') {
+            return None;
+        }
+
+        let is_delimiter = |byte: u8| byte.is_ascii_whitespace() || matches!(byte, b'/' | b'>');
+
+        let mut i = start + 1;
+
+        while bytes.get(i).is_some_and(|byte| !is_delimiter(*byte)) {
+            i += 1;
+        }
+
+        let name = source[start + 1..i].to_owned();
+        let mut attributes = Vec::new();
+
+        loop {
+            while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+                i += 1;
+            }
+
+            match *bytes.get(i)? {
+                b'>' => {
+                    return Some(Self {
+                        tag: start..i + 1,
+                        name,
+                        attributes,
+                        self_closing: false,
+                    });
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'>') => {
+                    return Some(Self {
+                        tag: start..i + 2,
+                        name,
+                        attributes,
+                        self_closing: true,
+                    });
+                }
+                _ => {
+                    let name_start = i;
+
+                    while bytes
+                        .get(i)
+                        .is_some_and(|byte| !is_delimiter(*byte) && *byte != b'=')
+                    {
+                        i += 1;
+                    }
+
+                    let name = source[name_start..i].to_owned();
+
+                    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+                        i += 1;
+                    }
+
+                    if bytes.get(i) != Some(&b'=') {
+                        return None;
+                    }
+
+                    i += 1;
+
+                    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+                        i += 1;
+                    }
+
+                    let quote = match bytes.get(i)? {
+                        b'"' => '"',
+                        b'\'' => '\'',
+                        _ => return None,
+                    };
+
+                    let value_start = i + 1;
+                    let value_end = value_start + source[value_start..].find(quote)?;
+
+                    attributes.push(Attribute {
+                        name,
+                        value: source[value_start..value_end].to_owned(),
+                        quote,
+                    });
+
+                    i = value_end + 1;
+                }
+            }
+        }
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    fn write(&self, output: &mut String, overrides: &[(&str, String)]) {
+        // The last override of an attribute wins
+        let mut latest: Vec<(&str, &str)> = Vec::new();
+
+        for (attribute, value) in overrides.iter().rev() {
+            if !latest.iter().any(|(name, _)| name == attribute) {
+                latest.push((attribute, value.as_str()));
+            }
+        }
+
+        output.push('<');
+        output.push_str(&self.name);
+
+        for attribute in &self.attributes {
+            if latest.iter().any(|(name, _)| *name == attribute.name) {
+                continue;
+            }
+
+            let quote = attribute.quote;
+
+            output.push(' ');
+            output.push_str(&attribute.name);
+            output.push('=');
+            output.push(quote);
+            output.push_str(&attribute.value);
+
+            // Inline styles take precedence over presentation attributes
+            if attribute.name == "style" {
+                for (name, value) in latest.iter().rev() {
+                    if attribute.value.contains(*name) {
+                        output.push(';');
+                        output.push_str(name);
+                        output.push(':');
+                        output.push_str(&escape(value));
+                    }
+                }
+            }
+
+            output.push(quote);
+        }
+
+        for (name, value) in latest.iter().rev() {
+            output.push(' ');
+            output.push_str(name);
+            output.push_str("=\"");
+            output.push_str(&escape(value));
+            output.push('"');
+        }
+
+        output.push_str(if self.self_closing { "/>" } else { ">" });
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Animate {
+    target: usize,
+    attribute: String,
+    kind: Kind,
+    additive: bool,
+    begin: f64,
+    duration: f64,
+    active: f64,
+    freeze: bool,
+    values: Vec<String>,
+    key_times: Option<Vec<f64>>,
+    discrete: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Attribute,
+    Transform(String),
+}
+
+impl Animate {
+    fn parse(
+        node: roxmltree::Node<'_, '_>,
+        target: usize,
+        attribute: &str,
+        kind: Kind,
+        element: &Target,
+    ) -> Option<Self> {
+        let is_set = node.tag_name().name() == "set";
+
+        let begin = match node.attribute("begin") {
+            Some(begin) => begin.split(';').filter_map(parse_clock).reduce(f64::min)?,
+            None => 0.0,
+        };
+
+        let duration = match node.attribute("dur") {
+            Some("indefinite") => f64::INFINITY,
+            Some(duration) => parse_clock(duration).filter(|duration| *duration > 0.0)?,
+            None if is_set => f64::INFINITY,
+            None => return None,
+        };
+
+        let repeat_count = match node.attribute("repeatCount") {
+            Some("indefinite") => Some(f64::INFINITY),
+            Some(count) => count
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|count| *count > 0.0),
+            None => None,
+        };
+
+        let repeat_duration = match node.attribute("repeatDur") {
+            Some("indefinite") => Some(f64::INFINITY),
+            Some(duration) => parse_clock(duration),
+            None => None,
+        };
+
+        let active = match (repeat_count, repeat_duration) {
+            (None, None) => duration,
+            (Some(count), None) => duration * count,
+            (None, Some(repeat_duration)) => repeat_duration,
+            (Some(count), Some(repeat_duration)) => (duration * count).min(repeat_duration),
+        };
+
+        let values: Vec<String> = if is_set {
+            vec![node.attribute("to")?.to_owned()]
+        } else if let Some(values) = node.attribute("values") {
+            values
+                .split(';')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_owned)
+                .collect()
+        } else {
+            let from = node.attribute("from").or_else(|| match kind {
+                Kind::Attribute => element.attribute(attribute),
+                Kind::Transform(_) => None,
+            })?;
+
+            let to = match (node.attribute("to"), node.attribute("by")) {
+                (Some(to), _) => to.to_owned(),
+                (None, Some(by)) => add(from, by)?,
+                (None, None) => return None,
+            };
+
+            vec![from.to_owned(), to]
+        };
+
+        if values.is_empty() {
+            return None;
+        }
+
+        let key_times = node
+            .attribute("keyTimes")
+            .map(|key_times| {
+                key_times
+                    .split(';')
+                    .map(|time| time.trim().parse::<f64>().ok())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .and_then(|key_times| key_times.filter(|key_times| key_times.len() == values.len()));
+
+        Some(Self {
+            target,
+            attribute: attribute.to_owned(),
+            kind,
+            additive: node.attribute("additive") == Some("sum"),
+            begin,
+            duration,
+            active,
+            freeze: node.attribute("fill") == Some("freeze"),
+            values,
+            key_times,
+            discrete: is_set || node.attribute("calcMode") == Some("discrete"),
+        })
+    }
+
+    fn value_at(&self, time: f64) -> Option<String> {
+        let local = time - self.begin;
+
+        if local < 0.0 {
+            return None;
+        }
+
+        let progress = if local >= self.active {
+            if !self.freeze {
+                return None;
+            }
+
+            let fraction = (self.active / self.duration).fract();
+
+            if fraction == 0.0 { 1.0 } else { fraction }
+        } else if self.duration.is_infinite() {
+            0.0
+        } else {
+            (local % self.duration) / self.duration
+        };
+
+        Some(self.interpolate(progress))
+    }
+
+    fn interpolate(&self, progress: f64) -> String {
+        let n = self.values.len();
+
+        if n == 1 {
+            return self.values[0].clone();
+        }
+
+        let intervals = if self.discrete { n } else { n - 1 };
+
+        let key_time = |i: usize| {
+            self.key_times
+                .as_ref()
+                .map_or(i as f64 / intervals as f64, |key_times| key_times[i])
+        };
+
+        if self.discrete {
+            let index = (0..n).rev().find(|i| key_time(*i) <= progress).unwrap_or(0);
+
+            return self.values[index].clone();
+        }
+
+        let segment = (0..n - 1)
+            .find(|i| progress <= key_time(i + 1))
+            .unwrap_or(n - 2);
+
+        let (start, end) = (key_time(segment), key_time(segment + 1));
+
+        let factor = if end > start {
+            ((progress - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        interpolate(&self.values[segment], &self.values[segment + 1], factor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Number(f64),
+    Text(&'a str),
+}
+
+impl Token<'_> {
+    fn matches(self, other: Self) -> bool {
+        let is_separator = |text: &str| text.chars().all(|c| c.is_whitespace() || c == ',');
+
+        match (self, other) {
+            (Token::Number(_), Token::Number(_)) => true,
+            (Token::Text(a), Token::Text(b)) => a == b || (is_separator(a) && is_separator(b)),
+            _ => false,
+        }
+    }
+}
+
+fn interpolate(a: &str, b: &str, factor: f64) -> String {
+    let (a, b) = (a.trim(), b.trim());
+
+    if a.starts_with('#')
+        && b.starts_with('#')
+        && let (Ok(a), Ok(b)) = (a.parse::<Color>(), b.parse::<Color>())
+    {
+        let mix = |a: f32, b: f32| a + (b - a) * factor as f32;
+
+        let [red, green, blue, _] =
+            Color::from_rgb(mix(a.r, b.r), mix(a.g, b.g), mix(a.b, b.b)).into_rgba8();
+
+        return format!("rgba({red}, {green}, {blue}, {})", mix(a.a, b.a));
+    }
+
+    let (tokens_a, tokens_b) = (tokenize(a), tokenize(b));
+
+    if tokens_a.len() == tokens_b.len()
+        && tokens_a.iter().zip(&tokens_b).all(|(a, b)| a.matches(*b))
+    {
+        return tokens_a
+            .iter()
+            .zip(&tokens_b)
+            .map(|pair| match pair {
+                (Token::Number(a), Token::Number(b)) => format_number(a + (b - a) * factor),
+                (Token::Text(text), _) => (*text).to_owned(),
+                (Token::Number(number), _) => format_number(*number),
+            })
+            .collect();
+    }
+
+    if factor < 0.5 { a } else { b }.to_owned()
+}
+
+fn add(value: &str, by: &str) -> Option<String> {
+    let (tokens, by) = (tokenize(value), tokenize(by));
+
+    let numbers: Vec<f64> = by
+        .iter()
+        .filter_map(|token| match token {
+            Token::Number(number) => Some(*number),
+            Token::Text(_) => None,
+        })
+        .collect();
+
+    let mut offsets = numbers.iter();
+
+    let sum: String = tokens
+        .iter()
+        .map(|token| match token {
+            Token::Number(number) => format_number(number + offsets.next().unwrap_or(&0.0)),
+            Token::Text(text) => (*text).to_owned(),
+        })
+        .collect();
+
+    offsets.next().is_none().then_some(sum)
+}
+
+fn tokenize(value: &str) -> Vec<Token<'_>> {
+    let bytes = value.as_bytes();
+
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if let Some(end) = number_end(bytes, i) {
+            if text_start < i {
+                tokens.push(Token::Text(&value[text_start..i]));
+            }
+
+            tokens.push(Token::Number(value[i..end].parse().unwrap_or(0.0)));
+
+            i = end;
+            text_start = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    if text_start < bytes.len() {
+        tokens.push(Token::Text(&value[text_start..]));
+    }
+
+    tokens
+}
+
+fn number_end(bytes: &[u8], start: usize) -> Option<usize> {
+    fn digits(bytes: &[u8], i: &mut usize) -> bool {
+        let start = *i;
+
+        while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+            *i += 1;
+        }
+
+        *i > start
+    }
+
+    let mut i = start;
+
+    if matches!(bytes.get(i), Some(b'+' | b'-')) {
+        i += 1;
+    }
+
+    let integer = digits(bytes, &mut i);
+    let mut fraction = false;
+
+    if bytes.get(i) == Some(&b'.') {
+        let mut j = i + 1;
+
+        if digits(bytes, &mut j) {
+            i = j;
+            fraction = true;
+        }
+    }
+
+    if !integer && !fraction {
+        return None;
+    }
+
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+
+        if digits(bytes, &mut j) {
+            i = j;
+        }
+    }
+
+    Some(i)
+}
+
+fn format_number(number: f64) -> String {
+    format!("{}", (number * 10_000.0).round() / 10_000.0)
+}
+
+fn parse_clock(value: &str) -> Option<f64> {
+    let value = value.trim();
+
+    if value.contains(':') {
+        return value.split(':').try_fold(0.0, |seconds, part| {
+            Some(seconds * 60.0 + part.parse::<f64>().ok()?)
+        });
+    }
+
+    let (number, unit) = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .map_or((value, ""), |i| value.split_at(i));
+
+    let number: f64 = number.trim().parse().ok()?;
+
+    let scale = match unit {
+        "" | "s" => 1.0,
+        "ms" => 0.001,
+        "min" => 60.0,
+        "h" => 3600.0,
+        _ => return None,
+    };
+
+    Some(number * scale)
+}
+
+/// Returns the index of the first frame starting at or after the given time.
+fn frame_ceil(time: Duration) -> u64 {
+    u64::try_from(time.as_nanos().div_ceil(u128::from(FRAME_STEP))).unwrap_or(u64::MAX)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub(super) fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_times_rest_and_loop() {
+        let once = Animation::parse(
+            r#"<svg><rect width="10" height="10"><animate attributeName="x" from="0" to="10" dur="1s" fill="freeze"/></rect></svg>"#,
+        )
+        .expect("Parse animation");
+
+        assert_eq!(
+            once.frame_time(Duration::from_secs(2)),
+            once.frame_time(Duration::from_secs(5))
+        );
+
+        let forever = Animation::parse(
+            r#"<svg><rect width="10" height="10"><animate attributeName="x" from="0" to="10" dur="1s" repeatCount="indefinite"/></rect></svg>"#,
+        )
+        .expect("Parse animation");
+
+        assert_eq!(
+            forever.frame_time(Duration::from_millis(500)),
+            forever.frame_time(Duration::from_millis(1500))
+        );
+        assert_ne!(
+            forever.frame_time(Duration::from_millis(250)),
+            forever.frame_time(Duration::from_millis(500))
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use super::animation::escape;
+
+use std::fmt::Write;
+
+const SVG: &str = "http://www.w3.org/2000/svg";
+const XLINK: &str = "http://www.w3.org/1999/xlink";
+
+/// Extracts the element with the given `id` out of some SVG source; producing
+/// a standalone SVG document that only draws that element.
+///
+/// This is useful to reference the icons of a sprite sheet individually.
+///
+/// A `<symbol>` or a nested `<svg>` is drawn at the size given by its `width`
+/// and `height` or, otherwise, by its `viewBox`. Any other element is drawn in
+/// the coordinate space of the whole document.
+///
+/// The `id` may start with a `#`. Returns `None` if the source cannot be parsed
+/// or no element has the given `id`.
+pub fn extract_fragment(source: &str, id: &str) -> Option<String> {
+    let id = id.strip_prefix('#').unwrap_or(id);
+
+    let document = roxmltree::Document::parse_with_options(
+        source,
+        roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..roxmltree::ParsingOptions::default()
+        },
+    )
+    .ok()?;
+
+    let root = document.root_element();
+
+    let target = document
+        .descendants()
+        .find(|node| node.is_element() && node.attribute("id") == Some(id))?;
+
+    let size = match target.tag_name().name() {
+        "symbol" | "svg" => {
+            let view_box = target.attribute("viewBox").and_then(view_box);
+
+            let width = target
+                .attribute("width")
+                .and_then(length)
+                .or(view_box.map(|[_, _, width, _]| width));
+
+            let height = target
+                .attribute("height")
+                .and_then(length)
+                .or(view_box.map(|[_, _, _, height]| height));
+
+            width.zip(height)
+        }
+        _ => None,
+    };
+
+    // Keep any XML declaration and DTD, since entities may be used
+    let mut output = String::from(&source[..root.range().start]);
+
+    output.push_str("<svg");
+
+    let _ = write!(output, " xmlns=\"{SVG}\" xmlns:xlink=\"{XLINK}\"");
+
+    for namespace in root.namespaces() {
+        if let Some(name) = namespace.name()
+            && namespace.uri() != XLINK
+        {
+            let _ = write!(output, " xmlns:{name}=\"{}\"", escape(namespace.uri()));
+        }
+    }
+
+    for attribute in root.attributes() {
+        if attribute.namespace().is_some() {
+            continue;
+        }
+
+        let name = attribute.name();
+
+        // Presentation attributes are inherited by the extracted element
+        let is_inherited = !matches!(name, "id" | "x" | "y" | "version");
+        let is_size = matches!(name, "width" | "height" | "viewBox");
+
+        if is_inherited && !(is_size && size.is_some()) {
+            let _ = write!(output, " {name}=\"{}\"", escape(attribute.value()));
+        }
+    }
+
+    if let Some((width, height)) = size {
+        let _ = write!(
+            output,
+            " width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\""
+        );
+    }
+
+    output.push_str("><defs>");
+
+    if let (Some(first), Some(last)) = (root.first_child(), root.last_child()) {
+        output.push_str(&source[first.range().start..last.range().end]);
+    }
+
+    output.push_str("</defs>");
+
+    let _ = write!(output, "<use xlink:href=\"#{}\"", escape(id));
+
+    if let Some((width, height)) = size {
+        let _ = write!(output, " width=\"{width}\" height=\"{height}\"");
+    }
+
+    output.push_str("/></svg>");
+
+    Some(output)
+}
+
+fn length(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let number = value.strip_suffix("px").unwrap_or(value);
+
+    number
+        .parse()
+        .ok()
+        .filter(|length: &f32| length.is_finite() && *length > 0.0)
+}
+
+fn view_box(value: &str) -> Option<[f32; 4]> {
+    let mut numbers = value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|number| !number.is_empty())
+        .map(str::parse::<f32>);
+
+    let view_box = [
+        numbers.next()?.ok()?,
+        numbers.next()?.ok()?,
+        numbers.next()?.ok()?,
+        numbers.next()?.ok()?,
+    ];
+
+    (numbers.next().is_none() && view_box[2] > 0.0 && view_box[3] > 0.0).then_some(view_box)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPRITE: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" fill="currentColor">
+        <symbol id="save" viewBox="0 0 24 24"><path d="M0 0h24v24H0z"/></symbol>
+        <symbol id="open" viewBox="0 0 16 8"><path d="M0 0h16v8H0z"/></symbol>
+    </svg>"##;
+
+    #[test]
+    fn extracts_symbols() {
+        let save = extract_fragment(SPRITE, "#save").unwrap();
+
+        assert!(save.contains(r#"width="24" height="24" viewBox="0 0 24 24""#));
+        assert!(save.contains(r##"<use xlink:href="#save" width="24" height="24"/>"##));
+        assert!(save.contains(r#"fill="currentColor""#));
+        assert!(save.contains("<defs>"));
+
+        let open = extract_fragment(SPRITE, "open").unwrap();
+
+        assert!(open.contains(r#"viewBox="0 0 16 8""#));
+
+        assert!(roxmltree::Document::parse(&save).is_ok());
+        assert!(roxmltree::Document::parse(&open).is_ok());
+    }
+
+    #[test]
+    fn fails_on_missing_fragment() {
+        assert_eq!(extract_fragment(SPRITE, "#close"), None);
+    }
+}
@@ -32,6 +32,13 @@ pub trait Editor: Sized + Default {
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the regions covered by the text between the given positions.
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle>;
+
+    /// Returns the index of the visible lines of the [`Editor`], together
+    /// with the vertical offset of their first visual line.
+    fn visible_lines(&self) -> Vec<(usize, f32)>;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
@@ -93,12 +100,47 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Move the cursor of the [`Editor`] to the given [`Cursor`].
+    MoveTo(Cursor),
+    /// Undo the last group of edits.
+    Undo,
+    /// Redo the last undone group of edits.
+    Redo,
+    /// Add a cursor at the given [`Point`], keeping the current ones.
+    AddCursor(Point),
+    /// Select a rectangular block of text between the given [`Point`]s,
+    /// with a cursor for every line.
+    SelectBlock {
+        /// The corner where the selection started.
+        from: Point,
+        /// The corner where the selection ends.
+        to: Point,
+    },
+    /// Select the word at the current cursor or, if there is a selection
+    /// already, add a cursor selecting its next occurrence.
+    SelectNextOccurrence,
+    /// Search for the given text, highlighting all of its matches.
+    ///
+    /// An empty text ends the search.
+    Find(Arc<String>),
+    /// Select the next match of the current search.
+    FindNext,
+    /// Select the previous match of the current search.
+    FindPrevious,
+    /// Replace the selected match of the current search with the given
+    /// text and select the next one.
+    Replace(Arc<String>),
+    /// Replace every match of the current search with the given text.
+    ReplaceAll(Arc<String>),
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(
+            self,
+            Self::Edit(_) | Self::Undo | Self::Redo | Self::Replace(_) | Self::ReplaceAll(_)
+        )
     }
 }
 
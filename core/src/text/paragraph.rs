@@ -61,6 +61,15 @@ pub trait Paragraph: Sized + Default {
     /// that was hit.
     fn hit_span(&self, point: Point) -> Option<usize>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the byte offset of the nearest cursor
+    /// position in its whole text.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns all bounds covered by the text between the given byte offsets
+    /// of the [`Paragraph`]; one for each line it's on.
+    fn range_bounds(&self, start: usize, end: usize) -> Vec<Rectangle>;
+
     /// Returns all bounds for the provided [`Span`] index of the [`Paragraph`].
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
@@ -14,7 +14,7 @@ use crate::layout::{self, Layout};
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::{Clipboard, Event, Length, Rectangle, Shell, Size, Vector};
+use crate::{Clipboard, Event, Length, Point, Rectangle, Shell, Size, Vector};
 
 /// A component that displays information and allows interaction.
 ///
@@ -136,6 +136,24 @@ where
         mouse::Interaction::None
     }
 
+    /// Returns whether the given `position` hits the shape of the [`Widget`].
+    ///
+    /// Interactive widgets—like a button or a mouse area—may use this to
+    /// react only when the mouse cursor is over the actual shape of their
+    /// contents.
+    ///
+    /// By default, it returns `None`; meaning the [`Widget`] has no particular
+    /// shape and its bounds should be used instead.
+    fn hit_test(
+        &self,
+        _tree: &Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _position: Point,
+    ) -> Option<bool> {
+        None
+    }
+
     /// Returns the overlay of the [`Widget`], if there is any.
     fn overlay<'a>(
         &'a mut self,
@@ -440,3 +440,92 @@ pub fn scope<T: 'static>(target: Id, operation: impl Operation<T> + 'static) ->
         operation: Box::new(operation),
     }
 }
+
+/// Produces an [`Operation`] that returns the visible bounds of the widget
+/// with the given [`Id`]; that is, its bounds translated by the scrollables
+/// containing it and clipped to their viewports.
+///
+/// The resulting bounds will be `None` if the widget cannot be found or
+/// if it is scrolled out of view.
+pub fn visible_bounds(target: Id) -> impl Operation<Option<Rectangle>> {
+    struct VisibleBounds {
+        target: Id,
+        stack: Vec<(Rectangle, Vector)>,
+        viewport: Rectangle,
+        translation: Vector,
+        result: Option<Option<Rectangle>>,
+    }
+
+    impl VisibleBounds {
+        fn check(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            if self.result.is_none() && id.is_some_and(|id| *id == self.target) {
+                self.result = Some(self.viewport.intersection(&(bounds + self.translation)));
+            }
+        }
+    }
+
+    impl Operation<Option<Rectangle>> for VisibleBounds {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Option<Rectangle>>)) {
+            if self.result.is_some() {
+                return;
+            }
+
+            self.stack.push((self.viewport, self.translation));
+            operate(self);
+            let _ = self.stack.pop();
+
+            let (viewport, translation) = self.stack.last().unwrap();
+            self.viewport = *viewport;
+            self.translation = *translation;
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.check(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            self.check(id, bounds);
+
+            self.viewport = self
+                .viewport
+                .intersection(&(bounds + self.translation))
+                .unwrap_or_default();
+            self.translation -= translation;
+        }
+
+        fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Focusable) {
+            self.check(id, bounds);
+        }
+
+        fn text_input(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn TextInput) {
+            self.check(id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+            self.check(id, bounds);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Any) {
+            self.check(id, bounds);
+        }
+
+        fn finish(&self) -> Outcome<Option<Rectangle>> {
+            Outcome::Some(self.result.flatten())
+        }
+    }
+
+    VisibleBounds {
+        target,
+        stack: vec![(Rectangle::INFINITE, Vector::ZERO)],
+        viewport: Rectangle::INFINITE,
+        translation: Vector::ZERO,
+        result: None,
+    }
+}
@@ -1,7 +1,10 @@
 //! Operate on widgets that can be scrolled.
+use crate::widget::operation::{Focusable, Outcome, TextInput, then};
 use crate::widget::{Id, Operation};
 use crate::{Rectangle, Vector};
 
+use std::any::Any;
+
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
     /// Snaps the scroll of the widget to the given `percentage` along the horizontal & vertical axis.
@@ -12,6 +15,15 @@ pub trait Scrollable {
 
     /// Scroll the widget by the given [`AbsoluteOffset`] along the horizontal & vertical axis.
     fn scroll_by(&mut self, offset: AbsoluteOffset, bounds: Rectangle, content_bounds: Rectangle);
+
+    /// Scrolls the widget smoothly until its translation matches the given
+    /// [`AbsoluteOffset`] along the horizontal & vertical axis.
+    ///
+    /// The translation is measured from the start of the content, regardless
+    /// of the anchor of the widget. By default, the widget is scrolled instantly.
+    fn animate_to(&mut self, translation: AbsoluteOffset<Option<f32>>) {
+        self.scroll_to(translation);
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -104,6 +116,258 @@ pub fn scroll_by<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
     ScrollBy { target, offset }
 }
 
+/// Produces an [`Operation`] that smoothly scrolls the widget with the given
+/// [`Id`] until the `target` widget inside of it is aligned with the start
+/// of its viewport.
+pub fn scroll_to_widget<T>(scrollable: Id, target: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    then(Locate::new(target, Some(scrollable)), resolve)
+}
+
+/// Produces an [`Operation`] that smoothly scrolls every widget containing
+/// the `target` widget just enough to bring it into view.
+///
+/// Widgets that are already fully visible are left untouched.
+pub fn scroll_into_view<T>(target: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    then(Locate::new(target, None), resolve)
+}
+
+#[derive(Debug, Clone)]
+struct Ancestor {
+    index: usize,
+    id: Option<Id>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    translation: Vector,
+}
+
+impl Ancestor {
+    fn reveal(&self, target: Rectangle, align: bool) -> AbsoluteOffset<Option<f32>> {
+        AbsoluteOffset {
+            x: reveal(
+                target.x - self.content_bounds.x,
+                target.width,
+                self.bounds.width,
+                self.content_bounds.width,
+                self.translation.x,
+                align,
+            ),
+            y: reveal(
+                target.y - self.content_bounds.y,
+                target.height,
+                self.bounds.height,
+                self.content_bounds.height,
+                self.translation.y,
+                align,
+            ),
+        }
+    }
+}
+
+/// Computes the translation along a single axis needed to show a target
+/// placed at `start`—relative to the content—with the given `size`.
+///
+/// Unless `align` is set, the `current` translation is kept whenever the
+/// target is already visible.
+fn reveal(
+    start: f32,
+    size: f32,
+    viewport: f32,
+    content: f32,
+    current: f32,
+    align: bool,
+) -> Option<f32> {
+    let max = content - viewport;
+
+    if max <= 0.0 {
+        return None;
+    }
+
+    let translation = if align {
+        start
+    } else {
+        let end = start + size - viewport;
+
+        if current > start || end > start {
+            start
+        } else if current < end {
+            end
+        } else {
+            current
+        }
+    };
+
+    Some(translation.clamp(0.0, max))
+}
+
+struct Located {
+    scrollable: Option<Id>,
+    target: Option<(Vec<Ancestor>, Rectangle)>,
+}
+
+struct Locate {
+    target: Id,
+    scrollable: Option<Id>,
+    stack: Vec<usize>,
+    ancestors: Vec<Ancestor>,
+    visited: usize,
+    result: Option<(Vec<Ancestor>, Rectangle)>,
+}
+
+impl Locate {
+    fn new(target: Id, scrollable: Option<Id>) -> Self {
+        Self {
+            target,
+            scrollable,
+            stack: vec![0],
+            ancestors: Vec::new(),
+            visited: 0,
+            result: None,
+        }
+    }
+
+    fn check(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if self.result.is_none() && id.is_some_and(|id| *id == self.target) {
+            self.result = Some((self.ancestors.clone(), bounds));
+        }
+    }
+}
+
+impl Operation<Located> for Locate {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Located>)) {
+        if self.result.is_some() {
+            return;
+        }
+
+        self.stack.push(self.ancestors.len());
+        operate(self);
+        let _ = self.stack.pop();
+
+        self.ancestors.truncate(*self.stack.last().unwrap());
+    }
+
+    fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        self.check(id, bounds);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn Scrollable,
+    ) {
+        self.check(id, bounds);
+
+        self.ancestors.push(Ancestor {
+            index: self.visited,
+            id: id.cloned(),
+            bounds,
+            content_bounds,
+            translation,
+        });
+
+        self.visited += 1;
+    }
+
+    fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Focusable) {
+        self.check(id, bounds);
+    }
+
+    fn text_input(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn TextInput) {
+        self.check(id, bounds);
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+        self.check(id, bounds);
+    }
+
+    fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn Any) {
+        self.check(id, bounds);
+    }
+
+    fn finish(&self) -> Outcome<Located> {
+        Outcome::Some(Located {
+            scrollable: self.scrollable.clone(),
+            target: self.result.clone(),
+        })
+    }
+}
+
+fn resolve(located: Located) -> Apply {
+    let mut targets = Vec::new();
+
+    if let Some((ancestors, mut bounds)) = located.target {
+        for ancestor in ancestors.iter().rev() {
+            let translation = match &located.scrollable {
+                Some(id) if ancestor.id.as_ref() == Some(id) => {
+                    targets.push((ancestor.index, ancestor.reveal(bounds, true)));
+                    break;
+                }
+                Some(_) => ancestor.translation,
+                None => {
+                    let offset = ancestor.reveal(bounds, false);
+                    targets.push((ancestor.index, offset));
+
+                    Vector::new(
+                        offset.x.unwrap_or(ancestor.translation.x),
+                        offset.y.unwrap_or(ancestor.translation.y),
+                    )
+                }
+            };
+
+            bounds = (bounds - translation)
+                .intersection(&ancestor.bounds)
+                .unwrap_or(bounds - translation);
+        }
+    }
+
+    Apply {
+        targets,
+        visited: 0,
+    }
+}
+
+struct Apply {
+    targets: Vec<(usize, AbsoluteOffset<Option<f32>>)>,
+    visited: usize,
+}
+
+impl<T> Operation<T> for Apply {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        if self.targets.is_empty() {
+            return;
+        }
+
+        operate(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        state: &mut dyn Scrollable,
+    ) {
+        if let Some((_, translation)) = self
+            .targets
+            .iter()
+            .find(|(index, _)| *index == self.visited)
+        {
+            state.animate_to(*translation);
+        }
+
+        self.visited += 1;
+    }
+}
+
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct AbsoluteOffset<T = f32> {
@@ -149,3 +413,27 @@ impl From<RelativeOffset> for RelativeOffset<Option<f32>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::reveal;
+
+    #[test]
+    fn reveal_scrolls_only_as_much_as_needed() {
+        // Already visible
+        assert_eq!(reveal(120.0, 20.0, 100.0, 500.0, 100.0, false), Some(100.0));
+
+        // Below the viewport
+        assert_eq!(reveal(300.0, 20.0, 100.0, 500.0, 0.0, false), Some(220.0));
+
+        // Above the viewport
+        assert_eq!(reveal(50.0, 20.0, 100.0, 500.0, 200.0, false), Some(50.0));
+
+        // Aligned to the start, clamped to the content
+        assert_eq!(reveal(120.0, 20.0, 100.0, 500.0, 100.0, true), Some(120.0));
+        assert_eq!(reveal(480.0, 20.0, 100.0, 500.0, 0.0, true), Some(400.0));
+
+        // Nothing to scroll
+        assert_eq!(reveal(50.0, 20.0, 100.0, 80.0, 0.0, false), None);
+    }
+}
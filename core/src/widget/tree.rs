@@ -179,6 +179,59 @@ pub fn diff_children_custom_with_search<T>(
     }
 }
 
+/// Reconciles the `current_children` with the provided list of widgets by
+/// matching their keys, using custom logic both for diffing and creating new
+/// widget state.
+///
+/// The state of a child is reused as long as its key is still present,
+/// regardless of its position; so children can be reordered freely while
+/// keeping continuity.
+pub fn diff_children_keyed<K, T>(
+    current_children: &mut Vec<Tree>,
+    current_keys: &[K],
+    new_children: &[T],
+    new_keys: &[K],
+    diff: impl Fn(&mut Tree, &T),
+    new_state: impl Fn(&T) -> Tree,
+) where
+    K: PartialEq,
+{
+    if current_keys == new_keys && current_children.len() == new_children.len() {
+        for (child_state, new) in current_children.iter_mut().zip(new_children) {
+            diff(child_state, new);
+        }
+
+        return;
+    }
+
+    let mut previous: Vec<_> = std::mem::take(current_children)
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    for (i, (new, key)) in new_children.iter().zip(new_keys).enumerate() {
+        let index =
+            if current_keys.get(i) == Some(key) && previous.get(i).is_some_and(Option::is_some) {
+                Some(i)
+            } else {
+                current_keys
+                    .iter()
+                    .zip(&previous)
+                    .position(|(current, state)| current == key && state.is_some())
+            };
+
+        let child_state = match index.and_then(|index| previous[index].take()) {
+            Some(mut child_state) => {
+                diff(&mut child_state, new);
+                child_state
+            }
+            None => new_state(new),
+        };
+
+        current_children.push(child_state);
+    }
+}
+
 /// The identifier of some widget state.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct Tag(any::TypeId);
@@ -253,3 +306,40 @@ impl fmt::Debug for State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{State, Tree, diff_children_keyed};
+
+    fn value(tree: &Tree) -> u32 {
+        *tree.state.downcast_ref::<u32>()
+    }
+
+    #[test]
+    fn keyed_children_keep_their_state_when_reordered() {
+        let new_state = |&key: &u32| Tree {
+            state: State::new(key * 10),
+            ..Tree::empty()
+        };
+
+        let mut children: Vec<Tree> = [1, 2, 3].iter().map(new_state).collect();
+
+        if let State::Some(state) = &mut children[1].state {
+            *state.downcast_mut::<u32>().unwrap() = 99;
+        }
+
+        diff_children_keyed(
+            &mut children,
+            &[1, 2, 3],
+            &[4, 3, 2],
+            &[4, 3, 2],
+            |_, _| {},
+            new_state,
+        );
+
+        assert_eq!(
+            children.iter().map(value).collect::<Vec<_>>(),
+            vec![40, 30, 99]
+        );
+    }
+}
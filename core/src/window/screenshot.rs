@@ -3,6 +3,9 @@ use crate::{Bytes, Rectangle, Size};
 
 use std::fmt::{Debug, Formatter};
 
+#[cfg(feature = "png")]
+use std::sync::Arc;
+
 /// Data of a screenshot, captured with `window::screenshot()`.
 ///
 /// The `bytes` of this screenshot will always be ordered as `RGBA` in the `sRGB` color space.
@@ -77,6 +80,77 @@ impl Screenshot {
             scale_factor: self.scale_factor,
         })
     }
+
+    /// Crops a [`Screenshot`] to the provided `region` in logical pixels, clipping it
+    /// to the bounds of the [`Screenshot`].
+    ///
+    /// This is useful to crop a [`Screenshot`] to the bounds of some widget.
+    pub fn crop_logical(&self, region: Rectangle) -> Result<Self, CropError> {
+        let bounds =
+            Rectangle::with_size(Size::new(self.size.width as f32, self.size.height as f32));
+
+        let region = (region * self.scale_factor)
+            .intersection(&bounds)
+            .and_then(Rectangle::snap)
+            .ok_or(CropError::Zero)?;
+
+        self.crop(region)
+    }
+
+    /// Returns the pixels of the [`Screenshot`] in the given [`PixelFormat`].
+    pub fn pixels(&self, format: PixelFormat) -> Bytes {
+        match format {
+            PixelFormat::Rgba => self.rgba.clone(),
+            PixelFormat::Bgra => Bytes::from(
+                self.rgba
+                    .chunks_exact(4)
+                    .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+                    .collect::<Vec<_>>(),
+            ),
+            PixelFormat::Rgb => Bytes::from(
+                self.rgba
+                    .chunks_exact(4)
+                    .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// Encodes the [`Screenshot`] as a PNG image.
+    #[cfg(feature = "png")]
+    pub fn encode_png(&self) -> Result<Bytes, EncodeError> {
+        let mut bytes = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut bytes, self.size.width, self.size.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .map_err(|error| EncodeError(Arc::new(error)))?;
+
+        writer
+            .write_image_data(&self.rgba)
+            .map_err(|error| EncodeError(Arc::new(error)))?;
+
+        writer
+            .finish()
+            .map_err(|error| EncodeError(Arc::new(error)))?;
+
+        Ok(Bytes::from(bytes))
+    }
+}
+
+/// The layout of the pixels of a [`Screenshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PixelFormat {
+    /// Red, green, blue, and alpha; each channel being a byte.
+    #[default]
+    Rgba,
+    /// Blue, green, red, and alpha; each channel being a byte.
+    Bgra,
+    /// Red, green, and blue; each channel being a byte. The alpha is discarded.
+    Rgb,
 }
 
 impl AsRef<[u8]> for Screenshot {
@@ -101,3 +175,9 @@ pub enum CropError {
     /// The cropped region's size is zero.
     Zero,
 }
+
+/// An error that occurred while encoding a [`Screenshot`].
+#[cfg(feature = "png")]
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("The screenshot could not be encoded: {0}")]
+pub struct EncodeError(Arc<dyn std::error::Error + Send + Sync>);
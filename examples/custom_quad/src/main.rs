@@ -25,6 +25,7 @@ enum Message {
     ShadowXOffsetChanged(f32),
     ShadowYOffsetChanged(f32),
     ShadowBlurRadiusChanged(f32),
+    ShadowSpreadChanged(f32),
     SnapToggled(bool),
 }
 
@@ -37,6 +38,7 @@ impl Example {
                 color: Color::from_rgba(0.0, 0.0, 0.0, 0.8),
                 offset: Vector::new(0.0, 8.0),
                 blur_radius: 16.0,
+                spread: 0.0,
             },
             snap: false,
         }
@@ -68,6 +70,9 @@ impl Example {
             Message::ShadowBlurRadiusChanged(s) => {
                 self.shadow.blur_radius = s;
             }
+            Message::ShadowSpreadChanged(s) => {
+                self.shadow.spread = s;
+            }
             Message::SnapToggled(snap) => {
                 self.snap = snap;
             }
@@ -85,6 +90,7 @@ impl Example {
         let Shadow {
             offset: Vector { x: sx, y: sy },
             blur_radius: sr,
+            spread: ss,
             ..
         } = self.shadow;
 
@@ -102,10 +108,11 @@ impl Example {
             slider(1.0..=200.0, bottom_right, Message::RadiusBottomRightChanged).step(0.01),
             slider(1.0..=200.0, bottom_left, Message::RadiusBottomLeftChanged).step(0.01),
             slider(0.0..=10.0, self.border_width, Message::BorderWidthChanged).step(0.01),
-            text!("Shadow: {sx:.2}x{sy:.2}, {sr:.2}"),
+            text!("Shadow: {sx:.2}x{sy:.2}, {sr:.2}, {ss:.2}"),
             slider(-100.0..=100.0, sx, Message::ShadowXOffsetChanged).step(0.01),
             slider(-100.0..=100.0, sy, Message::ShadowYOffsetChanged).step(0.01),
             slider(0.0..=100.0, sr, Message::ShadowBlurRadiusChanged).step(0.01),
+            slider(-50.0..=50.0, ss, Message::ShadowSpreadChanged).step(0.01),
             toggler(self.snap)
                 .label("Snap to pixel grid")
                 .on_toggle(Message::SnapToggled),
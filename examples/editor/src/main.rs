@@ -201,6 +201,7 @@ impl Editor {
                 .id(EDITOR)
                 .height(Fill)
                 .on_action(Message::ActionPerformed)
+                .line_numbers(true)
                 .wrapping(if self.word_wrap {
                     text::Wrapping::Word
                 } else {
@@ -2,10 +2,9 @@ use iced::event::{self, Event};
 use iced::keyboard;
 use iced::keyboard::key;
 use iced::widget::{
-    button, center, column, container, mouse_area, opaque, operation, pick_list, row, space, stack,
-    text, text_input,
+    button, center, column, container, modal, operation, pick_list, row, space, text, text_input,
 };
-use iced::{Bottom, Color, Element, Fill, Subscription, Task};
+use iced::{Bottom, Element, Fill, Subscription, Task};
 
 use std::fmt;
 
@@ -43,7 +42,7 @@ impl App {
         match message {
             Message::ShowModal => {
                 self.show_modal = true;
-                operation::focus_next()
+                Task::none()
             }
             Message::HideModal => {
                 self.hide_modal();
@@ -80,13 +79,6 @@ impl App {
                         operation::focus_next()
                     }
                 }
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key: keyboard::Key::Named(key::Named::Escape),
-                    ..
-                }) => {
-                    self.hide_modal();
-                    Task::none()
-                }
                 _ => Task::none(),
             },
         }
@@ -109,47 +101,47 @@ impl App {
         )
         .padding(10);
 
-        if self.show_modal {
-            let signup = container(
+        let signup = container(
+            column![
+                text("Sign Up").size(24),
                 column![
-                    text("Sign Up").size(24),
                     column![
-                        column![
-                            text("Email").size(12),
-                            text_input("abc@123.com", &self.email,)
-                                .on_input(Message::Email)
-                                .on_submit(Message::Submit)
-                                .padding(5),
-                        ]
-                        .spacing(5),
-                        column![
-                            text("Password").size(12),
-                            text_input("", &self.password)
-                                .on_input(Message::Password)
-                                .on_submit(Message::Submit)
-                                .secure(true)
-                                .padding(5),
-                        ]
-                        .spacing(5),
-                        column![
-                            text("Plan").size(12),
-                            pick_list(Plan::ALL, Some(self.plan), Message::Plan).padding(5),
-                        ]
-                        .spacing(5),
-                        button(text("Submit")).on_press(Message::HideModal),
+                        text("Email").size(12),
+                        text_input("abc@123.com", &self.email,)
+                            .on_input(Message::Email)
+                            .on_submit(Message::Submit)
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Password").size(12),
+                        text_input("", &self.password)
+                            .on_input(Message::Password)
+                            .on_submit(Message::Submit)
+                            .secure(true)
+                            .padding(5),
                     ]
-                    .spacing(10)
+                    .spacing(5),
+                    column![
+                        text("Plan").size(12),
+                        pick_list(Plan::ALL, Some(self.plan), Message::Plan).padding(5),
+                    ]
+                    .spacing(5),
+                    button(text("Submit")).on_press(Message::HideModal),
                 ]
-                .spacing(20),
-            )
-            .width(300)
-            .padding(10)
-            .style(container::rounded_box);
+                .spacing(10)
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box);
 
-            modal(content, signup, Message::HideModal)
-        } else {
-            content.into()
-        }
+        modal(content, signup)
+            .show(self.show_modal)
+            .on_escape(Message::HideModal)
+            .on_backdrop_press(Message::HideModal)
+            .into()
     }
 }
 
@@ -183,32 +175,3 @@ impl fmt::Display for Plan {
         .fmt(f)
     }
 }
-
-fn modal<'a, Message>(
-    base: impl Into<Element<'a, Message>>,
-    content: impl Into<Element<'a, Message>>,
-    on_blur: Message,
-) -> Element<'a, Message>
-where
-    Message: Clone + 'a,
-{
-    stack![
-        base.into(),
-        opaque(
-            mouse_area(center(opaque(content)).style(|_theme| {
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: 0.8,
-                            ..Color::BLACK
-                        }
-                        .into(),
-                    ),
-                    ..container::Style::default()
-                }
-            }))
-            .on_press(on_blur)
-        )
-    ]
-    .into()
-}
@@ -0,0 +1,183 @@
+use iced::event::{self, Event};
+use iced::keyboard;
+use iced::keyboard::key;
+use iced::time::Duration;
+use iced::widget::toast::{self, Severity, Toast};
+use iced::widget::{
+    button, center, checkbox, column, operation, pick_list, row, slider, text, text_input,
+};
+use iced::{Center, Element, Fill, Subscription, Task};
+
+pub fn main() -> iced::Result {
+    iced::application(App::default, App::update, App::view)
+        .subscription(App::subscription)
+        .run()
+}
+
+struct App {
+    toasts: Vec<Toast<Message>>,
+    title: String,
+    body: String,
+    severity: Severity,
+    is_sticky: bool,
+    timeout_secs: u64,
+    undone: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Add,
+    Dismiss(usize),
+    Undo,
+    Title(String),
+    Body(String),
+    Severity(Severity),
+    Sticky(bool),
+    Timeout(f64),
+    Event(Event),
+}
+
+impl App {
+    fn new() -> Self {
+        App {
+            toasts: vec![
+                Toast::new("Add more toasts in the form below!")
+                    .title("Example Toast")
+                    .sticky(),
+            ],
+            title: String::new(),
+            body: String::new(),
+            severity: Severity::default(),
+            is_sticky: false,
+            timeout_secs: 5,
+            undone: 0,
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        event::listen().map(Message::Event)
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Add => {
+                if !self.body.is_empty() {
+                    let toast = Toast::new(std::mem::take(&mut self.body))
+                        .severity(self.severity)
+                        .action("Undo", Message::Undo);
+
+                    let toast = if self.title.is_empty() {
+                        toast
+                    } else {
+                        toast.title(std::mem::take(&mut self.title))
+                    };
+
+                    self.toasts.push(if self.is_sticky {
+                        toast.sticky()
+                    } else {
+                        toast
+                    });
+                }
+                Task::none()
+            }
+            Message::Dismiss(index) => {
+                self.toasts.remove(index);
+                Task::none()
+            }
+            Message::Undo => {
+                self.undone += 1;
+                Task::none()
+            }
+            Message::Title(title) => {
+                self.title = title;
+                Task::none()
+            }
+            Message::Body(body) => {
+                self.body = body;
+                Task::none()
+            }
+            Message::Severity(severity) => {
+                self.severity = severity;
+                Task::none()
+            }
+            Message::Sticky(is_sticky) => {
+                self.is_sticky = is_sticky;
+                Task::none()
+            }
+            Message::Timeout(timeout) => {
+                self.timeout_secs = timeout as u64;
+                Task::none()
+            }
+            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            })) if modifiers.shift() => operation::focus_previous(),
+            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                ..
+            })) => operation::focus_next(),
+            Message::Event(_) => Task::none(),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let subtitle = |title, content: Element<'static, Message>| {
+            column![text(title).size(14), content].spacing(5)
+        };
+
+        let add_toast =
+            button("Add Toast").on_press_maybe((!self.body.is_empty()).then_some(Message::Add));
+
+        let content = center(
+            column![
+                subtitle(
+                    "Title",
+                    text_input("", &self.title)
+                        .on_input(Message::Title)
+                        .on_submit(Message::Add)
+                        .into()
+                ),
+                subtitle(
+                    "Message",
+                    text_input("", &self.body)
+                        .on_input(Message::Body)
+                        .on_submit(Message::Add)
+                        .into()
+                ),
+                subtitle(
+                    "Severity",
+                    pick_list(Severity::ALL, Some(self.severity), Message::Severity)
+                        .width(Fill)
+                        .into()
+                ),
+                subtitle(
+                    "Timeout",
+                    row![
+                        text!("{:0>2} sec", self.timeout_secs),
+                        slider(1.0..=30.0, self.timeout_secs as f64, Message::Timeout).step(1.0)
+                    ]
+                    .spacing(5)
+                    .into()
+                ),
+                checkbox(self.is_sticky)
+                    .label("Sticky")
+                    .on_toggle(Message::Sticky),
+                column![add_toast].align_x(Center),
+                text!("Undone {} times", self.undone).size(14),
+            ]
+            .spacing(10)
+            .max_width(200),
+        );
+
+        toast::Manager::new(content, &self.toasts, Message::Dismiss)
+            .timeout(Duration::from_secs(self.timeout_secs))
+            .into()
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,19 +2,21 @@
 pub mod fill;
 pub mod frame;
 pub mod path;
+pub mod pattern;
 pub mod stroke;
+pub mod text;
 
 mod cache;
 mod style;
-mod text;
 
-pub use cache::Cache;
+pub use cache::{Cache, Layers};
 pub use fill::Fill;
 pub use frame::Frame;
 pub use path::Path;
+pub use pattern::Pattern;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
-pub use text::Text;
+pub use text::{RichText, Text};
 
 pub use crate::core::{Image, Svg};
 pub use crate::gradient::{self, Gradient};
@@ -1,7 +1,9 @@
 use crate::cache::{self, Cached};
-use crate::core::{Rectangle, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::geometry::{self, Frame};
 
+use std::cell::RefCell;
+
 pub use cache::Group;
 
 /// A simple cache that stores generated geometry to avoid recomputation.
@@ -126,3 +128,217 @@ where
         Self::new()
     }
 }
+
+/// A stack of named [`Cache`] layers that can be invalidated independently.
+///
+/// Each layer can be split in square tiles, so that only the tiles
+/// intersecting a dirty region are redrawn when the layer is
+/// [`invalidate`](Self::invalidate)d.
+///
+/// This is useful to keep a static background cached while a dynamic
+/// foreground changes on every interaction, for instance.
+pub struct Layers<Renderer, Key = &'static str>
+where
+    Renderer: geometry::Renderer,
+{
+    tile_size: Option<f32>,
+    layers: RefCell<Vec<(Key, Layer<Renderer>)>>,
+}
+
+struct Layer<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    group: Group,
+    bounds: Rectangle,
+    tiles: Vec<(Rectangle, Cache<Renderer>)>,
+}
+
+impl<Renderer, Key> Layers<Renderer, Key>
+where
+    Renderer: geometry::Renderer,
+    Key: PartialEq,
+{
+    /// Creates a new empty set of [`Layers`], where each layer is cached as
+    /// a whole.
+    pub fn new() -> Self {
+        Self {
+            tile_size: None,
+            layers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Creates a new empty set of [`Layers`], where each layer is split in
+    /// tiles of the given size.
+    ///
+    /// Smaller tiles redraw less geometry when a region is invalidated, but
+    /// more draw calls are needed to draw them.
+    pub fn with_tile_size(tile_size: f32) -> Self {
+        Self {
+            tile_size: Some(tile_size.max(1.0)),
+            layers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Clears the layer with the given key, forcing a redraw of all of
+    /// it the next time it is used.
+    pub fn clear(&self, key: &Key) {
+        self.invalidate_with(key, |_| true);
+    }
+
+    /// Clears the tiles of the layer with the given key that intersect the
+    /// given region, forcing a redraw of only those the next time the layer
+    /// is used.
+    pub fn invalidate(&self, key: &Key, region: Rectangle) {
+        self.invalidate_with(key, |tile| tile.intersects(&region));
+    }
+
+    /// Clears every layer.
+    pub fn clear_all(&self) {
+        for (_, layer) in self.layers.borrow().iter() {
+            for (_, cache) in &layer.tiles {
+                cache.clear();
+            }
+        }
+    }
+
+    /// Draws the layer with the given key using the provided closure,
+    /// storing its geometry in the [`Layers`].
+    ///
+    /// The closure is called once for every tile of the layer that needs to
+    /// be redrawn, with a [`Frame`] clipped to the bounds of the tile. The
+    /// bounds are also given to the closure, so geometry outside of them
+    /// can be skipped.
+    ///
+    /// Layers are created the first time they are drawn and the geometry of
+    /// every tile is returned, even if it was not redrawn.
+    pub fn draw(
+        &self,
+        renderer: &Renderer,
+        size: Size,
+        key: Key,
+        draw_fn: impl Fn(&mut Frame<Renderer>, Rectangle),
+    ) -> Vec<Renderer::Geometry> {
+        let bounds = Rectangle::with_size(size);
+        let mut layers = self.layers.borrow_mut();
+
+        let index = match layers.iter().position(|(other, _)| *other == key) {
+            Some(index) => index,
+            None => {
+                layers.push((
+                    key,
+                    Layer {
+                        group: Group::unique(),
+                        bounds: Rectangle::default(),
+                        tiles: Vec::new(),
+                    },
+                ));
+
+                layers.len() - 1
+            }
+        };
+
+        let layer = &mut layers[index].1;
+
+        if layer.bounds != bounds {
+            let group = layer.group;
+
+            layer.bounds = bounds;
+            layer.tiles = tiles(bounds, self.tile_size)
+                .into_iter()
+                .map(|tile| (tile, Cache::with_group(group)))
+                .collect();
+        }
+
+        layer
+            .tiles
+            .iter()
+            .map(|(tile, cache)| {
+                cache.draw_with_bounds(renderer, *tile, |frame| draw_fn(frame, *tile))
+            })
+            .collect()
+    }
+
+    fn invalidate_with(&self, key: &Key, f: impl Fn(&Rectangle) -> bool) {
+        let layers = self.layers.borrow();
+
+        let Some((_, layer)) = layers.iter().find(|(other, _)| other == key) else {
+            return;
+        };
+
+        for (tile, cache) in &layer.tiles {
+            if f(tile) {
+                cache.clear();
+            }
+        }
+    }
+}
+
+impl<Renderer, Key> Default for Layers<Renderer, Key>
+where
+    Renderer: geometry::Renderer,
+    Key: PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Renderer, Key> std::fmt::Debug for Layers<Renderer, Key>
+where
+    Renderer: geometry::Renderer,
+    Key: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.layers
+                    .borrow()
+                    .iter()
+                    .map(|(key, layer)| (key, layer.tiles.len())),
+            )
+            .finish()
+    }
+}
+
+/// Splits the given bounds in tiles of the given size, row by row.
+fn tiles(bounds: Rectangle, tile_size: Option<f32>) -> Vec<Rectangle> {
+    let Some(tile_size) = tile_size else {
+        return vec![bounds];
+    };
+
+    let columns = (bounds.width / tile_size).ceil().max(1.0) as usize;
+    let rows = (bounds.height / tile_size).ceil().max(1.0) as usize;
+
+    (0..rows)
+        .flat_map(|row| (0..columns).map(move |column| (row, column)))
+        .map(|(row, column)| {
+            let x = bounds.x + column as f32 * tile_size;
+            let y = bounds.y + row as f32 * tile_size;
+
+            Rectangle::new(
+                Point::new(x, y),
+                Size::new(
+                    tile_size.min(bounds.x + bounds.width - x),
+                    tile_size.min(bounds.y + bounds.height - y),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiles_are_clipped_to_the_bounds() {
+        let tiles = tiles(Rectangle::with_size(Size::new(250.0, 100.0)), Some(100.0));
+
+        assert_eq!(tiles.len(), 3);
+        assert_eq!(
+            tiles[2],
+            Rectangle::new(Point::new(200.0, 0.0), Size::new(50.0, 100.0))
+        );
+    }
+}
@@ -1,6 +1,10 @@
 //! Draw and generate geometry.
+use crate::core::window::Screenshot;
 use crate::core::{Point, Radians, Rectangle, Size, Vector};
-use crate::geometry::{self, Fill, Image, Path, Stroke, Svg, Text};
+use crate::geometry::{self, Fill, Image, Path, RichText, Stroke, Svg, Text, text};
+
+#[cfg(feature = "image")]
+use crate::geometry::Pattern;
 
 /// The region of a surface that can be used to draw geometry.
 pub struct Frame<Renderer>
@@ -88,12 +92,67 @@ where
         self.raw.fill_text(text);
     }
 
+    /// Draws the spans of the given [`RichText`] on the [`Frame`], filling
+    /// them with their own colors.
+    pub fn fill_rich_text(&mut self, text: impl Into<RichText>) {
+        text.into()
+            .draw_with(|glyph, color| self.raw.fill(&glyph, color));
+    }
+
+    /// Draws the characters of the given [`Text`] along the given [`Path`]
+    /// on the [`Frame`], filling them with the given color.
+    ///
+    /// See [`Text::draw_along`] for the details on how the [`Text`] is
+    /// placed.
+    pub fn fill_text_along(&mut self, text: impl Into<Text>, path: &Path) {
+        text.into()
+            .draw_along(path, |glyph, color| self.raw.fill(&glyph, color));
+    }
+
+    /// Measures the given [`Text`] as it would be drawn on the [`Frame`];
+    /// returning its size, line breaks, and glyph positions.
+    pub fn measure_text(&self, text: impl Into<Text>) -> text::Measurement {
+        text.into().measure()
+    }
+
     /// Draws the given [`Image`] on the [`Frame`] inside the given bounds.
+    ///
+    /// The current transform of the [`Frame`] is applied to the [`Image`].
+    /// Images can only be translated, rotated, and scaled; any skew is
+    /// ignored.
+    ///
+    /// Use [`Image::region`] to draw only part of the [`Image`], and
+    /// [`Image::opacity`] to make it translucent.
     #[cfg(feature = "image")]
     pub fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
         self.raw.draw_image(bounds, image);
     }
 
+    /// Fills the bounds of the given [`Path`] with the tiles of a [`Pattern`].
+    ///
+    /// The tiles on the edges are cropped to the bounds of the [`Path`].
+    /// Currently, the tiles are not masked by the [`Path`] itself; so the
+    /// whole bounds of non-rectangular paths will be covered.
+    #[cfg(feature = "image")]
+    pub fn fill_pattern(&mut self, path: &Path, pattern: impl Into<Pattern>) {
+        let pattern = pattern.into();
+
+        for (bounds, region) in pattern.tiles(path.bounds()) {
+            let region = match pattern.image.region {
+                Some(crop) => Rectangle {
+                    x: crop.x + region.x * crop.width,
+                    y: crop.y + region.y * crop.height,
+                    width: region.width * crop.width,
+                    height: region.height * crop.height,
+                },
+                None => region,
+            };
+
+            self.raw
+                .draw_image(bounds, pattern.image.clone().region(region));
+        }
+    }
+
     /// Draws the given [`Svg`] on the [`Frame`] inside the given bounds.
     #[cfg(feature = "svg")]
     pub fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
@@ -178,12 +237,67 @@ where
         self.raw.scale_nonuniform(scale);
     }
 
+    /// Applies a skew with the given angles along the x and y axes to the
+    /// current transform of the [`Frame`].
+    ///
+    /// Combined with translations, rotations, and scalings, any affine
+    /// transform can be obtained.
+    pub fn skew(&mut self, x: impl Into<Radians>, y: impl Into<Radians>) {
+        self.raw.skew(x, y);
+    }
+
     /// Turns the [`Frame`] into its underlying geometry.
     pub fn into_geometry(self) -> Renderer::Geometry {
         self.raw.into_geometry()
     }
 }
 
+impl<Renderer> Frame<Renderer>
+where
+    Renderer: geometry::Renderer,
+    Renderer::Frame: Export,
+{
+    /// Creates a new [`Frame`] with the given dimensions that can be
+    /// exported to an SVG document or an image; without needing a renderer.
+    ///
+    /// Text is drawn as outlines in an exportable [`Frame`], so that it can be
+    /// exported as well.
+    pub fn exportable(size: Size) -> Self {
+        Self {
+            raw: Renderer::Frame::exportable(Rectangle::with_size(size)),
+        }
+    }
+
+    /// Exports the contents of the [`Frame`] as an SVG document.
+    ///
+    /// The [`Frame`] should be created with [`exportable`](Self::exportable);
+    /// otherwise, text and gradients may be missing. Images are not exported.
+    pub fn into_svg(self) -> String {
+        self.raw.into_svg()
+    }
+
+    /// Rasterizes the contents of the [`Frame`] into an image of the given
+    /// [`Size`] in pixels.
+    ///
+    /// The contents are scaled uniformly to fit the image, which can have any
+    /// resolution.
+    pub fn into_pixels(self, size: Size<u32>) -> Screenshot {
+        self.raw.into_pixels(size)
+    }
+
+    /// Rasterizes the contents of the [`Frame`] into a PNG image of the given
+    /// [`Size`] in pixels.
+    ///
+    /// See [`into_pixels`](Self::into_pixels) for the details.
+    #[cfg(feature = "png")]
+    pub fn into_png(
+        self,
+        size: Size<u32>,
+    ) -> Result<crate::core::Bytes, crate::core::window::screenshot::EncodeError> {
+        self.into_pixels(size).encode_png()
+    }
+}
+
 /// The internal implementation of a [`Frame`].
 ///
 /// Analogous to [`Frame`]. See [`Frame`] for the documentation
@@ -204,6 +318,7 @@ pub trait Backend: Sized {
     fn rotate(&mut self, angle: impl Into<Radians>);
     fn scale(&mut self, scale: impl Into<f32>);
     fn scale_nonuniform(&mut self, scale: impl Into<Vector>);
+    fn skew(&mut self, x: impl Into<Radians>, y: impl Into<Radians>);
 
     fn draft(&mut self, clip_bounds: Rectangle) -> Self;
     fn paste(&mut self, frame: Self);
@@ -222,6 +337,18 @@ pub trait Backend: Sized {
     fn into_geometry(self) -> Self::Geometry;
 }
 
+/// A [`Backend`] that can export its contents.
+///
+/// Analogous to [`Frame`]. See [`Frame`] for the documentation
+/// of each method.
+#[allow(missing_docs)]
+pub trait Export: Backend {
+    fn exportable(bounds: Rectangle) -> Self;
+
+    fn into_svg(self) -> String;
+    fn into_pixels(self, size: Size<u32>) -> Screenshot;
+}
+
 #[cfg(debug_assertions)]
 impl Backend for () {
     type Geometry = ();
@@ -249,6 +376,7 @@ impl Backend for () {
     fn rotate(&mut self, _angle: impl Into<Radians>) {}
     fn scale(&mut self, _scale: impl Into<f32>) {}
     fn scale_nonuniform(&mut self, _scale: impl Into<Vector>) {}
+    fn skew(&mut self, _x: impl Into<Radians>, _y: impl Into<Radians>) {}
 
     fn draft(&mut self, _clip_bounds: Rectangle) -> Self {}
     fn paste(&mut self, _frame: Self) {}
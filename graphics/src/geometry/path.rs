@@ -1,16 +1,18 @@
 //! Build different kinds of 2D shapes.
 pub mod arc;
 
+mod boolean;
 mod builder;
 
 #[doc(no_inline)]
 pub use arc::Arc;
+pub use boolean::Operation;
 pub use builder::Builder;
 
 pub use lyon_path;
 
 use crate::core::border;
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -69,6 +71,30 @@ impl Path {
         &self.raw
     }
 
+    /// Returns the smallest [`Rectangle`] containing the [`Path`].
+    pub fn bounds(&self) -> Rectangle {
+        use lyon_path::iterator::PathIterator;
+
+        let points =
+            self.raw
+                .iter()
+                .flattened(boolean::TOLERANCE)
+                .filter_map(|event| match event {
+                    lyon_path::Event::Begin { at } => Some(at),
+                    lyon_path::Event::Line { to, .. } => Some(to),
+                    _ => None,
+                });
+
+        let bounds = lyon_path::math::Box2D::from_points(points);
+
+        Rectangle {
+            x: bounds.min.x,
+            y: bounds.min.y,
+            width: bounds.width(),
+            height: bounds.height(),
+        }
+    }
+
     /// Returns the current [`Path`] with the given transform applied to it.
     #[inline]
     pub fn transform(&self, transform: &lyon_path::math::Transform) -> Path {
@@ -76,4 +102,59 @@ impl Path {
             raw: self.raw.clone().transformed(transform),
         }
     }
+
+    /// Returns a new [`Path`] covering the area covered by either this
+    /// [`Path`] or the `other` one.
+    pub fn union(&self, other: &Path) -> Path {
+        self.combine(other, Operation::Union)
+    }
+
+    /// Returns a new [`Path`] covering the area covered by both this
+    /// [`Path`] and the `other` one.
+    pub fn intersection(&self, other: &Path) -> Path {
+        self.combine(other, Operation::Intersection)
+    }
+
+    /// Returns a new [`Path`] covering the area covered by this [`Path`],
+    /// but not by the `other` one.
+    pub fn difference(&self, other: &Path) -> Path {
+        self.combine(other, Operation::Difference)
+    }
+
+    /// Returns a new [`Path`] covering the area covered by exactly one of
+    /// this [`Path`] and the `other` one.
+    pub fn xor(&self, other: &Path) -> Path {
+        self.combine(other, Operation::Xor)
+    }
+
+    /// Returns a new [`Path`] covering the area resulting from applying the
+    /// given [`Operation`] to the areas of this [`Path`] and the `other`
+    /// one.
+    ///
+    /// Every subpath is considered closed and the areas follow the nonzero
+    /// fill rule. Curves are flattened, so the new [`Path`] is only made of
+    /// line segments.
+    pub fn combine(&self, other: &Path, operation: Operation) -> Path {
+        boolean::build(&boolean::combine(
+            &boolean::rings(self),
+            &boolean::rings(other),
+            operation,
+        ))
+    }
+
+    /// Returns a new [`Path`] covering the area of this [`Path`] grown by
+    /// the given `distance` in every direction, with rounded corners.
+    ///
+    /// A negative `distance` shrinks the area instead.
+    pub fn offset(&self, distance: f32) -> Path {
+        boolean::build(&boolean::offset(self, distance))
+    }
+
+    /// Returns a new [`Path`] covering the area that stroking this [`Path`]
+    /// with the given `width` would paint, with round joins and caps.
+    ///
+    /// The new [`Path`] can be filled, combined, or offset like any other.
+    pub fn stroke_to_fill(&self, width: f32) -> Path {
+        boolean::build(&boolean::stroke(self, width / 2.0))
+    }
 }
@@ -0,0 +1,448 @@
+//! Combine the areas covered by paths.
+use crate::core::{Point, Vector};
+use crate::geometry::Path;
+
+use std::collections::HashMap;
+
+/// A boolean operation between the areas covered by two [`Path`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// The area covered by either [`Path`].
+    Union,
+    /// The area covered by both [`Path`]s.
+    Intersection,
+    /// The area covered by the first [`Path`], but not the second one.
+    Difference,
+    /// The area covered by exactly one of the [`Path`]s.
+    Xor,
+}
+
+impl Operation {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Union => a || b,
+            Self::Intersection => a && b,
+            Self::Difference => a && !b,
+            Self::Xor => a != b,
+        }
+    }
+}
+
+/// The tolerance used to flatten curves into line segments.
+pub(super) const TOLERANCE: f32 = 0.1;
+
+/// The grid points are snapped to, so intersections shared by different
+/// edges end up at the exact same coordinates.
+const GRID: f32 = 4096.0;
+
+/// A closed polygon.
+pub(super) type Ring = Vec<Point>;
+
+/// A flattened subpath, together with whether it is closed.
+struct Polyline {
+    points: Vec<Point>,
+    is_closed: bool,
+}
+
+fn polylines(path: &Path) -> Vec<Polyline> {
+    use lyon_path::iterator::PathIterator;
+
+    let mut polylines = Vec::new();
+    let mut points = Vec::new();
+
+    for event in path.raw().iter().flattened(TOLERANCE) {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                points = vec![snap(Point::new(at.x, at.y))];
+            }
+            lyon_path::Event::Line { to, .. } => {
+                let to = snap(Point::new(to.x, to.y));
+
+                if points.last() != Some(&to) {
+                    points.push(to);
+                }
+            }
+            lyon_path::Event::End { close, .. } => {
+                if points.len() > 1 && points.first() == points.last() {
+                    let _ = points.pop();
+                }
+
+                polylines.push(Polyline {
+                    points: std::mem::take(&mut points),
+                    is_closed: close,
+                });
+            }
+            lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {}
+        }
+    }
+
+    polylines
+}
+
+/// Flattens the subpaths of a [`Path`] into [`Ring`]s, closing them.
+pub(super) fn rings(path: &Path) -> Vec<Ring> {
+    polylines(path)
+        .into_iter()
+        .map(|polyline| polyline.points)
+        .filter(|points| points.len() > 2)
+        .collect()
+}
+
+/// Builds a [`Path`] out of some [`Ring`]s.
+pub(super) fn build(rings: &[Ring]) -> Path {
+    Path::new(|builder| {
+        for ring in rings {
+            let mut points = ring.iter();
+
+            if let Some(first) = points.next() {
+                builder.move_to(*first);
+
+                for point in points {
+                    builder.line_to(*point);
+                }
+
+                builder.close();
+            }
+        }
+    })
+}
+
+/// Computes the [`Ring`]s bounding the area resulting from the given
+/// [`Operation`], using the nonzero fill rule for both sides.
+pub(super) fn combine(a: &[Ring], b: &[Ring], operation: Operation) -> Vec<Ring> {
+    let edges: Vec<(Point, Point)> = a
+        .iter()
+        .chain(b)
+        .flat_map(|ring| {
+            ring.iter()
+                .zip(ring.iter().cycle().skip(1))
+                .map(|(from, to)| (*from, *to))
+        })
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    let mut splits: Vec<Vec<(f32, Point)>> = vec![Vec::new(); edges.len()];
+
+    for (i, first) in edges.iter().enumerate() {
+        for (j, second) in edges.iter().enumerate().skip(i + 1) {
+            for (edge, t, point) in intersections(*first, *second) {
+                splits[if edge == 0 { i } else { j }].push((t, point));
+            }
+        }
+    }
+
+    let mut segments: HashMap<(Key, Key), (Point, Point)> = HashMap::new();
+
+    for ((from, to), mut points) in edges.into_iter().zip(splits) {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut start = from;
+
+        for end in points.into_iter().map(|(_, point)| point).chain([to]) {
+            if start != end {
+                let key = if key(start) < key(end) {
+                    (key(start), key(end))
+                } else {
+                    (key(end), key(start))
+                };
+
+                let _ = segments.entry(key).or_insert((start, end));
+            }
+
+            start = end;
+        }
+    }
+
+    let mut boundary: Vec<(Point, Point)> = Vec::new();
+
+    for (from, to) in segments.into_values() {
+        let direction = to - from;
+        let length = from.distance(to);
+        let normal = Vector::new(-direction.y, direction.x) * (1.0 / length);
+        let epsilon = (length * 0.25).min(0.01);
+
+        let middle = Point::new((from.x + to.x) / 2.0, (from.y + to.y) / 2.0);
+        let left = middle + normal * epsilon;
+        let right = middle - normal * epsilon;
+
+        let is_left_inside = operation.apply(is_inside(a, left), is_inside(b, left));
+        let is_right_inside = operation.apply(is_inside(a, right), is_inside(b, right));
+
+        match (is_left_inside, is_right_inside) {
+            (true, false) => boundary.push((from, to)),
+            (false, true) => boundary.push((to, from)),
+            _ => {}
+        }
+    }
+
+    chain(&boundary)
+}
+
+/// Computes the outline of the area within `distance` of the lines of
+/// the given [`Path`], with round joins and caps.
+pub(super) fn stroke(path: &Path, distance: f32) -> Vec<Ring> {
+    let pieces: Vec<Ring> = polylines(path)
+        .iter()
+        .flat_map(|polyline| {
+            let points = &polyline.points;
+
+            let closing = polyline
+                .is_closed
+                .then(|| points.last().zip(points.first()))
+                .flatten();
+
+            points
+                .windows(2)
+                .map(|window| (window[0], window[1]))
+                .chain(closing.map(|(from, to)| (*from, *to)))
+                .map(move |(from, to)| band(from, to, distance))
+                .chain(points.iter().map(move |point| disc(*point, distance)))
+        })
+        .collect();
+
+    combine(&pieces, &[], Operation::Union)
+}
+
+/// Grows—or shrinks, given a negative `distance`—the area covered by the
+/// given [`Path`].
+pub(super) fn offset(path: &Path, distance: f32) -> Vec<Ring> {
+    let rings = rings(path);
+
+    if distance == 0.0 {
+        return combine(&rings, &[], Operation::Union);
+    }
+
+    let margin = stroke(&build(&rings), distance.abs());
+
+    if distance > 0.0 {
+        combine(&rings, &margin, Operation::Union)
+    } else {
+        combine(&rings, &margin, Operation::Difference)
+    }
+}
+
+type Key = (u32, u32);
+
+fn key(point: Point) -> Key {
+    (point.x.to_bits(), point.y.to_bits())
+}
+
+fn snap(point: Point) -> Point {
+    Point::new(
+        (point.x * GRID).round() / GRID,
+        (point.y * GRID).round() / GRID,
+    )
+}
+
+/// Returns the points where the interior of an edge is crossed or touched
+/// by the other one, together with the index of the edge to split and the
+/// position of the point along it.
+fn intersections(a: (Point, Point), b: (Point, Point)) -> Vec<(usize, f32, Point)> {
+    const EPSILON: f32 = 1e-6;
+
+    let r = a.1 - a.0;
+    let s = b.1 - b.0;
+    let cross = |u: Vector, v: Vector| u.x * v.y - u.y * v.x;
+
+    let denominator = cross(r, s);
+    let offset = b.0 - a.0;
+
+    let position = |edge: (Point, Point), point: Point| {
+        let direction = edge.1 - edge.0;
+        let offset = point - edge.0;
+
+        (offset.x * direction.x + offset.y * direction.y)
+            / (direction.x * direction.x + direction.y * direction.y)
+    };
+
+    let is_interior = |t: f32| t > EPSILON && t < 1.0 - EPSILON;
+
+    if denominator.abs() <= EPSILON * r.x.hypot(r.y) * s.x.hypot(s.y) {
+        // Parallel edges only touch each other when they are collinear
+        if cross(offset, r).abs() > EPSILON * r.x.hypot(r.y) * offset.x.hypot(offset.y) {
+            return Vec::new();
+        }
+
+        return [(0, a, b.0), (0, a, b.1), (1, b, a.0), (1, b, a.1)]
+            .into_iter()
+            .filter_map(|(index, edge, point)| {
+                let t = position(edge, point);
+
+                is_interior(t).then_some((index, t, point))
+            })
+            .collect();
+    }
+
+    let t = cross(offset, s) / denominator;
+    let u = cross(offset, r) / denominator;
+
+    if !(-EPSILON..=1.0 + EPSILON).contains(&t) || !(-EPSILON..=1.0 + EPSILON).contains(&u) {
+        return Vec::new();
+    }
+
+    // Prefer existing vertices, so touching edges share their points exactly
+    let point = if !is_interior(u) {
+        if u < 0.5 { b.0 } else { b.1 }
+    } else if !is_interior(t) {
+        if t < 0.5 { a.0 } else { a.1 }
+    } else {
+        snap(a.0 + r * t)
+    };
+
+    let mut intersections = Vec::new();
+
+    if is_interior(t) && point != a.0 && point != a.1 {
+        intersections.push((0, t, point));
+    }
+
+    if is_interior(u) && point != b.0 && point != b.1 {
+        intersections.push((1, u, point));
+    }
+
+    intersections
+}
+
+/// Returns whether the given point is inside the [`Ring`]s, following the
+/// nonzero fill rule.
+fn is_inside(rings: &[Ring], point: Point) -> bool {
+    let mut winding = 0;
+
+    for ring in rings {
+        for (from, to) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            let side = (to.x - from.x) * (point.y - from.y) - (point.x - from.x) * (to.y - from.y);
+
+            if from.y <= point.y {
+                if to.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding != 0
+}
+
+/// Chains directed boundary edges into closed [`Ring`]s.
+fn chain(edges: &[(Point, Point)]) -> Vec<Ring> {
+    let mut outgoing: HashMap<Key, Vec<usize>> = HashMap::new();
+
+    for (i, (from, _)) in edges.iter().enumerate() {
+        outgoing.entry(key(*from)).or_default().push(i);
+    }
+
+    let mut is_used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+
+    for start in 0..edges.len() {
+        if is_used[start] {
+            continue;
+        }
+
+        let mut ring = Vec::new();
+        let mut current = start;
+
+        loop {
+            is_used[current] = true;
+
+            let (from, to) = edges[current];
+            ring.push(from);
+
+            if key(to) == key(edges[start].0) {
+                break;
+            }
+
+            let next = outgoing
+                .get(&key(to))
+                .and_then(|candidates| candidates.iter().find(|i| !is_used[**i]));
+
+            match next {
+                Some(next) => current = *next,
+                None => break,
+            }
+        }
+
+        if ring.len() > 2 {
+            rings.push(ring);
+        }
+    }
+
+    rings
+}
+
+/// A rectangle around a line segment; counterclockwise.
+fn band(from: Point, to: Point, distance: f32) -> Ring {
+    let direction = to - from;
+    let normal =
+        Vector::new(-direction.y, direction.x) * (distance / from.distance(to).max(f32::EPSILON));
+
+    oriented(vec![
+        snap(from + normal),
+        snap(from - normal),
+        snap(to - normal),
+        snap(to + normal),
+    ])
+}
+
+/// A polygon approximating a circle; counterclockwise.
+fn disc(center: Point, radius: f32) -> Ring {
+    let segments = (std::f32::consts::PI / (1.0 - TOLERANCE / radius.max(TOLERANCE)).acos())
+        .ceil()
+        .clamp(8.0, 128.0) as usize;
+
+    oriented(
+        (0..segments)
+            .map(|i| {
+                let angle = std::f32::consts::TAU * i as f32 / segments as f32;
+
+                snap(center + Vector::new(angle.cos(), angle.sin()) * radius)
+            })
+            .collect(),
+    )
+}
+
+fn oriented(mut ring: Ring) -> Ring {
+    if area(&ring) < 0.0 {
+        ring.reverse();
+    }
+
+    ring
+}
+
+/// The signed area of a [`Ring`].
+fn area(ring: &[Point]) -> f32 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(from, to)| from.x * to.y - to.x * from.y)
+        .sum::<f32>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Operation, Ring, area, combine};
+    use crate::core::Point;
+
+    fn square(x: f32, y: f32, size: f32) -> Ring {
+        vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+        ]
+    }
+
+    #[test]
+    fn overlapping_squares_are_combined() {
+        let a = [square(0.0, 0.0, 2.0)];
+        let b = [square(1.0, 1.0, 2.0)];
+
+        let total = |rings: Vec<Ring>| rings.iter().map(|ring| area(ring).abs()).sum::<f32>();
+
+        assert_eq!(total(combine(&a, &b, Operation::Union)), 7.0);
+        assert_eq!(total(combine(&a, &b, Operation::Intersection)), 1.0);
+        assert_eq!(total(combine(&a, &b, Operation::Difference)), 3.0);
+        assert_eq!(total(combine(&a, &b, Operation::Xor)), 6.0);
+    }
+}
@@ -0,0 +1,114 @@
+//! Fill regions with repeating images.
+use crate::core::{Point, Rectangle, Size};
+use crate::geometry::Image;
+
+/// An [`Image`] repeated over and over to fill a region.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    /// The [`Image`] of a single tile of the [`Pattern`].
+    pub image: Image,
+
+    /// The [`Size`] of a single tile of the [`Pattern`].
+    pub size: Size,
+
+    /// The position of the top-left corner of one of the tiles.
+    ///
+    /// Every other tile is laid out relative to it.
+    pub origin: Point,
+}
+
+impl Pattern {
+    /// Creates a new [`Pattern`] repeating the given [`Image`] in tiles
+    /// of the given [`Size`].
+    pub fn new(image: impl Into<Image>, size: impl Into<Size>) -> Self {
+        Self {
+            image: image.into(),
+            size: size.into(),
+            origin: Point::ORIGIN,
+        }
+    }
+
+    /// Sets the origin of the [`Pattern`].
+    pub fn origin(mut self, origin: impl Into<Point>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
+    /// Returns the tiles of the [`Pattern`] covering the given bounds.
+    ///
+    /// Each tile is returned with its normalized region of the [`Image`],
+    /// cropping the tiles on the edges of the bounds.
+    pub fn tiles(&self, bounds: Rectangle) -> Vec<(Rectangle, Rectangle)> {
+        let Size { width, height } = self.size;
+
+        if width <= 0.0 || height <= 0.0 {
+            return Vec::new();
+        }
+
+        let start = Point::new(
+            self.origin.x + ((bounds.x - self.origin.x) / width).floor() * width,
+            self.origin.y + ((bounds.y - self.origin.y) / height).floor() * height,
+        );
+
+        let mut tiles = Vec::new();
+        let mut y = start.y;
+
+        while y < bounds.y + bounds.height {
+            let mut x = start.x;
+
+            while x < bounds.x + bounds.width {
+                let tile = Rectangle::new(Point::new(x, y), self.size);
+
+                if let Some(visible) = tile.intersection(&bounds) {
+                    let region = Rectangle {
+                        x: (visible.x - x) / width,
+                        y: (visible.y - y) / height,
+                        width: visible.width / width,
+                        height: visible.height / height,
+                    };
+
+                    tiles.push((visible, region));
+                }
+
+                x += width;
+            }
+
+            y += height;
+        }
+
+        tiles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::image;
+
+    #[test]
+    fn edge_tiles_are_cropped() {
+        let pattern = Pattern::new(
+            Image::new(image::Handle::from_rgba(1, 1, vec![0; 4])),
+            Size::new(10.0, 10.0),
+        )
+        .origin(Point::new(5.0, 0.0));
+
+        let tiles = pattern.tiles(Rectangle::new(Point::ORIGIN, Size::new(20.0, 10.0)));
+
+        assert_eq!(tiles.len(), 3);
+
+        assert_eq!(
+            tiles[0],
+            (
+                Rectangle::new(Point::ORIGIN, Size::new(5.0, 10.0)),
+                Rectangle::new(Point::new(0.5, 0.0), Size::new(0.5, 1.0)),
+            )
+        );
+
+        assert_eq!(
+            tiles[2].1,
+            Rectangle::new(Point::ORIGIN, Size::new(0.5, 1.0))
+        );
+    }
+}
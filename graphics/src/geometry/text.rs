@@ -1,10 +1,13 @@
+//! Draw and measure text on a canvas.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Alignment, LineHeight, Paragraph, Shaping, Wrapping};
-use crate::core::{Color, Font, Pixels, Point, Size, Vector};
+use crate::core::text::{Alignment, LineHeight, Paragraph, Shaping, Span, Wrapping};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Size, Vector};
 use crate::geometry::Path;
 use crate::text;
+use crate::text::paragraph;
 
+use std::ops::Range;
 /// A bunch of text that can be drawn to a canvas
 #[derive(Debug, Clone)]
 pub struct Text {
@@ -46,7 +49,84 @@ impl Text {
     /// Computes the [`Path`]s of the [`Text`] and draws them using
     /// the given closure.
     pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
-        let paragraph = text::Paragraph::with_text(core::text::Text {
+        let paragraph = self.paragraph();
+        let translation = translation(&paragraph, self.position, self.align_x, self.align_y);
+
+        draw_paragraph(
+            &paragraph,
+            self.color,
+            |run, glyph| {
+                Some(Pen::straight(Point::new(
+                    translation.x + glyph.x + glyph.x_offset,
+                    translation.y + glyph.y_offset + run.line_y,
+                )))
+            },
+            &mut f,
+        );
+    }
+
+    /// Computes the [`Path`]s of the [`Text`] laid out along the given
+    /// [`Path`] and draws them using the given closure.
+    ///
+    /// The [`Path`] takes the place of the position of the [`Text`]: the
+    /// horizontal alignment is applied along the [`Path`], and the vertical
+    /// alignment across it. Glyphs that fall outside of the [`Path`] are
+    /// not drawn.
+    pub fn draw_along(&self, path: &Path, mut f: impl FnMut(Path, Color)) {
+        let paragraph = self.paragraph();
+        let segments = segments(path);
+
+        let length: f32 = segments.iter().map(|(from, to)| from.distance(*to)).sum();
+        let width = paragraph.min_width();
+        let height = paragraph.min_height();
+
+        let start = match self.align_x {
+            Alignment::Default | Alignment::Left | Alignment::Justified => 0.0,
+            Alignment::Center => (length - width) / 2.0,
+            Alignment::Right => length - width,
+        };
+
+        let offset = match self.align_y {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => -height / 2.0,
+            alignment::Vertical::Bottom => -height,
+        };
+
+        draw_paragraph(
+            &paragraph,
+            self.color,
+            |run, glyph| {
+                let (point, direction) = sample(&segments, start + glyph.x + glyph.w / 2.0)?;
+
+                let pen = Pen {
+                    origin: point,
+                    sin: direction.y,
+                    cos: direction.x,
+                };
+
+                Some(Pen {
+                    origin: pen.apply(
+                        glyph.x_offset - glyph.w / 2.0,
+                        offset + glyph.y_offset + run.line_y,
+                    ),
+                    ..pen
+                })
+            },
+            &mut f,
+        );
+    }
+
+    /// Computes the [`Measurement`] of the [`Text`]; that is, the bounds of
+    /// its lines and glyphs once laid out at its position.
+    pub fn measure(&self) -> Measurement {
+        let paragraph = self.paragraph();
+        let translation = translation(&paragraph, self.position, self.align_x, self.align_y);
+
+        measure(&paragraph, translation)
+    }
+
+    fn paragraph(&self) -> text::Paragraph {
+        text::Paragraph::with_text(core::text::Text {
             content: &self.content,
             bounds: Size::new(self.max_width, f32::INFINITY),
             size: self.size,
@@ -57,96 +137,7 @@ impl Text {
             shaping: self.shaping,
             wrapping: Wrapping::default(),
             hint_factor: None,
-        });
-
-        let translation_x = match self.align_x {
-            Alignment::Default | Alignment::Left | Alignment::Justified => self.position.x,
-            Alignment::Center => self.position.x - paragraph.min_width() / 2.0,
-            Alignment::Right => self.position.x - paragraph.min_width(),
-        };
-
-        let translation_y = {
-            match self.align_y {
-                alignment::Vertical::Top => self.position.y,
-                alignment::Vertical::Center => self.position.y - paragraph.min_height() / 2.0,
-                alignment::Vertical::Bottom => self.position.y - paragraph.min_height(),
-            }
-        };
-
-        let buffer = paragraph.buffer();
-        let mut swash_cache = cosmic_text::SwashCache::new();
-
-        let mut font_system = text::font_system().write().expect("Write font system");
-
-        for run in buffer.layout_runs() {
-            for glyph in run.glyphs.iter() {
-                let physical_glyph = glyph.physical((0.0, 0.0), 1.0);
-
-                let start_x = translation_x + glyph.x + glyph.x_offset;
-                let start_y = translation_y + glyph.y_offset + run.line_y;
-                let offset = Vector::new(start_x, start_y);
-
-                if let Some(commands) =
-                    swash_cache.get_outline_commands(font_system.raw(), physical_glyph.cache_key)
-                {
-                    let glyph = Path::new(|path| {
-                        use cosmic_text::Command;
-
-                        for command in commands {
-                            match command {
-                                Command::MoveTo(p) => {
-                                    path.move_to(Point::new(p.x, -p.y) + offset);
-                                }
-                                Command::LineTo(p) => {
-                                    path.line_to(Point::new(p.x, -p.y) + offset);
-                                }
-                                Command::CurveTo(control_a, control_b, to) => {
-                                    path.bezier_curve_to(
-                                        Point::new(control_a.x, -control_a.y) + offset,
-                                        Point::new(control_b.x, -control_b.y) + offset,
-                                        Point::new(to.x, -to.y) + offset,
-                                    );
-                                }
-                                Command::QuadTo(control, to) => {
-                                    path.quadratic_curve_to(
-                                        Point::new(control.x, -control.y) + offset,
-                                        Point::new(to.x, -to.y) + offset,
-                                    );
-                                }
-                                Command::Close => {
-                                    path.close();
-                                }
-                            }
-                        }
-                    });
-
-                    f(glyph, self.color);
-                } else {
-                    // TODO: Raster image support for `Canvas`
-                    let [r, g, b, a] = self.color.into_rgba8();
-
-                    swash_cache.with_pixels(
-                        font_system.raw(),
-                        physical_glyph.cache_key,
-                        cosmic_text::Color::rgba(r, g, b, a),
-                        |x, y, color| {
-                            f(
-                                Path::rectangle(
-                                    Point::new(x as f32, y as f32) + offset,
-                                    Size::new(1.0, 1.0),
-                                ),
-                                Color::from_rgba8(
-                                    color.r(),
-                                    color.g(),
-                                    color.b(),
-                                    color.a() as f32 / 255.0,
-                                ),
-                            );
-                        },
-                    );
-                }
-            }
-        }
+        })
     }
 }
 
@@ -181,3 +172,365 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+/// Some text made of [`Span`]s of different styles that can be drawn to a
+/// canvas.
+///
+/// The size, line height, font, and color of each [`Span`] are honored;
+/// its highlight and decorations are not.
+#[derive(Debug, Clone)]
+pub struct RichText {
+    /// The spans of the text.
+    pub spans: Vec<Span<'static>>,
+    /// The position of the text relative to the alignment properties.
+    ///
+    /// See [`Text::position`].
+    pub position: Point,
+    /// The maximum horizontal space available for this [`RichText`].
+    ///
+    /// Text will break into new lines when the width is reached.
+    pub max_width: f32,
+    /// The color of the spans without a color of their own.
+    pub color: Color,
+    /// The size of the spans without a size of their own.
+    pub size: Pixels,
+    /// The line height of the spans without a line height of their own.
+    pub line_height: LineHeight,
+    /// The font of the spans without a font of their own.
+    pub font: Font,
+    /// The horizontal alignment of the text
+    pub align_x: Alignment,
+    /// The vertical alignment of the text
+    pub align_y: alignment::Vertical,
+}
+
+impl RichText {
+    /// Computes the [`Path`]s of the [`RichText`] and draws them using
+    /// the given closure.
+    pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
+        let paragraph = self.paragraph();
+        let translation = translation(&paragraph, self.position, self.align_x, self.align_y);
+
+        draw_paragraph(
+            &paragraph,
+            self.color,
+            |run, glyph| {
+                Some(Pen::straight(Point::new(
+                    translation.x + glyph.x + glyph.x_offset,
+                    translation.y + glyph.y_offset + run.line_y,
+                )))
+            },
+            &mut f,
+        );
+    }
+
+    /// Computes the [`Measurement`] of the [`RichText`].
+    ///
+    /// The ranges of the [`Measurement`] index the text of all the spans
+    /// put together.
+    pub fn measure(&self) -> Measurement {
+        let paragraph = self.paragraph();
+        let translation = translation(&paragraph, self.position, self.align_x, self.align_y);
+
+        measure(&paragraph, translation)
+    }
+
+    fn paragraph(&self) -> text::Paragraph {
+        text::Paragraph::with_spans(core::text::Text {
+            content: self.spans.as_slice(),
+            bounds: Size::new(self.max_width, f32::INFINITY),
+            size: self.size,
+            line_height: self.line_height,
+            font: self.font,
+            align_x: self.align_x,
+            align_y: self.align_y,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::default(),
+            hint_factor: None,
+        })
+    }
+}
+
+impl Default for RichText {
+    fn default() -> Self {
+        let text = Text::default();
+
+        Self {
+            spans: Vec::new(),
+            position: text.position,
+            max_width: text.max_width,
+            color: text.color,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            align_x: text.align_x,
+            align_y: text.align_y,
+        }
+    }
+}
+
+impl From<Vec<Span<'static>>> for RichText {
+    fn from(spans: Vec<Span<'static>>) -> Self {
+        Self {
+            spans,
+            ..Self::default()
+        }
+    }
+}
+
+/// The metrics of some text laid out on a canvas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// The minimum size that fits the text.
+    pub size: Size,
+    /// The visual lines of the text, after breaking it.
+    pub lines: Vec<Line>,
+}
+
+/// A visual line of some measured text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    /// The byte range of the text in the [`Line`].
+    pub range: Range<usize>,
+    /// The bounds of the [`Line`].
+    pub bounds: Rectangle,
+    /// The vertical coordinate of the baseline of the [`Line`].
+    pub baseline: f32,
+    /// The glyphs of the [`Line`], in visual order.
+    pub glyphs: Vec<Glyph>,
+}
+
+/// A glyph of some measured text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Glyph {
+    /// The byte range of the text shaped into the [`Glyph`].
+    pub range: Range<usize>,
+    /// The bounds of the [`Glyph`], spanning the height of its line.
+    pub bounds: Rectangle,
+}
+
+/// The placement of a glyph; a pen position and a rotation.
+#[derive(Debug, Clone, Copy)]
+struct Pen {
+    origin: Point,
+    sin: f32,
+    cos: f32,
+}
+
+impl Pen {
+    fn straight(origin: Point) -> Self {
+        Self {
+            origin,
+            sin: 0.0,
+            cos: 1.0,
+        }
+    }
+
+    fn apply(&self, x: f32, y: f32) -> Point {
+        Point::new(
+            self.origin.x + x * self.cos - y * self.sin,
+            self.origin.y + x * self.sin + y * self.cos,
+        )
+    }
+}
+
+fn translation(
+    paragraph: &text::Paragraph,
+    position: Point,
+    align_x: Alignment,
+    align_y: alignment::Vertical,
+) -> Vector {
+    let x = match align_x {
+        Alignment::Default | Alignment::Left | Alignment::Justified => position.x,
+        Alignment::Center => position.x - paragraph.min_width() / 2.0,
+        Alignment::Right => position.x - paragraph.min_width(),
+    };
+
+    let y = match align_y {
+        alignment::Vertical::Top => position.y,
+        alignment::Vertical::Center => position.y - paragraph.min_height() / 2.0,
+        alignment::Vertical::Bottom => position.y - paragraph.min_height(),
+    };
+
+    Vector::new(x, y)
+}
+
+/// Draws every glyph of the [`text::Paragraph`] placed by the given closure;
+/// skipping the glyphs without one.
+fn draw_paragraph(
+    paragraph: &text::Paragraph,
+    color: Color,
+    mut place: impl FnMut(&cosmic_text::LayoutRun<'_>, &cosmic_text::LayoutGlyph) -> Option<Pen>,
+    f: &mut impl FnMut(Path, Color),
+) {
+    let buffer = paragraph.buffer();
+    let mut swash_cache = cosmic_text::SwashCache::new();
+
+    let mut font_system = text::font_system().write().expect("Write font system");
+
+    for run in buffer.layout_runs() {
+        for glyph in run.glyphs.iter() {
+            let Some(pen) = place(&run, glyph) else {
+                continue;
+            };
+
+            let physical_glyph = glyph.physical((0.0, 0.0), 1.0);
+
+            let color = glyph
+                .color_opt
+                .map(|color| {
+                    Color::from_rgba8(color.r(), color.g(), color.b(), color.a() as f32 / 255.0)
+                })
+                .unwrap_or(color);
+
+            if let Some(commands) =
+                swash_cache.get_outline_commands(font_system.raw(), physical_glyph.cache_key)
+            {
+                let glyph = Path::new(|path| {
+                    use cosmic_text::Command;
+
+                    for command in commands {
+                        match command {
+                            Command::MoveTo(p) => {
+                                path.move_to(pen.apply(p.x, -p.y));
+                            }
+                            Command::LineTo(p) => {
+                                path.line_to(pen.apply(p.x, -p.y));
+                            }
+                            Command::CurveTo(control_a, control_b, to) => {
+                                path.bezier_curve_to(
+                                    pen.apply(control_a.x, -control_a.y),
+                                    pen.apply(control_b.x, -control_b.y),
+                                    pen.apply(to.x, -to.y),
+                                );
+                            }
+                            Command::QuadTo(control, to) => {
+                                path.quadratic_curve_to(
+                                    pen.apply(control.x, -control.y),
+                                    pen.apply(to.x, -to.y),
+                                );
+                            }
+                            Command::Close => {
+                                path.close();
+                            }
+                        }
+                    }
+                });
+
+                f(glyph, color);
+            } else {
+                // TODO: Raster image support for `Canvas`
+                let [r, g, b, a] = color.into_rgba8();
+
+                swash_cache.with_pixels(
+                    font_system.raw(),
+                    physical_glyph.cache_key,
+                    cosmic_text::Color::rgba(r, g, b, a),
+                    |x, y, color| {
+                        f(
+                            Path::rectangle(pen.apply(x as f32, y as f32), Size::new(1.0, 1.0)),
+                            Color::from_rgba8(
+                                color.r(),
+                                color.g(),
+                                color.b(),
+                                color.a() as f32 / 255.0,
+                            ),
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn measure(paragraph: &text::Paragraph, translation: Vector) -> Measurement {
+    let buffer = paragraph.buffer();
+
+    let lines = buffer
+        .layout_runs()
+        .map(|run| {
+            let offset = paragraph::line_offset(buffer, run.line_i);
+
+            let start = run.glyphs.iter().map(|glyph| glyph.start).min();
+            let end = run.glyphs.iter().map(|glyph| glyph.end).max();
+
+            let left = run
+                .glyphs
+                .iter()
+                .map(|glyph| glyph.x)
+                .reduce(f32::min)
+                .unwrap_or_default();
+
+            let glyphs = run
+                .glyphs
+                .iter()
+                .map(|glyph| Glyph {
+                    range: offset + glyph.start..offset + glyph.end,
+                    bounds: Rectangle::new(
+                        Point::new(translation.x + glyph.x, translation.y + run.line_top),
+                        Size::new(glyph.w, run.line_height),
+                    ),
+                })
+                .collect();
+
+            Line {
+                range: offset + start.unwrap_or_default()..offset + end.unwrap_or_default(),
+                bounds: Rectangle::new(
+                    Point::new(translation.x + left, translation.y + run.line_top),
+                    Size::new(run.line_w, run.line_height),
+                ),
+                baseline: translation.y + run.line_y,
+                glyphs,
+            }
+        })
+        .collect();
+
+    Measurement {
+        size: paragraph.min_bounds(),
+        lines,
+    }
+}
+
+/// Flattens the given [`Path`] into line segments.
+fn segments(path: &Path) -> Vec<(Point, Point)> {
+    use lyon_path::iterator::PathIterator;
+
+    path.raw()
+        .iter()
+        .flattened(0.1)
+        .filter_map(|event| match event {
+            lyon_path::Event::Line { from, to } => Some((from, to)),
+            lyon_path::Event::End {
+                last,
+                first,
+                close: true,
+            } => Some((last, first)),
+            _ => None,
+        })
+        .map(|(from, to)| (Point::new(from.x, from.y), Point::new(to.x, to.y)))
+        .collect()
+}
+
+/// Returns the point at the given distance along some segments, together
+/// with the direction of the segment containing it.
+fn sample(segments: &[(Point, Point)], distance: f32) -> Option<(Point, Vector)> {
+    if distance < 0.0 {
+        return None;
+    }
+
+    let mut remaining = distance;
+
+    for (from, to) in segments {
+        let length = from.distance(*to);
+
+        if length > 0.0 && remaining <= length {
+            let direction = (*to - *from) * (1.0 / length);
+
+            return Some((*from + direction * remaining, direction));
+        }
+
+        remaining -= length;
+    }
+
+    None
+}
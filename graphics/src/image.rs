@@ -6,6 +6,13 @@ use crate::core::Rectangle;
 use crate::core::image;
 use crate::core::svg;
 
+#[cfg(feature = "image")]
+use crate::core::Size;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use std::hash::Hash;
+
 /// A raster or vector image.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq)]
@@ -35,6 +42,148 @@ impl Image {
     }
 }
 
+/// The eviction policy shared by the image caches of a renderer.
+///
+/// By default, an [`Eviction`] drops any entry that was not used in the
+/// last frame. A memory budget can be set to keep unused entries around, and
+/// specific images can be pinned or evicted explicitly.
+#[derive(Debug, Default)]
+pub struct Eviction {
+    budget: usize,
+    pinned: FxHashSet<image::Id>,
+    evicted: FxHashSet<image::Id>,
+    trim_all: bool,
+    frame: u64,
+}
+
+impl Eviction {
+    /// Returns the memory budget for unused entries, in bytes.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Sets the memory budget for unused entries, in bytes.
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+    }
+
+    /// Pins or unpins the image with the given [`image::Id`].
+    pub fn pin(&mut self, id: image::Id, pinned: bool) {
+        if pinned {
+            let _ = self.pinned.insert(id);
+        } else {
+            let _ = self.pinned.remove(&id);
+        }
+    }
+
+    /// Returns whether the image with the given [`image::Id`] is pinned.
+    pub fn is_pinned(&self, id: image::Id) -> bool {
+        self.pinned.contains(&id)
+    }
+
+    /// Returns the amount of pinned images.
+    pub fn pinned(&self) -> usize {
+        self.pinned.len()
+    }
+
+    /// Requests the image with the given [`image::Id`] to be evicted at the
+    /// end of the current frame.
+    pub fn evict(&mut self, id: image::Id) {
+        let _ = self.evicted.insert(id);
+    }
+
+    /// Requests all unused entries to be evicted at the end of the current
+    /// frame, regardless of the budget.
+    pub fn trim_all(&mut self) {
+        self.trim_all = true;
+    }
+
+    /// Returns whether any evictions have been requested explicitly during
+    /// the current frame.
+    pub fn is_requested(&self) -> bool {
+        self.trim_all || !self.evicted.is_empty()
+    }
+
+    /// Finishes the current frame, forgetting any requested evictions.
+    pub fn finish(&mut self) {
+        self.evicted.clear();
+        self.trim_all = false;
+        self.frame += 1;
+    }
+
+    /// Retains the entries of an image cache that should survive the current
+    /// frame.
+    ///
+    /// Pinned entries are always kept. Entries used during the frame are kept
+    /// unless evicted explicitly. Unused entries are kept—most recently used
+    /// first—as long as their total size fits in the budget.
+    ///
+    /// The frame in which each entry was last used is tracked in `last_used`.
+    pub fn retain<K, V>(
+        &self,
+        entries: &mut FxHashMap<K, V>,
+        last_used: &mut FxHashMap<K, u64>,
+        hits: &FxHashSet<K>,
+        id: impl Fn(&K) -> image::Id,
+        size: impl Fn(&V) -> usize,
+        mut on_drop: impl FnMut(&K, &mut V),
+    ) where
+        K: Eq + Hash + Clone,
+    {
+        for key in hits {
+            let _ = last_used.insert(key.clone(), self.frame);
+        }
+
+        let mut kept = FxHashSet::default();
+
+        if self.budget > 0 && !self.trim_all {
+            let mut unused: Vec<_> = entries
+                .iter()
+                .filter(|(key, _)| {
+                    let id = id(key);
+
+                    !hits.contains(*key)
+                        && !self.pinned.contains(&id)
+                        && !self.evicted.contains(&id)
+                })
+                .map(|(key, value)| {
+                    let frame = last_used.get(key).copied().unwrap_or_default();
+
+                    (frame, key, size(value))
+                })
+                .collect();
+
+            unused.sort_unstable_by(|(a, ..), (b, ..)| b.cmp(a));
+
+            let mut total = 0;
+
+            for (_, key, size) in unused {
+                if total + size > self.budget {
+                    break;
+                }
+
+                total += size;
+                let _ = kept.insert(key.clone());
+            }
+        }
+
+        entries.retain(|key, value| {
+            let id = id(key);
+
+            let retain = self.pinned.contains(&id)
+                || (hits.contains(key) && !self.evicted.contains(&id))
+                || kept.contains(key);
+
+            if !retain {
+                on_drop(key, value);
+                let _ = last_used.remove(key);
+            }
+
+            retain
+        });
+    }
+}
+
 /// An image buffer.
 #[cfg(feature = "image")]
 pub type Buffer = ::image::ImageBuffer<::image::Rgba<u8>, Bytes>;
@@ -111,25 +260,37 @@ pub fn load(handle: &image::Handle) -> Result<Buffer, image::Error> {
     }
 
     let (width, height, pixels) = match handle {
-        image::Handle::Path(_, path) => {
-            let image = ::image::open(path).map_err(to_error)?;
+        image::Handle::Path(_, path, orientation) => {
+            // The format is guessed from the contents first, since files
+            // downloaded from the web often have the wrong extension
+            let image = ::image::ImageReader::open(path)
+                .and_then(::image::ImageReader::with_guessed_format)
+                .map_err(::image::ImageError::IoError)
+                .and_then(::image::ImageReader::decode)
+                .map_err(to_error)?;
 
-            let operation = std::fs::File::open(path)
-                .ok()
-                .map(std::io::BufReader::new)
-                .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
-                .unwrap_or_else(Operation::empty);
+            let operation = match orientation {
+                image::Orientation::Exif => std::fs::File::open(path)
+                    .ok()
+                    .map(std::io::BufReader::new)
+                    .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
+                    .unwrap_or_else(Operation::empty),
+                image::Orientation::Stored => Operation::empty(),
+            };
 
             let rgba = operation.perform(image).into_rgba8();
 
             (rgba.width(), rgba.height(), Bytes::from(rgba.into_raw()))
         }
-        image::Handle::Bytes(_, bytes) => {
+        image::Handle::Bytes(_, bytes, orientation) => {
             let image = ::image::load_from_memory(bytes).map_err(to_error)?;
 
-            let operation = Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                .ok()
-                .unwrap_or_else(Operation::empty);
+            let operation = match orientation {
+                image::Orientation::Exif => Operation::from_exif(&mut std::io::Cursor::new(bytes))
+                    .ok()
+                    .unwrap_or_else(Operation::empty),
+                image::Orientation::Stored => Operation::empty(),
+            };
 
             let rgba = operation.perform(image).into_rgba8();
 
@@ -141,6 +302,7 @@ pub fn load(handle: &image::Handle) -> Result<Buffer, image::Error> {
             pixels,
             ..
         } => (*width, *height, pixels.clone()),
+        image::Handle::Texture { .. } => return Err(image::Error::Unsupported),
     };
 
     if let Some(image) = ::image::ImageBuffer::from_raw(width, height, pixels) {
@@ -161,3 +323,313 @@ fn to_error(error: ::image::ImageError) -> image::Error {
         error => image::Error::Invalid(Arc::new(error)),
     }
 }
+
+/// Decodes all the frames of the animated image of the given [`Handle`];
+/// like a GIF, an APNG, or an animated WebP.
+///
+/// Returns `None` if the image is not animated or if it cannot be decoded.
+///
+/// [`Handle`]: image::Handle
+#[cfg(all(feature = "image", feature = "animated-image"))]
+pub fn load_animation(handle: &image::Handle) -> Option<image::Animation> {
+    use crate::core::time::Duration;
+
+    use ::image::AnimationDecoder;
+    use ::image::codecs::{gif, png, webp};
+    use std::io::{BufRead, Cursor, Seek};
+
+    // Browsers display frames with tiny delays for 100ms
+    const MIN_DELAY: Duration = Duration::from_millis(20);
+    const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    fn decode<'a>(reader: impl BufRead + Seek + 'a) -> Option<::image::Frames<'a>> {
+        let reader = ::image::ImageReader::new(reader)
+            .with_guessed_format()
+            .ok()?;
+
+        let format = reader.format()?;
+        let reader = reader.into_inner();
+
+        match format {
+            ::image::ImageFormat::Gif => Some(gif::GifDecoder::new(reader).ok()?.into_frames()),
+            ::image::ImageFormat::Png => {
+                let decoder = png::PngDecoder::new(reader).ok()?;
+
+                if !decoder.is_apng().ok()? {
+                    return None;
+                }
+
+                Some(decoder.apng().ok()?.into_frames())
+            }
+            ::image::ImageFormat::WebP => {
+                let decoder = webp::WebPDecoder::new(reader).ok()?;
+
+                if !decoder.has_animation() {
+                    return None;
+                }
+
+                Some(decoder.into_frames())
+            }
+            _ => None,
+        }
+    }
+
+    let frames = match handle {
+        image::Handle::Path(_, path, _) => {
+            decode(std::io::BufReader::new(std::fs::File::open(path).ok()?))?
+        }
+        image::Handle::Bytes(_, bytes, _) => decode(Cursor::new(bytes.as_ref()))?,
+        image::Handle::Rgba { .. } | image::Handle::Texture { .. } => return None,
+    };
+
+    let frames = frames.collect_frames().ok()?;
+
+    if frames.len() < 2 {
+        return None;
+    }
+
+    image::Animation::new(frames.into_iter().map(|frame| {
+        let delay = Duration::from(frame.delay());
+        let buffer = frame.into_buffer();
+
+        image::Frame::new(
+            image::Handle::from_rgba(buffer.width(), buffer.height(), buffer.into_raw()),
+            if delay < MIN_DELAY {
+                DEFAULT_DELAY
+            } else {
+                delay
+            },
+        )
+    }))
+}
+
+/// Decodes all the frames of the animated image of the given [`Handle`].
+///
+/// Always returns `None`, since the `animated-image` feature is disabled.
+///
+/// [`Handle`]: image::Handle
+#[cfg(all(feature = "image", not(feature = "animated-image")))]
+pub fn load_animation(_handle: &image::Handle) -> Option<image::Animation> {
+    None
+}
+
+/// A cache of the frames of animated images.
+#[cfg(feature = "image")]
+#[derive(Debug, Default)]
+pub struct Animations {
+    entries: FxHashMap<image::Id, Option<image::Animation>>,
+    hits: FxHashSet<image::Id>,
+}
+
+#[cfg(feature = "image")]
+impl Animations {
+    /// Returns the [`image::Animation`] of the given [`Handle`], decoding it if
+    /// necessary.
+    ///
+    /// Returns `None` if the image is not animated.
+    ///
+    /// [`Handle`]: image::Handle
+    pub fn load(&mut self, handle: &image::Handle) -> Option<&image::Animation> {
+        let id = handle.id();
+        let _ = self.hits.insert(id);
+
+        self.entries
+            .entry(id)
+            .or_insert_with(|| load_animation(handle))
+            .as_ref()
+    }
+
+    /// Drops the frames of the animations that were not used since the last trim.
+    pub fn trim(&mut self) {
+        let hits = &self.hits;
+
+        self.entries.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+}
+
+/// A cache of the mipmaps of raster images.
+#[cfg(feature = "image")]
+#[derive(Debug, Default)]
+pub struct Mipmaps {
+    entries: FxHashMap<image::Id, Option<Vec<image::Handle>>>,
+    hits: FxHashSet<image::Id>,
+}
+
+#[cfg(feature = "image")]
+impl Mipmaps {
+    /// Returns the mipmap of the given [`Handle`] that best fits the given
+    /// physical size, generating it if necessary.
+    ///
+    /// Returns `None` if the image is not drawn at less than half its size or
+    /// if it cannot be decoded.
+    ///
+    /// [`Handle`]: image::Handle
+    pub fn level(
+        &mut self,
+        handle: &image::Handle,
+        image_size: Size<u32>,
+        size: Size,
+    ) -> Option<image::Handle> {
+        let ratio = f32::min(
+            image_size.width as f32 / size.width,
+            image_size.height as f32 / size.height,
+        );
+
+        if ratio.is_nan() || ratio < 2.0 {
+            return None;
+        }
+
+        let max_level = image_size.width.max(image_size.height).ilog2();
+        let level = (ratio.log2().floor() as u32).min(max_level) as usize;
+
+        let id = handle.id();
+        let _ = self.hits.insert(id);
+
+        let entry = self.entries.entry(id).or_insert_with(|| Some(Vec::new()));
+        let levels = entry.as_mut()?;
+
+        while levels.len() < level {
+            let next = match levels.last() {
+                Some(image::Handle::Rgba {
+                    width,
+                    height,
+                    pixels,
+                    ..
+                }) => downsample(*width, *height, pixels),
+                _ => match load(handle) {
+                    Ok(image) => downsample(image.width(), image.height(), image.as_raw()),
+                    Err(_) => {
+                        *entry = None;
+
+                        return None;
+                    }
+                },
+            };
+
+            levels.push(next);
+        }
+
+        levels.get(level - 1).cloned()
+    }
+
+    /// Drops the mipmaps of the images that were not used since the last trim.
+    pub fn trim(&mut self) {
+        let hits = &self.hits;
+
+        self.entries.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+}
+
+/// A cache of raster images with some [`ColorFilter`] applied to their pixels.
+///
+/// This is useful for renderers that cannot filter colors while drawing.
+///
+/// [`ColorFilter`]: image::ColorFilter
+#[cfg(feature = "image")]
+#[derive(Debug, Default)]
+pub struct ColorFilters {
+    entries: FxHashMap<(image::Id, [u32; 12]), Option<image::Handle>>,
+    hits: FxHashSet<(image::Id, [u32; 12])>,
+}
+
+#[cfg(feature = "image")]
+impl ColorFilters {
+    /// Returns a [`Handle`] to the pixels of the given [`Handle`] with the
+    /// given [`ColorFilter`] applied, filtering them if necessary.
+    ///
+    /// Returns `None` if the image cannot be decoded.
+    ///
+    /// [`Handle`]: image::Handle
+    /// [`ColorFilter`]: image::ColorFilter
+    pub fn apply(
+        &mut self,
+        handle: &image::Handle,
+        color_filter: image::ColorFilter,
+    ) -> Option<image::Handle> {
+        let matrix = color_filter.matrix();
+        let key = (
+            handle.id(),
+            std::array::from_fn(|i| matrix[i / 4][i % 4].to_bits()),
+        );
+
+        let _ = self.hits.insert(key);
+
+        self.entries
+            .entry(key)
+            .or_insert_with(|| {
+                let image = load(handle).ok()?;
+                let mut pixels = image.as_raw().to_vec();
+
+                for pixel in pixels.chunks_exact_mut(4) {
+                    let [r, g, b] = color_filter.apply([
+                        f32::from(pixel[0]) / 255.0,
+                        f32::from(pixel[1]) / 255.0,
+                        f32::from(pixel[2]) / 255.0,
+                    ]);
+
+                    pixel[0] = (r * 255.0).round() as u8;
+                    pixel[1] = (g * 255.0).round() as u8;
+                    pixel[2] = (b * 255.0).round() as u8;
+                }
+
+                Some(image::Handle::from_rgba(
+                    image.width(),
+                    image.height(),
+                    pixels,
+                ))
+            })
+            .clone()
+    }
+
+    /// Drops the filtered images that were not used since the last trim.
+    pub fn trim(&mut self) {
+        let hits = &self.hits;
+
+        self.entries.retain(|key, _| hits.contains(key));
+        self.hits.clear();
+    }
+}
+
+/// Halves the size of some RGBA pixels by averaging each 2x2 block,
+/// weighting the colors by their alpha.
+#[cfg(feature = "image")]
+fn downsample(width: u32, height: u32, pixels: &[u8]) -> image::Handle {
+    let new_width = (width / 2).max(1);
+    let new_height = (height / 2).max(1);
+
+    let pixel = |x: u32, y: u32| {
+        let index = ((y.min(height - 1) * width + x.min(width - 1)) * 4) as usize;
+
+        &pixels[index..index + 4]
+    };
+
+    let mut output = Vec::with_capacity((new_width * new_height * 4) as usize);
+
+    for y in 0..new_height {
+        for x in 0..new_width {
+            let block = [
+                pixel(x * 2, y * 2),
+                pixel(x * 2 + 1, y * 2),
+                pixel(x * 2, y * 2 + 1),
+                pixel(x * 2 + 1, y * 2 + 1),
+            ];
+
+            let alpha: u32 = block.iter().map(|pixel| u32::from(pixel[3])).sum();
+
+            for channel in 0..3 {
+                let color: u32 = block
+                    .iter()
+                    .map(|pixel| u32::from(pixel[channel]) * u32::from(pixel[3]))
+                    .sum();
+
+                output.push(color.checked_div(alpha).unwrap_or(0) as u8);
+            }
+
+            output.push((alpha / 4) as u8);
+        }
+    }
+
+    image::Handle::from_rgba(new_width, new_height, output)
+}
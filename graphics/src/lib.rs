@@ -24,6 +24,9 @@ pub mod mesh;
 pub mod shell;
 pub mod text;
 
+#[cfg(feature = "svg")]
+pub mod svg;
+
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
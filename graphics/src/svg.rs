@@ -0,0 +1,183 @@
+//! Parse and rasterize vector graphics.
+#[cfg(feature = "svg-text")]
+mod fonts;
+
+#[cfg(feature = "svg-text")]
+pub use fonts::{Fonts, configure_fonts, font_database};
+
+use crate::core::svg::{self, Animation, ColorMode, Data, Handle, Svg};
+use crate::core::{Color, Size, image};
+
+use resvg::{tiny_skia, usvg};
+
+use std::fs;
+use std::panic;
+
+/// Rasterizes the given [`Svg`] into an [`image::Handle`] of the given size,
+/// in physical pixels.
+///
+/// The [`Svg`] is scaled to fit the size while keeping its aspect ratio, unless a
+/// viewport is set. Its color, color mode, animation time, and viewport are honored;
+/// its rotation and opacity are not.
+///
+/// This is useful for producing raster assets out of vector ones—like window icons,
+/// tray icons, or exports—with the same pipeline the renderer uses to draw them.
+///
+/// Text elements are only drawn if the `svg-text` feature is enabled.
+///
+/// Returns `None` if the size is zero or the SVG could not be loaded.
+pub fn rasterize(svg: impl Into<Svg>, size: Size<u32>) -> Option<image::Handle> {
+    let svg = svg.into();
+    let handle = &svg.handle;
+
+    let current_color = match svg.color_mode {
+        ColorMode::Filter => None,
+        ColorMode::CurrentColor => svg.color,
+    };
+
+    let options = usvg::Options {
+        #[cfg(feature = "svg-text")]
+        fontdb: font_database(),
+        style_sheet: style_sheet(handle, current_color),
+        ..usvg::Options::default()
+    };
+
+    let frame = svg
+        .time
+        .and_then(|time| Some(Animation::load(handle.data())?.frame(time)));
+
+    let tree = if let Some(frame) = frame {
+        parse_frame(handle, &frame, &options)?
+    } else {
+        parse(handle, &options)?
+    };
+
+    let mut pixmap = tiny_skia::Pixmap::new(size.width, size.height)?;
+
+    let tree_size = tree.size().to_int_size();
+
+    let target_size = if size.width > size.height {
+        tree_size.scale_to_width(size.width)
+    } else {
+        tree_size.scale_to_height(size.height)
+    };
+
+    let viewport = svg
+        .viewport
+        .filter(|viewport| viewport.width > 0.0 && viewport.height > 0.0);
+
+    let transform = if let Some(viewport) = viewport {
+        tiny_skia::Transform::from_scale(
+            size.width as f32 / viewport.width,
+            size.height as f32 / viewport.height,
+        )
+        .pre_translate(-viewport.x, -viewport.y)
+    } else if let Some(target_size) = target_size {
+        let tree_size = tree_size.to_size();
+        let target_size = target_size.to_size();
+
+        tiny_skia::Transform::from_scale(
+            target_size.width() / tree_size.width(),
+            target_size.height() / tree_size.height(),
+        )
+    } else {
+        tiny_skia::Transform::default()
+    };
+
+    // SVG rendering can panic on malformed or complex vectors.
+    // We catch panics to prevent crashes and continue gracefully.
+    let render = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+    }));
+
+    if let Err(error) = render {
+        log::warn!("SVG rendering for {handle:?} panicked: {error:?}");
+    }
+
+    let filter = match svg.color_mode {
+        ColorMode::Filter => svg.color.map(Color::into_rgba8),
+        ColorMode::CurrentColor => None,
+    };
+
+    // Demultiply the pixels, since raster images are not premultiplied
+    let rgba: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+
+            match filter {
+                Some([r, g, b, _]) => [r, g, b, pixel.alpha()],
+                None => [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()],
+            }
+        })
+        .collect();
+
+    Some(image::Handle::from_rgba(size.width, size.height, rgba))
+}
+
+/// Parses the SVG of the given [`Handle`] with the given [`usvg::Options`].
+///
+/// If the [`Handle`] points to a fragment, only the referenced element is kept.
+pub fn parse(handle: &Handle, options: &usvg::Options<'_>) -> Option<usvg::Tree> {
+    let tree = load(handle, options);
+
+    if tree.is_none() {
+        log::warn!("Failed to load SVG {handle:?}");
+    }
+
+    tree
+}
+
+fn load(handle: &Handle, options: &usvg::Options<'_>) -> Option<usvg::Tree> {
+    match (handle.data(), handle.fragment()) {
+        (Data::Tree(tree), _) => Some(tree.clone()),
+        (data, Some(fragment)) => {
+            let source = svg::extract_fragment(&data.source()?, fragment)?;
+
+            usvg::Tree::from_str(&source, options).ok()
+        }
+        (Data::Path(path), None) => {
+            let contents = fs::read_to_string(path).ok()?;
+
+            usvg::Tree::from_str(&contents, options).ok()
+        }
+        (Data::Bytes(bytes), None) => usvg::Tree::from_data(bytes, options).ok(),
+    }
+}
+
+/// Parses a frame of the SMIL [`Animation`] of the SVG of the given [`Handle`]
+/// with the given [`usvg::Options`].
+///
+/// If the [`Handle`] points to a fragment, only the referenced element is kept.
+pub fn parse_frame(
+    handle: &Handle,
+    frame: &str,
+    options: &usvg::Options<'_>,
+) -> Option<usvg::Tree> {
+    match handle.fragment() {
+        Some(fragment) => {
+            let source = svg::extract_fragment(frame, fragment)?;
+
+            usvg::Tree::from_str(&source, options).ok()
+        }
+        None => usvg::Tree::from_str(frame, options).ok(),
+    }
+}
+
+/// Builds the style sheet injected into the SVG of the given [`Handle`].
+///
+/// If some `current_color` is provided, it is used to resolve `currentColor`.
+/// The styles of the [`Handle`] come last, so they can override it.
+pub fn style_sheet(handle: &Handle, current_color: Option<Color>) -> Option<String> {
+    let current_color = current_color.map(|color| {
+        let [r, g, b, _] = color.into_rgba8();
+
+        format!("svg {{ color: rgba({r}, {g}, {b}, {}) }}", color.a)
+    });
+
+    match (current_color, handle.styles()) {
+        (Some(current_color), Some(styles)) => Some(format!("{current_color}\n{styles}")),
+        (current_color, styles) => current_color.or_else(|| styles.map(str::to_owned)),
+    }
+}
@@ -0,0 +1,123 @@
+use crate::text;
+
+use resvg::usvg::fontdb;
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// The fonts used to draw the text of vector graphics.
+///
+/// The fonts loaded by the application—with `font::load` or in its settings—are
+/// always available and take precedence over any system fonts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fonts {
+    /// Whether the fonts installed in the system should be loaded as a fallback.
+    ///
+    /// Defaults to `true`.
+    pub system: bool,
+
+    /// The font family used for the `sans-serif` generic family.
+    ///
+    /// Defaults to Fira Sans, if the `fira-sans` feature is enabled.
+    pub sans_serif: Option<String>,
+
+    /// The font family used for the `serif` generic family.
+    pub serif: Option<String>,
+
+    /// The font family used for the `monospace` generic family.
+    pub monospace: Option<String>,
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        Self {
+            system: true,
+            sans_serif: cfg!(feature = "fira-sans").then(|| String::from("Fira Sans")),
+            serif: None,
+            monospace: None,
+        }
+    }
+}
+
+/// Configures the [`Fonts`] used to draw the text of vector graphics.
+///
+/// Vector graphics that were already parsed are not affected.
+pub fn configure_fonts(fonts: Fonts) {
+    let mut state = state().lock().expect("Lock SVG fonts");
+
+    if state.fonts != fonts {
+        state.fonts = fonts;
+        state.database = None;
+    }
+}
+
+/// Returns the font database used to draw the text of vector graphics.
+///
+/// The database is rebuilt whenever a new font is loaded by the application.
+pub fn font_database() -> Arc<fontdb::Database> {
+    let font_system = text::font_system().read().expect("Read font system");
+    let version = font_system.version();
+
+    let mut state = state().lock().expect("Lock SVG fonts");
+
+    if let Some((current, database)) = &state.database
+        && *current == version
+    {
+        return database.clone();
+    }
+
+    let mut database = fontdb::Database::new();
+
+    // Fonts are matched in loading order, so application fonts come first
+    for source in font_system.sources() {
+        let _ = database.load_font_source(fontdb::Source::Binary(source.clone()));
+    }
+
+    if state.fonts.system {
+        let system = state.system.get_or_insert_with(|| {
+            let mut system = fontdb::Database::new();
+            system.load_system_fonts();
+
+            system
+        });
+
+        // Collections are loaded along with their first face
+        for face in system.faces().filter(|face| face.index == 0) {
+            let _ = database.load_font_source(face.source.clone());
+        }
+    }
+
+    if let Some(family) = &state.fonts.sans_serif {
+        database.set_sans_serif_family(family);
+    }
+
+    if let Some(family) = &state.fonts.serif {
+        database.set_serif_family(family);
+    }
+
+    if let Some(family) = &state.fonts.monospace {
+        database.set_monospace_family(family);
+    }
+
+    let database = Arc::new(database);
+    state.database = Some((version, database.clone()));
+
+    database
+}
+
+struct State {
+    fonts: Fonts,
+    database: Option<(text::Version, Arc<fontdb::Database>)>,
+    system: Option<fontdb::Database>,
+}
+
+fn state() -> &'static Mutex<State> {
+    static STATE: OnceLock<Mutex<State>> = OnceLock::new();
+
+    STATE.get_or_init(|| {
+        Mutex::new(State {
+            fonts: Fonts::default(),
+            database: None,
+            system: None,
+        })
+    })
+}
@@ -116,16 +116,20 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
     static FONT_SYSTEM: OnceLock<RwLock<FontSystem>> = OnceLock::new();
 
     FONT_SYSTEM.get_or_init(|| {
+        let sources: Vec<Arc<dyn AsRef<[u8]> + Send + Sync>> = vec![
+            Arc::new(include_bytes!("../fonts/Iced-Icons.ttf").as_slice()),
+            #[cfg(feature = "fira-sans")]
+            Arc::new(include_bytes!("../fonts/FiraSans-Regular.ttf").as_slice()),
+        ];
+
         RwLock::new(FontSystem {
-            raw: cosmic_text::FontSystem::new_with_fonts([
-                cosmic_text::fontdb::Source::Binary(Arc::new(
-                    include_bytes!("../fonts/Iced-Icons.ttf").as_slice(),
-                )),
-                #[cfg(feature = "fira-sans")]
-                cosmic_text::fontdb::Source::Binary(Arc::new(
-                    include_bytes!("../fonts/FiraSans-Regular.ttf").as_slice(),
-                )),
-            ]),
+            raw: cosmic_text::FontSystem::new_with_fonts(
+                sources
+                    .iter()
+                    .cloned()
+                    .map(cosmic_text::fontdb::Source::Binary),
+            ),
+            sources,
             loaded_fonts: HashSet::new(),
             version: Version::default(),
         })
@@ -135,6 +139,7 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
 /// A set of system fonts.
 pub struct FontSystem {
     raw: cosmic_text::FontSystem,
+    sources: Vec<Arc<dyn AsRef<[u8]> + Send + Sync>>,
     loaded_fonts: HashSet<usize>,
     version: Version,
 }
@@ -155,16 +160,23 @@ impl FontSystem {
             }
         }
 
+        let source: Arc<dyn AsRef<[u8]> + Send + Sync> = Arc::new(bytes.into_owned());
+
         let _ = self
             .raw
             .db_mut()
-            .load_font_source(cosmic_text::fontdb::Source::Binary(Arc::new(
-                bytes.into_owned(),
-            )));
+            .load_font_source(cosmic_text::fontdb::Source::Binary(source.clone()));
 
+        self.sources.push(source);
         self.version = Version(self.version.0 + 1);
     }
 
+    /// Returns the raw data of all the fonts loaded in the [`FontSystem`],
+    /// including the embedded ones; in loading order.
+    pub fn sources(&self) -> &[Arc<dyn AsRef<[u8]> + Send + Sync>] {
+        &self.sources
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -130,6 +130,53 @@ impl editor::Editor for Editor {
         self.buffer().lines.len()
     }
 
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let (start, end) = if (start.line, start.column) <= (end.line, end.column) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        // Lines above the scroll position may not be laid out
+        let topmost_line = buffer.scroll().line;
+
+        if end.line < topmost_line || start.line >= buffer.lines.len() {
+            return Vec::new();
+        }
+
+        let start = if start.line < topmost_line {
+            Position {
+                line: topmost_line,
+                column: 0,
+            }
+        } else {
+            start
+        };
+
+        range_bounds(
+            buffer,
+            cosmic_text::Cursor::new(start.line, start.column),
+            cosmic_text::Cursor::new(end.line, end.column),
+            internal.hint_factor,
+        )
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        let internal = self.internal();
+        let mut lines: Vec<(usize, f32)> = Vec::new();
+
+        for run in buffer_from_editor(&internal.editor).layout_runs() {
+            if lines.last().is_none_or(|(line, _)| *line != run.line_i) {
+                lines.push((run.line_i, run.line_top / internal.hint_factor));
+            }
+        }
+
+        lines
+    }
+
     fn copy(&self) -> Option<String> {
         self.internal().editor.copy_selection()
     }
@@ -146,48 +193,7 @@ impl editor::Editor for Editor {
 
         let cursor = match internal.editor.selection_bounds() {
             Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset = visual_lines_offset(start.line, buffer);
-
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(
-                                Rectangle {
-                                    x,
-                                    width,
-                                    y: (visual_line as i32 + visual_lines_offset) as f32
-                                        * line_height
-                                        - buffer.scroll().vertical,
-                                    height: line_height,
-                                } * (1.0 / internal.hint_factor),
-                            )
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                Selection::Range(regions)
+                Selection::Range(range_bounds(buffer, start, end, internal.hint_factor))
             }
             _ => {
                 let line_height = buffer.metrics().line_height;
@@ -455,6 +461,32 @@ impl editor::Editor for Editor {
                         },
                     );
                 }
+                Action::MoveTo(cursor) => {
+                    editor.set_selection(cosmic_text::Selection::None);
+                    editor.set_cursor(cosmic_text::Cursor::new(
+                        cursor.position.line,
+                        cursor.position.column,
+                    ));
+
+                    if let Some(selection) = cursor.selection {
+                        editor.set_selection(cosmic_text::Selection::Normal(
+                            cosmic_text::Cursor::new(selection.line, selection.column),
+                        ));
+                    }
+                }
+
+                // The edit history, the additional cursors, and the search
+                // are kept by the owner of the editor
+                Action::Undo
+                | Action::Redo
+                | Action::AddCursor(_)
+                | Action::SelectBlock { .. }
+                | Action::SelectNextOccurrence
+                | Action::Find(_)
+                | Action::FindNext
+                | Action::FindPrevious
+                | Action::Replace(_)
+                | Action::ReplaceAll(_) => {}
             }
         });
     }
@@ -801,6 +833,53 @@ fn highlight_line(
     })
 }
 
+fn range_bounds(
+    buffer: &cosmic_text::Buffer,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+    hint_factor: f32,
+) -> Vec<Rectangle> {
+    let line_height = buffer.metrics().line_height;
+    let selected_lines = end.line - start.line + 1;
+
+    let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+    buffer
+        .lines
+        .iter()
+        .skip(start.line)
+        .take(selected_lines)
+        .enumerate()
+        .flat_map(|(i, line)| {
+            highlight_line(
+                line,
+                if i == 0 { start.index } else { 0 },
+                if i == selected_lines - 1 {
+                    end.index
+                } else {
+                    line.text().len()
+                },
+            )
+        })
+        .enumerate()
+        .filter_map(|(visual_line, (x, width))| {
+            if width > 0.0 {
+                Some(
+                    Rectangle {
+                        x,
+                        width,
+                        y: (visual_line as i32 + visual_lines_offset) as f32 * line_height
+                            - buffer.scroll().vertical,
+                        height: line_height,
+                    } * (1.0 / hint_factor),
+                )
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     let scroll = buffer.scroll();
 
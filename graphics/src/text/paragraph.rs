@@ -374,6 +374,52 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+
+        let cursor = buffer.hit(point.x * self.0.hint_factor, point.y * self.0.hint_factor)?;
+
+        Some(line_offset(buffer, cursor.line) + cursor.index)
+    }
+
+    fn range_bounds(&self, start: usize, end: usize) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+        let (start, end) = (start.min(end), start.max(end));
+
+        let mut bounds = Vec::new();
+
+        for run in buffer.layout_runs() {
+            let offset = line_offset(buffer, run.line_i);
+
+            if offset > end {
+                break;
+            }
+
+            let from = start.saturating_sub(offset);
+            let to = end - offset;
+
+            let (left, right) = run
+                .glyphs
+                .iter()
+                .filter(|glyph| glyph.start < to && glyph.end > from)
+                .fold(
+                    (f32::INFINITY, f32::NEG_INFINITY),
+                    |(left, right), glyph| (left.min(glyph.x), right.max(glyph.x + glyph.w)),
+                );
+
+            if left < right {
+                bounds.push(
+                    Rectangle::new(
+                        Point::new(left, run.line_top),
+                        Size::new(right - left, run.line_height),
+                    ) * (1.0 / self.0.hint_factor),
+                );
+            }
+        }
+
+        bounds
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -496,3 +542,19 @@ impl PartialEq for Weak {
         }
     }
 }
+
+pub(crate) fn line_offset(buffer: &cosmic_text::Buffer, line: usize) -> usize {
+    buffer
+        .lines
+        .iter()
+        .take(line)
+        .map(|line| {
+            line.text().len()
+                + match line.ending() {
+                    cosmic_text::LineEnding::Lf | cosmic_text::LineEnding::Cr => 1,
+                    cosmic_text::LineEnding::CrLf | cosmic_text::LineEnding::LfCr => 2,
+                    cosmic_text::LineEnding::None => 0,
+                }
+        })
+        .sum()
+}
@@ -2,6 +2,7 @@
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
+use crate::core::time::Duration;
 use crate::core::{
     self, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg, Transformation,
 };
@@ -42,6 +43,19 @@ where
         delegate!(self, renderer, renderer.fill_quad(quad, background.into()));
     }
 
+    fn blur_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+        blur_radius: f32,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.blur_backdrop(bounds, border_radius, blur_radius)
+        );
+    }
+
     fn start_layer(&mut self, bounds: Rectangle) {
         delegate!(self, renderer, renderer.start_layer(bounds));
     }
@@ -70,6 +84,34 @@ where
         delegate!(self, renderer, renderer.allocate_image(handle, callback));
     }
 
+    fn prepare_svg(
+        &mut self,
+        handle: &svg::Handle,
+        callback: impl FnOnce(Size<u32>) + Send + 'static,
+    ) {
+        delegate!(self, renderer, renderer.prepare_svg(handle, callback));
+    }
+
+    fn set_image_cache_budget(&mut self, budget: usize) {
+        delegate!(self, renderer, renderer.set_image_cache_budget(budget));
+    }
+
+    fn pin_image(&mut self, id: image::Id, pinned: bool) {
+        delegate!(self, renderer, renderer.pin_image(id, pinned));
+    }
+
+    fn evict_image(&mut self, id: image::Id) {
+        delegate!(self, renderer, renderer.evict_image(id));
+    }
+
+    fn trim_image_cache(&mut self) {
+        delegate!(self, renderer, renderer.trim_image_cache());
+    }
+
+    fn image_cache_stats(&self) -> image::CacheStats {
+        delegate!(self, renderer, renderer.image_cache_stats())
+    }
+
     fn hint(&mut self, scale_factor: f32) {
         delegate!(self, renderer, renderer.hint(scale_factor));
     }
@@ -181,6 +223,18 @@ where
         delegate!(self, renderer, renderer.measure_image(handle))
     }
 
+    fn measure_image_animation(&self, handle: &Self::Handle) -> Option<Duration> {
+        delegate!(self, renderer, renderer.measure_image_animation(handle))
+    }
+
+    fn image_error(&self, handle: &Self::Handle) -> Option<image::Error> {
+        delegate!(self, renderer, renderer.image_error(handle))
+    }
+
+    fn decode_image(&self, handle: &Self::Handle) -> Option<image::Handle> {
+        delegate!(self, renderer, renderer.decode_image(handle))
+    }
+
     fn draw_image(&mut self, image: Image<A::Handle>, bounds: Rectangle, clip_bounds: Rectangle) {
         delegate!(
             self,
@@ -199,6 +253,22 @@ where
         delegate!(self, renderer, renderer.measure_svg(handle))
     }
 
+    fn measure_svg_animation(&self, handle: &svg::Handle) -> Option<Duration> {
+        delegate!(self, renderer, renderer.measure_svg_animation(handle))
+    }
+
+    fn is_svg_pending(&self, handle: &svg::Handle) -> bool {
+        delegate!(self, renderer, renderer.is_svg_pending(handle))
+    }
+
+    fn is_svg_invalid(&self, handle: &svg::Handle) -> bool {
+        delegate!(self, renderer, renderer.is_svg_invalid(handle))
+    }
+
+    fn rasterize_svg(&self, svg: &Svg, size: Size<u32>) -> Option<image::Handle> {
+        delegate!(self, renderer, renderer.rasterize_svg(svg, size))
+    }
+
     fn draw_svg(&mut self, svg: Svg, bounds: Rectangle, clip_bounds: Rectangle) {
         delegate!(self, renderer, renderer.draw_svg(svg, bounds, clip_bounds));
     }
@@ -432,6 +502,7 @@ where
 #[cfg(feature = "geometry")]
 mod geometry {
     use super::Renderer;
+    use crate::core::window::Screenshot;
     use crate::core::{Point, Radians, Rectangle, Size, Svg, Vector};
     use crate::graphics::cache::{self, Cached};
     use crate::graphics::geometry::{self, Fill, Image, Path, Stroke, Text};
@@ -610,6 +681,10 @@ mod geometry {
             delegate!(self, frame, frame.scale_nonuniform(scale));
         }
 
+        fn skew(&mut self, x: impl Into<Radians>, y: impl Into<Radians>) {
+            delegate!(self, frame, frame.skew(x, y));
+        }
+
         fn into_geometry(self) -> Self::Geometry {
             match self {
                 Frame::Primary(frame) => Geometry::Primary(frame.into_geometry()),
@@ -617,6 +692,38 @@ mod geometry {
             }
         }
     }
+
+    impl<A, B> geometry::frame::Export for Frame<A, B>
+    where
+        A: geometry::frame::Backend,
+        B: geometry::frame::Export,
+    {
+        fn exportable(bounds: Rectangle) -> Self {
+            Frame::Secondary(B::exportable(bounds))
+        }
+
+        fn into_svg(self) -> String {
+            match self {
+                Frame::Primary(_) => {
+                    log::warn!("Exporting is not supported by the primary renderer.");
+
+                    String::new()
+                }
+                Frame::Secondary(frame) => frame.into_svg(),
+            }
+        }
+
+        fn into_pixels(self, size: Size<u32>) -> Screenshot {
+            match self {
+                Frame::Primary(_) => {
+                    log::warn!("Exporting is not supported by the primary renderer.");
+
+                    Screenshot::new(Vec::new(), Size::new(0, 0), 1.0)
+                }
+                Frame::Secondary(frame) => frame.into_pixels(size),
+            }
+        }
+    }
 }
 
 impl<A, B> renderer::Headless for Renderer<A, B>
@@ -3,13 +3,28 @@ use crate::core::image::Handle;
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
-pub use crate::core::image::{Allocation, Error};
+pub use crate::core::image::{Allocation, CacheStats, Error, Id};
 
 /// An image action.
 #[derive(Debug)]
 pub enum Action {
     /// Allocates the given [`Handle`].
     Allocate(Handle, oneshot::Sender<Result<Allocation, Error>>),
+
+    /// Sets the memory budget for the unused entries of the image caches, in bytes.
+    SetCacheBudget(usize),
+
+    /// Pins or unpins the image with the given [`Id`].
+    Pin(Id, bool),
+
+    /// Evicts the image with the given [`Id`] from the image caches.
+    Evict(Id),
+
+    /// Evicts all the unused and unpinned entries from the image caches.
+    TrimCache,
+
+    /// Retrieves the [`CacheStats`] of the image caches.
+    CacheStats(oneshot::Sender<CacheStats>),
 }
 
 /// Allocates an image [`Handle`].
@@ -20,3 +35,50 @@ pub enum Action {
 pub fn allocate(handle: impl Into<Handle>) -> Task<Result<Allocation, Error>> {
     task::oneshot(|sender| crate::Action::Image(Action::Allocate(handle.into(), sender)))
 }
+
+/// Sets the memory budget for the unused entries of the image caches, in bytes.
+///
+/// Images and rasterized SVGs that are not drawn anymore are kept in memory,
+/// evicting the least recently used first, as long as they fit in the budget.
+/// This avoids loading or rasterizing them again when they come back into view.
+///
+/// By default, the budget is zero and unused entries are dropped right away.
+pub fn set_cache_budget<T>(bytes: usize) -> Task<T> {
+    task::effect(crate::Action::Image(Action::SetCacheBudget(bytes)))
+}
+
+/// Pins the image or SVG with the given [`Id`], so that it is never evicted
+/// from the image caches until unpinned.
+///
+/// Both [`image::Handle`] and [`svg::Handle`] expose their [`Id`].
+///
+/// [`image::Handle`]: crate::core::image::Handle
+/// [`svg::Handle`]: crate::core::svg::Handle
+pub fn pin<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Pin(id, true)))
+}
+
+/// Unpins the image or SVG with the given [`Id`].
+pub fn unpin<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Pin(id, false)))
+}
+
+/// Evicts the image or SVG with the given [`Id`] from the image caches.
+///
+/// Pinned images are not evicted.
+pub fn evict<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Image(Action::Evict(id)))
+}
+
+/// Evicts all the unused and unpinned entries from the image caches,
+/// regardless of the budget.
+pub fn trim_cache<T>() -> Task<T> {
+    task::effect(crate::Action::Image(Action::TrimCache))
+}
+
+/// Retrieves the [`CacheStats`] of the image caches.
+///
+/// This can be useful to debug the memory usage of an application.
+pub fn cache_stats() -> Task<CacheStats> {
+    task::oneshot(|sender| crate::Action::Image(Action::CacheStats(sender)))
+}
@@ -13,6 +13,7 @@ pub mod clipboard;
 pub mod font;
 pub mod image;
 pub mod keyboard;
+pub mod svg;
 pub mod system;
 pub mod task;
 pub mod user_interface;
@@ -59,6 +60,9 @@ pub enum Action<T> {
     /// Run an image action.
     Image(image::Action),
 
+    /// Run an svg action.
+    Svg(svg::Action),
+
     /// Poll any resources that may have pending computations.
     Tick,
 
@@ -87,6 +91,7 @@ impl<T> Action<T> {
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
             Action::Image(action) => Err(Action::Image(action)),
+            Action::Svg(action) => Err(Action::Svg(action)),
             Action::Tick => Err(Action::Tick),
             Action::Reload => Err(Action::Reload),
             Action::Exit => Err(Action::Exit),
@@ -113,6 +118,7 @@ where
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
             Action::Image(_) => write!(f, "Action::Image"),
+            Action::Svg(action) => write!(f, "Action::Svg({action:?})"),
             Action::Tick => write!(f, "Action::Tick"),
             Action::Reload => write!(f, "Action::Reload"),
             Action::Exit => write!(f, "Action::Exit"),
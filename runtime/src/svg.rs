@@ -0,0 +1,21 @@
+//! Prepare vector graphics ahead of time to avoid drawing placeholders.
+use crate::core::Size;
+use crate::core::svg::Handle;
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+/// An svg action.
+#[derive(Debug)]
+pub enum Action {
+    /// Prepares the given [`Handle`].
+    Prepare(Handle, oneshot::Sender<Size<u32>>),
+}
+
+/// Prepares an SVG [`Handle`] in the background and produces its dimensions.
+///
+/// Large SVGs may be parsed concurrently by the renderer and, until they are
+/// ready, nothing—or a placeholder—is drawn in their place. Once the returned
+/// [`Task`] completes, using the [`Handle`] will draw the SVG in the next frame.
+pub fn prepare(handle: impl Into<Handle>) -> Task<Size<u32>> {
+    task::oneshot(|sender| crate::Action::Svg(Action::Prepare(handle.into(), sender)))
+}
@@ -1,4 +1,5 @@
 //! Change internal widget state.
+use crate::core::Rectangle;
 use crate::core::widget::Id;
 use crate::core::widget::operation;
 use crate::task;
@@ -38,6 +39,23 @@ pub fn scroll_by<T>(id: impl Into<Id>, offset: AbsoluteOffset) -> Task<T> {
     )))
 }
 
+/// Smoothly scrolls the scrollable with the given [`Id`] until the widget with
+/// the `target` [`Id`] is aligned with the start of its viewport.
+pub fn scroll_to_widget<T>(id: impl Into<Id>, target: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_to_widget(
+        id.into(),
+        target.into(),
+    )))
+}
+
+/// Smoothly scrolls every scrollable containing the widget with the given [`Id`]
+/// just enough to bring it into view.
+pub fn scroll_into_view<T>(id: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_into_view(
+        id.into(),
+    )))
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_previous()))
@@ -53,6 +71,15 @@ pub fn is_focused(id: impl Into<Id>) -> Task<bool> {
     task::widget(operation::focusable::is_focused(id.into()))
 }
 
+/// Returns the visible bounds of the widget with the given [`Id`]; that is,
+/// its bounds translated by the scrollables containing it and clipped to
+/// their viewports.
+///
+/// Produces `None` if the widget cannot be found or if it is scrolled out of view.
+pub fn visible_bounds(id: impl Into<Id>) -> Task<Option<Rectangle>> {
+    task::widget(operation::visible_bounds(id.into()))
+}
+
 /// Focuses the widget with the given [`Id`].
 pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus(id.into())))
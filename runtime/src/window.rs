@@ -1,9 +1,11 @@
 //! Build window-based GUI applications.
+use crate::core::image;
 use crate::core::time::Instant;
+use crate::core::window::screenshot;
 use crate::core::window::{
     Direction, Event, Icon, Id, Level, Mode, Screenshot, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::futures::Subscription;
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
@@ -459,6 +461,56 @@ pub fn screenshot(id: Id) -> Task<Screenshot> {
     task::oneshot(move |channel| crate::Action::Window(Action::Screenshot(id, channel)))
 }
 
+/// Captures a [`Screenshot`] of the given region of the window, in logical pixels.
+///
+/// The region is clipped to the viewport of the window.
+pub fn screenshot_region(
+    id: Id,
+    region: Rectangle,
+) -> Task<Result<Screenshot, screenshot::CropError>> {
+    screenshot(id).map(move |screenshot| screenshot.crop_logical(region))
+}
+
+/// Captures a [`Screenshot`] from the window and encodes it as a PNG image.
+///
+/// The encoding is performed by the executor of the application.
+#[cfg(feature = "png")]
+pub fn screenshot_png(id: Id) -> Task<Result<crate::core::Bytes, screenshot::EncodeError>> {
+    screenshot(id).then(|screenshot| Task::future(async move { screenshot.encode_png() }))
+}
+
+/// Captures an image of the widget with the given [`widget::Id`] in the window
+/// with the given [`Id`].
+///
+/// The widget is captured as it is currently displayed in the window; so only
+/// its visible bounds are captured—including anything drawn on top of it.
+/// This can be useful to create drag previews, thumbnails, or to export
+/// some content as an image.
+///
+/// Produces `None` if the widget cannot be found or if it is not visible.
+///
+/// [`widget::Id`]: crate::core::widget::Id
+pub fn capture_widget(
+    window: Id,
+    widget: impl Into<crate::core::widget::Id>,
+) -> Task<Option<image::Handle>> {
+    crate::widget::operation::visible_bounds(widget).then(move |bounds| {
+        let Some(bounds) = bounds else {
+            return Task::done(None);
+        };
+
+        screenshot_region(window, bounds).map(|capture| {
+            let capture = capture.ok()?;
+
+            Some(image::Handle::from_rgba(
+                capture.size.width,
+                capture.size.height,
+                capture.rgba,
+            ))
+        })
+    })
+}
+
 /// Enables mouse passthrough for the given window.
 ///
 /// This disables mouse events for the window and passes mouse events
@@ -532,9 +532,9 @@ pub use crate::core::gradient;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Animation, Background, Border, Color, ContentFit, Degrees, Function, Gradient,
-    Length, Never, Padding, Pixels, Point, Radians, Rectangle, Rotation, Settings, Shadow, Size,
-    Theme, Transformation, Vector, never,
+    Alignment, Animated, Animation, Background, Border, Color, ContentFit, Degrees, Function,
+    Gradient, Length, Never, Padding, Pixels, Point, Radians, Rectangle, Rotation, Settings,
+    Shadow, Size, Theme, Transformation, Vector, never,
 };
 pub use crate::program::Preset;
 pub use crate::program::message;
@@ -627,11 +627,240 @@ pub mod widget {
     pub use iced_runtime::widget::*;
     pub use iced_widget::*;
 
+    pub mod collapsible {
+        //! Collapsibles display a header that expands and collapses some content.
+        pub use iced_widget::collapsible::*;
+
+        use crate::runtime::{Action, Task, task};
+        use crate::widget::Id;
+
+        /// Expands the [`Collapsible`] with the given [`Id`].
+        pub fn expand<T>(id: impl Into<Id>) -> Task<T> {
+            task::effect(Action::widget(operation::expand(id.into())))
+        }
+
+        /// Collapses the [`Collapsible`] with the given [`Id`].
+        pub fn collapse<T>(id: impl Into<Id>) -> Task<T> {
+            task::effect(Action::widget(operation::collapse(id.into())))
+        }
+
+        /// Toggles the [`Collapsible`] with the given [`Id`].
+        pub fn toggle<T>(id: impl Into<Id>) -> Task<T> {
+            task::effect(Action::widget(operation::toggle(id.into())))
+        }
+    }
+
+    pub mod file_browser {
+        //! File browsers let users navigate the file system and pick some entries.
+        pub use iced_widget::file_browser::*;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        use crate::futures::channel::oneshot;
+        #[cfg(not(target_arch = "wasm32"))]
+        use crate::runtime::Task;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        use std::io;
+        #[cfg(not(target_arch = "wasm32"))]
+        use std::path::PathBuf;
+        #[cfg(not(target_arch = "wasm32"))]
+        use std::sync::Arc;
+        #[cfg(not(target_arch = "wasm32"))]
+        use std::thread;
+
+        /// Reads the entries of the given directory in a background thread.
+        ///
+        /// This is only available on native platforms, since there are no
+        /// threads on the Web.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn read(directory: impl Into<PathBuf>) -> Task<Result<Listing, Error>> {
+            let directory = directory.into();
+
+            Task::future(async move {
+                let (sender, receiver) = oneshot::channel();
+
+                let _ = thread::spawn(move || {
+                    let _ = sender.send(list(&directory));
+                });
+
+                receiver.await.unwrap_or_else(|_| {
+                    Err(Error::Inaccessible(Arc::new(io::Error::other(
+                        "the directory reader stopped unexpectedly",
+                    ))))
+                })
+            })
+        }
+    }
+
     #[cfg(feature = "image")]
     pub mod image {
         //! Images display raster graphics in different formats (PNG, JPG, etc.).
-        pub use iced_runtime::image::{Allocation, Error, allocate};
+        pub use iced_runtime::image::{
+            Allocation, CacheStats, Error, allocate, cache_stats, evict, pin, set_cache_budget,
+            trim_cache, unpin,
+        };
         pub use iced_widget::image::*;
+
+        pub mod viewer {
+            //! Zoom and pan on an image.
+            pub use iced_widget::image::viewer::*;
+
+            use crate::core::Point;
+            use crate::runtime::{Action, Task, task};
+            use crate::widget::Id;
+
+            /// Zooms the [`Viewer`] with the given [`Id`] to the given scale,
+            /// around its center.
+            pub fn zoom_to<T>(id: impl Into<Id>, scale: f32) -> Task<T> {
+                task::effect(Action::widget(operation::zoom_to(id.into(), scale)))
+            }
+
+            /// Pans the [`Viewer`] with the given [`Id`] so the given point of its
+            /// image—in pixels—is displayed at its center.
+            pub fn center_on<T>(id: impl Into<Id>, point: impl Into<Point>) -> Task<T> {
+                task::effect(Action::widget(operation::center_on(
+                    id.into(),
+                    point.into(),
+                )))
+            }
+
+            /// Fits the image of the [`Viewer`] with the given [`Id`] back into
+            /// its bounds.
+            pub fn fit<T>(id: impl Into<Id>) -> Task<T> {
+                task::effect(Action::widget(operation::fit(id.into())))
+            }
+        }
+    }
+
+    #[cfg(feature = "svg")]
+    pub mod svg {
+        //! Svg widgets display vector graphics in your application.
+        pub use iced_runtime::image::{evict, pin, unpin};
+        pub use iced_runtime::svg::prepare;
+        pub use iced_widget::svg::*;
+    }
+
+    #[cfg(feature = "web_view")]
+    pub mod web_view {
+        //! Web views embed a native web view of the platform in your application.
+        pub use iced_widget::web_view::*;
+
+        use crate::Subscription;
+        use crate::futures::channel::{mpsc, oneshot};
+        use crate::runtime::{Task, window};
+
+        use std::collections::BTreeMap;
+        use std::sync::Mutex;
+
+        static WINDOWS: Mutex<BTreeMap<Id, window::Id>> = Mutex::new(BTreeMap::new());
+        static LISTENERS: Mutex<Vec<mpsc::UnboundedSender<(Id, Event)>>> = Mutex::new(Vec::new());
+
+        /// Opens a new web view with the given [`Id`] and [`Settings`] in the
+        /// window with the given id.
+        ///
+        /// The web view stays hidden until a [`WebView`] with its [`Id`] is
+        /// part of the view.
+        pub fn open(window: window::Id, id: Id, settings: Settings) -> Task<Result<(), Error>> {
+            let _ = WINDOWS
+                .lock()
+                .expect("Lock web view windows")
+                .insert(id, window);
+
+            window::run(window, move |handle| {
+                host::open(handle, id, settings, notify)
+            })
+        }
+
+        /// Closes the web view with the given [`Id`].
+        pub fn close(id: Id) -> Task<Result<(), Error>> {
+            let task = run(id, host::close);
+
+            let _ = WINDOWS.lock().expect("Lock web view windows").remove(&id);
+
+            task
+        }
+
+        /// Navigates the web view with the given [`Id`] to the given URL.
+        pub fn navigate(id: Id, url: impl Into<String>) -> Task<Result<(), Error>> {
+            let url = url.into();
+
+            run(id, move |id| host::navigate(id, &url))
+        }
+
+        /// Evaluates the given JavaScript in the web view with the given [`Id`],
+        /// producing the JSON of its result.
+        pub fn evaluate(id: Id, script: impl Into<String>) -> Task<Result<String, Error>> {
+            let script = script.into();
+
+            run(id, move |id| {
+                let (sender, receiver) = oneshot::channel();
+
+                host::evaluate(id, &script, move |result| {
+                    let _ = sender.send(result);
+                })
+                .map(|()| receiver)
+            })
+            .then(|evaluation| match evaluation {
+                Ok(receiver) => Task::future(async move {
+                    receiver
+                        .await
+                        .map_err(|_| Error::Platform("the script was cancelled".to_owned()))
+                }),
+                Err(error) => Task::done(Err(error)),
+            })
+        }
+
+        /// Posts the given message to the page of the web view with the given
+        /// [`Id`].
+        ///
+        /// The page receives it as the `data` of an `iced` event of `window`.
+        pub fn post(id: Id, message: impl Into<String>) -> Task<Result<(), Error>> {
+            let message = message.into();
+
+            run(id, move |id| host::post(id, &message))
+        }
+
+        /// Listens to the [`Event`] of all the web views.
+        pub fn listen() -> Subscription<(Id, Event)> {
+            Subscription::run(|| {
+                let (sender, receiver) = mpsc::unbounded();
+
+                LISTENERS
+                    .lock()
+                    .expect("Lock web view listeners")
+                    .push(sender);
+
+                receiver
+            })
+        }
+
+        /// Runs the given function in the window of the web view with the given
+        /// [`Id`].
+        fn run<T>(
+            id: Id,
+            f: impl FnOnce(Id) -> Result<T, Error> + Send + 'static,
+        ) -> Task<Result<T, Error>>
+        where
+            T: Send + 'static,
+        {
+            let window = WINDOWS
+                .lock()
+                .expect("Lock web view windows")
+                .get(&id)
+                .copied();
+
+            match window {
+                Some(window) => window::run(window, move |_| f(id)),
+                None => Task::done(Err(Error::NotFound)),
+            }
+        }
+
+        fn notify(id: Id, event: Event) {
+            LISTENERS
+                .lock()
+                .expect("Lock web view listeners")
+                .retain(|listener| listener.unbounded_send((id, event.clone())).is_ok());
+        }
     }
 
     // We hide the re-exported modules by `iced_widget`
@@ -260,6 +260,10 @@ impl<P: Program + 'static> Emulator<P> {
                     // TODO
                     dbg!(action);
                 }
+                iced_runtime::Action::Svg(action) => {
+                    // TODO
+                    dbg!(action);
+                }
                 iced_runtime::Action::Tick => {
                     // TODO
                 }
@@ -880,6 +880,7 @@ where
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
                         },
+                        ..slider::default(theme, status)
                     }
                 })
         )
@@ -12,6 +12,8 @@ pub struct Engine {
     pub(crate) raster_pipeline: crate::raster::Pipeline,
     #[cfg(feature = "svg")]
     pub(crate) vector_pipeline: crate::vector::Pipeline,
+    #[cfg(any(feature = "image", feature = "svg"))]
+    pub(crate) eviction: crate::graphics::image::Eviction,
 }
 
 impl Engine {
@@ -22,6 +24,8 @@ impl Engine {
             raster_pipeline: crate::raster::Pipeline::new(),
             #[cfg(feature = "svg")]
             vector_pipeline: crate::vector::Pipeline::new(),
+            #[cfg(any(feature = "image", feature = "svg"))]
+            eviction: crate::graphics::image::Eviction::default(),
         }
     }
 
@@ -64,16 +68,20 @@ impl Engine {
         let shadow = quad.shadow;
 
         if shadow.color.a > 0.0 {
+            let shadow_extent = (shadow.blur_radius + shadow.spread).max(0.0);
+
             let shadow_bounds = Rectangle {
-                x: quad.bounds.x + shadow.offset.x - shadow.blur_radius,
-                y: quad.bounds.y + shadow.offset.y - shadow.blur_radius,
-                width: quad.bounds.width + shadow.blur_radius * 2.0,
-                height: quad.bounds.height + shadow.blur_radius * 2.0,
+                x: quad.bounds.x + shadow.offset.x - shadow_extent,
+                y: quad.bounds.y + shadow.offset.y - shadow_extent,
+                width: quad.bounds.width + shadow_extent * 2.0,
+                height: quad.bounds.height + shadow_extent * 2.0,
             } * transformation;
 
+            let spread = shadow.spread * transformation.scale_factor();
+
             let radii = fill_border_radius
                 .into_iter()
-                .map(|radius| radius * transformation.scale_factor())
+                .map(|radius| (radius * transformation.scale_factor() + spread).max(0.0))
                 .collect::<Vec<_>>();
             let (x, y, width, height) = (
                 shadow_bounds.x as u32,
@@ -87,7 +95,11 @@ impl Engine {
             let colors = (y..y + height)
                 .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
                 .filter_map(|(x, y)| {
-                    tiny_skia::Size::from_wh(half_width, half_height).map(|size| {
+                    tiny_skia::Size::from_wh(
+                        (half_width + spread).max(0.0),
+                        (half_height + spread).max(0.0),
+                    )
+                    .map(|size| {
                         let shadow_distance = rounded_box_sdf(
                             Vector::new(
                                 x - physical_bounds.position().x
@@ -540,6 +552,8 @@ impl Engine {
                 self.raster_pipeline.draw(
                     &image.handle,
                     image.filter_method,
+                    image.color_filter,
+                    image.region,
                     *bounds,
                     image.opacity,
                     _pixels,
@@ -567,15 +581,8 @@ impl Engine {
                     center.y,
                 );
 
-                self.vector_pipeline.draw(
-                    &svg.handle,
-                    svg.color,
-                    *bounds,
-                    svg.opacity,
-                    _pixels,
-                    transform,
-                    clip_mask,
-                );
+                self.vector_pipeline
+                    .draw(svg, *bounds, _pixels, transform, clip_mask);
             }
             #[cfg(not(feature = "image"))]
             Image::Raster { .. } => {
@@ -592,10 +599,37 @@ impl Engine {
         self.text_pipeline.trim_cache();
 
         #[cfg(feature = "image")]
-        self.raster_pipeline.trim_cache();
+        self.raster_pipeline.trim_cache(&self.eviction);
 
         #[cfg(feature = "svg")]
-        self.vector_pipeline.trim_cache();
+        self.vector_pipeline.trim_cache(&self.eviction);
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.eviction.finish();
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    pub fn image_cache_stats(&self) -> crate::core::image::CacheStats {
+        #[cfg(feature = "image")]
+        let (images, image_bytes) = self.raster_pipeline.cache_stats();
+
+        #[cfg(not(feature = "image"))]
+        let (images, image_bytes) = (0, 0);
+
+        #[cfg(feature = "svg")]
+        let (vectors, vector_bytes) = self.vector_pipeline.cache_stats();
+
+        #[cfg(not(feature = "svg"))]
+        let (vectors, vector_bytes) = (0, 0);
+
+        crate::core::image::CacheStats {
+            images,
+            image_bytes,
+            vectors,
+            vector_bytes,
+            pinned: self.eviction.pinned(),
+            budget: self.eviction.budget(),
+        }
     }
 }
 
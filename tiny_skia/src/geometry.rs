@@ -1,5 +1,8 @@
+mod export;
+
 use crate::Primitive;
 use crate::core::text::LineHeight;
+use crate::core::window::Screenshot;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
@@ -61,6 +64,7 @@ pub struct Frame {
     primitives: Vec<Primitive>,
     images: Vec<graphics::Image>,
     text: Vec<Text>,
+    paints: Option<Vec<export::Paint>>,
 }
 
 impl Frame {
@@ -72,6 +76,17 @@ impl Frame {
             images: Vec::new(),
             text: Vec::new(),
             transform: tiny_skia::Transform::identity(),
+            paints: None,
+        }
+    }
+
+    fn record(&mut self, style: Style, dash: Option<(Vec<f32>, f32)>) {
+        if let Some(paints) = &mut self.paints {
+            paints.push(export::Paint {
+                style,
+                transform: self.transform,
+                dash,
+            });
         }
     }
 }
@@ -101,6 +116,7 @@ impl geometry::frame::Backend for Frame {
         };
 
         let fill = fill.into();
+        self.record(fill.style, None);
 
         let mut paint = into_paint(fill.style);
         paint.shader.transform(self.transform);
@@ -120,6 +136,7 @@ impl geometry::frame::Backend for Frame {
         };
 
         let fill = fill.into();
+        self.record(fill.style, None);
 
         let mut paint = tiny_skia::Paint {
             anti_alias: false,
@@ -142,6 +159,16 @@ impl geometry::frame::Backend for Frame {
         let stroke = stroke.into();
         let skia_stroke = into_stroke(&stroke);
 
+        self.record(
+            stroke.style,
+            (!stroke.line_dash.segments.is_empty()).then(|| {
+                (
+                    stroke.line_dash.segments.to_vec(),
+                    stroke.line_dash.offset as f32,
+                )
+            }),
+        );
+
         let mut paint = into_paint(stroke.style);
         paint.shader.transform(self.transform);
 
@@ -161,7 +188,14 @@ impl geometry::frame::Backend for Frame {
 
         let (scale_x, scale_y) = self.transform.get_scale();
 
-        if !self.transform.has_skew() && scale_x == scale_y && scale_x > 0.0 && scale_y > 0.0 {
+        let is_exportable = self.paints.is_some();
+
+        if !is_exportable
+            && !self.transform.has_skew()
+            && scale_x == scale_y
+            && scale_x > 0.0
+            && scale_y > 0.0
+        {
             let (bounds, size, line_height) = if self.transform.is_identity() {
                 (
                     Rectangle::new(text.position, Size::new(text.max_width, f32::INFINITY)),
@@ -229,13 +263,20 @@ impl geometry::frame::Backend for Frame {
     }
 
     fn draft(&mut self, clip_bounds: Rectangle) -> Self {
-        Self::new(clip_bounds)
+        Self {
+            paints: self.paints.as_ref().map(|_| Vec::new()),
+            ..Self::new(clip_bounds)
+        }
     }
 
     fn paste(&mut self, frame: Self) {
         self.primitives.extend(frame.primitives);
         self.text.extend(frame.text);
         self.images.extend(frame.images);
+
+        if let (Some(paints), Some(pasted)) = (&mut self.paints, frame.paints) {
+            paints.extend(pasted);
+        }
     }
 
     fn translate(&mut self, translation: Vector) {
@@ -260,6 +301,13 @@ impl geometry::frame::Backend for Frame {
         self.transform = self.transform.pre_scale(scale.x, scale.y);
     }
 
+    fn skew(&mut self, x: impl Into<Radians>, y: impl Into<Radians>) {
+        self.transform = self.transform.pre_concat(tiny_skia::Transform::from_skew(
+            x.into().0.tan(),
+            y.into().0.tan(),
+        ));
+    }
+
     fn into_geometry(self) -> Geometry {
         Geometry::Live {
             primitives: self.primitives,
@@ -298,6 +346,27 @@ impl geometry::frame::Backend for Frame {
     }
 }
 
+impl geometry::frame::Export for Frame {
+    fn exportable(bounds: Rectangle) -> Self {
+        Self {
+            paints: Some(Vec::new()),
+            ..Self::new(bounds)
+        }
+    }
+
+    fn into_svg(self) -> String {
+        export::svg(
+            self.clip_bounds,
+            &self.primitives,
+            self.paints.as_deref().unwrap_or_default(),
+        )
+    }
+
+    fn into_pixels(self, size: Size<u32>) -> Screenshot {
+        export::pixels(self.clip_bounds, &self.primitives, &self.images, size)
+    }
+}
+
 fn transform_rectangle(
     rectangle: Rectangle,
     transform: tiny_skia::Transform,
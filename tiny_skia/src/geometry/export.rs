@@ -0,0 +1,288 @@
+//! Export geometry as SVG documents and images.
+use crate::Primitive;
+use crate::core::window::Screenshot;
+use crate::core::{Color, Rectangle, Size, Transformation};
+use crate::engine::{self, Engine};
+use crate::graphics::geometry::Style;
+use crate::graphics::{self, Gradient};
+
+use std::fmt::Write;
+
+/// The original paint of a [`Primitive`], needed to export it.
+#[derive(Debug, Clone)]
+pub struct Paint {
+    pub style: Style,
+    pub transform: tiny_skia::Transform,
+    pub dash: Option<(Vec<f32>, f32)>,
+}
+
+/// Writes an SVG document with the given primitives.
+///
+/// The paints of the primitives are used to export their gradients and
+/// dashes, if available.
+pub fn svg(bounds: Rectangle, primitives: &[Primitive], paints: &[Paint]) -> String {
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="{x} {y} {width} {height}">"#,
+        x = bounds.x,
+        y = bounds.y,
+        width = bounds.width,
+        height = bounds.height,
+    );
+
+    for (i, primitive) in primitives.iter().enumerate() {
+        let original = paints.get(i);
+
+        match primitive {
+            Primitive::Fill { path, paint, rule } => {
+                let fill = write_paint(&mut svg, i, "fill", original, paint);
+
+                let rule = match rule {
+                    tiny_skia::FillRule::Winding => "nonzero",
+                    tiny_skia::FillRule::EvenOdd => "evenodd",
+                };
+
+                let _ = writeln!(
+                    svg,
+                    r#"<path d="{}" {fill} fill-rule="{rule}"/>"#,
+                    data(path)
+                );
+            }
+            Primitive::Stroke {
+                path,
+                paint,
+                stroke,
+            } => {
+                let mut attributes = write_paint(&mut svg, i, "stroke", original, paint);
+
+                let line_cap = match stroke.line_cap {
+                    tiny_skia::LineCap::Butt => "butt",
+                    tiny_skia::LineCap::Round => "round",
+                    tiny_skia::LineCap::Square => "square",
+                };
+
+                let line_join = match stroke.line_join {
+                    tiny_skia::LineJoin::Miter => "miter",
+                    tiny_skia::LineJoin::MiterClip => "miter-clip",
+                    tiny_skia::LineJoin::Round => "round",
+                    tiny_skia::LineJoin::Bevel => "bevel",
+                };
+
+                let _ = write!(
+                    attributes,
+                    r#" stroke-width="{}" stroke-linecap="{line_cap}" stroke-linejoin="{line_join}" stroke-miterlimit="{}""#,
+                    stroke.width, stroke.miter_limit,
+                );
+
+                if let Some((segments, offset)) = original.and_then(|paint| paint.dash.as_ref()) {
+                    let segments: Vec<_> = segments.iter().map(f32::to_string).collect();
+
+                    let _ = write!(
+                        attributes,
+                        r#" stroke-dasharray="{}" stroke-dashoffset="{offset}""#,
+                        segments.join(" "),
+                    );
+                }
+
+                let _ = writeln!(
+                    svg,
+                    r#"<path d="{}" fill="none" {attributes}/>"#,
+                    data(path)
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Rasterizes the given primitives and images into an image of the given
+/// [`Size`], scaling them uniformly to fit.
+pub fn pixels(
+    bounds: Rectangle,
+    primitives: &[Primitive],
+    images: &[graphics::Image],
+    size: Size<u32>,
+) -> Screenshot {
+    let empty = || Screenshot::new(Vec::new(), Size::new(0, 0), 1.0);
+
+    let (Some(mut pixmap), Some(mut clip_mask)) = (
+        tiny_skia::Pixmap::new(size.width, size.height),
+        tiny_skia::Mask::new(size.width, size.height),
+    ) else {
+        return empty();
+    };
+
+    let scale = (size.width as f32 / bounds.width).min(size.height as f32 / bounds.height);
+
+    if !scale.is_finite() {
+        return empty();
+    }
+
+    let transformation =
+        Transformation::scale(scale) * Transformation::translate(-bounds.x, -bounds.y);
+
+    let clip_bounds = Rectangle::with_size(Size::new(size.width as f32, size.height as f32));
+    engine::adjust_clip_mask(&mut clip_mask, clip_bounds);
+
+    let mut engine = Engine::new();
+    let mut pixels = pixmap.as_mut();
+
+    for primitive in primitives {
+        engine.draw_primitive(
+            primitive,
+            transformation,
+            &mut pixels,
+            &mut clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for image in images {
+        engine.draw_image(
+            image,
+            transformation,
+            &mut pixels,
+            &mut clip_mask,
+            clip_bounds,
+        );
+    }
+
+    // The surface of the renderer is BGRA, so the channels must be swapped back
+    let rgba: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+
+            [color.blue(), color.green(), color.red(), color.alpha()]
+        })
+        .collect();
+
+    Screenshot::new(rgba, size, scale)
+}
+
+/// Writes the definitions needed by a paint, returning the attributes
+/// that apply it with the given name.
+fn write_paint(
+    svg: &mut String,
+    id: usize,
+    name: &str,
+    original: Option<&Paint>,
+    paint: &tiny_skia::Paint<'_>,
+) -> String {
+    let color = match original.map(|paint| paint.style) {
+        Some(Style::Solid(color)) => color,
+        Some(Style::Gradient(Gradient::Linear(linear))) => {
+            let transform = original.map(|paint| paint.transform).unwrap_or_default();
+
+            let _ = writeln!(
+                svg,
+                r#"<linearGradient id="gradient-{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}" gradientTransform="matrix({} {} {} {} {} {})">"#,
+                linear.start.x,
+                linear.start.y,
+                linear.end.x,
+                linear.end.y,
+                transform.sx,
+                transform.ky,
+                transform.kx,
+                transform.sy,
+                transform.tx,
+                transform.ty,
+            );
+
+            for stop in linear.stops.iter().flatten() {
+                let (color, opacity) = hex(stop.color);
+
+                let _ = writeln!(
+                    svg,
+                    r#"<stop offset="{}" stop-color="{color}" stop-opacity="{opacity}"/>"#,
+                    stop.offset,
+                );
+            }
+
+            svg.push_str("</linearGradient>\n");
+
+            return format!(r#"{name}="url(#gradient-{id})""#);
+        }
+        None => match paint.shader {
+            // Colors are stored as BGRA, like the surface of the renderer
+            tiny_skia::Shader::SolidColor(color) => {
+                Color::from_rgba(color.blue(), color.green(), color.red(), color.alpha())
+            }
+            _ => Color::BLACK,
+        },
+    };
+
+    let (color, opacity) = hex(color);
+
+    format!(r#"{name}="{color}" {name}-opacity="{opacity}""#)
+}
+
+/// Returns the hexadecimal representation of a [`Color`], together with
+/// its opacity.
+fn hex(color: Color) -> (String, f32) {
+    let [r, g, b, _] = color.into_rgba8();
+
+    (format!("#{r:02x}{g:02x}{b:02x}"), color.a)
+}
+
+/// Returns the SVG path data of a [`tiny_skia::Path`].
+fn data(path: &tiny_skia::Path) -> String {
+    let mut data = String::new();
+
+    for segment in path.segments() {
+        let _ = match segment {
+            tiny_skia::PathSegment::MoveTo(p) => write!(data, "M{} {}", p.x, p.y),
+            tiny_skia::PathSegment::LineTo(p) => write!(data, "L{} {}", p.x, p.y),
+            tiny_skia::PathSegment::QuadTo(control, p) => {
+                write!(data, "Q{} {} {} {}", control.x, control.y, p.x, p.y)
+            }
+            tiny_skia::PathSegment::CubicTo(a, b, p) => {
+                write!(data, "C{} {} {} {} {} {}", a.x, a.y, b.x, b.y, p.x, p.y)
+            }
+            tiny_skia::PathSegment::Close => write!(data, "Z"),
+        };
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solid_fills_are_exported() {
+        let mut builder = tiny_skia::PathBuilder::new();
+        builder.move_to(0.0, 0.0);
+        builder.line_to(10.0, 0.0);
+        builder.line_to(10.0, 10.0);
+        builder.close();
+
+        let primitive = Primitive::Fill {
+            path: builder.finish().expect("Build path"),
+            paint: tiny_skia::Paint::default(),
+            rule: tiny_skia::FillRule::EvenOdd,
+        };
+
+        let paint = Paint {
+            style: Style::Solid(Color::from_rgb8(255, 0, 0)),
+            transform: tiny_skia::Transform::identity(),
+            dash: None,
+        };
+
+        let svg = svg(
+            Rectangle::with_size(Size::new(10.0, 10.0)),
+            &[primitive],
+            &[paint],
+        );
+
+        assert!(svg.contains(
+            r##"<path d="M0 0L10 0L10 10Z" fill="#ff0000" fill-opacity="1" fill-rule="evenodd"/>"##
+        ));
+    }
+}
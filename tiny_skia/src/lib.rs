@@ -226,6 +226,44 @@ impl core::Renderer for Renderer {
         callback(Err(core::image::Error::Unsupported));
     }
 
+    fn prepare_svg(
+        &mut self,
+        _handle: &core::svg::Handle,
+        callback: impl FnOnce(core::Size<u32>) + Send + 'static,
+    ) {
+        #[cfg(feature = "svg")]
+        // TODO: Concurrency
+        callback(self.engine.vector_pipeline.viewport_dimensions(_handle));
+
+        #[cfg(not(feature = "svg"))]
+        callback(core::Size::ZERO);
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn set_image_cache_budget(&mut self, budget: usize) {
+        self.engine.eviction.set_budget(budget);
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn pin_image(&mut self, id: core::image::Id, pinned: bool) {
+        self.engine.eviction.pin(id, pinned);
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn evict_image(&mut self, id: core::image::Id) {
+        self.engine.eviction.evict(id);
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn trim_image_cache(&mut self) {
+        self.engine.eviction.trim_all();
+    }
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    fn image_cache_stats(&self) -> core::image::CacheStats {
+        self.engine.image_cache_stats()
+    }
+
     fn hint(&mut self, _scale_factor: f32) {
         // TODO: No hinting supported
         // We'll replace `tiny-skia` with `vello_cpu` soon
@@ -369,7 +407,34 @@ impl core::image::Renderer for Renderer {
         self.engine.raster_pipeline.dimensions(handle)
     }
 
-    fn draw_image(&mut self, image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
+    fn measure_image_animation(&self, handle: &Self::Handle) -> Option<core::time::Duration> {
+        self.engine.raster_pipeline.animation_duration(handle)
+    }
+
+    fn image_error(&self, handle: &Self::Handle) -> Option<core::image::Error> {
+        self.engine.raster_pipeline.error(handle)
+    }
+
+    fn decode_image(&self, handle: &Self::Handle) -> Option<core::image::Handle> {
+        let image = graphics::image::load(handle).ok()?;
+
+        Some(core::image::Handle::from_rgba(
+            image.width(),
+            image.height(),
+            image.into_raw(),
+        ))
+    }
+
+    fn draw_image(&mut self, mut image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
+        if let Some(time) = image.time
+            && let Some(frame) = self
+                .engine
+                .raster_pipeline
+                .animation_frame(&image.handle, time)
+        {
+            image.handle = frame;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, clip_bounds, transformation);
     }
@@ -381,6 +446,18 @@ impl core::svg::Renderer for Renderer {
         self.engine.vector_pipeline.viewport_dimensions(handle)
     }
 
+    fn measure_svg_animation(&self, handle: &core::svg::Handle) -> Option<core::time::Duration> {
+        self.engine.vector_pipeline.animation_duration(handle)
+    }
+
+    fn is_svg_invalid(&self, handle: &core::svg::Handle) -> bool {
+        self.engine.vector_pipeline.is_invalid(handle)
+    }
+
+    fn rasterize_svg(&self, svg: &core::Svg, size: core::Size<u32>) -> Option<core::image::Handle> {
+        graphics::svg::rasterize(svg.clone(), size)
+    }
+
     fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle, clip_bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(svg, bounds, clip_bounds, transformation);
@@ -1,4 +1,5 @@
 use crate::core::image as raster;
+use crate::core::time::Duration;
 use crate::core::{Rectangle, Size};
 use crate::graphics;
 
@@ -9,12 +10,18 @@ use std::collections::hash_map;
 #[derive(Debug)]
 pub struct Pipeline {
     cache: RefCell<Cache>,
+    animations: RefCell<graphics::image::Animations>,
+    mipmaps: RefCell<graphics::image::Mipmaps>,
+    color_filters: RefCell<graphics::image::ColorFilters>,
 }
 
 impl Pipeline {
     pub fn new() -> Self {
         Self {
             cache: RefCell::new(Cache::default()),
+            animations: RefCell::new(graphics::image::Animations::default()),
+            mipmaps: RefCell::new(graphics::image::Mipmaps::default()),
+            color_filters: RefCell::new(graphics::image::ColorFilters::default()),
         }
     }
 
@@ -37,6 +44,8 @@ impl Pipeline {
         &mut self,
         handle: &raster::Handle,
         filter_method: raster::FilterMethod,
+        color_filter: raster::ColorFilter,
+        region: Option<Rectangle>,
         bounds: Rectangle,
         opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
@@ -45,17 +54,59 @@ impl Pipeline {
     ) {
         let mut cache = self.cache.borrow_mut();
 
-        let Ok(image) = cache.allocate(handle) else {
+        let mipmap = match filter_method {
+            raster::FilterMethod::Mipmap => {
+                let scale_x = transform.sx.hypot(transform.ky);
+                let scale_y = transform.kx.hypot(transform.sy);
+
+                cache.allocate(handle).ok().and_then(|image| {
+                    self.mipmaps.borrow_mut().level(
+                        handle,
+                        Size::new(image.width(), image.height()),
+                        Size::new(bounds.width * scale_x, bounds.height * scale_y),
+                    )
+                })
+            }
+            _ => None,
+        };
+
+        let handle = mipmap.as_ref().unwrap_or(handle);
+
+        let filtered = if color_filter.is_identity() {
+            None
+        } else {
+            self.color_filters.borrow_mut().apply(handle, color_filter)
+        };
+
+        let Ok(image) = cache.allocate(filtered.as_ref().unwrap_or(handle)) else {
             return;
         };
 
+        let cropped = region.and_then(|region| {
+            let width = image.width() as f32;
+            let height = image.height() as f32;
+
+            let rect = tiny_skia::IntRect::from_xywh(
+                (region.x * width).round() as i32,
+                (region.y * height).round() as i32,
+                ((region.width * width).round() as u32).max(1),
+                ((region.height * height).round() as u32).max(1),
+            )?;
+
+            image.clone_rect(rect)
+        });
+
+        let image = cropped.as_ref().map_or(image, tiny_skia::Pixmap::as_ref);
+
         let width_scale = bounds.width / image.width() as f32;
         let height_scale = bounds.height / image.height() as f32;
 
         let transform = transform.pre_scale(width_scale, height_scale);
 
         let quality = match filter_method {
-            raster::FilterMethod::Linear => tiny_skia::FilterQuality::Bilinear,
+            raster::FilterMethod::Linear | raster::FilterMethod::Mipmap => {
+                tiny_skia::FilterQuality::Bilinear
+            }
             raster::FilterMethod::Nearest => tiny_skia::FilterQuality::Nearest,
         };
 
@@ -73,15 +124,45 @@ impl Pipeline {
         );
     }
 
-    pub fn trim_cache(&mut self) {
-        self.cache.borrow_mut().trim();
+    pub fn animation_duration(&self, handle: &raster::Handle) -> Option<Duration> {
+        self.animations
+            .borrow_mut()
+            .load(handle)
+            .map(raster::Animation::duration)
+    }
+
+    pub fn animation_frame(
+        &self,
+        handle: &raster::Handle,
+        time: Duration,
+    ) -> Option<raster::Handle> {
+        let mut animations = self.animations.borrow_mut();
+        let animation = animations.load(handle)?;
+
+        Some(animation.frame(time).handle().clone())
+    }
+
+    pub fn error(&self, handle: &raster::Handle) -> Option<raster::Error> {
+        self.cache.borrow().error(handle.id())
+    }
+
+    pub fn cache_stats(&self) -> (usize, usize) {
+        self.cache.borrow().stats()
+    }
+
+    pub fn trim_cache(&mut self, eviction: &graphics::image::Eviction) {
+        self.cache.borrow_mut().trim(eviction);
+        self.animations.borrow_mut().trim();
+        self.mipmaps.borrow_mut().trim();
+        self.color_filters.borrow_mut().trim();
     }
 }
 
 #[derive(Debug, Default)]
 struct Cache {
-    entries: FxHashMap<raster::Id, Option<Entry>>,
+    entries: FxHashMap<raster::Id, Result<Entry, raster::Error>>,
     hits: FxHashSet<raster::Id>,
+    last_used: FxHashMap<raster::Id, u64>,
 }
 
 impl Cache {
@@ -93,18 +174,21 @@ impl Cache {
 
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
             let image = match graphics::image::load(handle) {
+                Ok(image) if image.width() == 0 || image.height() == 0 => {
+                    let _ = entry.insert(Err(raster::Error::Empty));
+
+                    return Err(raster::Error::Empty);
+                }
                 Ok(image) => image,
                 Err(error) => {
-                    let _ = entry.insert(None);
+                    log::warn!("Failed to load image {handle:?}: {error}");
+
+                    let _ = entry.insert(Err(error.clone()));
 
                     return Err(error);
                 }
             };
 
-            if image.width() == 0 || image.height() == 0 {
-                return Err(raster::Error::Empty);
-            }
-
             let mut buffer = vec![0u32; image.width() as usize * image.height() as usize];
 
             for (i, pixel) in image.pixels().enumerate() {
@@ -113,7 +197,7 @@ impl Cache {
                 buffer[i] = bytemuck::cast(tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply());
             }
 
-            let _ = entry.insert(Some(Entry {
+            let _ = entry.insert(Ok(Entry {
                 width: image.width(),
                 height: image.height(),
                 pixels: buffer,
@@ -122,24 +206,40 @@ impl Cache {
 
         let _ = self.hits.insert(id);
 
-        Ok(self
-            .entries
-            .get(&id)
-            .unwrap()
-            .as_ref()
-            .map(|entry| {
-                tiny_skia::PixmapRef::from_bytes(
-                    bytemuck::cast_slice(&entry.pixels),
-                    entry.width,
-                    entry.height,
-                )
-                .expect("Build pixmap from image bytes")
+        match self.entries.get(&id).unwrap() {
+            Ok(entry) => Ok(tiny_skia::PixmapRef::from_bytes(
+                bytemuck::cast_slice(&entry.pixels),
+                entry.width,
+                entry.height,
+            )
+            .expect("Build pixmap from image bytes")),
+            Err(error) => Err(error.clone()),
+        }
+    }
+
+    fn error(&self, id: raster::Id) -> Option<raster::Error> {
+        self.entries.get(&id)?.as_ref().err().cloned()
+    }
+
+    fn stats(&self) -> (usize, usize) {
+        self.entries
+            .values()
+            .flatten()
+            .fold((0, 0), |(count, bytes), entry| {
+                (count + 1, bytes + entry.bytes())
             })
-            .expect("Image should be allocated"))
     }
 
-    fn trim(&mut self) {
-        self.entries.retain(|key, _| self.hits.contains(key));
+    fn trim(&mut self, eviction: &graphics::image::Eviction) {
+        eviction.retain(
+            &mut self.entries,
+            &mut self.last_used,
+            &self.hits,
+            |id| *id,
+            |entry| entry.as_ref().map_or(0, Entry::bytes),
+            |_, _| {},
+        );
+
         self.hits.clear();
     }
 }
@@ -150,3 +250,9 @@ struct Entry {
     height: u32,
     pixels: Vec<u32>,
 }
+
+impl Entry {
+    fn bytes(&self) -> usize {
+        self.pixels.len() * 4
+    }
+}
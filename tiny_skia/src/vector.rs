@@ -1,5 +1,8 @@
-use crate::core::svg::{Data, Handle};
+use crate::core::svg::{Animation, ColorMode, Handle, Svg};
+use crate::core::time::Duration;
 use crate::core::{Color, Rectangle, Size};
+use crate::graphics::image::Eviction;
+use crate::graphics::svg::{self, style_sheet};
 
 use iced_debug::core::svg::Id;
 use resvg::usvg;
@@ -8,7 +11,6 @@ use tiny_skia::Transform;
 
 use std::cell::RefCell;
 use std::collections::hash_map;
-use std::fs;
 use std::panic;
 #[cfg(feature = "svg-text")]
 use std::sync::Arc;
@@ -32,19 +34,27 @@ impl Pipeline {
             .unwrap_or(Size::new(0, 0))
     }
 
+    pub fn is_invalid(&self, handle: &Handle) -> bool {
+        self.cache.borrow_mut().load(handle, None, None).is_none()
+    }
+
+    pub fn animation_duration(&self, handle: &Handle) -> Option<Duration> {
+        self.cache
+            .borrow_mut()
+            .animation(handle)
+            .map(Animation::duration)
+    }
+
     pub fn draw(
         &mut self,
-        handle: &Handle,
-        color: Option<Color>,
+        svg: &Svg,
         bounds: Rectangle,
-        opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: Transform,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
         if let Some(image) = self.cache.borrow_mut().draw(
-            handle,
-            color,
+            svg,
             Size::new(
                 (bounds.width * transform.sx) as u32,
                 (bounds.height * transform.sy) as u32,
@@ -55,7 +65,7 @@ impl Pipeline {
                 (bounds.y * transform.sy) as i32,
                 image,
                 &tiny_skia::PixmapPaint {
-                    opacity,
+                    opacity: svg.opacity,
                     ..tiny_skia::PixmapPaint::default()
                 },
                 Transform::default(),
@@ -64,93 +74,162 @@ impl Pipeline {
         }
     }
 
-    pub fn trim_cache(&mut self) {
-        self.cache.borrow_mut().trim();
+    pub fn cache_stats(&self) -> (usize, usize) {
+        let cache = self.cache.borrow();
+
+        (
+            cache.rasters.len(),
+            cache
+                .rasters
+                .values()
+                .map(|pixmap| pixmap.data().len())
+                .sum(),
+        )
+    }
+
+    pub fn trim_cache(&mut self, eviction: &Eviction) {
+        self.cache.borrow_mut().trim(eviction);
     }
 }
 
 #[derive(Default)]
 struct Cache {
-    trees: FxHashMap<Id, Option<resvg::usvg::Tree>>,
-    tree_hits: FxHashSet<Id>,
+    trees: FxHashMap<TreeKey, Option<resvg::usvg::Tree>>,
+    tree_hits: FxHashSet<TreeKey>,
     rasters: FxHashMap<RasterKey, tiny_skia::Pixmap>,
     raster_hits: FxHashSet<RasterKey>,
+    raster_last_used: FxHashMap<RasterKey, u64>,
+    animations: FxHashMap<Id, Option<Animation>>,
+    animation_hits: FxHashSet<Id>,
     #[cfg(feature = "svg-text")]
     fontdb: Option<Arc<usvg::fontdb::Database>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TreeKey {
+    id: Id,
+    current_color: Option<[u8; 4]>,
+    time: Option<Duration>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct RasterKey {
     id: Id,
     color: Option<[u8; 4]>,
+    color_mode: ColorMode,
+    time: Option<Duration>,
+    viewport: Option<[u32; 4]>,
     size: Size<u32>,
 }
 
 impl Cache {
-    fn load(&mut self, handle: &Handle) -> Option<&usvg::Tree> {
+    fn animation(&mut self, handle: &Handle) -> Option<&Animation> {
         let id = handle.id();
 
-        // TODO: Reuse `cosmic-text` font database
-        #[cfg(feature = "svg-text")]
-        if self.fontdb.is_none() {
-            let mut fontdb = usvg::fontdb::Database::new();
-            fontdb.load_system_fonts();
+        let _ = self.animation_hits.insert(id);
 
-            self.fontdb = Some(Arc::new(fontdb));
-        }
+        self.animations
+            .entry(id)
+            .or_insert_with(|| Animation::load(handle.data()))
+            .as_ref()
+    }
+
+    fn load(
+        &mut self,
+        handle: &Handle,
+        current_color: Option<Color>,
+        time: Option<Duration>,
+    ) -> Option<&usvg::Tree> {
+        let key = TreeKey {
+            id: handle.id(),
+            current_color: current_color.map(Color::into_rgba8),
+            time: time.and_then(|time| Some(self.animation(handle)?.frame_time(time))),
+        };
 
-        let options = usvg::Options {
-            #[cfg(feature = "svg-text")]
-            fontdb: self
+        #[cfg(feature = "svg-text")]
+        {
+            let fontdb = svg::font_database();
+
+            // Svgs parsed with older fonts are parsed again
+            if self
                 .fontdb
                 .as_ref()
-                .expect("fontdb must be initialized")
-                .clone(),
-            ..usvg::Options::default()
-        };
+                .is_some_and(|current| !Arc::ptr_eq(current, &fontdb))
+            {
+                self.trees.clear();
+                self.rasters.clear();
+            }
+
+            self.fontdb = Some(fontdb);
+        }
+
+        if let hash_map::Entry::Vacant(entry) = self.trees.entry(key) {
+            let options = usvg::Options {
+                #[cfg(feature = "svg-text")]
+                fontdb: self
+                    .fontdb
+                    .as_ref()
+                    .expect("fontdb must be initialized")
+                    .clone(),
+                style_sheet: style_sheet(handle, current_color),
+                ..usvg::Options::default()
+            };
 
-        if let hash_map::Entry::Vacant(entry) = self.trees.entry(id) {
-            let svg = match handle.data() {
-                Data::Path(path) => fs::read_to_string(path)
-                    .ok()
-                    .and_then(|contents| usvg::Tree::from_str(&contents, &options).ok()),
-                Data::Bytes(bytes) => usvg::Tree::from_data(bytes, &options).ok(),
-                Data::Tree(tree) => Some(tree.clone()),
+            let frame = key.time.and_then(|time| {
+                self.animations
+                    .get(&key.id)?
+                    .as_ref()
+                    .map(|animation| animation.frame(time))
+            });
+
+            let svg = if let Some(frame) = frame {
+                svg::parse_frame(handle, &frame, &options)
+            } else {
+                svg::parse(handle, &options)
             };
 
             let _ = entry.insert(svg);
         }
 
-        let _ = self.tree_hits.insert(id);
-        self.trees.get(&id).unwrap().as_ref()
+        let _ = self.tree_hits.insert(key);
+        self.trees.get(&key).unwrap().as_ref()
     }
 
     fn viewport_dimensions(&mut self, handle: &Handle) -> Option<Size<u32>> {
-        let tree = self.load(handle)?;
+        let tree = self.load(handle, None, None)?;
         let size = tree.size();
 
         Some(Size::new(size.width() as u32, size.height() as u32))
     }
 
-    fn draw(
-        &mut self,
-        handle: &Handle,
-        color: Option<Color>,
-        size: Size<u32>,
-    ) -> Option<tiny_skia::PixmapRef<'_>> {
+    fn draw(&mut self, svg: &Svg, size: Size<u32>) -> Option<tiny_skia::PixmapRef<'_>> {
         if size.width == 0 || size.height == 0 {
             return None;
         }
 
+        let handle = &svg.handle;
+
         let key = RasterKey {
             id: handle.id(),
-            color: color.map(Color::into_rgba8),
+            color: svg.color.map(Color::into_rgba8),
+            color_mode: svg.color_mode,
+            time: svg
+                .time
+                .and_then(|time| Some(self.animation(handle)?.frame_time(time))),
+            viewport: svg.viewport.map(|viewport| {
+                [viewport.x, viewport.y, viewport.width, viewport.height].map(f32::to_bits)
+            }),
             size,
         };
 
         #[allow(clippy::map_entry)]
         if !self.rasters.contains_key(&key) {
-            let tree = self.load(handle)?;
+            let current_color = match svg.color_mode {
+                ColorMode::Filter => None,
+                ColorMode::CurrentColor => svg.color,
+            };
+
+            let tree = self.load(handle, current_color, key.time)?;
 
             let mut image = tiny_skia::Pixmap::new(size.width, size.height)?;
 
@@ -162,7 +241,17 @@ impl Cache {
                 tree_size.scale_to_height(size.height)
             };
 
-            let transform = if let Some(target_size) = target_size {
+            let viewport = svg
+                .viewport
+                .filter(|viewport| viewport.width > 0.0 && viewport.height > 0.0);
+
+            let transform = if let Some(viewport) = viewport {
+                tiny_skia::Transform::from_scale(
+                    size.width as f32 / viewport.width,
+                    size.height as f32 / viewport.height,
+                )
+                .pre_translate(-viewport.x, -viewport.y)
+            } else if let Some(target_size) = target_size {
                 let tree_size = tree_size.to_size();
                 let target_size = target_size.to_size();
 
@@ -184,7 +273,7 @@ impl Cache {
                 log::warn!("SVG rendering for {handle:?} panicked: {error:?}");
             }
 
-            if let Some([r, g, b, _]) = key.color {
+            if let (Some([r, g, b, _]), ColorMode::Filter) = (key.color, key.color_mode) {
                 // Apply color filter
                 for pixel in bytemuck::cast_slice_mut::<u8, u32>(image.data_mut()) {
                     *pixel = bytemuck::cast(
@@ -207,12 +296,25 @@ impl Cache {
         self.rasters.get(&key).map(tiny_skia::Pixmap::as_ref)
     }
 
-    fn trim(&mut self) {
-        self.trees.retain(|key, _| self.tree_hits.contains(key));
-        self.rasters.retain(|key, _| self.raster_hits.contains(key));
+    fn trim(&mut self, eviction: &Eviction) {
+        self.trees
+            .retain(|key, _| self.tree_hits.contains(key) || eviction.is_pinned(key.id));
+
+        eviction.retain(
+            &mut self.rasters,
+            &mut self.raster_last_used,
+            &self.raster_hits,
+            |key| key.id,
+            |pixmap| pixmap.data().len(),
+            |_, _| {},
+        );
+
+        self.animations
+            .retain(|key, _| self.animation_hits.contains(key));
 
         self.tree_hits.clear();
         self.raster_hits.clear();
+        self.animation_hits.clear();
     }
 }
 
@@ -0,0 +1,522 @@
+//! Blur whatever is drawn beneath a region of the screen.
+use crate::core::{Rectangle, Size, Transformation};
+
+use std::borrow::Cow;
+use std::mem;
+
+/// The distance between the uniforms of each pass in the uniforms buffer.
+///
+/// It must be a multiple of the `min_uniform_buffer_offset_alignment` limit,
+/// which is never bigger than 256.
+const STRIDE: usize = 256;
+
+/// A region of the screen whose contents must be blurred.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The bounds of the region.
+    pub bounds: Rectangle,
+    /// The border radius of the region.
+    pub border_radius: [f32; 4],
+    /// The radius of the blur.
+    pub blur_radius: f32,
+}
+
+impl Backdrop {
+    pub fn new(
+        bounds: Rectangle,
+        border_radius: [f32; 4],
+        blur_radius: f32,
+        transformation: Transformation,
+    ) -> Self {
+        let scale = transformation.scale_factor();
+
+        Self {
+            bounds: bounds * transformation,
+            border_radius: border_radius.map(|radius| radius * scale),
+            blur_radius: blur_radius * scale,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    sampler: wgpu::Sampler,
+    blur: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    constant_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("iced_wgpu::backdrop sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..wgpu::SamplerDescriptor::default()
+        });
+
+        let constant_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(
+                            mem::size_of::<Uniforms>() as wgpu::BufferAddress
+                        ),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop texture layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop pipeline layout"),
+            bind_group_layouts: &[&constant_layout, &texture_layout],
+            immediate_size: 0,
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("iced_wgpu::backdrop shader"),
+            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("shader/backdrop.wgsl"))),
+        });
+
+        let pipeline = |label, entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..wgpu::PrimitiveState::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview_mask: None,
+                cache: None,
+            })
+        };
+
+        Self {
+            format,
+            sampler,
+            blur: pipeline("iced_wgpu::backdrop blur pipeline", "fs_blur", None),
+            composite: pipeline(
+                "iced_wgpu::backdrop composite pipeline",
+                "fs_composite",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            constant_layout,
+            texture_layout,
+        }
+    }
+}
+
+/// The backdrops of a frame, together with the offscreen targets that are
+/// used to blur them.
+///
+/// When a frame contains backdrops, it is rendered offscreen first and
+/// then presented in the actual frame.
+#[derive(Debug, Default)]
+pub struct State {
+    uniforms: Vec<Uniforms>,
+    buffer: Option<Buffer>,
+    targets: Option<Targets>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the passes needed to blur the given backdrops.
+    pub fn prepare(&mut self, backdrops: &[Backdrop], scale_factor: f32, screen: Size<u32>) {
+        let screen = [screen.width as f32, screen.height as f32];
+
+        for backdrop in backdrops {
+            let bounds = backdrop.bounds * scale_factor;
+            let blur_radius = backdrop.blur_radius * scale_factor;
+
+            // The horizontal pass covers the vertical margin needed
+            // by the vertical one
+            self.uniforms.push(Uniforms {
+                bounds: [
+                    bounds.x,
+                    bounds.y - blur_radius,
+                    bounds.width,
+                    bounds.height + blur_radius * 2.0,
+                ],
+                border_radius: [0.0; 4],
+                screen,
+                direction: [1.0, 0.0],
+                blur_radius,
+                _padding: [0.0; 3],
+            });
+
+            self.uniforms.push(Uniforms {
+                bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius: backdrop.border_radius.map(|radius| radius * scale_factor),
+                screen,
+                direction: [0.0, 1.0],
+                blur_radius,
+                _padding: [0.0; 3],
+            });
+        }
+    }
+
+    /// Uploads the recorded passes and allocates the offscreen targets, if
+    /// there are any backdrops to blur.
+    pub fn upload(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        screen: Size<u32>,
+    ) {
+        if self.uniforms.is_empty() {
+            return;
+        }
+
+        // The last pass presents the offscreen target in the frame
+        self.uniforms.push(Uniforms {
+            bounds: [0.0, 0.0, screen.width as f32, screen.height as f32],
+            border_radius: [0.0; 4],
+            screen: [screen.width as f32, screen.height as f32],
+            direction: [0.0; 2],
+            blur_radius: 0.0,
+            _padding: [0.0; 3],
+        });
+
+        if self
+            .buffer
+            .as_ref()
+            .is_none_or(|buffer| buffer.capacity < self.uniforms.len())
+        {
+            self.buffer = Some(Buffer::new(
+                pipeline,
+                device,
+                self.uniforms.len().next_power_of_two(),
+            ));
+        }
+
+        if self
+            .targets
+            .as_ref()
+            .is_none_or(|targets| targets.size != screen)
+        {
+            self.targets = Some(Targets::new(pipeline, device, screen));
+        }
+
+        let buffer = self.buffer.as_ref().expect("Backdrop buffer");
+
+        for (i, uniforms) in self.uniforms.iter().enumerate() {
+            let bytes = bytemuck::bytes_of(uniforms);
+
+            belt.write_buffer(
+                encoder,
+                &buffer.raw,
+                (i * STRIDE) as u64,
+                (bytes.len() as u64).try_into().expect("Sized uniforms"),
+            )
+            .copy_from_slice(bytes);
+        }
+    }
+
+    /// Returns the offscreen target where the current frame must be rendered,
+    /// if it contains any backdrops.
+    pub fn target(&self) -> Option<&wgpu::TextureView> {
+        if self.uniforms.is_empty() {
+            return None;
+        }
+
+        self.targets.as_ref().map(|targets| &targets.scene.view)
+    }
+
+    /// Blurs the given amount of backdrops, starting at the given index, in the
+    /// offscreen target.
+    pub fn render(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        start: usize,
+        count: usize,
+        scissor_rect: Rectangle<u32>,
+    ) {
+        let (Some(buffer), Some(targets)) = (&self.buffer, &self.targets) else {
+            return;
+        };
+
+        for i in start..start + count {
+            let horizontal = (i * 2 * STRIDE) as u32;
+            let vertical = horizontal + STRIDE as u32;
+
+            run_pass(
+                encoder,
+                &pipeline.blur,
+                buffer,
+                horizontal,
+                &targets.scene.bind_group,
+                &targets.ping.view,
+                None,
+                false,
+            );
+
+            run_pass(
+                encoder,
+                &pipeline.blur,
+                buffer,
+                vertical,
+                &targets.ping.bind_group,
+                &targets.pong.view,
+                None,
+                false,
+            );
+
+            run_pass(
+                encoder,
+                &pipeline.composite,
+                buffer,
+                vertical,
+                &targets.pong.bind_group,
+                &targets.scene.view,
+                Some(scissor_rect),
+                false,
+            );
+        }
+    }
+
+    /// Presents the offscreen target in the given frame.
+    pub fn present(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+    ) {
+        let (Some(buffer), Some(targets)) = (&self.buffer, &self.targets) else {
+            return;
+        };
+
+        let Some(last) = self.uniforms.len().checked_sub(1) else {
+            return;
+        };
+
+        run_pass(
+            encoder,
+            &pipeline.composite,
+            buffer,
+            (last * STRIDE) as u32,
+            &targets.scene.bind_group,
+            frame,
+            None,
+            true,
+        );
+    }
+
+    pub fn trim(&mut self) {
+        self.uniforms.clear();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_pass(
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::RenderPipeline,
+    buffer: &Buffer,
+    offset: u32,
+    source: &wgpu::BindGroup,
+    destination: &wgpu::TextureView,
+    scissor_rect: Option<Rectangle<u32>>,
+    clear: bool,
+) {
+    let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::backdrop render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: destination,
+            depth_slice: None,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: if clear {
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
+                } else {
+                    wgpu::LoadOp::Load
+                },
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+        multiview_mask: None,
+    });
+
+    if let Some(scissor_rect) = scissor_rect {
+        pass.set_scissor_rect(
+            scissor_rect.x,
+            scissor_rect.y,
+            scissor_rect.width,
+            scissor_rect.height,
+        );
+    }
+
+    pass.set_pipeline(pipeline);
+    pass.set_bind_group(0, &buffer.constants, &[offset]);
+    pass.set_bind_group(1, source, &[]);
+    pass.draw(0..6, 0..1);
+}
+
+#[derive(Debug)]
+struct Buffer {
+    raw: wgpu::Buffer,
+    constants: wgpu::BindGroup,
+    capacity: usize,
+}
+
+impl Buffer {
+    fn new(pipeline: &Pipeline, device: &wgpu::Device, capacity: usize) -> Self {
+        let raw = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms buffer"),
+            size: (capacity * STRIDE) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms bind group"),
+            layout: &pipeline.constant_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                        buffer: &raw,
+                        offset: 0,
+                        size: wgpu::BufferSize::new(
+                            mem::size_of::<Uniforms>() as wgpu::BufferAddress
+                        ),
+                    }),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+                },
+            ],
+        });
+
+        Self {
+            raw,
+            constants,
+            capacity,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Targets {
+    size: Size<u32>,
+    scene: Target,
+    ping: Target,
+    pong: Target,
+}
+
+impl Targets {
+    fn new(pipeline: &Pipeline, device: &wgpu::Device, size: Size<u32>) -> Self {
+        Self {
+            size,
+            scene: Target::new(pipeline, device, size, "iced_wgpu::backdrop scene"),
+            ping: Target::new(pipeline, device, size, "iced_wgpu::backdrop ping"),
+            pong: Target::new(pipeline, device, size, "iced_wgpu::backdrop pong"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Target {
+    fn new(pipeline: &Pipeline, device: &wgpu::Device, size: Size<u32>, label: &str) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: pipeline.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(label),
+            layout: &pipeline.texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Self { view, bind_group }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Zeroable, bytemuck::Pod)]
+struct Uniforms {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    screen: [f32; 2],
+    direction: [f32; 2],
+    blur_radius: f32,
+    // Uniforms must be aligned to their largest member,
+    // this uses a vec4<f32> which aligns to 16, so align to that
+    _padding: [f32; 3],
+}
@@ -351,6 +351,14 @@ impl geometry::frame::Backend for Frame {
         self.transforms.current.0 = self.transforms.current.0.pre_scale(scale.x, scale.y);
     }
 
+    #[inline]
+    fn skew(&mut self, x: impl Into<Radians>, y: impl Into<Radians>) {
+        let skew =
+            lyon::math::Transform::new(1.0, y.into().0.tan(), x.into().0.tan(), 1.0, 0.0, 0.0);
+
+        self.transforms.current.0 = skew.then(&self.transforms.current.0);
+    }
+
     fn push_transform(&mut self) {
         self.transforms.previous.push(self.transforms.current);
     }
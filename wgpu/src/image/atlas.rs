@@ -106,6 +106,10 @@ impl Atlas {
         &self.texture_bind_group
     }
 
+    pub fn texture_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.texture_layout
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
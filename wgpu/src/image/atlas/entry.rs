@@ -11,13 +11,18 @@ pub enum Entry {
 }
 
 impl Entry {
-    #[cfg(feature = "image")]
     pub fn size(&self) -> Size<u32> {
         match self {
             Entry::Contiguous(allocation) => allocation.size(),
             Entry::Fragmented { size, .. } => *size,
         }
     }
+
+    pub fn bytes(&self) -> usize {
+        let size = self.size();
+
+        size.width as usize * size.height as usize * 4
+    }
 }
 
 #[derive(Debug)]
@@ -1,5 +1,6 @@
 use crate::core::{self, Size};
 use crate::graphics::Shell;
+use crate::graphics::image::Eviction;
 use crate::image::atlas::{self, Atlas};
 
 #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
@@ -12,6 +13,7 @@ use std::sync::Arc;
 
 pub struct Cache {
     atlas: Atlas,
+    eviction: Eviction,
     #[cfg(feature = "image")]
     raster: Raster,
     #[cfg(feature = "svg")]
@@ -33,14 +35,18 @@ impl Cache {
 
         Self {
             atlas: Atlas::new(device, backend, layout),
+            eviction: Eviction::default(),
             #[cfg(feature = "image")]
             raster: Raster {
                 cache: crate::image::raster::Cache::default(),
                 pending: HashMap::new(),
+                animations: crate::graphics::image::Animations::default(),
+                mipmaps: crate::graphics::image::Mipmaps::default(),
+                textures: crate::image::raster::Textures::default(),
                 belt: wgpu::util::StagingBelt::new(device.clone(), 2 * 1024 * 1024),
             },
             #[cfg(feature = "svg")]
-            vector: crate::image::vector::Cache::default(),
+            vector: crate::image::vector::Cache::new(_shell),
             #[cfg(all(feature = "image", not(target_arch = "wasm32")))]
             worker,
         }
@@ -54,6 +60,15 @@ impl Cache {
     ) {
         use crate::image::raster::Memory;
 
+        if let core::image::Handle::Texture { width, height, .. } = handle {
+            #[allow(unsafe_code)]
+            callback(Ok(unsafe {
+                core::image::allocate(handle, Size::new(*width, *height))
+            }));
+
+            return;
+        }
+
         let callback = Box::new(callback);
 
         if let Some(callbacks) = self.raster.pending.get_mut(&handle.id()) {
@@ -96,6 +111,11 @@ impl Cache {
     ) -> Result<core::image::Allocation, core::image::Error> {
         use crate::image::raster::Memory;
 
+        if let core::image::Handle::Texture { width, height, .. } = handle {
+            #[allow(unsafe_code)]
+            return Ok(unsafe { core::image::allocate(handle, Size::new(*width, *height)) });
+        }
+
         if !self.raster.cache.contains(handle) {
             self.raster.cache.insert(handle, Memory::load(handle));
         }
@@ -165,8 +185,57 @@ impl Cache {
         }
     }
 
+    #[cfg(feature = "image")]
+    pub fn measure_image_animation(
+        &mut self,
+        handle: &core::image::Handle,
+    ) -> Option<core::time::Duration> {
+        self.raster
+            .animations
+            .load(handle)
+            .map(core::image::Animation::duration)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn image_animation_frame(
+        &mut self,
+        handle: &core::image::Handle,
+        time: core::time::Duration,
+    ) -> Option<core::image::Handle> {
+        let animation = self.raster.animations.load(handle)?;
+
+        Some(animation.frame(time).handle().clone())
+    }
+
+    #[cfg(feature = "image")]
+    pub fn image_mipmap(
+        &mut self,
+        handle: &core::image::Handle,
+        size: Size,
+    ) -> Option<core::image::Handle> {
+        let image_size = self.measure_image(handle)?;
+
+        self.raster.mipmaps.level(handle, image_size, size)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn image_error(&mut self, handle: &core::image::Handle) -> Option<core::image::Error> {
+        use crate::image::raster::Memory;
+
+        self.receive();
+
+        match self.raster.cache.get_mut(handle)? {
+            Memory::Error(error) => Some(error.clone()),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(&mut self, handle: &core::image::Handle) -> Option<Size<u32>> {
+        if let core::image::Handle::Texture { width, height, .. } = handle {
+            return Some(Size::new(*width, *height));
+        }
+
         self.receive();
 
         let image = load_image(
@@ -183,10 +252,54 @@ impl Cache {
 
     #[cfg(feature = "svg")]
     pub fn measure_svg(&mut self, handle: &core::svg::Handle) -> Size<u32> {
-        // TODO: Concurrency
         self.vector.load(handle).viewport_dimensions()
     }
 
+    #[cfg(feature = "svg")]
+    pub fn prepare_svg(
+        &mut self,
+        handle: &core::svg::Handle,
+        callback: impl FnOnce(Size<u32>) + Send + 'static,
+    ) {
+        self.vector.prepare(handle, Box::new(callback));
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn is_svg_pending(&mut self, handle: &core::svg::Handle) -> bool {
+        self.vector.is_pending(handle)
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn is_svg_invalid(&mut self, handle: &core::svg::Handle) -> bool {
+        self.vector.is_invalid(handle)
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn receive_vectors(&mut self) {
+        self.vector.receive();
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn measure_svg_animation(
+        &mut self,
+        handle: &core::svg::Handle,
+    ) -> Option<core::time::Duration> {
+        self.vector
+            .animation(handle)
+            .map(core::svg::Animation::duration)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn upload_texture(
+        &mut self,
+        device: &wgpu::Device,
+        handle: &core::image::Handle,
+    ) -> Option<&Arc<wgpu::BindGroup>> {
+        self.raster
+            .textures
+            .upload(device, self.atlas.texture_layout(), handle)
+    }
+
     #[cfg(feature = "image")]
     pub fn upload_raster(
         &mut self,
@@ -255,38 +368,73 @@ impl Cache {
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         belt: &mut wgpu::util::StagingBelt,
-        handle: &core::svg::Handle,
-        color: Option<core::Color>,
+        svg: &core::Svg,
         size: Size,
         scale: f32,
     ) -> Option<(&atlas::Entry, &Arc<wgpu::BindGroup>)> {
-        // TODO: Concurrency
         self.vector
-            .upload(
-                device,
-                encoder,
-                belt,
-                handle,
-                color,
-                size,
-                scale,
-                &mut self.atlas,
-            )
+            .upload(device, encoder, belt, svg, size, scale, &mut self.atlas)
             .map(|entry| (entry, self.atlas.bind_group()))
     }
 
+    pub fn set_budget(&mut self, budget: usize) {
+        self.eviction.set_budget(budget);
+    }
+
+    pub fn pin(&mut self, id: core::image::Id, pinned: bool) {
+        self.eviction.pin(id, pinned);
+    }
+
+    pub fn evict(&mut self, id: core::image::Id) {
+        self.eviction.evict(id);
+    }
+
+    pub fn trim_all(&mut self) {
+        self.eviction.trim_all();
+    }
+
+    pub fn stats(&self) -> core::image::CacheStats {
+        #[cfg(feature = "image")]
+        let (images, image_bytes) = self.raster.cache.stats();
+
+        #[cfg(not(feature = "image"))]
+        let (images, image_bytes) = (0, 0);
+
+        #[cfg(feature = "svg")]
+        let (vectors, vector_bytes) = self.vector.stats();
+
+        #[cfg(not(feature = "svg"))]
+        let (vectors, vector_bytes) = (0, 0);
+
+        core::image::CacheStats {
+            images,
+            image_bytes,
+            vectors,
+            vector_bytes,
+            pinned: self.eviction.pinned(),
+            budget: self.eviction.budget(),
+        }
+    }
+
     pub fn trim(&mut self) {
         #[cfg(feature = "image")]
         {
             self.receive();
-            self.raster.cache.trim(&mut self.atlas, |_bind_group| {
-                #[cfg(not(target_arch = "wasm32"))]
-                self.worker.drop(_bind_group);
-            });
+            self.raster.animations.trim();
+            self.raster.mipmaps.trim();
+            self.raster.textures.trim();
+            self.raster
+                .cache
+                .trim(&mut self.atlas, &self.eviction, |_bind_group| {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    self.worker.drop(_bind_group);
+                });
         }
 
         #[cfg(feature = "svg")]
-        self.vector.trim(&mut self.atlas); // TODO: Concurrency
+        self.vector.trim(&mut self.atlas, &self.eviction); // TODO: Concurrency
+
+        self.eviction.finish();
     }
 
     #[cfg(feature = "image")]
@@ -328,6 +476,8 @@ impl Cache {
                     );
                 }
                 worker::Work::Error { handle, error } => {
+                    log::warn!("Failed to load image {handle:?}: {error}");
+
                     let callbacks = self.raster.pending.remove(&handle.id());
 
                     if let Some(callbacks) = callbacks {
@@ -354,6 +504,9 @@ impl Drop for Cache {
 struct Raster {
     cache: crate::image::raster::Cache,
     pending: HashMap<core::image::Id, Vec<Callback>>,
+    animations: crate::graphics::image::Animations,
+    mipmaps: crate::graphics::image::Mipmaps,
+    textures: crate::image::raster::Textures,
     belt: wgpu::util::StagingBelt,
 }
 
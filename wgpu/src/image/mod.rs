@@ -11,7 +11,8 @@ mod vector;
 
 use crate::Buffer;
 use crate::core::border;
-use crate::core::{Rectangle, Size, Transformation};
+use crate::core::image::ColorFilter;
+use crate::core::{Point, Rectangle, Size, Transformation};
 use crate::graphics::Shell;
 
 use bytemuck::{Pod, Zeroable};
@@ -136,6 +137,10 @@ impl Pipeline {
                         8 => Sint32,
                         // Snap
                         9 => Uint32,
+                        // Color filter
+                        10 => Float32x4,
+                        11 => Float32x4,
+                        12 => Float32x4,
                     ),
                 }],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -242,8 +247,69 @@ impl State {
                     bounds,
                     clip_bounds,
                 } => {
+                    if let crate::core::image::Handle::Texture { .. } = &image.handle {
+                        if let Some(bind_group) = cache.upload_texture(device, &image.handle) {
+                            match atlas.as_mut() {
+                                None => {
+                                    atlas = Some(bind_group.clone());
+                                }
+                                Some(atlas) if atlas != bind_group => {
+                                    layer.push(
+                                        atlas,
+                                        &self.nearest_instances,
+                                        &self.linear_instances,
+                                    );
+
+                                    *atlas = Arc::clone(bind_group);
+                                }
+                                _ => {}
+                            }
+
+                            add_texture(
+                                *bounds,
+                                *clip_bounds,
+                                image.border_radius,
+                                f32::from(image.rotation),
+                                image.opacity,
+                                image.snap,
+                                image.color_filter,
+                                image.region,
+                                match image.filter_method {
+                                    crate::core::image::FilterMethod::Nearest => {
+                                        &mut self.nearest_instances
+                                    }
+                                    crate::core::image::FilterMethod::Linear
+                                    | crate::core::image::FilterMethod::Mipmap => {
+                                        &mut self.linear_instances
+                                    }
+                                },
+                            );
+                        }
+
+                        continue;
+                    }
+
+                    let mipmap = match image.filter_method {
+                        crate::core::image::FilterMethod::Mipmap => {
+                            cache.image_mipmap(&image.handle, bounds.size() * scale)
+                        }
+                        _ => None,
+                    };
+
+                    // Mipmaps are drawn once uploaded, to avoid flickering
+                    let handle = match mipmap {
+                        Some(mipmap)
+                            if cache
+                                .upload_raster(device, encoder, belt, &mipmap)
+                                .is_some() =>
+                        {
+                            mipmap
+                        }
+                        _ => image.handle.clone(),
+                    };
+
                     if let Some((atlas_entry, bind_group)) =
-                        cache.upload_raster(device, encoder, belt, &image.handle)
+                        cache.upload_raster(device, encoder, belt, &handle)
                     {
                         match atlas.as_mut() {
                             None => {
@@ -264,12 +330,15 @@ impl State {
                             f32::from(image.rotation),
                             image.opacity,
                             image.snap,
+                            image.color_filter,
+                            image.region,
                             atlas_entry,
                             match image.filter_method {
                                 crate::core::image::FilterMethod::Nearest => {
                                     &mut self.nearest_instances
                                 }
-                                crate::core::image::FilterMethod::Linear => {
+                                crate::core::image::FilterMethod::Linear
+                                | crate::core::image::FilterMethod::Mipmap => {
                                     &mut self.linear_instances
                                 }
                             },
@@ -285,15 +354,9 @@ impl State {
                     bounds,
                     clip_bounds,
                 } => {
-                    if let Some((atlas_entry, bind_group)) = cache.upload_vector(
-                        device,
-                        encoder,
-                        belt,
-                        &svg.handle,
-                        svg.color,
-                        bounds.size(),
-                        scale,
-                    ) {
+                    if let Some((atlas_entry, bind_group)) =
+                        cache.upload_vector(device, encoder, belt, svg, bounds.size(), scale)
+                    {
                         match atlas.as_mut() {
                             None => {
                                 atlas = Some(bind_group.clone());
@@ -313,6 +376,8 @@ impl State {
                             f32::from(svg.rotation),
                             svg.opacity,
                             true,
+                            ColorFilter::IDENTITY,
+                            None,
                             atlas_entry,
                             &mut self.nearest_instances,
                         );
@@ -469,7 +534,8 @@ impl Layer {
         let uniforms = Uniforms {
             transform: transformation.into(),
             scale_factor,
-            _padding: [0.0; 3],
+            gamma_correction: u32::from(crate::graphics::color::GAMMA_CORRECTION),
+            _padding: [0.0; 2],
         };
 
         let bytes = bytemuck::bytes_of(&uniforms);
@@ -571,6 +637,7 @@ struct Instance {
     _size_in_atlas: [f32; 2],
     _layer: u32,
     _snap: u32,
+    _color_filter: [[f32; 4]; 3],
 }
 
 impl Instance {
@@ -582,9 +649,10 @@ impl Instance {
 struct Uniforms {
     transform: [f32; 16],
     scale_factor: f32,
+    gamma_correction: u32,
     // Uniforms must be aligned to their largest member,
     // this uses a mat4x4<f32> which aligns to 16, so align to that
-    _padding: [f32; 3],
+    _padding: [f32; 2],
 }
 
 fn add_instances(
@@ -594,6 +662,8 @@ fn add_instances(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    color_filter: ColorFilter,
+    region: Option<Rectangle>,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
@@ -610,57 +680,115 @@ fn add_instances(
     ];
 
     let border_radius = border_radius.into();
+    let color_filter = color_filter.matrix();
+
+    let size = entry.size();
+    let (width, height) = (size.width as f32, size.height as f32);
+
+    // The region of the entry to draw, in pixels
+    let source = region.map_or(
+        Rectangle::new(Point::ORIGIN, Size::new(width, height)),
+        |region| Rectangle {
+            x: region.x * width,
+            y: region.y * height,
+            width: region.width * width,
+            height: region.height * height,
+        },
+    );
+
+    let scaling_x = bounds.width / source.width;
+    let scaling_y = bounds.height / source.height;
+
+    let mut add_fragment = |allocation: &atlas::Allocation, (x, y): (u32, u32)| {
+        let Size { width, height } = allocation.size();
+
+        let fragment = Rectangle::new(
+            Point::new(x as f32, y as f32),
+            Size::new(width as f32, height as f32),
+        );
+
+        let Some(visible) = fragment.intersection(&source) else {
+            return;
+        };
+
+        let tile = [
+            bounds.x + (visible.x - source.x) * scaling_x,
+            bounds.y + (visible.y - source.y) * scaling_y,
+            visible.width * scaling_x,
+            visible.height * scaling_y,
+        ];
+
+        add_instance(
+            center,
+            clip_bounds,
+            border_radius,
+            tile,
+            rotation,
+            opacity,
+            snap,
+            color_filter,
+            allocation,
+            Rectangle {
+                x: visible.x - fragment.x,
+                y: visible.y - fragment.y,
+                ..visible
+            },
+            instances,
+        );
+    };
 
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(
-                center,
-                clip_bounds,
-                border_radius,
-                [bounds.x, bounds.y, bounds.width, bounds.height],
-                rotation,
-                opacity,
-                snap,
-                allocation,
-                instances,
-            );
+            add_fragment(allocation, (0, 0));
         }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = bounds.width / size.width as f32;
-            let scaling_y = bounds.height / size.height as f32;
-
+        atlas::Entry::Fragmented { fragments, .. } => {
             for fragment in fragments {
-                let allocation = &fragment.allocation;
-                let (fragment_x, fragment_y) = fragment.position;
-
-                let Size {
-                    width: fragment_width,
-                    height: fragment_height,
-                } = allocation.size();
-
-                let tile = [
-                    bounds.x + fragment_x as f32 * scaling_x,
-                    bounds.y + fragment_y as f32 * scaling_y,
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(
-                    center,
-                    clip_bounds,
-                    border_radius,
-                    tile,
-                    rotation,
-                    opacity,
-                    snap,
-                    allocation,
-                    instances,
-                );
+                add_fragment(&fragment.allocation, fragment.position);
             }
         }
     }
 }
 
+#[cfg(feature = "image")]
+fn add_texture(
+    bounds: Rectangle,
+    clip_bounds: Rectangle,
+    border_radius: border::Radius,
+    rotation: f32,
+    opacity: f32,
+    snap: bool,
+    color_filter: ColorFilter,
+    region: Option<Rectangle>,
+    instances: &mut Vec<Instance>,
+) {
+    // Textures are not part of an atlas, so they are sampled entirely
+    let region = region.unwrap_or(Rectangle::new(Point::ORIGIN, Size::new(1.0, 1.0)));
+
+    let instance = Instance {
+        _center: [
+            bounds.x + bounds.width / 2.0,
+            bounds.y + bounds.height / 2.0,
+        ],
+        _clip_bounds: [
+            clip_bounds.x,
+            clip_bounds.y,
+            clip_bounds.width,
+            clip_bounds.height,
+        ],
+        _border_radius: border_radius.into(),
+        _tile: [bounds.x, bounds.y, bounds.width, bounds.height],
+        _rotation: rotation,
+        _opacity: opacity,
+        _position_in_atlas: [region.x, region.y],
+        _size_in_atlas: [region.width, region.height],
+        _layer: 0,
+        _snap: snap as u32,
+        _color_filter: color_filter.matrix(),
+    };
+
+    instances.push(instance);
+}
+
 #[inline]
 fn add_instance(
     center: [f32; 2],
@@ -670,11 +798,12 @@ fn add_instance(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    color_filter: [[f32; 4]; 3],
     allocation: &atlas::Allocation,
+    region: Rectangle,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let Size { width, height } = allocation.size();
     let layer = allocation.layer();
     let atlas_size = allocation.atlas_size();
 
@@ -685,13 +814,17 @@ fn add_instance(
         _tile: tile,
         _rotation: rotation,
         _opacity: opacity,
-        _position_in_atlas: [x as f32 / atlas_size as f32, y as f32 / atlas_size as f32],
+        _position_in_atlas: [
+            (x as f32 + region.x) / atlas_size as f32,
+            (y as f32 + region.y) / atlas_size as f32,
+        ],
         _size_in_atlas: [
-            width as f32 / atlas_size as f32,
-            height as f32 / atlas_size as f32,
+            region.width / atlas_size as f32,
+            region.height / atlas_size as f32,
         ],
         _layer: layer as u32,
         _snap: snap as u32,
+        _color_filter: color_filter,
     };
 
     instances.push(instance);
@@ -26,7 +26,11 @@ impl Memory {
     pub fn load(handle: &image::Handle) -> Self {
         match graphics::image::load(handle) {
             Ok(image) => Self::Host(image),
-            Err(error) => Self::Error(error),
+            Err(error) => {
+                log::warn!("Failed to load image {handle:?}: {error}");
+
+                Self::Error(error)
+            }
         }
     }
 
@@ -42,6 +46,14 @@ impl Memory {
         }
     }
 
+    pub fn bytes(&self) -> usize {
+        match self {
+            Memory::Host(image) => image.as_raw().len(),
+            Memory::Device { entry, .. } => entry.bytes(),
+            Memory::Error(_) => 0,
+        }
+    }
+
     pub fn host(&self) -> Option<Image> {
         match self {
             Memory::Host(image) => Some(image.clone()),
@@ -54,6 +66,7 @@ impl Memory {
 pub struct Cache {
     map: FxHashMap<image::Id, Memory>,
     hits: FxHashSet<image::Id>,
+    last_used: FxHashMap<image::Id, u64>,
     should_trim: bool,
 }
 
@@ -75,27 +88,42 @@ impl Cache {
         self.map.contains_key(&handle.id())
     }
 
-    pub fn trim(&mut self, atlas: &mut Atlas, on_drop: impl Fn(Arc<wgpu::BindGroup>)) {
+    pub fn stats(&self) -> (usize, usize) {
+        self.map
+            .values()
+            .filter(|memory| !matches!(memory, Memory::Error(_)))
+            .fold((0, 0), |(count, bytes), memory| {
+                (count + 1, bytes + memory.bytes())
+            })
+    }
+
+    pub fn trim(
+        &mut self,
+        atlas: &mut Atlas,
+        eviction: &graphics::image::Eviction,
+        on_drop: impl Fn(Arc<wgpu::BindGroup>),
+    ) {
         // Only trim if new entries have landed in the `Cache`
-        if !self.should_trim {
+        if !self.should_trim && !eviction.is_requested() {
             return;
         }
 
-        let hits = &self.hits;
-
-        self.map.retain(|id, memory| {
-            // Retain active allocations
-            if let Memory::Device { allocation, .. } = memory
-                && allocation
-                    .as_ref()
-                    .is_some_and(|allocation| allocation.strong_count() > 0)
-            {
-                return true;
-            }
-
-            let retain = hits.contains(id);
-
-            if !retain {
+        // Retain active allocations
+        let allocated: Vec<_> = self
+            .map
+            .extract_if(|_, memory| {
+                matches!(memory, Memory::Device { allocation: Some(allocation), .. }
+                    if allocation.strong_count() > 0)
+            })
+            .collect();
+
+        eviction.retain(
+            &mut self.map,
+            &mut self.last_used,
+            &self.hits,
+            |id| *id,
+            Memory::bytes,
+            |id, memory| {
                 log::debug!("Dropping image allocation: {id:?}");
 
                 if let Memory::Device {
@@ -108,12 +136,64 @@ impl Cache {
                         atlas.remove(entry);
                     }
                 }
-            }
-
-            retain
-        });
+            },
+        );
 
+        self.map.extend(allocated);
         self.hits.clear();
         self.should_trim = false;
     }
 }
+
+/// Bind groups of the textures pointed to by texture handles
+#[derive(Debug, Default)]
+pub struct Textures {
+    map: FxHashMap<image::Id, Arc<wgpu::BindGroup>>,
+    hits: FxHashSet<image::Id>,
+}
+
+impl Textures {
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        handle: &image::Handle,
+    ) -> Option<&Arc<wgpu::BindGroup>> {
+        let image::Handle::Texture { id, texture, .. } = handle else {
+            return None;
+        };
+
+        let _ = self.hits.insert(*id);
+
+        if !self.map.contains_key(id) {
+            let Some(texture) = texture.downcast_ref::<wgpu::Texture>() else {
+                log::warn!("Failed to load image {handle:?}: not a wgpu::Texture");
+                return None;
+            };
+
+            let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
+                dimension: Some(wgpu::TextureViewDimension::D2Array),
+                array_layer_count: Some(1),
+                ..Default::default()
+            });
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu::image texture bind group"),
+                layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&texture_view),
+                }],
+            });
+
+            let _ = self.map.insert(*id, Arc::new(bind_group));
+        }
+
+        self.map.get(id)
+    }
+
+    pub fn trim(&mut self) {
+        self.map.retain(|id, _| self.hits.contains(id));
+        self.hits.clear();
+    }
+}
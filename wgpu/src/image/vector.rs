@@ -1,19 +1,35 @@
 use crate::core::svg;
+use crate::core::time::Duration;
 use crate::core::{Color, Size};
+use crate::graphics::svg::style_sheet;
+use crate::graphics::{self, Shell};
 use crate::image::atlas::{self, Atlas};
 
+#[cfg(not(target_arch = "wasm32"))]
+use worker::Worker;
+
 use resvg::tiny_skia;
 use resvg::usvg;
 use rustc_hash::{FxHashMap, FxHashSet};
+#[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 use std::panic;
-#[cfg(feature = "svg-text")]
 use std::sync::Arc;
 
+/// The maximum size of svg data that is parsed synchronously
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_SYNC_DATA_SIZE: u64 = 128 * 1024;
+
+/// The maximum size of raster data that is rasterized synchronously
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_SYNC_RASTER_SIZE: usize = 2 * 1024 * 1024;
+
 /// Entry in cache corresponding to an svg handle
 pub enum Svg {
     /// Parsed svg
-    Loaded(usvg::Tree),
+    Loaded(Arc<usvg::Tree>),
+    /// Svg being parsed in the background
+    Pending,
     /// Svg not found or failed to parse
     NotFound,
 }
@@ -27,68 +43,185 @@ impl Svg {
 
                 Size::new(size.width() as u32, size.height() as u32)
             }
+            Svg::Pending => Size::ZERO,
             Svg::NotFound => Size::new(1, 1),
         }
     }
 }
 
 /// Caches svg vector and raster data
-#[derive(Debug, Default)]
 pub struct Cache {
-    svgs: FxHashMap<svg::Id, Svg>,
-    rasterized: FxHashMap<(svg::Id, u32, u32, ColorFilter), atlas::Entry>,
-    svg_hits: FxHashSet<svg::Id>,
-    rasterized_hits: FxHashSet<(svg::Id, u32, u32, ColorFilter)>,
+    svgs: FxHashMap<SvgKey, Svg>,
+    rasterized: FxHashMap<RasterKey, atlas::Entry>,
+    svg_hits: FxHashSet<SvgKey>,
+    rasterized_hits: FxHashSet<RasterKey>,
+    rasterized_last_used: FxHashMap<RasterKey, u64>,
+    animations: FxHashMap<svg::Id, Option<svg::Animation>>,
+    animation_hits: FxHashSet<svg::Id>,
     should_trim: bool,
     #[cfg(feature = "svg-text")]
     fontdb: Option<Arc<usvg::fontdb::Database>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    shell: Shell,
+    #[cfg(not(target_arch = "wasm32"))]
+    worker: Option<Worker>,
+    #[cfg(not(target_arch = "wasm32"))]
+    preparations: FxHashMap<SvgKey, Vec<Callback>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pending_rasters: FxHashSet<RasterKey>,
+    #[cfg(not(target_arch = "wasm32"))]
+    rasters: FxHashMap<RasterKey, Vec<u8>>,
 }
 
+pub type Callback = Box<dyn FnOnce(Size<u32>) + Send>;
+
 type ColorFilter = Option<[u8; 4]>;
 
+type Viewport = Option<[u32; 4]>;
+
+type SvgKey = (svg::Id, ColorFilter, Option<Duration>);
+
+type RasterKey = (
+    svg::Id,
+    u32,
+    u32,
+    ColorFilter,
+    svg::ColorMode,
+    Option<Duration>,
+    Viewport,
+);
+
 impl Cache {
+    pub fn new(_shell: &Shell) -> Self {
+        Self {
+            svgs: FxHashMap::default(),
+            rasterized: FxHashMap::default(),
+            svg_hits: FxHashSet::default(),
+            rasterized_hits: FxHashSet::default(),
+            rasterized_last_used: FxHashMap::default(),
+            animations: FxHashMap::default(),
+            animation_hits: FxHashSet::default(),
+            should_trim: false,
+            #[cfg(feature = "svg-text")]
+            fontdb: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            shell: _shell.clone(),
+            #[cfg(not(target_arch = "wasm32"))]
+            worker: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            preparations: FxHashMap::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            pending_rasters: FxHashSet::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            rasters: FxHashMap::default(),
+        }
+    }
+
     /// Load svg
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
-        if self.svgs.contains_key(&handle.id()) {
-            return self.svgs.get(&handle.id()).unwrap();
+        self.load_with(handle, None, None)
+    }
+
+    /// Load svg in the background and call the given callback with its
+    /// dimensions once it is ready to be drawn
+    pub fn prepare(&mut self, handle: &svg::Handle, callback: Callback) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.is_pending(handle) {
+            self.preparations
+                .entry((handle.id(), None, None))
+                .or_default()
+                .push(callback);
+
+            return;
         }
 
-        // TODO: Reuse `cosmic-text` font database
+        callback(self.load(handle).viewport_dimensions());
+    }
+
+    /// Whether the svg is still being parsed in the background
+    pub fn is_pending(&mut self, handle: &svg::Handle) -> bool {
+        matches!(self.load(handle), Svg::Pending)
+    }
+
+    /// Whether the svg could not be found or parsed
+    pub fn is_invalid(&mut self, handle: &svg::Handle) -> bool {
+        matches!(self.load(handle), Svg::NotFound)
+    }
+
+    /// Load the SMIL animation of an svg, if any
+    pub fn animation(&mut self, handle: &svg::Handle) -> Option<&svg::Animation> {
+        let id = handle.id();
+
+        let _ = self.animation_hits.insert(id);
+
+        self.animations
+            .entry(id)
+            .or_insert_with(|| svg::Animation::load(handle.data()))
+            .as_ref()
+    }
+
+    /// Load svg, resolving `currentColor` to the given [`Color`] and
+    /// applying its animations at the given time
+    fn load_with(
+        &mut self,
+        handle: &svg::Handle,
+        current_color: Option<Color>,
+        time: Option<Duration>,
+    ) -> &Svg {
+        self.receive();
+
+        let time = time.and_then(|time| Some(self.animation(handle)?.frame_time(time)));
+        let key = (handle.id(), current_color.map(Color::into_rgba8), time);
+
         #[cfg(feature = "svg-text")]
-        if self.fontdb.is_none() {
-            let mut fontdb = usvg::fontdb::Database::new();
-            fontdb.load_system_fonts();
+        let fontdb = self.fontdb();
 
-            self.fontdb = Some(Arc::new(fontdb));
+        if self.svgs.contains_key(&key) {
+            return self.svgs.get(&key).unwrap();
         }
 
-        let options = usvg::Options {
+        let style_sheet = style_sheet(handle, current_color);
+
+        self.should_trim = true;
+
+        // Large svgs are parsed in the background, unless they are
+        // animated—since every frame needs to be parsed on demand
+        #[cfg(not(target_arch = "wasm32"))]
+        if time.is_none() && !is_small(handle) {
+            self.worker().parse(
+                key,
+                handle.clone(),
+                style_sheet,
+                #[cfg(feature = "svg-text")]
+                fontdb,
+            );
+
+            let _ = self.svgs.insert(key, Svg::Pending);
+            return self.svgs.get(&key).unwrap();
+        }
+
+        let options = options(
+            style_sheet,
             #[cfg(feature = "svg-text")]
-            fontdb: self
-                .fontdb
+            fontdb,
+        );
+
+        let frame = time.and_then(|time| {
+            self.animations
+                .get(&handle.id())?
                 .as_ref()
-                .expect("fontdb must be initialized")
-                .clone(),
-            ..usvg::Options::default()
-        };
+                .map(|animation| animation.frame(time))
+        });
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => fs::read_to_string(path)
-                .ok()
-                .and_then(|contents| usvg::Tree::from_str(&contents, &options).ok())
-                .map(Svg::Loaded)
-                .unwrap_or(Svg::NotFound),
-            svg::Data::Bytes(bytes) => match usvg::Tree::from_data(bytes, &options) {
-                Ok(tree) => Svg::Loaded(tree),
-                Err(_) => Svg::NotFound,
-            },
-            svg::Data::Tree(tree) => Svg::Loaded(tree.clone()),
+        let svg = if let Some(frame) = frame {
+            graphics::svg::parse_frame(handle, &frame, &options)
+                .map_or(Svg::NotFound, |tree| Svg::Loaded(Arc::new(tree)))
+        } else {
+            parse(handle, &options)
         };
 
-        self.should_trim = true;
-
-        let _ = self.svgs.insert(handle.id(), svg);
-        self.svgs.get(&handle.id()).unwrap()
+        let _ = self.svgs.insert(key, svg);
+        self.svgs.get(&key).unwrap()
     }
 
     /// Load svg and upload raster data
@@ -97,12 +230,14 @@ impl Cache {
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         belt: &mut wgpu::util::StagingBelt,
-        handle: &svg::Handle,
-        color: Option<Color>,
+        svg: &svg::Svg,
         size: Size,
         scale: f32,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
+        self.receive();
+
+        let handle = &svg.handle;
         let id = handle.id();
 
         let (width, height) = (
@@ -110,119 +245,436 @@ impl Cache {
             (scale * size.height).ceil() as u32,
         );
 
-        let color = color.map(Color::into_rgba8);
-        let key = (id, width, height, color);
+        let color = svg.color.map(Color::into_rgba8);
+        let time = svg
+            .time
+            .and_then(|time| Some(self.animation(handle)?.frame_time(time)));
+        let viewport = svg.viewport.map(|viewport| {
+            [viewport.x, viewport.y, viewport.width, viewport.height].map(f32::to_bits)
+        });
+
+        let key = (id, width, height, color, svg.color_mode, time, viewport);
+
+        let current_color = match svg.color_mode {
+            svg::ColorMode::Filter => None,
+            svg::ColorMode::CurrentColor => svg.color,
+        };
+
+        let tree_key = (id, current_color.map(Color::into_rgba8), time);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
         if self.rasterized.contains_key(&key) {
-            let _ = self.svg_hits.insert(id);
+            let _ = self.svg_hits.insert(tree_key);
             let _ = self.rasterized_hits.insert(key);
 
             return self.rasterized.get(&key);
         }
 
-        match self.load(handle) {
-            Svg::Loaded(tree) => {
-                if width == 0 || height == 0 {
-                    return None;
-                }
+        let tree = match self.load_with(handle, current_color, time) {
+            Svg::Loaded(tree) => tree.clone(),
+            Svg::Pending | Svg::NotFound => return None,
+        };
 
-                // TODO: Optimize!
-                // We currently rerasterize the SVG when its size changes. This is slow
-                // as heck. A GPU rasterizer like `pathfinder` may perform better.
-                // It would be cool to be able to smooth resize the `svg` example.
-                let mut img = tiny_skia::Pixmap::new(width, height)?;
-
-                let tree_size = tree.size().to_int_size();
-
-                let target_size = if width > height {
-                    tree_size.scale_to_width(width)
-                } else {
-                    tree_size.scale_to_height(height)
-                };
-
-                let transform = if let Some(target_size) = target_size {
-                    let tree_size = tree_size.to_size();
-                    let target_size = target_size.to_size();
-
-                    tiny_skia::Transform::from_scale(
-                        target_size.width() / tree_size.width(),
-                        target_size.height() / tree_size.height(),
-                    )
-                } else {
-                    tiny_skia::Transform::default()
-                };
-
-                // SVG rendering can panic on malformed or complex vectors.
-                // We catch panics to prevent crashes and continue gracefully.
-                let render = panic::catch_unwind(panic::AssertUnwindSafe(|| {
-                    resvg::render(tree, transform, &mut img.as_mut());
-                }));
-
-                if let Err(error) = render {
-                    log::warn!("SVG rendering for {handle:?} panicked: {error:?}");
-                }
+        if width == 0 || height == 0 {
+            return None;
+        }
 
-                let mut rgba = img.take();
+        // Large rasterizations happen in the background, while any
+        // rasterization of a different size is displayed instead
+        #[cfg(not(target_arch = "wasm32"))]
+        let rgba = if let Some(rgba) = self.rasters.remove(&key) {
+            rgba
+        } else if time.is_none() && width as usize * height as usize * 4 > MAX_SYNC_RASTER_SIZE {
+            if self.pending_rasters.insert(key) {
+                self.worker().rasterize(key, tree);
+            }
+
+            let _ = self.svg_hits.insert(tree_key);
+
+            return self.stale(key);
+        } else {
+            rasterize(&tree, key)?
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        let rgba = rasterize(&tree, key)?;
+
+        let allocation = atlas.upload(device, encoder, belt, width, height, &rgba)?;
+
+        log::debug!("allocating {id:?} {width}x{height}");
+
+        let _ = self.svg_hits.insert(tree_key);
+        let _ = self.rasterized_hits.insert(key);
+        let _ = self.rasterized.insert(key, allocation);
+        self.should_trim = true;
 
-                if let Some(color) = color {
-                    rgba.chunks_exact_mut(4).for_each(|rgba| {
-                        if rgba[3] > 0 {
-                            rgba[0] = color[0];
-                            rgba[1] = color[1];
-                            rgba[2] = color[2];
+        self.rasterized.get(&key)
+    }
+
+    /// Find a rasterization of the same svg with a different size
+    #[cfg(not(target_arch = "wasm32"))]
+    fn stale(&mut self, key: RasterKey) -> Option<&atlas::Entry> {
+        let (id, _, _, color, color_mode, time, viewport) = key;
+
+        let stale = self.rasterized.keys().copied().find(
+            |&(other_id, _, _, other_color, other_color_mode, other_time, other_viewport)| {
+                (
+                    other_id,
+                    other_color,
+                    other_color_mode,
+                    other_time,
+                    other_viewport,
+                ) == (id, color, color_mode, time, viewport)
+            },
+        )?;
+
+        let _ = self.rasterized_hits.insert(stale);
+
+        self.rasterized.get(&stale)
+    }
+
+    /// Receive the svgs and rasterizations finished in the background
+    pub fn receive(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(worker) = &self.worker else {
+                return;
+            };
+
+            while let Some(work) = worker.try_recv() {
+                match work {
+                    worker::Work::Parse { key, svg } => {
+                        if let Some(callbacks) = self.preparations.remove(&key) {
+                            let size = svg.viewport_dimensions();
+
+                            for callback in callbacks {
+                                callback(size);
+                            }
                         }
-                    });
-                }
 
-                let allocation = atlas.upload(device, encoder, belt, width, height, &rgba)?;
+                        let _ = self.svgs.insert(key, svg);
+                    }
+                    worker::Work::Rasterize { key, rgba } => {
+                        let _ = self.pending_rasters.remove(&key);
 
-                log::debug!("allocating {id:?} {width}x{height}");
+                        if let Some(rgba) = rgba {
+                            let _ = self.rasters.insert(key, rgba);
+                        }
+                    }
+                }
 
-                let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert(key);
-                let _ = self.rasterized.insert(key, allocation);
                 self.should_trim = true;
-
-                self.rasterized.get(&key)
             }
-            Svg::NotFound => None,
         }
     }
 
+    #[cfg(feature = "svg-text")]
+    fn fontdb(&mut self) -> Arc<usvg::fontdb::Database> {
+        let fontdb = graphics::svg::font_database();
+
+        // Svgs parsed with older fonts are parsed again
+        if self
+            .fontdb
+            .as_ref()
+            .is_some_and(|current| !Arc::ptr_eq(current, &fontdb))
+        {
+            self.svgs.clear();
+        }
+
+        self.fontdb = Some(fontdb.clone());
+
+        fontdb
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn worker(&mut self) -> &Worker {
+        self.worker.get_or_insert_with(|| Worker::new(&self.shell))
+    }
+
+    /// The amount of rasterized svgs and their size in bytes
+    pub fn stats(&self) -> (usize, usize) {
+        (
+            self.rasterized.len(),
+            self.rasterized.values().map(atlas::Entry::bytes).sum(),
+        )
+    }
+
     /// Load svg and upload raster data
-    pub fn trim(&mut self, atlas: &mut Atlas) {
-        if !self.should_trim {
+    pub fn trim(&mut self, atlas: &mut Atlas, eviction: &graphics::image::Eviction) {
+        if !self.should_trim && !eviction.is_requested() {
             return;
         }
 
         let svg_hits = &self.svg_hits;
-        let rasterized_hits = &self.rasterized_hits;
 
-        self.svgs.retain(|k, _| svg_hits.contains(k));
-        self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+        let animation_hits = &self.animation_hits;
 
-            if !retain {
-                atlas.remove(entry);
-            }
-
-            retain
+        self.svgs.retain(|k, svg| {
+            svg_hits.contains(k) || matches!(svg, Svg::Pending) || eviction.is_pinned(k.0)
         });
+        self.animations.retain(|k, _| animation_hits.contains(k));
+
+        // Rasterizations finished in the background are kept until uploaded,
+        // as long as their svg is still in use
+        #[cfg(not(target_arch = "wasm32"))]
+        self.rasters
+            .retain(|&key, _| svg_hits.contains(&tree_key(key)));
+
+        eviction.retain(
+            &mut self.rasterized,
+            &mut self.rasterized_last_used,
+            &self.rasterized_hits,
+            |key| key.0,
+            atlas::Entry::bytes,
+            |_key, entry| atlas.remove(entry),
+        );
+
         self.svg_hits.clear();
         self.rasterized_hits.clear();
+        self.animation_hits.clear();
         self.should_trim = false;
     }
 }
 
+/// The key of the svg tree used by the rasterization with the given key
+#[cfg(not(target_arch = "wasm32"))]
+fn tree_key(key: RasterKey) -> SvgKey {
+    let (id, _, _, color, color_mode, time, _) = key;
+
+    let current_color = match color_mode {
+        svg::ColorMode::Filter => None,
+        svg::ColorMode::CurrentColor => color,
+    };
+
+    (id, current_color, time)
+}
+
+/// Parses the svg of the given [`svg::Handle`]
+fn parse(handle: &svg::Handle, options: &usvg::Options<'_>) -> Svg {
+    graphics::svg::parse(handle, options).map_or(Svg::NotFound, |tree| Svg::Loaded(Arc::new(tree)))
+}
+
+/// Whether the svg data of the given [`svg::Handle`] is small enough to be
+/// parsed synchronously
+#[cfg(not(target_arch = "wasm32"))]
+fn is_small(handle: &svg::Handle) -> bool {
+    match handle.data() {
+        svg::Data::Path(path) => {
+            !fs::metadata(path).is_ok_and(|metadata| metadata.len() > MAX_SYNC_DATA_SIZE)
+        }
+        svg::Data::Bytes(bytes) => bytes.len() as u64 <= MAX_SYNC_DATA_SIZE,
+        svg::Data::Tree(_) => true,
+    }
+}
+
+/// Rasterizes the svg tree with the given raster key
+fn rasterize(tree: &usvg::Tree, key: RasterKey) -> Option<Vec<u8>> {
+    let (id, width, height, color, color_mode, _, viewport) = key;
+
+    // TODO: Optimize!
+    // We currently rerasterize the SVG when its size changes. This is slow
+    // as heck. A GPU rasterizer like `pathfinder` may perform better.
+    // It would be cool to be able to smooth resize the `svg` example.
+    let mut img = tiny_skia::Pixmap::new(width, height)?;
+
+    let tree_size = tree.size().to_int_size();
+
+    let target_size = if width > height {
+        tree_size.scale_to_width(width)
+    } else {
+        tree_size.scale_to_height(height)
+    };
+
+    let viewport = viewport
+        .map(|viewport| viewport.map(f32::from_bits))
+        .filter(|[_, _, width, height]| *width > 0.0 && *height > 0.0);
+
+    let transform = if let Some([x, y, viewport_width, viewport_height]) = viewport {
+        tiny_skia::Transform::from_scale(
+            width as f32 / viewport_width,
+            height as f32 / viewport_height,
+        )
+        .pre_translate(-x, -y)
+    } else if let Some(target_size) = target_size {
+        let tree_size = tree_size.to_size();
+        let target_size = target_size.to_size();
+
+        tiny_skia::Transform::from_scale(
+            target_size.width() / tree_size.width(),
+            target_size.height() / tree_size.height(),
+        )
+    } else {
+        tiny_skia::Transform::default()
+    };
+
+    // SVG rendering can panic on malformed or complex vectors.
+    // We catch panics to prevent crashes and continue gracefully.
+    let render = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        resvg::render(tree, transform, &mut img.as_mut());
+    }));
+
+    if let Err(error) = render {
+        log::warn!("SVG rendering for {id:?} panicked: {error:?}");
+    }
+
+    let mut rgba = img.take();
+
+    if let (Some(color), svg::ColorMode::Filter) = (color, color_mode) {
+        rgba.chunks_exact_mut(4).for_each(|rgba| {
+            if rgba[3] > 0 {
+                rgba[0] = color[0];
+                rgba[1] = color[1];
+                rgba[2] = color[2];
+            }
+        });
+    }
+
+    Some(rgba)
+}
+
+/// Builds the [`usvg::Options`] used to parse an svg
+fn options(
+    style_sheet: Option<String>,
+    #[cfg(feature = "svg-text")] fontdb: Arc<usvg::fontdb::Database>,
+) -> usvg::Options<'static> {
+    usvg::Options {
+        #[cfg(feature = "svg-text")]
+        fontdb,
+        style_sheet,
+        ..usvg::Options::default()
+    }
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Svg::Loaded(_) => write!(f, "Svg::Loaded"),
+            Svg::Pending => write!(f, "Svg::Pending"),
             Svg::NotFound => write!(f, "Svg::NotFound"),
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+mod worker {
+    use super::{RasterKey, Svg, SvgKey};
+    use crate::core::svg;
+    use crate::graphics::Shell;
+
+    use resvg::usvg;
+    use std::sync::Arc;
+    use std::sync::mpsc;
+    use std::thread;
+
+    pub struct Worker {
+        jobs: mpsc::Sender<Job>,
+        work: mpsc::Receiver<Work>,
+    }
+
+    enum Job {
+        Parse {
+            key: SvgKey,
+            handle: svg::Handle,
+            style_sheet: Option<String>,
+            #[cfg(feature = "svg-text")]
+            fontdb: Arc<usvg::fontdb::Database>,
+        },
+        Rasterize {
+            key: RasterKey,
+            tree: Arc<usvg::Tree>,
+        },
+    }
+
+    pub enum Work {
+        Parse {
+            key: SvgKey,
+            svg: Svg,
+        },
+        Rasterize {
+            key: RasterKey,
+            rgba: Option<Vec<u8>>,
+        },
+    }
+
+    impl Worker {
+        pub fn new(shell: &Shell) -> Self {
+            let (jobs_sender, jobs_receiver) = mpsc::channel();
+            let (work_sender, work_receiver) = mpsc::channel();
+
+            let shell = shell.clone();
+
+            // The worker stops once the sender of jobs is dropped
+            let _ = thread::spawn(move || run(jobs_receiver, work_sender, shell));
+
+            Self {
+                jobs: jobs_sender,
+                work: work_receiver,
+            }
+        }
+
+        pub fn parse(
+            &self,
+            key: SvgKey,
+            handle: svg::Handle,
+            style_sheet: Option<String>,
+            #[cfg(feature = "svg-text")] fontdb: Arc<usvg::fontdb::Database>,
+        ) {
+            let _ = self.jobs.send(Job::Parse {
+                key,
+                handle,
+                style_sheet,
+                #[cfg(feature = "svg-text")]
+                fontdb,
+            });
+        }
+
+        pub fn rasterize(&self, key: RasterKey, tree: Arc<usvg::Tree>) {
+            let _ = self.jobs.send(Job::Rasterize { key, tree });
+        }
+
+        pub fn try_recv(&self) -> Option<Work> {
+            self.work.try_recv().ok()
+        }
+    }
+
+    fn run(jobs: mpsc::Receiver<Job>, output: mpsc::Sender<Work>, shell: Shell) {
+        while let Ok(job) = jobs.recv() {
+            match job {
+                Job::Parse {
+                    key,
+                    handle,
+                    style_sheet,
+                    #[cfg(feature = "svg-text")]
+                    fontdb,
+                } => {
+                    let options = super::options(
+                        style_sheet,
+                        #[cfg(feature = "svg-text")]
+                        fontdb,
+                    );
+
+                    let svg = super::parse(&handle, &options);
+
+                    if output.send(Work::Parse { key, svg }).is_err() {
+                        return;
+                    }
+
+                    shell.tick();
+                    shell.invalidate_layout();
+                }
+                Job::Rasterize { key, tree } => {
+                    let rgba = super::rasterize(&tree, key);
+
+                    if output.send(Work::Rasterize { key, rgba }).is_err() {
+                        return;
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::backdrop::Backdrop;
 use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation, renderer};
 use crate::graphics;
 use crate::graphics::Mesh;
@@ -16,6 +17,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub backdrops: Vec<Backdrop>,
     pub quads: quad::Batch,
     pub triangles: triangle::Batch,
     pub primitives: primitive::Batch,
@@ -27,7 +29,8 @@ pub struct Layer {
 
 impl Layer {
     pub fn is_empty(&self) -> bool {
-        self.quads.is_empty()
+        self.backdrops.is_empty()
+            && self.quads.is_empty()
             && self.triangles.is_empty()
             && self.primitives.is_empty()
             && self.images.is_empty()
@@ -53,12 +56,28 @@ impl Layer {
             shadow_color: color::pack(quad.shadow.color),
             shadow_offset: (quad.shadow.offset * transformation.scale_factor()).into(),
             shadow_blur_radius: quad.shadow.blur_radius * transformation.scale_factor(),
+            shadow_spread_radius: quad.shadow.spread * transformation.scale_factor(),
             snap: quad.snap as u32,
         };
 
         self.quads.add(quad, &background);
     }
 
+    pub fn draw_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+        blur_radius: f32,
+        transformation: Transformation,
+    ) {
+        self.backdrops.push(Backdrop::new(
+            bounds,
+            border_radius.into(),
+            blur_radius,
+            transformation,
+        ));
+    }
+
     pub fn draw_paragraph(
         &mut self,
         paragraph: &Paragraph,
@@ -292,6 +311,7 @@ impl graphics::Layer for Layer {
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
 
+        self.backdrops.clear();
         self.quads.clear();
         self.triangles.clear();
         self.primitives.clear();
@@ -302,47 +322,55 @@ impl graphics::Layer for Layer {
     }
 
     fn start(&self) -> usize {
-        if !self.quads.is_empty() {
+        if !self.backdrops.is_empty() {
             return 1;
         }
 
-        if !self.triangles.is_empty() {
+        if !self.quads.is_empty() {
             return 2;
         }
 
-        if !self.primitives.is_empty() {
+        if !self.triangles.is_empty() {
             return 3;
         }
 
-        if !self.images.is_empty() {
+        if !self.primitives.is_empty() {
             return 4;
         }
 
-        if !self.text.is_empty() {
+        if !self.images.is_empty() {
             return 5;
         }
 
+        if !self.text.is_empty() {
+            return 6;
+        }
+
         usize::MAX
     }
 
     fn end(&self) -> usize {
         if !self.text.is_empty() {
-            return 5;
+            return 6;
         }
 
         if !self.images.is_empty() {
-            return 4;
+            return 5;
         }
 
         if !self.primitives.is_empty() {
-            return 3;
+            return 4;
         }
 
         if !self.triangles.is_empty() {
-            return 2;
+            return 3;
         }
 
         if !self.quads.is_empty() {
+            return 2;
+        }
+
+        if !self.backdrops.is_empty() {
             return 1;
         }
 
@@ -350,6 +378,7 @@ impl graphics::Layer for Layer {
     }
 
     fn merge(&mut self, layer: &mut Self) {
+        self.backdrops.append(&mut layer.backdrops);
         self.quads.append(&mut layer.quads);
         self.triangles.append(&mut layer.triangles);
         self.primitives.append(&mut layer.primitives);
@@ -362,6 +391,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            backdrops: Vec::new(),
             quads: quad::Batch::default(),
             triangles: triangle::Batch::default(),
             primitives: primitive::Batch::default(),
@@ -30,6 +30,7 @@ pub mod window;
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
+mod backdrop;
 mod buffer;
 mod color;
 mod engine;
@@ -79,6 +80,7 @@ pub struct Renderer {
     layers: layer::Stack,
     scale_factor: Option<f32>,
 
+    backdrop: backdrop::State,
     quad: quad::State,
     triangle: triangle::State,
     text: text::State,
@@ -102,6 +104,7 @@ impl Renderer {
             layers: layer::Stack::new(),
             scale_factor: None,
 
+            backdrop: backdrop::State::new(),
             quad: quad::State::new(),
             triangle: triangle::State::new(&engine.device, &engine.triangle_pipeline),
             text: text::State::new(),
@@ -138,9 +141,12 @@ impl Renderer {
                     label: Some("iced_wgpu encoder"),
                 });
 
-        self.prepare(&mut encoder, viewport);
+        // Backdrops need the contents of the whole frame, so they
+        // can only be blurred when the frame is cleared first
+        self.prepare(&mut encoder, viewport, clear_color.is_some());
         self.render(&mut encoder, target, clear_color, viewport);
 
+        self.backdrop.trim();
         self.quad.trim();
         self.triangle.trim();
         self.text.trim();
@@ -279,7 +285,12 @@ impl Renderer {
             })
     }
 
-    fn prepare(&mut self, encoder: &mut wgpu::CommandEncoder, viewport: &Viewport) {
+    fn prepare(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        viewport: &Viewport,
+        blur_backdrops: bool,
+    ) {
         let scale_factor = viewport.scale_factor();
 
         self.text_viewport
@@ -301,6 +312,11 @@ impl Renderer {
                 continue;
             }
 
+            if blur_backdrops && !layer.backdrops.is_empty() {
+                self.backdrop
+                    .prepare(&layer.backdrops, scale_factor, viewport.physical_size());
+            }
+
             if !layer.quads.is_empty() {
                 let prepare_span = debug::prepare(debug::Primitive::Quad);
 
@@ -391,6 +407,14 @@ impl Renderer {
                 prepare_span.finish();
             }
         }
+
+        self.backdrop.upload(
+            &self.engine.backdrop_pipeline,
+            &self.engine.device,
+            encoder,
+            &mut self.staging_belt,
+            viewport.physical_size(),
+        );
     }
 
     fn render(
@@ -402,11 +426,13 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
+        let target = self.backdrop.target().unwrap_or(frame);
+
         let mut render_pass =
             ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu render pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: frame,
+                    view: target,
                     depth_slice: None,
                     resolve_target: None,
                     ops: wgpu::Operations {
@@ -433,6 +459,7 @@ impl Renderer {
                 multiview_mask: None,
             }));
 
+        let mut backdrop_index = 0;
         let mut quad_layer = 0;
         let mut mesh_layer = 0;
         let mut text_layer = 0;
@@ -457,6 +484,38 @@ impl Renderer {
                 continue;
             };
 
+            if self.backdrop.target().is_some() && !layer.backdrops.is_empty() {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.backdrop.render(
+                    &self.engine.backdrop_pipeline,
+                    encoder,
+                    backdrop_index,
+                    layer.backdrops.len(),
+                    scissor_rect,
+                );
+
+                backdrop_index += layer.backdrops.len();
+
+                render_pass =
+                    ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                            view: target,
+                            depth_slice: None,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: wgpu::StoreOp::Store,
+                            },
+                        })],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                        multiview_mask: None,
+                    }));
+            }
+
             if !layer.quads.is_empty() {
                 let render_span = debug::render(debug::Primitive::Quad);
                 self.quad.render(
@@ -478,7 +537,7 @@ impl Renderer {
                 mesh_layer += self.triangle.render(
                     &self.engine.triangle_pipeline,
                     encoder,
-                    frame,
+                    target,
                     mesh_layer,
                     &layer.triangles,
                     physical_bounds,
@@ -490,7 +549,7 @@ impl Renderer {
                     ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view: frame,
+                            view: target,
                             depth_slice: None,
                             resolve_target: None,
                             ops: wgpu::Operations {
@@ -569,16 +628,19 @@ impl Renderer {
                     let _ = ManuallyDrop::into_inner(render_pass);
 
                     for (instance, clip_bounds) in need_render {
-                        instance
-                            .primitive
-                            .render(&primitive_storage, encoder, frame, &clip_bounds);
+                        instance.primitive.render(
+                            &primitive_storage,
+                            encoder,
+                            target,
+                            &clip_bounds,
+                        );
                     }
 
                     render_pass =
                         ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                             label: Some("iced_wgpu render pass"),
                             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view: target,
                                 depth_slice: None,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
@@ -626,6 +688,11 @@ impl Renderer {
 
         let _ = ManuallyDrop::into_inner(render_pass);
 
+        if self.backdrop.target().is_some() {
+            self.backdrop
+                .present(&self.engine.backdrop_pipeline, encoder, frame);
+        }
+
         debug::layers_rendered(|| {
             self.layers
                 .iter()
@@ -662,6 +729,16 @@ impl core::Renderer for Renderer {
         layer.draw_quad(quad, background.into(), transformation);
     }
 
+    fn blur_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+        blur_radius: f32,
+    ) {
+        let (layer, transformation) = self.layers.current_mut();
+        layer.draw_backdrop(bounds, border_radius, blur_radius, transformation);
+    }
+
     fn allocate_image(
         &mut self,
         _handle: &core::image::Handle,
@@ -673,6 +750,43 @@ impl core::Renderer for Renderer {
             .allocate_image(_handle, _callback);
     }
 
+    fn prepare_svg(
+        &mut self,
+        _handle: &core::svg::Handle,
+        callback: impl FnOnce(core::Size<u32>) + Send + 'static,
+    ) {
+        #[cfg(feature = "svg")]
+        self.image_cache.get_mut().prepare_svg(_handle, callback);
+
+        #[cfg(not(feature = "svg"))]
+        callback(core::Size::ZERO);
+    }
+
+    #[cfg(any(feature = "svg", feature = "image"))]
+    fn set_image_cache_budget(&mut self, budget: usize) {
+        self.image_cache.get_mut().set_budget(budget);
+    }
+
+    #[cfg(any(feature = "svg", feature = "image"))]
+    fn pin_image(&mut self, id: core::image::Id, pinned: bool) {
+        self.image_cache.get_mut().pin(id, pinned);
+    }
+
+    #[cfg(any(feature = "svg", feature = "image"))]
+    fn evict_image(&mut self, id: core::image::Id) {
+        self.image_cache.get_mut().evict(id);
+    }
+
+    #[cfg(any(feature = "svg", feature = "image"))]
+    fn trim_image_cache(&mut self) {
+        self.image_cache.get_mut().trim_all();
+    }
+
+    #[cfg(any(feature = "svg", feature = "image"))]
+    fn image_cache_stats(&self) -> core::image::CacheStats {
+        self.image_cache.borrow().stats()
+    }
+
     fn hint(&mut self, scale_factor: f32) {
         self.scale_factor = Some(scale_factor);
     }
@@ -684,6 +798,9 @@ impl core::Renderer for Renderer {
     fn tick(&mut self) {
         #[cfg(feature = "image")]
         self.image_cache.get_mut().receive();
+
+        #[cfg(feature = "svg")]
+        self.image_cache.get_mut().receive_vectors();
     }
 
     fn reset(&mut self, new_bounds: Rectangle) {
@@ -772,7 +889,36 @@ impl core::image::Renderer for Renderer {
         self.image_cache.borrow_mut().measure_image(handle)
     }
 
-    fn draw_image(&mut self, image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
+    fn measure_image_animation(&self, handle: &Self::Handle) -> Option<core::time::Duration> {
+        self.image_cache
+            .borrow_mut()
+            .measure_image_animation(handle)
+    }
+
+    fn image_error(&self, handle: &Self::Handle) -> Option<core::image::Error> {
+        self.image_cache.borrow_mut().image_error(handle)
+    }
+
+    fn decode_image(&self, handle: &Self::Handle) -> Option<core::image::Handle> {
+        let image = graphics::image::load(handle).ok()?;
+
+        Some(core::image::Handle::from_rgba(
+            image.width(),
+            image.height(),
+            image.into_raw(),
+        ))
+    }
+
+    fn draw_image(&mut self, mut image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
+        if let Some(time) = image.time
+            && let Some(frame) = self
+                .image_cache
+                .borrow_mut()
+                .image_animation_frame(&image.handle, time)
+        {
+            image.handle = frame;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, clip_bounds, transformation);
     }
@@ -784,6 +930,22 @@ impl core::svg::Renderer for Renderer {
         self.image_cache.borrow_mut().measure_svg(handle)
     }
 
+    fn measure_svg_animation(&self, handle: &core::svg::Handle) -> Option<core::time::Duration> {
+        self.image_cache.borrow_mut().measure_svg_animation(handle)
+    }
+
+    fn is_svg_pending(&self, handle: &core::svg::Handle) -> bool {
+        self.image_cache.borrow_mut().is_svg_pending(handle)
+    }
+
+    fn is_svg_invalid(&self, handle: &core::svg::Handle) -> bool {
+        self.image_cache.borrow_mut().is_svg_invalid(handle)
+    }
+
+    fn rasterize_svg(&self, svg: &core::Svg, size: core::Size<u32>) -> Option<core::image::Handle> {
+        graphics::svg::rasterize(svg.clone(), size)
+    }
+
     fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle, clip_bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_svg(svg, bounds, clip_bounds, transformation);
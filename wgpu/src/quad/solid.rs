@@ -109,8 +109,10 @@ impl Pipeline {
                         7 => Float32x2,
                         // Shadow blur radius
                         8 => Float32,
+                        // Shadow spread radius
+                        9 => Float32,
                         // Snap
-                        9 => Uint32,
+                        10 => Uint32,
                     ),
                 }],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
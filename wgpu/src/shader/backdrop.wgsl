@@ -0,0 +1,86 @@
+struct Uniforms {
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+    screen: vec2<f32>,
+    direction: vec2<f32>,
+    blur_radius: f32,
+}
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+@group(0) @binding(0) var<uniform> u: Uniforms;
+@group(0) @binding(1) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) pixel: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let pixel = u.bounds.xy + positions[vertex_index] * u.bounds.zw;
+
+    var out: VertexOutput;
+    out.pixel = pixel;
+    out.position = vec4<f32>(pixel / u.screen * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+
+    return out;
+}
+
+// A separable gaussian blur along the direction of the pass
+@fragment
+fn fs_blur(input: VertexOutput) -> @location(0) vec4<f32> {
+    let radius = min(ceil(u.blur_radius), 64.0);
+    let sigma = max(u.blur_radius / 2.0, 0.5);
+
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+
+    for (var offset = -radius; offset <= radius; offset += 1.0) {
+        let weight = exp(-(offset * offset) / (2.0 * sigma * sigma));
+        let coordinates = (input.pixel + u.direction * offset) / u.screen;
+
+        color += weight * textureSampleLevel(u_texture, u_sampler, coordinates, 0.0);
+        total += weight;
+    }
+
+    return color / total;
+}
+
+// Draws the blurred texture masked by the rounded bounds of the backdrop
+@fragment
+fn fs_composite(input: VertexOutput) -> @location(0) vec4<f32> {
+    let half_size = u.bounds.zw / 2.0;
+    let center = u.bounds.xy + half_size;
+
+    let radius = select_border_radius(u.border_radius, input.pixel, center);
+    let distance = rounded_box_sdf(input.pixel - center, half_size, radius);
+    let coverage = clamp(0.5 - distance, 0.0, 1.0);
+
+    return textureSampleLevel(u_texture, u_sampler, input.pixel / u.screen, 0.0) * coverage;
+}
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(to_center) - size + radius;
+
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = radi.x;
+    var ry = radi.y;
+
+    rx = select(radi.x, radi.y, position.x > center.x);
+    ry = select(radi.w, radi.z, position.x > center.x);
+    rx = select(rx, ry, position.y > center.y);
+
+    return rx;
+}
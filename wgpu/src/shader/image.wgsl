@@ -1,6 +1,7 @@
 struct Globals {
     transform: mat4x4<f32>,
     scale_factor: f32,
+    gamma_correction: u32,
 }
 
 @group(0) @binding(0) var<uniform> globals: Globals;
@@ -19,6 +20,9 @@ struct VertexInput {
     @location(7) atlas_scale: vec2<f32>,
     @location(8) layer: i32,
     @location(9) snap: u32,
+    @location(10) color_filter_r: vec4<f32>,
+    @location(11) color_filter_g: vec4<f32>,
+    @location(12) color_filter_b: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -29,6 +33,9 @@ struct VertexOutput {
     @location(3) @interpolate(flat) layer: i32,
     @location(4) @interpolate(flat) opacity: f32,
     @location(5) uv: vec2<f32>,
+    @location(6) @interpolate(flat) color_filter_r: vec4<f32>,
+    @location(7) @interpolate(flat) color_filter_g: vec4<f32>,
+    @location(8) @interpolate(flat) color_filter_b: vec4<f32>,
 }
 
 @vertex
@@ -99,6 +106,9 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.atlas = vec4(input.atlas_pos, input.atlas_pos + input.atlas_scale);
     out.layer = input.layer;
     out.opacity = input.opacity;
+    out.color_filter_r = input.color_filter_r;
+    out.color_filter_g = input.color_filter_g;
+    out.color_filter_b = input.color_filter_b;
 
     return out;
 }
@@ -118,7 +128,54 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let antialias: f32 = clamp(1.0 - d, 0.0, 1.0);
     let inside = all(input.uv >= input.atlas.xy) && all(input.uv <= input.atlas.zw);
 
-    return textureSample(u_texture, u_sampler, input.uv, input.layer) * vec4<f32>(1.0, 1.0, 1.0, antialias * input.opacity * f32(inside));
+    let color = apply_color_filter(textureSample(u_texture, u_sampler, input.uv, input.layer), input);
+
+    return color * vec4<f32>(1.0, 1.0, 1.0, antialias * input.opacity * f32(inside));
+}
+
+fn apply_color_filter(color: vec4<f32>, input: VertexOutput) -> vec4<f32> {
+    let is_identity = all(input.color_filter_r == vec4(1.0, 0.0, 0.0, 0.0))
+        && all(input.color_filter_g == vec4(0.0, 1.0, 0.0, 0.0))
+        && all(input.color_filter_b == vec4(0.0, 0.0, 1.0, 0.0));
+
+    if is_identity {
+        return color;
+    }
+
+    // Color filters operate on gamma-encoded colors, like CSS filters
+    var rgb = color.rgb;
+
+    if bool(globals.gamma_correction) {
+        rgb = linear_to_srgb(rgb);
+    }
+
+    let components = vec4(rgb, 1.0);
+
+    rgb = clamp(vec3(
+        dot(input.color_filter_r, components),
+        dot(input.color_filter_g, components),
+        dot(input.color_filter_b, components),
+    ), vec3(0.0), vec3(1.0));
+
+    if bool(globals.gamma_correction) {
+        rgb = srgb_to_linear(rgb);
+    }
+
+    return vec4(rgb, color.a);
+}
+
+fn linear_to_srgb(color: vec3<f32>) -> vec3<f32> {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+
+    return select(high, low, color <= vec3(0.0031308));
+}
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3(2.4));
+
+    return select(high, low, color <= vec3(0.04045));
 }
 
 fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
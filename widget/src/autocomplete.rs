@@ -0,0 +1,897 @@
+//! Autocompletes display suggestions below a text input, which the
+//! application can fetch asynchronously while the user types.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{autocomplete, column, text};
+//!
+//! struct State {
+//!    query: String,
+//!    users: Vec<User>,
+//!    is_searching: bool,
+//! }
+//!
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//!     email: String,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     QueryChanged(String),
+//!     Search(String),
+//!     UserSelected(u64),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     autocomplete("Search users...", &state.query, Message::QueryChanged)
+//!         .on_query(Message::Search)
+//!         .loading(state.is_searching)
+//!         .suggestions(state.users.iter().map(|user| {
+//!             (
+//!                 column![text(&user.name), text(&user.email).size(12)].into(),
+//!                 Message::UserSelected(user.id),
+//!             )
+//!         }))
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Border, Clipboard, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell,
+    Size, Vector,
+};
+use crate::overlay::menu;
+use crate::text_input::{self, TextInput};
+
+/// A text input that displays a list of suggestions below it.
+///
+/// Unlike a [`ComboBox`](crate::ComboBox), an [`Autocomplete`] does not
+/// filter any options by itself. Instead, it produces a message when
+/// the user stops typing for a while—see [`on_query`](Self::on_query)—so
+/// the application can fetch the matching suggestions asynchronously;
+/// for instance, with a `Task`.
+///
+/// Every suggestion is an [`Element`] paired with the message produced when
+/// it is selected, so suggestions can be rendered in any way. They are only
+/// displayed, though; they do not receive any events.
+pub struct Autocomplete<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_query: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_submit: Option<Message>,
+    debounce: Duration,
+    is_loading: bool,
+    loading_label: String,
+    contents: Vec<Element<'a, Message, Theme, Renderer>>,
+    messages: Vec<Message>,
+    padding: Padding,
+    menu_height: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    input: TextInput<'a, Edit, Theme, Renderer>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Autocomplete<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default debounce delay of an [`Autocomplete`].
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+    /// Creates a new [`Autocomplete`] with the given placeholder, its current
+    /// value, and the function that produces a message when some text is
+    /// typed into it.
+    pub fn new(placeholder: &str, value: &str, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        Self {
+            on_input: Box::new(on_input),
+            on_query: None,
+            on_submit: None,
+            debounce: Self::DEFAULT_DEBOUNCE,
+            is_loading: false,
+            loading_label: String::from("Loading..."),
+            contents: Vec::new(),
+            messages: Vec::new(),
+            padding: Padding::from([5, 10]),
+            menu_height: 200.0,
+            text_size: None,
+            font: None,
+            input: TextInput::new(placeholder, value)
+                .on_input(Edit::Input)
+                .on_submit(Edit::Submit)
+                .class(Theme::default_input()),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Sets the function that produces a message when the user stops typing
+    /// for the [`debounce`](Self::debounce) delay.
+    ///
+    /// This is the place to fetch the suggestions matching the given query.
+    pub fn on_query(mut self, on_query: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_query = Some(Box::new(on_query));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Autocomplete`] is
+    /// focused and the enter key is pressed, while no suggestion is selected.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets how long the user must stop typing before a query is produced.
+    ///
+    /// By default, it is [`DEFAULT_DEBOUNCE`](Self::DEFAULT_DEBOUNCE).
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets whether the suggestions of the [`Autocomplete`] are being loaded.
+    ///
+    /// While loading, a row with the [`loading_label`](Self::loading_label)
+    /// is displayed below the current suggestions.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the label of the row displayed while loading.
+    pub fn loading_label(mut self, label: impl Into<String>) -> Self {
+        self.loading_label = label.into();
+        self
+    }
+
+    /// Adds a suggestion to the [`Autocomplete`], with the message produced
+    /// when it is selected.
+    pub fn suggestion(
+        mut self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_select: Message,
+    ) -> Self {
+        self.contents.push(content.into());
+        self.messages.push(on_select);
+        self
+    }
+
+    /// Adds multiple suggestions to the [`Autocomplete`], with the messages
+    /// produced when they are selected.
+    pub fn suggestions(
+        self,
+        suggestions: impl IntoIterator<Item = (Element<'a, Message, Theme, Renderer>, Message)>,
+    ) -> Self {
+        suggestions
+            .into_iter()
+            .fold(self, |autocomplete, (content, on_select)| {
+                autocomplete.suggestion(content, on_select)
+            })
+    }
+
+    /// Sets the [`widget::Id`] of the [`Autocomplete`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`Autocomplete`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Autocomplete`] and its suggestions.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self.input = self.input.padding(self.padding);
+        self
+    }
+
+    /// Sets the maximum height of the suggestions menu of the [`Autocomplete`].
+    pub fn menu_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.menu_height = height.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Autocomplete`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_size = Some(size);
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the font of the [`Autocomplete`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the style of the input of the [`Autocomplete`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style of the suggestions menu of the [`Autocomplete`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the input of the [`Autocomplete`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn input_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.input = self.input.class(class);
+        self
+    }
+
+    /// Sets the style class of the suggestions menu of the [`Autocomplete`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.contents.len() + usize::from(self.is_loading)
+    }
+
+    fn select(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.messages.get(index) {
+            shell.publish(message.clone());
+
+            state.is_dismissed = true;
+            state.hovered = None;
+            shell.request_redraw();
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Submit,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    query: Option<Query>,
+    hovered: Option<usize>,
+    is_dismissed: bool,
+    offset: f32,
+    height: f32,
+    rows: Vec<(f32, f32)>,
+}
+
+#[derive(Debug, Clone)]
+struct Query {
+    text: String,
+    at: Instant,
+}
+
+impl State {
+    /// Scrolls the hovered row into view.
+    fn scroll_to_hovered(&mut self) {
+        let Some((y, height)) = self.hovered.and_then(|index| self.rows.get(index)) else {
+            return;
+        };
+
+        if *y < self.offset {
+            self.offset = *y;
+        } else if y + height > self.offset + self.height {
+            self.offset = y + height - self.height;
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Autocomplete<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.input as &dyn Widget<_, _, _>),
+            Tree {
+                children: self.contents.iter().map(Tree::new).collect(),
+                ..Tree::empty()
+            },
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+        tree.children[1].diff_children(&self.contents);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.input)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.input
+            .layout(&mut tree.children[0], renderer, limits, None)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !is_focused {
+            state.is_dismissed = false;
+            state.hovered = None;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && let Some(query) = state.query.take()
+        {
+            if *now >= query.at {
+                if let Some(on_query) = &self.on_query {
+                    shell.publish(on_query(query.text));
+                }
+            } else {
+                shell.request_redraw_at(query.at);
+                state.query = Some(query);
+            }
+        }
+
+        let is_open = is_focused && !state.is_dismissed && self.rows() > 0;
+
+        if is_open
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) = event
+        {
+            match named {
+                key::Named::ArrowUp | key::Named::ArrowDown if !self.contents.is_empty() => {
+                    let last = self.contents.len() - 1;
+
+                    state.hovered = Some(match (named, state.hovered) {
+                        (key::Named::ArrowUp, Some(0) | None) => last,
+                        (key::Named::ArrowUp, Some(index)) => index - 1,
+                        (_, Some(index)) if index < last => index + 1,
+                        _ => 0,
+                    });
+
+                    state.scroll_to_hovered();
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                key::Named::Enter => {
+                    if let Some(index) = state.hovered {
+                        self.select(state, index, shell);
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                key::Named::Escape => {
+                    state.is_dismissed = true;
+                    state.hovered = None;
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        self.input.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for edit in edits {
+            match edit {
+                Edit::Input(text) => {
+                    shell.publish((self.on_input)(text.clone()));
+
+                    state.is_dismissed = false;
+                    state.hovered = None;
+                    state.offset = 0.0;
+
+                    if self.on_query.is_some() {
+                        let at = Instant::now() + self.debounce;
+
+                        state.query = Some(Query { text, at });
+                        shell.request_redraw_at(at);
+                    }
+                }
+                Edit::Submit => {
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        shell.publish(on_submit);
+                    }
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            None,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !is_focused || state.is_dismissed || self.rows() == 0 {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            position: bounds.position() + translation,
+            target_height: bounds.height,
+            width: bounds.width,
+            viewport: *viewport + translation,
+            contents: &mut self.contents,
+            trees: &mut tree.children[1].children,
+            messages: &self.messages,
+            state,
+            loading_label: self.is_loading.then_some(self.loading_label.as_str()),
+            padding: self.padding,
+            menu_height: self.menu_height,
+            text_size: self.text_size,
+            font: self.font,
+            class: &self.menu_class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Autocomplete<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(autocomplete: Autocomplete<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(autocomplete)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    position: Point,
+    target_height: f32,
+    width: f32,
+    viewport: Rectangle,
+    contents: &'b mut [Element<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+    messages: &'b [Message],
+    state: &'b mut State,
+    loading_label: Option<&'b str>,
+    padding: Padding,
+    menu_height: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: &'b <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the index of the suggestion under the given position.
+    fn hit(&self, layout: Layout<'_>, position: Point) -> Option<usize> {
+        if !layout.bounds().contains(position) {
+            return None;
+        }
+
+        let position = position + Vector::new(0.0, self.state.offset);
+
+        layout
+            .children()
+            .take(self.contents.len())
+            .position(|row| row.bounds().contains(position))
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(self.width - self.padding.x(), f32::INFINITY),
+        );
+
+        let mut rows = Vec::with_capacity(self.contents.len() + 1);
+        let mut y = 0.0;
+
+        for (content, tree) in self.contents.iter_mut().zip(self.trees.iter_mut()) {
+            let node = content
+                .as_widget_mut()
+                .layout(tree, renderer, &limits)
+                .move_to(Point::new(self.padding.left, self.padding.top));
+
+            let height = node.size().height + self.padding.y();
+
+            rows.push(
+                layout::Node::with_children(Size::new(self.width, height), vec![node])
+                    .move_to(Point::new(0.0, y)),
+            );
+
+            y += height;
+        }
+
+        if self.loading_label.is_some() {
+            let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+            let height = text::LineHeight::default().to_absolute(size).0 + self.padding.y();
+
+            rows.push(layout::Node::new(Size::new(self.width, height)).move_to(Point::new(0.0, y)));
+
+            y += height;
+        }
+
+        let space_below = (self.viewport.y + self.viewport.height).min(bounds.height)
+            - (self.position.y + self.target_height);
+        let space_above = self.position.y - self.viewport.y.max(0.0);
+
+        let height = y.min(self.menu_height);
+
+        let (height, position_y) = if height <= space_below || space_below >= space_above {
+            (
+                height.min(space_below.max(0.0)),
+                self.position.y + self.target_height,
+            )
+        } else {
+            let height = height.min(space_above.max(0.0));
+
+            (height, self.position.y - height)
+        };
+
+        self.state.rows = rows
+            .iter()
+            .map(|row| (row.bounds().y, row.bounds().height))
+            .collect();
+        self.state.height = height;
+        self.state.offset = self.state.offset.clamp(0.0, (y - height).max(0.0));
+
+        layout::Node::with_children(Size::new(self.width, height), rows)
+            .move_to(Point::new(self.position.x, position_y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = <Theme as menu::Catalog>::style(theme, self.class);
+        let offset = Vector::new(0.0, self.state.offset);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(-offset, |renderer| {
+                let viewport = bounds + offset;
+
+                for (index, row) in layout.children().enumerate() {
+                    let row_bounds = row.bounds();
+
+                    if !row_bounds.intersects(&viewport) {
+                        continue;
+                    }
+
+                    let Some((content, tree)) = self.contents.get(index).zip(self.trees.get(index))
+                    else {
+                        if let Some(label) = self.loading_label {
+                            let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                            renderer.fill_text(
+                                Text {
+                                    content: label.to_owned(),
+                                    bounds: Size::new(
+                                        row_bounds.width - self.padding.x(),
+                                        row_bounds.height,
+                                    ),
+                                    size,
+                                    line_height: text::LineHeight::default(),
+                                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                                    align_x: text::Alignment::Default,
+                                    align_y: alignment::Vertical::Center,
+                                    shaping: text::Shaping::Advanced,
+                                    wrapping: text::Wrapping::None,
+                                    hint_factor: None,
+                                },
+                                Point::new(row_bounds.x + self.padding.left, row_bounds.center_y()),
+                                style.text_color.scale_alpha(0.6),
+                                viewport,
+                            );
+                        }
+
+                        continue;
+                    };
+
+                    let is_hovered = self.state.hovered == Some(index);
+
+                    if is_hovered {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row_bounds,
+                                border: Border::default().rounded(style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            style.selected_background,
+                        );
+                    }
+
+                    content.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: if is_hovered {
+                                style.selected_text_color
+                            } else {
+                                style.text_color
+                            },
+                        },
+                        row.children().next().unwrap(),
+                        cursor + offset,
+                        &viewport,
+                    );
+                }
+            });
+        });
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 20.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                let content_height = self.state.rows.last().map_or(0.0, |(y, height)| y + height);
+
+                self.state.offset = (self.state.offset - delta)
+                    .clamp(0.0, (content_height - bounds.height).max(0.0));
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(position) = cursor.position()
+                    && let Some(index) = self.hit(layout, position)
+                    && self.state.hovered != Some(index)
+                {
+                    self.state.hovered = Some(index);
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let is_primary = matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(_)
+                );
+
+                if is_primary
+                    && let Some(index) = self.hit(layout, position)
+                    && let Some(message) = self.messages.get(index)
+                {
+                    shell.publish(message.clone());
+
+                    self.state.is_dismissed = true;
+                    self.state.hovered = None;
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match cursor.position() {
+            Some(position) if self.hit(layout, position).is_some() => mouse::Interaction::Pointer,
+            Some(position) if layout.bounds().contains(position) => mouse::Interaction::Idle,
+            _ => mouse::Interaction::None,
+        }
+    }
+}
+
+/// The theme catalog of an [`Autocomplete`].
+pub trait Catalog: text_input::Catalog + menu::Catalog {
+    /// The default class for the text input of the [`Autocomplete`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The default class for the suggestions menu of the [`Autocomplete`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+}
+
+impl Catalog for crate::Theme {}
@@ -0,0 +1,807 @@
+//! Breadcrumbs display a path of clickable segments separated by a symbol.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::breadcrumbs;
+//! use iced::widget::breadcrumbs::segment;
+//!
+//! struct State {
+//!     path: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Navigate(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let last = state.path.len().saturating_sub(1);
+//!
+//!     breadcrumbs(state.path.iter().enumerate().map(|(index, directory)| {
+//!         segment(directory).on_press_maybe((index < last).then_some(Message::Navigate(index)))
+//!     }))
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Navigate(index) => {
+//!             state.path.truncate(index + 1);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+
+use std::fmt;
+
+const MENU_WIDTH: f32 = 200.0;
+
+/// A segment of some [`Breadcrumbs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment<Message> {
+    label: String,
+    on_press: Option<Message>,
+}
+
+impl<Message> Segment<Message> {
+    /// Creates a new [`Segment`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_press: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Segment`] is
+    /// pressed.
+    ///
+    /// Unless this is set, the [`Segment`] will be disabled.
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Segment`] is
+    /// pressed, if `Some`.
+    ///
+    /// If `None`, the [`Segment`] will be disabled.
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        self.on_press = on_press;
+        self
+    }
+}
+
+/// Creates a new [`Segment`] with the given label.
+pub fn segment<Message>(label: impl Into<String>) -> Segment<Message> {
+    Segment::new(label)
+}
+
+/// A path of clickable segments with separators.
+///
+/// When the segments do not fit, the ones in the middle are collapsed into
+/// an overflow button that lists them in a menu. The first and last segments
+/// are always visible.
+///
+/// Collapsed segments without a message are not listed in the menu.
+pub struct Breadcrumbs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    segments: Vec<Segment<Message>>,
+    separator: String,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Breadcrumbs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Segment`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 6.0,
+        left: 6.0,
+    };
+
+    /// Creates new [`Breadcrumbs`] with the given segments.
+    pub fn new(segments: impl IntoIterator<Item = Segment<Message>>) -> Self {
+        Self {
+            segments: segments.into_iter().collect(),
+            separator: String::from("›"),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Adds a [`Segment`] to the [`Breadcrumbs`].
+    pub fn push(mut self, segment: Segment<Message>) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Sets the separator displayed between the segments of the
+    /// [`Breadcrumbs`].
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the width of the [`Breadcrumbs`].
+    ///
+    /// Segments are collapsed when they do not fit in it.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each [`Segment`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the segments and the separators of the
+    /// [`Breadcrumbs`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Breadcrumbs`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Breadcrumbs`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumbs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the overflow menu of the [`Breadcrumbs`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Breadcrumbs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the overflow menu of the [`Breadcrumbs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size_or_default(renderer))
+            .0
+    }
+
+    fn text<'b>(&self, content: &'b str, renderer: &Renderer) -> Text<&'b str, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, self.line_height(renderer)),
+            size: self.text_size_or_default(renderer),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    fn measure(&self, content: &str, renderer: &Renderer) -> f32 {
+        Renderer::Paragraph::with_text(self.text(content, renderer)).min_width()
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        viewport: &Rectangle,
+    ) {
+        let text = self.text(content, renderer);
+
+        renderer.fill_text(
+            text.with_content(content.to_owned()),
+            bounds.center(),
+            color,
+            *viewport,
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    menu: menu::State,
+    hovered_option: Option<usize>,
+    collapsed: Vec<Collapsed>,
+}
+
+/// A collapsed segment listed in the overflow menu.
+#[derive(Debug, Clone)]
+struct Collapsed {
+    index: usize,
+    label: String,
+}
+
+impl fmt::Display for Collapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// The visible parts of some [`Breadcrumbs`].
+struct Regions<'a> {
+    segments: Layout<'a>,
+    overflow: Option<Rectangle>,
+    separators: Layout<'a>,
+}
+
+impl<'a> Regions<'a> {
+    fn new(layout: Layout<'a>) -> Self {
+        let mut children = layout.children();
+        let segments = children.next().unwrap();
+        let overflow = children
+            .next()
+            .map(|overflow| overflow.bounds())
+            .filter(|overflow| overflow.width > 0.0);
+        let separators = children.next().unwrap();
+
+        Self {
+            segments,
+            overflow,
+            separators,
+        }
+    }
+
+    /// Returns the index of the visible segment under the given position.
+    fn segment_at(&self, position: Point) -> Option<usize> {
+        self.segments
+            .children()
+            .position(|segment| segment.bounds().contains(position))
+    }
+}
+
+/// Returns the amount of segments after the first one that need to be
+/// collapsed for all of them to fit in the available width.
+fn collapsed_count(widths: &[f32], gap: f32, overflow: f32, available: f32) -> usize {
+    let total = |collapsed: usize| {
+        let visible = widths.len() - collapsed;
+        let width: f32 = widths[..1]
+            .iter()
+            .chain(&widths[1 + collapsed..])
+            .sum::<f32>()
+            + gap * visible.saturating_sub(1) as f32;
+
+        if collapsed > 0 {
+            width + overflow + gap
+        } else {
+            width
+        }
+    };
+
+    let max = widths.len().saturating_sub(2);
+
+    (0..max)
+        .find(|&collapsed| total(collapsed) <= available)
+        .unwrap_or(max)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Breadcrumbs<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        let line_height = self.line_height(renderer);
+        let height = line_height + self.padding.y();
+
+        let widths: Vec<f32> = self
+            .segments
+            .iter()
+            .map(|segment| self.measure(&segment.label, renderer) + self.padding.x())
+            .collect();
+
+        let separator = self.measure(&self.separator, renderer);
+        let overflow = self.measure("…", renderer) + self.padding.x();
+        let gap = separator + self.spacing * 2.0;
+
+        let available = limits.width(self.width).max().width;
+        let collapsed = if widths.is_empty() {
+            0
+        } else {
+            collapsed_count(&widths, gap, overflow, available)
+        };
+
+        let mut x = 0.0;
+        let mut separators = Vec::new();
+
+        let mut place = |width: f32, separators: &mut Vec<layout::Node>| {
+            if x > 0.0 {
+                separators.push(
+                    layout::Node::new(Size::new(separator, height))
+                        .move_to(Point::new(x + self.spacing, 0.0)),
+                );
+
+                x += gap;
+            }
+
+            let node = layout::Node::new(Size::new(width, height)).move_to(Point::new(x, 0.0));
+
+            x += width;
+
+            node
+        };
+
+        let mut segments = Vec::with_capacity(widths.len());
+        let mut overflow_node = layout::Node::new(Size::ZERO);
+
+        for (index, width) in widths.iter().enumerate() {
+            if (1..=collapsed).contains(&index) {
+                segments.push(layout::Node::new(Size::ZERO));
+                continue;
+            }
+
+            segments.push(place(*width, &mut separators));
+
+            if index == 0 && collapsed > 0 {
+                overflow_node = place(overflow, &mut separators);
+            }
+        }
+
+        state.collapsed = self
+            .segments
+            .iter()
+            .enumerate()
+            .skip(1)
+            .take(collapsed)
+            .filter(|(_, segment)| segment.on_press.is_some())
+            .map(|(index, segment)| Collapsed {
+                index,
+                label: segment.label.clone(),
+            })
+            .collect();
+
+        if state.collapsed.is_empty() {
+            state.is_open = false;
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(
+            size,
+            vec![
+                layout::Node::with_children(size, segments),
+                overflow_node,
+                layout::Node::with_children(size, separators),
+            ],
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let regions = Regions::new(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(button)) if state.is_open => {
+                // The event was not captured by the menu, so the cursor
+                // was pressed outside of it
+                state.is_open = false;
+
+                if *button == mouse::Button::Left
+                    && regions
+                        .overflow
+                        .is_some_and(|overflow| cursor.is_over(overflow))
+                {
+                    shell.capture_event();
+                }
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if regions
+                    .overflow
+                    .is_some_and(|overflow| overflow.contains(position))
+                {
+                    if !state.collapsed.is_empty() {
+                        state.is_open = true;
+                        state.hovered_option = None;
+
+                        shell.request_redraw();
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+
+                let Some(on_press) = regions
+                    .segment_at(position)
+                    .and_then(|index| self.segments[index].on_press.clone())
+                else {
+                    return;
+                };
+
+                shell.publish(on_press);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if cursor.is_over(layout.bounds()) {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let regions = Regions::new(layout);
+
+        let hovered = cursor
+            .position()
+            .filter(|_| !state.is_open)
+            .and_then(|position| regions.segment_at(position));
+
+        let draw_segment = |renderer: &mut Renderer, label: &str, bounds: Rectangle, status| {
+            let style = <Theme as Catalog>::style(theme, &self.class, status);
+
+            if let Some(background) = style.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            self.fill_text(renderer, label, bounds, style.text_color, viewport);
+        };
+
+        for (index, (segment, layout)) in self
+            .segments
+            .iter()
+            .zip(regions.segments.children())
+            .enumerate()
+        {
+            let segment_bounds = layout.bounds();
+
+            if segment_bounds.width == 0.0 {
+                continue;
+            }
+
+            let status = if segment.on_press.is_none() {
+                Status::Disabled
+            } else if hovered == Some(index) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            draw_segment(renderer, &segment.label, segment_bounds, status);
+        }
+
+        if let Some(overflow) = regions.overflow {
+            let status = if state.collapsed.is_empty() {
+                Status::Disabled
+            } else if state.is_open || cursor.is_over(overflow) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            draw_segment(renderer, "…", overflow, status);
+        }
+
+        let separator_color =
+            <Theme as Catalog>::style(theme, &self.class, Status::Active).separator_color;
+
+        for separator in regions.separators.children() {
+            self.fill_text(
+                renderer,
+                &self.separator,
+                separator.bounds(),
+                separator_color,
+                &bounds,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let regions = Regions::new(layout);
+
+        let is_over_segment = cursor
+            .position()
+            .and_then(|position| regions.segment_at(position))
+            .is_some_and(|index| self.segments[index].on_press.is_some());
+
+        let is_over_overflow = !state.collapsed.is_empty()
+            && regions
+                .overflow
+                .is_some_and(|overflow| cursor.is_over(overflow));
+
+        if is_over_segment || is_over_overflow {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let overflow = Regions::new(layout).overflow?;
+        let bounds = layout.bounds();
+        let segments = &self.segments;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &state.collapsed,
+            &mut state.hovered_option,
+            |collapsed| {
+                state.is_open = false;
+
+                segments[collapsed.index]
+                    .on_press
+                    .clone()
+                    .expect("collapsed segment has a message")
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(MENU_WIDTH)
+        .padding(self.padding)
+        .font(self.font.unwrap_or_else(|| renderer.default_font()));
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(
+            Point::new(overflow.x, bounds.y) + translation,
+            *viewport,
+            bounds.height,
+            Length::Shrink,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Breadcrumbs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(breadcrumbs: Breadcrumbs<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(breadcrumbs)
+    }
+}
+
+/// The possible status of a [`Segment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Segment`] can be pressed.
+    Active,
+    /// The [`Segment`] can be pressed and it is being hovered.
+    Hovered,
+    /// The [`Segment`] cannot be pressed.
+    Disabled,
+}
+
+/// The appearance of some [`Breadcrumbs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of a segment.
+    pub text_color: Color,
+    /// The [`Background`] of a segment, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of a segment.
+    pub border: Border,
+    /// The [`Color`] of the separators.
+    pub separator_color: Color,
+}
+
+/// The theme catalog of some [`Breadcrumbs`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the overflow menu of the [`Breadcrumbs`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for some [`Breadcrumbs`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of some [`Breadcrumbs`].
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let base = Style {
+        text_color: palette.primary.base.color,
+        background: None,
+        border: Border::default().rounded(4.0),
+        separator_color: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            ..base
+        },
+        Status::Disabled => Style {
+            text_color: palette.background.base.text,
+            ..base
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapsed_count_keeps_first_and_last() {
+        let widths = [50.0, 50.0, 50.0, 50.0];
+
+        assert_eq!(collapsed_count(&widths, 10.0, 20.0, 1000.0), 0);
+        assert_eq!(collapsed_count(&widths, 10.0, 20.0, 200.0), 1);
+        assert_eq!(collapsed_count(&widths, 10.0, 20.0, 150.0), 2);
+        assert_eq!(collapsed_count(&widths, 10.0, 20.0, 10.0), 2);
+        assert_eq!(collapsed_count(&widths[..2], 10.0, 20.0, 10.0), 0);
+    }
+}
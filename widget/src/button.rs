@@ -16,12 +16,14 @@
 //!     button("Press me!").on_press(Message::ButtonPressed).into()
 //! }
 //! ```
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
@@ -75,10 +77,13 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
+    on_press_repeat: Option<(Duration, Message)>,
+    repeat_delay: Duration,
     width: Length,
     height: Length,
     padding: Padding,
     clip: bool,
+    transition: Option<Duration>,
     class: Theme::Class<'a>,
     status: Option<Status>,
 }
@@ -110,10 +115,13 @@ where
         Button {
             content,
             on_press: None,
+            on_press_repeat: None,
+            repeat_delay: DEFAULT_REPEAT_DELAY,
             width: size.width.fluid(),
             height: size.height.fluid(),
             padding: DEFAULT_PADDING,
             clip: false,
+            transition: None,
             class: Theme::default(),
             status: None,
         }
@@ -139,7 +147,8 @@ where
 
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
-    /// Unless `on_press` is called, the [`Button`] will be disabled.
+    /// Unless `on_press` or [`Button::on_press_repeat`] is called, the
+    /// [`Button`] will be disabled.
     pub fn on_press(mut self, on_press: Message) -> Self {
         self.on_press = Some(OnPress::Direct(on_press));
         self
@@ -167,6 +176,29 @@ where
         self
     }
 
+    /// Sets the message that will be produced repeatedly, every `interval`,
+    /// while the [`Button`] is held pressed.
+    ///
+    /// The message is produced as soon as the [`Button`] is pressed and then
+    /// again after the [`Button::repeat_delay`], as long as the cursor stays
+    /// over the [`Button`]. It is useful for spinner arrows, scrolling arrows,
+    /// or volume controls.
+    ///
+    /// Any [`Button::on_press`] message will still be produced on release.
+    pub fn on_press_repeat(mut self, interval: Duration, message: Message) -> Self {
+        self.on_press_repeat = Some((interval, message));
+        self
+    }
+
+    /// Sets the delay before the [`Button::on_press_repeat`] message starts
+    /// repeating while the [`Button`] is held pressed.
+    ///
+    /// By default, it is [`DEFAULT_REPEAT_DELAY`].
+    pub fn repeat_delay(mut self, delay: Duration) -> Self {
+        self.repeat_delay = delay;
+        self
+    }
+
     /// Sets whether the contents of the [`Button`] should be clipped on
     /// overflow.
     pub fn clip(mut self, clip: bool) -> Self {
@@ -174,6 +206,15 @@ where
         self
     }
 
+    /// Sets the [`Duration`] of the transitions between the styles of the
+    /// [`Button`] when its [`Status`] changes.
+    ///
+    /// By default, the style of a [`Button`] changes right away.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -191,11 +232,49 @@ where
         self.class = class.into();
         self
     }
+
+    /// Returns whether the [`Button`] can be pressed.
+    fn is_enabled(&self) -> bool {
+        self.on_press.is_some() || self.on_press_repeat.is_some()
+    }
+
+    /// Returns whether the [`mouse::Cursor`] is over the [`Button`], taking
+    /// the shape of its content into account.
+    fn is_over(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> bool {
+        cursor
+            .position_over(layout.bounds())
+            .is_some_and(|position| {
+                self.content.as_widget().hit_test(
+                    &tree.children[0],
+                    layout.children().next().unwrap(),
+                    renderer,
+                    position,
+                ) != Some(false)
+            })
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Default)]
 struct State {
     is_pressed: bool,
+    next_repeat: Option<Instant>,
+    status: Option<Status>,
+    transition: Option<Transition>,
+    now: Option<Instant>,
+}
+
+/// The transition of a [`Button`] between the styles of two statuses.
+#[derive(Debug)]
+struct Transition {
+    from: Status,
+    to: Status,
+    progress: Animation<bool>,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -285,50 +364,79 @@ where
             return;
         }
 
+        let is_over = self.is_over(tree, layout, cursor, renderer);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
-                    let bounds = layout.bounds();
+                if self.is_enabled() && is_over {
+                    let state = tree.state.downcast_mut::<State>();
+
+                    state.is_pressed = true;
 
-                    if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
+                    if let Some((_interval, message)) = &self.on_press_repeat {
+                        let next_repeat = Instant::now() + self.repeat_delay;
 
-                        state.is_pressed = true;
+                        state.next_repeat = Some(next_repeat);
 
-                        shell.capture_event();
+                        shell.publish(message.clone());
+                        shell.request_redraw_at(next_repeat);
                     }
+
+                    shell.capture_event();
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = &self.on_press {
-                    let state = tree.state.downcast_mut::<State>();
-
-                    if state.is_pressed {
-                        state.is_pressed = false;
-
-                        let bounds = layout.bounds();
+                let state = tree.state.downcast_mut::<State>();
 
-                        if cursor.is_over(bounds) {
-                            shell.publish(on_press.get());
-                        }
+                if state.is_pressed {
+                    state.is_pressed = false;
+                    state.next_repeat = None;
 
-                        shell.capture_event();
+                    if is_over && let Some(on_press) = &self.on_press {
+                        shell.publish(on_press.get());
                     }
+
+                    shell.capture_event();
                 }
             }
             Event::Touch(touch::Event::FingerLost { .. }) => {
                 let state = tree.state.downcast_mut::<State>();
 
                 state.is_pressed = false;
+                state.next_repeat = None;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some((interval, message)) = &self.on_press_repeat
+                    && let Some(next_repeat) = state.next_repeat
+                {
+                    if *now >= next_repeat {
+                        // Repeating pauses while the cursor is away
+                        if is_over {
+                            shell.publish(message.clone());
+                        }
+
+                        let next = next_repeat + *interval;
+
+                        // Skip the repetitions missed while the window was idle
+                        state.next_repeat =
+                            Some(if next <= *now { *now + *interval } else { next });
+                    }
+
+                    if let Some(next_repeat) = state.next_repeat {
+                        shell.request_redraw_at(next_repeat);
+                    }
+                }
             }
             _ => {}
         }
 
-        let current_status = if self.on_press.is_none() {
+        let current_status = if !self.is_enabled() {
             Status::Disabled
-        } else if cursor.is_over(layout.bounds()) {
+        } else if is_over {
             let state = tree.state.downcast_ref::<State>();
 
             if state.is_pressed {
@@ -340,8 +448,48 @@ where
             Status::Active
         };
 
-        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             self.status = Some(current_status);
+
+            if let Some(duration) = self.transition {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(status) = state.status
+                    && status != current_status
+                {
+                    match &mut state.transition {
+                        // Going back and forth between the same statuses
+                        // reverses the transition smoothly
+                        Some(transition)
+                            if transition.progress.is_animating(*now)
+                                && (current_status == transition.from
+                                    || current_status == transition.to) =>
+                        {
+                            transition
+                                .progress
+                                .go_mut(current_status == transition.to, *now);
+                        }
+                        transition => {
+                            *transition = Some(Transition {
+                                from: status,
+                                to: current_status,
+                                progress: Animation::new(false).duration(duration).go(true, *now),
+                            });
+                        }
+                    }
+                }
+
+                if state
+                    .transition
+                    .as_ref()
+                    .is_some_and(|transition| transition.progress.is_animating(*now))
+                {
+                    shell.request_redraw();
+                }
+
+                state.status = Some(current_status);
+                state.now = Some(*now);
+            }
         } else if self.status.is_some_and(|status| status != current_status) {
             shell.request_redraw();
         }
@@ -359,7 +507,19 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let style = theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
+        let state = tree.state.downcast_ref::<State>();
+
+        let style = match (&state.transition, state.now) {
+            (Some(transition), Some(now))
+                if self.transition.is_some() && transition.progress.is_animating(now) =>
+            {
+                let from = theme.style(&self.class, transition.from);
+                let to = theme.style(&self.class, transition.to);
+
+                from.interpolate(&to, transition.progress.interpolate(0.0, 1.0, now))
+            }
+            _ => theme.style(&self.class, self.status.unwrap_or(Status::Disabled)),
+        };
 
         if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
             renderer.fill_quad(
@@ -396,15 +556,15 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
-        _renderer: &Renderer,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        let is_mouse_over = cursor.is_over(layout.bounds());
+        let is_mouse_over = self.is_over(tree, layout, cursor, renderer);
 
-        if is_mouse_over && self.on_press.is_some() {
+        if is_mouse_over && self.is_enabled() {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -441,6 +601,10 @@ where
     }
 }
 
+/// The default delay before the [`Button::on_press_repeat`] message starts
+/// repeating.
+pub const DEFAULT_REPEAT_DELAY: Duration = Duration::from_millis(500);
+
 /// The default [`Padding`] of a [`Button`].
 pub const DEFAULT_PADDING: Padding = Padding {
     top: 5.0,
@@ -502,6 +666,18 @@ impl Default for Style {
     }
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
+            snap: other.snap,
+        }
+    }
+}
+
 /// The theme catalog of a [`Button`].
 ///
 /// All themes that can be used with [`Button`]
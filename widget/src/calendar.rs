@@ -0,0 +1,1255 @@
+//! Calendars display the days of a month, with optional markers for events,
+//! and let users select a date or a range of dates.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::calendar;
+//! use iced::widget::calendar::{Date, Marker, Month, Selection};
+//!
+//! struct State {
+//!     month: Month,
+//!     selection: Selection,
+//!     meetings: Vec<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Navigated(Month),
+//!     Selected(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     calendar(state.month)
+//!         .selection(state.selection)
+//!         .on_select(Message::Selected)
+//!         .on_navigate(Message::Navigated)
+//!         .marker(|date| {
+//!             let meetings = state.meetings.iter().filter(|meeting| **meeting == date).count();
+//!
+//!             (meetings > 0).then_some(Marker::Dots(meetings))
+//!         })
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Navigated(month) => {
+//!             state.month = month;
+//!         }
+//!         Message::Selected(date) => {
+//!             state.selection = state.selection.extend(date);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::SystemTime;
+use crate::core::touch;
+use crate::core::widget::tree::Tree;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::fmt;
+
+/// The maximum amount of dots displayed by a [`Marker::Dots`].
+const MAX_DOTS: usize = 3;
+
+/// The diameter of a dot of a [`Marker::Dots`].
+const DOT_SIZE: f32 = 4.0;
+
+/// A month view displaying the days of a [`Month`].
+///
+/// The displayed [`Month`] is controlled by the application; the navigation
+/// arrows are shown once [`Calendar::on_navigate`] is set.
+pub struct Calendar<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    month: Month,
+    selection: Selection,
+    on_select: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    on_navigate: Option<Box<dyn Fn(Month) -> Message + 'a>>,
+    marker: Option<Box<dyn Fn(Date) -> Option<Marker> + 'a>>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    today: Option<Date>,
+    locale: Locale,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Calendar`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(10.0);
+
+    /// Creates a new [`Calendar`] displaying the given [`Month`].
+    pub fn new(month: Month) -> Self {
+        Self {
+            month,
+            selection: Selection::None,
+            on_select: None,
+            on_navigate: None,
+            marker: None,
+            min: None,
+            max: None,
+            is_disabled: None,
+            today: Some(Date::today()),
+            locale: Locale::default(),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Selection`] of the [`Calendar`].
+    pub fn selection(mut self, selection: impl Into<Selection>) -> Self {
+        self.selection = selection.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Date`] is pressed.
+    ///
+    /// Dates cannot be pressed unless this is set.
+    pub fn on_select(mut self, on_select: impl Fn(Date) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when the user navigates to
+    /// another [`Month`].
+    ///
+    /// The navigation arrows are not displayed unless this is set.
+    pub fn on_navigate(mut self, on_navigate: impl Fn(Month) -> Message + 'a) -> Self {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+
+    /// Sets the function that returns the [`Marker`] of a [`Date`], if any;
+    /// like the events scheduled on that day.
+    pub fn marker(mut self, marker: impl Fn(Date) -> Option<Marker> + 'a) -> Self {
+        self.marker = Some(Box::new(marker));
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that decides whether a [`Date`] is disabled; that is,
+    /// whether it cannot be selected.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the [`Date`] highlighted as today, if any.
+    ///
+    /// By default, it is [`Date::today`].
+    pub fn today(mut self, today: impl Into<Option<Date>>) -> Self {
+        self.today = today.into();
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`Calendar`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the width of the [`Calendar`].
+    ///
+    /// The columns of the days are stretched to fill it.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Calendar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Calendar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Calendar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Calendar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns whether the given [`Date`] can be selected.
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self
+                .is_disabled
+                .as_ref()
+                .is_some_and(|is_disabled| is_disabled(date))
+    }
+
+    /// Returns whether the given [`Month`] can be navigated to.
+    fn is_reachable(&self, month: Month) -> bool {
+        self.min.is_none_or(|min| month >= Month::of(min))
+            && self.max.is_none_or(|max| month <= Month::of(max))
+    }
+
+    fn resolve_text_size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the height of a row of the calendar.
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        (self.resolve_text_size(renderer).0 * 2.5).round()
+    }
+
+    /// Returns the bounds of the cell in the given row and column.
+    fn cell(&self, bounds: Rectangle, row: usize, column: usize, renderer: &Renderer) -> Rectangle {
+        let inner = bounds.shrink(self.padding);
+        let width = inner.width / 7.0;
+        let height = self.row_height(renderer);
+
+        Rectangle {
+            x: inner.x + column as f32 * width,
+            y: inner.y + row as f32 * height,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the interactive [`Region`] under the cursor, if any.
+    fn hovered_region(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> Option<Region> {
+        let inner = bounds.shrink(self.padding);
+        let position = cursor.position_in(inner)?;
+
+        let row = (position.y / self.row_height(renderer)) as usize;
+        let column = ((position.x / (inner.width / 7.0)) as usize).min(6);
+
+        let region = match (row, column) {
+            (0, 0) => Region::PreviousYear,
+            (0, 1) => Region::Previous,
+            (0, 5) => Region::Next,
+            (0, 6) => Region::NextYear,
+            (2.., _) => Region::Day(Grid::new(self.month, self.locale).day(row - 2, column)?),
+            _ => return None,
+        };
+
+        let is_enabled = match region {
+            Region::Day(date) => self.on_select.is_some() && self.is_enabled(date),
+            _ => {
+                self.on_navigate.is_some()
+                    && region
+                        .target(self.month)
+                        .is_some_and(|month| self.is_reachable(month))
+            }
+        };
+
+        is_enabled.then_some(region)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Calendar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let row = self.row_height(renderer);
+
+        let intrinsic = Size::new(row * 7.0, row * (Grid::WEEKS + 2) as f32).expand(self.padding);
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        _tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(region) = self.hovered_region(bounds, cursor, renderer) else {
+                    return;
+                };
+
+                match region {
+                    Region::Day(date) => {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(date));
+                        }
+                    }
+                    _ => {
+                        if let Some(on_navigate) = &self.on_navigate
+                            && let Some(month) = region.target(self.month)
+                        {
+                            shell.publish(on_navigate(month));
+                        }
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if cursor.is_over(bounds) {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .hovered_region(layout.bounds(), cursor, renderer)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let grid = Grid::new(self.month, self.locale);
+        let hovered = self.hovered_region(bounds, cursor, renderer);
+
+        let text_size = self.resolve_text_size(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let fill_text = |renderer: &mut Renderer,
+                         content: String,
+                         size: Pixels,
+                         bounds: Rectangle,
+                         color: Color| {
+            renderer.fill_text(
+                Text {
+                    content,
+                    bounds: bounds.size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: None,
+                },
+                bounds.center(),
+                color,
+                *viewport,
+            );
+        };
+
+        // The circle centered in a cell
+        let circle = |cell: Rectangle| {
+            let side = cell.width.min(cell.height) - 4.0;
+
+            Rectangle::new(
+                Point::new(cell.center_x() - side / 2.0, cell.center_y() - side / 2.0),
+                Size::new(side, side),
+            )
+        };
+
+        let fill =
+            |renderer: &mut Renderer, bounds: Rectangle, radius: f32, background: Background| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::default().rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            };
+
+        // Header
+        let header = self.cell(bounds, 0, 0, renderer);
+
+        fill_text(
+            renderer,
+            format!(
+                "{} {}",
+                self.locale.months[self.month.month as usize - 1],
+                self.month.year
+            ),
+            text_size,
+            Rectangle {
+                width: bounds.shrink(self.padding).width,
+                ..header
+            },
+            style.header_text_color,
+        );
+
+        if self.on_navigate.is_some() {
+            for (region, column, arrow) in [
+                (Region::PreviousYear, 0, "«"),
+                (Region::Previous, 1, "‹"),
+                (Region::Next, 5, "›"),
+                (Region::NextYear, 6, "»"),
+            ] {
+                let cell = self.cell(bounds, 0, column, renderer);
+                let is_reachable = region
+                    .target(self.month)
+                    .is_some_and(|month| self.is_reachable(month));
+
+                if hovered == Some(region) {
+                    let circle = circle(cell);
+
+                    fill(
+                        renderer,
+                        circle,
+                        circle.width / 2.0,
+                        style.hovered_background,
+                    );
+                }
+
+                fill_text(
+                    renderer,
+                    arrow.to_owned(),
+                    text_size,
+                    cell,
+                    if is_reachable {
+                        style.text_color
+                    } else {
+                        style.disabled_text_color
+                    },
+                );
+            }
+        }
+
+        // Weekdays
+        for column in 0..7 {
+            fill_text(
+                renderer,
+                self.locale.weekdays[grid.weekday(column).index()].to_owned(),
+                text_size * 0.85,
+                self.cell(bounds, 1, column, renderer),
+                style.weekday_text_color,
+            );
+        }
+
+        // Days
+        for week in 0..Grid::WEEKS {
+            for column in 0..7 {
+                let Some(date) = grid.day(week, column) else {
+                    continue;
+                };
+
+                let cell = self.cell(bounds, week + 2, column, renderer);
+                let circle = circle(cell);
+                let radius = circle.width / 2.0;
+
+                let is_endpoint = self.selection.is_endpoint(date);
+
+                if let Selection::Range(start, end) = self.selection
+                    && start != end
+                    && date >= start
+                    && date <= end
+                {
+                    // Ranges are joined across the cells of a week
+                    let left = if date == start {
+                        cell.center_x()
+                    } else {
+                        cell.x
+                    };
+
+                    let right = if date == end {
+                        cell.center_x()
+                    } else {
+                        cell.x + cell.width
+                    };
+
+                    fill(
+                        renderer,
+                        Rectangle {
+                            x: left,
+                            y: circle.y,
+                            width: right - left,
+                            height: circle.height,
+                        },
+                        0.0,
+                        style.range_background,
+                    );
+                }
+
+                if is_endpoint {
+                    fill(renderer, circle, radius, style.selected_background);
+                } else if hovered == Some(Region::Day(date)) {
+                    fill(renderer, circle, radius, style.hovered_background);
+                }
+
+                if !is_endpoint && self.today == Some(date) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: circle,
+                            border: Border {
+                                color: style.today_color,
+                                width: 1.0,
+                                radius: radius.into(),
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                fill_text(
+                    renderer,
+                    date.day().to_string(),
+                    text_size,
+                    cell,
+                    if is_endpoint {
+                        style.selected_text_color
+                    } else if self.is_enabled(date) {
+                        style.text_color
+                    } else {
+                        style.disabled_text_color
+                    },
+                );
+
+                let Some(marker) = self.marker.as_ref().and_then(|marker| marker(date)) else {
+                    continue;
+                };
+
+                match marker {
+                    Marker::Dots(amount) => {
+                        let amount = amount.min(MAX_DOTS);
+                        let width = amount as f32 * DOT_SIZE * 2.0 - DOT_SIZE;
+                        let y = circle.y + circle.height - DOT_SIZE * 1.5;
+
+                        for dot in 0..amount {
+                            fill(
+                                renderer,
+                                Rectangle {
+                                    x: cell.center_x() - width / 2.0 + dot as f32 * DOT_SIZE * 2.0,
+                                    y,
+                                    width: DOT_SIZE,
+                                    height: DOT_SIZE,
+                                },
+                                DOT_SIZE / 2.0,
+                                style.dot_color.into(),
+                            );
+                        }
+                    }
+                    Marker::Badge(count) => {
+                        let size = text_size * 0.65;
+                        let height = size.0 * 1.4;
+                        let label = if count > 99 {
+                            "99+".to_owned()
+                        } else {
+                            count.to_string()
+                        };
+                        let width = (label.chars().count() as f32 * size.0 * 0.6 + size.0 * 0.5)
+                            .max(height);
+
+                        let badge = Rectangle {
+                            x: circle.x + circle.width - width * 0.6,
+                            y: circle.y - height * 0.2,
+                            width,
+                            height,
+                        };
+
+                        fill(renderer, badge, height / 2.0, style.badge_background);
+                        fill_text(renderer, label, size, badge, style.badge_text_color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Calendar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(calendar: Calendar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(calendar)
+    }
+}
+
+/// The selected dates of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Selection {
+    /// No date is selected.
+    #[default]
+    None,
+    /// A single [`Date`] is selected.
+    Date(Date),
+    /// The dates between the given ones—both included—are selected.
+    Range(Date, Date),
+}
+
+impl Selection {
+    /// Creates a [`Selection::Range`] between the given dates, in any order.
+    pub fn range(a: Date, b: Date) -> Self {
+        Self::Range(a.min(b), a.max(b))
+    }
+
+    /// Returns whether the given [`Date`] is selected.
+    pub fn contains(self, date: Date) -> bool {
+        match self {
+            Self::None => false,
+            Self::Date(selected) => selected == date,
+            Self::Range(start, end) => date >= start && date <= end,
+        }
+    }
+
+    /// Returns the [`Selection`] after pressing the given [`Date`] when
+    /// selecting a range.
+    ///
+    /// A single selected [`Date`] becomes the start of a range ending at the
+    /// pressed one; otherwise, the pressed [`Date`] starts a new range.
+    pub fn extend(self, date: Date) -> Self {
+        match self {
+            Self::Date(selected) if selected != date => Self::range(selected, date),
+            _ => Self::Date(date),
+        }
+    }
+
+    fn is_endpoint(self, date: Date) -> bool {
+        match self {
+            Self::None => false,
+            Self::Date(selected) => selected == date,
+            Self::Range(start, end) => date == start || date == end,
+        }
+    }
+}
+
+impl From<Date> for Selection {
+    fn from(date: Date) -> Self {
+        Self::Date(date)
+    }
+}
+
+impl From<Option<Date>> for Selection {
+    fn from(date: Option<Date>) -> Self {
+        date.map_or(Self::None, Self::Date)
+    }
+}
+
+/// The marker of a day of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Marker {
+    /// Some dots below the day—up to three.
+    Dots(usize),
+    /// A badge with a count in the corner of the day.
+    Badge(usize),
+}
+
+/// An interactive region of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    PreviousYear,
+    Previous,
+    Next,
+    NextYear,
+    Day(Date),
+}
+
+impl Region {
+    /// Returns the [`Month`] navigated to when pressing the [`Region`].
+    fn target(self, month: Month) -> Option<Month> {
+        match self {
+            Self::PreviousYear => Some(Month {
+                year: month.year - 1,
+                ..month
+            }),
+            Self::Previous => Some(month.previous()),
+            Self::Next => Some(month.next()),
+            Self::NextYear => Some(Month {
+                year: month.year + 1,
+                ..month
+            }),
+            Self::Day(_) => None,
+        }
+    }
+}
+
+/// A calendar date, in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from the given year, month, and day.
+    ///
+    /// Months and days start at `1`. Returns `None` if the date does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`], in UTC.
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / 86_400)
+            .unwrap_or_default();
+
+        Self::from_days(days as i64)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at `1`.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at `1`.
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn days(self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let month = i64::from(self.month);
+
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates a [`Date`] from the amount of days since 1970-01-01.
+    pub(crate) fn from_days(days: i64) -> Self {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719_468;
+
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Returns the index of the [`Weekday`], starting at `0` for Monday.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % 7]
+    }
+}
+
+/// The names of the months and weekdays, and the first day of the week of
+/// a calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The first day of the week; the first column of the calendar.
+    pub first_weekday: Weekday,
+    /// The names of the months, starting with January.
+    pub months: [&'static str; 12],
+    /// The short names of the weekdays, starting with Monday.
+    pub weekdays: [&'static str; 7],
+}
+
+impl Locale {
+    /// The English [`Locale`], with weeks starting on Sunday.
+    pub const ENGLISH: Self = Self {
+        first_weekday: Weekday::Sunday,
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+    };
+
+    /// The German [`Locale`], with weeks starting on Monday.
+    pub const GERMAN: Self = Self {
+        first_weekday: Weekday::Monday,
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    };
+
+    /// The French [`Locale`], with weeks starting on Monday.
+    pub const FRENCH: Self = Self {
+        first_weekday: Weekday::Monday,
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        weekdays: ["lu", "ma", "me", "je", "ve", "sa", "di"],
+    };
+
+    /// The Spanish [`Locale`], with weeks starting on Monday.
+    pub const SPANISH: Self = Self {
+        first_weekday: Weekday::Monday,
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        weekdays: ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+    };
+
+    /// Returns the [`Locale`] with the given first day of the week.
+    pub fn first_weekday(self, first_weekday: Weekday) -> Self {
+        Self {
+            first_weekday,
+            ..self
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// A month of a specific year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Month {
+    year: i32,
+    month: u32,
+}
+
+impl Month {
+    /// Creates a new [`Month`] from the given year and month, starting at `1`.
+    ///
+    /// Returns `None` if the month does not exist.
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        (1..=12).contains(&month).then_some(Self { year, month })
+    }
+
+    /// Returns the [`Month`] of the given [`Date`].
+    pub fn of(date: Date) -> Self {
+        Self {
+            year: date.year,
+            month: date.month,
+        }
+    }
+
+    /// Returns the year of the [`Month`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the year of the [`Month`], starting at `1`.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// Returns the [`Month`] before this one.
+    pub fn previous(self) -> Self {
+        if self.month == 1 {
+            Self {
+                year: self.year - 1,
+                month: 12,
+            }
+        } else {
+            Self {
+                month: self.month - 1,
+                ..self
+            }
+        }
+    }
+
+    /// Returns the [`Month`] after this one.
+    pub fn next(self) -> Self {
+        if self.month == 12 {
+            Self {
+                year: self.year + 1,
+                month: 1,
+            }
+        } else {
+            Self {
+                month: self.month + 1,
+                ..self
+            }
+        }
+    }
+}
+
+/// The days of a [`Month`], laid out in weeks.
+pub(crate) struct Grid {
+    month: Month,
+    locale: Locale,
+    offset: usize,
+}
+
+impl Grid {
+    /// The amount of weeks of a [`Grid`]; enough for any [`Month`].
+    pub(crate) const WEEKS: usize = 6;
+
+    pub(crate) fn new(month: Month, locale: Locale) -> Self {
+        let first = Date {
+            year: month.year,
+            month: month.month,
+            day: 1,
+        };
+
+        let offset = (first.weekday().index() + 7 - locale.first_weekday.index()) % 7;
+
+        Self {
+            month,
+            locale,
+            offset,
+        }
+    }
+
+    /// Returns the [`Weekday`] of the given column.
+    pub(crate) fn weekday(&self, column: usize) -> Weekday {
+        Weekday::from_index(self.locale.first_weekday.index() + column)
+    }
+
+    /// Returns the [`Date`] in the given week and column, if it is part of
+    /// the [`Month`].
+    pub(crate) fn day(&self, week: usize, column: usize) -> Option<Date> {
+        if week >= Self::WEEKS {
+            return None;
+        }
+
+        let day = (week * 7 + column + 1).checked_sub(self.offset)?;
+
+        Date::from_ymd(self.month.year, self.month.month, day as u32)
+    }
+}
+
+/// The appearance of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the calendar.
+    pub background: Background,
+    /// The [`Border`] of the calendar.
+    pub border: Border,
+    /// The text [`Color`] of the month and year.
+    pub header_text_color: Color,
+    /// The text [`Color`] of the days and the navigation arrows.
+    pub text_color: Color,
+    /// The text [`Color`] of the weekdays.
+    pub weekday_text_color: Color,
+    /// The text [`Color`] of the disabled days and navigation arrows.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered day or navigation arrow.
+    pub hovered_background: Background,
+    /// The [`Background`] of a selected day, and the ends of a selected
+    /// range.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected day.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the days inside of a selected range.
+    pub range_background: Background,
+    /// The [`Color`] of the ring around today.
+    pub today_color: Color,
+    /// The [`Color`] of the dots of a [`Marker::Dots`].
+    pub dot_color: Color,
+    /// The [`Background`] of a [`Marker::Badge`].
+    pub badge_background: Background,
+    /// The text [`Color`] of a [`Marker::Badge`].
+    pub badge_text_color: Color,
+}
+
+/// The theme catalog of a [`Calendar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Calendar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Calendar`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        header_text_color: palette.background.base.text,
+        text_color: palette.background.base.text,
+        weekday_text_color: palette.background.strongest.color,
+        disabled_text_color: palette.background.strong.color,
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        range_background: palette.primary.weak.color.into(),
+        today_color: palette.primary.strong.color,
+        dot_color: palette.primary.base.color,
+        badge_background: palette.danger.base.color.into(),
+        badge_text_color: palette.danger.base.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_round_trip_through_days() {
+        for (year, month, day, days) in [
+            (1970, 1, 1, 0),
+            (2000, 2, 29, 11_016),
+            (1969, 12, 31, -1),
+            (2024, 12, 31, 20_088),
+        ] {
+            let date = Date::from_ymd(year, month, day).unwrap();
+
+            assert_eq!(date.days(), days);
+            assert_eq!(Date::from_days(days), date);
+        }
+
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+        assert_eq!(
+            Date::from_ymd(2024, 10, 14).map(Date::weekday),
+            Some(Weekday::Monday)
+        );
+    }
+
+    #[test]
+    fn selections_extend_into_ranges() {
+        let date = |day| Date::from_ymd(2024, 5, day).unwrap();
+
+        let selection = Selection::None.extend(date(10));
+        assert_eq!(selection, Selection::Date(date(10)));
+
+        let selection = selection.extend(date(3));
+        assert_eq!(selection, Selection::Range(date(3), date(10)));
+        assert!(selection.contains(date(7)));
+        assert!(!selection.contains(date(11)));
+
+        assert_eq!(selection.extend(date(20)), Selection::Date(date(20)));
+    }
+}
@@ -56,8 +56,8 @@ pub use crate::Action;
 pub use crate::core::event::Event;
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
-    Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Stroke, Style, Text, fill, gradient,
-    path, stroke,
+    Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Pattern, RichText, Stroke, Style,
+    Text, fill, gradient, path, pattern, stroke, text,
 };
 
 use crate::core::event;
@@ -77,6 +77,10 @@ use std::marker::PhantomData;
 /// change or it is explicitly cleared.
 pub type Cache<Renderer = crate::Renderer> = geometry::Cache<Renderer>;
 
+/// A stack of named caches that can be invalidated independently; optionally
+/// split in tiles, so only a dirty region is redrawn.
+pub type Layers<Renderer = crate::Renderer, Key = &'static str> = geometry::Layers<Renderer, Key>;
+
 /// The geometry supported by a renderer.
 pub type Geometry<Renderer = crate::Renderer> = <Renderer as geometry::Renderer>::Geometry;
 
@@ -0,0 +1,1178 @@
+//! Charts plot series of data as lines, bars, or areas.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::chart;
+//!
+//! struct State {
+//!     temperature: Vec<(f32, f32)>,
+//!     rainfall: Vec<(f32, f32)>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     chart([
+//!         chart::bar("Rainfall", &state.rainfall),
+//!         chart::line("Temperature", &state.temperature),
+//!     ])
+//!     .height(300)
+//!     .into()
+//! }
+//! ```
+use crate::canvas::{self, LineCap, LineJoin, Path, Stroke};
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme,
+    Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// The space around the plot of a [`Chart`] and between its labels.
+const PADDING: f32 = 8.0;
+
+/// The approximate width of a character of the labels of a [`Chart`],
+/// relative to their text size.
+const CHARACTER_WIDTH: f32 = 0.6;
+
+/// The factor applied to the visible domain of a [`Chart`] for every line
+/// scrolled.
+const ZOOM_FACTOR: f32 = 0.9;
+
+/// The smallest visible domain of a [`Chart`], relative to the domain of
+/// its data.
+const MIN_ZOOM: f32 = 0.001;
+
+/// Creates a new line [`Series`] with the given label and points.
+pub fn line<'a>(label: impl Into<String>, points: impl Into<Cow<'a, [(f32, f32)]>>) -> Series<'a> {
+    Series::new(Kind::Line, label, points)
+}
+
+/// Creates a new bar [`Series`] with the given label and points.
+pub fn bar<'a>(label: impl Into<String>, points: impl Into<Cow<'a, [(f32, f32)]>>) -> Series<'a> {
+    Series::new(Kind::Bar, label, points)
+}
+
+/// Creates a new area [`Series`] with the given label and points.
+pub fn area<'a>(label: impl Into<String>, points: impl Into<Cow<'a, [(f32, f32)]>>) -> Series<'a> {
+    Series::new(Kind::Area, label, points)
+}
+
+/// A plot of some [`Series`] of data, with axes, ticks, and a legend.
+///
+/// Hovering a [`Chart`] shows the values of every series closest to the
+/// cursor. Scrolling zooms the horizontal domain around the cursor, dragging
+/// pans it, and double-clicking resets it.
+///
+/// The plot is cached and only redrawn when the series, the visible domain,
+/// the style, or the size of the [`Chart`] change.
+pub struct Chart<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    series: Vec<Series<'a>>,
+    width: Length,
+    height: Length,
+    x_ticks: usize,
+    y_ticks: usize,
+    x_format: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    y_format: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    show_legend: bool,
+    is_zoomable: bool,
+    text_size: Pixels,
+    class: Theme::Class<'a>,
+}
+
+/// A series of data of a [`Chart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series<'a> {
+    kind: Kind,
+    label: String,
+    points: Cow<'a, [(f32, f32)]>,
+    color: Option<Color>,
+}
+
+/// The way a [`Series`] is plotted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A line joining the points.
+    Line,
+    /// A bar for every point, grouped with the bars of other series.
+    Bar,
+    /// A line joining the points, filled down to zero.
+    Area,
+}
+
+impl<'a> Series<'a> {
+    /// Creates a new [`Series`] of the given [`Kind`] with the given label
+    /// and points.
+    pub fn new(
+        kind: Kind,
+        label: impl Into<String>,
+        points: impl Into<Cow<'a, [(f32, f32)]>>,
+    ) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            points: points.into(),
+            color: None,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Series`].
+    ///
+    /// By default, the color is picked from the [`Style`] of the [`Chart`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl<'a, Theme> Chart<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Chart`] with the given [`Series`].
+    pub fn new(series: impl IntoIterator<Item = Series<'a>>) -> Self {
+        Self {
+            series: series.into_iter().collect(),
+            width: Length::Fill,
+            height: Length::Fixed(200.0),
+            x_ticks: 6,
+            y_ticks: 5,
+            x_format: None,
+            y_format: None,
+            show_legend: true,
+            is_zoomable: true,
+            text_size: Pixels(12.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Series`] to the [`Chart`].
+    pub fn push(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the width of the [`Chart`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Chart`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the approximate amount of ticks of the horizontal and vertical
+    /// axes of the [`Chart`].
+    pub fn ticks(mut self, x: usize, y: usize) -> Self {
+        self.x_ticks = x;
+        self.y_ticks = y;
+        self
+    }
+
+    /// Sets the function that formats the labels of the horizontal axis of
+    /// the [`Chart`].
+    pub fn x_format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.x_format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the function that formats the labels of the vertical axis of the
+    /// [`Chart`].
+    pub fn y_format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.y_format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets whether the legend of the [`Chart`] is shown.
+    pub fn legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    /// Sets whether the domain of the [`Chart`] can be zoomed and panned.
+    pub fn zoomable(mut self, is_zoomable: bool) -> Self {
+        self.is_zoomable = is_zoomable;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Chart`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chart`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns a hash of everything that affects the cached plot of the
+    /// [`Chart`], except for its visible domain.
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = rustc_hash::FxHasher::default();
+
+        for series in &self.series {
+            series.kind.hash(&mut hasher);
+            series.label.hash(&mut hasher);
+            series.color.map(Color::into_rgba8).hash(&mut hasher);
+
+            for (x, y) in series.points.iter() {
+                x.to_bits().hash(&mut hasher);
+                y.to_bits().hash(&mut hasher);
+            }
+        }
+
+        self.x_ticks.hash(&mut hasher);
+        self.y_ticks.hash(&mut hasher);
+        self.show_legend.hash(&mut hasher);
+        self.text_size.0.to_bits().hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Returns the domain covering all the data of the [`Chart`].
+    fn domain(&self) -> Domain {
+        let points = || {
+            self.series
+                .iter()
+                .flat_map(|series| series.points.iter())
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+        };
+
+        let (mut x_min, mut x_max, mut y_min, mut y_max) = points().fold(
+            (
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+            ),
+            |(x_min, x_max, y_min, y_max), (x, y)| {
+                (x_min.min(*x), x_max.max(*x), y_min.min(*y), y_max.max(*y))
+            },
+        );
+
+        if x_min > x_max {
+            return Domain {
+                x: (0.0, 1.0),
+                y: (0.0, 1.0),
+            };
+        }
+
+        let has_baseline = self
+            .series
+            .iter()
+            .any(|series| matches!(series.kind, Kind::Bar | Kind::Area));
+
+        if has_baseline {
+            y_min = y_min.min(0.0);
+            y_max = y_max.max(0.0);
+        }
+
+        if let Some(slot) = self.bar_slot(x_min, x_max) {
+            x_min -= slot / 2.0;
+            x_max += slot / 2.0;
+        }
+
+        if x_min == x_max {
+            x_min -= 1.0;
+            x_max += 1.0;
+        }
+
+        if y_min == y_max {
+            y_min -= 1.0;
+            y_max += 1.0;
+        } else {
+            let margin = (y_max - y_min) * 0.05;
+
+            if y_max != 0.0 || !has_baseline {
+                y_max += margin;
+            }
+
+            if y_min != 0.0 || !has_baseline {
+                y_min -= margin;
+            }
+        }
+
+        Domain {
+            x: (x_min, x_max),
+            y: (y_min, y_max),
+        }
+    }
+
+    /// Returns the horizontal space taken by the bars at a single point, if
+    /// the [`Chart`] has any bar series.
+    fn bar_slot(&self, x_min: f32, x_max: f32) -> Option<f32> {
+        let points = self
+            .series
+            .iter()
+            .filter(|series| series.kind == Kind::Bar)
+            .map(|series| series.points.len())
+            .max()?;
+
+        Some(if points > 1 {
+            (x_max - x_min) / (points - 1) as f32
+        } else {
+            1.0
+        })
+    }
+
+    /// Returns the visible domain of the [`Chart`].
+    fn visible(&self, state: &State<impl geometry::Renderer>) -> Domain {
+        let domain = self.domain();
+
+        match state.zoom {
+            Some(x) => Domain { x, ..domain },
+            None => domain,
+        }
+    }
+
+    /// Returns the [`Plot`] of the [`Chart`] for the given size and domain.
+    fn plot(&self, size: Size, domain: Domain) -> Plot {
+        let text_size = self.text_size.0;
+
+        let (x_ticks, x_step) = ticks(domain.x.0, domain.x.1, self.x_ticks);
+        let (y_ticks, y_step) = ticks(domain.y.0, domain.y.1, self.y_ticks);
+
+        let y_labels: Vec<String> = y_ticks
+            .iter()
+            .map(|tick| self.y_label(*tick, y_step))
+            .collect();
+
+        let left = y_labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0) as f32
+            * text_size
+            * CHARACTER_WIDTH
+            + PADDING * 2.0;
+
+        let top = if self.show_legend && !self.series.is_empty() {
+            text_size * 1.5 + PADDING
+        } else {
+            PADDING
+        };
+
+        let bottom = text_size * 1.5 + PADDING;
+
+        Plot {
+            area: Rectangle {
+                x: left,
+                y: top,
+                width: (size.width - left - PADDING).max(0.0),
+                height: (size.height - top - bottom).max(0.0),
+            },
+            domain,
+            x_ticks,
+            x_step,
+            y_ticks,
+            y_labels,
+        }
+    }
+
+    fn x_label(&self, value: f32, step: f32) -> String {
+        match &self.x_format {
+            Some(format) => format(value),
+            None => format_tick(value, step),
+        }
+    }
+
+    fn y_label(&self, value: f32, step: f32) -> String {
+        match &self.y_format {
+            Some(format) => format(value),
+            None => format_tick(value, step),
+        }
+    }
+
+    fn color(&self, index: usize, style: &Style) -> Color {
+        self.series[index]
+            .color
+            .unwrap_or(style.palette[index % style.palette.len()])
+    }
+
+    fn label(&self, content: String, position: Point, color: Color) -> canvas::Text {
+        canvas::Text {
+            content,
+            position,
+            color,
+            size: self.text_size,
+            ..canvas::Text::default()
+        }
+    }
+
+    /// Draws the axes, the ticks, the series, and the legend of the
+    /// [`Chart`].
+    fn draw_plot<Renderer>(&self, frame: &mut canvas::Frame<Renderer>, plot: &Plot, style: &Style)
+    where
+        Renderer: geometry::Renderer,
+    {
+        let area = plot.area;
+
+        if let Some(background) = style.background {
+            frame.fill_rectangle(Point::ORIGIN, frame.size(), background);
+        }
+
+        let grid = Stroke::default()
+            .with_color(style.grid_color)
+            .with_width(1.0);
+
+        for (tick, label) in plot.y_ticks.iter().zip(&plot.y_labels) {
+            let y = plot.y(*tick);
+
+            frame.stroke(
+                &Path::line(Point::new(area.x, y), Point::new(area.x + area.width, y)),
+                grid,
+            );
+
+            frame.fill_text(canvas::Text {
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                ..self.label(
+                    label.clone(),
+                    Point::new(area.x - PADDING, y),
+                    style.text_color,
+                )
+            });
+        }
+
+        for tick in &plot.x_ticks {
+            let x = plot.x(*tick);
+
+            frame.stroke(
+                &Path::line(Point::new(x, area.y), Point::new(x, area.y + area.height)),
+                grid,
+            );
+
+            frame.fill_text(canvas::Text {
+                align_x: text::Alignment::Center,
+                ..self.label(
+                    self.x_label(*tick, plot.x_step),
+                    Point::new(x, area.y + area.height + PADDING / 2.0),
+                    style.text_color,
+                )
+            });
+        }
+
+        let axis = Stroke::default()
+            .with_color(style.axis_color)
+            .with_width(1.0);
+
+        frame.stroke(
+            &Path::line(
+                Point::new(area.x, area.y),
+                Point::new(area.x, area.y + area.height),
+            ),
+            axis,
+        );
+
+        frame.stroke(
+            &Path::line(
+                Point::new(area.x, area.y + area.height),
+                Point::new(area.x + area.width, area.y + area.height),
+            ),
+            axis,
+        );
+
+        frame.with_clip(area, |frame| {
+            self.draw_series(frame, plot, style);
+        });
+
+        if self.show_legend {
+            let mut x = area.x;
+            let swatch = self.text_size.0 * 0.8;
+
+            for (index, series) in self.series.iter().enumerate() {
+                frame.fill_rectangle(
+                    Point::new(x, PADDING + (self.text_size.0 - swatch) / 2.0),
+                    Size::new(swatch, swatch),
+                    self.color(index, style),
+                );
+
+                x += swatch + PADDING / 2.0;
+
+                frame.fill_text(self.label(
+                    series.label.clone(),
+                    Point::new(x, PADDING),
+                    style.text_color,
+                ));
+
+                x += series.label.chars().count() as f32 * self.text_size.0 * CHARACTER_WIDTH
+                    + PADDING * 2.0;
+            }
+        }
+    }
+
+    fn draw_series<Renderer>(&self, frame: &mut canvas::Frame<Renderer>, plot: &Plot, style: &Style)
+    where
+        Renderer: geometry::Renderer,
+    {
+        let baseline = plot.y(0.0_f32.clamp(plot.domain.y.0, plot.domain.y.1));
+
+        let bars = self
+            .series
+            .iter()
+            .filter(|series| series.kind == Kind::Bar)
+            .count();
+
+        let slot = self
+            .bar_slot(plot.domain.x.0, plot.domain.x.1)
+            .map(|slot| slot / (plot.domain.x.1 - plot.domain.x.0) * plot.area.width)
+            .unwrap_or_default();
+
+        let bar_width = slot * 0.8 / bars.max(1) as f32;
+        let mut bar = 0;
+
+        for (index, series) in self.series.iter().enumerate() {
+            let color = self.color(index, style);
+
+            let points = series
+                .points
+                .iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|(x, y)| Point::new(plot.x(*x), plot.y(*y)));
+
+            match series.kind {
+                Kind::Line | Kind::Area => {
+                    let line = Path::new(|builder| {
+                        for (i, point) in points.clone().enumerate() {
+                            if i == 0 {
+                                builder.move_to(point);
+                            } else {
+                                builder.line_to(point);
+                            }
+                        }
+                    });
+
+                    if series.kind == Kind::Area {
+                        let fill = Path::new(|builder| {
+                            let mut last = None;
+
+                            for (i, point) in points.clone().enumerate() {
+                                if i == 0 {
+                                    builder.move_to(Point::new(point.x, baseline));
+                                }
+
+                                builder.line_to(point);
+                                last = Some(point);
+                            }
+
+                            if let Some(last) = last {
+                                builder.line_to(Point::new(last.x, baseline));
+                                builder.close();
+                            }
+                        });
+
+                        frame.fill(&fill, color.scale_alpha(style.area_alpha));
+                    }
+
+                    frame.stroke(
+                        &line,
+                        Stroke::default()
+                            .with_color(color)
+                            .with_width(style.line_width)
+                            .with_line_cap(LineCap::Round)
+                            .with_line_join(LineJoin::Round),
+                    );
+                }
+                Kind::Bar => {
+                    let offset = -slot * 0.4 + bar as f32 * bar_width;
+
+                    for point in points {
+                        let top = point.y.min(baseline);
+
+                        frame.fill_rectangle(
+                            Point::new(point.x + offset, top),
+                            Size::new(bar_width, (point.y - baseline).abs()),
+                            color,
+                        );
+                    }
+
+                    bar += 1;
+                }
+            }
+        }
+    }
+
+    /// Draws the values of the series closest to the cursor.
+    fn draw_tooltip<Renderer>(
+        &self,
+        frame: &mut canvas::Frame<Renderer>,
+        plot: &Plot,
+        style: &Style,
+        cursor: Point,
+    ) where
+        Renderer: geometry::Renderer,
+    {
+        let value = plot.value(cursor.x);
+
+        let closest: Vec<(usize, (f32, f32))> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter_map(|(index, series)| {
+                series
+                    .points
+                    .iter()
+                    .copied()
+                    .filter(|(x, y)| x.is_finite() && y.is_finite())
+                    .min_by(|(a, _), (b, _)| (a - value).abs().total_cmp(&(b - value).abs()))
+                    .map(|point| (index, point))
+            })
+            .collect();
+
+        let Some((_, (x, _))) = closest
+            .iter()
+            .min_by(|(_, (a, _)), (_, (b, _))| (a - value).abs().total_cmp(&(b - value).abs()))
+            .copied()
+        else {
+            return;
+        };
+
+        let area = plot.area;
+        let position = plot.x(x);
+
+        frame.stroke(
+            &Path::line(
+                Point::new(position, area.y),
+                Point::new(position, area.y + area.height),
+            ),
+            Stroke::default()
+                .with_color(style.axis_color)
+                .with_width(1.0),
+        );
+
+        for (index, (x, y)) in &closest {
+            if self.series[*index].kind != Kind::Bar {
+                frame.fill(
+                    &Path::circle(Point::new(plot.x(*x), plot.y(*y)), style.line_width * 2.0),
+                    self.color(*index, style),
+                );
+            }
+        }
+
+        let lines: Vec<String> = std::iter::once(self.x_label(x, plot.x_step))
+            .chain(closest.iter().map(|(index, (_, y))| {
+                format!(
+                    "{}: {}",
+                    self.series[*index].label,
+                    self.y_label(*y, plot.y_step())
+                )
+            }))
+            .collect();
+
+        let text_size = self.text_size.0;
+        let line_height = text_size * 1.3;
+
+        let size = Size::new(
+            lines
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0) as f32
+                * text_size
+                * CHARACTER_WIDTH
+                + PADDING * 2.0,
+            lines.len() as f32 * line_height + PADDING,
+        );
+
+        let origin = Point::new(
+            if cursor.x + PADDING + size.width > frame.width() {
+                cursor.x - PADDING - size.width
+            } else {
+                cursor.x + PADDING
+            },
+            (cursor.y - size.height - PADDING).max(0.0),
+        );
+
+        frame.fill(
+            &Path::rounded_rectangle(origin, size, 4.0.into()),
+            style.tooltip_background,
+        );
+
+        for (i, line) in lines.into_iter().enumerate() {
+            frame.fill_text(self.label(
+                line,
+                origin + Vector::new(PADDING, PADDING / 2.0 + i as f32 * line_height),
+                style.tooltip_text_color,
+            ));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Chart<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer>::new(self.fingerprint()))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+        let fingerprint = self.fingerprint();
+
+        if state.fingerprint != fingerprint {
+            state.fingerprint = fingerprint;
+            state.cache.clear();
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+        let bounds = layout.bounds();
+
+        let domain = self.visible(state);
+        let plot = self.plot(bounds.size(), domain);
+        let area = plot.area + Vector::new(bounds.x, bounds.y);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if self.is_zoomable => {
+                let Some(position) = cursor.position_over(area) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if click.kind() == mouse::click::Kind::Double {
+                    if state.zoom.take().is_some() {
+                        state.cache.clear();
+                        shell.request_redraw();
+                    }
+
+                    state.drag = None;
+                } else {
+                    state.drag = Some(Drag {
+                        origin: position,
+                        domain: domain.x,
+                    });
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.drag.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(drag) = state.drag {
+                    let Some(position) = cursor.position() else {
+                        return;
+                    };
+
+                    if area.width > 0.0 {
+                        let delta = (position.x - drag.origin.x) / area.width
+                            * (drag.domain.1 - drag.domain.0);
+
+                        state.zoom = Some((drag.domain.0 - delta, drag.domain.1 - delta));
+                        state.cache.clear();
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else {
+                    let is_hovered = cursor.is_over(area);
+
+                    if is_hovered || state.is_hovered {
+                        shell.request_redraw();
+                    }
+
+                    state.is_hovered = is_hovered;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if self.is_zoomable => {
+                let Some(position) = cursor.position_over(area) else {
+                    return;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y / 50.0,
+                };
+
+                let full = self.domain().x;
+                let (start, end) = domain.x;
+
+                let center = plot.value(position.x - bounds.x);
+                let factor = ZOOM_FACTOR.powf(lines);
+
+                let span = ((end - start) * factor)
+                    .max((full.1 - full.0) * MIN_ZOOM)
+                    .min(full.1 - full.0);
+
+                let ratio = (center - start) / (end - start);
+                let start = center - span * ratio;
+
+                state.zoom = if span >= full.1 - full.0 {
+                    None
+                } else {
+                    Some((start, start + span))
+                };
+
+                state.cache.clear();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        if state.style.get() != Some(style) {
+            state.style.set(Some(style));
+            state.cache.clear();
+        }
+
+        let plot = self.plot(bounds.size(), self.visible(state));
+
+        let geometry = state.cache.draw(renderer, bounds.size(), |frame| {
+            self.draw_plot(frame, &plot, &style);
+        });
+
+        let tooltip = cursor
+            .position_in(bounds)
+            .filter(|position| plot.area.contains(*position) && state.drag.is_none())
+            .map(|position| {
+                let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+                self.draw_tooltip(&mut frame, &plot, &style, position);
+
+                frame.into_geometry()
+            });
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(geometry);
+
+            if let Some(tooltip) = tooltip {
+                renderer.draw_geometry(tooltip);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Chart<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(chart: Chart<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(chart)
+    }
+}
+
+struct State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    cache: canvas::Cache<Renderer>,
+    fingerprint: u64,
+    style: Cell<Option<Style>>,
+    zoom: Option<(f32, f32)>,
+    drag: Option<Drag>,
+    last_click: Option<mouse::Click>,
+    is_hovered: bool,
+}
+
+impl<Renderer> State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn new(fingerprint: u64) -> Self {
+        Self {
+            cache: canvas::Cache::new(),
+            fingerprint,
+            style: Cell::new(None),
+            zoom: None,
+            drag: None,
+            last_click: None,
+            is_hovered: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    domain: (f32, f32),
+}
+
+/// The ranges of values displayed by a [`Chart`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Domain {
+    x: (f32, f32),
+    y: (f32, f32),
+}
+
+/// The plotting area of a [`Chart`], relative to its bounds.
+#[derive(Debug, Clone)]
+struct Plot {
+    area: Rectangle,
+    domain: Domain,
+    x_ticks: Vec<f32>,
+    x_step: f32,
+    y_ticks: Vec<f32>,
+    y_labels: Vec<String>,
+}
+
+impl Plot {
+    fn x(&self, value: f32) -> f32 {
+        let (start, end) = self.domain.x;
+
+        self.area.x + (value - start) / (end - start) * self.area.width
+    }
+
+    fn y(&self, value: f32) -> f32 {
+        let (start, end) = self.domain.y;
+
+        self.area.y + self.area.height - (value - start) / (end - start) * self.area.height
+    }
+
+    /// Returns the horizontal value at the given horizontal position.
+    fn value(&self, x: f32) -> f32 {
+        let (start, end) = self.domain.x;
+
+        if self.area.width <= 0.0 {
+            return start;
+        }
+
+        start + (x - self.area.x) / self.area.width * (end - start)
+    }
+
+    fn y_step(&self) -> f32 {
+        match self.y_ticks.as_slice() {
+            [first, second, ..] => second - first,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Returns evenly spaced ticks at "nice" values within the given range,
+/// alongside their step.
+fn ticks(min: f32, max: f32, count: usize) -> (Vec<f32>, f32) {
+    if max.partial_cmp(&min) != Some(Ordering::Greater) || count == 0 {
+        return (vec![min], 1.0);
+    }
+
+    let raw = (max - min) / count as f32;
+    let magnitude = 10.0_f32.powf(raw.log10().floor());
+
+    let step = magnitude
+        * match raw / magnitude {
+            residual if residual > 5.0 => 10.0,
+            residual if residual > 2.0 => 5.0,
+            residual if residual > 1.0 => 2.0,
+            _ => 1.0,
+        };
+
+    let start = (min / step).ceil() * step;
+
+    let ticks = (0..)
+        .map(|i| start + i as f32 * step)
+        .take_while(|tick| *tick <= max + step * 1e-3)
+        .collect();
+
+    (ticks, step)
+}
+
+/// Formats the given tick with as many decimals as its step needs.
+fn format_tick(value: f32, step: f32) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+
+    // Avoid displaying "-0"
+    let value = if value.abs() < step * 1e-3 {
+        0.0
+    } else {
+        value
+    };
+
+    format!("{value:.decimals$}")
+}
+
+/// The appearance of a chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the background of the chart, if any.
+    pub background: Option<Color>,
+    /// The [`Color`] of the axes of the chart.
+    pub axis_color: Color,
+    /// The [`Color`] of the grid lines of the chart.
+    pub grid_color: Color,
+    /// The [`Color`] of the labels of the chart.
+    pub text_color: Color,
+    /// The colors of the series without an explicit [`Color`], in order.
+    pub palette: [Color; 6],
+    /// The width of line and area series.
+    pub line_width: f32,
+    /// The opacity of the fill of area series.
+    pub area_alpha: f32,
+    /// The [`Color`] of the background of the tooltip.
+    pub tooltip_background: Color,
+    /// The [`Color`] of the text of the tooltip.
+    pub tooltip_text_color: Color,
+}
+
+/// The theme catalog of a [`Chart`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Chart`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Chart`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        axis_color: palette.background.strong.color,
+        grid_color: palette.background.weak.color,
+        text_color: palette.background.base.text.scale_alpha(0.8),
+        palette: [
+            palette.primary.base.color,
+            palette.success.base.color,
+            palette.danger.base.color,
+            palette.warning.base.color,
+            palette.secondary.base.color,
+            palette.primary.strong.color,
+        ],
+        line_width: 2.0,
+        area_alpha: 0.3,
+        tooltip_background: palette.background.strong.color,
+        tooltip_text_color: palette.background.strong.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_are_nice() {
+        assert_eq!(
+            ticks(0.0, 10.0, 5),
+            (vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0], 2.0)
+        );
+        assert_eq!(ticks(-3.0, 17.0, 4), (vec![0.0, 5.0, 10.0, 15.0], 5.0));
+        assert_eq!(format_tick(2.5, 0.5), "2.5");
+        assert_eq!(format_tick(-0.0001, 0.5), "0.0");
+    }
+}
@@ -85,6 +85,8 @@ where
     Theme: Catalog,
 {
     is_checked: bool,
+    is_indeterminate: bool,
+    indeterminate_checks: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: Option<text::Fragment<'a>>,
     width: Length,
@@ -115,6 +117,8 @@ where
     pub fn new(is_checked: bool) -> Self {
         Checkbox {
             is_checked,
+            is_indeterminate: false,
+            indeterminate_checks: true,
             on_toggle: None,
             label: None,
             width: Length::Shrink,
@@ -168,6 +172,27 @@ where
         self
     }
 
+    /// Sets whether the [`Checkbox`] is indeterminate; that is, neither
+    /// checked nor unchecked.
+    ///
+    /// An indeterminate [`Checkbox`] is drawn with a dash and styled as if it
+    /// were checked. It is useful for "select all" headers over lists where
+    /// only some items are selected.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets whether toggling the [`Checkbox`] while it is indeterminate
+    /// checks it.
+    ///
+    /// By default, an indeterminate [`Checkbox`] is checked when toggled;
+    /// otherwise, it is unchecked.
+    pub fn indeterminate_checks(mut self, checks: bool) -> Self {
+        self.indeterminate_checks = checks;
+        self
+    }
+
     /// Sets the size of the [`Checkbox`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = size.into().0;
@@ -325,7 +350,11 @@ where
                 let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over && let Some(on_toggle) = &self.on_toggle {
-                    shell.publish((on_toggle)(!self.is_checked));
+                    shell.publish((on_toggle)(toggled(
+                        self.is_checked,
+                        self.is_indeterminate,
+                        self.indeterminate_checks,
+                    )));
                     shell.capture_event();
                 }
             }
@@ -335,7 +364,7 @@ where
         let current_status = {
             let is_mouse_over = cursor.is_over(layout.bounds());
             let is_disabled = self.on_toggle.is_none();
-            let is_checked = self.is_checked;
+            let is_checked = self.is_checked || self.is_indeterminate;
 
             if is_disabled {
                 Status::Disabled { is_checked }
@@ -386,7 +415,7 @@ where
         let style = theme.style(
             &self.class,
             self.last_status.unwrap_or(Status::Disabled {
-                is_checked: self.is_checked,
+                is_checked: self.is_checked || self.is_indeterminate,
             }),
         );
 
@@ -412,7 +441,25 @@ where
             } = &self.icon;
             let size = size.unwrap_or(Pixels(bounds.height * 0.7));
 
-            if self.is_checked {
+            if self.is_indeterminate {
+                let center = bounds.center();
+                let width = bounds.width * 0.5;
+                let height = (bounds.height * 0.125).max(2.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - width / 2.0,
+                            y: center.y - height / 2.0,
+                            width,
+                            height,
+                        },
+                        border: Border::default().rounded(height / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.icon_color,
+                );
+            } else if self.is_checked {
                 renderer.fill_text(
                     text::Text {
                         content: code_point.to_string(),
@@ -481,6 +528,15 @@ where
     }
 }
 
+/// Returns the new state of a [`Checkbox`] when toggled.
+fn toggled(is_checked: bool, is_indeterminate: bool, indeterminate_checks: bool) -> bool {
+    if is_indeterminate {
+        indeterminate_checks
+    } else {
+        !is_checked
+    }
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -500,6 +556,8 @@ pub struct Icon<Font> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     /// The [`Checkbox`] can be interacted with.
+    ///
+    /// An indeterminate [`Checkbox`] is considered checked in every [`Status`].
     Active {
         /// Indicates if the [`Checkbox`] is currently checked.
         is_checked: bool,
@@ -698,3 +756,16 @@ fn styled(
         text_color: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::toggled;
+
+    #[test]
+    fn indeterminate_checkboxes_toggle_as_configured() {
+        assert!(toggled(false, false, true));
+        assert!(!toggled(true, false, true));
+        assert!(toggled(false, true, true));
+        assert!(!toggled(true, true, false));
+    }
+}
@@ -0,0 +1,1008 @@
+//! Collapsibles display a header that expands and collapses some content.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::collapsible::Group;
+//! use iced::widget::{checkbox, collapsible, column, text};
+//!
+//! struct State {
+//!     notifications: bool,
+//!     sounds: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ToggleNotifications(bool),
+//!     ToggleSounds(bool),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     Group::new([
+//!         collapsible(
+//!             text("Notifications"),
+//!             checkbox(state.notifications).label("Enabled")
+//!                 .on_toggle(Message::ToggleNotifications),
+//!         )
+//!         .expanded(true),
+//!         collapsible(
+//!             text("Sounds"),
+//!             column![checkbox(state.sounds).label("Enabled").on_toggle(Message::ToggleSounds)],
+//!         ),
+//!     ])
+//!     .into()
+//! }
+//! ```
+pub mod operation;
+
+use crate::core::animation::{Animation, Easing};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::f32::consts::FRAC_PI_2;
+
+const CHEVRON_SIZE: f32 = 12.0;
+
+/// A header that expands and collapses some content when pressed.
+///
+/// The open state of a [`Collapsible`] is kept in the widget tree, so
+/// `view` does not need to track it. It can be controlled with an
+/// [`operation`] when an [`Id`](widget::Id) is set.
+pub struct Collapsible<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    id: Option<widget::Id>,
+    header: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    is_expanded: bool,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Collapsible<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// The default padding of the header of a [`Collapsible`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 8.0,
+        bottom: 8.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Collapsible`] with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            id: None,
+            header: header.into(),
+            content: content.into(),
+            is_expanded: false,
+            on_toggle: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 8.0,
+            duration: Duration::from_millis(200),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Id`](widget::Id) of the [`Collapsible`].
+    ///
+    /// The open state is reset when the [`Id`](widget::Id) changes.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets whether the [`Collapsible`] is expanded when it is first
+    /// displayed.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Collapsible`] is
+    /// expanded or collapsed by the user.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the [`Collapsible`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the header of the [`Collapsible`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the chevron and the header of the
+    /// [`Collapsible`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the duration of the expand and collapse animation of the
+    /// [`Collapsible`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Collapsible`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Collapsible`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn initial_state(&self) -> State {
+        State {
+            id: self.id.clone(),
+            animation: Animation::new(self.is_expanded)
+                .duration(self.duration)
+                .easing(Easing::EaseInOut),
+            now: Instant::now(),
+        }
+    }
+}
+
+/// The local state of a [`Collapsible`].
+#[derive(Debug)]
+struct State {
+    id: Option<widget::Id>,
+    animation: Animation<bool>,
+    now: Instant,
+}
+
+impl State {
+    fn is_open(&self) -> bool {
+        self.animation.value()
+    }
+
+    fn set(&mut self, is_open: bool, at: Instant) {
+        if self.is_open() != is_open {
+            self.animation.go_mut(is_open, at);
+            self.now = at;
+        }
+    }
+
+    /// Returns how much of the content is visible, from 0 to 1.
+    fn progress(&self) -> f32 {
+        self.animation.interpolate(0.0, 1.0, self.now)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Collapsible<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(self.initial_state())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.header), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.state.downcast_ref::<State>().id != self.id {
+            tree.state = tree::State::new(self.initial_state());
+        }
+
+        tree.diff_children(&[&self.header, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let progress = tree.state.downcast_ref::<State>().progress();
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+
+        let header_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (width - CHEVRON_SIZE - self.spacing - self.padding.x()).max(0.0),
+                f32::INFINITY,
+            ),
+        );
+
+        let header =
+            self.header
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, &header_limits);
+
+        let header_height = header.size().height.max(CHEVRON_SIZE) + self.padding.y();
+        let header_offset = (header_height - header.size().height) / 2.0;
+
+        let header = layout::Node::with_children(
+            Size::new(width, header_height),
+            vec![header.move_to(Point::new(
+                self.padding.left + CHEVRON_SIZE + self.spacing,
+                header_offset,
+            ))],
+        );
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+            )
+            .move_to(Point::new(0.0, header_height));
+
+        let height = header_height + content.size().height * progress;
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, height));
+
+        layout::Node::with_children(size, vec![header, content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+
+        let is_open = state.is_open();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.header.as_widget_mut().operate(
+                &mut tree.children[0],
+                header.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+
+            if is_open {
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[1],
+                    content,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        self.header.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            header.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if tree.state.downcast_ref::<State>().is_open() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(header.bounds()) {
+                    return;
+                }
+
+                let is_open = !state.is_open();
+
+                state.set(is_open, Instant::now());
+
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(is_open));
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if cursor.is_over(header.bounds()) {
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if state.animation.is_animating(state.now) {
+                    state.now = *now;
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let status = if cursor.is_over(header.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status);
+
+        if appearance.background.is_some() || appearance.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        if let Some(background) = appearance.header_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header.bounds(),
+                    border: Border {
+                        width: 0.0,
+                        ..appearance.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let progress = state.progress();
+        let header_bounds = header.bounds();
+
+        draw_chevron(
+            renderer,
+            Point::new(
+                header_bounds.x + self.padding.left + CHEVRON_SIZE / 2.0,
+                header_bounds.center_y(),
+            ),
+            progress * FRAC_PI_2,
+            appearance.chevron_color,
+        );
+
+        let header_style = renderer::Style {
+            text_color: appearance.text_color,
+        };
+
+        self.header.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &header_style,
+            header.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        if progress == 0.0 {
+            return;
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                &clip,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let interaction = self.header.as_widget().mouse_interaction(
+            &tree.children[0],
+            header.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction != mouse::Interaction::None {
+            return interaction;
+        }
+
+        if cursor.is_over(header.bounds()) {
+            return mouse::Interaction::Pointer;
+        }
+
+        if tree.state.downcast_ref::<State>().is_open() {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[1],
+                content,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_open = tree.state.downcast_ref::<State>().is_open();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+        let (header_tree, content_tree) = tree.children.split_at_mut(1);
+
+        let mut overlays = Vec::new();
+
+        if let Some(overlay) = self.header.as_widget_mut().overlay(
+            &mut header_tree[0],
+            header.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        ) {
+            overlays.push(overlay);
+        }
+
+        if is_open
+            && let Some(overlay) = self.content.as_widget_mut().overlay(
+                &mut content_tree[0],
+                content,
+                renderer,
+                viewport,
+                translation,
+            )
+        {
+            overlays.push(overlay);
+        }
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Collapsible<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(collapsible: Collapsible<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(collapsible)
+    }
+}
+
+/// Draws a chevron pointing right, rotated clockwise by the given angle.
+///
+/// The chevron is drawn with small dots, since quads cannot be rotated.
+fn draw_chevron<Renderer>(renderer: &mut Renderer, center: Point, angle: f32, color: Color)
+where
+    Renderer: crate::core::Renderer,
+{
+    const STEPS: usize = 6;
+    const STROKE: f32 = 2.0;
+
+    let points = [(-0.25, -0.5), (0.25, 0.0), (-0.25, 0.5)];
+    let (sin, cos) = angle.sin_cos();
+
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+
+        for step in 0..=STEPS {
+            let t = step as f32 / STEPS as f32;
+            let x = (start.0 + (end.0 - start.0) * t) * CHEVRON_SIZE;
+            let y = (start.1 + (end.1 - start.1) * t) * CHEVRON_SIZE;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x + x * cos - y * sin - STROKE / 2.0,
+                        y: center.y + x * sin + y * cos - STROKE / 2.0,
+                        width: STROKE,
+                        height: STROKE,
+                    },
+                    border: Border::default().rounded(STROKE / 2.0),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+/// A group of [`Collapsible`] sections where at most one is open at a time.
+///
+/// Expanding a section of a [`Group`] collapses the others.
+pub struct Group<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    sections: Vec<Collapsible<'a, Message, Theme, Renderer>>,
+    width: Length,
+    spacing: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Group<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Group`] with the given sections.
+    ///
+    /// Only the first expanded section will be open initially.
+    pub fn new(
+        sections: impl IntoIterator<Item = Collapsible<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut is_expanded = false;
+
+        let sections = sections
+            .into_iter()
+            .map(|section| {
+                let is_open = section.is_expanded && !is_expanded;
+                is_expanded |= is_open;
+
+                section.expanded(is_open)
+            })
+            .collect();
+
+        Self {
+            sections,
+            width: Length::Fill,
+            spacing: 0.0,
+        }
+    }
+
+    /// Adds a [`Collapsible`] section to the [`Group`].
+    pub fn push(mut self, section: Collapsible<'a, Message, Theme, Renderer>) -> Self {
+        let is_open =
+            section.is_expanded && !self.sections.iter().any(|section| section.is_expanded);
+
+        self.sections.push(section.expanded(is_open));
+        self
+    }
+
+    /// Sets the width of the [`Group`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the sections of the [`Group`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Collapses every open section, except the one that was just opened,
+    /// if any.
+    fn collapse_others(&self, tree: &mut Tree, was_open: &[bool], shell: &mut Shell<'_, Message>) {
+        let Some(opened) = tree
+            .children
+            .iter()
+            .zip(was_open)
+            .position(|(tree, was_open)| tree.state.downcast_ref::<State>().is_open() && !was_open)
+        else {
+            return;
+        };
+
+        let now = tree.children[opened].state.downcast_ref::<State>().now;
+
+        for (index, (section, tree)) in self.sections.iter().zip(&mut tree.children).enumerate() {
+            let state = tree.state.downcast_mut::<State>();
+
+            if index == opened || !state.is_open() {
+                continue;
+            }
+
+            state.set(false, now);
+
+            if let Some(on_toggle) = &section.on_toggle {
+                shell.publish(on_toggle(false));
+            }
+        }
+    }
+}
+
+fn open_sections(tree: &Tree) -> Vec<bool> {
+    tree.children
+        .iter()
+        .map(|tree| tree.state.downcast_ref::<State>().is_open())
+        .collect()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Group<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.sections
+            .iter()
+            .map(|section| Tree::new(section as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.sections,
+            |tree, section| tree.diff(section as &dyn Widget<_, _, _>),
+            |section| Tree::new(section as &dyn Widget<_, _, _>),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+
+        let mut y = 0.0;
+
+        let sections: Vec<_> = self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(section, tree)| {
+                let node = section
+                    .layout(tree, renderer, &limits.loose())
+                    .move_to(Point::new(0.0, y));
+
+                y += node.size().height + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let height = (y - self.spacing).max(0.0);
+
+        layout::Node::with_children(
+            limits.resolve(self.width, Length::Shrink, Size::new(width, height)),
+            sections,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let was_open = open_sections(tree);
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((section, tree), layout) in self
+                .sections
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                section.operate(tree, layout, renderer, operation);
+            }
+        });
+
+        // Operations cannot produce messages
+        let mut messages = Vec::new();
+        self.collapse_others(tree, &was_open, &mut Shell::new(&mut messages));
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let was_open = open_sections(tree);
+
+        for ((section, tree), layout) in self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            section.update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+
+        self.collapse_others(tree, &was_open, shell);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((section, tree), layout) in self
+            .sections
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            section.draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.sections
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((section, tree), layout)| {
+                section.mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overlays: Vec<_> = self
+            .sections
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((section, tree), layout)| {
+                section.overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Group<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(group: Group<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(group)
+    }
+}
+
+/// The possible status of a [`Collapsible`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Collapsible`] can be toggled.
+    Active,
+    /// The header of the [`Collapsible`] is being hovered.
+    Hovered,
+}
+
+/// The appearance of a [`Collapsible`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Collapsible`], if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`Collapsible`].
+    pub border: Border,
+    /// The [`Background`] of the header, if any.
+    pub header_background: Option<Background>,
+    /// The default text [`Color`] of the header.
+    pub text_color: Color,
+    /// The [`Color`] of the chevron.
+    pub chevron_color: Color,
+}
+
+/// The theme catalog of a [`Collapsible`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Collapsible`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Collapsible`].
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let base = Style {
+        background: None,
+        border: Border::default(),
+        header_background: None,
+        text_color: palette.background.base.text,
+        chevron_color: palette.background.strongest.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            header_background: Some(palette.background.weak.color.into()),
+            chevron_color: palette.background.base.text,
+            ..base
+        },
+    }
+}
@@ -0,0 +1,53 @@
+//! Control a [`Collapsible`] programmatically.
+//!
+//! [`Collapsible`]: super::Collapsible
+use crate::collapsible::State;
+use crate::core::Rectangle;
+use crate::core::time::Instant;
+use crate::core::widget::Id;
+use crate::core::widget::Operation;
+
+use std::any::Any;
+
+/// Produces an [`Operation`] that expands the collapsible with the given
+/// [`Id`].
+pub fn expand<T>(target: Id) -> impl Operation<T> {
+    request(target, |_| true)
+}
+
+/// Produces an [`Operation`] that collapses the collapsible with the given
+/// [`Id`].
+pub fn collapse<T>(target: Id) -> impl Operation<T> {
+    request(target, |_| false)
+}
+
+/// Produces an [`Operation`] that toggles the collapsible with the given
+/// [`Id`].
+pub fn toggle<T>(target: Id) -> impl Operation<T> {
+    request(target, |is_open| !is_open)
+}
+
+fn request<T>(target: Id, f: fn(bool) -> bool) -> impl Operation<T> {
+    struct Apply {
+        target: Id,
+        f: fn(bool) -> bool,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if Some(&self.target) == id
+                && let Some(state) = state.downcast_mut::<State>()
+            {
+                let is_open = (self.f)(state.is_open());
+
+                state.set(is_open, Instant::now());
+            }
+        }
+    }
+
+    Apply { target, f }
+}
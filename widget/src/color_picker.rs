@@ -0,0 +1,1291 @@
+//! Color pickers let users choose a [`Color`] visually or by its components.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::Color;
+//! use iced::widget::color_picker;
+//!
+//! struct State {
+//!    color: Color,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ColorChanged(Color),
+//!     PickFromScreen,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     color_picker(state.color, Message::ColorChanged)
+//!         .swatches([Color::BLACK, Color::WHITE])
+//!         .on_eyedropper(Message::PickFromScreen)
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Radians, Rectangle, Shadow, Shell, Size, Vector,
+};
+use crate::text_input::{self, TextInput};
+
+const DEFAULT_WIDTH: f32 = 240.0;
+const AREA_RATIO: f32 = 2.0 / 3.0;
+const SLIDER_HEIGHT: f32 = 12.0;
+const HANDLE_SIZE: f32 = 14.0;
+const SWATCH_SIZE: f32 = 20.0;
+const SWATCH_SPACING: f32 = 4.0;
+const CHECKER_SIZE: f32 = 4.0;
+const LABEL_SPACING: f32 = 2.0;
+
+/// A widget for choosing a [`Color`].
+///
+/// A [`ColorPicker`] is made of a saturation and value area, hue and alpha
+/// sliders, and input fields for the hexadecimal and RGB representations of
+/// the [`Color`]. Optionally, it can display a palette of swatches and an
+/// eyedropper button.
+pub struct ColorPicker<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_eyedropper: Option<Message>,
+    swatches: Vec<Color>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    inputs: Vec<TextInput<'a, Edit, Theme, Renderer>>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ColorPicker`] with the given [`Color`] and the function
+    /// that produces a message when the [`Color`] changes.
+    pub fn new(color: Color, on_change: impl Fn(Color) -> Message + 'a) -> Self {
+        let mut color_picker = Self {
+            color,
+            on_change: Box::new(on_change),
+            on_eyedropper: None,
+            swatches: Vec::new(),
+            width: Length::Shrink,
+            padding: Padding::new(10.0),
+            spacing: 8.0,
+            text_size: None,
+            font: None,
+            inputs: Vec::new(),
+            class: <Theme as Catalog>::default(),
+        };
+
+        color_picker.inputs = Field::ALL
+            .iter()
+            .map(|field| color_picker.input(*field, &field.format(color)))
+            .collect();
+
+        color_picker
+    }
+
+    /// Sets the message that should be produced when the eyedropper button of
+    /// the [`ColorPicker`] is pressed.
+    ///
+    /// The eyedropper button is only displayed when this message is set. It is
+    /// up to the application to pick a [`Color`] from the screen—for instance,
+    /// by taking a screenshot of the window.
+    pub fn on_eyedropper(mut self, message: Message) -> Self {
+        self.on_eyedropper = Some(message);
+        self
+    }
+
+    /// Sets the swatches of the [`ColorPicker`]; that is, a palette of colors
+    /// that can be picked with a single press.
+    pub fn swatches(mut self, swatches: impl IntoIterator<Item = Color>) -> Self {
+        self.swatches = swatches.into_iter().collect();
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ColorPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the elements of the [`ColorPicker`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the input fields of the [`ColorPicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the input fields of the [`ColorPicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ColorPicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn input(&self, field: Field, value: &str) -> TextInput<'a, Edit, Theme, Renderer> {
+        let input = TextInput::new("", value)
+            .on_input(move |value| Edit { field, value })
+            .padding([4, 6])
+            .width(Length::Fill)
+            .align_x(alignment::Horizontal::Center)
+            .class(Theme::default_input());
+
+        let input = match self.text_size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        }
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size()) * 0.8
+    }
+
+    fn swatch_columns(&self, width: f32) -> usize {
+        (((width + SWATCH_SPACING) / (SWATCH_SIZE + SWATCH_SPACING)).floor() as usize).max(1)
+    }
+
+    fn regions(&self, layout: Layout<'_>, renderer: &Renderer) -> Regions {
+        let inner = layout.bounds().shrink(self.padding);
+        let control_size = SLIDER_HEIGHT * 2.0 + self.spacing;
+
+        let area = Rectangle {
+            height: inner.width * AREA_RATIO,
+            ..inner
+        };
+
+        let controls = Rectangle {
+            y: area.y + area.height + self.spacing,
+            height: control_size,
+            ..inner
+        };
+
+        let mut x = controls.x;
+
+        let mut square = || {
+            let bounds = Rectangle {
+                x,
+                width: control_size,
+                ..controls
+            };
+
+            x += control_size + self.spacing;
+
+            bounds
+        };
+
+        let eyedropper = self.on_eyedropper.is_some().then(&mut square);
+        let preview = square();
+
+        let hue = Rectangle {
+            x,
+            y: controls.y,
+            width: controls.x + controls.width - x,
+            height: SLIDER_HEIGHT,
+        };
+
+        let alpha = Rectangle {
+            y: hue.y + SLIDER_HEIGHT + self.spacing,
+            ..hue
+        };
+
+        let inputs = layout
+            .children()
+            .next()
+            .map(|input| input.bounds())
+            .unwrap_or_default();
+
+        let labels_y = inputs.y + inputs.height + LABEL_SPACING;
+        let labels_height =
+            f32::from(text::LineHeight::default().to_absolute(self.label_size(renderer)));
+
+        let swatches = Rectangle {
+            y: labels_y + labels_height + self.spacing,
+            height: inner.y + inner.height - (labels_y + labels_height + self.spacing),
+            ..inner
+        };
+
+        Regions {
+            area,
+            hue,
+            alpha,
+            preview,
+            eyedropper,
+            labels_y,
+            labels_height,
+            swatches,
+            columns: self.swatch_columns(inner.width),
+        }
+    }
+
+    fn change(&self, state: &mut State, hsva: Hsva, shell: &mut Shell<'_, Message>) {
+        state.sync_to(hsva.into_color(), hsva);
+
+        if state.color != Some(self.color) {
+            shell.publish((self.on_change)(hsva.into_color()));
+        }
+    }
+
+    fn drag(
+        &self,
+        state: &mut State,
+        target: Target,
+        position: Point,
+        regions: &Regions,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let hsva = state.hsva;
+
+        let ratio = |bounds: Rectangle| {
+            (
+                ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0),
+                ((position.y - bounds.y) / bounds.height).clamp(0.0, 1.0),
+            )
+        };
+
+        let hsva = match target {
+            Target::Area => {
+                let (x, y) = ratio(regions.area);
+
+                Hsva {
+                    saturation: x,
+                    value: 1.0 - y,
+                    ..hsva
+                }
+            }
+            Target::Hue => Hsva {
+                hue: ratio(regions.hue).0 * 360.0,
+                ..hsva
+            },
+            Target::Alpha => Hsva {
+                alpha: ratio(regions.alpha).0,
+                ..hsva
+            },
+        };
+
+        self.change(state, hsva, shell);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Regions {
+    area: Rectangle,
+    hue: Rectangle,
+    alpha: Rectangle,
+    preview: Rectangle,
+    eyedropper: Option<Rectangle>,
+    labels_y: f32,
+    labels_height: f32,
+    swatches: Rectangle,
+    columns: usize,
+}
+
+impl Regions {
+    fn swatch(&self, index: usize) -> Rectangle {
+        let row = index / self.columns;
+        let column = index % self.columns;
+
+        Rectangle {
+            x: self.swatches.x + column as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+            y: self.swatches.y + row as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+            width: SWATCH_SIZE,
+            height: SWATCH_SIZE,
+        }
+    }
+
+    fn target(&self, position: Point) -> Option<Target> {
+        let handle = (HANDLE_SIZE - SLIDER_HEIGHT) / 2.0;
+
+        if self.area.contains(position) {
+            Some(Target::Area)
+        } else if self.hue.expand(handle).contains(position) {
+            Some(Target::Hue)
+        } else if self.alpha.expand(handle).contains(position) {
+            Some(Target::Alpha)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Area,
+    Hue,
+    Alpha,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Hex,
+    Red,
+    Green,
+    Blue,
+}
+
+impl Field {
+    const ALL: [Self; 4] = [Self::Hex, Self::Red, Self::Green, Self::Blue];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hex => "HEX",
+            Self::Red => "R",
+            Self::Green => "G",
+            Self::Blue => "B",
+        }
+    }
+
+    fn weight(self) -> f32 {
+        match self {
+            Self::Hex => 2.0,
+            Self::Red | Self::Green | Self::Blue => 1.0,
+        }
+    }
+
+    fn format(self, color: Color) -> String {
+        let [r, g, b, _] = color.into_rgba8();
+
+        match self {
+            Self::Hex => color.to_string(),
+            Self::Red => r.to_string(),
+            Self::Green => g.to_string(),
+            Self::Blue => b.to_string(),
+        }
+    }
+
+    fn parse(self, value: &str, color: Color) -> Option<Color> {
+        let value = value.trim();
+
+        let component = || {
+            value
+                .parse::<u8>()
+                .ok()
+                .map(|value| f32::from(value) / 255.0)
+        };
+
+        match self {
+            Self::Hex => {
+                let parsed = value.parse::<Color>().ok()?;
+
+                // Keep the current alpha if the hex code does not specify one
+                if matches!(value.trim_start_matches('#').len(), 3 | 6) {
+                    Some(Color {
+                        a: color.a,
+                        ..parsed
+                    })
+                } else {
+                    Some(parsed)
+                }
+            }
+            Self::Red => Some(Color {
+                r: component()?,
+                ..color
+            }),
+            Self::Green => Some(Color {
+                g: component()?,
+                ..color
+            }),
+            Self::Blue => Some(Color {
+                b: component()?,
+                ..color
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Edit {
+    field: Field,
+    value: String,
+}
+
+/// A [`Color`] in the HSV color space, with an alpha component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsva {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+    alpha: f32,
+}
+
+impl Hsva {
+    /// Converts the given [`Color`], keeping the hue and saturation of the
+    /// previous [`Hsva`] when the [`Color`] does not determine them.
+    fn from_color(color: Color, previous: Self) -> Self {
+        let Color { r, g, b, a } = color;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            previous.hue
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let saturation = if max == 0.0 {
+            previous.saturation
+        } else {
+            delta / max
+        };
+
+        Self {
+            hue,
+            saturation,
+            value: max,
+            alpha: a,
+        }
+    }
+
+    fn into_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = (self.hue / 60.0).clamp(0.0, 6.0);
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let m = self.value - chroma;
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color::from_rgba(r + m, g + m, b + m, self.alpha)
+    }
+
+    fn opaque(self) -> Color {
+        Self { alpha: 1.0, ..self }.into_color()
+    }
+}
+
+impl Default for Hsva {
+    fn default() -> Self {
+        Self {
+            hue: 0.0,
+            saturation: 0.0,
+            value: 0.0,
+            alpha: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    hsva: Hsva,
+    color: Option<Color>,
+    buffers: [String; 4],
+    drag: Option<Target>,
+}
+
+impl State {
+    fn sync(&mut self, color: Color) {
+        if self.color != Some(color) {
+            self.sync_to(color, Hsva::from_color(color, self.hsva));
+        }
+    }
+
+    fn sync_to(&mut self, color: Color, hsva: Hsva) {
+        self.hsva = hsva;
+        self.color = Some(color);
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.inputs
+            .iter()
+            .map(|input| Tree::new(input as &dyn Widget<_, _, _>))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.inputs,
+            |tree, input| tree.diff(input as &dyn Widget<_, _, _>),
+            |input| Tree::new(input as &dyn Widget<_, _, _>),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(self.color);
+
+        // Focused inputs keep what is being typed, even if it is not a valid color
+        for ((field, buffer), input) in Field::ALL
+            .iter()
+            .zip(&mut state.buffers)
+            .zip(&tree.children)
+        {
+            let is_focused = input
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused();
+
+            if !is_focused {
+                *buffer = field.format(self.color);
+            }
+        }
+
+        self.inputs = Field::ALL
+            .iter()
+            .zip(&state.buffers)
+            .map(|(field, buffer)| self.input(*field, buffer))
+            .collect();
+
+        let width = limits
+            .width(self.width)
+            .resolve(self.width, Length::Shrink, Size::new(DEFAULT_WIDTH, 0.0))
+            .width;
+
+        let inner_width = width - self.padding.x();
+        let control_size = SLIDER_HEIGHT * 2.0 + self.spacing;
+
+        let unit = (inner_width - self.spacing * (Field::ALL.len() - 1) as f32)
+            / Field::ALL.iter().map(|field| field.weight()).sum::<f32>();
+
+        let mut x = self.padding.left;
+        let y = self.padding.top + inner_width * AREA_RATIO + control_size + self.spacing * 2.0;
+
+        let inputs: Vec<_> = self
+            .inputs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(Field::ALL)
+            .map(|((input, tree), field)| {
+                let width = unit * field.weight();
+
+                let node = input
+                    .layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+                        None,
+                    )
+                    .move_to(Point::new(x, y));
+
+                x += width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let inputs_height = inputs
+            .iter()
+            .map(|input| input.size().height)
+            .fold(0.0, f32::max);
+
+        let labels_height =
+            f32::from(text::LineHeight::default().to_absolute(self.label_size(renderer)));
+
+        let swatches_height = if self.swatches.is_empty() {
+            0.0
+        } else {
+            let rows = self
+                .swatches
+                .len()
+                .div_ceil(self.swatch_columns(inner_width));
+
+            self.spacing + rows as f32 * (SWATCH_SIZE + SWATCH_SPACING) - SWATCH_SPACING
+        };
+
+        let height = y
+            + inputs_height
+            + LABEL_SPACING
+            + labels_height
+            + swatches_height
+            + self.padding.bottom;
+
+        layout::Node::with_children(Size::new(width, height), inputs)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((input, tree), layout) in self
+                .inputs
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                input.operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        for ((input, tree), layout) in self
+            .inputs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            input.update(
+                tree,
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+        }
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for Edit { field, value } in edits {
+            if let Some(color) = field.parse(&value, state.color.unwrap_or(self.color)) {
+                self.change(state, Hsva::from_color(color, state.hsva), shell);
+            }
+
+            state.buffers[field as usize] = value;
+            shell.invalidate_layout();
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let regions = self.regions(layout, renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return;
+                };
+
+                if let Some(target) = regions.target(position) {
+                    state.drag = Some(target);
+
+                    self.drag(state, target, position, &regions, shell);
+                    shell.capture_event();
+                } else if regions
+                    .eyedropper
+                    .is_some_and(|bounds| bounds.contains(position))
+                {
+                    if let Some(on_eyedropper) = self.on_eyedropper.clone() {
+                        shell.publish(on_eyedropper);
+                    }
+
+                    shell.capture_event();
+                } else if let Some(color) = (0..self.swatches.len())
+                    .find(|index| regions.swatch(*index).contains(position))
+                    .map(|index| self.swatches[index])
+                {
+                    self.change(state, Hsva::from_color(color, state.hsva), shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(target) = state.drag
+                    && let Some(position) = cursor.position()
+                {
+                    self.drag(state, target, position, &regions, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let regions = self.regions(layout, renderer);
+
+        match regions.target(position) {
+            Some(Target::Area) => return mouse::Interaction::Crosshair,
+            Some(Target::Hue | Target::Alpha) => return mouse::Interaction::Grab,
+            None => {}
+        }
+
+        if regions
+            .eyedropper
+            .is_some_and(|bounds| bounds.contains(position))
+            || (0..self.swatches.len()).any(|index| regions.swatch(index).contains(position))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((input, tree), layout)| {
+                input.mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+        let regions = self.regions(layout, renderer);
+        let hsva = state.hsva;
+        let color = hsva.into_color();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let fill_handle = |renderer: &mut Renderer, center: Point, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        center - Vector::new(HANDLE_SIZE / 2.0, HANDLE_SIZE / 2.0),
+                        Size::new(HANDLE_SIZE, HANDLE_SIZE),
+                    ),
+                    border: Border {
+                        radius: (HANDLE_SIZE / 2.0).into(),
+                        width: 2.0,
+                        color: style.handle_color,
+                    },
+                    shadow: Shadow {
+                        color: Color::BLACK.scale_alpha(0.5),
+                        offset: Vector::ZERO,
+                        blur_radius: 2.0,
+                        spread: 0.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        };
+
+        // Saturation and value area
+        let area_border = Border::default().rounded(style.border.radius);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.area,
+                border: area_border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians::PI / 2.0)
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(
+                    1.0,
+                    Hsva {
+                        saturation: 1.0,
+                        value: 1.0,
+                        ..hsva
+                    }
+                    .opaque(),
+                ),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.area,
+                border: area_border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians::PI)
+                .add_stop(0.0, Color::TRANSPARENT)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        fill_handle(
+            renderer,
+            Point::new(
+                regions.area.x + hsva.saturation * regions.area.width,
+                regions.area.y + (1.0 - hsva.value) * regions.area.height,
+            ),
+            hsva.opaque(),
+        );
+
+        // Hue slider
+        let slider_border = Border::default().rounded(2);
+
+        let hue = (0..=6).fold(gradient::Linear::new(Radians::PI / 2.0), |gradient, i| {
+            gradient.add_stop(
+                i as f32 / 6.0,
+                Hsva {
+                    hue: i as f32 * 60.0,
+                    saturation: 1.0,
+                    value: 1.0,
+                    alpha: 1.0,
+                }
+                .into_color(),
+            )
+        });
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.hue,
+                border: slider_border,
+                ..renderer::Quad::default()
+            },
+            hue,
+        );
+
+        fill_handle(
+            renderer,
+            Point::new(
+                regions.hue.x + hsva.hue / 360.0 * regions.hue.width,
+                regions.hue.center_y(),
+            ),
+            Hsva {
+                saturation: 1.0,
+                value: 1.0,
+                ..hsva
+            }
+            .opaque(),
+        );
+
+        // Alpha slider
+        draw_checkerboard(renderer, regions.alpha, style.checkerboard);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.alpha,
+                border: slider_border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians::PI / 2.0)
+                .add_stop(0.0, Color { a: 0.0, ..color })
+                .add_stop(1.0, Color { a: 1.0, ..color }),
+        );
+
+        fill_handle(
+            renderer,
+            Point::new(
+                regions.alpha.x + hsva.alpha * regions.alpha.width,
+                regions.alpha.center_y(),
+            ),
+            color,
+        );
+
+        // Preview
+        draw_checkerboard(renderer, regions.preview, style.checkerboard);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.preview,
+                border: style.swatch_border,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+
+        // Eyedropper
+        if let Some(bounds) = regions.eyedropper {
+            let is_hovered = cursor.is_over(bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.swatch_border,
+                    ..renderer::Quad::default()
+                },
+                if is_hovered {
+                    style.hovered_background
+                } else {
+                    style.background
+                },
+            );
+
+            let center = bounds.center();
+            let ring = bounds.width / 3.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        center - Vector::new(ring / 2.0, ring / 2.0),
+                        Size::new(ring, ring),
+                    ),
+                    border: Border {
+                        radius: (ring / 2.0).into(),
+                        width: 2.0,
+                        color: style.text_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            for (offset, size) in [
+                (Vector::new(0.0, -ring), Size::new(2.0, ring / 2.0)),
+                (Vector::new(0.0, ring), Size::new(2.0, ring / 2.0)),
+                (Vector::new(-ring, 0.0), Size::new(ring / 2.0, 2.0)),
+                (Vector::new(ring, 0.0), Size::new(ring / 2.0, 2.0)),
+            ] {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            center + offset - Vector::new(size.width / 2.0, size.height / 2.0),
+                            size,
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color,
+                );
+            }
+        }
+
+        // Inputs
+        let label_size = self.label_size(renderer);
+        let label_font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (((input, tree), layout), field) in self
+            .inputs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(Field::ALL)
+        {
+            input.draw(tree, renderer, theme, layout, cursor, None, viewport);
+
+            let bounds = layout.bounds();
+
+            renderer.fill_text(
+                Text {
+                    content: field.label().to_owned(),
+                    bounds: Size::new(bounds.width, regions.labels_height),
+                    size: label_size,
+                    line_height: text::LineHeight::default(),
+                    font: label_font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: None,
+                },
+                Point::new(bounds.center_x(), regions.labels_y),
+                style.label_color,
+                *viewport,
+            );
+        }
+
+        // Swatches
+        for (index, swatch) in self.swatches.iter().enumerate() {
+            let bounds = regions.swatch(index);
+
+            if swatch.a < 1.0 {
+                draw_checkerboard(renderer, bounds, style.checkerboard);
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: if *swatch == self.color {
+                        Border {
+                            width: 2.0,
+                            color: style.selected_swatch_color,
+                            ..style.swatch_border
+                        }
+                    } else {
+                        style.swatch_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                *swatch,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+fn draw_checkerboard<Renderer>(renderer: &mut Renderer, bounds: Rectangle, colors: [Color; 2])
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..renderer::Quad::default()
+        },
+        colors[0],
+    );
+
+    renderer.with_layer(bounds, |renderer| {
+        let rows = (bounds.height / CHECKER_SIZE).ceil() as usize;
+        let columns = (bounds.width / CHECKER_SIZE).ceil() as usize;
+
+        for row in 0..rows {
+            for column in (row % 2..columns).step_by(2) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column as f32 * CHECKER_SIZE,
+                            y: bounds.y + row as f32 * CHECKER_SIZE,
+                            width: CHECKER_SIZE,
+                            height: CHECKER_SIZE,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    colors[1],
+                );
+            }
+        }
+    });
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`ColorPicker`].
+    pub background: Background,
+    /// The [`Border`] of the [`ColorPicker`].
+    pub border: Border,
+    /// The [`Color`] of the eyedropper icon.
+    pub text_color: Color,
+    /// The text [`Color`] of the labels of the input fields.
+    pub label_color: Color,
+    /// The [`Color`] of the ring around the handles of the area and sliders.
+    pub handle_color: Color,
+    /// The colors of the checkerboard displayed behind transparent colors.
+    pub checkerboard: [Color; 2],
+    /// The [`Border`] of the swatches, the preview, and the eyedropper button.
+    pub swatch_border: Border,
+    /// The border [`Color`] of the swatch matching the current [`Color`].
+    pub selected_swatch_color: Color,
+    /// The [`Background`] of the hovered eyedropper button.
+    pub hovered_background: Background,
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the input fields of the [`ColorPicker`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        text_color: palette.background.base.text,
+        label_color: palette.background.strongest.color,
+        handle_color: Color::WHITE,
+        checkerboard: [Color::WHITE, Color::from_rgb8(0xCC, 0xCC, 0xCC)],
+        swatch_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        selected_swatch_color: palette.primary.strong.color,
+        hovered_background: palette.background.weak.color.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_round_trip_through_hsva() {
+        for color in [
+            Color::BLACK,
+            Color::WHITE,
+            Color::from_rgb8(0xFF, 0x00, 0x00),
+            Color::from_rgb8(0x12, 0x34, 0x56),
+            Color::from_rgba8(0xAB, 0xCD, 0xEF, 0.5),
+        ] {
+            let hsva = Hsva::from_color(color, Hsva::default());
+
+            assert_eq!(hsva.into_color().into_rgba8(), color.into_rgba8());
+        }
+
+        let gray = Hsva::from_color(
+            Color::from_rgb8(0x80, 0x80, 0x80),
+            Hsva {
+                hue: 120.0,
+                ..Hsva::default()
+            },
+        );
+
+        assert_eq!(gray.hue, 120.0);
+    }
+}
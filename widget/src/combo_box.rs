@@ -148,6 +148,7 @@ where
     text_shaping: text::Shaping,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
     menu_height: Length,
+    loading_text: String,
 }
 
 impl<'a, T, Message, Theme, Renderer> ComboBox<'a, T, Message, Theme, Renderer>
@@ -186,6 +187,7 @@ where
             text_shaping: text::Shaping::default(),
             menu_class: <Theme as Catalog>::default_menu(),
             menu_height: Length::Shrink,
+            loading_text: String::from("Loading..."),
         }
     }
 
@@ -271,6 +273,15 @@ where
         self
     }
 
+    /// Sets the text shown at the end of the menu of the [`ComboBox`] while
+    /// its [`State`] is loading options.
+    ///
+    /// See [`State::set_loading`].
+    pub fn loading_text(mut self, text: impl Into<String>) -> Self {
+        self.loading_text = text.into();
+        self
+    }
+
     /// Sets the [`text::Shaping`] strategy of the [`ComboBox`].
     pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
         self.text_shaping = shaping;
@@ -321,9 +332,14 @@ where
 }
 
 /// The local state of a [`ComboBox`].
+///
+/// Its options can be replaced at any time, even while the menu of the
+/// [`ComboBox`] is open; for instance, with the results of a `Task` fetching
+/// suggestions for the current input.
 #[derive(Debug, Clone)]
 pub struct State<T> {
     options: Vec<T>,
+    is_loading: bool,
     inner: RefCell<Inner<T>>,
 }
 
@@ -365,6 +381,7 @@ where
 
         Self {
             options,
+            is_loading: false,
             inner: RefCell::new(Inner {
                 value,
                 option_matchers,
@@ -395,6 +412,36 @@ where
         );
     }
 
+    /// Replaces the options of the [`State`], keeping the current input.
+    ///
+    /// If the menu of the [`ComboBox`] is open, the hovered option stays
+    /// hovered as long as it is still one of the options.
+    pub fn set_options(&mut self, options: Vec<T>) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.option_matchers = build_matchers(&options);
+        self.options = options;
+
+        inner.filtered_options = Filtered::new(
+            search(&self.options, &inner.option_matchers, &inner.value)
+                .cloned()
+                .collect(),
+        );
+    }
+
+    /// Sets whether the options of the [`State`] are being loaded.
+    ///
+    /// While loading, the menu of the [`ComboBox`] shows an additional row
+    /// with its loading text.
+    pub fn set_loading(&mut self, is_loading: bool) {
+        self.is_loading = is_loading;
+    }
+
+    /// Returns whether the options of the [`State`] are being loaded.
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
     /// Returns ownership of the options of the [`State`].
     pub fn into_options(self) -> Vec<T> {
         self.options
@@ -418,10 +465,10 @@ where
         f(&mut inner);
     }
 
-    fn sync_filtered_options(&self, options: &mut Filtered<T>) {
+    fn sync_filtered_options(&self, options: &mut Filtered<T>) -> bool {
         let inner = self.inner.borrow();
 
-        inner.filtered_options.sync(options);
+        inner.filtered_options.sync(options)
     }
 }
 
@@ -457,10 +504,14 @@ where
         self.updated = Instant::now();
     }
 
-    fn sync(&self, other: &mut Filtered<T>) {
-        if other.updated != self.updated {
-            *other = self.clone();
+    fn sync(&self, other: &mut Filtered<T>) -> bool {
+        if other.updated == self.updated {
+            return false;
         }
+
+        *other = self.clone();
+
+        true
     }
 }
 
@@ -599,6 +650,11 @@ where
                         .collect(),
                 );
             });
+
+            // The filtered options changed because of the input, so the
+            // first one must stay hovered
+            let _ = self.state.sync_filtered_options(&mut menu.filtered_options);
+
             shell.invalidate_layout();
             shell.request_redraw();
         }
@@ -823,9 +879,23 @@ where
                 ..
             } = tree.state.downcast_mut::<Menu<T>>();
 
-            self.state.sync_filtered_options(filtered_options);
+            let hovered = hovered_option
+                .and_then(|index| filtered_options.options.get(index))
+                .map(T::to_string);
+
+            if self.state.sync_filtered_options(filtered_options) {
+                // The options were replaced; keep the same option hovered
+                *hovered_option = hovered
+                    .and_then(|hovered| {
+                        filtered_options
+                            .options
+                            .iter()
+                            .position(|option| option.to_string() == hovered)
+                    })
+                    .or(Some(0));
+            }
 
-            if filtered_options.options.is_empty() {
+            if filtered_options.options.is_empty() && !self.state.is_loading {
                 None
             } else {
                 let bounds = layout.bounds();
@@ -862,6 +932,10 @@ where
                     menu = menu.text_size(size);
                 }
 
+                if self.state.is_loading {
+                    menu = menu.footer(&self.loading_text);
+                }
+
                 Some(menu.overlay(
                     layout.position() + translation,
                     *viewport,
@@ -945,3 +1019,21 @@ where
     matcher.retain(|c| c.is_ascii_alphanumeric());
     matcher.to_lowercase()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+
+    #[test]
+    fn replaced_options_are_searched() {
+        let mut state = State::new(vec!["Apple", "Banana"]);
+
+        state.with_inner_mut(|inner| inner.value = String::from("an"));
+        state.set_options(vec!["Mango", "Cherry", "Banana"]);
+
+        assert_eq!(
+            state.with_inner(|inner| inner.filtered_options.options.clone()),
+            ["Mango", "Banana"]
+        );
+    }
+}
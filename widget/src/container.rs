@@ -20,6 +20,7 @@
 //! }
 //! ```
 use crate::core::alignment::{self, Alignment};
+use crate::core::animation::Interpolate;
 use crate::core::border::{self, Border};
 use crate::core::gradient::{self, Gradient};
 use crate::core::layout;
@@ -30,8 +31,8 @@ use crate::core::theme;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
-    self, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Rectangle,
-    Shadow, Shell, Size, Theme, Vector, Widget, color,
+    self, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, color,
 };
 
 /// A widget that aligns its contents inside of its boundaries.
@@ -320,6 +321,21 @@ where
         )
     }
 
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        self.content.as_widget().hit_test(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            position,
+        )
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -334,23 +350,37 @@ where
         let style = theme.style(&self.class);
 
         if let Some(clipped_viewport) = bounds.intersection(viewport) {
-            draw_background(renderer, &style, bounds);
-
-            self.content.as_widget().draw(
-                tree,
-                renderer,
-                theme,
-                &renderer::Style {
-                    text_color: style.text_color.unwrap_or(renderer_style.text_color),
-                },
-                layout.children().next().unwrap(),
-                cursor,
-                if self.clip {
-                    &clipped_viewport
-                } else {
-                    viewport
-                },
-            );
+            let draw = |renderer: &mut Renderer| {
+                draw_background(renderer, &style, bounds);
+
+                self.content.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style.text_color.unwrap_or(renderer_style.text_color),
+                    },
+                    layout.children().next().unwrap(),
+                    cursor,
+                    if self.clip {
+                        &clipped_viewport
+                    } else {
+                        viewport
+                    },
+                );
+            };
+
+            if style.backdrop_blur > 0.0 {
+                // The backdrop must be blurred in its own layer, below anything
+                // drawn on top of it
+                renderer.with_layer(*viewport, |renderer| {
+                    renderer.blur_backdrop(bounds, style.border.radius, style.backdrop_blur);
+
+                    draw(renderer);
+                });
+            } else {
+                draw(renderer);
+            }
         }
     }
 
@@ -447,6 +477,11 @@ pub struct Style {
     pub shadow: Shadow,
     /// Whether the container should be snapped to the pixel grid.
     pub snap: bool,
+    /// The blur radius applied to whatever is drawn beneath the container.
+    ///
+    /// Renderers unable to blur will only draw the [`Background`], so a
+    /// translucent one is a good companion.
+    pub backdrop_blur: f32,
 }
 
 impl Default for Style {
@@ -457,6 +492,20 @@ impl Default for Style {
             border: Border::default(),
             shadow: Shadow::default(),
             snap: renderer::CRISP,
+            backdrop_blur: 0.0,
+        }
+    }
+}
+
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            background: self.background.interpolate(&other.background, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
+            snap: other.snap,
+            backdrop_blur: self.backdrop_blur.interpolate(&other.backdrop_blur, ratio),
         }
     }
 }
@@ -493,6 +542,14 @@ impl Style {
             ..self
         }
     }
+
+    /// Updates the backdrop blur radius of the [`Style`].
+    pub fn backdrop_blur(self, radius: f32) -> Self {
+        Self {
+            backdrop_blur: radius,
+            ..self
+        }
+    }
 }
 
 impl From<Color> for Style {
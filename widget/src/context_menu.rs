@@ -0,0 +1,425 @@
+//! Context menus display a menu at the pointer when some content is
+//! right-clicked or long-pressed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::menu_bar::{item, separator, submenu};
+//! use iced::widget::{container, context_menu, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Copy,
+//!     Paste,
+//!     Share(&'static str),
+//!     Delete,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     context_menu(
+//!         container(text("Right-click me!")).padding(20),
+//!         [
+//!             item("&Copy").shortcut("Ctrl+C").on_press(Message::Copy),
+//!             item("&Paste").shortcut("Ctrl+V").on_press(Message::Paste),
+//!             submenu(
+//!                 "&Share",
+//!                 [
+//!                     item("&Email").on_press(Message::Share("email")),
+//!                     item("&Link").on_press(Message::Share("link")),
+//!                 ],
+//!             ),
+//!             separator(),
+//!             item("&Delete").on_press(Message::Delete),
+//!         ],
+//!     )
+//!     .into()
+//! }
+//! # struct State;
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::menu_bar::{self, Anchor, Catalog, Item, Metrics, Path, Style, StyleFn};
+
+/// How long a finger must be held down to open a [`ContextMenu`].
+const LONG_PRESS: Duration = Duration::from_millis(500);
+
+/// How far a finger may move before a long press is cancelled.
+const LONG_PRESS_TOLERANCE: f32 = 10.0;
+
+/// A widget that opens a menu at the pointer when its content is
+/// right-clicked or long-pressed.
+///
+/// The items of a [`ContextMenu`] are the same as the ones of a
+/// [`MenuBar`](crate::MenuBar) and can be created with [`menu_bar::item`],
+/// [`menu_bar::submenu`], and [`menu_bar::separator`].
+pub struct ContextMenu<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] wrapping the given content, which opens
+    /// a menu with the given items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            padding: menu_bar::MenuBar::<'a, Message, Theme, Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    path: Path,
+    position: Vector,
+    press: Option<Press>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    finger: touch::Finger,
+    position: Point,
+    at: Instant,
+}
+
+impl State {
+    fn open(&mut self, position: Point, bounds: Rectangle) {
+        self.path.open(None);
+        self.position = position - bounds.position();
+        self.press = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if state.path.is_open() {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                    // The event was not captured by the menu, so the cursor
+                    // was pressed outside of it
+                    state.path.close();
+
+                    if *button == mouse::Button::Right
+                        && let Some(position) = cursor.position_over(bounds)
+                    {
+                        state.open(position, bounds);
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    state.path.close();
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                    if !state.path.navigate(&self.items, key, shell)
+                        && *key == keyboard::Key::Named(key::Named::Escape)
+                    {
+                        state.path.close();
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+                Event::Window(window::Event::Unfocused) => {
+                    state.path.close();
+                    shell.request_redraw();
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if state.path.is_open() || self.items.is_empty() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.open(position, bounds);
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if bounds.contains(*position) {
+                    let at = Instant::now();
+
+                    state.press = Some(Press {
+                        finger: *id,
+                        position: *position,
+                        at,
+                    });
+
+                    shell.request_redraw_at(at + LONG_PRESS);
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(press) = state.press
+                    && press.finger == *id
+                    && press.position.distance(*position) > LONG_PRESS_TOLERANCE
+                {
+                    state.press = None;
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if state.press.is_some_and(|press| press.finger == *id) {
+                    state.press = None;
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(press) = state.press {
+                    if *now >= press.at + LONG_PRESS {
+                        state.open(press.position, bounds);
+                        shell.request_redraw();
+                    } else {
+                        shell.request_redraw_at(press.at + LONG_PRESS);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let menu = if state.path.is_open() {
+            Some(overlay::Element::new(Box::new(menu_bar::Overlay {
+                root: &self.items,
+                path: &mut state.path,
+                metrics: Metrics {
+                    padding: self.padding,
+                    text_size: self.text_size,
+                    font: self.font,
+                },
+                class: &self.class,
+                anchor: Anchor::At(layout.position() + translation + state.position),
+            })))
+        } else {
+            None
+        };
+
+        if content.is_some() || menu.is_some() {
+            Some(overlay::Group::with_children(content.into_iter().chain(menu).collect()).overlay())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
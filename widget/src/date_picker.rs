@@ -0,0 +1,932 @@
+//! Date pickers let users select a date from a calendar.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::date_picker;
+//! use iced::widget::date_picker::Date;
+//!
+//! struct State {
+//!    date: Option<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     DateSelected(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     date_picker(state.date, Message::DateSelected)
+//!         .min(Date::from_ymd(2024, 1, 1).unwrap())
+//!         .placeholder("Pick a date...")
+//!         .into()
+//! }
+//! ```
+use crate::calendar::{Grid, Month};
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+pub use crate::calendar::{Date, Locale, Weekday};
+
+/// A widget for selecting a [`Date`] from a calendar.
+///
+/// By default, a [`DatePicker`] displays the selected [`Date`] in a field
+/// that opens the calendar in an overlay when pressed. The calendar can
+/// also be displayed inline with [`Presentation::Inline`].
+pub struct DatePicker<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    selected: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    locale: Locale,
+    presentation: Presentation,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`DatePicker`] with the given selected [`Date`] and
+    /// the function that produces a message when a [`Date`] is selected.
+    pub fn new(selected: Option<Date>, on_select: impl Fn(Date) -> Message + 'a) -> Self {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            min: None,
+            max: None,
+            is_disabled: None,
+            locale: Locale::default(),
+            presentation: Presentation::default(),
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the earliest [`Date`] that can be selected.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be selected.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that decides whether a [`Date`] is disabled; that is,
+    /// whether it cannot be selected.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`DatePicker`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the [`Presentation`] of the [`DatePicker`].
+    pub fn presentation(mut self, presentation: Presentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    /// Sets the placeholder of the [`DatePicker`], displayed in its field
+    /// when no [`Date`] is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the field of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns whether the given [`Date`] can be selected.
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self
+                .is_disabled
+                .as_ref()
+                .is_some_and(|is_disabled| is_disabled(date))
+    }
+
+    /// Returns the month displayed when the calendar is first shown.
+    fn initial_month(&self) -> Month {
+        let date = self.selected.unwrap_or_else(Date::today);
+
+        let date = match (self.min, self.max) {
+            (Some(min), _) if date < min => min,
+            (_, Some(max)) if date > max => max,
+            _ => date,
+        };
+
+        Month::of(date)
+    }
+
+    fn resolve_text_size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the size of a cell of the calendar.
+    fn cell_size(&self, renderer: &Renderer) -> f32 {
+        (self.resolve_text_size(renderer).0 * 2.0).round()
+    }
+
+    /// Returns the size of the calendar.
+    fn calendar_size(&self, renderer: &Renderer) -> Size {
+        let cell = self.cell_size(renderer);
+
+        Size::new(cell * 7.0, cell * (Calendar::ROWS as f32)).expand(self.padding)
+    }
+
+    /// Returns the [`Calendar`] region under the cursor, if any.
+    fn hovered_region(
+        &self,
+        month: Month,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> Option<Region> {
+        let position = cursor.position_in(bounds.shrink(self.padding))?;
+        let cell = self.cell_size(renderer);
+
+        let calendar = Calendar::new(month, self.locale);
+        let region = calendar.region(
+            (position.y / cell) as usize,
+            ((position.x / cell) as usize).min(6),
+        )?;
+
+        self.is_region_enabled(month, region).then_some(region)
+    }
+
+    /// Returns whether the given [`Calendar`] region of the given [`Month`]
+    /// can be pressed.
+    fn is_region_enabled(&self, month: Month, region: Region) -> bool {
+        match region {
+            Region::Previous => self
+                .min
+                .is_none_or(|min| month.previous() >= Month::of(min)),
+            Region::Next => self.max.is_none_or(|max| month.next() <= Month::of(max)),
+            Region::Day(date) => self.is_enabled(date),
+        }
+    }
+
+    /// Processes an event in the calendar with the given bounds, returning
+    /// `true` if a [`Date`] was selected.
+    fn update_calendar<P: text::Paragraph>(
+        &self,
+        state: &mut State<P>,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })) = event
+        else {
+            if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event
+                && cursor.is_over(bounds)
+            {
+                shell.request_redraw();
+            }
+
+            return false;
+        };
+
+        if !cursor.is_over(bounds) {
+            return false;
+        }
+
+        shell.capture_event();
+
+        let month = state.month.unwrap_or_else(|| self.initial_month());
+
+        match self.hovered_region(month, bounds, cursor, renderer) {
+            Some(Region::Previous) => {
+                state.month = Some(month.previous());
+                shell.request_redraw();
+
+                false
+            }
+            Some(Region::Next) => {
+                state.month = Some(month.next());
+                shell.request_redraw();
+
+                false
+            }
+            Some(Region::Day(date)) => {
+                shell.publish((self.on_select)(date));
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn draw_calendar<P: text::Paragraph>(
+        &self,
+        state: &State<P>,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let style = theme.style(&self.class);
+        let month = state.month.unwrap_or_else(|| self.initial_month());
+        let calendar = Calendar::new(month, self.locale);
+        let hovered = self.hovered_region(month, bounds, cursor, renderer);
+
+        let cell = self.cell_size(renderer);
+        let text_size = self.resolve_text_size(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let inner = bounds.shrink(self.padding);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let cell_bounds = |row: usize, column: usize| Rectangle {
+            x: inner.x + column as f32 * cell,
+            y: inner.y + row as f32 * cell,
+            width: cell,
+            height: cell,
+        };
+
+        let fill_text =
+            |renderer: &mut Renderer, content: String, bounds: Rectangle, color: Color| {
+                renderer.fill_text(
+                    Text {
+                        content,
+                        bounds: bounds.size(),
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                        hint_factor: None,
+                    },
+                    bounds.center(),
+                    color,
+                    *viewport,
+                );
+            };
+
+        let fill_cell = |renderer: &mut Renderer, bounds: Rectangle, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default().rounded(cell / 2.0),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        // Header
+        fill_text(
+            renderer,
+            format!(
+                "{} {}",
+                self.locale.months[month.month() as usize - 1],
+                month.year()
+            ),
+            Rectangle {
+                width: inner.width,
+                ..cell_bounds(0, 0)
+            },
+            style.text_color,
+        );
+
+        for (region, column, arrow) in [(Region::Previous, 0, "‹"), (Region::Next, 6, "›")] {
+            let bounds = cell_bounds(0, column);
+
+            if hovered == Some(region) {
+                fill_cell(renderer, bounds, style.hovered_background);
+            }
+
+            fill_text(
+                renderer,
+                arrow.to_owned(),
+                bounds,
+                if self.is_region_enabled(month, region) {
+                    style.text_color
+                } else {
+                    style.disabled_text_color
+                },
+            );
+        }
+
+        // Weekdays
+        for column in 0..7 {
+            fill_text(
+                renderer,
+                self.locale.weekdays[calendar.weekday(column).index()].to_owned(),
+                cell_bounds(1, column),
+                style.weekday_text_color,
+            );
+        }
+
+        // Days
+        for week in 0..Grid::WEEKS {
+            for column in 0..7 {
+                let row = week + 2;
+
+                let Some(Region::Day(date)) = calendar.region(row, column) else {
+                    continue;
+                };
+
+                let bounds = cell_bounds(row, column);
+                let is_selected = self.selected == Some(date);
+
+                if is_selected {
+                    fill_cell(renderer, bounds, style.selected_background);
+                } else if hovered == Some(Region::Day(date)) {
+                    fill_cell(renderer, bounds, style.hovered_background);
+                }
+
+                fill_text(
+                    renderer,
+                    date.day().to_string(),
+                    bounds,
+                    if is_selected {
+                        style.selected_text_color
+                    } else if self.is_enabled(date) {
+                        style.text_color
+                    } else {
+                        style.disabled_text_color
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    is_open: bool,
+    month: Option<Month>,
+    label: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            month: None,
+            label: paragraph::Plain::default(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        match self.presentation {
+            Presentation::Overlay => Size {
+                width: self.width,
+                height: Length::Shrink,
+            },
+            Presentation::Inline => Size {
+                width: Length::Shrink,
+                height: Length::Shrink,
+            },
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if self.presentation == Presentation::Inline {
+            return layout::Node::new(limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                self.calendar_size(renderer),
+            ));
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let text_size = self.resolve_text_size(renderer);
+        let line_height = text::LineHeight::default();
+
+        let label = self.selected.map(|date| date.to_string());
+
+        let _ = state.label.update(Text {
+            content: label
+                .as_deref()
+                .or(self.placeholder.as_deref())
+                .unwrap_or("0000-00-00"),
+            bounds: Size::new(f32::INFINITY, line_height.to_absolute(text_size).into()),
+            size: text_size,
+            line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::default(),
+            hint_factor: renderer.scale_factor(),
+        });
+
+        let intrinsic = Size::new(
+            state.label.min_width() + text_size.0 + self.padding.left,
+            f32::from(line_height.to_absolute(text_size)),
+        );
+
+        layout::Node::new(
+            limits
+                .width(self.width)
+                .shrink(self.padding)
+                .resolve(self.width, Length::Shrink, intrinsic)
+                .expand(self.padding),
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if self.presentation == Presentation::Inline {
+            let _ = self.update_calendar(state, event, layout.bounds(), cursor, renderer, shell);
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if state.is_open {
+                // The event was not captured by the overlay, so the cursor was
+                // pressed outside of the calendar
+                state.is_open = false;
+                shell.capture_event();
+            } else if cursor.is_over(layout.bounds()) {
+                state.is_open = true;
+                state.month = Some(self.initial_month());
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let is_interactive = match self.presentation {
+            Presentation::Overlay => cursor.is_over(bounds),
+            Presentation::Inline => self
+                .hovered_region(
+                    state.month.unwrap_or_else(|| self.initial_month()),
+                    bounds,
+                    cursor,
+                    renderer,
+                )
+                .is_some(),
+        };
+
+        if is_interactive {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        if self.presentation == Presentation::Inline {
+            self.draw_calendar(state, renderer, theme, bounds, cursor, viewport);
+            return;
+        }
+
+        let style = theme.style(&self.class);
+        let text_size = self.resolve_text_size(renderer);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.fill_text(
+            Text {
+                content: Renderer::ARROW_DOWN_ICON.to_string(),
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: Renderer::ICON_FONT,
+                bounds: Size::new(
+                    bounds.width,
+                    f32::from(text::LineHeight::default().to_absolute(text_size)),
+                ),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::default(),
+                hint_factor: None,
+            },
+            Point::new(
+                bounds.x + bounds.width - self.padding.right,
+                bounds.center_y(),
+            ),
+            style.text_color,
+            *viewport,
+        );
+
+        let label = self.selected.map(|date| date.to_string());
+
+        if let Some(label) = label.or_else(|| self.placeholder.clone()) {
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    bounds: Size::new(
+                        bounds.width - self.padding.x(),
+                        f32::from(text::LineHeight::default().to_absolute(text_size)),
+                    ),
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::default(),
+                    hint_factor: renderer.scale_factor(),
+                },
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if self.selected.is_some() {
+                    style.text_color
+                } else {
+                    style.disabled_text_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if self.presentation != Presentation::Overlay || !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            picker: self,
+            state,
+            position: bounds.position() + translation,
+            target_height: bounds.height,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(date_picker)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a DatePicker<'b, Message, Theme, Renderer>,
+    state: &'a mut State<Renderer::Paragraph>,
+    position: Point,
+    target_height: f32,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self.picker.calendar_size(renderer);
+
+        let space_below = bounds.height - (self.position.y + self.target_height);
+        let space_above = self.position.y;
+
+        let y = if space_below >= size.height || space_below > space_above {
+            self.position.y + self.target_height
+        } else {
+            self.position.y - size.height
+        };
+
+        let x = self.position.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.with_layer(bounds, |renderer| {
+            self.picker
+                .draw_calendar(self.state, renderer, theme, bounds, cursor, &bounds);
+        });
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self
+            .picker
+            .update_calendar(self.state, event, layout.bounds(), cursor, renderer, shell)
+        {
+            self.state.is_open = false;
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let month = self
+            .state
+            .month
+            .unwrap_or_else(|| self.picker.initial_month());
+
+        if self
+            .picker
+            .hovered_region(month, layout.bounds(), cursor, renderer)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// The presentation of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Presentation {
+    /// The calendar is displayed in an overlay, when the field of the
+    /// [`DatePicker`] is pressed.
+    #[default]
+    Overlay,
+    /// The calendar is always displayed in place.
+    Inline,
+}
+
+/// The grid of a calendar: a header, the weekdays, and the weeks of a [`Month`].
+struct Calendar {
+    grid: Grid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+impl Calendar {
+    const ROWS: usize = Grid::WEEKS + 2;
+
+    fn new(month: Month, locale: Locale) -> Self {
+        Self {
+            grid: Grid::new(month, locale),
+        }
+    }
+
+    fn weekday(&self, column: usize) -> Weekday {
+        self.grid.weekday(column)
+    }
+
+    fn region(&self, row: usize, column: usize) -> Option<Region> {
+        match (row, column) {
+            (0, 0) => Some(Region::Previous),
+            (0, 6) => Some(Region::Next),
+            (2.., _) => self.grid.day(row - 2, column).map(Region::Day),
+            _ => None,
+        }
+    }
+}
+
+/// The appearance of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the field and the calendar.
+    pub background: Background,
+    /// The [`Border`] of the field and the calendar.
+    pub border: Border,
+    /// The text [`Color`] of the [`DatePicker`].
+    pub text_color: Color,
+    /// The text [`Color`] of the weekdays of the calendar.
+    pub weekday_text_color: Color,
+    /// The text [`Color`] of the disabled dates and the placeholder.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of the hovered date.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected date.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected date.
+    pub selected_text_color: Color,
+}
+
+/// The theme catalog of a [`DatePicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DatePicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DatePicker`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        text_color: palette.background.base.text,
+        weekday_text_color: palette.background.strongest.color,
+        disabled_text_color: palette.background.strong.color,
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+    }
+}
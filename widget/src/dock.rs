@@ -0,0 +1,424 @@
+//! Docks arrange panels in tab groups that can be dragged, torn off, and
+//! collapsed—like the tool windows of an IDE.
+//!
+//! A [`Dock`] is built on top of a [`PaneGrid`]:
+//!
+//! * Each dock area holds a [`Group`] of panels, displayed as [`Tabs`]
+//! * The title bar of an area is a drag handle, with drop-zone previews
+//! * Dropping on the center of another area moves the selected tab into it
+//! * Dropping on an edge tears the selected tab off into a new area
+//! * Areas can be collapsed to a sidebar and expanded back
+//! * The [`Configuration`] of a dock can be stored and restored later
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{dock, text};
+//!
+//! struct State {
+//!     dock: dock::State<Panel>,
+//! }
+//!
+//! enum Panel {
+//!     Explorer,
+//!     Editor(String),
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dock(dock::Event),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     dock(
+//!         &state.dock,
+//!         |panel| match panel {
+//!             Panel::Explorer => "Explorer".to_owned(),
+//!             Panel::Editor(file) => file.clone(),
+//!         },
+//!         |panel| match panel {
+//!             Panel::Explorer => text("Files").into(),
+//!             Panel::Editor(file) => text(file).into(),
+//!         },
+//!         Message::Dock,
+//!     )
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Dock(event) => state.dock.perform(event),
+//!     }
+//! }
+//! ```
+//!
+//! [`PaneGrid`]: crate::PaneGrid
+//! [`Tabs`]: crate::Tabs
+mod configuration;
+
+pub mod state;
+
+pub use configuration::{Area, Configuration};
+pub use state::{Group, State};
+
+pub use crate::pane_grid::{Axis, DragEvent, Pane, ResizeEvent};
+
+use crate::button::{self, Button};
+use crate::container::{self, Container};
+use crate::core::border;
+use crate::core::text;
+use crate::core::{Background, Border, Color, Element, Length, Pixels, Theme};
+use crate::pane_grid::{self, PaneGrid};
+use crate::tabs::{self, Reorder, Tab, Tabs};
+use crate::{Column, Row, Space, Text};
+
+use std::rc::Rc;
+
+/// A set of dock areas holding tab groups of panels.
+///
+/// A [`Dock`] produces an [`Event`] for every interaction, which must be
+/// applied to its [`State`] with [`State::perform`].
+pub struct Dock<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a State<T>,
+    title: Box<dyn Fn(&T) -> String + 'a>,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    on_event: Rc<dyn Fn(Event) -> Message + 'a>,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Dock<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Dock`] with the given [`State`], a function that
+    /// produces the title of a panel, the view function of a panel, and a
+    /// function that produces a message for an [`Event`].
+    pub fn new(
+        state: &'a State<T>,
+        title: impl Fn(&T) -> String + 'a,
+        view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+        on_event: impl Fn(Event) -> Message + 'a,
+    ) -> Self {
+        Self {
+            state,
+            title: Box::new(title),
+            view: Box::new(view),
+            on_event: Rc::new(on_event),
+            spacing: 2.0,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the spacing _between_ the dock areas of the [`Dock`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the text size of the tabs and the sidebar of the [`Dock`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the [`Dock`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Dock`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// An interaction with a [`Dock`].
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    /// The title bar of a dock area was dragged.
+    Dragged(DragEvent),
+    /// A split between dock areas was resized.
+    Resized(ResizeEvent),
+    /// A tab of a dock area was selected.
+    Selected {
+        /// The dock area of the tab.
+        pane: Pane,
+        /// The index of the tab.
+        index: usize,
+    },
+    /// A tab of a dock area was closed.
+    Closed {
+        /// The dock area of the tab.
+        pane: Pane,
+        /// The index of the tab.
+        index: usize,
+    },
+    /// A tab of a dock area was dragged to a new position.
+    Reordered {
+        /// The dock area of the tab.
+        pane: Pane,
+        /// The [`Reorder`] of the tab.
+        reorder: Reorder,
+    },
+    /// A dock area was collapsed to the sidebar.
+    Collapsed(Pane),
+    /// The group at the given index of the sidebar was expanded.
+    Expanded(usize),
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Dock<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(dock: Dock<'a, T, Message, Theme, Renderer>) -> Self {
+        let Dock {
+            state,
+            title,
+            view,
+            on_event,
+            spacing,
+            text_size,
+            class,
+        } = dock;
+
+        let class = Rc::new(class);
+        let is_collapsible = state.panes.len() > 1;
+
+        let grid = PaneGrid::new(&state.panes, |pane, group, _is_maximized| {
+            let tabs = {
+                let on_select = on_event.clone();
+                let on_close = on_event.clone();
+                let on_reorder = on_event.clone();
+
+                let tabs = Tabs::new(
+                    group.panels().iter().map(|panel| Tab::new(title(panel))),
+                    Some(group.active_index()),
+                    move |index| on_select(Event::Selected { pane, index }),
+                )
+                .on_close(move |index| on_close(Event::Closed { pane, index }))
+                .on_reorder(move |reorder| on_reorder(Event::Reordered { pane, reorder }))
+                .width(Length::Shrink);
+
+                if let Some(text_size) = text_size {
+                    tabs.text_size(text_size)
+                } else {
+                    tabs
+                }
+            };
+
+            let title_bar = {
+                let class = class.clone();
+
+                pane_grid::TitleBar::new(tabs).style(move |theme| {
+                    let style = <Theme as Catalog>::style(theme, &class);
+
+                    container::Style {
+                        background: style.title_bar,
+                        ..container::Style::default()
+                    }
+                })
+            };
+
+            let title_bar = if is_collapsible {
+                title_bar.controls(pane_grid::Controls::new(entry(
+                    "–".to_owned(),
+                    on_event(Event::Collapsed(pane)),
+                    text_size,
+                    class.clone(),
+                )))
+            } else {
+                title_bar
+            };
+
+            let body: Element<'a, Message, Theme, Renderer> = match group.active() {
+                Some(panel) => view(panel),
+                None => Space::new().into(),
+            };
+
+            let class = class.clone();
+
+            pane_grid::Content::new(body)
+                .title_bar(title_bar)
+                .style(move |theme| {
+                    let style = <Theme as Catalog>::style(theme, &class);
+
+                    container::Style {
+                        background: style.background,
+                        border: style.border,
+                        ..container::Style::default()
+                    }
+                })
+        })
+        .spacing(spacing)
+        .on_drag({
+            let on_event = on_event.clone();
+
+            move |event| on_event(Event::Dragged(event))
+        })
+        .on_resize(10, {
+            let on_event = on_event.clone();
+
+            move |event| on_event(Event::Resized(event))
+        });
+
+        if state.sidebar.is_empty() {
+            return grid.into();
+        }
+
+        let entries = state
+            .sidebar
+            .iter()
+            .enumerate()
+            .filter_map(|(index, group)| {
+                let panel = group.active()?;
+
+                Some(entry(
+                    title(panel),
+                    on_event(Event::Expanded(index)),
+                    text_size,
+                    class.clone(),
+                ))
+            })
+            .map(Element::from);
+
+        let sidebar = Container::new(Column::with_children(entries).spacing(2).padding(2))
+            .height(Length::Fill)
+            .style(move |theme| {
+                let style = <Theme as Catalog>::style(theme, &class);
+
+                container::Style {
+                    background: style.sidebar,
+                    ..container::Style::default()
+                }
+            });
+
+        Row::new().push(sidebar).push(grid).spacing(spacing).into()
+    }
+}
+
+/// Produces a flat button of a [`Dock`], used in its sidebar and title bars.
+fn entry<'a, Message, Theme, Renderer>(
+    label: String,
+    on_press: Message,
+    text_size: Option<Pixels>,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    let label = Text::new(label);
+    let label = if let Some(text_size) = text_size {
+        label.size(text_size)
+    } else {
+        label
+    };
+
+    Button::new(label)
+        .padding([2, 6])
+        .on_press(on_press)
+        .style(move |theme, status| {
+            let style = <Theme as Catalog>::style(theme, &class);
+
+            button::Style {
+                background: match status {
+                    button::Status::Hovered | button::Status::Pressed => style.hovered,
+                    button::Status::Active | button::Status::Disabled => None,
+                },
+                text_color: style.text_color,
+                border: border::rounded(2),
+                ..button::Style::default()
+            }
+        })
+}
+
+/// The appearance of a dock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the dock areas.
+    pub background: Option<Background>,
+    /// The [`Border`] of the dock areas.
+    pub border: Border,
+    /// The [`Background`] of the title bars of the dock areas.
+    pub title_bar: Option<Background>,
+    /// The [`Background`] of the sidebar.
+    pub sidebar: Option<Background>,
+    /// The text [`Color`] of the sidebar and the title bar controls.
+    pub text_color: Color,
+    /// The [`Background`] of the hovered entries of the sidebar and the
+    /// title bar controls.
+    pub hovered: Option<Background>,
+}
+
+/// The theme catalog of a [`Dock`].
+pub trait Catalog:
+    pane_grid::Catalog + tabs::Catalog + button::Catalog + container::Catalog + crate::text::Catalog
+{
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Dock`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Dock`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            radius: 0.0.into(),
+        },
+        title_bar: Some(palette.background.weak.color.into()),
+        sidebar: Some(palette.background.weak.color.into()),
+        text_color: palette.background.weak.text,
+        hovered: Some(palette.background.strong.color.into()),
+    }
+}
@@ -0,0 +1,96 @@
+use crate::dock::Group;
+use crate::pane_grid::{self, Axis};
+
+/// The arrangement of a [`Dock`], which can be stored and restored later.
+///
+/// With the `serde` feature enabled, a [`Configuration`] can be serialized
+/// as long as its panels can. Use [`Configuration::map`] to store only a
+/// key of each panel.
+///
+/// [`Dock`]: super::Dock
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Configuration<T> {
+    /// The arrangement of the dock areas.
+    pub area: Area<T>,
+    /// The groups collapsed to the sidebar.
+    pub sidebar: Vec<Group<T>>,
+}
+
+impl<T> Configuration<T> {
+    /// Produces a new [`Configuration`] by applying the given function to
+    /// each panel.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Configuration<U> {
+        Configuration {
+            area: self.area.map(&mut f),
+            sidebar: self
+                .sidebar
+                .into_iter()
+                .map(|group| group.map(&mut f))
+                .collect(),
+        }
+    }
+}
+
+impl<T> From<Area<T>> for Configuration<T> {
+    fn from(area: Area<T>) -> Self {
+        Self {
+            area,
+            sidebar: Vec::new(),
+        }
+    }
+}
+
+/// The arrangement of the dock areas of a [`Dock`].
+///
+/// [`Dock`]: super::Dock
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Area<T> {
+    /// A split of the available space.
+    Split {
+        /// The direction of the split.
+        axis: Axis,
+
+        /// The ratio of the split in [0.0, 1.0].
+        ratio: f32,
+
+        /// The left/top [`Area`] of the split.
+        a: Box<Area<T>>,
+
+        /// The right/bottom [`Area`] of the split.
+        b: Box<Area<T>>,
+    },
+    /// A dock area with a [`Group`] of tabs.
+    Group(Group<T>),
+}
+
+impl<T> Area<T> {
+    /// Produces a new [`Area`] by applying the given function to each
+    /// panel.
+    pub fn map<U>(self, f: &mut impl FnMut(T) -> U) -> Area<U> {
+        match self {
+            Area::Split { axis, ratio, a, b } => Area::Split {
+                axis,
+                ratio,
+                a: Box::new(a.map(f)),
+                b: Box::new(b.map(f)),
+            },
+            Area::Group(group) => Area::Group(group.map(f)),
+        }
+    }
+}
+
+impl<T> From<Area<T>> for pane_grid::Configuration<Group<T>> {
+    fn from(area: Area<T>) -> Self {
+        match area {
+            Area::Split { axis, ratio, a, b } => pane_grid::Configuration::Split {
+                axis,
+                ratio,
+                a: Box::new((*a).into()),
+                b: Box::new((*b).into()),
+            },
+            Area::Group(group) => pane_grid::Configuration::Pane(group),
+        }
+    }
+}
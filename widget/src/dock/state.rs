@@ -0,0 +1,374 @@
+//! The state of a [`Dock`].
+//!
+//! [`Dock`]: super::Dock
+use crate::dock::{Area, Configuration, Event};
+use crate::pane_grid::{self, Axis, Edge, Node, Pane, Region, Target};
+use crate::tabs::Reorder;
+
+/// The state of a [`Dock`].
+///
+/// It keeps the tab groups of the dock areas and the groups collapsed to
+/// the sidebar. Call [`State::perform`] with the [`Event`]s produced by the
+/// [`Dock`] to keep it up to date.
+///
+/// [`Dock`]: super::Dock
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    pub(super) panes: pane_grid::State<Group<T>>,
+    pub(super) sidebar: Vec<Group<T>>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with a single dock area holding the given
+    /// panel.
+    pub fn new(panel: T) -> Self {
+        let (panes, _) = pane_grid::State::new(Group::new(panel));
+
+        Self {
+            panes,
+            sidebar: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`State`] with the given [`Configuration`].
+    pub fn with_configuration(configuration: impl Into<Configuration<T>>) -> Self {
+        let Configuration { area, sidebar } = configuration.into();
+
+        Self {
+            panes: pane_grid::State::with_configuration(area),
+            sidebar,
+        }
+    }
+
+    /// Returns the [`Configuration`] of the [`State`], which can be stored and
+    /// restored later with [`State::with_configuration`].
+    pub fn configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        fn area<T: Clone>(panes: &pane_grid::State<Group<T>>, node: &Node) -> Area<T> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Area::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(area(panes, a)),
+                    b: Box::new(area(panes, b)),
+                },
+                Node::Pane(pane) => {
+                    Area::Group(panes.get(*pane).cloned().unwrap_or_else(Group::empty))
+                }
+            }
+        }
+
+        Configuration {
+            area: area(&self.panes, self.panes.layout()),
+            sidebar: self.sidebar.clone(),
+        }
+    }
+
+    /// Returns the internal [`pane_grid::State`] of the dock areas.
+    pub fn panes(&self) -> &pane_grid::State<Group<T>> {
+        &self.panes
+    }
+
+    /// Returns the [`Group`] of the given dock area.
+    pub fn get(&self, pane: Pane) -> Option<&Group<T>> {
+        self.panes.get(pane)
+    }
+
+    /// Returns the mutable [`Group`] of the given dock area.
+    pub fn get_mut(&mut self, pane: Pane) -> Option<&mut Group<T>> {
+        self.panes.get_mut(pane)
+    }
+
+    /// Returns the groups collapsed to the sidebar.
+    pub fn sidebar(&self) -> &[Group<T>] {
+        &self.sidebar
+    }
+
+    /// Adds the given panel as a new tab of the given dock area and selects
+    /// it.
+    pub fn push(&mut self, pane: Pane, panel: T) {
+        if let Some(group) = self.panes.get_mut(pane) {
+            group.push(panel);
+        }
+    }
+
+    /// Splits the given dock area in the given [`Axis`], placing the given
+    /// panel in the new area.
+    pub fn split(&mut self, axis: Axis, pane: Pane, panel: T) -> Option<Pane> {
+        self.panes
+            .split(axis, pane, Group::new(panel))
+            .map(|(pane, _)| pane)
+    }
+
+    /// Closes the tab at the given index of the given dock area and returns
+    /// its panel.
+    ///
+    /// The dock area is closed as well once it is left empty, unless it is
+    /// the last one.
+    pub fn close(&mut self, pane: Pane, index: usize) -> Option<T> {
+        let group = self.panes.get_mut(pane)?;
+        let panel = group.remove(index)?;
+
+        if group.is_empty() && self.panes.len() > 1 {
+            let _ = self.panes.close(pane);
+        }
+
+        Some(panel)
+    }
+
+    /// Collapses the given dock area to the sidebar.
+    ///
+    /// Returns `false` if the dock area could not be collapsed; the last
+    /// one always stays in place.
+    pub fn collapse(&mut self, pane: Pane) -> bool {
+        if self.panes.len() <= 1 {
+            return false;
+        }
+
+        if let Some((group, _)) = self.panes.close(pane) {
+            self.sidebar.push(group);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Expands the group at the given index of the sidebar, placing it at
+    /// the left edge of the [`Dock`].
+    ///
+    /// [`Dock`]: super::Dock
+    pub fn expand(&mut self, index: usize) -> Option<Pane> {
+        if index >= self.sidebar.len() {
+            return None;
+        }
+
+        let anchor = *self.panes.iter().next()?.0;
+        let group = self.sidebar.remove(index);
+
+        let (pane, _) = self.panes.split(Axis::Vertical, anchor, group)?;
+        self.panes.move_to_edge(pane, Edge::Left);
+
+        Some(pane)
+    }
+
+    /// Applies the given [`Event`] to the [`State`].
+    pub fn perform(&mut self, event: Event) {
+        match event {
+            Event::Dragged(pane_grid::DragEvent::Dropped { pane, target }) => {
+                self.drop(pane, target);
+            }
+            Event::Dragged(_) => {}
+            Event::Resized(pane_grid::ResizeEvent { split, ratio }) => {
+                self.panes.resize(split, ratio);
+            }
+            Event::Selected { pane, index } => {
+                if let Some(group) = self.panes.get_mut(pane) {
+                    group.select(index);
+                }
+            }
+            Event::Closed { pane, index } => {
+                let _ = self.close(pane, index);
+            }
+            Event::Reordered { pane, reorder } => {
+                if let Some(group) = self.panes.get_mut(pane) {
+                    group.reorder(reorder);
+                }
+            }
+            Event::Collapsed(pane) => {
+                let _ = self.collapse(pane);
+            }
+            Event::Expanded(index) => {
+                let _ = self.expand(index);
+            }
+        }
+    }
+
+    /// Drops the selected tab of the given dock area into the given
+    /// [`Target`].
+    ///
+    /// Dropping on the center of another area moves the tab into its group.
+    /// Dropping anywhere else tears the tab off into a new area, or moves the
+    /// whole area if the tab is the last one of its group.
+    fn drop(&mut self, pane: Pane, target: Target) {
+        match target {
+            Target::Pane(target, Region::Center) => {
+                if target == pane || self.panes.get(target).is_none() {
+                    return;
+                }
+
+                let panels = if self.panes.get(pane).is_some_and(|group| group.len() > 1) {
+                    self.panes
+                        .get_mut(pane)
+                        .and_then(|group| group.remove(group.active))
+                        .into_iter()
+                        .collect()
+                } else {
+                    self.panes
+                        .close(pane)
+                        .map(|(group, _)| group.panels)
+                        .unwrap_or_default()
+                };
+
+                if let Some(group) = self.panes.get_mut(target) {
+                    for panel in panels {
+                        group.push(panel);
+                    }
+                }
+            }
+            Target::Pane(target, region) if target == pane => {
+                if let Some(torn) = self.tear(pane) {
+                    self.panes.drop(torn, Target::Pane(pane, region));
+                }
+            }
+            _ => {
+                let pane = self.tear(pane).unwrap_or(pane);
+
+                self.panes.drop(pane, target);
+            }
+        }
+    }
+
+    /// Moves the selected tab of the given dock area into a new area, as
+    /// long as it is not the last one of its group.
+    fn tear(&mut self, pane: Pane) -> Option<Pane> {
+        let group = self.panes.get_mut(pane)?;
+
+        if group.len() <= 1 {
+            return None;
+        }
+
+        let panel = group.remove(group.active)?;
+
+        self.split(Axis::Vertical, pane, panel)
+    }
+}
+
+/// The tab group of a dock area.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group<T> {
+    panels: Vec<T>,
+    active: usize,
+}
+
+impl<T> Group<T> {
+    /// Creates a new [`Group`] with the given panel.
+    pub fn new(panel: T) -> Self {
+        Self {
+            panels: vec![panel],
+            active: 0,
+        }
+    }
+
+    /// Creates a new [`Group`] with the given panels and the index of the
+    /// selected one.
+    pub fn with_panels(panels: impl IntoIterator<Item = T>, active: usize) -> Self {
+        let panels: Vec<T> = panels.into_iter().collect();
+        let active = active.min(panels.len().saturating_sub(1));
+
+        Self { panels, active }
+    }
+
+    fn empty() -> Self {
+        Self {
+            panels: Vec::new(),
+            active: 0,
+        }
+    }
+
+    /// Returns the panels of the [`Group`].
+    pub fn panels(&self) -> &[T] {
+        &self.panels
+    }
+
+    /// Returns the mutable panels of the [`Group`].
+    pub fn panels_mut(&mut self) -> &mut [T] {
+        &mut self.panels
+    }
+
+    /// Returns the selected panel of the [`Group`].
+    pub fn active(&self) -> Option<&T> {
+        self.panels.get(self.active)
+    }
+
+    /// Returns the index of the selected panel of the [`Group`].
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Returns the amount of panels in the [`Group`].
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Returns `true` if the [`Group`] has no panels.
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// Selects the panel at the given index.
+    pub fn select(&mut self, index: usize) {
+        if index < self.panels.len() {
+            self.active = index;
+        }
+    }
+
+    /// Adds the given panel to the [`Group`] and selects it.
+    pub fn push(&mut self, panel: T) {
+        self.panels.push(panel);
+        self.active = self.panels.len() - 1;
+    }
+
+    /// Produces a new [`Group`] by applying the given function to each
+    /// panel.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Group<U> {
+        Group {
+            panels: self.panels.into_iter().map(f).collect(),
+            active: self.active,
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.panels.len() {
+            return None;
+        }
+
+        let panel = self.panels.remove(index);
+
+        if self.active > index || self.active >= self.panels.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+
+        Some(panel)
+    }
+
+    fn reorder(&mut self, reorder: Reorder) {
+        reorder.apply(&mut self.panels);
+        self.active = reorder.to.min(self.panels.len().saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_on_an_edge_tears_the_selected_tab_off() {
+        let mut state = State::new("explorer");
+        let pane = *state.panes.iter().next().unwrap().0;
+
+        state.push(pane, "editor");
+        state.perform(Event::Dragged(pane_grid::DragEvent::Dropped {
+            pane,
+            target: Target::Edge(Edge::Right),
+        }));
+
+        assert_eq!(state.panes.len(), 2);
+        assert_eq!(state.get(pane).and_then(Group::active), Some(&"explorer"));
+    }
+}
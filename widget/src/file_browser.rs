@@ -0,0 +1,1211 @@
+//! File browsers let users navigate the file system and pick some entries,
+//! embedded in your application instead of a dialog of the platform.
+//!
+//! A [`FileBrowser`] does not read directories by itself. Its [`State`]
+//! produces an [`Action::Read`] whenever it needs the contents of a
+//! directory, which you can [`list`] in the background—the `file_browser`
+//! module of `iced` offers a `read` task for this.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::file_browser;
+//!
+//! struct State {
+//!     browser: file_browser::State,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Browser(file_browser::Event),
+//!     Listed(Result<file_browser::Listing, file_browser::Error>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     file_browser(&state.browser, Message::Browser).into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Browser(event) => match state.browser.perform(event) {
+//!             Some(file_browser::Action::Read(directory)) => {
+//!                 // Read the directory in the background...
+//!                 let _listing = file_browser::list(&directory);
+//!             }
+//!             Some(file_browser::Action::Open(file)) => {
+//!                 // Open the file...
+//!             }
+//!             None => {}
+//!         },
+//!         Message::Listed(listing) => state.browser.load(listing),
+//!     }
+//! }
+//! ```
+use crate::breadcrumbs::{self, Breadcrumbs, Segment};
+use crate::button::{self, Button};
+use crate::calendar::Date;
+use crate::container::{self, Container};
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::{
+    Operation,
+    tree::{self, Tree},
+};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event as CoreEvent, Layout, Length, Pixels,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, Scrollable};
+use crate::{Grid, Row, Text};
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// An entry of a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The name of the [`Entry`].
+    pub name: String,
+    /// The full path of the [`Entry`].
+    pub path: PathBuf,
+    /// Whether the [`Entry`] is a directory.
+    pub is_directory: bool,
+    /// The size of the [`Entry`], in bytes.
+    pub size: u64,
+    /// The last time the [`Entry`] was modified, if known.
+    pub modified: Option<SystemTime>,
+}
+
+impl Entry {
+    /// Returns true if the [`Entry`] is hidden, by convention.
+    pub fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+
+    /// Returns the extension of the [`Entry`], if any.
+    pub fn extension(&self) -> Option<&str> {
+        self.path.extension()?.to_str()
+    }
+}
+
+/// The entries of a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Listing {
+    /// The directory that was read.
+    pub directory: PathBuf,
+    /// The entries of the directory, in no particular order.
+    pub entries: Vec<Entry>,
+}
+
+/// An error that occurred when reading a directory.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The directory could not be read.
+    #[error("the directory could not be read: {0}")]
+    Inaccessible(Arc<io::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Inaccessible(Arc::new(error))
+    }
+}
+
+/// Reads the entries of the given directory.
+///
+/// This blocks the current thread until the whole directory is read!
+pub fn list(directory: impl AsRef<Path>) -> Result<Listing, Error> {
+    let directory = directory.as_ref();
+    let mut entries = Vec::new();
+
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        // Broken links are listed as themselves
+        let Ok(metadata) = fs::metadata(&path).or_else(|_| entry.metadata()) else {
+            continue;
+        };
+
+        entries.push(Entry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            path,
+            is_directory: metadata.is_dir(),
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+        });
+    }
+
+    Ok(Listing {
+        directory: directory.to_path_buf(),
+        entries,
+    })
+}
+
+/// The way the entries of a [`FileBrowser`] are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum View {
+    /// A list of rows, with the details of every entry.
+    #[default]
+    List,
+    /// A grid of cells, with the name of every entry.
+    Grid,
+}
+
+/// A column the entries of a [`FileBrowser`] can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Column {
+    /// The name of the entries.
+    #[default]
+    Name,
+    /// The size of the entries.
+    Size,
+    /// The last modification time of the entries.
+    Modified,
+}
+
+/// The order of the entries of a [`FileBrowser`].
+///
+/// Directories are always listed before files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sort {
+    /// The [`Column`] the entries are sorted by.
+    pub column: Column,
+    /// Whether the entries are sorted in descending order.
+    pub is_descending: bool,
+}
+
+/// An interaction with a [`FileBrowser`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The given directory was navigated to.
+    Navigated(PathBuf),
+    /// The entry with the given path was clicked.
+    Clicked {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The keyboard modifiers pressed during the click.
+        modifiers: keyboard::Modifiers,
+    },
+    /// The entry with the given path was double-clicked.
+    Activated(PathBuf),
+    /// A [`Column`] header was clicked.
+    Sorted(Column),
+    /// The [`View`] was changed.
+    ViewChanged(View),
+    /// The directory was asked to be read again.
+    Refreshed,
+}
+
+/// Something a [`State`] needs from your application after an [`Event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// The given directory must be read and its [`Listing`] loaded.
+    Read(PathBuf),
+    /// The given file was opened.
+    Open(PathBuf),
+}
+
+/// The state of a [`FileBrowser`].
+#[derive(Debug, Clone)]
+pub struct State {
+    directory: PathBuf,
+    entries: Vec<Entry>,
+    error: Option<Error>,
+    is_loading: bool,
+    selection: BTreeSet<PathBuf>,
+    anchor: Option<PathBuf>,
+    sort: Sort,
+    view: View,
+    extensions: Vec<String>,
+    show_hidden: bool,
+    is_multiple: bool,
+}
+
+impl State {
+    /// Creates a new [`State`] in the given directory.
+    ///
+    /// The [`State`] is loading until the [`Listing`] of the directory is
+    /// [`load`](Self::load)ed.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            entries: Vec::new(),
+            error: None,
+            is_loading: true,
+            selection: BTreeSet::new(),
+            anchor: None,
+            sort: Sort::default(),
+            view: View::default(),
+            extensions: Vec::new(),
+            show_hidden: false,
+            is_multiple: true,
+        }
+    }
+
+    /// Returns the current directory of the [`State`].
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Loads the [`Listing`] of the current directory.
+    ///
+    /// Listings of other directories are ignored, since they are outdated.
+    pub fn load(&mut self, listing: Result<Listing, Error>) {
+        match listing {
+            Ok(listing) if listing.directory == self.directory => {
+                self.entries = listing.entries;
+                self.error = None;
+                self.is_loading = false;
+
+                let entries = &self.entries;
+
+                self.selection
+                    .retain(|path| entries.iter().any(|entry| &entry.path == path));
+            }
+            Ok(_) => {}
+            Err(error) => {
+                self.entries.clear();
+                self.selection.clear();
+                self.error = Some(error);
+                self.is_loading = false;
+            }
+        }
+    }
+
+    /// Applies the given [`Event`] to the [`State`], producing the
+    /// [`Action`] it needs, if any.
+    pub fn perform(&mut self, event: Event) -> Option<Action> {
+        match event {
+            Event::Navigated(directory) => Some(self.navigate(directory)),
+            Event::Clicked { path, modifiers } => {
+                self.select(path, modifiers);
+
+                None
+            }
+            Event::Activated(path) => {
+                let is_directory = self
+                    .entries
+                    .iter()
+                    .any(|entry| entry.path == path && entry.is_directory);
+
+                Some(if is_directory {
+                    self.navigate(path)
+                } else {
+                    Action::Open(path)
+                })
+            }
+            Event::Sorted(column) => {
+                self.sort = Sort {
+                    column,
+                    is_descending: self.sort.column == column && !self.sort.is_descending,
+                };
+
+                None
+            }
+            Event::ViewChanged(view) => {
+                self.view = view;
+
+                None
+            }
+            Event::Refreshed => {
+                self.is_loading = true;
+
+                Some(Action::Read(self.directory.clone()))
+            }
+        }
+    }
+
+    /// Returns the visible entries of the [`State`], filtered and sorted.
+    pub fn entries(&self) -> Vec<&Entry> {
+        let mut entries: Vec<&Entry> = self
+            .entries
+            .iter()
+            .filter(|entry| self.show_hidden || !entry.is_hidden())
+            .filter(|entry| {
+                entry.is_directory
+                    || self.extensions.is_empty()
+                    || entry.extension().is_some_and(|extension| {
+                        self.extensions
+                            .iter()
+                            .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+                    })
+            })
+            .collect();
+
+        entries.sort_by(|a, b| {
+            b.is_directory.cmp(&a.is_directory).then_with(|| {
+                let ordering = match self.sort.column {
+                    Column::Name => compare_names(&a.name, &b.name),
+                    Column::Size => a.size.cmp(&b.size),
+                    Column::Modified => a.modified.cmp(&b.modified),
+                }
+                .then_with(|| compare_names(&a.name, &b.name));
+
+                if self.sort.is_descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+        });
+
+        entries
+    }
+
+    /// Returns the paths of the selected entries.
+    pub fn selection(&self) -> impl Iterator<Item = &Path> {
+        self.selection.iter().map(PathBuf::as_path)
+    }
+
+    /// Returns true if the entry with the given path is selected.
+    pub fn is_selected(&self, path: &Path) -> bool {
+        self.selection.contains(path)
+    }
+
+    /// Returns the [`Error`] of the last read of the directory, if any.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns true if the directory is being read.
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    /// Returns the current [`View`] of the [`State`].
+    pub fn view(&self) -> View {
+        self.view
+    }
+
+    /// Returns the current [`Sort`] of the [`State`].
+    pub fn sort(&self) -> Sort {
+        self.sort
+    }
+
+    /// Only displays the files with one of the given extensions, besides
+    /// directories.
+    ///
+    /// An empty filter displays all the files.
+    pub fn set_filter(&mut self, extensions: impl IntoIterator<Item = impl Into<String>>) {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+
+        let visible: BTreeSet<PathBuf> = self
+            .entries()
+            .into_iter()
+            .map(|entry| entry.path.clone())
+            .collect();
+
+        self.selection.retain(|path| visible.contains(path));
+    }
+
+    /// Sets whether hidden entries are displayed.
+    pub fn set_show_hidden(&mut self, show_hidden: bool) {
+        self.show_hidden = show_hidden;
+    }
+
+    /// Sets whether more than one entry can be selected.
+    pub fn set_multiple(&mut self, is_multiple: bool) {
+        self.is_multiple = is_multiple;
+
+        if !is_multiple && self.selection.len() > 1 {
+            self.selection.clear();
+        }
+    }
+
+    fn navigate(&mut self, directory: PathBuf) -> Action {
+        self.directory = directory.clone();
+        self.entries.clear();
+        self.selection.clear();
+        self.anchor = None;
+        self.error = None;
+        self.is_loading = true;
+
+        Action::Read(directory)
+    }
+
+    fn select(&mut self, path: PathBuf, modifiers: keyboard::Modifiers) {
+        if !self.is_multiple || !(modifiers.shift() || modifiers.command()) {
+            self.selection.clear();
+            let _ = self.selection.insert(path.clone());
+            self.anchor = Some(path);
+
+            return;
+        }
+
+        if modifiers.shift()
+            && let Some(anchor) = &self.anchor
+        {
+            let entries = self.entries();
+            let position = |target: &Path| entries.iter().position(|entry| entry.path == target);
+
+            if let (Some(start), Some(end)) = (position(anchor), position(&path)) {
+                let range: Vec<PathBuf> = entries[start.min(end)..=start.max(end)]
+                    .iter()
+                    .map(|entry| entry.path.clone())
+                    .collect();
+
+                if !modifiers.command() {
+                    self.selection.clear();
+                }
+
+                self.selection.extend(range);
+            }
+
+            return;
+        }
+
+        if !self.selection.remove(&path) {
+            let _ = self.selection.insert(path.clone());
+        }
+
+        self.anchor = Some(path);
+    }
+}
+
+/// A file and directory browser with a breadcrumb path, a list or grid of
+/// entries, and sortable columns.
+///
+/// Clicking an entry selects it; holding shift selects a range, and holding
+/// the command key toggles it. Double-clicking a directory opens it.
+///
+/// A [`FileBrowser`] produces an [`Event`] for every interaction, which must
+/// be applied to its [`State`] with [`State::perform`].
+pub struct FileBrowser<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    state: &'a State,
+    on_event: Rc<dyn Fn(Event) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    text_size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme> FileBrowser<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`FileBrowser`] with the given [`State`] and a function
+    /// that produces a message for an [`Event`].
+    pub fn new(state: &'a State, on_event: impl Fn(Event) -> Message + 'a) -> Self {
+        Self {
+            state,
+            on_event: Rc::new(on_event),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the width of the [`FileBrowser`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`FileBrowser`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the [`FileBrowser`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the [`FileBrowser`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`FileBrowser`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FileBrowser<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(browser: FileBrowser<'a, Message, Theme>) -> Self {
+        let FileBrowser {
+            state,
+            on_event,
+            width,
+            height,
+            text_size,
+            class,
+        } = browser;
+
+        let class = Rc::new(class);
+        let label = |content: String| {
+            let label = Text::new(content).wrapping(text::Wrapping::None);
+
+            match text_size {
+                Some(size) => label.size(size),
+                None => label,
+            }
+        };
+
+        let toolbar = {
+            let up = flat_button(
+                label("↑".to_owned()),
+                state
+                    .directory
+                    .parent()
+                    .map(|parent| on_event(Event::Navigated(parent.to_path_buf()))),
+                false,
+                class.clone(),
+            );
+
+            let mut ancestors: Vec<&Path> = state.directory.ancestors().collect();
+            ancestors.reverse();
+
+            let last = ancestors.len().saturating_sub(1);
+
+            let path = Breadcrumbs::new(ancestors.into_iter().enumerate().map(|(i, ancestor)| {
+                let name = ancestor.file_name().map_or_else(
+                    || ancestor.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                );
+
+                Segment::new(name).on_press_maybe(
+                    (i < last).then(|| on_event(Event::Navigated(ancestor.to_path_buf()))),
+                )
+            }));
+
+            let path = match text_size {
+                Some(size) => path.text_size(size),
+                None => path,
+            };
+
+            let toggle = |name: &str, view: View| {
+                flat_button(
+                    label(name.to_owned()),
+                    Some(on_event(Event::ViewChanged(view))),
+                    state.view == view,
+                    class.clone(),
+                )
+            };
+
+            Row::new()
+                .push(up)
+                .push(path)
+                .push(toggle("List", View::List))
+                .push(toggle("Grid", View::Grid))
+                .push(flat_button(
+                    label("Reload".to_owned()),
+                    Some(on_event(Event::Refreshed)),
+                    false,
+                    class.clone(),
+                ))
+                .spacing(4)
+                .align_y(alignment::Vertical::Center)
+        };
+
+        let body: Element<'a, Message, Theme, Renderer> = if let Some(error) = &state.error {
+            status(label(error.to_string()), class.clone())
+        } else if state.is_loading {
+            status(label("Loading…".to_owned()), class.clone())
+        } else {
+            let entries = state.entries();
+
+            if entries.is_empty() {
+                status(label("This directory is empty".to_owned()), class.clone())
+            } else {
+                let items = entries.into_iter().map(|entry| {
+                    let name = if entry.is_directory {
+                        format!("{}/", entry.name)
+                    } else {
+                        entry.name.clone()
+                    };
+
+                    let content: Element<'a, Message, Theme, Renderer> = match state.view {
+                        View::List => Row::new()
+                            .push(label(name).width(Length::Fill))
+                            .push(
+                                label(if entry.is_directory {
+                                    String::new()
+                                } else {
+                                    format_size(entry.size)
+                                })
+                                .width(SIZE_WIDTH)
+                                .align_x(text::Alignment::Right),
+                            )
+                            .push(
+                                label(entry.modified.map(format_date).unwrap_or_default())
+                                    .width(MODIFIED_WIDTH)
+                                    .align_x(text::Alignment::Right),
+                            )
+                            .spacing(8)
+                            .into(),
+                        View::Grid => Container::new(
+                            label(name)
+                                .wrapping(text::Wrapping::WordOrGlyph)
+                                .align_x(text::Alignment::Center),
+                        )
+                        .center(Length::Fill)
+                        .into(),
+                    };
+
+                    let is_selected = state.is_selected(&entry.path);
+                    let class = class.clone();
+
+                    let content = Container::new(content)
+                        .width(Length::Fill)
+                        .padding([4, 8])
+                        .style(move |theme| {
+                            let style = <Theme as Catalog>::style(theme, &class);
+
+                            container::Style {
+                                background: is_selected.then_some(style.selected),
+                                text_color: Some(if is_selected {
+                                    style.selected_text_color
+                                } else {
+                                    style.text_color
+                                }),
+                                border: style.item_border,
+                                ..container::Style::default()
+                            }
+                        });
+
+                    let path = entry.path.clone();
+                    let on_click = on_event.clone();
+
+                    Element::from(Item {
+                        content: content.into(),
+                        on_click: Box::new(move |modifiers| {
+                            on_click(Event::Clicked {
+                                path: path.clone(),
+                                modifiers,
+                            })
+                        }),
+                        on_activate: on_event(Event::Activated(entry.path.clone())),
+                    })
+                });
+
+                let items: Element<'a, Message, Theme, Renderer> = match state.view {
+                    View::List => crate::Column::with_children(items).spacing(1).into(),
+                    View::Grid => Grid::with_children(items).fluid(120).spacing(4).into(),
+                };
+
+                let list = Scrollable::new(items).height(Length::Fill);
+
+                if state.view == View::List {
+                    let header = |name: &str, column: Column| {
+                        let arrow = if state.sort.column != column {
+                            ""
+                        } else if state.sort.is_descending {
+                            " ▼"
+                        } else {
+                            " ▲"
+                        };
+
+                        flat_button(
+                            label(format!("{name}{arrow}")),
+                            Some(on_event(Event::Sorted(column))),
+                            false,
+                            class.clone(),
+                        )
+                        .padding(0)
+                    };
+
+                    crate::Column::new()
+                        .push(
+                            Row::new()
+                                .push(header("Name", Column::Name).width(Length::Fill))
+                                .push(
+                                    Container::new(header("Size", Column::Size))
+                                        .align_right(SIZE_WIDTH),
+                                )
+                                .push(
+                                    Container::new(header("Modified", Column::Modified))
+                                        .align_right(MODIFIED_WIDTH),
+                                )
+                                .spacing(8)
+                                .padding([4, 8]),
+                        )
+                        .push(list)
+                        .into()
+                } else {
+                    list.into()
+                }
+            }
+        };
+
+        Container::new(crate::Column::new().push(toolbar).push(body).spacing(4))
+            .width(width)
+            .height(height)
+            .padding(4)
+            .style(move |theme| {
+                let style = <Theme as Catalog>::style(theme, &class);
+
+                container::Style {
+                    background: style.background,
+                    border: style.border,
+                    text_color: Some(style.text_color),
+                    ..container::Style::default()
+                }
+            })
+            .into()
+    }
+}
+
+/// The width of the size column of a [`FileBrowser`].
+const SIZE_WIDTH: f32 = 80.0;
+
+/// The width of the modified column of a [`FileBrowser`].
+const MODIFIED_WIDTH: f32 = 100.0;
+
+/// Produces the centered message displayed instead of the entries of a
+/// [`FileBrowser`].
+fn status<'a, Message, Theme, Renderer>(
+    message: Text<'a, Theme, Renderer>,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    Container::new(message)
+        .center(Length::Fill)
+        .style(move |theme| container::Style {
+            text_color: Some(<Theme as Catalog>::style(theme, &class).placeholder_color),
+            ..container::Style::default()
+        })
+        .into()
+}
+
+/// Produces a flat button of a [`FileBrowser`], used in its toolbar and
+/// headers.
+fn flat_button<'a, Message, Theme, Renderer>(
+    label: Text<'a, Theme, Renderer>,
+    on_press: Option<Message>,
+    is_active: bool,
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    Button::new(label)
+        .padding([2, 6])
+        .on_press_maybe(on_press)
+        .style(move |theme, status| {
+            let style = <Theme as Catalog>::style(theme, &class);
+
+            button::Style {
+                background: match status {
+                    _ if is_active => Some(style.selected),
+                    button::Status::Hovered | button::Status::Pressed => style.hovered,
+                    button::Status::Active | button::Status::Disabled => None,
+                },
+                text_color: match status {
+                    _ if is_active => style.selected_text_color,
+                    button::Status::Disabled => style.placeholder_color,
+                    _ => style.text_color,
+                },
+                border: style.item_border,
+                ..button::Style::default()
+            }
+        })
+}
+
+/// An entry of a [`FileBrowser`] that can be clicked with some keyboard
+/// modifiers and double-clicked.
+struct Item<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_click: Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>,
+    on_activate: Message,
+}
+
+#[derive(Debug, Default)]
+struct ItemState {
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Item<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<ItemState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ItemState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &CoreEvent,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<ItemState>();
+
+        match event {
+            CoreEvent::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            CoreEvent::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if !shell.is_event_captured() =>
+            {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if click.kind() == mouse::click::Kind::Double {
+                    shell.publish(self.on_activate.clone());
+                } else {
+                    shell.publish((self.on_click)(state.modifiers));
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Item<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(item: Item<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(item)
+    }
+}
+
+/// Compares two names like humans do, ignoring their case.
+fn compare_names(a: &str, b: &str) -> Ordering {
+    a.to_lowercase()
+        .cmp(&b.to_lowercase())
+        .then_with(|| a.cmp(b))
+}
+
+/// Formats the given size in bytes with the closest binary unit.
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["KB", "MB", "GB", "TB", "PB"];
+
+    if size < 1024 {
+        return format!("{size} B");
+    }
+
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Formats the date of the given time, in UTC.
+fn format_date(time: SystemTime) -> String {
+    let days = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default();
+
+    Date::from_days(days as i64).to_string()
+}
+
+/// The appearance of a file browser.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the file browser, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the file browser.
+    pub border: Border,
+    /// The [`Color`] of the text of the entries.
+    pub text_color: Color,
+    /// The [`Color`] of the messages displayed instead of the entries.
+    pub placeholder_color: Color,
+    /// The [`Border`] of every entry and button.
+    pub item_border: Border,
+    /// The [`Background`] of the hovered buttons, if any.
+    pub hovered: Option<Background>,
+    /// The [`Background`] of the selected entries.
+    pub selected: Background,
+    /// The [`Color`] of the text of the selected entries.
+    pub selected_text_color: Color,
+}
+
+/// The theme catalog of a [`FileBrowser`].
+pub trait Catalog:
+    button::Catalog
+    + container::Catalog
+    + scrollable::Catalog
+    + breadcrumbs::Catalog
+    + crate::text::Catalog
+{
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`FileBrowser`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`FileBrowser`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            radius: 2.0.into(),
+        },
+        text_color: palette.background.base.text,
+        placeholder_color: palette.background.strong.color,
+        item_border: Border {
+            radius: 2.0.into(),
+            ..Border::default()
+        },
+        hovered: Some(palette.background.weak.color.into()),
+        selected: palette.primary.weak.color.into(),
+        selected_text_color: palette.primary.weak.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, is_directory: bool, size: u64) -> Entry {
+        Entry {
+            name: name.to_owned(),
+            path: PathBuf::from("/files").join(name),
+            is_directory,
+            size,
+            modified: None,
+        }
+    }
+
+    #[test]
+    fn entries_are_filtered_sorted_and_selected() {
+        let mut state = State::new("/files");
+
+        state.load(Ok(Listing {
+            directory: PathBuf::from("/files"),
+            entries: vec![
+                entry("b.png", false, 30),
+                entry("notes.txt", false, 10),
+                entry("Photos", true, 0),
+                entry(".cache", true, 0),
+                entry("a.PNG", false, 20),
+            ],
+        }));
+
+        state.set_filter(["png"]);
+
+        let names = |state: &State| -> Vec<String> {
+            state
+                .entries()
+                .into_iter()
+                .map(|entry| entry.name.clone())
+                .collect()
+        };
+
+        assert_eq!(names(&state), ["Photos", "a.PNG", "b.png"]);
+
+        assert_eq!(state.perform(Event::Sorted(Column::Size)), None);
+        assert_eq!(state.perform(Event::Sorted(Column::Size)), None);
+        assert_eq!(names(&state), ["Photos", "b.png", "a.PNG"]);
+
+        let click = |name: &str, modifiers| Event::Clicked {
+            path: PathBuf::from("/files").join(name),
+            modifiers,
+        };
+
+        let _ = state.perform(click("Photos", keyboard::Modifiers::empty()));
+        let _ = state.perform(click("a.PNG", keyboard::Modifiers::SHIFT));
+        assert_eq!(state.selection().count(), 3);
+
+        assert_eq!(
+            state.perform(Event::Activated(PathBuf::from("/files/Photos"))),
+            Some(Action::Read(PathBuf::from("/files/Photos")))
+        );
+        assert!(state.is_loading());
+        assert_eq!(state.selection().count(), 0);
+    }
+}
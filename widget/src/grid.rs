@@ -1,4 +1,8 @@
 //! Distribute content on a grid.
+pub mod template;
+
+pub use template::{Cell, Template, Track, cell};
+
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -14,7 +18,7 @@ pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     columns: Constraint,
     width: Option<Pixels>,
     height: Sizing,
-    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    pub(crate) children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
 enum Constraint {
@@ -0,0 +1,716 @@
+//! Lay out content on explicit rows and columns.
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A container that lays out its contents on explicit [`Track`]s of rows
+/// and columns.
+///
+/// Contents can be placed at specific cells, span multiple tracks, and be
+/// aligned inside their own area. Contents without a position fill the
+/// first free cells, row by row.
+pub struct Template<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    auto_rows: Track,
+    column_spacing: f32,
+    row_spacing: f32,
+    width: Length,
+    height: Length,
+    align_x: Alignment,
+    align_y: Alignment,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The size of a row or a column of a [`Template`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Track {
+    /// The track has a fixed size in pixels.
+    Fixed(f32),
+    /// The track takes a portion of the space left by the other tracks,
+    /// relative to the other fractions.
+    ///
+    /// A fraction fits its content when the available space is unbounded.
+    Fraction(u16),
+    /// The track fits the largest content placed only on it.
+    Auto,
+}
+
+impl From<f32> for Track {
+    fn from(size: f32) -> Self {
+        Self::Fixed(size)
+    }
+}
+
+impl From<Pixels> for Track {
+    fn from(pixels: Pixels) -> Self {
+        Self::Fixed(pixels.0)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Template<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Template`] with the given column [`Track`]s.
+    pub fn new(columns: impl IntoIterator<Item = Track>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: Vec::new(),
+            auto_rows: Track::Auto,
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the row [`Track`]s of the [`Template`].
+    ///
+    /// Any rows beyond these will use the [`Track`] set with
+    /// [`auto_rows`](Self::auto_rows).
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Track>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`Track`] of the rows created implicitly by the contents of
+    /// the [`Template`].
+    ///
+    /// By default, implicit rows are [`Track::Auto`].
+    pub fn auto_rows(mut self, track: Track) -> Self {
+        self.auto_rows = track;
+        self
+    }
+
+    /// Sets the spacing _between_ both the rows and the columns of the
+    /// [`Template`].
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.row_spacing(amount).column_spacing(amount)
+    }
+
+    /// Sets the spacing _between_ the columns of the [`Template`].
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the spacing _between_ the rows of the [`Template`].
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Template`].
+    ///
+    /// By default, a [`Template`] will [`Fill`] its parent.
+    ///
+    /// [`Fill`]: Length::Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Template`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents inside their
+    /// cells.
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = Alignment::from(alignment.into());
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents inside their
+    /// cells.
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = Alignment::from(alignment.into());
+        self
+    }
+
+    /// Adds an [`Element`] to the first free cell of the [`Template`].
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.place(Cell::new(child))
+    }
+
+    /// Adds a [`Cell`] to the [`Template`].
+    pub fn place(mut self, cell: Cell<'a, Message, Theme, Renderer>) -> Self {
+        self.placements.push(cell.placement);
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Extends the [`Template`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+}
+
+/// Some content placed in a [`Template`].
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] with the given content, spanning a single
+    /// track in each direction.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            placement: Placement {
+                position: None,
+                rows: 1,
+                columns: 1,
+                align_x: None,
+                align_y: None,
+            },
+        }
+    }
+
+    /// Places the [`Cell`] at the given row and column, starting from zero.
+    ///
+    /// By default, a [`Cell`] is placed in the first free cell of the
+    /// [`Template`].
+    pub fn at(mut self, row: usize, column: usize) -> Self {
+        self.placement.position = Some((row, column));
+        self
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Cell`].
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.placement.rows = rows.max(1);
+        self.placement.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`Cell`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.placement.align_x = Some(Alignment::from(alignment.into()));
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`Cell`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.placement.align_y = Some(Alignment::from(alignment.into()));
+        self
+    }
+
+    /// Centers the content of the [`Cell`] in both directions.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+}
+
+/// Creates a new [`Cell`] with the given content.
+pub fn cell<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Cell<'a, Message, Theme, Renderer> {
+    Cell::new(content)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Placement {
+    position: Option<(usize, usize)>,
+    rows: usize,
+    columns: usize,
+    align_x: Option<Alignment>,
+    align_y: Option<Alignment>,
+}
+
+/// The tracks covered by a [`Placement`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Area {
+    row: usize,
+    column: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Template<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let available = limits.max();
+
+        let (areas, columns) = place(&self.placements, self.columns.len());
+        let rows = areas
+            .iter()
+            .map(|area| area.row + area.rows)
+            .fold(self.rows.len(), usize::max);
+
+        let measure = layout::Limits::new(Size::ZERO, available);
+        let mut content = vec![0.0f32; columns];
+
+        for ((child, tree), area) in self.children.iter_mut().zip(&mut tree.children).zip(&areas) {
+            let track = track(&self.columns, Track::Auto, area.column);
+
+            if area.columns == 1 && fits_content(track, available.width) {
+                let node = child.as_widget_mut().layout(tree, renderer, &measure);
+
+                content[area.column] = content[area.column].max(node.size().width);
+            }
+        }
+
+        let widths = resolve(
+            &self.columns,
+            Track::Auto,
+            &content,
+            available.width,
+            self.column_spacing,
+        );
+
+        let mut content = vec![0.0f32; rows];
+
+        for ((child, tree), area) in self.children.iter_mut().zip(&mut tree.children).zip(&areas) {
+            let track = track(&self.rows, self.auto_rows, area.row);
+
+            if area.rows == 1 && fits_content(track, available.height) {
+                let width = span(&widths, area.column, area.columns, self.column_spacing);
+
+                let node = child.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(width, available.height)),
+                );
+
+                content[area.row] = content[area.row].max(node.size().height);
+            }
+        }
+
+        let heights = resolve(
+            &self.rows,
+            self.auto_rows,
+            &content,
+            available.height,
+            self.row_spacing,
+        );
+
+        let x = offsets(&widths, self.column_spacing);
+        let y = offsets(&heights, self.row_spacing);
+
+        let nodes = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(areas.iter().zip(&self.placements))
+            .map(|((child, tree), (area, placement))| {
+                let size = Size::new(
+                    span(&widths, area.column, area.columns, self.column_spacing),
+                    span(&heights, area.row, area.rows, self.row_spacing),
+                );
+
+                child
+                    .as_widget_mut()
+                    .layout(tree, renderer, &layout::Limits::new(Size::ZERO, size))
+                    .move_to((x[area.column], y[area.row]))
+                    .align(
+                        placement.align_x.unwrap_or(self.align_x),
+                        placement.align_y.unwrap_or(self.align_y),
+                        size,
+                    )
+            })
+            .collect();
+
+        let size = Size::new(
+            span(&widths, 0, columns, self.column_spacing),
+            span(&heights, 0, rows, self.row_spacing),
+        );
+
+        layout::Node::with_children(limits.resolve(self.width, self.height, size), nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(viewport) = layout.bounds().intersection(viewport) {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .filter(|(_, layout)| layout.bounds().intersects(&viewport))
+            {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Template<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(template: Template<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(template)
+    }
+}
+
+/// Places every [`Placement`] in the grid, returning their [`Area`]s and
+/// the total amount of columns.
+///
+/// Positioned placements are placed first; the rest take the first free
+/// cells, row by row.
+fn place(placements: &[Placement], columns: usize) -> (Vec<Area>, usize) {
+    let columns = placements
+        .iter()
+        .filter_map(|placement| {
+            placement
+                .position
+                .map(|(_, column)| column + placement.columns)
+        })
+        .fold(columns.max(1), usize::max);
+
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut areas = vec![Area::default(); placements.len()];
+
+    let occupy = |occupied: &mut Vec<Vec<bool>>, area: Area| {
+        if occupied.len() < area.row + area.rows {
+            occupied.resize(area.row + area.rows, vec![false; columns]);
+        }
+
+        for row in &mut occupied[area.row..area.row + area.rows] {
+            row[area.column..area.column + area.columns].fill(true);
+        }
+    };
+
+    for (placement, area) in placements.iter().zip(&mut areas) {
+        if let Some((row, column)) = placement.position {
+            *area = Area {
+                row,
+                column,
+                rows: placement.rows,
+                columns: placement.columns,
+            };
+
+            occupy(&mut occupied, *area);
+        }
+    }
+
+    let is_free = |occupied: &[Vec<bool>], area: Area| {
+        (area.row..area.row + area.rows).all(|row| {
+            occupied
+                .get(row)
+                .is_none_or(|row| !row[area.column..area.column + area.columns].contains(&true))
+        })
+    };
+
+    let mut cursor = (0, 0);
+
+    for (placement, area) in placements.iter().zip(&mut areas) {
+        if placement.position.is_some() {
+            continue;
+        }
+
+        let spanned = placement.columns.min(columns);
+
+        loop {
+            if cursor.1 + spanned > columns {
+                cursor = (cursor.0 + 1, 0);
+                continue;
+            }
+
+            let candidate = Area {
+                row: cursor.0,
+                column: cursor.1,
+                rows: placement.rows,
+                columns: spanned,
+            };
+
+            if is_free(&occupied, candidate) {
+                *area = candidate;
+                occupy(&mut occupied, candidate);
+
+                cursor.1 += spanned;
+                break;
+            }
+
+            cursor.1 += 1;
+        }
+    }
+
+    (areas, columns)
+}
+
+fn track(tracks: &[Track], implicit: Track, index: usize) -> Track {
+    tracks.get(index).copied().unwrap_or(implicit)
+}
+
+fn fits_content(track: Track, available: f32) -> bool {
+    match track {
+        Track::Fixed(_) => false,
+        Track::Fraction(_) => available.is_infinite(),
+        Track::Auto => true,
+    }
+}
+
+/// Resolves the size of every track, given the size of their content.
+fn resolve(
+    tracks: &[Track],
+    implicit: Track,
+    content: &[f32],
+    available: f32,
+    gap: f32,
+) -> Vec<f32> {
+    let tracks: Vec<Track> = (0..content.len())
+        .map(|index| track(tracks, implicit, index))
+        .collect();
+
+    let fractions: u32 = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fraction(fraction) => u32::from(*fraction),
+            _ => 0,
+        })
+        .sum();
+
+    let used = tracks
+        .iter()
+        .zip(content)
+        .map(|(track, content)| match track {
+            Track::Fixed(size) => *size,
+            Track::Fraction(_) => 0.0,
+            Track::Auto => *content,
+        })
+        .sum::<f32>()
+        + gap * content.len().saturating_sub(1) as f32;
+
+    let remaining = (available - used).max(0.0);
+
+    tracks
+        .iter()
+        .zip(content)
+        .map(|(track, content)| match track {
+            Track::Fixed(size) => *size,
+            Track::Fraction(_) if available.is_infinite() => *content,
+            Track::Fraction(fraction) => remaining * f32::from(*fraction) / fractions.max(1) as f32,
+            Track::Auto => *content,
+        })
+        .collect()
+}
+
+fn offsets(sizes: &[f32], gap: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size + gap;
+
+            Some(start)
+        })
+        .collect()
+}
+
+fn span(sizes: &[f32], start: usize, count: usize, gap: f32) -> f32 {
+    sizes[start..start + count].iter().sum::<f32>() + gap * count.saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Area, Placement, Track, place, resolve};
+
+    fn placement(position: Option<(usize, usize)>, rows: usize, columns: usize) -> Placement {
+        Placement {
+            position,
+            rows,
+            columns,
+            align_x: None,
+            align_y: None,
+        }
+    }
+
+    #[test]
+    fn cells_flow_around_positioned_ones() {
+        let (areas, columns) = place(
+            &[
+                placement(None, 1, 1),
+                placement(Some((0, 1)), 2, 2),
+                placement(None, 1, 1),
+                placement(None, 1, 2),
+            ],
+            3,
+        );
+
+        assert_eq!(columns, 3);
+        assert_eq!(
+            areas,
+            [
+                Area {
+                    row: 0,
+                    column: 0,
+                    rows: 1,
+                    columns: 1
+                },
+                Area {
+                    row: 0,
+                    column: 1,
+                    rows: 2,
+                    columns: 2
+                },
+                Area {
+                    row: 1,
+                    column: 0,
+                    rows: 1,
+                    columns: 1
+                },
+                Area {
+                    row: 2,
+                    column: 0,
+                    rows: 1,
+                    columns: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fractions_share_the_remaining_space() {
+        let sizes = resolve(
+            &[Track::Fixed(100.0), Track::Fraction(1), Track::Fraction(2)],
+            Track::Auto,
+            &[0.0, 0.0, 0.0, 50.0],
+            480.0,
+            10.0,
+        );
+
+        assert_eq!(sizes, [100.0, 100.0, 200.0, 50.0]);
+    }
+}
@@ -1,34 +1,63 @@
 //! Helper functions to create pure widgets.
+use crate::autocomplete::{self, Autocomplete};
+use crate::breadcrumbs::{self, Breadcrumbs};
 use crate::button::{self, Button};
+use crate::calendar::{self, Calendar};
 use crate::checkbox::{self, Checkbox};
+use crate::collapsible::{self, Collapsible};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::theme;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Size, Widget};
+use crate::date_picker::{self, Date, DatePicker};
+use crate::dock::{self, Dock};
+use crate::file_browser::{self, FileBrowser};
 use crate::float::{self, Float};
+use crate::grid::{self, Grid};
 use crate::keyed;
+use crate::masked_input::{self, MaskedInput};
+use crate::menu_bar::{self};
+use crate::modal::{self, Modal};
+use crate::number_input::{self, NumberInput};
 use crate::overlay;
 use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
+use crate::popover::{self, Popover};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::range_slider::{self, RangeSlider};
+use crate::reorderable_column::{self, ReorderableColumn};
+use crate::responsive::{self, Responsive};
+use crate::rich_editor::{self, RichEditor};
+use crate::row::{self, Row};
 use crate::scrollable::{self, Scrollable};
+use crate::segmented::{self, Segmented};
 use crate::slider::{self, Slider};
+use crate::sparkline::{self, Sparkline};
+use crate::steps::{self, Steps};
+use crate::sticky::{self, Sticky};
+use crate::tabs::{self, Tabs};
+use crate::tags_input::{self, TagsInput};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::timeline::{self, Timeline};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer};
+use crate::{Column, MouseArea, Pin, Sensor, Space, Stack, Themer, VirtualList};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 
+pub use crate::menu_bar::menu_bar;
 pub use crate::table::table;
+pub use crate::tree::tree;
 
 /// Creates a [`Column`] with the given children.
 ///
@@ -543,6 +572,23 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new [`Wrapping`] row with the given children.
+///
+/// The children are laid out horizontally and wrapped to new lines
+/// when they run out of space; like a tag cloud.
+///
+/// This is a shorthand for [`Row::wrap`].
+///
+/// [`Wrapping`]: crate::row::Wrapping
+pub fn wrap<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> row::Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Row::with_children(children).wrap()
+}
+
 /// Creates a new [`Grid`] from an iterator.
 pub fn grid<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
@@ -553,6 +599,22 @@ where
     Grid::with_children(children)
 }
 
+/// Creates a new [`Template`] grid with the given column tracks.
+///
+/// Rows, spans, and per-cell alignment can be configured with the
+/// [`Template`] and [`Cell`] builders.
+///
+/// [`Template`]: crate::grid::Template
+/// [`Cell`]: crate::grid::Cell
+pub fn grid_template<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = grid::Track>,
+) -> grid::Template<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    grid::Template::new(columns)
+}
+
 /// Creates a new [`Stack`] with the given children.
 ///
 /// [`Stack`]: crate::Stack
@@ -565,6 +627,45 @@ where
     Stack::with_children(children)
 }
 
+/// Creates a new [`keyed::Row`] from an iterator of elements.
+///
+/// Keyed rows distribute content horizontally while keeping continuity.
+pub fn keyed_row<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> keyed::Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: core::Renderer,
+{
+    keyed::Row::with_children(children)
+}
+
+/// Creates a new [`keyed::Grid`] from an iterator of elements.
+///
+/// Keyed grids distribute content on a grid while keeping continuity.
+pub fn keyed_grid<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> keyed::Grid<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: core::Renderer,
+{
+    keyed::Grid::with_children(children)
+}
+
+/// Creates a new [`keyed::Stack`] from an iterator of elements.
+///
+/// Keyed stacks display content on top of each other while keeping continuity.
+pub fn keyed_stack<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> keyed::Stack<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: core::Renderer,
+{
+    keyed::Stack::with_children(children)
+}
+
 /// Wraps the given widget and captures any mouse button presses inside the bounds of
 /// the widget—effectively making it _opaque_.
 ///
@@ -1056,6 +1157,55 @@ where
     Button::new(content)
 }
 
+/// Creates a new [`Collapsible`] with the given header and content.
+///
+/// Collapsibles display a header that expands and collapses the content
+/// when pressed.
+pub fn collapsible<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Collapsible<'a, Message, Theme, Renderer>
+where
+    Theme: collapsible::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Collapsible::new(header, content)
+}
+
+/// Creates a new [`Popover`] displaying some content next to the given
+/// anchor element.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, popover};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle,
+///     Close,
+/// }
+///
+/// fn view(is_open: bool) -> Element<'static, Message> {
+///     popover(button("Help").on_press(Message::Toggle), "Some help!")
+///         .open(is_open)
+///         .placement(popover::Placement::End)
+///         .on_dismiss(Message::Close)
+///         .into()
+/// }
+/// ```
+pub fn popover<'a, Message, Theme, Renderer>(
+    anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: popover::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Popover::new(anchor, content)
+}
+
 /// Creates a new [`Tooltip`] for the provided content with the given
 /// [`Element`] and [`tooltip::Position`].
 ///
@@ -1214,6 +1364,49 @@ pub fn span<'a, Link, Font>(text: impl text::IntoFragment<'a>) -> text::Span<'a,
 #[doc(inline)]
 pub use crate::markdown::view as markdown;
 
+/// Creates a new [`Terminal`](crate::Terminal) displaying the given
+/// [`terminal::State`](crate::terminal::State).
+#[cfg(feature = "terminal")]
+pub fn terminal<'a, Message, Theme, Renderer>(
+    state: &'a crate::terminal::State,
+) -> crate::Terminal<'a, Message, Theme, Renderer>
+where
+    Theme: crate::terminal::Catalog + 'a,
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    crate::Terminal::new(state)
+}
+
+/// Creates a new [`VideoPlayer`](crate::VideoPlayer) displaying the given
+/// [`Video`](crate::video::Video).
+#[cfg(feature = "video")]
+pub fn video_player<'a, Message>(
+    video: &'a crate::video::Video,
+) -> crate::VideoPlayer<'a, Message> {
+    crate::VideoPlayer::new(video)
+}
+
+/// Creates a new [`WebView`](crate::WebView) reserving the space of the web
+/// view with the given [`Id`](crate::web_view::Id).
+#[cfg(feature = "web_view")]
+pub fn web_view(id: crate::web_view::Id) -> crate::WebView {
+    crate::WebView::new(id)
+}
+
+/// Creates a new [`Map`](crate::Map) displaying the given
+/// [`Tiles`](crate::map::Tiles) in the given
+/// [`Viewport`](crate::map::Viewport).
+#[cfg(feature = "map")]
+pub fn map<'a, Message, Theme>(
+    tiles: &'a crate::map::Tiles,
+    viewport: crate::map::Viewport,
+) -> crate::Map<'a, Message, Theme>
+where
+    Theme: crate::map::Catalog,
+{
+    crate::Map::new(tiles, viewport)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// # Example
@@ -1422,6 +1615,36 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`MaskedInput`] with the given placeholder, mask, and
+/// current value.
+///
+/// Learn how to write a mask in the documentation of [`masked_input::Mask`].
+pub fn masked_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    mask: impl Into<masked_input::Mask>,
+    value: &str,
+) -> MaskedInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text_input::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    MaskedInput::new(placeholder, mask, value)
+}
+
+/// Creates a new [`TagsInput`] with the given tags and the function that
+/// produces a message when a tag is added.
+pub fn tags_input<'a, Message, Theme, Renderer>(
+    tags: &'a [String],
+    on_add: impl Fn(String) -> Message + 'a,
+) -> TagsInput<'a, Message, Theme, Renderer>
+where
+    Theme: tags_input::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TagsInput::new(tags, on_add)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// Text editors display a multi-line text input for text editing.
@@ -1468,6 +1691,51 @@ where
     TextEditor::new(content)
 }
 
+/// Creates a new [`RichEditor`].
+///
+/// Rich editors display a multi-line text input with bold, italic,
+/// colored, linked, and code spans.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::rich_editor;
+///
+/// struct State {
+///    content: rich_editor::Content,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Edit(rich_editor::Action)
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     rich_editor(&state.content)
+///         .on_action(Message::Edit)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Edit(action) => {
+///             state.content.perform(action);
+///         }
+///     }
+/// }
+/// ```
+pub fn rich_editor<'a, Message, Theme, Renderer>(
+    content: &'a rich_editor::Content<Renderer>,
+) -> RichEditor<'a, Message, Theme, Renderer>
+where
+    Theme: text_editor::Catalog + 'a,
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    RichEditor::new(content)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// Sliders let users set a value by moving an indicator.
@@ -1513,6 +1781,102 @@ where
     Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`Sparkline`] of the given values.
+///
+/// Sparklines are small line plots, sized like text.
+pub fn sparkline<'a, Theme>(values: &'a [f32]) -> Sparkline<'a, Theme>
+where
+    Theme: sparkline::Catalog + 'a,
+{
+    Sparkline::new(values)
+}
+
+/// Creates a new [`Timeline`] with the given rows.
+///
+/// Timelines display bars spanning ranges of time, which can be moved and
+/// resized.
+pub fn timeline<'a, Message, Theme, Renderer>(
+    rows: impl IntoIterator<Item = timeline::Row>,
+) -> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: timeline::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Timeline::with_rows(rows)
+}
+
+/// Creates a new [`RangeSlider`].
+///
+/// Range sliders let users select a range of values by moving two indicators.
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: range_slider::Catalog + 'a,
+{
+    RangeSlider::new(range, values, on_change)
+}
+
+/// Creates a new [`Knob`](crate::Knob).
+///
+/// Knobs let users set a value by turning a dial.
+#[cfg(feature = "canvas")]
+pub fn knob<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> crate::Knob<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: crate::knob::Catalog + 'a,
+{
+    crate::Knob::new(range, value, on_change)
+}
+
+/// Creates a new indeterminate [`Spinner`](crate::Spinner).
+///
+/// Spinners show that an operation is in progress.
+#[cfg(feature = "canvas")]
+pub fn spinner<'a, Theme>() -> crate::Spinner<'a, Theme>
+where
+    Theme: crate::spinner::Catalog + 'a,
+{
+    crate::Spinner::new()
+}
+
+/// Creates a new [`Chart`](crate::Chart) with the given series.
+///
+/// Series can be created with [`chart::line`](crate::chart::line),
+/// [`chart::bar`](crate::chart::bar), and [`chart::area`](crate::chart::area).
+#[cfg(feature = "canvas")]
+pub fn chart<'a, Theme>(
+    series: impl IntoIterator<Item = crate::chart::Series<'a>>,
+) -> crate::Chart<'a, Theme>
+where
+    Theme: crate::chart::Catalog + 'a,
+{
+    crate::Chart::new(series)
+}
+
+/// Creates a new [`NumberInput`] with the given value and the function
+/// that produces a message when the value changes.
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + std::fmt::Display + From<u8>,
+    Theme: number_input::Catalog,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// Sliders let users set a value by moving an indicator.
@@ -1710,6 +2074,22 @@ where
     ComboBox::new(state, placeholder, selection, on_selected)
 }
 
+/// Creates a new [`Autocomplete`] with the given placeholder, its current
+/// value, and the function that produces a message when some text is typed
+/// into it.
+pub fn autocomplete<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> Autocomplete<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: autocomplete::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Autocomplete::new(placeholder, value, on_input)
+}
+
 /// Creates some empty [`Space`] with no size.
 ///
 /// This is considered the "identity" widget. It will take
@@ -1809,6 +2189,31 @@ where
     crate::Svg::new(handle)
 }
 
+/// Creates a new [`NinePatch`] widget that stretches the given asset without
+/// distorting the borders described by the given insets.
+///
+/// [`NinePatch`]: crate::NinePatch
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{image, nine_patch};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     nine_patch(image::Handle::from_path("panel.png"), [8, 16]).into()
+/// }
+/// ```
+#[cfg(any(feature = "image", feature = "svg"))]
+pub fn nine_patch<A>(asset: A, insets: impl Into<core::Padding>) -> crate::NinePatch<A> {
+    crate::NinePatch::new(asset, insets)
+}
+
 /// Creates an [`Element`] that displays the iced logo with the given `text_size`.
 ///
 /// Useful for showing some love to your favorite GUI library in your "About" screen,
@@ -1954,6 +2359,38 @@ where
     crate::QRCode::new(data)
 }
 
+/// Creates a new [`Lottie`] playing the given [`Handle`].
+///
+/// Lotties play vector animations exported in the Bodymovin JSON format.
+///
+/// [`Lottie`]: crate::Lottie
+/// [`Handle`]: crate::lottie::Handle
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::lottie;
+///
+/// struct State {
+///    animation: lottie::Handle,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     lottie(&state.animation).looping(false).into()
+/// }
+/// ```
+#[cfg(feature = "lottie")]
+pub fn lottie(handle: impl Into<crate::lottie::Handle>) -> crate::Lottie {
+    crate::Lottie::new(handle)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -1975,6 +2412,120 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates new [`Tabs`] with the given tabs, the index of the selected one,
+/// and a function that produces a message when a tab is selected.
+pub fn tabs<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = tabs::Tab>,
+    selected: Option<usize>,
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: tabs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(tabs, selected, on_select)
+}
+
+/// Creates a new [`Segmented`] control with the given segments, the index
+/// of the selected one, and a function that produces a message when a
+/// segment is selected.
+pub fn segmented<'a, Message, Theme, Renderer>(
+    segments: impl IntoIterator<Item = segmented::Segment>,
+    selected: Option<usize>,
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> Segmented<'a, Message, Theme, Renderer>
+where
+    Theme: segmented::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Segmented::new(segments, selected, on_select)
+}
+
+/// Creates new [`Steps`] with the given steps, the index of the current one,
+/// and its content.
+pub fn steps<'a, Message, Theme, Renderer>(
+    steps: impl IntoIterator<Item = steps::Step>,
+    current: usize,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Steps<'a, Message, Theme, Renderer>
+where
+    Theme: steps::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Steps::new(steps, current, content)
+}
+
+/// Creates a new [`Sticky`] section with the given header and content.
+///
+/// The header stays pinned to the top of the enclosing [`Scrollable`] while
+/// the content is visible.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn sticky<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Sticky<'a, Message, Theme, Renderer>
+where
+    Theme: sticky::Catalog,
+    Renderer: core::Renderer,
+{
+    Sticky::new(header, content)
+}
+
+/// Creates a new [`ReorderableColumn`] with the given children and a
+/// function that produces a message when an item is dragged from one index
+/// to another.
+pub fn reorderable_column<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    on_reorder: impl Fn(usize, usize) -> Message + 'a,
+) -> ReorderableColumn<'a, Message, Theme, Renderer>
+where
+    Theme: reorderable_column::Catalog,
+    Renderer: core::Renderer,
+{
+    ReorderableColumn::new(children, on_reorder)
+}
+
+/// Creates new [`Breadcrumbs`] with the given segments.
+pub fn breadcrumbs<'a, Message, Theme, Renderer>(
+    segments: impl IntoIterator<Item = breadcrumbs::Segment<Message>>,
+) -> Breadcrumbs<'a, Message, Theme, Renderer>
+where
+    Theme: breadcrumbs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Breadcrumbs::new(segments)
+}
+
+/// Creates a new [`Modal`] that displays the given dialog on top of the
+/// given base content.
+pub fn modal<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    dialog: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: modal::Catalog,
+    Renderer: core::Renderer,
+{
+    Modal::new(base, dialog)
+}
+
+/// Creates a new [`ContextMenu`] that opens a menu with the given items
+/// when the given content is right-clicked or long-pressed.
+///
+/// Items can be created using [`menu_bar::item`], [`menu_bar::submenu`],
+/// and [`menu_bar::separator`].
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu_bar::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, Theme, Renderer>(
     theme: Option<Theme>,
@@ -2035,6 +2586,80 @@ where
     PaneGrid::new(state, view)
 }
 
+/// Creates a new [`Dock`] with the given [`dock::State`], a function that
+/// produces the title of a panel, the view function of a panel, and a
+/// function that produces a message for a [`dock::Event`].
+///
+/// Docks arrange panels in tab groups that can be dragged, torn off, and
+/// collapsed to a sidebar.
+pub fn dock<'a, T, Message, Theme, Renderer>(
+    state: &'a dock::State<T>,
+    title: impl Fn(&T) -> String + 'a,
+    view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    on_event: impl Fn(dock::Event) -> Message + 'a,
+) -> Dock<'a, T, Message, Theme, Renderer>
+where
+    Theme: dock::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Dock::new(state, title, view, on_event)
+}
+
+/// Creates a new [`FileBrowser`] with the given [`file_browser::State`] and a
+/// function that produces a message for a [`file_browser::Event`].
+///
+/// File browsers let users navigate the file system and pick some entries
+/// without leaving your application.
+pub fn file_browser<'a, Message, Theme>(
+    state: &'a file_browser::State,
+    on_event: impl Fn(file_browser::Event) -> Message + 'a,
+) -> FileBrowser<'a, Message, Theme>
+where
+    Theme: file_browser::Catalog,
+{
+    FileBrowser::new(state, on_event)
+}
+
+/// Creates a new [`ColorPicker`] with the given [`Color`] and the function
+/// that produces a message when the [`Color`] changes.
+///
+/// [`Color`]: core::Color
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: color_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
+/// Creates a new [`Calendar`] displaying the given [`Month`](calendar::Month).
+pub fn calendar<'a, Message, Theme, Renderer>(
+    month: calendar::Month,
+) -> Calendar<'a, Message, Theme, Renderer>
+where
+    Theme: calendar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Calendar::new(month)
+}
+
+/// Creates a new [`DatePicker`] with the given selected [`Date`] and the function
+/// that produces a message when a [`Date`] is selected.
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    selected: Option<Date>,
+    on_select: impl Fn(Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(selected, on_select)
+}
+
 /// Creates a new [`Float`] widget with the given content.
 pub fn float<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -2060,3 +2685,36 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`Responsive`] widget with a closure that produces its
+/// contents for the [`Breakpoint`] reached by its width, using the default
+/// [`Breakpoints`].
+///
+/// The `view` closure will only be called again when the available width
+/// crosses a breakpoint.
+///
+/// [`Breakpoint`]: responsive::Breakpoint
+/// [`Breakpoints`]: responsive::Breakpoints
+pub fn breakpoints<'a, Message, Theme, Renderer>(
+    view: impl Fn(responsive::Breakpoint) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Responsive<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Responsive::with_breakpoints(responsive::Breakpoints::default(), view)
+}
+
+/// Creates a new [`VirtualList`] with the given amount of rows and a closure
+/// that produces the row with the given index.
+///
+/// Only the rows that are visible in the viewport of the [`VirtualList`] are
+/// built and laid out.
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    VirtualList::new(count, view)
+}
@@ -0,0 +1,113 @@
+//! Keep track of the edits of a text widget to undo and redo them.
+use crate::core::time::{Duration, Instant};
+
+/// The maximum amount of snapshots kept in a [`History`].
+const LIMIT: usize = 100;
+
+/// The time after which consecutive edits of the same [`Kind`] stop
+/// being coalesced.
+const COALESCE: Duration = Duration::from_secs(1);
+
+/// The kind of an edit; consecutive edits of the same kind are undone
+/// together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// A stack of snapshots taken before every group of edits.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    last: Option<(Kind, Instant)>,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last: None,
+        }
+    }
+}
+
+impl<T> History<T> {
+    /// Records the snapshot taken right before an edit of the given [`Kind`],
+    /// unless the edit continues the previous group.
+    pub fn record(&mut self, kind: Kind, now: Instant, snapshot: impl FnOnce() -> T) {
+        let continues = kind != Kind::Other
+            && self.last.is_some_and(|(last, at)| {
+                last == kind && now.saturating_duration_since(at) < COALESCE
+            });
+
+        if !continues {
+            self.undo.push(snapshot());
+
+            if self.undo.len() > LIMIT {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.redo.clear();
+        self.last = Some((kind, now));
+    }
+
+    /// Ends the current group of edits; the next edit will start a new one.
+    pub fn interrupt(&mut self) {
+        self.last = None;
+    }
+
+    /// Returns the snapshot to restore in order to undo the last group of
+    /// edits, keeping the `current` one for redoing.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop()?;
+
+        self.redo.push(current);
+        self.last = None;
+
+        Some(previous)
+    }
+
+    /// Returns the snapshot to restore in order to redo the last undone
+    /// group of edits, keeping the `current` one for undoing.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+
+        self.undo.push(current);
+        self.last = None;
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, Kind};
+    use crate::core::time::{Duration, Instant};
+
+    #[test]
+    fn consecutive_typing_is_undone_at_once() {
+        let start = Instant::now();
+        let mut history = History::default();
+
+        history.record(Kind::Typing, start, || "");
+        history.record(Kind::Typing, start + Duration::from_millis(100), || "h");
+        history.record(Kind::Deleting, start + Duration::from_millis(200), || "he");
+        history.record(Kind::Typing, start + Duration::from_secs(2), || "h");
+
+        assert_eq!(history.undo("hi"), Some("h"));
+        assert_eq!(history.undo("h"), Some("he"));
+        assert_eq!(history.undo("he"), Some(""));
+        assert_eq!(history.undo(""), None);
+
+        assert_eq!(history.redo(""), Some("he"));
+
+        history.record(Kind::Other, start, || "he");
+
+        assert_eq!(history.redo("x"), None);
+    }
+}
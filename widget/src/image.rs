@@ -16,7 +16,9 @@
 //! }
 //! ```
 //! <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+pub mod loader;
 pub mod viewer;
+pub use loader::Loader;
 pub use viewer::Viewer;
 
 use crate::core::border;
@@ -24,18 +26,36 @@ use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Rotation, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Event, Layout, Length, Point, Rectangle, Rotation, Shell, Size,
+    Vector, Widget,
 };
+use crate::mask::Mask;
 
-pub use image::{FilterMethod, Handle};
+use std::cell::RefCell;
+
+pub use image::{ColorFilter, FilterMethod, Handle, Orientation};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
-pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
+pub fn viewer<'a, Message, Handle>(handle: Handle) -> Viewer<'a, Message, Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Loader`] that displays the given placeholder content while
+/// the given [`Image`] loads.
+pub fn loader<'a, Message, Theme, Renderer, Handle>(
+    image: impl Into<Image<Handle>>,
+    placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Loader<'a, Message, Theme, Renderer, Handle>
+where
+    Renderer: crate::core::Renderer,
+{
+    Loader::new(image, placeholder)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -56,6 +76,7 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
 pub struct Image<Handle = image::Handle> {
     handle: Handle,
+    fallback: Option<Handle>,
     width: Length,
     height: Length,
     crop: Option<Rectangle<u32>>,
@@ -64,8 +85,13 @@ pub struct Image<Handle = image::Handle> {
     filter_method: FilterMethod,
     rotation: Rotation,
     opacity: f32,
+    color_filter: ColorFilter,
     scale: f32,
     expand: bool,
+    alpha_hit_test: bool,
+    playing: bool,
+    looping: bool,
+    speed: f32,
 }
 
 impl<Handle> Image<Handle> {
@@ -73,6 +99,7 @@ impl<Handle> Image<Handle> {
     pub fn new(handle: impl Into<Handle>) -> Self {
         Image {
             handle: handle.into(),
+            fallback: None,
             width: Length::Shrink,
             height: Length::Shrink,
             crop: None,
@@ -81,8 +108,13 @@ impl<Handle> Image<Handle> {
             filter_method: FilterMethod::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            color_filter: ColorFilter::IDENTITY,
             scale: 1.0,
             expand: false,
+            alpha_hit_test: false,
+            playing: true,
+            looping: true,
+            speed: 1.0,
         }
     }
 
@@ -140,6 +172,15 @@ impl<Handle> Image<Handle> {
         self
     }
 
+    /// Sets the [`ColorFilter`] applied to the pixels of the [`Image`].
+    ///
+    /// Filters can be composed with [`ColorFilter::then`]; like a grayscale
+    /// and tinted avatar.
+    pub fn color_filter(mut self, color_filter: ColorFilter) -> Self {
+        self.color_filter = color_filter;
+        self
+    }
+
     /// Sets the scale of the [`Image`].
     ///
     /// The region of the [`Image`] drawn will be scaled from the center by the given scale factor.
@@ -175,6 +216,112 @@ impl<Handle> Image<Handle> {
         self.border_radius = border_radius.into();
         self
     }
+
+    /// Sets whether the animation of the [`Image`] should be playing; if it
+    /// is an animated GIF, APNG, or WebP.
+    ///
+    /// When paused, the [`Image`] keeps its current frame and resumes from it
+    /// when played again.
+    ///
+    /// Defaults to `true`.
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] should start over once
+    /// it has finished.
+    ///
+    /// Defaults to `true`.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the playback speed of the animation of the [`Image`].
+    ///
+    /// Defaults to `1.0`.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the fallback [`Handle`] of the [`Image`], which will be displayed
+    /// instead if the [`Image`] cannot be loaded; either because its file is
+    /// missing or because its data cannot be decoded.
+    ///
+    /// This is useful to show a placeholder for broken assets, instead of
+    /// nothing at all. The [`crop`](Self::crop) region is not applied to the
+    /// fallback.
+    pub fn fallback(mut self, fallback: impl Into<Handle>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Returns the [`Handle`] to display and its crop region, which may be
+    /// the fallback if the [`Image`] could not be loaded.
+    fn displayed<Renderer>(&self, renderer: &Renderer) -> (&Handle, Option<Rectangle<u32>>)
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        match &self.fallback {
+            Some(fallback) if renderer.image_error(&self.handle).is_some() => (fallback, None),
+            _ => (&self.handle, self.crop),
+        }
+    }
+
+    /// Sets whether the [`Image`] should only be hit by the mouse cursor over
+    /// its non-transparent pixels.
+    ///
+    /// When enabled, interactive widgets containing the [`Image`]—like a
+    /// [`button`](crate::button) or a [`mouse_area`](crate::mouse_area)—will
+    /// ignore the mouse cursor while it is over the transparent regions of
+    /// the image.
+    ///
+    /// Defaults to `false`.
+    pub fn alpha_hit_test(mut self, alpha_hit_test: bool) -> Self {
+        self.alpha_hit_test = alpha_hit_test;
+        self
+    }
+
+    /// Draws the [`Image`] with the given opacity, instead of its own.
+    fn draw_with_opacity<Renderer>(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        opacity: f32,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+        Handle: Clone,
+    {
+        let (handle, crop) = self.displayed(renderer);
+
+        let time = renderer.measure_image_animation(handle).map(|duration| {
+            let state = tree.state.downcast_ref::<State>();
+
+            if self.looping && !duration.is_zero() {
+                Duration::from_secs_f64(state.elapsed.as_secs_f64() % duration.as_secs_f64())
+            } else {
+                state.elapsed
+            }
+        });
+
+        draw(
+            renderer,
+            layout,
+            handle,
+            crop,
+            self.border_radius,
+            self.content_fit,
+            self.filter_method,
+            self.rotation,
+            opacity,
+            self.scale,
+            time,
+            self.color_filter,
+        );
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -315,6 +462,8 @@ pub fn draw<Renderer, Handle>(
     rotation: Rotation,
     opacity: f32,
     scale: f32,
+    time: Option<Duration>,
+    color_filter: ColorFilter,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
@@ -331,6 +480,9 @@ pub fn draw<Renderer, Handle>(
             rotation: rotation.radians(),
             opacity,
             snap: true,
+            time,
+            color_filter,
+            region: None,
         },
         drawing_bounds,
         bounds,
@@ -342,6 +494,14 @@ where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -355,22 +515,60 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (handle, crop) = self.displayed(renderer);
+
         layout(
             renderer,
             limits,
-            &self.handle,
+            handle,
             self.width,
             self.height,
-            self.crop,
+            crop,
             self.content_fit,
             self.rotation,
             self.expand,
         )
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.playing
+            && let Some(duration) = renderer.measure_image_animation(&self.handle)
+        {
+            if let Some(last_tick) = state.last_tick {
+                state.elapsed += now
+                    .saturating_duration_since(last_tick)
+                    .mul_f32(self.speed.max(0.0));
+            }
+
+            state.last_tick = Some(*now);
+
+            if self.looping || state.elapsed < duration {
+                shell.request_redraw();
+            }
+        } else {
+            state.last_tick = None;
+        }
+    }
+
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         _theme: &Theme,
         _style: &renderer::Style,
@@ -378,21 +576,64 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(
+        self.draw_with_opacity(tree, renderer, layout, self.opacity);
+    }
+
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        if !self.alpha_hit_test {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        if !bounds.contains(position) {
+            return Some(false);
+        }
+
+        let (handle, crop) = self.displayed(renderer);
+        let id = renderer.load_image(handle).ok()?.handle().id();
+
+        let state = tree.state.downcast_ref::<State>();
+        let mut mask = state.mask.borrow_mut();
+
+        if mask.as_ref().is_none_or(|(current, _)| *current != id) {
+            let decoded = renderer.decode_image(handle);
+
+            *mask = Some((id, decoded.as_ref().and_then(Mask::new)));
+        }
+
+        let (_, mask) = mask.as_ref()?;
+
+        let drawing_bounds = drawing_bounds(
             renderer,
-            layout,
-            &self.handle,
-            self.crop,
-            self.border_radius,
+            bounds,
+            handle,
+            crop,
             self.content_fit,
-            self.filter_method,
             self.rotation,
-            self.opacity,
             self.scale,
         );
+
+        Some(
+            mask.as_ref()?
+                .hit(drawing_bounds, self.rotation.radians(), position),
+        )
     }
 }
 
+#[derive(Debug, Default)]
+struct State {
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    mask: RefCell<Option<(image::Id, Option<Mask>)>>,
+}
+
 impl<'a, Message, Theme, Renderer, Handle> From<Image<Handle>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -0,0 +1,354 @@
+//! Display some placeholder content while an image loads.
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::image::Image;
+
+/// A widget that displays some placeholder content while an [`Image`] loads,
+/// and then cross-fades to the [`Image`] once it is ready.
+///
+/// Renderers decode images concurrently, so the placeholder is only displayed
+/// for images that are not loaded yet. Images that are already loaded are
+/// displayed right away, without fading.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::image;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     image::loader(image("photo.jpg").width(400), "Loading...").into()
+/// }
+/// ```
+pub struct Loader<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+    Handle = image::Handle,
+> where
+    Renderer: core::Renderer,
+{
+    image: Image<Handle>,
+    placeholder: Element<'a, Message, Theme, Renderer>,
+    fade: Duration,
+}
+
+impl<'a, Message, Theme, Renderer, Handle> Loader<'a, Message, Theme, Renderer, Handle>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Loader`] for the given [`Image`], displaying the given
+    /// placeholder content until it is loaded.
+    pub fn new(
+        image: impl Into<Image<Handle>>,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            image: image.into(),
+            placeholder: placeholder.into(),
+            fade: Duration::from_millis(200),
+        }
+    }
+
+    /// Sets the [`Duration`] of the cross-fade between the placeholder and
+    /// the [`Image`].
+    ///
+    /// A [`Duration::ZERO`] disables the cross-fade.
+    ///
+    /// Defaults to 200 milliseconds.
+    pub fn fade(mut self, fade: Duration) -> Self {
+        self.fade = fade;
+        self
+    }
+}
+
+impl<Handle> Image<Handle> {
+    /// Returns whether the [`Image`]—or its fallback—is ready to be drawn.
+    fn is_loaded<Renderer>(&self, renderer: &Renderer) -> bool
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let (handle, _) = self.displayed(renderer);
+
+        renderer.measure_image(handle).is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum State {
+    #[default]
+    Idle,
+    Loading,
+    Fading {
+        start: Instant,
+        progress: f32,
+    },
+    Loaded,
+}
+
+impl State {
+    /// Returns the opacity of the image, if it is visible.
+    fn opacity(self, is_loaded: bool) -> Option<f32> {
+        if !is_loaded {
+            return None;
+        }
+
+        match self {
+            State::Idle | State::Loaded => Some(1.0),
+            State::Loading => Some(0.0),
+            State::Fading { progress, .. } => Some(progress),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for Loader<'_, Message, Theme, Renderer, Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.image as &dyn Widget<Message, Theme, Renderer>),
+            Tree::new(&self.placeholder),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            &self.image as &dyn Widget<Message, Theme, Renderer>,
+            self.placeholder.as_widget(),
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Message, Theme, Renderer>::size(&self.image)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (image_tree, placeholder_tree) = tree.children.split_at_mut(1);
+
+        let size = if self.image.is_loaded(renderer) {
+            Widget::<Message, Theme, Renderer>::layout(
+                &mut self.image,
+                &mut image_tree[0],
+                renderer,
+                limits,
+            )
+            .size()
+        } else {
+            self.placeholder
+                .as_widget_mut()
+                .layout(&mut placeholder_tree[0], renderer, limits)
+                .size()
+        };
+
+        // The placeholder is stretched to the final size, so it stays
+        // in place during the cross-fade
+        let placeholder = self.placeholder.as_widget_mut().layout(
+            &mut placeholder_tree[0],
+            renderer,
+            &layout::Limits::new(size, size),
+        );
+
+        layout::Node::with_children(size, vec![layout::Node::new(size), placeholder])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_loaded = self.image.is_loaded(renderer);
+        let mut children = layout.children();
+        let image_layout = children.next().unwrap();
+        let placeholder_layout = children.next().unwrap();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            *state = match *state {
+                _ if !is_loaded => State::Loading,
+                State::Idle => State::Loaded,
+                State::Loading if self.fade.is_zero() => State::Loaded,
+                State::Loading => State::Fading {
+                    start: *now,
+                    progress: 0.0,
+                },
+                State::Fading { start, .. } => {
+                    let progress = now.saturating_duration_since(start).as_secs_f32()
+                        / self.fade.as_secs_f32();
+
+                    if progress >= 1.0 {
+                        State::Loaded
+                    } else {
+                        State::Fading { start, progress }
+                    }
+                }
+                State::Loaded => State::Loaded,
+            };
+
+            if let State::Fading { .. } = state {
+                shell.request_redraw();
+            }
+        }
+
+        let state = *tree.state.downcast_ref::<State>();
+
+        if state.opacity(is_loaded).is_some() {
+            Widget::<Message, Theme, Renderer>::update(
+                &mut self.image,
+                &mut tree.children[0],
+                event,
+                image_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if state.opacity(is_loaded) != Some(1.0) {
+            self.placeholder.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                placeholder_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.opacity(self.image.is_loaded(renderer)) == Some(1.0) {
+            return mouse::Interaction::None;
+        }
+
+        self.placeholder.as_widget().mouse_interaction(
+            &tree.children[1],
+            layout.children().nth(1).unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let opacity = state.opacity(self.image.is_loaded(renderer));
+        let mut children = layout.children();
+        let image_layout = children.next().unwrap();
+        let placeholder_layout = children.next().unwrap();
+
+        if opacity != Some(1.0) {
+            self.placeholder.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                placeholder_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if let Some(opacity) = opacity {
+            self.image.draw_with_opacity(
+                &tree.children[0],
+                renderer,
+                image_layout,
+                self.image.opacity * opacity,
+            );
+        }
+    }
+
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        if !self.image.is_loaded(renderer) {
+            return None;
+        }
+
+        Widget::<Message, Theme, Renderer>::hit_test(
+            &self.image,
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            position,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<Loader<'a, Message, Theme, Renderer, Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + 'a,
+{
+    fn from(
+        loader: Loader<'a, Message, Theme, Renderer, Handle>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(loader)
+    }
+}
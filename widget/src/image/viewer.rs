@@ -1,17 +1,26 @@
 //! Zoom and pan on an image.
+pub mod operation;
+
 use crate::core::border;
 use crate::core::image::{self, FilterMethod};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Id;
+use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Clipboard, ContentFit, Element, Event, Image, Layout, Length, Pixels, Point, Radians,
-    Rectangle, Shell, Size, Vector, Widget,
+    Rectangle, Rotation, Shell, Size, Vector, Widget,
 };
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
-pub struct Viewer<Handle> {
+///
+/// The image can be zoomed with the mouse wheel or by pinching, panned by
+/// dragging, and fit back into the frame with a double click.
+pub struct Viewer<'a, Message, Handle = image::Handle> {
+    id: Option<Id>,
     padding: f32,
     width: Length,
     height: Length,
@@ -21,12 +30,16 @@ pub struct Viewer<Handle> {
     handle: Handle,
     filter_method: FilterMethod,
     content_fit: ContentFit,
+    rotation: Rotation,
+    double_click_to_fit: bool,
+    on_viewport_change: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
 }
 
-impl<Handle> Viewer<Handle> {
+impl<'a, Message, Handle> Viewer<'a, Message, Handle> {
     /// Creates a new [`Viewer`] with the given [`State`].
     pub fn new<T: Into<Handle>>(handle: T) -> Self {
         Viewer {
+            id: None,
             handle: handle.into(),
             padding: 0.0,
             width: Length::Shrink,
@@ -36,9 +49,19 @@ impl<Handle> Viewer<Handle> {
             scale_step: 0.10,
             filter_method: FilterMethod::default(),
             content_fit: ContentFit::default(),
+            rotation: Rotation::default(),
+            double_click_to_fit: true,
+            on_viewport_change: None,
         }
     }
 
+    /// Sets the [`Id`] of the [`Viewer`], which can be used to control it
+    /// with an [`operation`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the [`FilterMethod`] of the [`Viewer`].
     pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
         self.filter_method = filter_method;
@@ -51,6 +74,12 @@ impl<Handle> Viewer<Handle> {
         self
     }
 
+    /// Applies the given [`Rotation`] to the image of the [`Viewer`].
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
     /// Sets the padding of the [`Viewer`].
     pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
         self.padding = padding.into().0;
@@ -93,9 +122,228 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Sets whether a double click should fit the image back into the
+    /// [`Viewer`].
+    ///
+    /// Default is `true`
+    pub fn double_click_to_fit(mut self, double_click_to_fit: bool) -> Self {
+        self.double_click_to_fit = double_click_to_fit;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Viewport`] of the
+    /// [`Viewer`] changes; either because it was zoomed, panned, or controlled
+    /// with an [`operation`].
+    pub fn on_viewport_change(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.on_viewport_change = Some(Box::new(f));
+        self
+    }
+}
+
+impl<Message, Handle> Viewer<'_, Message, Handle> {
+    /// Returns the unrotated bounds of the image, given the bounds of the
+    /// [`Viewer`] and its [`State`].
+    fn image_bounds<Renderer>(
+        &self,
+        renderer: &Renderer,
+        state: &State,
+        bounds: Rectangle,
+    ) -> Rectangle
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let final_size = scaled_image_size(
+            renderer,
+            &self.handle,
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.rotation,
+        );
+
+        let rotated_size = self.rotation.apply(final_size);
+
+        let diff_w = bounds.width - rotated_size.width;
+        let diff_h = bounds.height - rotated_size.height;
+
+        let image_top_left = match self.content_fit {
+            ContentFit::None => Vector::new(diff_w.max(0.0) / 2.0, diff_h.max(0.0) / 2.0),
+            _ => Vector::new(diff_w / 2.0, diff_h / 2.0),
+        };
+
+        // The image is rotated around its center, inside its rotated bounds
+        let rotation_offset = Vector::new(
+            (rotated_size.width - final_size.width) / 2.0,
+            (rotated_size.height - final_size.height) / 2.0,
+        );
+
+        Rectangle::new(
+            bounds.position() + image_top_left + rotation_offset
+                - state.offset(bounds, rotated_size),
+            final_size,
+        )
+    }
+
+    /// Returns the rotated size of the image, given the bounds of the
+    /// [`Viewer`] and its [`State`].
+    fn rotated_size<Renderer>(&self, renderer: &Renderer, state: &State, bounds: Rectangle) -> Size
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        self.rotation.apply(scaled_image_size(
+            renderer,
+            &self.handle,
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.rotation,
+        ))
+    }
+
+    /// Zooms the image to the given scale, keeping it in place around the
+    /// given `anchor`.
+    fn zoom<Renderer>(
+        &self,
+        renderer: &Renderer,
+        state: &mut State,
+        bounds: Rectangle,
+        scale: f32,
+        anchor: Point,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = state.scale;
+
+        state.scale = scale.clamp(self.min_scale, self.max_scale);
+
+        let scaled_size = self.rotated_size(renderer, state, bounds);
+
+        let factor = state.scale / previous_scale - 1.0;
+
+        let cursor_to_center = anchor - bounds.center();
+
+        let adjustment = cursor_to_center * factor + state.current_offset * factor;
+
+        state.current_offset = Vector::new(
+            if scaled_size.width > bounds.width {
+                state.current_offset.x + adjustment.x
+            } else {
+                0.0
+            },
+            if scaled_size.height > bounds.height {
+                state.current_offset.y + adjustment.y
+            } else {
+                0.0
+            },
+        );
+    }
+
+    /// Pans the image from the given starting offset by the given delta.
+    fn pan<Renderer>(
+        &self,
+        renderer: &Renderer,
+        state: &mut State,
+        bounds: Rectangle,
+        starting_offset: Vector,
+        delta: Vector,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let scaled_size = self.rotated_size(renderer, state, bounds);
+
+        let hidden_width = (scaled_size.width - bounds.width / 2.0).max(0.0).round();
+
+        let hidden_height = (scaled_size.height - bounds.height / 2.0).max(0.0).round();
+
+        let x = if bounds.width < scaled_size.width {
+            (starting_offset.x - delta.x).clamp(-hidden_width, hidden_width)
+        } else {
+            0.0
+        };
+
+        let y = if bounds.height < scaled_size.height {
+            (starting_offset.y - delta.y).clamp(-hidden_height, hidden_height)
+        } else {
+            0.0
+        };
+
+        state.current_offset = Vector::new(x, y);
+    }
+
+    /// Returns the current [`Viewport`] of the [`Viewer`].
+    fn viewport<Renderer>(&self, renderer: &Renderer, state: &State, bounds: Rectangle) -> Viewport
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
+        let image_bounds = self.image_bounds(renderer, state, bounds);
+
+        let offset = rotate(
+            bounds.center() - image_bounds.center(),
+            Radians(-self.rotation.radians().0),
+        );
+
+        let center = if image_bounds.width > 0.0 && image_bounds.height > 0.0 {
+            Point::new(
+                (offset.x / image_bounds.width + 0.5) * image_size.width as f32,
+                (offset.y / image_bounds.height + 0.5) * image_size.height as f32,
+            )
+        } else {
+            Point::ORIGIN
+        };
+
+        Viewport {
+            scale: state.scale,
+            center,
+        }
+    }
+
+    /// Applies a [`Request`] made by an [`operation`].
+    fn apply<Renderer>(
+        &self,
+        renderer: &Renderer,
+        state: &mut State,
+        bounds: Rectangle,
+        request: Request,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        match request {
+            Request::Zoom(scale) => {
+                self.zoom(renderer, state, bounds, scale, bounds.center());
+            }
+            Request::Center(point) => {
+                let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
+
+                if image_size.width == 0 || image_size.height == 0 {
+                    return;
+                }
+
+                state.current_offset = Vector::ZERO;
+
+                let image_bounds = self.image_bounds(renderer, state, bounds);
+
+                let relative = Vector::new(
+                    (point.x / image_size.width as f32 - 0.5) * image_bounds.width,
+                    (point.y / image_size.height as f32 - 0.5) * image_bounds.height,
+                );
+
+                let target = image_bounds.center() + rotate(relative, self.rotation.radians())
+                    - bounds.center();
+
+                self.pan(renderer, state, bounds, Vector::ZERO, -target);
+            }
+            Request::Fit => {
+                state.scale = 1.0_f32.clamp(self.min_scale, self.max_scale);
+                state.current_offset = Vector::ZERO;
+            }
+        }
+    }
 }
 
-impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for Viewer<Handle>
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
+    for Viewer<'_, Message, Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone,
@@ -124,7 +372,9 @@ where
         // The raw w/h of the underlying image
         let image_size = renderer.measure_image(&self.handle).unwrap_or_default();
 
-        let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+        let image_size = self
+            .rotation
+            .apply(Size::new(image_size.width as f32, image_size.height as f32));
 
         // The size to be available to the widget prior to `Shrink`ing
         let raw_size = limits.resolve(self.width, self.height, image_size);
@@ -147,6 +397,22 @@ where
         layout::Node::new(final_size)
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+
+        if let Some(request) = state.request.take() {
+            self.apply(renderer, state, layout.bounds(), request);
+        }
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -159,6 +425,7 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
@@ -168,46 +435,18 @@ where
 
                 match *delta {
                     mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                        let state = tree.state.downcast_mut::<State>();
                         let previous_scale = state.scale;
 
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            state.scale = (if y > 0.0 {
+                            let scale = if y > 0.0 {
                                 state.scale * (1.0 + self.scale_step)
                             } else {
                                 state.scale / (1.0 + self.scale_step)
-                            })
-                            .clamp(self.min_scale, self.max_scale);
-
-                            let scaled_size = scaled_image_size(
-                                renderer,
-                                &self.handle,
-                                state,
-                                bounds.size(),
-                                self.content_fit,
-                            );
-
-                            let factor = state.scale / previous_scale - 1.0;
+                            };
 
-                            let cursor_to_center = cursor_position - bounds.center();
-
-                            let adjustment =
-                                cursor_to_center * factor + state.current_offset * factor;
-
-                            state.current_offset = Vector::new(
-                                if scaled_size.width > bounds.width {
-                                    state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if scaled_size.height > bounds.height {
-                                    state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
-                            );
+                            self.zoom(renderer, state, bounds, scale, cursor_position);
                         }
                     }
                 }
@@ -220,54 +459,115 @@ where
                     return;
                 };
 
-                let state = tree.state.downcast_mut::<State>();
+                let click =
+                    mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
+
+                state.last_click = Some(click);
+
+                if self.double_click_to_fit && click.kind() == mouse::click::Kind::Double {
+                    self.apply(renderer, state, bounds, Request::Fit);
 
-                state.cursor_grabbed_at = Some(cursor_position);
-                state.starting_offset = state.current_offset;
+                    state.cursor_grabbed_at = None;
+                    shell.request_redraw();
+                } else {
+                    state.cursor_grabbed_at = Some(cursor_position);
+                    state.starting_offset = state.current_offset;
+                }
 
                 shell.capture_event();
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let state = tree.state.downcast_mut::<State>();
-
                 state.cursor_grabbed_at = None;
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                let state = tree.state.downcast_mut::<State>();
-
                 if let Some(origin) = state.cursor_grabbed_at {
-                    let scaled_size = scaled_image_size(
-                        renderer,
-                        &self.handle,
-                        state,
-                        bounds.size(),
-                        self.content_fit,
-                    );
-                    let hidden_width = (scaled_size.width - bounds.width / 2.0).max(0.0).round();
-
-                    let hidden_height = (scaled_size.height - bounds.height / 2.0).max(0.0).round();
-
-                    let delta = *position - origin;
-
-                    let x = if bounds.width < scaled_size.width {
-                        (state.starting_offset.x - delta.x).clamp(-hidden_width, hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < scaled_size.height {
-                        (state.starting_offset.y - delta.y).clamp(-hidden_height, hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    state.current_offset = Vector::new(x, y);
+                    let starting_offset = state.starting_offset;
+
+                    self.pan(renderer, state, bounds, starting_offset, *position - origin);
+
                     shell.request_redraw();
                     shell.capture_event();
                 }
             }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if !bounds.contains(*position) {
+                    return;
+                }
+
+                if let Some(slot) = state.fingers.iter_mut().find(|finger| finger.is_none()) {
+                    *slot = Some((*id, *position));
+                }
+
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some(index) = state
+                    .fingers
+                    .iter()
+                    .position(|finger| finger.is_some_and(|(finger, _)| finger == *id))
+                else {
+                    return;
+                };
+
+                let previous = state.fingers;
+                state.fingers[index] = Some((*id, *position));
+
+                match (previous, state.fingers) {
+                    // Pinch to zoom around the fingers, while panning along with them
+                    ([Some((_, a)), Some((_, b))], [Some((_, c)), Some((_, d))]) => {
+                        let previous_distance = a.distance(b);
+
+                        if previous_distance > 0.0 {
+                            let scale = state.scale * c.distance(d) / previous_distance;
+
+                            let previous_center = midpoint(a, b);
+                            let center = midpoint(c, d);
+
+                            self.zoom(renderer, state, bounds, scale, previous_center);
+
+                            let starting_offset = state.current_offset;
+
+                            self.pan(
+                                renderer,
+                                state,
+                                bounds,
+                                starting_offset,
+                                center - previous_center,
+                            );
+                        }
+                    }
+                    _ => {
+                        let (_, origin) = previous[index].expect("Finger is tracked");
+                        let starting_offset = state.current_offset;
+
+                        self.pan(renderer, state, bounds, starting_offset, *position - origin);
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                for slot in &mut state.fingers {
+                    if slot.is_some_and(|(finger, _)| finger == *id) {
+                        *slot = None;
+                    }
+                }
+            }
             _ => {}
         }
+
+        if let Some(on_viewport_change) = &self.on_viewport_change {
+            let viewport = self.viewport(renderer, state, bounds);
+
+            if state.last_viewport != Some(viewport) {
+                state.last_viewport = Some(viewport);
+
+                shell.publish(on_viewport_change(viewport));
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -304,49 +604,42 @@ where
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
-        let final_size = scaled_image_size(
-            renderer,
-            &self.handle,
-            state,
-            bounds.size(),
-            self.content_fit,
-        );
-
-        let translation = {
-            let diff_w = bounds.width - final_size.width;
-            let diff_h = bounds.height - final_size.height;
-
-            let image_top_left = match self.content_fit {
-                ContentFit::None => Vector::new(diff_w.max(0.0) / 2.0, diff_h.max(0.0) / 2.0),
-                _ => Vector::new(diff_w / 2.0, diff_h / 2.0),
-            };
-
-            image_top_left - state.offset(bounds, final_size)
-        };
-
-        let drawing_bounds = Rectangle::new(bounds.position(), final_size);
+        let drawing_bounds = self.image_bounds(renderer, state, bounds);
 
         let render = |renderer: &mut Renderer| {
-            renderer.with_translation(translation, |renderer| {
-                renderer.draw_image(
-                    Image {
-                        handle: self.handle.clone(),
-                        border_radius: border::Radius::default(),
-                        filter_method: self.filter_method,
-                        rotation: Radians(0.0),
-                        opacity: 1.0,
-                        snap: true,
-                    },
-                    drawing_bounds,
-                    *viewport - translation,
-                );
-            });
+            renderer.draw_image(
+                Image {
+                    handle: self.handle.clone(),
+                    border_radius: border::Radius::default(),
+                    filter_method: self.filter_method,
+                    rotation: self.rotation.radians(),
+                    opacity: 1.0,
+                    snap: true,
+                    time: None,
+                    color_filter: image::ColorFilter::IDENTITY,
+                    region: None,
+                },
+                drawing_bounds,
+                *viewport,
+            );
         };
 
         renderer.with_layer(bounds, render);
     }
 }
 
+/// The visible region of the image of a [`Viewer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The scale applied to the image, relative to its size when fit into
+    /// the [`Viewer`].
+    pub scale: f32,
+
+    /// The point of the image—in pixels—displayed at the center of the
+    /// [`Viewer`].
+    pub center: Point,
+}
+
 /// The local state of a [`Viewer`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -354,6 +647,18 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    last_click: Option<mouse::Click>,
+    fingers: [Option<(touch::Finger, Point)>; 2],
+    last_viewport: Option<Viewport>,
+    request: Option<Request>,
+}
+
+/// A change of the [`State`] of a [`Viewer`] made by an [`operation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Request {
+    Zoom(f32),
+    Center(Point),
+    Fit,
 }
 
 impl Default for State {
@@ -363,6 +668,10 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            last_click: None,
+            fingers: [None; 2],
+            last_viewport: None,
+            request: None,
         }
     }
 }
@@ -392,14 +701,14 @@ impl State {
     }
 }
 
-impl<'a, Message, Theme, Renderer, Handle> From<Viewer<Handle>>
+impl<'a, Message, Theme, Renderer, Handle> From<Viewer<'a, Message, Handle>>
     for Element<'a, Message, Theme, Renderer>
 where
     Renderer: 'a + image::Renderer<Handle = Handle>,
     Message: 'a,
     Handle: Clone + 'a,
 {
-    fn from(viewer: Viewer<Handle>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(viewer: Viewer<'a, Message, Handle>) -> Element<'a, Message, Theme, Renderer> {
         Element::new(viewer)
     }
 }
@@ -407,12 +716,16 @@ where
 /// Returns the bounds of the underlying image, given the bounds of
 /// the [`Viewer`]. Scaling will be applied and original aspect ratio
 /// will be respected.
+///
+/// The returned size is unrotated, but it is fit into the bounds as
+/// given by the [`Rotation`].
 pub fn scaled_image_size<Renderer>(
     renderer: &Renderer,
     handle: &<Renderer as image::Renderer>::Handle,
     state: &State,
     bounds: Size,
     content_fit: ContentFit,
+    rotation: Rotation,
 ) -> Size
 where
     Renderer: image::Renderer,
@@ -420,11 +733,29 @@ where
     let Size { width, height } = renderer.measure_image(handle).unwrap_or_default();
 
     let image_size = Size::new(width as f32, height as f32);
+    let rotated_size = rotation.apply(image_size);
 
-    let adjusted_fit = content_fit.fit(image_size, bounds);
+    if rotated_size.width <= 0.0 || rotated_size.height <= 0.0 {
+        return Size::ZERO;
+    }
+
+    let adjusted_fit = content_fit.fit(rotated_size, bounds);
 
     Size::new(
-        adjusted_fit.width * state.scale,
-        adjusted_fit.height * state.scale,
+        image_size.width * adjusted_fit.width / rotated_size.width * state.scale,
+        image_size.height * adjusted_fit.height / rotated_size.height * state.scale,
     )
 }
+
+fn rotate(vector: Vector, rotation: Radians) -> Vector {
+    let (sin, cos) = rotation.0.sin_cos();
+
+    Vector::new(
+        vector.x * cos - vector.y * sin,
+        vector.x * sin + vector.y * cos,
+    )
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
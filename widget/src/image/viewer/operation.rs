@@ -0,0 +1,55 @@
+//! Control a [`Viewer`] programmatically.
+//!
+//! [`Viewer`]: super::Viewer
+use crate::core::Point;
+use crate::core::Rectangle;
+use crate::core::widget::Id;
+use crate::core::widget::Operation;
+use crate::image::viewer::{Request, State};
+
+use std::any::Any;
+
+/// Produces an [`Operation`] that zooms the viewer with the given [`Id`]
+/// to the given scale, around its center.
+///
+/// The scale is relative to the size of the image when fit into the viewer,
+/// and it is clamped to its min and max scale.
+pub fn zoom_to<T>(target: Id, scale: f32) -> impl Operation<T> {
+    request(target, Request::Zoom(scale))
+}
+
+/// Produces an [`Operation`] that pans the viewer with the given [`Id`] so
+/// the given point of its image—in pixels—is displayed at its center, as
+/// much as possible.
+pub fn center_on<T>(target: Id, point: Point) -> impl Operation<T> {
+    request(target, Request::Center(point))
+}
+
+/// Produces an [`Operation`] that fits the image of the viewer with the
+/// given [`Id`] back into its bounds.
+pub fn fit<T>(target: Id) -> impl Operation<T> {
+    request(target, Request::Fit)
+}
+
+fn request<T>(target: Id, request: Request) -> impl Operation<T> {
+    struct Apply {
+        target: Id,
+        request: Request,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if Some(&self.target) == id
+                && let Some(state) = state.downcast_mut::<State>()
+            {
+                state.request = Some(self.request);
+            }
+        }
+    }
+
+    Apply { target, request }
+}
@@ -38,8 +38,65 @@
 //! to help them keep continuity, you need to make sure the hint stays the same
 //! for the same items in your user interface between `view` calls.
 pub mod column;
+pub mod grid;
+pub mod row;
+pub mod stack;
 
 pub use column::Column;
+pub use grid::Grid;
+pub use row::Row;
+pub use stack::Stack;
+
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{self, Element};
+
+/// The keys of the children of a keyed widget, as of the last reconciliation.
+struct State<Key> {
+    keys: Vec<Key>,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + PartialEq + 'static,
+{
+    fn tree(keys: &[Key]) -> tree::State {
+        tree::State::new(Self {
+            keys: keys.to_vec(),
+        })
+    }
+}
+
+/// Reconciles the children of the given [`Tree`] by key.
+fn diff<Key, Message, Theme, Renderer>(
+    tree: &mut Tree,
+    keys: &[Key],
+    children: &[Element<'_, Message, Theme, Renderer>],
+) where
+    Key: Copy + PartialEq + 'static,
+    Renderer: core::Renderer,
+{
+    let Tree {
+        state,
+        children: trees,
+        ..
+    } = tree;
+
+    let state = state.downcast_mut::<State<Key>>();
+
+    tree::diff_children_keyed(
+        trees,
+        &state.keys,
+        children,
+        keys,
+        |tree, child| tree.diff(child.as_widget()),
+        |child| Tree::new(child.as_widget()),
+    );
+
+    if state.keys != keys {
+        state.keys.clear();
+        state.keys.extend_from_slice(keys);
+    }
+}
 
 /// Creates a keyed [`Column`] with the given children.
 ///
@@ -73,3 +130,36 @@ macro_rules! keyed_column {
         $crate::keyed::Column::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
     );
 }
+
+/// Creates a keyed [`Row`] with the given children.
+///
+/// Keyed rows distribute content horizontally while keeping continuity.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::keyed_row;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     keyed_row![
+///         (0, "Item 0"),
+///         (1, "Item 1"),
+///         (2, "Item 2"),
+///     ].into()
+/// }
+/// ```
+#[macro_export]
+macro_rules! keyed_row {
+    () => (
+        $crate::keyed::Row::new()
+    );
+    ($(($key:expr, $x:expr)),+ $(,)?) => (
+        $crate::keyed::Row::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
+    );
+}
@@ -9,6 +9,7 @@ use crate::core::{
     Alignment, Clipboard, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size,
     Vector, Widget,
 };
+use crate::keyed::{self, State};
 
 /// A container that distributes its contents vertically while keeping continuity.
 ///
@@ -178,13 +179,6 @@ where
     }
 }
 
-struct State<Key>
-where
-    Key: Copy + PartialEq,
-{
-    keys: Vec<Key>,
-}
-
 impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Column<'_, Key, Message, Theme, Renderer>
 where
@@ -196,9 +190,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State {
-            keys: self.keys.clone(),
-        })
+        State::tree(&self.keys)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -206,26 +198,7 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
-        let Tree {
-            state, children, ..
-        } = tree;
-
-        let state = state.downcast_mut::<State<Key>>();
-
-        tree::diff_children_custom_with_search(
-            children,
-            &self.children,
-            |tree, child| child.as_widget().diff(tree),
-            |index| {
-                self.keys.get(index).or_else(|| self.keys.last()).copied()
-                    != Some(state.keys[index])
-            },
-            |child| Tree::new(child.as_widget()),
-        );
-
-        if state.keys != self.keys {
-            state.keys.clone_from(&self.keys);
-        }
+        keyed::diff(tree, &self.keys, &self.children);
     }
 
     fn size(&self) -> Size<Length> {
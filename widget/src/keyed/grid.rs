@@ -0,0 +1,231 @@
+//! Keyed grids distribute content on a grid while keeping continuity.
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::grid::Sizing;
+use crate::keyed::{self, State};
+
+/// A container that distributes its contents on a responsive grid while
+/// keeping continuity.
+pub struct Grid<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Key: Copy + PartialEq,
+{
+    keys: Vec<Key>,
+    grid: crate::Grid<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Grid<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a [`Grid`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: Vec::with_capacity(capacity),
+            grid: crate::Grid::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a [`Grid`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        let iterator = children.into_iter();
+
+        Self::with_capacity(iterator.size_hint().0).extend(iterator)
+    }
+
+    /// Sets the spacing _between_ cells in the [`Grid`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.grid = self.grid.spacing(amount);
+        self
+    }
+
+    /// Sets the width of the [`Grid`] in [`Pixels`].
+    ///
+    /// By default, a [`Grid`] will [`Fill`] its parent.
+    ///
+    /// [`Fill`]: Length::Fill
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.grid = self.grid.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// By default, a [`Grid`] uses a cell aspect ratio of `1.0` (i.e. squares).
+    pub fn height(mut self, height: impl Into<Sizing>) -> Self {
+        self.grid = self.grid.height(height);
+        self
+    }
+
+    /// Sets the amount of columns in the [`Grid`].
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.grid = self.grid.columns(columns);
+        self
+    }
+
+    /// Makes the amount of columns dynamic in the [`Grid`], never
+    /// exceeding the provided `max_width`.
+    pub fn fluid(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.grid = self.grid.fluid(max_width);
+        self
+    }
+
+    /// Adds an element to the [`Grid`].
+    pub fn push(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.keys.push(key);
+        self.grid = self.grid.push(child);
+        self
+    }
+
+    /// Extends the [`Grid`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(self, |grid, (key, child)| grid.push(key, child))
+    }
+}
+
+impl<Key, Message, Renderer> Default for Grid<'_, Key, Message, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'_, Key, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Key: Copy + PartialEq + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        State::tree(&self.keys)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.grid.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        keyed::diff(tree, &self.keys, &self.grid.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.grid.size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.grid.layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.grid.operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.grid.update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.grid
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.grid
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.grid
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Grid<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
@@ -0,0 +1,237 @@
+//! Keyed stacks display content on top of other content while keeping continuity.
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size, Vector, Widget};
+use crate::keyed::{self, State};
+use crate::stack::Layer;
+
+/// A container that displays children on top of each other while keeping
+/// continuity.
+///
+/// Just like a [`Stack`](crate::Stack), the first [`Element`] dictates the
+/// intrinsic [`Size`] of the [`Stack`].
+pub struct Stack<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Key: Copy + PartialEq,
+{
+    keys: Vec<Key>,
+    stack: crate::Stack<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Stack<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Stack`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a [`Stack`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            keys: Vec::with_capacity(capacity),
+            stack: crate::Stack::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a [`Stack`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        let iterator = children.into_iter();
+
+        Self::with_capacity(iterator.size_hint().0).extend(iterator)
+    }
+
+    /// Sets the width of the [`Stack`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.stack = self.stack.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.stack = self.stack.height(height);
+        self
+    }
+
+    /// Sets whether the [`Stack`] should clip overflowing content.
+    ///
+    /// By default, it is set to `false`.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.stack = self.stack.clip(clip);
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`].
+    pub fn push(self, key: Key, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_layer(key, child, Layer::default())
+    }
+
+    /// Adds an element to the [`Stack`] with the given [`Layer`] options.
+    pub fn push_layer(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        layer: Layer,
+    ) -> Self {
+        let child = child.into();
+
+        if !child.as_widget().size_hint().is_void() {
+            self.keys.push(key);
+            self.stack = self.stack.push_layer(child, layer);
+        }
+
+        self
+    }
+
+    /// Adds an element under the [`Stack`].
+    pub fn push_under(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.keys.insert(0, key);
+        self.stack = self.stack.push_under(child);
+        self
+    }
+
+    /// Extends the [`Stack`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(self, |stack, (key, child)| stack.push(key, child))
+    }
+}
+
+impl<Key, Message, Renderer> Default for Stack<'_, Key, Message, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Stack<'_, Key, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Key: Copy + PartialEq + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        State::tree(&self.keys)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.stack.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        keyed::diff(tree, &self.keys, &self.stack.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.stack.size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.stack.layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.stack.operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.stack.update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.stack
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.stack
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.stack
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Stack<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(stack: Stack<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(stack)
+    }
+}
@@ -0,0 +1,740 @@
+//! Knobs let users set a value by turning a dial.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::knob;
+//!
+//! struct State {
+//!    gain: f32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     GainChanged(f32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     knob(-12.0..=12.0, state.gain, Message::GainChanged)
+//!         .step(0.5)
+//!         .detents([0.0])
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::GainChanged(gain) => {
+//!             state.gain = gain;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::canvas::{self, LineCap, Path, Stroke, path};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Radians, Rectangle, Shell,
+    Size, Theme, Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+/// The angle of the minimum value of a [`Knob`], clockwise from the positive
+/// x-axis.
+const START_ANGLE: f32 = PI * 0.75;
+
+/// The angle covered by the arc of a [`Knob`].
+const SWEEP: f32 = PI * 1.5;
+
+/// The distance, in pixels, the cursor must be dragged vertically to cover
+/// the whole range of a [`Knob`].
+const DRAG_DISTANCE: f64 = 200.0;
+
+/// The factor applied to dragging while the fine-adjust modifier is pressed.
+const FINE_FACTOR: f64 = 0.1;
+
+/// The distance to a detent, relative to the range of a [`Knob`], under
+/// which values snap to it.
+const DETENT_THRESHOLD: f64 = 0.03;
+
+/// A circular dial that selects a single value from a range of values.
+///
+/// A [`Knob`] is turned by dragging it vertically or, when its [`DragMode`]
+/// is [`Circular`](DragMode::Circular), around its center. Holding shift
+/// while dragging adjusts the value finely.
+///
+/// Like a [`Slider`](crate::Slider), its step size defaults to 1 unit.
+pub struct Knob<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    value: T,
+    default: Option<T>,
+    detents: Vec<T>,
+    drag_mode: DragMode,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    on_release: Option<Message>,
+    size: f32,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+/// The way a [`Knob`] is turned when dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DragMode {
+    /// Dragging up increases the value, and dragging down decreases it.
+    #[default]
+    Vertical,
+    /// Dragging around the center of the [`Knob`] turns it.
+    Circular,
+}
+
+impl<'a, T, Message, Theme> Knob<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// The default size of a [`Knob`].
+    pub const DEFAULT_SIZE: f32 = 48.0;
+
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Knob`]
+    ///   * a function that will be called when the [`Knob`] is turned.
+    ///     It receives the new value of the [`Knob`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value < *range.start() {
+            *range.start()
+        } else if value > *range.end() {
+            *range.end()
+        } else {
+            value
+        };
+
+        Knob {
+            value,
+            default: None,
+            range,
+            step: T::from(1),
+            shift_step: None,
+            detents: Vec::new(),
+            drag_mode: DragMode::default(),
+            on_change: Box::new(on_change),
+            on_release: None,
+            size: Self::DEFAULT_SIZE,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the optional default value for the [`Knob`].
+    ///
+    /// If set, the [`Knob`] will reset to this value when double-clicked,
+    /// ctrl-clicked, or command-clicked.
+    pub fn default(mut self, default: impl Into<T>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets the release message of the [`Knob`].
+    /// This is called when the mouse is released from the knob.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the size of the [`Knob`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`Knob`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`Knob`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the detents of the [`Knob`].
+    ///
+    /// Detents are marked on the arc of the [`Knob`], and values close to
+    /// them snap to them while dragging.
+    pub fn detents(mut self, detents: impl IntoIterator<Item = T>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`DragMode`] of the [`Knob`].
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Knob`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> Knob<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+{
+    fn bounds(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    fn step_for(&self, modifiers: keyboard::Modifiers) -> f64 {
+        if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    /// Returns the position of the given value in the range, from 0 to 1.
+    fn normalize(&self, value: T) -> f64 {
+        let (start, end) = self.bounds();
+
+        if start >= end {
+            0.0
+        } else {
+            ((value.into() - start) / (end - start)).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Publishes the value at the given position of the range, snapped to
+    /// the step and the detents, if it changed.
+    fn change(&mut self, position: f64, step: f64, shell: &mut Shell<'_, Message>) {
+        let (start, end) = self.bounds();
+        let detents: Vec<f64> = self.detents.iter().map(|detent| (*detent).into()).collect();
+
+        let value = snap(
+            start + position * (end - start),
+            (start, end),
+            step,
+            &detents,
+        );
+
+        self.set(value, shell);
+    }
+
+    fn set(&mut self, value: f64, shell: &mut Shell<'_, Message>) {
+        if (self.value.into() - value).abs() <= f64::EPSILON {
+            return;
+        }
+
+        if let Some(value) = T::from_f64(value) {
+            shell.publish((self.on_change)(value));
+
+            self.value = value;
+        }
+    }
+}
+
+/// Snaps the given value to the step of the given range and, if close
+/// enough, to one of the given detents.
+fn snap(value: f64, (start, end): (f64, f64), step: f64, detents: &[f64]) -> f64 {
+    let threshold = (end - start) * DETENT_THRESHOLD;
+
+    if let Some(detent) = detents
+        .iter()
+        .copied()
+        .filter(|detent| (detent - value).abs() <= threshold)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+    {
+        return detent.clamp(start, end);
+    }
+
+    let value = if step > 0.0 {
+        ((value - start) / step).round() * step + start
+    } else {
+        value
+    };
+
+    value.clamp(start, end)
+}
+
+/// Returns the angle of the given position of the range of a [`Knob`].
+fn angle(position: f32) -> f32 {
+    START_ANGLE + position * SWEEP
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Knob<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let step = self.step_for(state.keyboard_modifiers);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                let is_reset = state.keyboard_modifiers.command()
+                    || matches!(click.kind(), mouse::click::Kind::Double);
+
+                if is_reset {
+                    if let Some(default) = self.default {
+                        self.set(default.into(), shell);
+                    }
+
+                    state.drag = None;
+                } else {
+                    state.drag = Some(Drag {
+                        last: position,
+                        position: self.normalize(self.value),
+                    });
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some()
+                    && let Some(on_release) = self.on_release.clone()
+                {
+                    shell.publish(on_release);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return;
+                };
+
+                let Some(position) = cursor.land().position() else {
+                    return;
+                };
+
+                let factor = if state.keyboard_modifiers.shift() {
+                    FINE_FACTOR
+                } else {
+                    1.0
+                };
+
+                let delta = match self.drag_mode {
+                    DragMode::Vertical => f64::from(drag.last.y - position.y) / DRAG_DISTANCE,
+                    DragMode::Circular => {
+                        let center = bounds.center();
+                        let from = (drag.last - center).y.atan2((drag.last - center).x);
+                        let to = (position - center).y.atan2((position - center).x);
+
+                        let mut turn = to - from;
+
+                        if turn > PI {
+                            turn -= 2.0 * PI;
+                        } else if turn < -PI {
+                            turn += 2.0 * PI;
+                        }
+
+                        f64::from(turn / SWEEP)
+                    }
+                };
+
+                drag.last = position;
+                drag.position = (drag.position + delta * factor).clamp(0.0, 1.0);
+
+                let position = drag.position;
+
+                self.change(position, step, shell);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.keyboard_modifiers.control() =>
+            {
+                if cursor.is_over(bounds) {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { x: _, y } => y,
+                        mouse::ScrollDelta::Pixels { x: _, y } => y,
+                    };
+
+                    let value = self.value.into();
+
+                    if *delta < 0.0 {
+                        self.set(snap(value - step, self.bounds(), step, &[]), shell);
+                    } else {
+                        self.set(snap(value + step, self.bounds(), step, &[]), shell);
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                if cursor.is_over(bounds) {
+                    let value = self.value.into();
+
+                    match key {
+                        Key::Named(key::Named::ArrowUp) => {
+                            self.set(snap(value + step, self.bounds(), step, &[]), shell);
+                            shell.capture_event();
+                        }
+                        Key::Named(key::Named::ArrowDown) => {
+                            self.set(snap(value - step, self.bounds(), step, &[]), shell);
+                            shell.capture_event();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let current_status = if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let arc_radius = radius - style.arc_width / 2.0;
+        let body_radius = (radius - style.arc_width - style.arc_gap).max(0.0);
+
+        let arc = |from: f32, to: f32| {
+            Path::new(|builder| {
+                builder.arc(path::Arc {
+                    center,
+                    radius: arc_radius,
+                    start_angle: Radians(angle(from)),
+                    end_angle: Radians(angle(to)),
+                });
+            })
+        };
+
+        let arc_stroke = |color| {
+            Stroke::default()
+                .with_color(color)
+                .with_width(style.arc_width)
+                .with_line_cap(LineCap::Round)
+        };
+
+        let position = self.normalize(self.value) as f32;
+
+        frame.stroke(&arc(0.0, 1.0), arc_stroke(style.arc_background));
+
+        if position > 0.0 {
+            frame.stroke(&arc(0.0, position), arc_stroke(style.arc_color));
+        }
+
+        for detent in &self.detents {
+            let angle = angle(self.normalize(*detent) as f32);
+
+            frame.fill(
+                &Path::circle(
+                    center + Vector::new(angle.cos(), angle.sin()) * arc_radius,
+                    style.arc_width / 4.0,
+                ),
+                style.detent_color,
+            );
+        }
+
+        let body = Path::circle(center, body_radius);
+
+        frame.fill(&body, style.background);
+
+        if style.border_width > 0.0 {
+            frame.stroke(
+                &body,
+                Stroke::default()
+                    .with_color(style.border_color)
+                    .with_width(style.border_width),
+            );
+        }
+
+        let direction = {
+            let angle = angle(position);
+
+            Vector::new(angle.cos(), angle.sin())
+        };
+
+        frame.stroke(
+            &Path::line(
+                center + direction * (body_radius * 0.35),
+                center + direction * (body_radius * 0.85),
+            ),
+            Stroke::default()
+                .with_color(style.indicator_color)
+                .with_width(style.indicator_width)
+                .with_line_cap(LineCap::Round),
+        );
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            // FIXME: Fall back to `Pointer` on Windows
+            // See https://github.com/rust-windowing/winit/issues/1043
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grabbing
+            }
+        } else if cursor.is_over(layout.bounds()) {
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grab
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Knob<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(knob: Knob<'a, T, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(knob)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    last: Point,
+    /// The position in the range, from 0 to 1, before snapping.
+    position: f64,
+}
+
+/// The possible status of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Knob`] can be interacted with.
+    Active,
+    /// The [`Knob`] is being hovered.
+    Hovered,
+    /// The [`Knob`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a knob.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the body of the knob.
+    pub background: Color,
+    /// The border width of the body of the knob.
+    pub border_width: f32,
+    /// The border [`Color`] of the body of the knob.
+    pub border_color: Color,
+    /// The [`Color`] of the indicator of the current value.
+    pub indicator_color: Color,
+    /// The width of the indicator of the current value.
+    pub indicator_width: f32,
+    /// The [`Color`] of the arc up to the current value.
+    pub arc_color: Color,
+    /// The [`Color`] of the rest of the arc.
+    pub arc_background: Color,
+    /// The width of the arc.
+    pub arc_width: f32,
+    /// The gap between the arc and the body of the knob.
+    pub arc_gap: f32,
+    /// The [`Color`] of the detent marks on the arc.
+    pub detent_color: Color,
+}
+
+/// The theme catalog of a [`Knob`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Knob`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Knob`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Active => palette.primary.base.color,
+        Status::Hovered => palette.primary.strong.color,
+        Status::Dragged => palette.primary.weak.color,
+    };
+
+    Style {
+        background: palette.background.weak.color,
+        border_width: 1.0,
+        border_color: palette.background.strong.color,
+        indicator_color: palette.background.weak.text,
+        indicator_width: 2.0,
+        arc_color: color,
+        arc_background: palette.background.strong.color,
+        arc_width: 4.0,
+        arc_gap: 3.0,
+        detent_color: palette.background.base.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_prefers_close_detents() {
+        let range = (-12.0, 12.0);
+
+        assert_eq!(snap(3.3, range, 0.5, &[]), 3.5);
+        assert_eq!(snap(0.6, range, 0.5, &[0.0]), 0.0);
+        assert_eq!(snap(0.9, range, 0.5, &[0.0]), 1.0);
+        assert_eq!(snap(20.0, range, 0.5, &[]), 12.0);
+    }
+}
@@ -11,37 +11,69 @@ pub use core::widget::Id;
 
 mod action;
 mod column;
+mod history;
 mod mouse_area;
 mod pin;
-mod responsive;
-mod stack;
 mod themer;
 
+#[cfg(any(feature = "image", feature = "svg"))]
+mod mask;
+
+pub mod autocomplete;
+pub mod breadcrumbs;
 pub mod button;
+pub mod calendar;
 pub mod checkbox;
+pub mod collapsible;
+pub mod color_picker;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod dock;
+pub mod file_browser;
 pub mod float;
 pub mod grid;
 pub mod keyed;
+pub mod masked_input;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod popover;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
+pub mod reorderable_column;
+pub mod responsive;
+pub mod rich_editor;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented;
 pub mod sensor;
+pub mod skeleton;
 pub mod slider;
 pub mod space;
+pub mod sparkline;
+pub mod stack;
+pub mod steps;
+pub mod sticky;
 pub mod table;
+pub mod tabs;
+pub mod tags_input;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod timeline;
+pub mod toast;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree;
 pub mod vertical_slider;
+pub mod virtual_list;
 
 mod helpers;
 
@@ -53,49 +85,97 @@ mod lazy;
 #[cfg(feature = "lazy")]
 pub use crate::lazy::helpers::*;
 
+#[doc(no_inline)]
+pub use autocomplete::Autocomplete;
+#[doc(no_inline)]
+pub use breadcrumbs::Breadcrumbs;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use calendar::Calendar;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::Collapsible;
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use dock::Dock;
+#[doc(no_inline)]
+pub use file_browser::FileBrowser;
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use masked_input::MaskedInput;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use pin::Pin;
 #[doc(no_inline)]
+pub use popover::Popover;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
 pub use responsive::Responsive;
 #[doc(no_inline)]
+pub use rich_editor::RichEditor;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use segmented::Segmented;
+#[doc(no_inline)]
 pub use sensor::Sensor;
 #[doc(no_inline)]
+pub use skeleton::Skeleton;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use sparkline::Sparkline;
+#[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use steps::Steps;
+#[doc(no_inline)]
+pub use sticky::Sticky;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tags_input::TagsInput;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -104,11 +184,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use timeline::Timeline;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree::Tree;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -131,6 +217,13 @@ pub mod image;
 #[doc(no_inline)]
 pub use image::Image;
 
+#[cfg(any(feature = "image", feature = "svg"))]
+pub mod nine_patch;
+
+#[cfg(any(feature = "image", feature = "svg"))]
+#[doc(no_inline)]
+pub use nine_patch::NinePatch;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
@@ -138,6 +231,27 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+pub mod chart;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use chart::Chart;
+
+#[cfg(feature = "canvas")]
+pub mod knob;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use knob::Knob;
+
+#[cfg(feature = "canvas")]
+pub mod spinner;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use spinner::Spinner;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
@@ -145,9 +259,44 @@ pub mod qr_code;
 #[doc(no_inline)]
 pub use qr_code::QRCode;
 
+#[cfg(feature = "lottie")]
+pub mod lottie;
+
+#[cfg(feature = "lottie")]
+#[doc(no_inline)]
+pub use lottie::Lottie;
+
 #[cfg(feature = "markdown")]
 pub mod markdown;
 
+#[cfg(feature = "terminal")]
+pub mod terminal;
+
+#[cfg(feature = "terminal")]
+#[doc(no_inline)]
+pub use terminal::Terminal;
+
+#[cfg(feature = "video")]
+pub mod video;
+
+#[cfg(feature = "video")]
+#[doc(no_inline)]
+pub use video::VideoPlayer;
+
+#[cfg(feature = "web_view")]
+pub mod web_view;
+
+#[cfg(feature = "web_view")]
+#[doc(no_inline)]
+pub use web_view::WebView;
+
+#[cfg(feature = "map")]
+pub mod map;
+
+#[cfg(feature = "map")]
+#[doc(no_inline)]
+pub use map::Map;
+
 pub use crate::core::theme::{self, Theme};
 pub use action::Action;
 pub use renderer::Renderer;
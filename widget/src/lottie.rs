@@ -0,0 +1,424 @@
+//! Lotties play vector animations exported in the Bodymovin JSON format.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::lottie;
+//!
+//! struct State {
+//!    animation: lottie::Handle,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     lottie(&state.animation).into()
+//! }
+//! ```
+mod composition;
+
+use crate::Renderer;
+use crate::canvas;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use composition::Composition;
+
+use std::cell::Cell;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A vector animation player.
+///
+/// A [`Lottie`] plays a [`Handle`] loaded from Bodymovin JSON, scaling it
+/// smoothly to its bounds.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::lottie;
+///
+/// struct State {
+///    animation: lottie::Handle,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     lottie(&state.animation).speed(2.0).segment(0.0..30.0).into()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Lottie {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    playing: bool,
+    looping: bool,
+    speed: f32,
+    segment: Option<Range<f32>>,
+}
+
+impl Lottie {
+    /// Creates a new [`Lottie`] playing the given [`Handle`].
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            playing: true,
+            looping: true,
+            speed: 1.0,
+            segment: None,
+        }
+    }
+
+    /// Sets the width of the [`Lottie`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Lottie`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Lottie`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    #[must_use]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets whether the [`Lottie`] should be playing.
+    ///
+    /// When paused, the [`Lottie`] keeps its current frame and resumes from it
+    /// when played again.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Sets whether the [`Lottie`] should start over once it reaches the end
+    /// of its segment.
+    ///
+    /// When not looping, the [`Lottie`] stops at its last frame.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the playback speed of the [`Lottie`].
+    ///
+    /// Defaults to `1.0`.
+    #[must_use]
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the range of frames of the [`Handle`] that the [`Lottie`] plays.
+    ///
+    /// Defaults to [`Handle::frames`].
+    #[must_use]
+    pub fn segment(mut self, frames: Range<f32>) -> Self {
+        self.segment = Some(frames);
+        self
+    }
+
+    /// Returns the frame to display after the given elapsed time, and whether
+    /// the playback has finished.
+    fn frame(&self, elapsed: Duration) -> (f32, bool) {
+        let composition = &self.handle.composition;
+        let Range { start, end } = self.segment.clone().unwrap_or_else(|| self.handle.frames());
+
+        let start = start.clamp(composition.in_point, composition.out_point);
+        let end = end.clamp(start, composition.out_point);
+        let length = end - start;
+
+        if length <= 0.0 {
+            return (start, true);
+        }
+
+        let frames = elapsed.as_secs_f32() * composition.frame_rate;
+
+        if self.looping {
+            (start + frames % length, false)
+        } else {
+            (
+                start + frames.min((length - 1.0).max(0.0)),
+                frames >= length,
+            )
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Lottie {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let animation_size = self.handle.size();
+
+        // The size to be available to the widget prior to `Shrink`ing
+        let raw_size = limits.resolve(self.width, self.height, animation_size);
+
+        // The uncropped size of the animation when fit to the bounds above
+        let full_size = self.content_fit.fit(animation_size, raw_size);
+
+        // Shrink the widget to fit the resized animation, if requested
+        let final_size = Size {
+            width: match self.width {
+                Length::Shrink => f32::min(raw_size.width, full_size.width),
+                _ => raw_size.width,
+            },
+            height: match self.height {
+                Length::Shrink => f32::min(raw_size.height, full_size.height),
+                _ => raw_size.height,
+            },
+        };
+
+        layout::Node::new(final_size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.playing {
+            state.last_tick = None;
+            return;
+        }
+
+        if let Some(last_tick) = state.last_tick {
+            state.elapsed += now
+                .saturating_duration_since(last_tick)
+                .mul_f32(self.speed.max(0.0));
+        }
+
+        state.last_tick = Some(*now);
+
+        let (_, finished) = self.frame(state.elapsed);
+
+        if !finished {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let animation_size = self.handle.size();
+        let fitted_size = self.content_fit.fit(animation_size, bounds.size());
+
+        let scale = Vector::new(
+            fitted_size.width / animation_size.width,
+            fitted_size.height / animation_size.height,
+        );
+
+        let offset = Vector::new(
+            (bounds.width - fitted_size.width) / 2.0,
+            (bounds.height - fitted_size.height) / 2.0,
+        );
+
+        let Some(clip_bounds) =
+            Rectangle::new(bounds.position() + offset, fitted_size).intersection(&bounds)
+        else {
+            return;
+        };
+
+        let (time, _) = self.frame(state.elapsed);
+
+        if state.time.get() != Some(time) {
+            state.cache.clear();
+            state.time.set(Some(time));
+        }
+
+        let geometry = state.cache.draw(renderer, bounds.size(), |frame| {
+            frame.translate(offset);
+            frame.scale_nonuniform(scale);
+
+            self.handle.composition.draw(frame, time);
+        });
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+                use crate::graphics::geometry::Renderer as _;
+
+                renderer.draw_geometry(geometry);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Lottie> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a,
+{
+    fn from(lottie: Lottie) -> Self {
+        Self::new(lottie)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    time: Cell<Option<f32>>,
+    cache: canvas::Cache<Renderer>,
+}
+
+/// A handle of a Bodymovin animation.
+///
+/// Cloning a [`Handle`] is cheap; the parsed animation is shared.
+#[derive(Clone)]
+pub struct Handle {
+    composition: Arc<Composition>,
+}
+
+impl Handle {
+    /// Loads a [`Handle`] from the Bodymovin JSON file at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path).map_err(|error| Error::Inaccessible(Arc::new(error)))?;
+
+        Self::from_memory(bytes)
+    }
+
+    /// Parses a [`Handle`] from Bodymovin JSON in memory.
+    pub fn from_memory(bytes: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let json: serde_json::Value = serde_json::from_slice(bytes.as_ref())
+            .map_err(|error| Error::Invalid(Arc::new(error)))?;
+
+        let composition = Composition::parse(&json).ok_or(Error::Unsupported)?;
+
+        Ok(Self {
+            composition: Arc::new(composition),
+        })
+    }
+
+    /// Returns the size of the animation, in logical pixels.
+    pub fn size(&self) -> Size {
+        self.composition.size
+    }
+
+    /// Returns the amount of frames per second of the animation.
+    pub fn frame_rate(&self) -> f32 {
+        self.composition.frame_rate
+    }
+
+    /// Returns the range of frames of the animation.
+    pub fn frames(&self) -> Range<f32> {
+        self.composition.in_point..self.composition.out_point
+    }
+
+    /// Returns the total duration of the animation at normal speed.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(
+            (self.composition.out_point - self.composition.in_point) / self.composition.frame_rate,
+        )
+    }
+}
+
+impl From<&Handle> for Handle {
+    fn from(handle: &Handle) -> Self {
+        handle.clone()
+    }
+}
+
+impl fmt::Debug for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("size", &self.size())
+            .field("frames", &self.frames())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error that occurred when loading a [`Handle`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The animation file could not be opened.
+    #[error("the animation file could not be opened: {0}")]
+    Inaccessible(Arc<io::Error>),
+    /// The animation data is not valid JSON.
+    #[error("the animation data is not valid JSON: {0}")]
+    Invalid(Arc<serde_json::Error>),
+    /// The animation data is not a Bodymovin composition.
+    #[error("the animation data is not a Bodymovin composition")]
+    Unsupported,
+}
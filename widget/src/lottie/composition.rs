@@ -0,0 +1,685 @@
+use crate::canvas::path::{self, arc};
+use crate::canvas::{Fill, Frame, LineCap, LineJoin, Path, Stroke, Style, fill};
+use crate::core::{Color, Degrees, Point, Radians, Size, Vector};
+
+use serde_json::Value;
+
+/// The maximum depth of a chain of parent layers.
+const MAX_PARENT_DEPTH: usize = 32;
+
+/// A Bodymovin composition.
+///
+/// Only shape, solid, and null layers are drawn. Precompositions, images,
+/// text, masks, mattes, gradients, and shape modifiers are ignored.
+#[derive(Debug)]
+pub struct Composition {
+    pub size: Size,
+    pub frame_rate: f32,
+    pub in_point: f32,
+    pub out_point: f32,
+    layers: Vec<Layer>,
+}
+
+impl Composition {
+    /// Parses a [`Composition`] from its JSON representation.
+    pub fn parse(json: &Value) -> Option<Self> {
+        let width = number(json.get("w")?)?;
+        let height = number(json.get("h")?)?;
+        let frame_rate = number(json.get("fr")?)?;
+        let in_point = number(json.get("ip")?)?;
+        let out_point = number(json.get("op")?)?;
+
+        if width <= 0.0 || height <= 0.0 || frame_rate <= 0.0 {
+            return None;
+        }
+
+        let layers = json
+            .get("layers")?
+            .as_array()?
+            .iter()
+            .filter_map(Layer::parse)
+            .collect();
+
+        Some(Self {
+            size: Size::new(width, height),
+            frame_rate,
+            in_point,
+            out_point: out_point.max(in_point),
+            layers,
+        })
+    }
+
+    /// Draws the [`Composition`] at the given frame.
+    pub fn draw(&self, frame: &mut Frame, time: f32) {
+        for layer in self.layers.iter().rev() {
+            if layer.hidden
+                || matches!(layer.content, Content::Null)
+                || time < layer.in_point
+                || time >= layer.out_point
+            {
+                continue;
+            }
+
+            frame.with_save(|frame| {
+                self.apply_parents(frame, layer, time, 0);
+
+                let time = time - layer.start_time;
+
+                layer.transform.apply(frame, time);
+                layer.draw(frame, time, layer.transform.opacity(time));
+            });
+        }
+    }
+
+    fn apply_parents(&self, frame: &mut Frame, layer: &Layer, time: f32, depth: usize) {
+        if depth >= MAX_PARENT_DEPTH {
+            return;
+        }
+
+        let Some(parent) = layer
+            .parent
+            .and_then(|index| self.layers.iter().find(|layer| layer.index == Some(index)))
+        else {
+            return;
+        };
+
+        self.apply_parents(frame, parent, time, depth + 1);
+        parent.transform.apply(frame, time - parent.start_time);
+    }
+}
+
+#[derive(Debug)]
+struct Layer {
+    index: Option<i64>,
+    parent: Option<i64>,
+    in_point: f32,
+    out_point: f32,
+    start_time: f32,
+    hidden: bool,
+    transform: Transform,
+    content: Content,
+}
+
+#[derive(Debug)]
+enum Content {
+    Shapes(Vec<Shape>),
+    Solid { size: Size, color: Color },
+    Null,
+}
+
+impl Layer {
+    fn parse(json: &Value) -> Option<Self> {
+        let content = match json.get("ty")?.as_i64()? {
+            1 => Content::Solid {
+                size: Size::new(
+                    json.get("sw").and_then(number).unwrap_or_default(),
+                    json.get("sh").and_then(number).unwrap_or_default(),
+                ),
+                color: json
+                    .get("sc")
+                    .and_then(Value::as_str)
+                    .and_then(|color| color.parse().ok())
+                    .unwrap_or(Color::TRANSPARENT),
+            },
+            4 => Content::Shapes(shapes(json.get("shapes"))),
+            _ => Content::Null,
+        };
+
+        Some(Self {
+            index: json.get("ind").and_then(Value::as_i64),
+            parent: json.get("parent").and_then(Value::as_i64),
+            in_point: json.get("ip").and_then(number).unwrap_or(f32::MIN),
+            out_point: json.get("op").and_then(number).unwrap_or(f32::MAX),
+            start_time: json.get("st").and_then(number).unwrap_or_default(),
+            hidden: is_hidden(json),
+            transform: Transform::parse(json.get("ks")),
+            content,
+        })
+    }
+
+    fn draw(&self, frame: &mut Frame, time: f32, opacity: f32) {
+        match &self.content {
+            Content::Shapes(shapes) => draw_shapes(shapes, frame, time, opacity),
+            Content::Solid { size, color } => {
+                frame.fill_rectangle(Point::ORIGIN, *size, color.scale_alpha(opacity));
+            }
+            Content::Null => {}
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Transform {
+    anchor: Property,
+    position: Property,
+    scale: Property,
+    rotation: Property,
+    opacity: Property,
+}
+
+impl Transform {
+    fn parse(json: Option<&Value>) -> Self {
+        let property = |key: &str, default: &[f32]| {
+            Property::parse(json.and_then(|json| json.get(key)), default)
+        };
+
+        Self {
+            anchor: property("a", &[0.0, 0.0]),
+            position: property("p", &[0.0, 0.0]),
+            scale: property("s", &[100.0, 100.0]),
+            rotation: if json.is_some_and(|json| json.get("r").is_none()) {
+                property("rz", &[0.0])
+            } else {
+                property("r", &[0.0])
+            },
+            opacity: property("o", &[100.0]),
+        }
+    }
+
+    fn apply(&self, frame: &mut Frame, time: f32) {
+        frame.translate(self.position.vector(time));
+
+        let rotation = self.rotation.scalar(time);
+
+        if rotation != 0.0 {
+            frame.rotate(Degrees(rotation));
+        }
+
+        frame.scale_nonuniform(self.scale.vector(time) * 0.01);
+        frame.translate(-self.anchor.vector(time));
+    }
+
+    fn opacity(&self, time: f32) -> f32 {
+        (self.opacity.scalar(time) / 100.0).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug)]
+enum Shape {
+    Group {
+        items: Vec<Shape>,
+        transform: Transform,
+    },
+    Rectangle {
+        position: Property,
+        size: Property,
+        roundness: Property,
+    },
+    Ellipse {
+        position: Property,
+        size: Property,
+    },
+    Path {
+        bezier: Property,
+    },
+    Fill {
+        color: Property,
+        opacity: Property,
+        rule: fill::Rule,
+    },
+    Stroke {
+        color: Property,
+        opacity: Property,
+        width: Property,
+        line_cap: LineCap,
+        line_join: LineJoin,
+    },
+}
+
+fn shapes(json: Option<&Value>) -> Vec<Shape> {
+    json.and_then(Value::as_array)
+        .map(|items| items.iter().filter_map(Shape::parse).collect())
+        .unwrap_or_default()
+}
+
+impl Shape {
+    fn parse(json: &Value) -> Option<Self> {
+        if is_hidden(json) {
+            return None;
+        }
+
+        let property = |key: &str, default: &[f32]| Property::parse(json.get(key), default);
+
+        let shape = match json.get("ty")?.as_str()? {
+            "gr" => {
+                let items = json.get("it")?.as_array()?;
+
+                Shape::Group {
+                    items: items.iter().filter_map(Shape::parse).collect(),
+                    transform: Transform::parse(
+                        items
+                            .iter()
+                            .find(|item| item.get("ty").and_then(Value::as_str) == Some("tr")),
+                    ),
+                }
+            }
+            "rc" => Shape::Rectangle {
+                position: property("p", &[0.0, 0.0]),
+                size: property("s", &[0.0, 0.0]),
+                roundness: property("r", &[0.0]),
+            },
+            "el" => Shape::Ellipse {
+                position: property("p", &[0.0, 0.0]),
+                size: property("s", &[0.0, 0.0]),
+            },
+            "sh" => Shape::Path {
+                bezier: property("ks", &[]),
+            },
+            "fl" => Shape::Fill {
+                color: property("c", &[0.0, 0.0, 0.0, 1.0]),
+                opacity: property("o", &[100.0]),
+                rule: if json.get("r").and_then(Value::as_i64) == Some(2) {
+                    fill::Rule::EvenOdd
+                } else {
+                    fill::Rule::NonZero
+                },
+            },
+            "st" => Shape::Stroke {
+                color: property("c", &[0.0, 0.0, 0.0, 1.0]),
+                opacity: property("o", &[100.0]),
+                width: property("w", &[1.0]),
+                line_cap: match json.get("lc").and_then(Value::as_i64) {
+                    Some(2) => LineCap::Round,
+                    Some(3) => LineCap::Square,
+                    _ => LineCap::Butt,
+                },
+                line_join: match json.get("lj").and_then(Value::as_i64) {
+                    Some(2) => LineJoin::Round,
+                    Some(3) => LineJoin::Bevel,
+                    _ => LineJoin::Miter,
+                },
+            },
+            _ => return None,
+        };
+
+        Some(shape)
+    }
+
+    fn trace(&self, builder: &mut path::Builder, time: f32) {
+        match self {
+            Shape::Rectangle {
+                position,
+                size,
+                roundness,
+            } => {
+                let center = Point::ORIGIN + position.vector(time);
+                let size = size.vector(time);
+                let top_left = center - size * 0.5;
+                let size = Size::new(size.x, size.y);
+
+                let radius = roundness
+                    .scalar(time)
+                    .min(size.width / 2.0)
+                    .min(size.height / 2.0);
+
+                if radius > 0.0 {
+                    builder.rounded_rectangle(top_left, size, radius.into());
+                } else {
+                    builder.rectangle(top_left, size);
+                }
+            }
+            Shape::Ellipse { position, size } => {
+                builder.ellipse(arc::Elliptical {
+                    center: Point::ORIGIN + position.vector(time),
+                    radii: size.vector(time) * 0.5,
+                    rotation: Radians(0.0),
+                    start_angle: Radians(0.0),
+                    end_angle: Radians(2.0 * std::f32::consts::PI),
+                });
+            }
+            Shape::Path { bezier } => {
+                let bezier = bezier.value(time);
+
+                let Some((closed, vertices)) = bezier.split_first() else {
+                    return;
+                };
+
+                let vertices: Vec<_> = vertices
+                    .chunks_exact(6)
+                    .map(|vertex| {
+                        (
+                            Point::new(vertex[0], vertex[1]),
+                            Vector::new(vertex[2], vertex[3]),
+                            Vector::new(vertex[4], vertex[5]),
+                        )
+                    })
+                    .collect();
+
+                let Some((start, _, _)) = vertices.first() else {
+                    return;
+                };
+
+                let closed = *closed != 0.0;
+                let segments = if closed {
+                    vertices.len()
+                } else {
+                    vertices.len() - 1
+                };
+
+                builder.move_to(*start);
+
+                for (i, (from, _, out_tangent)) in vertices.iter().enumerate().take(segments) {
+                    let (to, in_tangent, _) = vertices[(i + 1) % vertices.len()];
+
+                    builder.bezier_curve_to(*from + *out_tangent, to + in_tangent, to);
+                }
+
+                if closed {
+                    builder.close();
+                }
+            }
+            Shape::Group { .. } | Shape::Fill { .. } | Shape::Stroke { .. } => {}
+        }
+    }
+}
+
+fn draw_shapes(shapes: &[Shape], frame: &mut Frame, time: f32, opacity: f32) {
+    // Items at the top of the list are drawn over the ones below, and
+    // styles apply to all the geometry that precedes them.
+    for (i, shape) in shapes.iter().enumerate().rev() {
+        let geometry = || {
+            Path::new(|builder| {
+                for shape in &shapes[..i] {
+                    shape.trace(builder, time);
+                }
+            })
+        };
+
+        match shape {
+            Shape::Group { items, transform } => {
+                frame.with_save(|frame| {
+                    transform.apply(frame, time);
+                    draw_shapes(items, frame, time, opacity * transform.opacity(time));
+                });
+            }
+            Shape::Fill {
+                color,
+                opacity: fill_opacity,
+                rule,
+            } => {
+                frame.fill(
+                    &geometry(),
+                    Fill {
+                        style: Style::Solid(to_color(
+                            &color.value(time),
+                            opacity * fill_opacity.scalar(time) / 100.0,
+                        )),
+                        rule: *rule,
+                    },
+                );
+            }
+            Shape::Stroke {
+                color,
+                opacity: stroke_opacity,
+                width,
+                line_cap,
+                line_join,
+            } => {
+                frame.stroke(
+                    &geometry(),
+                    Stroke {
+                        style: Style::Solid(to_color(
+                            &color.value(time),
+                            opacity * stroke_opacity.scalar(time) / 100.0,
+                        )),
+                        width: width.scalar(time),
+                        line_cap: *line_cap,
+                        line_join: *line_join,
+                        ..Stroke::default()
+                    },
+                );
+            }
+            Shape::Rectangle { .. } | Shape::Ellipse { .. } | Shape::Path { .. } => {}
+        }
+    }
+}
+
+/// An animatable value of a [`Composition`].
+///
+/// Shape paths are flattened as a closed flag followed by the vertex,
+/// in tangent, and out tangent of every point.
+#[derive(Debug)]
+enum Property {
+    Static(Vec<f32>),
+    Animated(Vec<Keyframe>),
+    Split(Box<Property>, Box<Property>),
+}
+
+#[derive(Debug)]
+struct Keyframe {
+    time: f32,
+    start: Vec<f32>,
+    end: Option<Vec<f32>>,
+    hold: bool,
+    easing: [f32; 4],
+}
+
+impl Property {
+    fn parse(json: Option<&Value>, default: &[f32]) -> Self {
+        let Some(json) = json else {
+            return Self::Static(default.to_vec());
+        };
+
+        if json.get("s").and_then(Value::as_bool) == Some(true) {
+            return Self::Split(
+                Box::new(Self::parse(json.get("x"), &default[..1.min(default.len())])),
+                Box::new(Self::parse(json.get("y"), &default[1.min(default.len())..])),
+            );
+        }
+
+        let Some(value) = json.get("k") else {
+            return Self::Static(default.to_vec());
+        };
+
+        let Some(keyframes) = value.as_array().filter(|keyframes| {
+            keyframes
+                .first()
+                .is_some_and(|first| first.get("t").is_some())
+        }) else {
+            return Self::Static(flatten(value));
+        };
+
+        let mut previous_end: Option<Vec<f32>> = None;
+
+        let keyframes = keyframes
+            .iter()
+            .filter_map(|keyframe| {
+                let start = keyframe
+                    .get("s")
+                    .map(flatten)
+                    .or_else(|| previous_end.clone())?;
+
+                let end = keyframe.get("e").map(flatten);
+                previous_end.clone_from(&end);
+
+                let tangent = |key: &str, axis: &str, default: f32| {
+                    keyframe
+                        .get(key)
+                        .and_then(|tangent| tangent.get(axis))
+                        .and_then(|value| {
+                            value
+                                .as_array()
+                                .and_then(|values| values.first())
+                                .or(Some(value))
+                        })
+                        .and_then(number)
+                        .unwrap_or(default)
+                };
+
+                Some(Keyframe {
+                    time: keyframe.get("t").and_then(number)?,
+                    start,
+                    end,
+                    hold: keyframe.get("h").and_then(Value::as_i64) == Some(1),
+                    easing: [
+                        tangent("o", "x", 0.0),
+                        tangent("o", "y", 0.0),
+                        tangent("i", "x", 1.0),
+                        tangent("i", "y", 1.0),
+                    ],
+                })
+            })
+            .collect();
+
+        Self::Animated(keyframes)
+    }
+
+    fn value(&self, time: f32) -> Vec<f32> {
+        match self {
+            Self::Static(value) => value.clone(),
+            Self::Split(x, y) => vec![x.scalar(time), y.scalar(time)],
+            Self::Animated(keyframes) => {
+                let Some(first) = keyframes.first() else {
+                    return Vec::new();
+                };
+
+                if time <= first.time {
+                    return first.start.clone();
+                }
+
+                let i = keyframes
+                    .iter()
+                    .rposition(|keyframe| keyframe.time <= time)
+                    .unwrap_or(0);
+
+                let current = &keyframes[i];
+
+                let Some(next) = keyframes.get(i + 1) else {
+                    return current.start.clone();
+                };
+
+                if current.hold {
+                    return current.start.clone();
+                }
+
+                let end = current.end.as_ref().unwrap_or(&next.start);
+
+                if end.len() != current.start.len() {
+                    return current.start.clone();
+                }
+
+                let span = next.time - current.time;
+                let progress = if span > 0.0 {
+                    ease(current.easing, (time - current.time) / span)
+                } else {
+                    1.0
+                };
+
+                current
+                    .start
+                    .iter()
+                    .zip(end)
+                    .map(|(start, end)| start + (end - start) * progress)
+                    .collect()
+            }
+        }
+    }
+
+    fn scalar(&self, time: f32) -> f32 {
+        self.value(time).first().copied().unwrap_or_default()
+    }
+
+    fn vector(&self, time: f32) -> Vector {
+        let value = self.value(time);
+
+        Vector::new(
+            value.first().copied().unwrap_or_default(),
+            value.get(1).copied().unwrap_or_default(),
+        )
+    }
+}
+
+/// Solves the cubic bezier easing curve defined by the given control points
+/// for the given progress.
+fn ease([x1, y1, x2, y2]: [f32; 4], progress: f32) -> f32 {
+    let bezier = |a: f32, b: f32, t: f32| {
+        let u = 1.0 - t;
+
+        3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+    };
+
+    let progress = progress.clamp(0.0, 1.0);
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..24 {
+        let middle = (low + high) / 2.0;
+
+        if bezier(x1, x2, middle) < progress {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    bezier(y1, y2, (low + high) / 2.0)
+}
+
+fn flatten(json: &Value) -> Vec<f32> {
+    match json {
+        Value::Number(_) => number(json).into_iter().collect(),
+        Value::Array(values) => values.iter().flat_map(flatten).collect(),
+        Value::Object(_) => {
+            let points = |key: &str| {
+                json.get(key)
+                    .and_then(Value::as_array)
+                    .map(|points| points.iter().map(flatten).collect::<Vec<_>>())
+                    .unwrap_or_default()
+            };
+
+            let vertices = points("v");
+            let in_tangents = points("i");
+            let out_tangents = points("o");
+
+            let mut bezier = vec![if json.get("c").and_then(Value::as_bool) == Some(true) {
+                1.0
+            } else {
+                0.0
+            }];
+
+            for (i, vertex) in vertices.iter().enumerate() {
+                let coordinate =
+                    |points: &[f32], axis: usize| points.get(axis).copied().unwrap_or_default();
+
+                let in_tangent = in_tangents.get(i).map(Vec::as_slice).unwrap_or_default();
+                let out_tangent = out_tangents.get(i).map(Vec::as_slice).unwrap_or_default();
+
+                bezier.extend([
+                    coordinate(vertex, 0),
+                    coordinate(vertex, 1),
+                    coordinate(in_tangent, 0),
+                    coordinate(in_tangent, 1),
+                    coordinate(out_tangent, 0),
+                    coordinate(out_tangent, 1),
+                ]);
+            }
+
+            bezier
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn to_color(components: &[f32], opacity: f32) -> Color {
+    let scale = if components.iter().take(3).any(|component| *component > 1.0) {
+        255.0
+    } else {
+        1.0
+    };
+
+    let channel = |i: usize| components.get(i).map_or(0.0, |component| component / scale);
+
+    Color::from_rgba(
+        channel(0),
+        channel(1),
+        channel(2),
+        components.get(3).copied().unwrap_or(1.0).min(1.0) * opacity.clamp(0.0, 1.0),
+    )
+}
+
+fn number(json: &Value) -> Option<f32> {
+    json.as_f64().map(|number| number as f32)
+}
+
+fn is_hidden(json: &Value) -> bool {
+    json.get("hd").and_then(Value::as_bool) == Some(true)
+}
@@ -0,0 +1,1176 @@
+//! Maps display the raster tiles of a slippy map, with markers and polylines
+//! on top.
+//!
+//! A [`Map`] does not fetch its tiles by itself. Instead, it asks for the
+//! missing tiles of its viewport with a [`Request`], which you can fulfill
+//! with the HTTP client of your choice before storing the response in the
+//! [`Tiles`] cache.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::map;
+//! use iced::widget::map::{Coordinate, Tiles};
+//!
+//! struct State {
+//!     tiles: Tiles,
+//!     viewport: map::Viewport,
+//! }
+//!
+//! enum Message {
+//!     ViewportChanged(map::Viewport),
+//!     TileRequested(map::Request),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     map(&state.tiles, state.viewport)
+//!         .marker(map::marker(Coordinate::new(48.8584, 2.2945)).label("Eiffel Tower"))
+//!         .on_viewport_change(Message::ViewportChanged)
+//!         .on_request(Message::TileRequested)
+//!         .into()
+//! }
+//! ```
+use crate::canvas::{self, LineCap, LineJoin, Path, Stroke};
+use crate::core::alignment;
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Bytes, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme, Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+/// The largest latitude that can be displayed by the Web Mercator
+/// projection.
+const MAX_LATITUDE: f64 = 85.051_128_78;
+
+/// The zoom levels changed by every line scrolled.
+const ZOOM_STEP: f32 = 0.5;
+
+/// The amount of lower zoom levels searched for a tile to display instead
+/// of a missing one.
+const FALLBACK_LEVELS: u8 = 4;
+
+/// The space around the attribution of a [`Map`].
+const PADDING: f32 = 4.0;
+
+/// The approximate width of a character of the labels of a [`Map`],
+/// relative to their text size.
+const CHARACTER_WIDTH: f32 = 0.6;
+
+/// A geographic coordinate, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coordinate {
+    /// The latitude of the [`Coordinate`], positive towards the north.
+    pub latitude: f64,
+    /// The longitude of the [`Coordinate`], positive towards the east.
+    pub longitude: f64,
+}
+
+impl Coordinate {
+    /// Creates a new [`Coordinate`] with the given latitude and longitude.
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Projects the [`Coordinate`] into the Web Mercator square, where the
+    /// whole world spans from `0.0` to `1.0`.
+    fn project(self) -> (f64, f64) {
+        let latitude = self
+            .latitude
+            .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+            .to_radians();
+
+        (
+            (self.longitude + 180.0) / 360.0,
+            (1.0 - (latitude.tan() + 1.0 / latitude.cos()).ln() / PI) / 2.0,
+        )
+    }
+
+    /// Returns the [`Coordinate`] at the given position of the Web Mercator
+    /// square.
+    fn unproject((x, y): (f64, f64)) -> Self {
+        let y = y.clamp(0.0, 1.0);
+
+        Self {
+            latitude: (PI * (1.0 - 2.0 * y)).sinh().atan().to_degrees(),
+            longitude: x.rem_euclid(1.0) * 360.0 - 180.0,
+        }
+    }
+}
+
+/// The visible area of a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The [`Coordinate`] at the center of the [`Map`].
+    pub center: Coordinate,
+    /// The zoom level of the [`Map`].
+    ///
+    /// At zoom level `0.0`, the whole world fits in a single tile; every
+    /// additional level doubles its size.
+    pub zoom: f32,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] centered at the given [`Coordinate`] with
+    /// the given zoom level.
+    pub fn new(center: Coordinate, zoom: f32) -> Self {
+        Self { center, zoom }
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new(Coordinate::default(), 2.0)
+    }
+}
+
+/// A tile of a [`Source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The column of the [`Tile`], from the west.
+    pub x: u32,
+    /// The row of the [`Tile`], from the north.
+    pub y: u32,
+    /// The zoom level of the [`Tile`].
+    pub zoom: u8,
+}
+
+impl Tile {
+    /// Returns the [`Tile`] containing this one the given amount of zoom
+    /// levels below.
+    fn parent(self, levels: u8) -> Self {
+        Self {
+            x: self.x >> levels,
+            y: self.y >> levels,
+            zoom: self.zoom - levels,
+        }
+    }
+}
+
+/// A server of raster tiles with an OSM-style URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    template: String,
+    subdomains: Vec<String>,
+    tile_size: u32,
+    max_zoom: u8,
+    attribution: Option<String>,
+}
+
+impl Source {
+    /// Creates a new [`Source`] with the given URL template.
+    ///
+    /// The `{z}`, `{x}`, and `{y}` placeholders of the template are replaced
+    /// with the zoom level, column, and row of every [`Tile`]; `{s}` is
+    /// replaced with one of its [`subdomains`](Self::subdomains).
+    pub fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+            subdomains: Vec::new(),
+            tile_size: 256,
+            max_zoom: 19,
+            attribution: None,
+        }
+    }
+
+    /// Creates a new [`Source`] serving the standard tiles of OpenStreetMap.
+    ///
+    /// Make sure to follow its [tile usage policy] if you use it!
+    ///
+    /// [tile usage policy]: https://operations.osmfoundation.org/policies/tiles/
+    pub fn openstreetmap() -> Self {
+        Self::new("https://tile.openstreetmap.org/{z}/{x}/{y}.png")
+            .attribution("© OpenStreetMap contributors")
+    }
+
+    /// Sets the subdomains that replace the `{s}` placeholder of the
+    /// [`Source`].
+    pub fn subdomains(mut self, subdomains: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.subdomains = subdomains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the size of the tiles of the [`Source`], in pixels.
+    pub fn tile_size(mut self, tile_size: u32) -> Self {
+        self.tile_size = tile_size.max(1);
+        self
+    }
+
+    /// Sets the highest zoom level served by the [`Source`].
+    pub fn max_zoom(mut self, max_zoom: u8) -> Self {
+        self.max_zoom = max_zoom.min(30);
+        self
+    }
+
+    /// Sets the attribution displayed in the corner of the [`Map`].
+    pub fn attribution(mut self, attribution: impl Into<String>) -> Self {
+        self.attribution = Some(attribution.into());
+        self
+    }
+
+    /// Returns the URL of the given [`Tile`].
+    pub fn url(&self, tile: Tile) -> String {
+        let subdomain = if self.subdomains.is_empty() {
+            ""
+        } else {
+            &self.subdomains[(tile.x + tile.y) as usize % self.subdomains.len()]
+        };
+
+        self.template
+            .replace("{s}", subdomain)
+            .replace("{z}", &tile.zoom.to_string())
+            .replace("{x}", &tile.x.to_string())
+            .replace("{y}", &tile.y.to_string())
+    }
+}
+
+/// A request of a [`Map`] for a missing [`Tile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The missing [`Tile`].
+    pub tile: Tile,
+    /// The URL of the [`Tile`] in the [`Source`].
+    pub url: String,
+}
+
+/// A cache of the tiles of a [`Source`].
+///
+/// When full, the [`Tiles`] cache forgets the oldest tiles first.
+#[derive(Debug, Clone)]
+pub struct Tiles {
+    source: Source,
+    entries: FxHashMap<Tile, Option<image::Handle>>,
+    order: VecDeque<Tile>,
+    capacity: usize,
+}
+
+impl Tiles {
+    /// Creates a new empty [`Tiles`] cache of the given [`Source`].
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            entries: FxHashMap::default(),
+            order: VecDeque::new(),
+            capacity: 512,
+        }
+    }
+
+    /// Sets the maximum amount of tiles kept by the [`Tiles`] cache.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns the [`Source`] of the [`Tiles`].
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// Stores the encoded image of the given [`Tile`].
+    pub fn insert(&mut self, tile: Tile, bytes: impl Into<Bytes>) {
+        self.store(tile, Some(image::Handle::from_bytes(bytes)));
+    }
+
+    /// Marks the given [`Tile`] as failed, so it is not requested again
+    /// until it is forgotten.
+    pub fn fail(&mut self, tile: Tile) {
+        self.store(tile, None);
+    }
+
+    /// Returns the image of the given [`Tile`], if it is stored.
+    pub fn get(&self, tile: Tile) -> Option<&image::Handle> {
+        self.entries.get(&tile)?.as_ref()
+    }
+
+    /// Returns true if the given [`Tile`] was stored or failed.
+    pub fn contains(&self, tile: Tile) -> bool {
+        self.entries.contains_key(&tile)
+    }
+
+    /// Returns the amount of tiles in the [`Tiles`] cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Tiles`] cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forgets all the tiles of the [`Tiles`] cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn store(&mut self, tile: Tile, entry: Option<image::Handle>) {
+        if self.entries.insert(tile, entry).is_none() {
+            self.order.push_back(tile);
+        }
+
+        while self.order.len() > self.capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+
+            let _ = self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// Creates a new [`Marker`] at the given [`Coordinate`].
+pub fn marker(position: Coordinate) -> Marker {
+    Marker::new(position)
+}
+
+/// Creates a new [`Polyline`] joining the given coordinates.
+pub fn polyline<'a>(points: impl Into<Cow<'a, [Coordinate]>>) -> Polyline<'a> {
+    Polyline::new(points)
+}
+
+/// A point of interest displayed on top of a [`Map`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    position: Coordinate,
+    label: Option<String>,
+    color: Option<Color>,
+    radius: f32,
+}
+
+impl Marker {
+    /// Creates a new [`Marker`] at the given [`Coordinate`].
+    pub fn new(position: Coordinate) -> Self {
+        Self {
+            position,
+            label: None,
+            color: None,
+            radius: 6.0,
+        }
+    }
+
+    /// Sets the label displayed next to the [`Marker`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Marker`].
+    ///
+    /// By default, the color is picked from the [`Style`] of the [`Map`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the radius of the [`Marker`].
+    pub fn radius(mut self, radius: impl Into<Pixels>) -> Self {
+        self.radius = radius.into().0;
+        self
+    }
+}
+
+/// A line joining some coordinates on top of a [`Map`], like a route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline<'a> {
+    points: Cow<'a, [Coordinate]>,
+    color: Option<Color>,
+    width: f32,
+}
+
+impl<'a> Polyline<'a> {
+    /// Creates a new [`Polyline`] joining the given coordinates.
+    pub fn new(points: impl Into<Cow<'a, [Coordinate]>>) -> Self {
+        Self {
+            points: points.into(),
+            color: None,
+            width: 3.0,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Polyline`].
+    ///
+    /// By default, the color is picked from the [`Style`] of the [`Map`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the width of the [`Polyline`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+}
+
+/// A slippy map displaying the [`Tiles`] of a [`Source`] in some
+/// [`Viewport`].
+///
+/// Dragging a [`Map`] pans it, scrolling or pinching zooms it around the
+/// cursor, and double-clicking zooms it in. The new [`Viewport`] is
+/// produced with [`on_viewport_change`](Self::on_viewport_change); without
+/// it, the [`Map`] can not be moved.
+///
+/// Missing tiles are replaced with the parts of their lower zoom levels
+/// that are stored, if any.
+pub struct Map<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    tiles: &'a Tiles,
+    viewport: Viewport,
+    markers: Vec<Marker>,
+    polylines: Vec<Polyline<'a>>,
+    width: Length,
+    height: Length,
+    text_size: Pixels,
+    on_viewport_change: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    on_request: Option<Box<dyn Fn(Request) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Map<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Map`] displaying the given [`Tiles`] in the given
+    /// [`Viewport`].
+    pub fn new(tiles: &'a Tiles, viewport: Viewport) -> Self {
+        Self {
+            tiles,
+            viewport,
+            markers: Vec::new(),
+            polylines: Vec::new(),
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: Pixels(12.0),
+            on_viewport_change: None,
+            on_request: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Marker`] to the [`Map`].
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    /// Adds some [`Marker`] to the [`Map`].
+    pub fn markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers.extend(markers);
+        self
+    }
+
+    /// Adds a [`Polyline`] to the [`Map`].
+    pub fn polyline(mut self, polyline: Polyline<'a>) -> Self {
+        self.polylines.push(polyline);
+        self
+    }
+
+    /// Sets the width of the [`Map`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Map`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Map`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the message produced when the [`Map`] is panned or zoomed.
+    pub fn on_viewport_change(
+        mut self,
+        on_viewport_change: impl Fn(Viewport) -> Message + 'a,
+    ) -> Self {
+        self.on_viewport_change = Some(Box::new(on_viewport_change));
+        self
+    }
+
+    /// Sets the message produced when a visible [`Tile`] is missing from
+    /// the [`Tiles`] cache.
+    ///
+    /// Every [`Tile`] is only requested once, until it is stored or marked
+    /// as failed in the cache.
+    pub fn on_request(mut self, on_request: impl Fn(Request) -> Message + 'a) -> Self {
+        self.on_request = Some(Box::new(on_request));
+        self
+    }
+
+    /// Sets the style of the [`Map`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Map`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the zoom level of the tiles displayed by the [`Map`].
+    fn tile_zoom(&self) -> u8 {
+        let zoom = self.viewport.zoom.round().max(0.0) as u8;
+
+        zoom.min(self.tiles.source.max_zoom)
+    }
+
+    /// Returns the [`Projection`] of the [`Map`] in the given bounds.
+    fn projection(&self, bounds: Rectangle) -> Projection {
+        Projection::new(self.viewport, self.tiles.source.tile_size, bounds)
+    }
+
+    /// Returns the [`Viewport`] zoomed to the given level, with the point
+    /// displayed at `from` moved to `to`.
+    fn moved(
+        &self,
+        viewport: Viewport,
+        bounds: Rectangle,
+        from: Point,
+        to: Point,
+        zoom: f32,
+    ) -> Viewport {
+        let source = &self.tiles.source;
+        let (x, y) = Projection::new(viewport, source.tile_size, bounds).position(from);
+
+        let zoom = zoom.clamp(0.0, f32::from(source.max_zoom));
+        let size = world_size(source.tile_size, zoom);
+
+        Viewport {
+            center: Coordinate::unproject((
+                x - f64::from(to.x - bounds.center_x()) / size,
+                y - f64::from(to.y - bounds.center_y()) / size,
+            )),
+            zoom,
+        }
+    }
+
+    fn draw_tiles<Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle)
+    where
+        Renderer: image::Renderer<Handle = image::Handle>,
+    {
+        let projection = self.projection(bounds);
+
+        for (tile, area) in projection.tiles(self.tile_zoom()) {
+            let Some(clip_bounds) = area.intersection(&bounds) else {
+                continue;
+            };
+
+            if let Some(handle) = self.tiles.get(tile) {
+                renderer.draw_image(image::Image::new(handle.clone()), area, clip_bounds);
+                continue;
+            }
+
+            // Display the part of the closest lower zoom level that is stored
+            let fallback = (1..=FALLBACK_LEVELS.min(tile.zoom)).find_map(|levels| {
+                let parent = tile.parent(levels);
+
+                Some((levels, parent, self.tiles.get(parent)?))
+            });
+
+            if let Some((levels, parent, handle)) = fallback {
+                let scale = f32::from(1_u16 << levels);
+                let offset = Vector::new(
+                    (tile.x - (parent.x << levels)) as f32 * area.width,
+                    (tile.y - (parent.y << levels)) as f32 * area.height,
+                );
+
+                renderer.draw_image(
+                    image::Image::new(handle.clone()),
+                    Rectangle::new(
+                        area.position() - offset,
+                        Size::new(area.width * scale, area.height * scale),
+                    ),
+                    clip_bounds,
+                );
+            }
+        }
+    }
+
+    /// Draws the polylines, the markers, and the attribution of the [`Map`].
+    fn draw_overlays<Renderer>(&self, frame: &mut canvas::Frame<Renderer>, style: &Style)
+    where
+        Renderer: geometry::Renderer,
+    {
+        let projection = self.projection(Rectangle::with_size(frame.size()));
+
+        for polyline in &self.polylines {
+            let path = Path::new(|builder| {
+                for (i, point) in polyline.points.iter().enumerate() {
+                    let point = projection.point(point.project());
+
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+
+            frame.stroke(
+                &path,
+                Stroke::default()
+                    .with_color(polyline.color.unwrap_or(style.line_color))
+                    .with_width(polyline.width)
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round),
+            );
+        }
+
+        for marker in &self.markers {
+            let center = projection.point(marker.position.project());
+            let circle = Path::circle(center, marker.radius);
+
+            frame.fill(&circle, marker.color.unwrap_or(style.marker_color));
+            frame.stroke(
+                &circle,
+                Stroke::default()
+                    .with_color(style.marker_border_color)
+                    .with_width(2.0),
+            );
+
+            if let Some(label) = &marker.label {
+                frame.fill_text(canvas::Text {
+                    content: label.clone(),
+                    position: Point::new(center.x + marker.radius + PADDING, center.y),
+                    color: style.text_color,
+                    size: self.text_size,
+                    align_y: alignment::Vertical::Center,
+                    ..canvas::Text::default()
+                });
+            }
+        }
+
+        if let Some(attribution) = &self.tiles.source.attribution {
+            let size = Size::new(
+                attribution.chars().count() as f32 * self.text_size.0 * CHARACTER_WIDTH
+                    + PADDING * 2.0,
+                self.text_size.0 * 1.3 + PADDING,
+            );
+
+            let origin = Point::new(frame.width() - size.width, frame.height() - size.height);
+
+            frame.fill_rectangle(origin, size, style.attribution_background);
+            frame.fill_text(canvas::Text {
+                content: attribution.clone(),
+                position: Point::new(frame.width() - PADDING, frame.height() - PADDING / 2.0),
+                color: style.attribution_text_color,
+                size: self.text_size,
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Bottom,
+                ..canvas::Text::default()
+            });
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Map<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = image::Handle> + geometry::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let Some(on_request) = &self.on_request else {
+                return;
+            };
+
+            state.requested.retain(|tile| !self.tiles.contains(*tile));
+
+            for (tile, area) in self.projection(bounds).tiles(self.tile_zoom()) {
+                if area.intersects(&bounds)
+                    && !self.tiles.contains(tile)
+                    && state.requested.insert(tile)
+                {
+                    shell.publish(on_request(Request {
+                        tile,
+                        url: self.tiles.source.url(tile),
+                    }));
+                }
+            }
+
+            return;
+        }
+
+        let Some(on_viewport_change) = &self.on_viewport_change else {
+            return;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if click.kind() == mouse::click::Kind::Double {
+                    state.drag = None;
+
+                    shell.publish(on_viewport_change(self.moved(
+                        self.viewport,
+                        bounds,
+                        position,
+                        position,
+                        self.viewport.zoom.floor() + 1.0,
+                    )));
+                } else {
+                    state.drag = Some(Drag {
+                        origin: position,
+                        viewport: self.viewport,
+                    });
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.drag = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(drag) = state.drag else {
+                    return;
+                };
+
+                shell.publish(on_viewport_change(self.moved(
+                    drag.viewport,
+                    bounds,
+                    drag.origin,
+                    *position,
+                    drag.viewport.zoom,
+                )));
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y / 50.0,
+                };
+
+                shell.publish(on_viewport_change(self.moved(
+                    self.viewport,
+                    bounds,
+                    position,
+                    position,
+                    self.viewport.zoom + lines * ZOOM_STEP,
+                )));
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if bounds.contains(*position) =>
+            {
+                let _ = state.fingers.insert(*id, *position);
+
+                state.pinch = Pinch::new(&state.fingers, self.viewport);
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some(finger) = state.fingers.get_mut(id) else {
+                    return;
+                };
+
+                *finger = *position;
+
+                let Some(pinch) = state.pinch else {
+                    return;
+                };
+
+                let (center, spread) = centroid(&state.fingers);
+
+                let zoom = if pinch.spread > 0.0 && spread > 0.0 {
+                    pinch.viewport.zoom + (spread / pinch.spread).log2()
+                } else {
+                    pinch.viewport.zoom
+                };
+
+                shell.publish(on_viewport_change(self.moved(
+                    pinch.viewport,
+                    bounds,
+                    pinch.center,
+                    center,
+                    zoom,
+                )));
+                shell.capture_event();
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                if state.fingers.remove(id).is_some() {
+                    // The gesture starts over from the current viewport
+                    state.pinch = Pinch::new(&state.fingers, self.viewport);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        self.draw_tiles(renderer, bounds);
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        self.draw_overlays(&mut frame, &style);
+        let overlays = frame.into_geometry();
+
+        // Images are drawn on top of geometry in the same layer
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+                renderer.draw_geometry(overlays);
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.on_viewport_change.is_none() {
+            mouse::Interaction::default()
+        } else if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Map<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + geometry::Renderer + 'static,
+{
+    fn from(map: Map<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(map)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    fingers: FxHashMap<touch::Finger, Point>,
+    pinch: Option<Pinch>,
+    last_click: Option<mouse::Click>,
+    requested: FxHashSet<Tile>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    viewport: Viewport,
+}
+
+/// A touch gesture, relative to the fingers touching the [`Map`] when it
+/// started.
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    center: Point,
+    spread: f32,
+    viewport: Viewport,
+}
+
+impl Pinch {
+    fn new(fingers: &FxHashMap<touch::Finger, Point>, viewport: Viewport) -> Option<Self> {
+        if fingers.is_empty() {
+            return None;
+        }
+
+        let (center, spread) = centroid(fingers);
+
+        Some(Self {
+            center,
+            spread,
+            viewport,
+        })
+    }
+}
+
+/// Returns the center of the given fingers, alongside their average
+/// distance to it.
+fn centroid(fingers: &FxHashMap<touch::Finger, Point>) -> (Point, f32) {
+    let count = fingers.len().max(1) as f32;
+
+    let center = fingers.values().fold(Point::ORIGIN, |center, finger| {
+        center + Vector::new(finger.x / count, finger.y / count)
+    });
+
+    let spread = fingers
+        .values()
+        .map(|finger| finger.distance(center))
+        .sum::<f32>()
+        / count;
+
+    (center, spread)
+}
+
+/// Returns the width of the world at the given zoom level, in pixels.
+fn world_size(tile_size: u32, zoom: f32) -> f64 {
+    f64::from(tile_size) * 2.0_f64.powf(f64::from(zoom))
+}
+
+/// The projection of the Web Mercator square into the bounds of a [`Map`].
+#[derive(Debug, Clone, Copy)]
+struct Projection {
+    center: (f64, f64),
+    size: f64,
+    bounds: Rectangle,
+}
+
+impl Projection {
+    fn new(viewport: Viewport, tile_size: u32, bounds: Rectangle) -> Self {
+        Self {
+            center: viewport.center.project(),
+            size: world_size(tile_size, viewport.zoom),
+            bounds,
+        }
+    }
+
+    /// Returns the point displaying the given position of the Web Mercator
+    /// square.
+    fn point(&self, (x, y): (f64, f64)) -> Point {
+        Point::new(
+            self.bounds.center_x() + ((x - self.center.0) * self.size) as f32,
+            self.bounds.center_y() + ((y - self.center.1) * self.size) as f32,
+        )
+    }
+
+    /// Returns the position of the Web Mercator square displayed at the
+    /// given point.
+    fn position(&self, point: Point) -> (f64, f64) {
+        (
+            self.center.0 + f64::from(point.x - self.bounds.center_x()) / self.size,
+            self.center.1 + f64::from(point.y - self.bounds.center_y()) / self.size,
+        )
+    }
+
+    /// Returns the visible tiles of the given zoom level, alongside their
+    /// area.
+    ///
+    /// The world repeats horizontally, so the same [`Tile`] may be visible
+    /// more than once.
+    fn tiles(&self, zoom: u8) -> Vec<(Tile, Rectangle)> {
+        let count = 1_i64 << zoom;
+        let tiles = count as f64;
+        let tile_size = (self.size / tiles) as f32;
+
+        let (left, top) = self.position(self.bounds.position());
+        let (right, bottom) = self.position(Point::new(
+            self.bounds.x + self.bounds.width,
+            self.bounds.y + self.bounds.height,
+        ));
+
+        let columns = (left * tiles).floor() as i64..(right * tiles).ceil() as i64;
+        let rows =
+            ((top * tiles).floor() as i64).max(0)..((bottom * tiles).ceil() as i64).min(count);
+
+        rows.flat_map(|row| {
+            columns.clone().map(move |column| {
+                let origin = self.point((column as f64 / tiles, row as f64 / tiles));
+
+                (
+                    Tile {
+                        x: column.rem_euclid(count) as u32,
+                        y: row as u32,
+                        zoom,
+                    },
+                    Rectangle::new(origin, Size::new(tile_size, tile_size)),
+                )
+            })
+        })
+        .collect()
+    }
+}
+
+/// The appearance of a map.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the background of the map, behind missing tiles.
+    pub background: Color,
+    /// The [`Color`] of markers without an explicit [`Color`].
+    pub marker_color: Color,
+    /// The [`Color`] of the border of markers.
+    pub marker_border_color: Color,
+    /// The [`Color`] of polylines without an explicit [`Color`].
+    pub line_color: Color,
+    /// The [`Color`] of the labels of markers.
+    pub text_color: Color,
+    /// The [`Color`] of the background of the attribution.
+    pub attribution_background: Color,
+    /// The [`Color`] of the text of the attribution.
+    pub attribution_text_color: Color,
+}
+
+/// The theme catalog of a [`Map`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Map`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Map`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color,
+        marker_color: palette.danger.base.color,
+        marker_border_color: Color::WHITE,
+        line_color: palette.primary.base.color,
+        text_color: Color::BLACK,
+        attribution_background: Color::WHITE.scale_alpha(0.7),
+        attribution_text_color: Color::BLACK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinates_are_projected() {
+        let paris = Coordinate::new(48.8566, 2.3522);
+        let back = Coordinate::unproject(paris.project());
+
+        assert!((back.latitude - paris.latitude).abs() < 1e-9);
+        assert!((back.longitude - paris.longitude).abs() < 1e-9);
+
+        let projection = Projection::new(
+            Viewport::new(Coordinate::default(), 1.0),
+            256,
+            Rectangle::with_size(Size::new(512.0, 512.0)),
+        );
+
+        assert_eq!(projection.tiles(1).len(), 4);
+        assert_eq!(
+            Source::new("https://{s}.tiles.test/{z}/{x}/{y}.png")
+                .subdomains(["a", "b"])
+                .url(Tile {
+                    x: 3,
+                    y: 2,
+                    zoom: 4
+                }),
+            "https://b.tiles.test/4/3/2.png"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core::image;
+use crate::core::{Point, Radians, Rectangle};
+
+/// The alpha channel of an image, used to hit test its shape.
+#[derive(Debug)]
+pub struct Mask {
+    width: u32,
+    height: u32,
+    alpha: Vec<u8>,
+}
+
+impl Mask {
+    /// Creates a [`Mask`] out of the pixels of an [`image::Handle::Rgba`].
+    pub fn new(handle: &image::Handle) -> Option<Self> {
+        let image::Handle::Rgba {
+            width,
+            height,
+            pixels,
+            ..
+        } = handle
+        else {
+            return None;
+        };
+
+        Some(Self {
+            width: *width,
+            height: *height,
+            alpha: pixels.chunks_exact(4).map(|pixel| pixel[3]).collect(),
+        })
+    }
+
+    /// Returns whether the given `position` falls on a non-transparent pixel of
+    /// the image, when drawn inside the given `bounds` with the given `rotation`.
+    pub fn hit(&self, bounds: Rectangle, rotation: Radians, position: Point) -> bool {
+        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return false;
+        }
+
+        // Undo the rotation of the image around its center
+        let offset = position - bounds.center();
+        let (sin, cos) = (-rotation.0).sin_cos();
+
+        let x = (offset.x * cos - offset.y * sin) / bounds.width + 0.5;
+        let y = (offset.x * sin + offset.y * cos) / bounds.height + 0.5;
+
+        if !(0.0..1.0).contains(&x) || !(0.0..1.0).contains(&y) {
+            return false;
+        }
+
+        let column = (x * self.width as f32) as usize;
+        let row = (y * self.height as f32) as usize;
+
+        self.alpha
+            .get(row * self.width as usize + column)
+            .is_some_and(|alpha| *alpha > 0)
+    }
+}
@@ -0,0 +1,497 @@
+//! Masked inputs let users type text that follows a fixed pattern, like a
+//! phone number or a date.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::masked_input;
+//!
+//! struct State {
+//!    phone: String,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     PhoneChanged(String),
+//!     PhoneCompleted,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     masked_input("Phone number", "(###) ###-####", &state.phone)
+//!         .on_input(Message::PhoneChanged)
+//!         .on_complete(Message::PhoneCompleted)
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::Tree;
+use crate::core::widget::{self, Operation, Widget};
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size,
+};
+use crate::text_input::{self, Catalog, Status, Style, StyleFn, TextInput, Value};
+
+/// A pattern that the value of a [`MaskedInput`] must follow.
+///
+/// A [`Mask`] is created from a string where:
+///   * `#` stands for a digit.
+///   * `A` stands for a letter.
+///   * `*` stands for a letter or a digit.
+///   * `\` escapes the next character.
+///   * Any other character is a literal that is inserted automatically.
+///
+/// For instance, a phone number mask could be `(###) ###-####`, a date
+/// mask `##/##/####`, and a credit card mask `#### #### #### ####`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    Digit,
+    Letter,
+    Alphanumeric,
+}
+
+impl Token {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Token::Literal(_) => false,
+            Token::Digit => c.is_ascii_digit(),
+            Token::Letter => c.is_alphabetic(),
+            Token::Alphanumeric => c.is_alphanumeric(),
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut tokens = Vec::new();
+
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '#' => Token::Digit,
+                'A' => Token::Letter,
+                '*' => Token::Alphanumeric,
+                '\\' => Token::Literal(chars.next().unwrap_or('\\')),
+                c => Token::Literal(c),
+            });
+        }
+
+        Self { tokens }
+    }
+
+    /// Conforms the given input to the [`Mask`], inserting any missing
+    /// literals and dropping the characters that do not fit.
+    pub fn apply(&self, input: &str) -> String {
+        self.conform(input, 0, false).0
+    }
+
+    /// Returns whether the given value fills every position of the [`Mask`].
+    pub fn is_complete(&self, value: &str) -> bool {
+        self.apply(value).chars().count() == self.tokens.len()
+    }
+
+    /// Returns the characters of the given value typed by the user; that is,
+    /// without the literals of the [`Mask`].
+    pub fn strip(&self, value: &str) -> String {
+        self.apply(value)
+            .chars()
+            .zip(&self.tokens)
+            .filter(|(_, token)| !matches!(token, Token::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Conforms the given input to the [`Mask`], returning the new position
+    /// of the given cursor.
+    ///
+    /// When the input is growing, the literals following the last typed
+    /// character are inserted too—so the cursor skips over them.
+    fn conform(&self, input: &str, cursor: usize, is_growing: bool) -> (String, usize) {
+        let chars: Vec<char> = input.chars().collect();
+
+        let mut output = String::new();
+        let mut length = 0;
+        let mut new_cursor = 0;
+        let mut i = 0;
+
+        for token in &self.tokens {
+            let is_typed = match token {
+                Token::Literal(literal) => {
+                    let is_typed = chars.get(i) == Some(literal);
+
+                    if is_typed {
+                        i += 1;
+                    } else if i >= chars.len() && !is_growing {
+                        break;
+                    }
+
+                    output.push(*literal);
+                    is_typed
+                }
+                slot => {
+                    while i < chars.len() && !slot.accepts(chars[i]) {
+                        i += 1;
+                    }
+
+                    let Some(c) = chars.get(i) else {
+                        break;
+                    };
+
+                    output.push(*c);
+                    i += 1;
+                    true
+                }
+            };
+
+            length += 1;
+
+            // Inserted literals are only skipped by the cursor while typing
+            if i < cursor || (i == cursor && (is_typed || is_growing)) {
+                new_cursor = length;
+            }
+        }
+
+        (output, new_cursor)
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+/// A text input that enforces a [`Mask`].
+///
+/// The literals of the [`Mask`] are inserted automatically while typing, and
+/// any characters that do not fit are ignored.
+pub struct MaskedInput<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    mask: Mask,
+    value: String,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_submit: Option<Message>,
+    on_complete: Option<Message>,
+    input: TextInput<'a, Edit, Theme, Renderer>,
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Submit,
+}
+
+impl<'a, Message, Theme, Renderer> MaskedInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MaskedInput`] with the given placeholder, [`Mask`],
+    /// and current value.
+    pub fn new(placeholder: &str, mask: impl Into<Mask>, value: &str) -> Self {
+        let mask = mask.into();
+        let value = mask.apply(value);
+
+        Self {
+            input: TextInput::new(placeholder, &value).on_submit(Edit::Submit),
+            mask,
+            value,
+            on_input: None,
+            on_submit: None,
+            on_complete: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`MaskedInput`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into
+    /// the [`MaskedInput`].
+    ///
+    /// The produced text always conforms to the [`Mask`]. If this method is
+    /// not called, the [`MaskedInput`] will be disabled.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self.input = self.input.on_input(Edit::Input);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`MaskedInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the last position of
+    /// the [`Mask`] is filled.
+    pub fn on_complete(mut self, message: Message) -> Self {
+        self.on_complete = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`MaskedInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MaskedInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.input = self.input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`MaskedInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the font of the [`MaskedInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`MaskedInput`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.input = self.input.align_x(alignment);
+        self
+    }
+
+    /// Sets the style of the [`MaskedInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`MaskedInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.input = self.input.class(class);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MaskedInput<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.input)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let input = self
+            .input
+            .layout(&mut tree.children[0], renderer, limits, None);
+
+        layout::Node::with_children(input.size(), vec![input])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input.operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        self.input.update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        for edit in edits {
+            match edit {
+                Edit::Input(input) => {
+                    let Some(on_input) = &self.on_input else {
+                        continue;
+                    };
+
+                    let state = tree.children[0]
+                        .state
+                        .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+                    let cursor = match state.cursor().state(&Value::new(&input)) {
+                        text_input::cursor::State::Index(index) => index,
+                        text_input::cursor::State::Selection { end, .. } => end,
+                    };
+
+                    let is_growing = input.chars().count() > self.value.chars().count();
+                    let (value, cursor) = self.mask.conform(&input, cursor, is_growing);
+
+                    state.move_cursor_to(cursor);
+
+                    if value == self.value {
+                        continue;
+                    }
+
+                    let was_complete = self.mask.is_complete(&self.value);
+                    let is_complete = self.mask.is_complete(&value);
+
+                    shell.publish(on_input(value.clone()));
+
+                    if is_complete
+                        && !was_complete
+                        && let Some(on_complete) = self.on_complete.clone()
+                    {
+                        shell.publish(on_complete);
+                    }
+
+                    self.value = value;
+                }
+                Edit::Submit => {
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        shell.publish(on_submit);
+                    }
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input.mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout.children().next().unwrap(),
+            cursor,
+            None,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MaskedInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(masked_input: MaskedInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(masked_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_insert_literals_and_skip_the_cursor() {
+        let mask = Mask::new("(###) ###-####");
+
+        assert_eq!(mask.conform("5", 1, true), ("(5".to_owned(), 2));
+        assert_eq!(mask.conform("(555", 4, true), ("(555) ".to_owned(), 6));
+        assert_eq!(mask.conform("(555)", 5, false), ("(555)".to_owned(), 5));
+        assert_eq!(mask.apply("5a5-5 1234567"), "(555) 123-4567");
+        assert_eq!(mask.strip("(555) 123-4567"), "5551234567");
+
+        assert!(mask.is_complete("(555) 123-4567"));
+        assert!(!mask.is_complete("(555) 123-456"));
+
+        assert_eq!(Mask::new(r"\#A").apply("#x"), "#x");
+    }
+}
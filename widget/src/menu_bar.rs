@@ -0,0 +1,1543 @@
+//! Menu bars display a row of menus with nested submenus, like the ones of
+//! native applications.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::menu_bar;
+//! use iced::widget::menu_bar::{item, menu, separator, submenu};
+//!
+//! struct State {
+//!     show_grid: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Save,
+//!     Export(&'static str),
+//!     Quit,
+//!     ToggleGrid,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     menu_bar([
+//!         menu(
+//!             "&File",
+//!             [
+//!                 item("&Open...").shortcut("Ctrl+O").on_press(Message::Open),
+//!                 item("&Save").shortcut("Ctrl+S").on_press(Message::Save),
+//!                 submenu(
+//!                     "&Export",
+//!                     [
+//!                         item("&PNG").on_press(Message::Export("png")),
+//!                         item("&SVG").on_press(Message::Export("svg")),
+//!                     ],
+//!                 ),
+//!                 separator(),
+//!                 item("&Quit").on_press(Message::Quit),
+//!             ],
+//!         ),
+//!         menu(
+//!             "&View",
+//!             [item("Show &grid")
+//!                 .checked(state.show_grid)
+//!                 .on_press(Message::ToggleGrid)],
+//!         ),
+//!     ])
+//!     .into()
+//! }
+//! ```
+//!
+//! An ampersand in a label marks the next character as the mnemonic of the
+//! menu or item; that is, the key that opens or activates it when pressed
+//! together with Alt—or on its own, while the menus are being navigated with
+//! the keyboard. Two ampersands produce a literal one.
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+const PANEL_PADDING: f32 = 4.0;
+const MIN_PANEL_WIDTH: f32 = 160.0;
+const SEPARATOR_HEIGHT: f32 = 9.0;
+const SHORTCUT_SPACING: f32 = 24.0;
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// Menus can be created using the [`menu`] function.
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = Menu<Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`Menu`] with the given label and items.
+pub fn menu<Message>(
+    label: impl AsRef<str>,
+    items: impl IntoIterator<Item = Item<Message>>,
+) -> Menu<Message> {
+    Menu {
+        label: Label::parse(label.as_ref()),
+        items: items.into_iter().collect(),
+    }
+}
+
+/// Creates a new [`Item`] with the given label.
+///
+/// The [`Item`] will be disabled until [`Item::on_press`] is set.
+pub fn item<Message>(label: impl AsRef<str>) -> Item<Message> {
+    Item {
+        kind: Kind::Entry {
+            label: Label::parse(label.as_ref()),
+            shortcut: None,
+            is_checked: None,
+            on_press: None,
+        },
+    }
+}
+
+/// Creates a new [`Item`] that opens a submenu with the given items.
+pub fn submenu<Message>(
+    label: impl AsRef<str>,
+    items: impl IntoIterator<Item = Item<Message>>,
+) -> Item<Message> {
+    Item {
+        kind: Kind::Submenu {
+            label: Label::parse(label.as_ref()),
+            items: items.into_iter().collect(),
+        },
+    }
+}
+
+/// Creates a new [`Item`] that separates groups of items.
+pub fn separator<Message>() -> Item<Message> {
+    Item {
+        kind: Kind::Separator,
+    }
+}
+
+/// A horizontal bar of menus with nested submenus.
+///
+/// The menus of a [`MenuBar`] are displayed in overlays, so they can span
+/// the whole window. They can be navigated with the mouse or the keyboard:
+/// Alt focuses the [`MenuBar`], mnemonics open menus and activate items, and
+/// the arrow keys move between them.
+pub struct MenuBar<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of the menus and items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<Message>>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn push(mut self, menu: Menu<Message>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the menus and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn metrics(&self) -> Metrics<Renderer::Font> {
+        Metrics {
+            padding: self.padding,
+            text_size: self.text_size,
+            font: self.font,
+        }
+    }
+
+    fn navigate(
+        &self,
+        state: &mut State,
+        key: &keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool
+    where
+        Message: Clone,
+    {
+        let count = self.menus.len();
+
+        if let Some(open) = state.open_menu() {
+            if state.path.navigate(&self.menus[open].items, key, shell) {
+                return true;
+            }
+
+            match key.as_ref() {
+                keyboard::Key::Named(key::Named::ArrowRight) => {
+                    let next = (open + 1) % count;
+
+                    state.open(next, first(&self.menus[next].items));
+                }
+                keyboard::Key::Named(key::Named::ArrowLeft) => {
+                    let previous = (open + count - 1) % count;
+
+                    state.open(previous, first(&self.menus[previous].items));
+                }
+                keyboard::Key::Named(key::Named::Escape) => {
+                    state.path.close();
+                    state.focused = Some(open);
+                }
+                _ => return false,
+            }
+
+            state.path.is_keyboard = true;
+
+            return true;
+        }
+
+        let Some(focused) = state.focused else {
+            return false;
+        };
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::ArrowLeft) => {
+                state.focused = Some((focused + count - 1) % count);
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                state.focused = Some((focused + 1) % count);
+            }
+            keyboard::Key::Named(key::Named::ArrowDown | key::Named::Enter | key::Named::Space) => {
+                state.open(focused, first(&self.menus[focused].items));
+                state.path.is_keyboard = true;
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                state.close();
+            }
+            keyboard::Key::Character(c) => {
+                let Some(index) = c
+                    .chars()
+                    .next()
+                    .and_then(|c| self.menus.iter().position(|menu| menu.label.matches(c)))
+                else {
+                    return false;
+                };
+
+                state.open(index, first(&self.menus[index].items));
+                state.path.is_keyboard = true;
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    menu: Option<usize>,
+    path: Path,
+    focused: Option<usize>,
+    hovered: Option<usize>,
+    is_alt_alone: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn open_menu(&self) -> Option<usize> {
+        self.menu.filter(|_| self.path.is_open())
+    }
+
+    fn open(&mut self, menu: usize, highlighted: Option<usize>) {
+        self.menu = Some(menu);
+        self.path.open(highlighted);
+        self.focused = None;
+    }
+
+    fn close(&mut self) {
+        self.menu = None;
+        self.path.close();
+        self.focused = None;
+    }
+
+    fn is_active(&self) -> bool {
+        self.open_menu().is_some() || self.focused.is_some()
+    }
+
+    fn show_mnemonics(&self) -> bool {
+        self.path.is_keyboard || self.focused.is_some() || self.modifiers.alt()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = self.metrics();
+        let height = metrics.line_height(renderer) + self.padding.y();
+        let mut x = 0.0;
+
+        let titles: Vec<_> = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = metrics.measure(&menu.label.text, renderer) + self.padding.x();
+                let node = layout::Node::new(Size::new(width, height)).move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let title_at = |cursor: mouse::Cursor| {
+            cursor.position().and_then(|position| {
+                layout
+                    .children()
+                    .position(|title| title.bounds().contains(position))
+            })
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_alt_alone = false;
+
+                if !matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(_)
+                ) {
+                    return;
+                }
+
+                match (state.open_menu(), title_at(cursor)) {
+                    (Some(open), Some(title)) if open == title => {
+                        state.close();
+                    }
+                    (_, Some(title)) => {
+                        state.open(title, None);
+                    }
+                    (Some(_), None) => {
+                        // The event was not captured by the menus, so the cursor
+                        // was pressed outside of them
+                        state.close();
+                    }
+                    (None, None) => {
+                        if state.focused.is_some() {
+                            state.close();
+                            shell.request_redraw();
+                        }
+
+                        return;
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let title = title_at(cursor);
+
+                if let Some(open) = state.open_menu()
+                    && let Some(title) = title
+                    && title != open
+                {
+                    state.open(title, None);
+                    shell.request_redraw();
+                }
+
+                if state.hovered != title {
+                    state.hovered = title;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) => {
+                state.is_alt_alone = *key == keyboard::Key::Named(key::Named::Alt);
+
+                if modifiers.alt()
+                    && let Some(c) = key.to_latin(*physical_key)
+                    && let Some(index) = self.menus.iter().position(|menu| menu.label.matches(c))
+                {
+                    state.open(index, first(&self.menus[index].items));
+                    state.path.is_keyboard = true;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if state.is_active() && self.navigate(state, key, shell) {
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(key::Named::Alt),
+                ..
+            }) => {
+                if std::mem::take(&mut state.is_alt_alone) {
+                    if state.is_active() {
+                        state.close();
+                    } else if !self.menus.is_empty() {
+                        state.focused = Some(0);
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                if state.modifiers.alt() != modifiers.alt() {
+                    shell.request_redraw();
+                }
+
+                state.modifiers = *modifiers;
+            }
+            Event::Window(window::Event::Unfocused) => {
+                if state.is_active() {
+                    state.close();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let metrics = self.metrics();
+        let open = state.open_menu();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for (index, (menu, title)) in self.menus.iter().zip(layout.children()).enumerate() {
+            let bounds = title.bounds();
+            let is_active = open == Some(index) || state.focused == Some(index);
+            let is_hovered = open.is_none() && state.hovered == Some(index);
+
+            if is_active || is_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::default().rounded(style.menu_border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    if is_active {
+                        style.selected_background
+                    } else {
+                        style.hovered_background
+                    },
+                );
+            }
+
+            metrics.fill_label(
+                renderer,
+                &menu.label,
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if is_active {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                state.show_mnemonics(),
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let open = state.open_menu()?;
+        let title = layout.children().nth(open)?.bounds() + translation;
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            root: &self.menus[open].items,
+            path: &mut state.path,
+            metrics: self.metrics(),
+            class: &self.class,
+            anchor: Anchor::Below(title),
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// The text metrics of some menus.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Metrics<Font> {
+    pub(crate) padding: Padding,
+    pub(crate) text_size: Option<Pixels>,
+    pub(crate) font: Option<Font>,
+}
+
+impl<Font> Metrics<Font>
+where
+    Font: Copy,
+{
+    fn text_size<Renderer>(&self, renderer: &Renderer) -> Pixels
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn line_height<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        text::LineHeight::default()
+            .to_absolute(self.text_size(renderer))
+            .0
+    }
+
+    fn text<'a, Renderer>(&self, content: &'a str, renderer: &Renderer) -> Text<&'a str, Font>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, self.line_height(renderer)),
+            size: self.text_size(renderer),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    fn measure<Renderer>(&self, content: &str, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Renderer::Paragraph::with_text(self.text(content, renderer)).min_width()
+    }
+
+    fn fill_text<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        align_x: text::Alignment,
+        position: Point,
+        color: Color,
+        viewport: &Rectangle,
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let text = Text {
+            align_x,
+            ..self.text(content, renderer)
+        };
+
+        renderer.fill_text(
+            text.with_content(content.to_owned()),
+            position,
+            color,
+            *viewport,
+        );
+    }
+
+    fn fill_label<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        label: &Label,
+        position: Point,
+        color: Color,
+        show_mnemonic: bool,
+        viewport: &Rectangle,
+    ) where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        self.fill_text(
+            renderer,
+            &label.text,
+            text::Alignment::Left,
+            position,
+            color,
+            viewport,
+        );
+
+        let Some((_, index)) = label.mnemonic.filter(|_| show_mnemonic) else {
+            return;
+        };
+
+        let paragraph = Renderer::Paragraph::with_text(self.text(&label.text, renderer));
+
+        let Some(start) = paragraph.grapheme_position(0, index) else {
+            return;
+        };
+
+        let end = paragraph
+            .grapheme_position(0, index + 1)
+            .map_or(paragraph.min_width(), |end| end.x);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: position.x + start.x,
+                    y: position.y + self.text_size(renderer).0 / 2.0,
+                    width: end - start.x,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+
+    fn layout_panel<Message, Renderer>(
+        &self,
+        items: &[Item<Message>],
+        renderer: &Renderer,
+    ) -> layout::Node
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let item_height = self.line_height(renderer) + self.padding.y();
+        let column = self.line_height(renderer);
+
+        let content_width = items
+            .iter()
+            .map(|item| match &item.kind {
+                Kind::Entry {
+                    label, shortcut, ..
+                } => {
+                    self.measure(&label.text, renderer)
+                        + shortcut.as_deref().map_or(0.0, |shortcut| {
+                            SHORTCUT_SPACING + self.measure(shortcut, renderer)
+                        })
+                }
+                Kind::Submenu { label, .. } => self.measure(&label.text, renderer),
+                Kind::Separator => 0.0,
+            })
+            .fold(0.0, f32::max);
+
+        let width = (content_width + column * 2.0 + self.padding.x() + PANEL_PADDING * 2.0)
+            .max(MIN_PANEL_WIDTH);
+
+        let mut y = PANEL_PADDING;
+
+        let nodes = items
+            .iter()
+            .map(|item| {
+                let height = match item.kind {
+                    Kind::Separator => SEPARATOR_HEIGHT,
+                    _ => item_height,
+                };
+
+                let node = layout::Node::new(Size::new(width - PANEL_PADDING * 2.0, height))
+                    .move_to(Point::new(PANEL_PADDING, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, y + PANEL_PADDING), nodes)
+    }
+}
+
+/// The highlighted items of a stack of open menus.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Path {
+    levels: Vec<Option<usize>>,
+    is_keyboard: bool,
+}
+
+impl Path {
+    pub(crate) fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    pub(crate) fn open(&mut self, highlighted: Option<usize>) {
+        self.levels = vec![highlighted];
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.levels.clear();
+        self.is_keyboard = false;
+    }
+
+    fn items<'a, Message>(
+        &self,
+        root: &'a [Item<Message>],
+        level: usize,
+    ) -> Option<&'a [Item<Message>]> {
+        let mut items = root;
+
+        for highlighted in self.levels.iter().take(level) {
+            let Kind::Submenu {
+                items: children, ..
+            } = &items.get((*highlighted)?)?.kind
+            else {
+                return None;
+            };
+
+            items = children;
+        }
+
+        Some(items)
+    }
+
+    fn hover<Message>(&mut self, root: &[Item<Message>], level: usize, index: usize) -> bool {
+        let Some(item) = self.items(root, level).and_then(|items| items.get(index)) else {
+            return false;
+        };
+
+        let highlighted = item.is_enabled().then_some(index);
+        let is_submenu = matches!(item.kind, Kind::Submenu { .. }) && item.is_enabled();
+
+        // Keep the open submenu of the item as it is
+        if self.levels.get(level) == Some(&highlighted)
+            && (!is_submenu || self.levels.len() > level + 1)
+        {
+            return false;
+        }
+
+        self.levels.truncate(level + 1);
+        self.levels[level] = highlighted;
+
+        if is_submenu {
+            self.levels.push(None);
+        }
+
+        true
+    }
+
+    fn activate<Message>(
+        &mut self,
+        root: &[Item<Message>],
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let Some(item) = self.items(root, level).and_then(|items| items.get(index)) else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Entry {
+                on_press: Some(on_press),
+                ..
+            } => {
+                shell.publish(on_press.clone());
+                self.close();
+            }
+            Kind::Submenu { items, .. } => {
+                self.levels.truncate(level + 1);
+                self.levels[level] = Some(index);
+                self.levels.push(first(items));
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles a key press in the deepest open menu.
+    ///
+    /// Returns `false` if the key press should be handled by the owner of the
+    /// menus; for instance, an arrow key that leaves the root menu.
+    pub(crate) fn navigate<Message>(
+        &mut self,
+        root: &[Item<Message>],
+        key: &keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool
+    where
+        Message: Clone,
+    {
+        let level = self.levels.len().saturating_sub(1);
+
+        let Some(items) = self.items(root, level) else {
+            return false;
+        };
+
+        let highlighted = self.levels.get(level).copied().flatten();
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::ArrowDown) => {
+                self.levels[level] = step(items, highlighted, true);
+            }
+            keyboard::Key::Named(key::Named::ArrowUp) => {
+                self.levels[level] = step(items, highlighted, false);
+            }
+            keyboard::Key::Named(key::Named::Home) => {
+                self.levels[level] = first(items);
+            }
+            keyboard::Key::Named(key::Named::End) => {
+                self.levels[level] = last(items);
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                let Some(index) =
+                    highlighted.filter(|index| matches!(items[*index].kind, Kind::Submenu { .. }))
+                else {
+                    return false;
+                };
+
+                self.activate(root, level, index, shell);
+            }
+            keyboard::Key::Named(key::Named::ArrowLeft | key::Named::Escape) if level > 0 => {
+                let _ = self.levels.pop();
+            }
+            keyboard::Key::Named(key::Named::Enter | key::Named::Space) => {
+                if let Some(index) = highlighted {
+                    self.activate(root, level, index, shell);
+                }
+            }
+            keyboard::Key::Character(c) => {
+                if let Some(index) = c.chars().next().and_then(|c| {
+                    items.iter().position(|item| {
+                        item.is_enabled() && item.label().is_some_and(|label| label.matches(c))
+                    })
+                }) {
+                    self.levels[level] = Some(index);
+                    self.activate(root, level, index, shell);
+                }
+            }
+            _ => return false,
+        }
+
+        self.is_keyboard = self.is_open();
+
+        true
+    }
+}
+
+/// Where the root menu of an [`Overlay`] is placed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Anchor {
+    /// Below the given bounds; for instance, the title of a menu.
+    Below(Rectangle),
+    /// At the given point; for instance, the position of the cursor.
+    At(Point),
+}
+
+/// An overlay displaying a stack of open menus.
+pub(crate) struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub(crate) root: &'a [Item<Message>],
+    pub(crate) path: &'a mut Path,
+    pub(crate) metrics: Metrics<Renderer::Font>,
+    pub(crate) class: &'a Theme::Class<'b>,
+    pub(crate) anchor: Anchor,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn hit(&self, layout: Layout<'_>, position: Point) -> Option<(usize, usize)> {
+        let (level, panel) = layout
+            .children()
+            .enumerate()
+            .rev()
+            .find(|(_, panel)| panel.bounds().contains(position))?;
+
+        let index = panel
+            .children()
+            .position(|item| item.bounds().contains(position))?;
+
+        Some((level, index))
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let mut panels: Vec<layout::Node> = Vec::new();
+
+        for level in 0..self.path.levels.len() {
+            let Some(items) = self.path.items(self.root, level) else {
+                break;
+            };
+
+            let panel = self.metrics.layout_panel(items, renderer);
+            let size = panel.size();
+
+            let position = match panels.last() {
+                None => match self.anchor {
+                    Anchor::Below(target) => Point::new(target.x, target.y + target.height),
+                    Anchor::At(point) => Point::new(
+                        if point.x + size.width <= bounds.width {
+                            point.x
+                        } else {
+                            point.x - size.width
+                        },
+                        if point.y + size.height <= bounds.height {
+                            point.y
+                        } else {
+                            point.y - size.height
+                        },
+                    ),
+                },
+                Some(parent) => {
+                    let parent = parent.bounds();
+                    let item = self.path.levels[level - 1]
+                        .and_then(|index| panels[level - 1].children().get(index))
+                        .map(layout::Node::bounds)
+                        .unwrap_or_default();
+
+                    let x = if parent.x + parent.width + size.width <= bounds.width {
+                        parent.x + parent.width
+                    } else {
+                        parent.x - size.width
+                    };
+
+                    Point::new(x, parent.y + item.y - PANEL_PADDING)
+                }
+            };
+
+            panels.push(panel.move_to(Point::new(
+                position.x.min(bounds.width - size.width).max(0.0),
+                position.y.min(bounds.height - size.height).max(0.0),
+            )));
+        }
+
+        layout::Node::with_children(bounds, panels)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let viewport = layout.bounds();
+        let padding = self.metrics.padding;
+        let column = self.metrics.line_height(renderer);
+
+        for (level, panel) in layout.children().enumerate() {
+            let Some(items) = self.path.items(self.root, level) else {
+                break;
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel.bounds(),
+                    border: style.menu_border,
+                    shadow: style.menu_shadow,
+                    ..renderer::Quad::default()
+                },
+                style.menu_background,
+            );
+
+            for ((index, item), layout) in items.iter().enumerate().zip(panel.children()) {
+                let bounds = layout.bounds();
+
+                let (label, shortcut, is_checked) = match &item.kind {
+                    Kind::Entry {
+                        label,
+                        shortcut,
+                        is_checked,
+                        ..
+                    } => (label, shortcut.as_deref(), *is_checked),
+                    Kind::Submenu { label, .. } => (label, None, None),
+                    Kind::Separator => {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + padding.left,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width - padding.x(),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.separator_color,
+                        );
+
+                        continue;
+                    }
+                };
+
+                let is_highlighted = self.path.levels.get(level) == Some(&Some(index));
+
+                if is_highlighted {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border::default().rounded(style.menu_border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.selected_background,
+                    );
+                }
+
+                let text_color = if !item.is_enabled() {
+                    style.disabled_text_color
+                } else if is_highlighted {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                };
+
+                let center_y = bounds.center_y();
+                let check = Point::new(bounds.x + padding.left + column / 2.0, center_y);
+                let arrow = Point::new(
+                    bounds.x + bounds.width - padding.right - column / 2.0,
+                    center_y,
+                );
+
+                if is_checked == Some(true) {
+                    self.metrics.fill_text(
+                        renderer,
+                        "✓",
+                        text::Alignment::Center,
+                        check,
+                        text_color,
+                        &viewport,
+                    );
+                }
+
+                self.metrics.fill_label(
+                    renderer,
+                    label,
+                    Point::new(bounds.x + padding.left + column, center_y),
+                    text_color,
+                    self.path.is_keyboard,
+                    &viewport,
+                );
+
+                if let Some(shortcut) = shortcut {
+                    self.metrics.fill_text(
+                        renderer,
+                        shortcut,
+                        text::Alignment::Right,
+                        Point::new(arrow.x - column / 2.0, center_y),
+                        if is_highlighted {
+                            style.selected_text_color
+                        } else {
+                            style.shortcut_text_color
+                        },
+                        &viewport,
+                    );
+                }
+
+                if let Kind::Submenu { .. } = item.kind {
+                    self.metrics.fill_text(
+                        renderer,
+                        "▸",
+                        text::Alignment::Center,
+                        arrow,
+                        text_color,
+                        &viewport,
+                    );
+                }
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((level, index)) = self.hit(layout, position)
+                    && self.path.hover(self.root, level, index)
+                {
+                    self.path.is_keyboard = false;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout
+                    .children()
+                    .any(|panel| panel.bounds().contains(position))
+                {
+                    return;
+                }
+
+                let is_primary = matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(_)
+                );
+
+                if is_primary && let Some((level, index)) = self.hit(layout, position) {
+                    let _ = self.path.hover(self.root, level, index);
+
+                    if let Some(Kind::Entry { .. }) = self
+                        .path
+                        .items(self.root, level)
+                        .and_then(|items| items.get(index))
+                        .map(|item| &item.kind)
+                    {
+                        self.path.activate(self.root, level, index, shell);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|panel| cursor.is_over(panel.bounds()))
+        {
+            mouse::Interaction::Idle
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+/// A menu of a [`MenuBar`].
+///
+/// A [`Menu`] can be created using the [`menu`] function.
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    label: Label,
+    items: Vec<Item<Message>>,
+}
+
+/// An item of a [`Menu`].
+///
+/// An [`Item`] can be created using the [`item`], [`submenu`], and
+/// [`separator`] functions.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Entry {
+        label: Label,
+        shortcut: Option<String>,
+        is_checked: Option<bool>,
+        on_press: Option<Message>,
+    },
+    Submenu {
+        label: Label,
+        items: Vec<Item<Message>>,
+    },
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Sets the message that will be produced when the [`Item`] is activated.
+    ///
+    /// Unless `on_press` is called, the [`Item`] will be disabled.
+    ///
+    /// This has no effect on submenus and separators.
+    pub fn on_press(self, message: Message) -> Self {
+        self.on_press_maybe(Some(message))
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is activated,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Entry { on_press, .. } = &mut self.kind {
+            *on_press = message;
+        }
+
+        self
+    }
+
+    /// Sets the shortcut hint of the [`Item`]; for instance, `"Ctrl+S"`.
+    ///
+    /// The hint is only displayed. Handling the shortcut itself is up to the
+    /// application—for instance, with a keyboard subscription.
+    pub fn shortcut(mut self, hint: impl Into<String>) -> Self {
+        if let Kind::Entry { shortcut, .. } = &mut self.kind {
+            *shortcut = Some(hint.into());
+        }
+
+        self
+    }
+
+    /// Makes the [`Item`] checkable and sets whether it is checked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let Kind::Entry { is_checked, .. } = &mut self.kind {
+            *is_checked = Some(checked);
+        }
+
+        self
+    }
+
+    fn label(&self) -> Option<&Label> {
+        match &self.kind {
+            Kind::Entry { label, .. } | Kind::Submenu { label, .. } => Some(label),
+            Kind::Separator => None,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Entry { on_press, .. } => on_press.is_some(),
+            Kind::Submenu { items, .. } => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+}
+
+fn first<Message>(items: &[Item<Message>]) -> Option<usize> {
+    items.iter().position(Item::is_enabled)
+}
+
+fn last<Message>(items: &[Item<Message>]) -> Option<usize> {
+    items.iter().rposition(Item::is_enabled)
+}
+
+fn step<Message>(items: &[Item<Message>], from: Option<usize>, forward: bool) -> Option<usize> {
+    let Some(from) = from else {
+        return if forward { first(items) } else { last(items) };
+    };
+
+    let count = items.len();
+
+    (1..count)
+        .map(|offset| {
+            if forward {
+                (from + offset) % count
+            } else {
+                (from + count - offset) % count
+            }
+        })
+        .find(|index| items[*index].is_enabled())
+        .or(Some(from))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Label {
+    text: String,
+    mnemonic: Option<(char, usize)>,
+}
+
+impl Label {
+    fn parse(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('&') | None => text.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = c
+                            .to_lowercase()
+                            .next()
+                            .map(|lowercase| (lowercase, text.chars().count()));
+                    }
+
+                    text.push(c);
+                }
+            }
+        }
+
+        Self { text, mnemonic }
+    }
+
+    fn matches(&self, c: char) -> bool {
+        self.mnemonic
+            .is_some_and(|(mnemonic, _)| c.to_lowercase().next() == Some(mnemonic))
+    }
+}
+
+/// The appearance of a [`MenuBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub background: Background,
+    /// The [`Border`] of the bar.
+    pub border: Border,
+    /// The text [`Color`] of the [`MenuBar`].
+    pub text_color: Color,
+    /// The [`Background`] of the menus.
+    pub menu_background: Background,
+    /// The [`Border`] of the menus.
+    pub menu_border: Border,
+    /// The [`Shadow`] of the menus.
+    pub menu_shadow: Shadow,
+    /// The [`Background`] of the hovered menu of the bar.
+    pub hovered_background: Background,
+    /// The [`Background`] of the open menu of the bar and the highlighted item.
+    pub selected_background: Background,
+    /// The text [`Color`] of the open menu of the bar and the highlighted item.
+    pub selected_text_color: Color,
+    /// The text [`Color`] of the disabled items.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the shortcut hints.
+    pub shortcut_text_color: Color,
+    /// The [`Color`] of the separators.
+    pub separator_color: Color,
+}
+
+/// The theme catalog of a [`MenuBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default(),
+        text_color: palette.background.base.text,
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+            spread: 0.0,
+        },
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        disabled_text_color: palette.background.strong.color,
+        shortcut_text_color: palette.background.strongest.color,
+        separator_color: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_parse_mnemonics() {
+        let label = Label::parse("Save &As...");
+
+        assert_eq!(label.text, "Save As...");
+        assert_eq!(label.mnemonic, Some(('a', 5)));
+        assert!(label.matches('A'));
+
+        let label = Label::parse("Fish && &Chips");
+
+        assert_eq!(label.text, "Fish & Chips");
+        assert_eq!(label.mnemonic, Some(('c', 7)));
+
+        assert_eq!(Label::parse("Plain").mnemonic, None);
+    }
+}
@@ -0,0 +1,613 @@
+//! Modals display a dialog on top of some base content, blocking any
+//! interaction with it.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{button, center, column, container, modal, text};
+//!
+//! struct State {
+//!     is_confirming: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Delete,
+//!     Confirm,
+//!     Cancel,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let base = center(button("Delete").on_press(Message::Delete));
+//!
+//!     let dialog = container(
+//!         column![
+//!             text("Are you sure?"),
+//!             button("Confirm").on_press(Message::Confirm),
+//!             button("Cancel").on_press(Message::Cancel),
+//!         ]
+//!         .spacing(10),
+//!     )
+//!     .padding(20)
+//!     .style(container::rounded_box);
+//!
+//!     modal(base, dialog)
+//!         .show(state.is_confirming)
+//!         .on_escape(Message::Cancel)
+//!         .on_backdrop_press(Message::Cancel)
+//!         .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::focusable::{self, Focusable};
+use crate::core::widget::{Id, Operation, Tree, tree};
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A widget that displays a dialog on top of some base content.
+///
+/// While the dialog is shown, the base content is dimmed and does not
+/// receive any input. Keyboard focus is trapped within the dialog—Tab and
+/// Shift+Tab cycle through its focusable widgets—and it is restored to the
+/// base content once the dialog is hidden.
+///
+/// A [`Modal`] should be kept in the widget tree and toggled with
+/// [`Modal::show`], so the base content keeps its state and focus can be
+/// restored.
+pub struct Modal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    base: Element<'a, Message, Theme, Renderer>,
+    dialog: Element<'a, Message, Theme, Renderer>,
+    is_shown: bool,
+    on_escape: Option<Message>,
+    on_backdrop_press: Option<Message>,
+    padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Modal`] displaying the given dialog on top of the
+    /// given base content.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        dialog: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            dialog: dialog.into(),
+            is_shown: true,
+            on_escape: None,
+            on_backdrop_press: None,
+            padding: Padding::new(20.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the dialog of the [`Modal`] is shown.
+    ///
+    /// By default, it is shown.
+    pub fn show(mut self, is_shown: bool) -> Self {
+        self.is_shown = is_shown;
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape key is
+    /// pressed while the dialog is shown.
+    pub fn on_escape(mut self, message: Message) -> Self {
+        self.on_escape = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the backdrop around
+    /// the dialog is pressed.
+    pub fn on_backdrop_press(mut self, message: Message) -> Self {
+        self.on_backdrop_press = Some(message);
+        self
+    }
+
+    /// Sets the minimum [`Padding`] between the dialog and the edges of
+    /// the [`Modal`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Modal`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_shown: bool,
+    restore: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Modal<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.base), Tree::new(&self.dialog)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[self.base.as_widget(), self.dialog.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let base = self
+            .base
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = base.size();
+        let limits = layout::Limits::new(Size::ZERO, size).shrink(self.padding);
+
+        let dialog = self
+            .dialog
+            .as_widget_mut()
+            .layout(&mut tree.children[1], renderer, &limits)
+            .align(Alignment::Center, Alignment::Center, size);
+
+        layout::Node::with_children(size, vec![base, dialog])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog = children.next().unwrap();
+
+        // Only the visible layer can be operated on, which keeps focus
+        // operations within the dialog while it is shown
+        if self.is_shown {
+            self.dialog
+                .as_widget_mut()
+                .operate(&mut tree.children[1], dialog, renderer, operation);
+        } else {
+            self.base
+                .as_widget_mut()
+                .operate(&mut tree.children[0], base, renderer, operation);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog = children.next().unwrap();
+
+        let state = tree.state.downcast_mut::<State>();
+        let (base_tree, dialog_tree) = tree.children.split_at_mut(1);
+        let base_tree = &mut base_tree[0];
+        let dialog_tree = &mut dialog_tree[0];
+
+        if state.is_shown != self.is_shown {
+            state.is_shown = self.is_shown;
+
+            if self.is_shown {
+                state.restore = count(&mut self.base, base_tree, base, renderer).focused;
+
+                focus(&mut self.base, base_tree, base, renderer, None);
+                focus(&mut self.dialog, dialog_tree, dialog, renderer, Some(0));
+            } else {
+                focus(&mut self.dialog, dialog_tree, dialog, renderer, None);
+                focus(
+                    &mut self.base,
+                    base_tree,
+                    base,
+                    renderer,
+                    state.restore.take(),
+                );
+            }
+
+            shell.request_redraw();
+        }
+
+        if !self.is_shown {
+            self.base.as_widget_mut().update(
+                base_tree, event, base, cursor, renderer, clipboard, shell, viewport,
+            );
+
+            return;
+        }
+
+        if let Event::Window(_) = event {
+            self.base.as_widget_mut().update(
+                base_tree,
+                event,
+                base,
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        self.dialog.as_widget_mut().update(
+            dialog_tree,
+            event,
+            dialog,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                if let Some(on_escape) = &self.on_escape {
+                    shell.publish(on_escape.clone());
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) => {
+                let count = count(&mut self.dialog, dialog_tree, dialog, renderer);
+
+                if count.total > 0 {
+                    let next = match count.focused {
+                        None if modifiers.shift() => count.total - 1,
+                        None => 0,
+                        Some(focused) if modifiers.shift() => {
+                            (focused + count.total - 1) % count.total
+                        }
+                        Some(focused) => (focused + 1) % count.total,
+                    };
+
+                    focus(&mut self.dialog, dialog_tree, dialog, renderer, Some(next));
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(dialog.bounds())
+                    && let Some(on_backdrop_press) = &self.on_backdrop_press
+                {
+                    shell.publish(on_backdrop_press.clone());
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(_) | Event::Touch(_) => {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog = children.next().unwrap();
+
+        if !self.is_shown {
+            self.base.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                base,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            base,
+            mouse::Cursor::Unavailable,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        let appearance = theme.style(&self.class);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.backdrop,
+            );
+
+            self.dialog.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                dialog,
+                cursor,
+                &bounds,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog = children.next().unwrap();
+
+        if self.is_shown {
+            self.dialog.as_widget().mouse_interaction(
+                &tree.children[1],
+                dialog,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            self.base.as_widget().mouse_interaction(
+                &tree.children[0],
+                base,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let base = children.next().unwrap();
+        let dialog = children.next().unwrap();
+
+        if self.is_shown {
+            self.dialog.as_widget_mut().overlay(
+                &mut tree.children[1],
+                dialog,
+                renderer,
+                viewport,
+                translation,
+            )
+        } else {
+            self.base.as_widget_mut().overlay(
+                &mut tree.children[0],
+                base,
+                renderer,
+                viewport,
+                translation,
+            )
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Modal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(modal: Modal<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(modal)
+    }
+}
+
+/// Counts the focusable widgets of some content.
+fn count<Message, Theme, Renderer>(
+    content: &mut Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+) -> focusable::Count
+where
+    Renderer: crate::core::Renderer,
+{
+    struct CountFocusable {
+        count: focusable::Count,
+    }
+
+    impl Operation for CountFocusable {
+        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+            if state.is_focused() {
+                self.count.focused = Some(self.count.total);
+            }
+
+            self.count.total += 1;
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+    }
+
+    let mut operation = CountFocusable {
+        count: focusable::Count::default(),
+    };
+
+    content
+        .as_widget_mut()
+        .operate(tree, layout, renderer, &mut operation);
+
+    operation.count
+}
+
+/// Focuses the focusable widget of some content with the given index,
+/// unfocusing the rest.
+fn focus<Message, Theme, Renderer>(
+    content: &mut Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    target: Option<usize>,
+) where
+    Renderer: crate::core::Renderer,
+{
+    struct FocusIndex {
+        target: Option<usize>,
+        current: usize,
+    }
+
+    impl Operation for FocusIndex {
+        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+            if self.target == Some(self.current) {
+                state.focus();
+            } else if state.is_focused() {
+                state.unfocus();
+            }
+
+            self.current += 1;
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+    }
+
+    content.as_widget_mut().operate(
+        tree,
+        layout,
+        renderer,
+        &mut FocusIndex { target, current: 0 },
+    );
+}
+
+/// The appearance of a [`Modal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] dimming the base content.
+    pub backdrop: Background,
+}
+
+/// The theme catalog of a [`Modal`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Modal`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Modal`].
+pub fn default(_theme: &crate::Theme) -> Style {
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.8).into(),
+    }
+}
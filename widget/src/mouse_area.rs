@@ -3,8 +3,10 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
 use crate::core::touch;
 use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
@@ -15,6 +17,9 @@ pub struct MouseArea<'a, Message, Theme = crate::Theme, Renderer = crate::Render
     on_press: Option<Message>,
     on_release: Option<Message>,
     on_double_click: Option<Message>,
+    on_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_long_press: Option<Message>,
+    long_press_duration: Duration,
     on_right_press: Option<Message>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
@@ -23,6 +28,11 @@ pub struct MouseArea<'a, Message, Theme = crate::Theme, Renderer = crate::Render
     on_enter: Option<Message>,
     on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_exit: Option<Message>,
+    on_drag_start: Option<Message>,
+    on_drag: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_drag_end: Option<Message>,
+    drag_button: mouse::Button,
+    drag_threshold: f32,
     interaction: Option<mouse::Interaction>,
 }
 
@@ -57,6 +67,35 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit on a left button press, given the amount of
+    /// consecutive clicks.
+    ///
+    /// Clicks are consecutive when they happen close enough to each other
+    /// within the double-click interval of the platform.
+    #[must_use]
+    pub fn on_click(mut self, on_click: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// The message to emit when the left button or a finger is held
+    /// in place for the long press duration.
+    #[must_use]
+    pub fn on_long_press(mut self, message: Message) -> Self {
+        self.on_long_press = Some(message);
+        self
+    }
+
+    /// Sets the time the left button or a finger must be held for a
+    /// long press.
+    ///
+    /// By default, it is 500 milliseconds.
+    #[must_use]
+    pub fn long_press_duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.long_press_duration = duration.into();
+        self
+    }
+
     /// The message to emit on a right button press.
     #[must_use]
     pub fn on_right_press(mut self, message: Message) -> Self {
@@ -113,6 +152,55 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when a drag starts in the area.
+    ///
+    /// A drag starts once the cursor moves past the drag threshold
+    /// while the drag button is held.
+    #[must_use]
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// The message to emit when the cursor moves during a drag, given
+    /// the distance it moved since the last message.
+    ///
+    /// The first message includes the whole distance moved from the
+    /// position where the drag button was pressed. The drag continues
+    /// even if the cursor leaves the area.
+    #[must_use]
+    pub fn on_drag(mut self, on_drag: impl Fn(Vector) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// The message to emit when a drag ends.
+    #[must_use]
+    pub fn on_drag_end(mut self, message: Message) -> Self {
+        self.on_drag_end = Some(message);
+        self
+    }
+
+    /// Sets the [`mouse::Button`] used to drag the area.
+    ///
+    /// By default, it is [`mouse::Button::Left`]. Touches can only be used
+    /// to drag with the left button.
+    #[must_use]
+    pub fn drag_button(mut self, button: mouse::Button) -> Self {
+        self.drag_button = button;
+        self
+    }
+
+    /// Sets the distance, in logical pixels, that the cursor must move
+    /// with the drag button held for a drag to start.
+    ///
+    /// By default, it is 4 pixels.
+    #[must_use]
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
@@ -128,6 +216,24 @@ struct State {
     bounds: Rectangle,
     cursor_position: Option<Point>,
     previous_click: Option<mouse::Click>,
+    drag: Option<Drag>,
+    long_press: Option<LongPress>,
+}
+
+/// A press of a [`MouseArea`] that may become a long press.
+#[derive(Debug, Clone, Copy)]
+struct LongPress {
+    origin: Point,
+    deadline: Instant,
+}
+
+/// The drag of a [`MouseArea`].
+#[derive(Debug, Clone, Copy)]
+enum Drag {
+    /// The drag button is held, but the threshold has not been reached yet.
+    Pending { origin: Point },
+    /// The drag started and the cursor was last at the given position.
+    Active { last: Point },
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -138,6 +244,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_press: None,
             on_release: None,
             on_double_click: None,
+            on_click: None,
+            on_long_press: None,
+            long_press_duration: milliseconds(500),
             on_right_press: None,
             on_right_release: None,
             on_middle_press: None,
@@ -146,9 +255,36 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_enter: None,
             on_move: None,
             on_exit: None,
+            on_drag_start: None,
+            on_drag: None,
+            on_drag_end: None,
+            drag_button: mouse::Button::Left,
+            drag_threshold: 4.0,
             interaction: None,
         }
     }
+
+    /// Returns whether the [`mouse::Cursor`] is over the [`MouseArea`], taking
+    /// the shape of its content into account.
+    fn is_over(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> bool
+    where
+        Renderer: renderer::Renderer,
+    {
+        cursor
+            .position_over(layout.bounds())
+            .is_some_and(|position| {
+                self.content
+                    .as_widget()
+                    .hit_test(&tree.children[0], layout, renderer, position)
+                    != Some(false)
+            })
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -226,7 +362,9 @@ where
             return;
         }
 
-        update(self, tree, event, layout, cursor, shell);
+        let is_over = self.is_over(tree, layout, cursor, renderer);
+
+        update(self, tree, event, layout, cursor, is_over, shell);
     }
 
     fn mouse_interaction(
@@ -246,7 +384,9 @@ where
         );
 
         match (self.interaction, content_interaction) {
-            (Some(interaction), mouse::Interaction::None) if cursor.is_over(layout.bounds()) => {
+            (Some(interaction), mouse::Interaction::None)
+                if self.is_over(tree, layout, cursor, renderer) =>
+            {
                 interaction
             }
             _ => content_interaction,
@@ -314,6 +454,7 @@ fn update<Message: Clone, Theme, Renderer>(
     event: &Event,
     layout: Layout<'_>,
     cursor: mouse::Cursor,
+    is_over: bool,
     shell: &mut Shell<'_, Message>,
 ) {
     let state: &mut State = tree.state.downcast_mut();
@@ -324,7 +465,7 @@ fn update<Message: Clone, Theme, Renderer>(
     if state.cursor_position != cursor_position || state.bounds != bounds {
         let was_hovered = state.is_hovered;
 
-        state.is_hovered = cursor.is_over(layout.bounds());
+        state.is_hovered = is_over;
         state.cursor_position = cursor_position;
         state.bounds = bounds;
 
@@ -348,7 +489,15 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
-    if !cursor.is_over(layout.bounds()) {
+    if widget.on_drag_start.is_some() || widget.on_drag.is_some() || widget.on_drag_end.is_some() {
+        update_drag(widget, state, event, cursor_position, is_over, shell);
+    }
+
+    if widget.on_long_press.is_some() {
+        update_long_press(widget, state, event, cursor_position, is_over, shell);
+    }
+
+    if !is_over {
         return;
     }
 
@@ -361,12 +510,18 @@ fn update<Message: Clone, Theme, Renderer>(
             }
 
             if let Some(position) = cursor_position
-                && let Some(message) = widget.on_double_click.as_ref()
+                && (widget.on_double_click.is_some() || widget.on_click.is_some())
             {
                 let new_click =
                     mouse::Click::new(position, mouse::Button::Left, state.previous_click);
 
-                if new_click.kind() == mouse::click::Kind::Double {
+                if let Some(on_click) = widget.on_click.as_ref() {
+                    shell.publish(on_click(new_click.count()));
+                }
+
+                if let Some(message) = widget.on_double_click.as_ref()
+                    && new_click.kind() == mouse::click::Kind::Double
+                {
                     shell.publish(message.clone());
                 }
 
@@ -414,3 +569,132 @@ fn update<Message: Clone, Theme, Renderer>(
         _ => {}
     }
 }
+
+/// Processes the given [`Event`] and updates the [`Drag`] of a [`MouseArea`]
+/// accordingly.
+fn update_drag<Message: Clone, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
+    state: &mut State,
+    event: &Event,
+    cursor_position: Option<Point>,
+    is_over: bool,
+    shell: &mut Shell<'_, Message>,
+) {
+    let is_touch = widget.drag_button == mouse::Button::Left;
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(button)) if *button == widget.drag_button => {
+            if is_over && let Some(position) = cursor_position {
+                state.drag = Some(Drag::Pending { origin: position });
+                shell.capture_event();
+            }
+        }
+        Event::Touch(touch::Event::FingerPressed { position, .. }) if is_touch => {
+            if is_over {
+                state.drag = Some(Drag::Pending { origin: *position });
+                shell.capture_event();
+            }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => match state.drag {
+            Some(Drag::Pending { origin }) => {
+                if position.distance(origin) >= widget.drag_threshold {
+                    if let Some(message) = widget.on_drag_start.as_ref() {
+                        shell.publish(message.clone());
+                    }
+
+                    if let Some(on_drag) = widget.on_drag.as_ref() {
+                        shell.publish(on_drag(*position - origin));
+                    }
+
+                    state.drag = Some(Drag::Active { last: *position });
+                    shell.capture_event();
+                }
+            }
+            Some(Drag::Active { last }) => {
+                if let Some(on_drag) = widget.on_drag.as_ref() {
+                    shell.publish(on_drag(*position - last));
+                }
+
+                state.drag = Some(Drag::Active { last: *position });
+                shell.capture_event();
+            }
+            None => {}
+        },
+        Event::Mouse(mouse::Event::ButtonReleased(button)) if *button == widget.drag_button => {
+            end_drag(widget, state, shell);
+        }
+        Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+            if is_touch =>
+        {
+            end_drag(widget, state, shell);
+        }
+        _ => {}
+    }
+}
+
+fn end_drag<Message: Clone, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
+    state: &mut State,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some(Drag::Active { .. }) = state.drag.take()
+        && let Some(message) = widget.on_drag_end.as_ref()
+    {
+        shell.publish(message.clone());
+    }
+}
+
+/// Processes the given [`Event`] and updates the [`LongPress`] of a
+/// [`MouseArea`] accordingly.
+fn update_long_press<Message: Clone, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
+    state: &mut State,
+    event: &Event,
+    cursor_position: Option<Point>,
+    is_over: bool,
+    shell: &mut Shell<'_, Message>,
+) {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let position = match event {
+                Event::Touch(touch::Event::FingerPressed { position, .. }) => Some(*position),
+                _ => cursor_position,
+            };
+
+            if is_over && let Some(origin) = position {
+                let deadline = Instant::now() + widget.long_press_duration;
+
+                state.long_press = Some(LongPress { origin, deadline });
+                shell.request_redraw_at(deadline);
+            }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            if let Some(long_press) = state.long_press
+                && position.distance(long_press.origin) >= widget.drag_threshold
+            {
+                state.long_press = None;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+            state.long_press = None;
+        }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            if let Some(long_press) = state.long_press {
+                if *now >= long_press.deadline {
+                    state.long_press = None;
+
+                    if let Some(message) = widget.on_long_press.as_ref() {
+                        shell.publish(message.clone());
+                    }
+                } else {
+                    shell.request_redraw_at(long_press.deadline);
+                }
+            }
+        }
+        _ => {}
+    }
+}
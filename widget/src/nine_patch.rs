@@ -0,0 +1,330 @@
+//! Nine-patches stretch an image or a vector graphic without distorting its borders.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{image, nine_patch};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     nine_patch(image::Handle::from_path("bubble.png"), 12)
+//!         .width(300)
+//!         .height(80)
+//!         .into()
+//! }
+//! ```
+use crate::core::image::{self, FilterMethod};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{Element, Layout, Length, Padding, Rectangle, Size, Widget};
+
+#[cfg(feature = "svg")]
+use crate::core::svg;
+
+/// A widget that stretches an [`Asset`] by slicing it in nine regions.
+///
+/// The corners of the [`Asset`] are drawn at their original size, its edges are
+/// only stretched along their own direction, and its center is stretched in both
+/// directions. This way, skinned buttons, chat bubbles, or panels can be
+/// scaled to any size without distorting their borders.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{image, nine_patch};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     nine_patch(image::Handle::from_path("bubble.png"), 12)
+///         .width(300)
+///         .height(80)
+///         .into()
+/// }
+/// ```
+pub struct NinePatch<A = image::Handle> {
+    asset: A,
+    insets: Padding,
+    width: Length,
+    height: Length,
+    scale: f32,
+    filter_method: FilterMethod,
+    opacity: f32,
+}
+
+impl<A> NinePatch<A> {
+    /// Creates a new [`NinePatch`] with the given [`Asset`] and insets.
+    ///
+    /// The insets are the sizes of the borders of the [`Asset`]—in its own
+    /// pixels—which will not be stretched.
+    pub fn new(asset: A, insets: impl Into<Padding>) -> Self {
+        Self {
+            asset,
+            insets: insets.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            scale: 1.0,
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the width of the [`NinePatch`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`NinePatch`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the scale of the borders of the [`NinePatch`].
+    ///
+    /// This can be useful to draw high resolution assets with thinner borders.
+    ///
+    /// Defaults to `1.0`.
+    pub fn scale(mut self, scale: impl Into<f32>) -> Self {
+        self.scale = scale.into();
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`NinePatch`].
+    ///
+    /// It is ignored by vector graphics.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the opacity of the [`NinePatch`].
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+}
+
+/// Some graphics that can be sliced by a [`NinePatch`].
+pub trait Asset<Renderer> {
+    /// Returns the size of the [`Asset`], in pixels.
+    fn measure(&self, renderer: &Renderer) -> Size<u32>;
+
+    /// Draws the given `region` of the [`Asset`] stretched to the given `bounds`.
+    fn draw_region(
+        &self,
+        renderer: &mut Renderer,
+        region: Rectangle,
+        bounds: Rectangle,
+        filter_method: FilterMethod,
+        opacity: f32,
+    );
+}
+
+#[cfg(feature = "image")]
+impl<Renderer> Asset<Renderer> for image::Handle
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn measure(&self, renderer: &Renderer) -> Size<u32> {
+        renderer.measure_image(self).unwrap_or_default()
+    }
+
+    fn draw_region(
+        &self,
+        renderer: &mut Renderer,
+        region: Rectangle,
+        bounds: Rectangle,
+        filter_method: FilterMethod,
+        opacity: f32,
+    ) {
+        let size = Asset::<Renderer>::measure(self, renderer);
+
+        let scale_x = bounds.width / region.width;
+        let scale_y = bounds.height / region.height;
+
+        // The whole image is stretched so the region fills the bounds,
+        // which then clip it; just like cropping an `Image`
+        let drawing_bounds = Rectangle {
+            x: bounds.x - region.x * scale_x,
+            y: bounds.y - region.y * scale_y,
+            width: size.width as f32 * scale_x,
+            height: size.height as f32 * scale_y,
+        };
+
+        renderer.draw_image(
+            image::Image::new(self.clone())
+                .filter_method(filter_method)
+                .opacity(opacity)
+                .snap(true),
+            drawing_bounds,
+            bounds,
+        );
+    }
+}
+
+#[cfg(feature = "svg")]
+impl<Renderer> Asset<Renderer> for svg::Handle
+where
+    Renderer: svg::Renderer,
+{
+    fn measure(&self, renderer: &Renderer) -> Size<u32> {
+        renderer.measure_svg(self)
+    }
+
+    fn draw_region(
+        &self,
+        renderer: &mut Renderer,
+        region: Rectangle,
+        bounds: Rectangle,
+        _filter_method: FilterMethod,
+        opacity: f32,
+    ) {
+        renderer.draw_svg(
+            svg::Svg::new(self.clone())
+                .viewport(region)
+                .opacity(opacity),
+            bounds,
+            bounds,
+        );
+    }
+}
+
+impl<Message, Theme, Renderer, A> Widget<Message, Theme, Renderer> for NinePatch<A>
+where
+    Renderer: renderer::Renderer,
+    A: Asset<Renderer>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.asset.measure(renderer);
+        let intrinsic = Size::new(size.width as f32, size.height as f32) * self.scale;
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let size = self.asset.measure(renderer);
+
+        for (region, bounds) in slices(size, self.insets, self.scale, bounds) {
+            self.asset
+                .draw_region(renderer, region, bounds, self.filter_method, self.opacity);
+        }
+    }
+}
+
+/// Returns the regions of an asset of the given `size` and the bounds they
+/// are stretched to, skipping any empty ones.
+fn slices(
+    size: Size<u32>,
+    insets: Padding,
+    scale: f32,
+    bounds: Rectangle,
+) -> impl Iterator<Item = (Rectangle, Rectangle)> {
+    let size = Size::new(size.width as f32, size.height as f32);
+
+    let (left, right) = fit(insets.left, insets.right, size.width);
+    let (top, bottom) = fit(insets.top, insets.bottom, size.height);
+
+    let (target_left, target_right) = fit(left * scale, right * scale, bounds.width);
+    let (target_top, target_bottom) = fit(top * scale, bottom * scale, bounds.height);
+
+    let columns = [0.0, left, size.width - right, size.width];
+    let rows = [0.0, top, size.height - bottom, size.height];
+
+    let target_columns = [
+        bounds.x,
+        bounds.x + target_left,
+        bounds.x + bounds.width - target_right,
+        bounds.x + bounds.width,
+    ];
+
+    let target_rows = [
+        bounds.y,
+        bounds.y + target_top,
+        bounds.y + bounds.height - target_bottom,
+        bounds.y + bounds.height,
+    ];
+
+    (0..3)
+        .flat_map(|row| (0..3).map(move |column| (row, column)))
+        .filter_map(move |(row, column)| {
+            let region = Rectangle {
+                x: columns[column],
+                y: rows[row],
+                width: columns[column + 1] - columns[column],
+                height: rows[row + 1] - rows[row],
+            };
+
+            let bounds = Rectangle {
+                x: target_columns[column],
+                y: target_rows[row],
+                width: target_columns[column + 1] - target_columns[column],
+                height: target_rows[row + 1] - target_rows[row],
+            };
+
+            (region.width > 0.0 && region.height > 0.0 && bounds.width > 0.0 && bounds.height > 0.0)
+                .then_some((region, bounds))
+        })
+}
+
+/// Shrinks the given pair of insets proportionally, so they fit in `length`.
+fn fit(start: f32, end: f32, length: f32) -> (f32, f32) {
+    let start = start.max(0.0);
+    let end = end.max(0.0);
+    let total = start + end;
+
+    if total > length && total > 0.0 {
+        let ratio = length.max(0.0) / total;
+
+        (start * ratio, end * ratio)
+    } else {
+        (start, end)
+    }
+}
+
+impl<'a, Message, Theme, Renderer, A> From<NinePatch<A>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    A: Asset<Renderer> + 'a,
+{
+    fn from(nine_patch: NinePatch<A>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(nine_patch)
+    }
+}
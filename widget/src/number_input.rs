@@ -0,0 +1,898 @@
+//! Number inputs let users type a number or change it step by step.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, number_input};
+//!
+//! struct State {
+//!    quantity: u32,
+//!    price: f64,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     QuantityChanged(u32),
+//!     PriceChanged(f64),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         number_input(state.quantity, Message::QuantityChanged).max(99u32),
+//!         number_input(state.price, Message::PriceChanged)
+//!             .step(0.05)
+//!             .min(0.0)
+//!             .decimal_separator(','),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size,
+};
+use crate::text_input::{self, TextInput};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+const SPIN_BUTTON_WIDTH: f32 = 20.0;
+
+/// How long a spin button must be held down before it starts repeating.
+const SPIN_DELAY: Duration = Duration::from_millis(400);
+
+/// How often a held spin button repeats its step.
+const SPIN_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A field that can be used to type a number, with a pair of spin buttons to
+/// increment and decrement it.
+///
+/// A [`NumberInput`] is generic over the numeric type of its value, which may
+/// be an integer or a float. Its value can also be stepped with the mouse
+/// wheel and the arrow keys, while focused. Holding shift uses the
+/// [`shift_step`](Self::shift_step) instead, if set.
+///
+/// Typed text that is not a valid number—or is out of bounds—is kept as is
+/// and styled as invalid, but it does not produce any messages. Submitting
+/// it clamps it to the nearest valid number.
+pub struct NumberInput<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    value: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    step: T,
+    shift_step: Option<T>,
+    min: Option<T>,
+    max: Option<T>,
+    decimal_separator: char,
+    width: Length,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    input: TextInput<'a, Edit, Theme, Renderer>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + Display + From<u8>,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`] with the given value and the function
+    /// that produces a message when the value changes.
+    ///
+    /// The step of the [`NumberInput`] defaults to 1.
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        let mut number_input = Self {
+            value,
+            on_change: Box::new(on_change),
+            step: T::from(1),
+            shift_step: None,
+            min: None,
+            max: None,
+            decimal_separator: '.',
+            width: Length::Fill,
+            text_size: None,
+            font: None,
+            input: TextInput::new("", ""),
+            class: <Theme as Catalog>::default(),
+        };
+
+        number_input.input = number_input.input(&format(value, '.'), true);
+        number_input
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Copy + Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step of the [`NumberInput`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: impl Into<T>) -> Self {
+        self.min = Some(min.into());
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: impl Into<T>) -> Self {
+        self.max = Some(max.into());
+        self
+    }
+
+    /// Sets the decimal separator of the [`NumberInput`], which is used both
+    /// to display and to parse its value.
+    ///
+    /// By default, it is a period (`.`). Set it to a comma (`,`) for the
+    /// many locales that use one instead.
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`NumberInput`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the spin buttons of the [`NumberInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the spin buttons of the [`NumberInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn input(&self, buffer: &str, is_valid: bool) -> TextInput<'a, Edit, Theme, Renderer> {
+        let input = TextInput::new("", buffer)
+            .on_input(Edit::Input)
+            .on_submit(Edit::Submit)
+            .width(Length::Fill)
+            .class(if is_valid {
+                Theme::default_input()
+            } else {
+                Theme::invalid_input()
+            });
+
+        let input = match self.text_size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> NumberInput<'_, T, Message, Theme, Renderer>
+where
+    T: Copy + PartialOrd + Display + FromStr + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Parses the given buffer, returning the number only if it is in bounds.
+    fn parse(&self, buffer: &str) -> Option<T> {
+        parse(buffer, self.decimal_separator).filter(|value| self.clamp(*value) == *value)
+    }
+
+    fn clamp(&self, value: T) -> T {
+        let value = match self.min {
+            Some(min) if value < min => min,
+            _ => value,
+        };
+
+        match self.max {
+            Some(max) if value > max => max,
+            _ => value,
+        }
+    }
+
+    /// Returns the current value of the [`NumberInput`], which may be ahead
+    /// of the application when stepping several times in a row.
+    fn current(&self, state: &State) -> T {
+        self.parse(&state.buffer).unwrap_or(self.value)
+    }
+
+    fn can_step(&self, state: &State, direction: Direction) -> bool {
+        let value = self.current(state);
+
+        match direction {
+            Direction::Increment => self.max.is_none_or(|max| value < max),
+            Direction::Decrement => self.min.is_none_or(|min| value > min),
+        }
+    }
+
+    fn step_by(&self, state: &mut State, direction: Direction, shell: &mut Shell<'_, Message>) {
+        let step: f64 = if state.modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into();
+
+        if step <= 0.0 {
+            return;
+        }
+
+        let value: f64 = self.current(state).into();
+        let steps = value / step;
+
+        // Values in between steps are snapped to the nearest one first
+        let steps = if (steps - steps.round()).abs() < 1e-6 {
+            steps.round()
+        } else {
+            match direction {
+                Direction::Increment => steps.floor(),
+                Direction::Decrement => steps.ceil(),
+            }
+        };
+
+        let new_value = match direction {
+            Direction::Increment => step * (steps + 1.0),
+            Direction::Decrement => step * (steps - 1.0),
+        };
+
+        // Avoid floating point noise by rounding to the precision of the step
+        let factor = 10f64.powi(decimals(step));
+        let new_value = (new_value * factor).round() / factor;
+
+        let Some(new_value) = T::from_f64(new_value).map(|value| self.clamp(value)) else {
+            return;
+        };
+
+        if (new_value.into() - value).abs() > f64::EPSILON {
+            state.buffer = format(new_value, self.decimal_separator);
+
+            shell.publish((self.on_change)(new_value));
+            shell.invalidate_layout();
+        }
+    }
+
+    fn spin_buttons(&self, layout: Layout<'_>) -> [(Direction, Rectangle); 2] {
+        let bounds = layout.bounds();
+        let half = bounds.height / 2.0;
+
+        let x = bounds.x + bounds.width - SPIN_BUTTON_WIDTH;
+
+        [
+            (
+                Direction::Increment,
+                Rectangle {
+                    x,
+                    y: bounds.y,
+                    width: SPIN_BUTTON_WIDTH,
+                    height: half,
+                },
+            ),
+            (
+                Direction::Decrement,
+                Rectangle {
+                    x,
+                    y: bounds.y + half,
+                    width: SPIN_BUTTON_WIDTH,
+                    height: bounds.height - half,
+                },
+            ),
+        ]
+    }
+
+    fn spin_button_at(&self, layout: Layout<'_>, position: Point) -> Option<Direction> {
+        self.spin_buttons(layout)
+            .into_iter()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(direction, _)| direction)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Submit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Increment,
+    Decrement,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Spin {
+    direction: Direction,
+    next: Instant,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    buffer: String,
+    value: Option<f64>,
+    spin: Option<Spin>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'_, T, Message, Theme, Renderer>
+where
+    T: Copy + PartialOrd + Display + FromStr + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            buffer: format(self.value, self.decimal_separator),
+            value: Some(self.value.into()),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let value = self.value.into();
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        // A focused input keeps what is being typed, unless the value is
+        // changed by the application
+        if !is_focused
+            || (state.value != Some(value) && self.parse(&state.buffer) != Some(self.value))
+        {
+            state.buffer = format(self.value, self.decimal_separator);
+        }
+
+        state.value = Some(value);
+
+        let is_valid = self.parse(&state.buffer).is_some();
+        self.input = self.input(&state.buffer, is_valid);
+
+        let limits = limits.width(self.width);
+
+        let input = self.input.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(SPIN_BUTTON_WIDTH, 0.0)),
+            None,
+        );
+
+        let size = input.size();
+
+        layout::Node::with_children(
+            Size::new(size.width + SPIN_BUTTON_WIDTH, size.height),
+            vec![input],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input.operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if is_focused => {
+                let direction = match named {
+                    key::Named::ArrowUp => Some(Direction::Increment),
+                    key::Named::ArrowDown => Some(Direction::Decrement),
+                    _ => None,
+                };
+
+                if let Some(direction) = direction {
+                    self.step_by(state, direction, shell);
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        self.input.update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for edit in edits {
+            match edit {
+                Edit::Input(buffer) => {
+                    if let Some(value) = self.parse(&buffer) {
+                        shell.publish((self.on_change)(value));
+                    }
+
+                    state.buffer = buffer;
+                }
+                Edit::Submit => {
+                    let value = parse(&state.buffer, self.decimal_separator)
+                        .map(|value| self.clamp(value))
+                        .unwrap_or(self.value);
+
+                    if self.parse(&state.buffer).is_none() && value != self.value {
+                        shell.publish((self.on_change)(value));
+                    }
+
+                    state.buffer = format(value, self.decimal_separator);
+                }
+            }
+
+            shell.invalidate_layout();
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(layout.bounds())
+                    && let Some(direction) = self.spin_button_at(layout, position)
+                {
+                    if self.can_step(state, direction) {
+                        self.step_by(state, direction, shell);
+
+                        let next = Instant::now() + SPIN_DELAY;
+
+                        state.spin = Some(Spin { direction, next });
+                        shell.request_redraw_at(next);
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.spin.take().is_some() {
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !is_focused || !cursor.is_over(layout.bounds()) {
+                    return;
+                }
+
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y
+                    }
+                };
+
+                let direction = if y > 0.0 {
+                    Direction::Increment
+                } else if y < 0.0 {
+                    Direction::Decrement
+                } else {
+                    return;
+                };
+
+                self.step_by(state, direction, shell);
+                shell.capture_event();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(spin) = state.spin {
+                    if !self.can_step(state, spin.direction) {
+                        state.spin = None;
+                    } else if *now >= spin.next {
+                        self.step_by(state, spin.direction, shell);
+
+                        let next = *now + SPIN_INTERVAL;
+
+                        state.spin = Some(Spin { next, ..spin });
+                        shell.request_redraw_at(next);
+                    } else {
+                        shell.request_redraw_at(spin.next);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(position) = cursor.position_over(layout.bounds())
+            && let Some(direction) = self.spin_button_at(layout, position)
+        {
+            return if self.can_step(state, direction) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::NotAllowed
+            };
+        }
+
+        self.input.mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout.children().next().unwrap(),
+            cursor,
+            None,
+            viewport,
+        );
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size()) * 0.75;
+
+        for (direction, bounds) in self.spin_buttons(layout) {
+            let status = if !self.can_step(state, direction) {
+                Status::Disabled
+            } else if state.spin.is_some_and(|spin| spin.direction == direction) {
+                Status::Pressed
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = <Theme as Catalog>::style(theme, &self.class, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: match direction {
+                        Direction::Increment => Renderer::SCROLL_UP_ICON,
+                        Direction::Decrement => Renderer::SCROLL_DOWN_ICON,
+                    }
+                    .to_string(),
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: Renderer::ICON_FONT,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: None,
+                },
+                bounds.center(),
+                style.icon_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + PartialOrd + Display + FromStr + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(number_input: NumberInput<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(number_input)
+    }
+}
+
+/// Parses a number written with the given decimal separator.
+fn parse<T: FromStr>(buffer: &str, separator: char) -> Option<T> {
+    let buffer = buffer.trim();
+
+    if separator == '.' {
+        buffer.parse().ok()
+    } else if buffer.contains('.') {
+        None
+    } else {
+        buffer.replace(separator, ".").parse().ok()
+    }
+}
+
+/// Formats a number with the given decimal separator.
+fn format<T: Display>(value: T, separator: char) -> String {
+    let value = value.to_string();
+
+    if separator == '.' {
+        value
+    } else {
+        value.replace('.', &separator.to_string())
+    }
+}
+
+/// Returns the amount of decimal digits of a step.
+fn decimals(step: f64) -> i32 {
+    step.to_string()
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len() as i32)
+}
+
+/// The possible status of a spin button of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The spin button can be pressed.
+    Active,
+    /// The spin button is being hovered.
+    Hovered,
+    /// The spin button is being pressed.
+    Pressed,
+    /// The spin button cannot be pressed, since the value is at its bound.
+    Disabled,
+}
+
+/// The appearance of the spin buttons of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the spin button.
+    pub background: Background,
+    /// The [`Border`] of the spin button.
+    pub border: Border,
+    /// The [`Color`] of the arrow of the spin button.
+    pub icon_color: Color,
+}
+
+/// The theme catalog of a [`NumberInput`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the input field of the [`NumberInput`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The class for the input field of the [`NumberInput`] while it holds
+    /// an invalid number.
+    fn invalid_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        Self::default_input()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`NumberInput`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn invalid_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        Box::new(invalid)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of the spin buttons of a [`NumberInput`].
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        icon_color: palette.background.weak.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            background: palette.background.strong.color.into(),
+            icon_color: palette.background.strong.text,
+            ..active
+        },
+        Status::Pressed => Style {
+            background: palette.primary.weak.color.into(),
+            icon_color: palette.primary.weak.text,
+            ..active
+        },
+        Status::Disabled => Style {
+            icon_color: palette.background.strong.color,
+            ..active
+        },
+    }
+}
+
+/// The style of the input field of a [`NumberInput`] holding an invalid number.
+pub fn invalid(theme: &crate::Theme, status: text_input::Status) -> text_input::Style {
+    let palette = theme.extended_palette();
+    let style = text_input::default(theme, status);
+
+    text_input::Style {
+        border: Border {
+            color: match status {
+                text_input::Status::Focused { .. } => palette.danger.strong.color,
+                _ => palette.danger.base.color,
+            },
+            ..style.border
+        },
+        ..style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_round_trip_with_decimal_separators() {
+        assert_eq!(format(1.5, ','), "1,5");
+        assert_eq!(parse::<f32>("1,5", ','), Some(1.5));
+        assert_eq!(parse::<f32>(" 1,5 ", ','), Some(1.5));
+        assert_eq!(parse::<f32>("1.5", ','), None);
+        assert_eq!(parse::<f32>("1.5", '.'), Some(1.5));
+        assert_eq!(parse::<u32>("-1", '.'), None);
+        assert_eq!(parse::<u32>("", '.'), None);
+
+        assert_eq!(decimals(1.0), 0);
+        assert_eq!(decimals(0.05), 2);
+    }
+}
@@ -5,8 +5,9 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::text::{self, Text};
+use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -14,8 +15,11 @@ use crate::core::{
     Theme, Vector,
 };
 use crate::core::{Element, Shell, Widget};
+use crate::pick_list::Icon;
 use crate::scrollable::{self, Scrollable};
 
+use std::ops;
+
 /// A list of selectable options.
 pub struct Menu<'a, 'b, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
@@ -34,6 +38,11 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    highlight: Option<&'a str>,
+    is_disabled: Option<&'a dyn Fn(&T) -> bool>,
+    group: Option<&'a dyn Fn(&T) -> Option<String>>,
+    icon: Option<&'a dyn Fn(&T) -> Option<Icon<Renderer::Font>>>,
+    footer: Option<&'a str>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -67,6 +76,11 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
             font: None,
+            highlight: None,
+            is_disabled: None,
+            group: None,
+            icon: None,
+            footer: None,
             class,
         }
     }
@@ -107,6 +121,47 @@ where
         self
     }
 
+    /// Highlights the parts of the options of the [`Menu`] matching the
+    /// given query, ignoring case.
+    pub fn highlight(mut self, query: &'a str) -> Self {
+        self.highlight = Some(query);
+        self
+    }
+
+    /// Sets the function that determines whether an option of the [`Menu`]
+    /// is disabled.
+    ///
+    /// Disabled options are shown, but they cannot be hovered nor selected.
+    pub fn disabled(mut self, is_disabled: &'a dyn Fn(&T) -> bool) -> Self {
+        self.is_disabled = Some(is_disabled);
+        self
+    }
+
+    /// Sets the function that returns the group of an option of the [`Menu`].
+    ///
+    /// Consecutive options of different groups are separated and, unless the
+    /// name of the new group is empty, preceded by a header with it.
+    pub fn group(mut self, group: &'a dyn Fn(&T) -> Option<String>) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Sets the function that returns the [`Icon`] shown before an option of
+    /// the [`Menu`], if any.
+    pub fn icon(mut self, icon: &'a dyn Fn(&T) -> Option<Icon<Renderer::Font>>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the text of an additional row shown after the options of the
+    /// [`Menu`], like a loading indicator.
+    ///
+    /// The row cannot be hovered nor selected.
+    pub fn footer(mut self, footer: &'a str) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -134,6 +189,7 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    revealed: Option<usize>,
 }
 
 impl State {
@@ -141,6 +197,7 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            revealed: None,
         }
     }
 }
@@ -159,8 +216,14 @@ where
     position: Point,
     viewport: Rectangle,
     tree: &'a mut Tree,
+    revealed: &'a mut Option<usize>,
+    hovered: Option<usize>,
+    reveal: Option<(f32, f32)>,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
     target_height: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
@@ -194,9 +257,34 @@ where
             text_size,
             text_line_height,
             text_shaping,
+            highlight,
+            is_disabled,
+            group,
+            icon,
+            footer,
             class,
         } = menu;
 
+        let mut rows = rows(options, group);
+
+        if footer.is_some() {
+            let start = rows.last().map_or(0.0, |row| row.start + row.size);
+
+            rows.push(Row {
+                kind: RowKind::Footer,
+                start,
+                size: 1.0,
+            });
+        }
+        let hovered = *hovered_option;
+
+        // The vertical range of the hovered option, in option heights
+        let reveal = hovered.and_then(|index| {
+            rows.iter()
+                .find(|row| row.kind == RowKind::Option(index))
+                .map(|row| (row.start, row.start + row.size))
+        });
+
         let list = Scrollable::new(List {
             options,
             hovered_option,
@@ -207,6 +295,12 @@ where
             text_line_height,
             text_shaping,
             padding,
+            highlight,
+            is_disabled,
+            group,
+            icon,
+            footer,
+            rows,
             class,
         })
         .height(menu_height);
@@ -217,8 +311,14 @@ where
             position,
             viewport,
             tree: &mut state.tree,
+            revealed: &mut state.revealed,
+            hovered,
+            reveal,
             list,
             width,
+            padding,
+            text_size,
+            text_line_height,
             target_height,
             class,
         }
@@ -251,6 +351,27 @@ where
         let node = self.list.layout(self.tree, renderer, &limits);
         let size = node.size();
 
+        // Scroll the hovered option into view whenever it changes
+        if *self.revealed != self.hovered {
+            *self.revealed = self.hovered;
+
+            if let Some((start, end)) = self.reveal {
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                let option_height =
+                    f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
+
+                self.list.operate(
+                    self.tree,
+                    Layout::new(&node),
+                    renderer,
+                    &mut Reveal {
+                        top: start * option_height,
+                        bottom: end * option_height,
+                    },
+                );
+            }
+        }
+
         node.move_to(if space_below > space_above {
             self.position + Vector::new(0.0, self.target_height)
         } else {
@@ -312,6 +433,41 @@ where
     }
 }
 
+/// An [`Operation`] that scrolls the list of a [`Menu`] as little as
+/// possible to show the given vertical range.
+struct Reveal {
+    top: f32,
+    bottom: f32,
+}
+
+impl<T> Operation<T> for Reveal {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&crate::core::widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        state: &mut dyn operation::Scrollable,
+    ) {
+        let y = if self.top < translation.y {
+            self.top
+        } else if self.bottom > translation.y + bounds.height {
+            self.bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(operation::scrollable::AbsoluteOffset {
+            x: None,
+            y: Some(y),
+        });
+    }
+}
+
 struct List<'a, 'b, T, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -326,9 +482,105 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    highlight: Option<&'a str>,
+    is_disabled: Option<&'a dyn Fn(&T) -> bool>,
+    group: Option<&'a dyn Fn(&T) -> Option<String>>,
+    icon: Option<&'a dyn Fn(&T) -> Option<Icon<Renderer::Font>>>,
+    footer: Option<&'a str>,
+    rows: Vec<Row>,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
+impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y()
+    }
+
+    /// Returns the index of the enabled option at the given vertical
+    /// position of the list, if any.
+    fn option_at(&self, y: f32, option_height: f32) -> Option<usize> {
+        let y = y / option_height;
+
+        let row = self
+            .rows
+            .get(self.rows.partition_point(|row| row.start + row.size <= y))?;
+
+        match row.kind {
+            RowKind::Option(index) if !self.is_option_disabled(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    fn is_option_disabled(&self, index: usize) -> bool {
+        self.is_disabled
+            .zip(self.options.get(index))
+            .is_some_and(|(is_disabled, option)| is_disabled(option))
+    }
+}
+
+/// A row of the list of a [`Menu`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Row {
+    kind: RowKind,
+    /// The start of the row, in option heights.
+    start: f32,
+    /// The size of the row, in option heights.
+    size: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowKind {
+    Option(usize),
+    /// The header of the group of the option with the given index.
+    Header(usize),
+    Separator,
+    Footer,
+}
+
+/// The size of a separator between groups, in option heights.
+const SEPARATOR_SIZE: f32 = 0.5;
+
+/// Returns the rows of a list with the given options, grouped by the given
+/// function.
+fn rows<T>(options: &[T], group: Option<&dyn Fn(&T) -> Option<String>>) -> Vec<Row> {
+    let mut rows = Vec::with_capacity(options.len());
+    let mut start = 0.0;
+    let mut last_group = None;
+
+    let mut push = |kind, size| {
+        rows.push(Row { kind, start, size });
+        start += size;
+    };
+
+    for (index, option) in options.iter().enumerate() {
+        if let Some(group) = group {
+            let current = group(option);
+
+            if index == 0 || current != last_group {
+                if index > 0 {
+                    push(RowKind::Separator, SEPARATOR_SIZE);
+                }
+
+                if current.as_ref().is_some_and(|name| !name.is_empty()) {
+                    push(RowKind::Header(index), 1.0);
+                }
+            }
+
+            last_group = current;
+        }
+
+        push(RowKind::Option(index), 1.0);
+    }
+
+    rows
+}
+
 struct ListState {
     is_hovered: Option<bool>,
 }
@@ -361,17 +613,10 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        use std::f32;
-
-        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-
-        let text_line_height = self.text_line_height.to_absolute(text_size);
+        let rows = self.rows.last().map_or(0.0, |row| row.start + row.size);
 
         let size = {
-            let intrinsic = Size::new(
-                0.0,
-                (f32::from(text_line_height) + self.padding.y()) * self.options.len() as f32,
-            );
+            let intrinsic = Size::new(0.0, self.option_height(renderer) * rows);
 
             limits.resolve(Length::Fill, Length::Shrink, intrinsic)
         };
@@ -402,34 +647,27 @@ where
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
-                    let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-
-                    let option_height =
-                        f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
-
-                    let new_hovered_option = (cursor_position.y / option_height) as usize;
-
-                    if *self.hovered_option != Some(new_hovered_option)
-                        && let Some(option) = self.options.get(new_hovered_option)
-                    {
-                        if let Some(on_option_hovered) = self.on_option_hovered {
+                    let new_hovered_option =
+                        self.option_at(cursor_position.y, self.option_height(renderer));
+
+                    if *self.hovered_option != new_hovered_option {
+                        if let Some(on_option_hovered) = self.on_option_hovered
+                            && let Some(option) =
+                                new_hovered_option.and_then(|index| self.options.get(index))
+                        {
                             shell.publish(on_option_hovered(option.clone()));
                         }
 
                         shell.request_redraw();
                     }
 
-                    *self.hovered_option = Some(new_hovered_option);
+                    *self.hovered_option = new_hovered_option;
                 }
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
-                    let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-
-                    let option_height =
-                        f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
-
-                    *self.hovered_option = Some((cursor_position.y / option_height) as usize);
+                    *self.hovered_option =
+                        self.option_at(cursor_position.y, self.option_height(renderer));
 
                     if let Some(index) = *self.hovered_option
                         && let Some(option) = self.options.get(index)
@@ -485,26 +723,88 @@ where
         let bounds = layout.bounds();
 
         let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.y();
+        let option_height = self.option_height(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
-
-        let visible_options = &self.options[start..end.min(self.options.len())];
-
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+        let offset = (viewport.y - bounds.y) / option_height;
+        let start = self
+            .rows
+            .partition_point(|row| row.start + row.size <= offset);
+        let end = offset + viewport.height / option_height;
 
+        for row in self.rows[start..].iter().take_while(|row| row.start < end) {
             let bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + (option_height * i as f32),
+                y: bounds.y + option_height * row.start,
                 width: bounds.width,
-                height: option_height,
+                height: option_height * row.size,
             };
 
+            let fill_label = |renderer: &mut Renderer, content: String, color: Color| {
+                renderer.fill_text(
+                    Text {
+                        content,
+                        bounds: Size::new(f32::INFINITY, bounds.height),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font,
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::default(),
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                    color,
+                    *viewport,
+                );
+            };
+
+            let index = match row.kind {
+                RowKind::Option(index) => index,
+                RowKind::Header(index) => {
+                    if let Some(name) = self
+                        .group
+                        .zip(self.options.get(index))
+                        .and_then(|(group, option)| group(option))
+                    {
+                        fill_label(renderer, name, style.header_text_color);
+                    }
+
+                    continue;
+                }
+                RowKind::Footer => {
+                    if let Some(footer) = self.footer {
+                        fill_label(renderer, footer.to_owned(), style.disabled_text_color);
+                    }
+
+                    continue;
+                }
+                RowKind::Separator => {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + self.padding.left,
+                                y: bounds.center_y().floor(),
+                                width: bounds.width - self.padding.x(),
+                                height: 1.0,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.separator,
+                    );
+
+                    continue;
+                }
+            };
+
+            let Some(option) = self.options.get(index) else {
+                continue;
+            };
+
+            let is_disabled = self.is_option_disabled(index);
+            let is_selected = !is_disabled && *self.hovered_option == Some(index);
+
             if is_selected {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -520,25 +820,89 @@ where
                 );
             }
 
+            let color = if is_disabled {
+                style.disabled_text_color
+            } else if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut x = bounds.x + self.padding.left;
+
+            if let Some(Icon {
+                font,
+                code_point,
+                size,
+                line_height,
+                shaping,
+            }) = self.icon.and_then(|icon| icon(option))
+            {
+                let size = size.unwrap_or(text_size);
+
+                renderer.fill_text(
+                    Text {
+                        content: code_point.to_string(),
+                        bounds: Size::new(f32::INFINITY, bounds.height),
+                        size,
+                        line_height,
+                        font,
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Center,
+                        shaping,
+                        wrapping: text::Wrapping::default(),
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(x, bounds.center_y()),
+                    color,
+                    *viewport,
+                );
+
+                x += size.0 * 1.5;
+            }
+
+            let label = option.to_string();
+            let text = Text {
+                content: label.as_str(),
+                bounds: Size::new(f32::INFINITY, bounds.height),
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::default(),
+                hint_factor: renderer.scale_factor(),
+            };
+
+            if let Some(range) = self.highlight.and_then(|query| find(&label, query)) {
+                let width = |content: &str| {
+                    Renderer::Paragraph::with_text(Text { content, ..text }).min_width()
+                };
+
+                let start = width(&label[..range.start]);
+                let end = width(&label[..range.end]);
+                let line_height = f32::from(self.text_line_height.to_absolute(text_size));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x + start,
+                            y: bounds.center_y() - line_height / 2.0,
+                            width: end - start,
+                            height: line_height,
+                        },
+                        border: border::rounded(2),
+                        ..renderer::Quad::default()
+                    },
+                    style.highlight,
+                );
+            }
+
             renderer.fill_text(
-                Text {
-                    content: option.to_string(),
-                    bounds: Size::new(f32::INFINITY, bounds.height),
-                    size: text_size,
-                    line_height: self.text_line_height,
-                    font: self.font.unwrap_or_else(|| renderer.default_font()),
-                    align_x: text::Alignment::Default,
-                    align_y: alignment::Vertical::Center,
-                    shaping: self.text_shaping,
-                    wrapping: text::Wrapping::default(),
-                    hint_factor: renderer.scale_factor(),
-                },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
+                text.with_content(label.clone()),
+                Point::new(x, bounds.center_y()),
+                color,
                 *viewport,
             );
         }
@@ -574,6 +938,14 @@ pub struct Style {
     pub selected_background: Background,
     /// The [`Shadow`] of the menu.
     pub shadow: Shadow,
+    /// The [`Color`] behind the parts of the options matching a query.
+    pub highlight: Color,
+    /// The text [`Color`] of a disabled option in the menu.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the headers of the groups in the menu.
+    pub header_text_color: Color,
+    /// The [`Color`] of the separators between groups in the menu.
+    pub separator: Color,
 }
 
 /// The theme catalog of a [`Menu`].
@@ -623,5 +995,74 @@ pub fn default(theme: &Theme) -> Style {
         selected_text_color: palette.primary.strong.text,
         selected_background: palette.primary.strong.color.into(),
         shadow: Shadow::default(),
+        highlight: palette.primary.weak.color,
+        disabled_text_color: palette.background.weak.text.scale_alpha(0.5),
+        header_text_color: palette.background.strongest.color,
+        separator: palette.background.strong.color,
+    }
+}
+
+/// Returns the byte range of the first occurrence of the query in the
+/// label, ignoring case.
+pub(crate) fn find(label: &str, query: &str) -> Option<ops::Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+
+    label.char_indices().find_map(|(start, _)| {
+        let mut rest = label[start..].char_indices();
+        let mut end = start;
+
+        for expected in query.chars() {
+            let (index, c) = rest.next()?;
+
+            if !c.to_lowercase().eq(expected.to_lowercase()) {
+                return None;
+            }
+
+            end = start + index + c.len_utf8();
+        }
+
+        Some(start..end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Row, RowKind, find, rows};
+
+    #[test]
+    fn queries_are_found_ignoring_case() {
+        assert_eq!(find("Pineapple", "apple"), Some(4..9));
+        assert_eq!(find("Pineapple", "PINE"), Some(0..4));
+        assert_eq!(find("Ñandú", "dú"), Some(4..7));
+        assert_eq!(find("Pineapple", "pear"), None);
+        assert_eq!(find("Pineapple", ""), None);
+    }
+
+    #[test]
+    fn groups_are_separated_with_headers() {
+        let options = ["Apple", "Avocado", "Banana", "Cherry"];
+        let group = |option: &&str| match option.chars().next() {
+            Some('A') => Some("A".to_owned()),
+            Some('B') => Some(String::new()),
+            _ => None,
+        };
+
+        let row = |kind, start, size| Row { kind, start, size };
+
+        assert_eq!(
+            rows(&options, Some(&group)),
+            [
+                row(RowKind::Header(0), 0.0, 1.0),
+                row(RowKind::Option(0), 1.0, 1.0),
+                row(RowKind::Option(1), 2.0, 1.0),
+                row(RowKind::Separator, 3.0, 0.5),
+                row(RowKind::Option(2), 3.5, 1.0),
+                row(RowKind::Separator, 4.5, 0.5),
+                row(RowKind::Option(3), 5.0, 1.0),
+            ]
+        );
+        assert_eq!(rows(&options, None).len(), options.len());
     }
 }
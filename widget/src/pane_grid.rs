@@ -12,6 +12,8 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Floating panes that can be docked back
+//! * Stacks of tabbed panes
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -79,21 +81,29 @@ pub use state::State;
 pub use title_bar::TitleBar;
 
 use crate::container;
+use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay::{self, Group};
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     self, Background, Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Size, Theme, Vector, Widget,
+    Rectangle, Shadow, Shell, Size, Text, Theme, Vector, Widget,
 };
 
+use state::Grip;
+
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
+const DOCK_TARGET_SIZE: f32 = 32.0;
+const GRIP_THICKNESS: f32 = 6.0;
+const MAX_TAB_WIDTH: f32 = 160.0;
+const TAB_PADDING: f32 = 8.0;
 
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
@@ -110,6 +120,8 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Floating panes that can be docked back
+/// * Stacks of tabbed panes
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -162,6 +174,9 @@ where
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_float: Option<Box<dyn Fn(FloatEvent) -> Message + 'a>>,
+    on_tab: Option<Box<dyn Fn(TabEvent) -> Message + 'a>>,
+    tab_height: f32,
     class: <Theme as Catalog>::Class<'a>,
     last_mouse_interaction: Option<mouse::Interaction>,
 }
@@ -200,6 +215,9 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            on_float: None,
+            on_tab: None,
+            tab_height: 28.0,
             class: <Theme as Catalog>::default(),
             last_mouse_interaction: None,
         }
@@ -270,6 +288,51 @@ where
         self
     }
 
+    /// Enables the floating interactions of the [`PaneGrid`], which will
+    /// use the provided function to produce messages.
+    ///
+    /// Dropping a dragged [`Pane`] away from the dock targets of the
+    /// [`PaneGrid`] will float it, and floating panes can be resized by
+    /// dragging their borders. Dropping a floating [`Pane`] on a dock target
+    /// produces a [`DragEvent::Dropped`] as usual.
+    ///
+    /// Floating panes are moved by dragging them, so [`on_drag`] needs to be
+    /// enabled as well.
+    ///
+    /// [`on_drag`]: Self::on_drag
+    pub fn on_float<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(FloatEvent) -> Message,
+    {
+        if self.internal.maximized().is_none() {
+            self.on_float = Some(Box::new(f));
+        }
+        self
+    }
+
+    /// Sets the height of the tab strip of the stacks of panes of the
+    /// [`PaneGrid`].
+    pub fn tab_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.tab_height = height.into().0;
+        self
+    }
+
+    /// Enables the tab interactions of the [`PaneGrid`], which will use the
+    /// provided function to produce messages.
+    ///
+    /// Tabs can be selected by clicking them. If [`on_drag`] is enabled, they
+    /// can also be reordered by dragging them along their tab strip, or
+    /// dragged out of it like any other [`Pane`].
+    ///
+    /// [`on_drag`]: Self::on_drag
+    pub fn on_tab<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(TabEvent) -> Message,
+    {
+        self.on_tab = Some(Box::new(f));
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -296,6 +359,141 @@ where
         }
     }
 
+    fn is_floating(&self, pane: Pane) -> bool {
+        self.internal
+            .floating()
+            .iter()
+            .any(|(floating, _)| *floating == pane)
+    }
+
+    fn floating_at<'b>(
+        &self,
+        layout: Layout<'b>,
+        cursor_position: Point,
+        except: Option<Pane>,
+    ) -> Option<(Pane, Layout<'b>)> {
+        self.internal
+            .floating()
+            .iter()
+            .rev()
+            .filter(|(pane, _)| except != Some(*pane))
+            .find_map(|(pane, _)| {
+                let index = self.panes.binary_search(pane).ok()?;
+                let layout = layout.children().nth(index)?;
+
+                layout
+                    .bounds()
+                    .contains(cursor_position)
+                    .then_some((*pane, layout))
+            })
+    }
+
+    fn hovered_dock<'b>(
+        &self,
+        layout: Layout<'b>,
+        picked: Pane,
+        cursor_position: Point,
+    ) -> Option<(Pane, Layout<'b>)> {
+        if self
+            .floating_at(layout, cursor_position, Some(picked))
+            .is_some()
+        {
+            return None;
+        }
+
+        self.panes
+            .iter()
+            .copied()
+            .zip(layout.children())
+            .find(|(pane, layout)| {
+                *pane != picked
+                    && !self.is_floating(*pane)
+                    && self.internal.is_visible(*pane)
+                    && layout.bounds().contains(cursor_position)
+            })
+    }
+
+    fn tab_strips<'b>(
+        &'b self,
+        layout: Layout<'b>,
+    ) -> impl Iterator<Item = (&'b [Pane], Pane, Rectangle)> + 'b {
+        self.internal.stacks().filter_map(move |(tabs, active)| {
+            let index = self.panes.binary_search(&active).ok()?;
+            let bounds = layout.children().nth(index)?.bounds();
+
+            Some((
+                tabs,
+                active,
+                Rectangle {
+                    y: bounds.y - self.tab_height,
+                    height: self.tab_height,
+                    ..bounds
+                },
+            ))
+        })
+    }
+
+    fn tab_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<(Pane, Pane)> {
+        if self.floating_at(layout, cursor_position, None).is_some() {
+            return None;
+        }
+
+        self.tab_strips(layout).find_map(|(tabs, active, strip)| {
+            let index = tab_index(strip, tabs.len(), cursor_position)?;
+
+            Some((tabs[index], active))
+        })
+    }
+
+    fn drop_target(
+        &self,
+        layout: Layout<'_>,
+        picked: Pane,
+        cursor_position: Point,
+    ) -> Option<(Target, Rectangle)> {
+        if let Some(edge) = in_edge(layout, cursor_position) {
+            return Some((Target::Edge(edge), edge_bounds(layout, edge)));
+        }
+
+        if self
+            .floating_at(layout, cursor_position, Some(picked))
+            .is_none()
+            && let Some((tabs, active, strip)) = self
+                .tab_strips(layout)
+                .find(|(_, _, strip)| strip.contains(cursor_position))
+        {
+            let slot = tab_slot(strip, tabs.len(), cursor_position);
+            let x = tab_bounds(strip, tabs.len(), slot).x;
+
+            let index = match tabs.iter().position(|tab| *tab == picked) {
+                Some(position) if position < slot => slot - 1,
+                _ => slot,
+            };
+
+            return Some((
+                Target::Tab(active, index),
+                Rectangle {
+                    x: x - 1.0,
+                    width: 2.0,
+                    ..strip
+                },
+            ));
+        }
+
+        let (target, target_layout) = self.hovered_dock(layout, picked, cursor_position)?;
+
+        let region = if self.on_float.is_some() {
+            dock_region(target_layout, cursor_position)?
+        } else {
+            layout_region(target_layout, cursor_position)?
+        };
+
+        Some((
+            Target::Pane(target, region),
+            layout_region_bounds(target_layout, region),
+        ))
+    }
+
     fn grid_interaction(
         &self,
         action: &state::Action,
@@ -306,6 +504,30 @@ where
             return Some(mouse::Interaction::Grabbing);
         }
 
+        if let Some((_, grip)) = action.picked_floating() {
+            return Some(grip_interaction(grip));
+        }
+
+        if let Some(cursor_position) = cursor.position()
+            && let Some((_, pane_layout)) = self.floating_at(layout, cursor_position, None)
+        {
+            return self
+                .on_float
+                .as_ref()
+                .and_then(|_| Grip::at(pane_layout.bounds(), cursor_position, GRIP_THICKNESS))
+                .map(grip_interaction);
+        }
+
+        if let Some(cursor_position) = cursor.position()
+            && self.tab_at(layout, cursor_position).is_some()
+        {
+            return Some(if self.drag_enabled() {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::Pointer
+            });
+        }
+
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
         let node = self.internal.layout();
 
@@ -345,7 +567,7 @@ impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for PaneGrid<'_, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<Memory>()
@@ -409,6 +631,8 @@ where
             .internal
             .layout()
             .pane_regions(self.spacing, self.min_size, bounds);
+        let floating = self.internal.floating();
+        let stacked: Vec<Pane> = self.internal.stacks().map(|(_, active)| active).collect();
 
         let children = self
             .panes
@@ -416,15 +640,26 @@ where
             .zip(&mut self.contents)
             .zip(tree.children.iter_mut())
             .filter_map(|((pane, content), tree)| {
-                if self
-                    .internal
-                    .maximized()
-                    .is_some_and(|maximized| maximized != *pane)
-                {
+                if !self.internal.is_visible(*pane) {
                     return Some(layout::Node::new(Size::ZERO));
                 }
 
-                let region = regions.get(pane)?;
+                let region = floating
+                    .iter()
+                    .find(|(floating, _)| *floating == *pane)
+                    .map(|(_, region)| floating_bounds(*region, bounds))
+                    .or_else(|| regions.get(pane).copied())?;
+
+                let region = if stacked.contains(pane) {
+                    Rectangle {
+                        y: region.y + self.tab_height,
+                        height: (region.height - self.tab_height).max(0.0),
+                        ..region
+                    }
+                } else {
+                    region
+                };
+
                 let size = Size::new(region.width, region.height);
 
                 let node = content.layout(tree, renderer, &layout::Limits::new(size, size));
@@ -450,11 +685,7 @@ where
                 .zip(&mut self.contents)
                 .zip(&mut tree.children)
                 .zip(layout.children())
-                .filter(|(((pane, _), _), _)| {
-                    self.internal
-                        .maximized()
-                        .is_none_or(|maximized| **pane == maximized)
-                })
+                .filter(|(((pane, _), _), _)| self.internal.is_visible(**pane))
                 .for_each(|(((_, content), state), layout)| {
                     content.operate(state, layout, renderer, operation);
                 });
@@ -482,6 +713,10 @@ where
         };
 
         let picked_pane = action.picked_pane().map(|(pane, _)| pane);
+        let hovered_floating = cursor
+            .position()
+            .and_then(|cursor_position| self.floating_at(layout, cursor_position, None))
+            .map(|(pane, _)| pane);
 
         for (((pane, content), tree), layout) in self
             .panes
@@ -490,13 +725,10 @@ where
             .zip(&mut self.contents)
             .zip(&mut tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| *pane == maximized)
-            })
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
         {
             let is_picked = picked_pane == Some(pane);
+            let cursor = pane_cursor(pane, cursor, hovered_floating);
 
             content.update(
                 tree, event, layout, cursor, renderer, clipboard, shell, viewport, is_picked,
@@ -511,49 +743,111 @@ where
                 if let Some(cursor_position) = cursor.position_over(bounds) {
                     shell.capture_event();
 
-                    match &self.on_resize {
-                        Some((leeway, _)) => {
-                            let relative_cursor = Point::new(
-                                cursor_position.x - bounds.x,
-                                cursor_position.y - bounds.y,
+                    if let Some((pane, pane_layout)) =
+                        self.floating_at(layout, cursor_position, None)
+                    {
+                        let pane_bounds = pane_layout.bounds() - Vector::new(bounds.x, bounds.y);
+
+                        if let Some(on_float) = &self.on_float
+                            && self
+                                .internal
+                                .floating()
+                                .last()
+                                .is_some_and(|(top, _)| *top != pane)
+                        {
+                            shell.publish(on_float(FloatEvent {
+                                pane,
+                                bounds: pane_bounds,
+                            }));
+                        }
+
+                        if let Some(grip) = self.on_float.as_ref().and_then(|_| {
+                            Grip::at(pane_layout.bounds(), cursor_position, GRIP_THICKNESS)
+                        }) {
+                            *action = state::Action::ResizingFloating {
+                                pane,
+                                grip,
+                                origin: cursor_position,
+                                bounds: pane_bounds,
+                            };
+                        } else {
+                            click_pane(
+                                action,
+                                layout,
+                                cursor_position,
+                                shell,
+                                self.panes.iter().copied().zip(&self.contents),
+                                Some(pane),
+                                &self.on_click,
+                                on_drag,
                             );
+                        }
+                    } else if let Some((tab, active)) = self.tab_at(layout, cursor_position) {
+                        if tab != active
+                            && let Some(on_tab) = &self.on_tab
+                        {
+                            shell.publish(on_tab(TabEvent::Selected(tab)));
+                        }
 
-                            let splits =
-                                node.split_regions(self.spacing, self.min_size, bounds.size());
+                        if let Some(on_click) = &self.on_click {
+                            shell.publish(on_click(tab));
+                        }
 
-                            let clicked_split = hovered_split(
-                                splits.iter(),
-                                self.spacing + leeway,
-                                relative_cursor,
-                            );
+                        if let Some(on_drag) = on_drag {
+                            *action = state::Action::Dragging {
+                                pane: tab,
+                                origin: cursor_position,
+                            };
 
-                            if let Some((split, axis, _)) = clicked_split {
-                                if action.picked_pane().is_none() {
-                                    *action = state::Action::Resizing { split, axis };
+                            shell.publish(on_drag(DragEvent::Picked { pane: tab }));
+                        }
+                    } else {
+                        match &self.on_resize {
+                            Some((leeway, _)) => {
+                                let relative_cursor = Point::new(
+                                    cursor_position.x - bounds.x,
+                                    cursor_position.y - bounds.y,
+                                );
+
+                                let splits =
+                                    node.split_regions(self.spacing, self.min_size, bounds.size());
+
+                                let clicked_split = hovered_split(
+                                    splits.iter(),
+                                    self.spacing + leeway,
+                                    relative_cursor,
+                                );
+
+                                if let Some((split, axis, _)) = clicked_split {
+                                    if action.picked_pane().is_none() {
+                                        *action = state::Action::Resizing { split, axis };
+                                    }
+                                } else {
+                                    click_pane(
+                                        action,
+                                        layout,
+                                        cursor_position,
+                                        shell,
+                                        self.panes.iter().copied().zip(&self.contents),
+                                        None,
+                                        &self.on_click,
+                                        on_drag,
+                                    );
                                 }
-                            } else {
+                            }
+                            None => {
                                 click_pane(
                                     action,
                                     layout,
                                     cursor_position,
                                     shell,
                                     self.panes.iter().copied().zip(&self.contents),
+                                    None,
                                     &self.on_click,
                                     on_drag,
                                 );
                             }
                         }
-                        None => {
-                            click_pane(
-                                action,
-                                layout,
-                                cursor_position,
-                                shell,
-                                self.panes.iter().copied().zip(&self.contents),
-                                &self.on_click,
-                                on_drag,
-                            );
-                        }
                     }
                 }
             }
@@ -565,35 +859,37 @@ where
                     && let Some(cursor_position) = cursor.position()
                 {
                     if cursor_position.distance(origin) > DRAG_DEADBAND_DISTANCE {
-                        let event = if let Some(edge) = in_edge(layout, cursor_position) {
-                            DragEvent::Dropped {
-                                pane,
-                                target: Target::Edge(edge),
+                        let target = self.drop_target(layout, pane, cursor_position);
+
+                        if let Some((Target::Tab(active, index), _)) = target
+                            && self
+                                .internal
+                                .stacks()
+                                .any(|(tabs, stack)| stack == active && tabs.contains(&pane))
+                        {
+                            shell.publish(on_drag(DragEvent::Canceled { pane }));
+
+                            if let Some(on_tab) = &self.on_tab {
+                                shell.publish(on_tab(TabEvent::Moved { pane, index }));
                             }
+                        } else if let Some((target, _)) = target {
+                            shell.publish(on_drag(DragEvent::Dropped { pane, target }));
                         } else {
-                            let dropped_region = self
-                                .panes
-                                .iter()
-                                .copied()
-                                .zip(&self.contents)
-                                .zip(layout.children())
-                                .find_map(|(target, layout)| {
-                                    layout_region(layout, cursor_position)
-                                        .map(|region| (target, region))
-                                });
-
-                            match dropped_region {
-                                Some(((target, _), region)) if pane != target => {
-                                    DragEvent::Dropped {
-                                        pane,
-                                        target: Target::Pane(target, region),
-                                    }
-                                }
-                                _ => DragEvent::Canceled { pane },
+                            shell.publish(on_drag(DragEvent::Canceled { pane }));
+
+                            if let Some(on_float) = &self.on_float
+                                && let Ok(index) = self.panes.binary_search(&pane)
+                                && let Some(pane_layout) = layout.children().nth(index)
+                            {
+                                let grid = layout.bounds();
+
+                                shell.publish(on_float(FloatEvent {
+                                    pane,
+                                    bounds: pane_layout.bounds() - Vector::new(grid.x, grid.y)
+                                        + (cursor_position - origin),
+                                }));
                             }
-                        };
-
-                        shell.publish(on_drag(event));
+                        }
                     } else {
                         shell.publish(on_drag(DragEvent::Canceled { pane }));
                     }
@@ -603,35 +899,51 @@ where
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                if let Some((_, on_resize)) = &self.on_resize {
-                    if let Some((split, _)) = action.picked_split() {
-                        let bounds = layout.bounds();
+                if let state::Action::ResizingFloating {
+                    pane,
+                    grip,
+                    origin,
+                    bounds,
+                } = *action
+                {
+                    if let Some(on_float) = &self.on_float
+                        && let Some(cursor_position) = cursor.position()
+                    {
+                        let bounds = grip.resize(bounds, cursor_position - origin, self.min_size);
 
-                        let splits = node.split_regions(self.spacing, self.min_size, bounds.size());
+                        shell.publish(on_float(FloatEvent { pane, bounds }));
 
-                        if let Some((axis, rectangle, _)) = splits.get(&split)
-                            && let Some(cursor_position) = cursor.position()
-                        {
-                            let ratio = match axis {
-                                Axis::Horizontal => {
-                                    let position = cursor_position.y - bounds.y - rectangle.y;
+                        shell.capture_event();
+                    }
+                } else if let Some((_, on_resize)) = &self.on_resize
+                    && let Some((split, _)) = action.picked_split()
+                {
+                    let bounds = layout.bounds();
 
-                                    (position / rectangle.height).clamp(0.0, 1.0)
-                                }
-                                Axis::Vertical => {
-                                    let position = cursor_position.x - bounds.x - rectangle.x;
+                    let splits = node.split_regions(self.spacing, self.min_size, bounds.size());
 
-                                    (position / rectangle.width).clamp(0.0, 1.0)
-                                }
-                            };
+                    if let Some((axis, rectangle, _)) = splits.get(&split)
+                        && let Some(cursor_position) = cursor.position()
+                    {
+                        let ratio = match axis {
+                            Axis::Horizontal => {
+                                let position = cursor_position.y - bounds.y - rectangle.y;
 
-                            shell.publish(on_resize(ResizeEvent { split, ratio }));
+                                (position / rectangle.height).clamp(0.0, 1.0)
+                            }
+                            Axis::Vertical => {
+                                let position = cursor_position.x - bounds.x - rectangle.x;
 
-                            shell.capture_event();
-                        }
-                    } else if action.picked_pane().is_some() {
-                        shell.request_redraw();
+                                (position / rectangle.width).clamp(0.0, 1.0)
+                            }
+                        };
+
+                        shell.publish(on_resize(ResizeEvent { split, ratio }));
+
+                        shell.capture_event();
                     }
+                } else if action.picked_pane().is_some() {
+                    shell.request_redraw();
                 }
             }
             _ => {}
@@ -645,13 +957,13 @@ where
                         .iter()
                         .zip(&self.contents)
                         .zip(layout.children())
-                        .filter(|((pane, _content), _layout)| {
-                            self.internal
-                                .maximized()
-                                .is_none_or(|maximized| **pane == maximized)
-                        })
-                        .find_map(|((_pane, content), layout)| {
-                            content.grid_interaction(layout, cursor, on_drag.is_some())
+                        .filter(|((pane, _content), _layout)| self.internal.is_visible(**pane))
+                        .find_map(|((pane, content), layout)| {
+                            content.grid_interaction(
+                                layout,
+                                pane_cursor(*pane, cursor, hovered_floating),
+                                on_drag.is_some(),
+                            )
                         })
                 })
                 .unwrap_or(mouse::Interaction::None);
@@ -681,22 +993,23 @@ where
             return grid_interaction;
         }
 
+        let hovered_floating = cursor
+            .position()
+            .and_then(|cursor_position| self.floating_at(layout, cursor_position, None))
+            .map(|(pane, _)| pane);
+
         self.panes
             .iter()
             .copied()
             .zip(&self.contents)
             .zip(&tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| *pane == maximized)
-            })
-            .map(|(((_, content), tree), layout)| {
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
+            .map(|(((pane, content), tree), layout)| {
                 content.mouse_interaction(
                     tree,
                     layout,
-                    cursor,
+                    pane_cursor(pane, cursor, hovered_floating),
                     viewport,
                     renderer,
                     self.drag_enabled(),
@@ -746,6 +1059,10 @@ where
                     let cursor_position = cursor.position()?;
                     let bounds = layout.bounds();
 
+                    if self.floating_at(layout, cursor_position, None).is_some() {
+                        return None;
+                    }
+
                     let relative_cursor =
                         Point::new(cursor_position.x - bounds.x, cursor_position.y - bounds.y);
 
@@ -759,22 +1076,36 @@ where
                 None => None,
             });
 
-        let pane_cursor = if picked_pane.is_some() {
-            mouse::Cursor::Unavailable
-        } else {
-            cursor
-        };
-
-        let mut render_picked_pane = None;
-
-        let pane_in_edge = if picked_pane.is_some() {
+        let hovered_floating = if picked_pane.is_none() {
             cursor
                 .position()
-                .and_then(|cursor_position| in_edge(layout, cursor_position))
+                .and_then(|cursor_position| self.floating_at(layout, cursor_position, None))
+                .map(|(pane, _)| pane)
         } else {
             None
         };
 
+        let cursor_of = |pane| {
+            if picked_pane.is_some() {
+                mouse::Cursor::Unavailable
+            } else {
+                pane_cursor(pane, cursor, hovered_floating)
+            }
+        };
+
+        let (drop_target, hovered_dock) = match (picked_pane, cursor.position()) {
+            (Some((picked, _)), Some(cursor_position)) => (
+                self.drop_target(layout, picked, cursor_position)
+                    .map(|(_, bounds)| bounds),
+                self.on_float
+                    .as_ref()
+                    .and(self.hovered_dock(layout, picked, cursor_position)),
+            ),
+            _ => (None, None),
+        };
+
+        let mut render_picked_pane = None;
+
         let style = Catalog::style(theme, &self.class);
 
         for (((id, content), tree), pane_layout) in self
@@ -784,70 +1115,126 @@ where
             .zip(&self.contents)
             .zip(&tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| maximized == *pane)
-            })
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
         {
             match picked_pane {
                 Some((dragging, origin)) if id == dragging => {
                     render_picked_pane = Some(((content, tree), origin, pane_layout));
                 }
-                Some((dragging, _)) if id != dragging => {
+                _ if self.is_floating(id) => {}
+                _ => {
                     content.draw(
                         tree,
                         renderer,
                         theme,
                         defaults,
                         pane_layout,
-                        pane_cursor,
+                        cursor_of(id),
                         viewport,
                     );
+                }
+            }
+        }
 
-                    if picked_pane.is_some()
-                        && pane_in_edge.is_none()
-                        && let Some(region) = cursor
-                            .position()
-                            .and_then(|cursor_position| layout_region(pane_layout, cursor_position))
-                    {
-                        let bounds = layout_region_bounds(pane_layout, region);
+        for (tabs, active, strip) in self.tab_strips(layout) {
+            draw_tab_strip(
+                renderer,
+                &style.tabs,
+                strip,
+                tabs.iter().map(|tab| {
+                    let label = self
+                        .panes
+                        .binary_search(tab)
+                        .ok()
+                        .and_then(|index| self.contents.get(index))
+                        .map(Content::tab_label)
+                        .unwrap_or_default();
+
+                    (label, *tab == active)
+                }),
+                viewport,
+            );
+        }
+
+        for (pane, _) in self.internal.floating() {
+            if picked_pane.is_some_and(|(picked, _)| picked == *pane) {
+                continue;
+            }
+
+            let Ok(index) = self.panes.binary_search(pane) else {
+                continue;
+            };
+
+            if let Some(content) = self.contents.get(index)
+                && let Some(tree) = tree.children.get(index)
+                && let Some(pane_layout) = layout.children().nth(index)
+            {
+                renderer.with_layer(*viewport, |renderer| {
+                    container::draw_background(renderer, &style.floating, pane_layout.bounds());
+
+                    content.draw(
+                        tree,
+                        renderer,
+                        theme,
+                        defaults,
+                        pane_layout,
+                        cursor_of(*pane),
+                        viewport,
+                    );
+                });
+            }
+        }
 
+        if drop_target.is_some() || hovered_dock.is_some() {
+            renderer.with_layer(layout.bounds(), |renderer| {
+                if let Some((_, dock_layout)) = hovered_dock {
+                    for (_, bounds) in dock_targets(dock_layout.bounds()) {
                         renderer.fill_quad(
                             renderer::Quad {
                                 bounds,
-                                border: style.hovered_region.border,
+                                border: style.dock_target.border,
                                 ..renderer::Quad::default()
                             },
-                            style.hovered_region.background,
+                            style.dock_target.background,
                         );
                     }
                 }
-                _ => {
-                    content.draw(
-                        tree,
-                        renderer,
-                        theme,
-                        defaults,
-                        pane_layout,
-                        pane_cursor,
-                        viewport,
+
+                if let Some(bounds) = drop_target {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: style.hovered_region.border,
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_region.background,
                     );
                 }
-            }
+            });
         }
 
-        if let Some(edge) = pane_in_edge {
-            let bounds = edge_bounds(layout, edge);
+        if let Some((picked, origin)) = picked_pane
+            && let Some(cursor_position) = cursor.position()
+            && let Some((tabs, strip)) = self
+                .tab_strips(layout)
+                .find_map(|(tabs, _, strip)| tabs.contains(&picked).then_some((tabs, strip)))
+            && let Some(index) = tabs.iter().position(|tab| *tab == picked)
+            && let Ok(content) = self.panes.binary_search(&picked)
+        {
+            let bounds = tab_bounds(strip, tabs.len(), index);
+            let label = self.contents[content].tab_label();
 
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: style.hovered_region.border,
-                    ..renderer::Quad::default()
-                },
-                style.hovered_region.background,
-            );
+            renderer.with_translation(cursor_position - origin, |renderer| {
+                renderer.with_layer(bounds, |renderer| {
+                    draw_tab_strip(
+                        renderer,
+                        &style.tabs,
+                        bounds,
+                        std::iter::once((label, true)),
+                        viewport,
+                    );
+                });
+            });
         }
 
         // Render picked pane last
@@ -866,7 +1253,7 @@ where
                         theme,
                         defaults,
                         layout,
-                        pane_cursor,
+                        mouse::Cursor::Unavailable,
                         viewport,
                     );
                 });
@@ -923,11 +1310,7 @@ where
             .zip(&mut tree.children)
             .zip(layout.children())
             .filter_map(|(((pane, content), state), layout)| {
-                if self
-                    .internal
-                    .maximized()
-                    .is_some_and(|maximized| maximized != pane)
-                {
+                if !self.internal.is_visible(pane) {
                     return None;
                 }
 
@@ -944,7 +1327,7 @@ impl<'a, Message, Theme, Renderer> From<PaneGrid<'a, Message, Theme, Renderer>>
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         pane_grid: PaneGrid<'a, Message, Theme, Renderer>,
@@ -975,12 +1358,158 @@ fn layout_region(layout: Layout<'_>, cursor_position: Point) -> Option<Region> {
     Some(region)
 }
 
+fn tab_width(strip: Rectangle, count: usize) -> f32 {
+    (strip.width / count.max(1) as f32).min(MAX_TAB_WIDTH)
+}
+
+fn tab_bounds(strip: Rectangle, count: usize, index: usize) -> Rectangle {
+    let width = tab_width(strip, count);
+
+    Rectangle {
+        x: strip.x + width * index as f32,
+        width,
+        ..strip
+    }
+}
+
+fn tab_index(strip: Rectangle, count: usize, cursor_position: Point) -> Option<usize> {
+    if !strip.contains(cursor_position) {
+        return None;
+    }
+
+    let index = ((cursor_position.x - strip.x) / tab_width(strip, count)) as usize;
+
+    (index < count).then_some(index)
+}
+
+fn tab_slot(strip: Rectangle, count: usize, cursor_position: Point) -> usize {
+    let slot = ((cursor_position.x - strip.x) / tab_width(strip, count)).round();
+
+    (slot.max(0.0) as usize).min(count)
+}
+
+fn draw_tab_strip<'a, Renderer>(
+    renderer: &mut Renderer,
+    style: &Tabs,
+    strip: Rectangle,
+    tabs: impl ExactSizeIterator<Item = (&'a str, bool)>,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let count = tabs.len();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: strip,
+            ..renderer::Quad::default()
+        },
+        style.background,
+    );
+
+    for (index, (label, is_active)) in tabs.enumerate() {
+        let bounds = tab_bounds(strip, count, index);
+
+        if is_active {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.active,
+            );
+        }
+
+        let text_bounds = bounds.shrink([0.0, TAB_PADDING]);
+
+        renderer.fill_text(
+            Text {
+                content: label.to_owned(),
+                bounds: text_bounds.size(),
+                size: renderer.default_size(),
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::default(),
+                wrapping: text::Wrapping::None,
+                hint_factor: renderer.scale_factor(),
+            },
+            Point::new(text_bounds.x, text_bounds.center_y()),
+            if is_active {
+                style.active_text_color
+            } else {
+                style.text_color
+            },
+            text_bounds.intersection(viewport).unwrap_or(text_bounds),
+        );
+    }
+}
+
+fn dock_targets(bounds: Rectangle) -> [(Region, Rectangle); 5] {
+    let size = DOCK_TARGET_SIZE.min(bounds.width.min(bounds.height) / 4.0);
+    let center = bounds.center();
+
+    let target = |x: f32, y: f32| Rectangle {
+        x: center.x + x * size * 1.25 - size / 2.0,
+        y: center.y + y * size * 1.25 - size / 2.0,
+        width: size,
+        height: size,
+    };
+
+    [
+        (Region::Center, target(0.0, 0.0)),
+        (Region::Edge(Edge::Top), target(0.0, -1.0)),
+        (Region::Edge(Edge::Left), target(-1.0, 0.0)),
+        (Region::Edge(Edge::Right), target(1.0, 0.0)),
+        (Region::Edge(Edge::Bottom), target(0.0, 1.0)),
+    ]
+}
+
+fn dock_region(layout: Layout<'_>, cursor_position: Point) -> Option<Region> {
+    dock_targets(layout.bounds())
+        .into_iter()
+        .find_map(|(region, bounds)| bounds.contains(cursor_position).then_some(region))
+}
+
+fn floating_bounds(bounds: Rectangle, grid: Size) -> Rectangle {
+    let width = bounds.width.min(grid.width);
+    let height = bounds.height.min(grid.height);
+
+    Rectangle {
+        x: bounds.x.clamp(0.0, grid.width - width),
+        y: bounds.y.clamp(0.0, grid.height - height),
+        width,
+        height,
+    }
+}
+
+fn pane_cursor(pane: Pane, cursor: mouse::Cursor, hovered_floating: Option<Pane>) -> mouse::Cursor {
+    match hovered_floating {
+        Some(floating) if floating != pane => mouse::Cursor::Unavailable,
+        _ => cursor,
+    }
+}
+
+fn grip_interaction(grip: Grip) -> mouse::Interaction {
+    match (grip.horizontal, grip.vertical) {
+        (Some(Edge::Left), Some(Edge::Top)) | (Some(Edge::Right), Some(Edge::Bottom)) => {
+            mouse::Interaction::ResizingDiagonallyDown
+        }
+        (Some(_), Some(_)) => mouse::Interaction::ResizingDiagonallyUp,
+        (Some(_), None) => mouse::Interaction::ResizingHorizontally,
+        (None, _) => mouse::Interaction::ResizingVertically,
+    }
+}
+
 fn click_pane<'a, Message, T>(
     action: &mut state::Action,
     layout: Layout<'_>,
     cursor_position: Point,
     shell: &mut Shell<'_, Message>,
     contents: impl Iterator<Item = (Pane, T)>,
+    only: Option<Pane>,
     on_click: &Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: &Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
 ) where
@@ -988,7 +1517,9 @@ fn click_pane<'a, Message, T>(
 {
     let mut clicked_region = contents
         .zip(layout.children())
-        .filter(|(_, layout)| layout.bounds().contains(cursor_position));
+        .filter(|((pane, _), layout)| {
+            only.is_none_or(|only| only == *pane) && layout.bounds().contains(cursor_position)
+        });
 
     if let Some(((pane, content), layout)) = clicked_region.next() {
         if let Some(on_click) = &on_click {
@@ -1112,6 +1643,35 @@ pub enum DragEvent {
     },
 }
 
+/// An event produced when a floating [`Pane`] of a [`PaneGrid`] is popped
+/// out, moved, resized, or brought to the front.
+///
+/// It can be handled with [`State::float`].
+#[derive(Debug, Clone, Copy)]
+pub struct FloatEvent {
+    /// The floating [`Pane`].
+    pub pane: Pane,
+
+    /// The new bounds of the [`Pane`], relative to the [`PaneGrid`].
+    pub bounds: Rectangle,
+}
+
+/// An event produced by the tabs of a stack of panes in a [`PaneGrid`].
+#[derive(Debug, Clone, Copy)]
+pub enum TabEvent {
+    /// The tab of a [`Pane`] was selected.
+    Selected(Pane),
+
+    /// The tab of a [`Pane`] was dragged along its tab strip.
+    Moved {
+        /// The [`Pane`] of the tab.
+        pane: Pane,
+
+        /// The new index of the tab in its stack.
+        index: usize,
+    },
+}
+
 /// The [`Target`] area a pane can be dropped on.
 #[derive(Debug, Clone, Copy)]
 pub enum Target {
@@ -1119,6 +1679,8 @@ pub enum Target {
     Edge(Edge),
     /// A single [`Pane`] of the [`PaneGrid`].
     Pane(Pane, Region),
+    /// The tab strip of the stack of a [`Pane`], at the given index.
+    Tab(Pane, usize),
 }
 
 /// The region of a [`Pane`].
@@ -1132,7 +1694,7 @@ pub enum Region {
 }
 
 /// The edges of an area.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Edge {
     /// Top edge.
     Top,
@@ -1185,6 +1747,27 @@ pub struct Style {
     pub picked_split: Line,
     /// The appearance of a hovered split.
     pub hovered_split: Line,
+    /// The appearance of the dock targets shown while dragging a pane.
+    pub dock_target: Highlight,
+    /// The appearance behind a floating pane.
+    pub floating: container::Style,
+    /// The appearance of the tab strips of the stacks of panes.
+    pub tabs: Tabs,
+}
+
+/// The appearance of the tab strip of a stack of panes in a [`PaneGrid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tabs {
+    /// The [`Background`] of the tab strip.
+    pub background: Background,
+    /// The [`Background`] of the active tab.
+    pub active: Background,
+    /// The [`Border`] of the active tab.
+    pub border: Border,
+    /// The text [`Color`] of the inactive tabs.
+    pub text_color: Color,
+    /// The text [`Color`] of the active tab.
+    pub active_text_color: Color,
 }
 
 /// The appearance of a highlight of the [`PaneGrid`].
@@ -1260,5 +1843,39 @@ pub fn default(theme: &Theme) -> Style {
             color: palette.primary.strong.color,
             width: 2.0,
         },
+        dock_target: Highlight {
+            background: Background::Color(palette.background.weak.color),
+            border: Border {
+                width: 1.0,
+                color: palette.primary.strong.color,
+                radius: 4.0.into(),
+            },
+        },
+        floating: container::Style {
+            background: Some(palette.background.base.color.into()),
+            border: Border {
+                width: 1.0,
+                color: palette.background.strong.color,
+                radius: 0.0.into(),
+            },
+            shadow: Shadow {
+                color: Color::BLACK.scale_alpha(0.3),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 16.0,
+                spread: 0.0,
+            },
+            ..container::Style::default()
+        },
+        tabs: Tabs {
+            background: Background::Color(palette.background.weak.color),
+            active: Background::Color(palette.background.base.color),
+            border: Border {
+                width: 0.0,
+                color: Color::TRANSPARENT,
+                radius: 0.0.into(),
+            },
+            text_color: palette.background.weak.text,
+            active_text_color: palette.background.base.text,
+        },
     }
 }
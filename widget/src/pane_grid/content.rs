@@ -17,6 +17,7 @@ where
 {
     title_bar: Option<TitleBar<'a, Message, Theme, Renderer>>,
     body: Element<'a, Message, Theme, Renderer>,
+    tab: Option<String>,
     class: Theme::Class<'a>,
 }
 
@@ -30,6 +31,7 @@ where
         Self {
             title_bar: None,
             body: body.into(),
+            tab: None,
             class: Theme::default(),
         }
     }
@@ -40,6 +42,15 @@ where
         self
     }
 
+    /// Sets the label of the tab of the [`Content`], shown when its [`Pane`]
+    /// is stacked with others.
+    ///
+    /// [`Pane`]: super::Pane
+    pub fn tab(mut self, label: impl Into<String>) -> Self {
+        self.tab = Some(label.into());
+        self
+    }
+
     /// Sets the style of the [`Content`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> container::Style + 'a) -> Self
@@ -64,6 +75,10 @@ where
     Theme: container::Catalog,
     Renderer: core::Renderer,
 {
+    pub(super) fn tab_label(&self) -> &str {
+        self.tab.as_deref().unwrap_or_default()
+    }
+
     pub(super) fn state(&self) -> Tree {
         let children = if let Some(title_bar) = self.title_bar.as_ref() {
             vec![Tree::new(&self.body), title_bar.state()]
@@ -243,7 +243,7 @@ impl Node {
         }
     }
 
-    fn first_pane(&self) -> Pane {
+    pub(crate) fn first_pane(&self) -> Pane {
         match self {
             Node::Split { a, .. } => a.first_pane(),
             Node::Pane(pane) => *pane,
@@ -1,7 +1,7 @@
 //! The state of a [`PaneGrid`].
 //!
 //! [`PaneGrid`]: super::PaneGrid
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size, Vector};
 use crate::pane_grid::{Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target};
 
 use std::borrow::Cow;
@@ -9,8 +9,9 @@ use std::collections::BTreeMap;
 
 /// The state of a [`PaneGrid`].
 ///
-/// It keeps track of the state of each [`Pane`] and the position of each
-/// [`Split`].
+/// It keeps track of the state of each [`Pane`], the position of each
+/// [`Split`], the bounds of the floating panes, and the stacks of tabbed
+/// panes.
 ///
 /// The [`State`] needs to own any mutable contents a [`Pane`] may need. This is
 /// why this struct is generic over the type `T`. Values of this type are
@@ -53,6 +54,144 @@ impl<T> State<T> {
         State { panes, internal }
     }
 
+    /// Creates a new [`State`] from a [`Layout`] previously produced by
+    /// [`State::to_layout`], using the given function to restore the state of
+    /// each pane from its key.
+    ///
+    /// Returns `None` if the [`Layout`] contains an empty stack of tabs or
+    /// a split with an invalid ratio.
+    pub fn from_layout<K>(layout: Layout<K>, mut f: impl FnMut(K) -> T) -> Option<Self> {
+        fn restore<K, T>(
+            arrangement: Arrangement<K>,
+            state: &mut State<T>,
+            f: &mut impl FnMut(K) -> T,
+        ) -> Option<Node> {
+            match arrangement {
+                Arrangement::Split { axis, ratio, a, b } => {
+                    if ratio.is_nan() {
+                        return None;
+                    }
+
+                    let ratio = ratio.clamp(0.0, 1.0);
+                    let a = restore(*a, state, f)?;
+                    let b = restore(*b, state, f)?;
+
+                    Some(Node::Split {
+                        id: Split(state.internal.next_id()),
+                        axis,
+                        ratio,
+                        a: Box::new(a),
+                        b: Box::new(b),
+                    })
+                }
+                Arrangement::Pane(key) => Some(Node::Pane(state.insert(f(key)))),
+                Arrangement::Tabs { tabs, active } => {
+                    let tabs: Vec<Pane> =
+                        tabs.into_iter().map(|key| state.insert(f(key))).collect();
+                    let active = active.min(tabs.len().checked_sub(1)?);
+                    let pane = tabs[active];
+
+                    if tabs.len() > 1 {
+                        state.internal.stacks.push(Stack { tabs, active });
+                    }
+
+                    Some(Node::Pane(pane))
+                }
+            }
+        }
+
+        let mut state = State {
+            panes: BTreeMap::new(),
+            internal: Internal {
+                layout: Node::Pane(Pane(0)),
+                last_id: 0,
+                maximized: None,
+                floating: Vec::new(),
+                stacks: Vec::new(),
+            },
+        };
+
+        state.internal.layout = restore(layout.root, &mut state, &mut f)?;
+
+        for floating in layout.floating {
+            let pane = state.insert(f(floating.pane));
+
+            state.internal.floating.push((
+                pane,
+                Rectangle {
+                    x: floating.x,
+                    y: floating.y,
+                    width: floating.width,
+                    height: floating.height,
+                },
+            ));
+        }
+
+        Some(state)
+    }
+
+    /// Returns a stable [`Layout`] of the [`State`], which can be stored and
+    /// restored later with [`State::from_layout`].
+    ///
+    /// The given function produces the key that identifies the state of each
+    /// pane in the [`Layout`].
+    pub fn to_layout<K>(&self, mut key: impl FnMut(Pane, &T) -> K) -> Layout<K> {
+        fn arrangement<K, T>(
+            state: &State<T>,
+            node: &Node,
+            key: &mut impl FnMut(Pane, &T) -> K,
+        ) -> Arrangement<K> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Arrangement::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(arrangement(state, a, key)),
+                    b: Box::new(arrangement(state, b, key)),
+                },
+                Node::Pane(pane) => match state.internal.stack_index(*pane) {
+                    Some(index) => {
+                        let stack = &state.internal.stacks[index];
+
+                        Arrangement::Tabs {
+                            tabs: stack
+                                .tabs
+                                .iter()
+                                .map(|tab| key(*tab, &state.panes[tab]))
+                                .collect(),
+                            active: stack.active,
+                        }
+                    }
+                    None => Arrangement::Pane(key(*pane, &state.panes[pane])),
+                },
+            }
+        }
+
+        Layout {
+            root: arrangement(self, &self.internal.layout, &mut key),
+            floating: self
+                .internal
+                .floating
+                .iter()
+                .map(|(pane, bounds)| Floating {
+                    pane: key(*pane, &self.panes[pane]),
+                    x: bounds.x,
+                    y: bounds.y,
+                    width: bounds.width,
+                    height: bounds.height,
+                })
+                .collect(),
+        }
+    }
+
+    fn insert(&mut self, state: T) -> Pane {
+        let pane = Pane(self.internal.next_id());
+        let _ = self.panes.insert(pane, state);
+
+        pane
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -91,6 +230,199 @@ impl<T> State<T> {
         &self.internal.layout
     }
 
+    /// Returns an iterator over the floating panes of the [`State`] and their
+    /// bounds, from back to front.
+    pub fn floating(&self) -> impl Iterator<Item = (Pane, Rectangle)> + '_ {
+        self.internal.floating.iter().copied()
+    }
+
+    /// Returns `true` if the given [`Pane`] is floating.
+    pub fn is_floating(&self, pane: Pane) -> bool {
+        self.internal.floating_index(pane).is_some()
+    }
+
+    /// Returns the tabs of the stack the given [`Pane`] belongs to, if any.
+    pub fn tabs(&self, pane: Pane) -> Option<&[Pane]> {
+        self.internal
+            .stack_index(pane)
+            .map(|index| self.internal.stacks[index].tabs.as_slice())
+    }
+
+    /// Stacks the given [`Pane`] on the target [`Pane`] as a new tab and makes
+    /// it the active one.
+    ///
+    /// If the target [`Pane`] is not part of a stack yet, a new stack is
+    /// created in its place. Returns `true` if the [`Pane`] was stacked.
+    ///
+    /// You may want to call this method when handling a [`DragEvent`] that
+    /// drops a [`Pane`] on the center [`Region`] of another, instead of
+    /// swapping them.
+    ///
+    /// [`DragEvent`]: super::DragEvent
+    pub fn stack(&mut self, pane: Pane, target: Pane) -> bool {
+        if pane == target || !self.panes.contains_key(&pane) || self.is_floating(target) {
+            return false;
+        }
+
+        let target_stack = self.internal.stack_index(target);
+
+        if target_stack.is_some() && target_stack == self.internal.stack_index(pane) {
+            self.select_tab(pane);
+            return true;
+        }
+
+        if target_stack.is_none() && self.internal.layout.find(target).is_none() {
+            return false;
+        }
+
+        if let Some(index) = self.internal.floating_index(pane) {
+            let _ = self.internal.floating.remove(index);
+        } else if self.unstack(pane).is_none() && self.internal.layout.remove(pane).is_none() {
+            return false;
+        }
+
+        if self.internal.maximized == Some(pane) {
+            let _ = self.internal.maximized.take();
+        }
+
+        let active = if let Some(index) = self.internal.stack_index(target) {
+            let stack = &mut self.internal.stacks[index];
+            let active = stack.tabs[stack.active];
+
+            stack.tabs.push(pane);
+            stack.active = stack.tabs.len() - 1;
+
+            active
+        } else {
+            self.internal.stacks.push(Stack {
+                tabs: vec![target, pane],
+                active: 1,
+            });
+
+            target
+        };
+
+        self.internal.replace(active, pane);
+
+        true
+    }
+
+    /// Makes the given [`Pane`] the active tab of its stack.
+    ///
+    /// If you want to enable tab interactions in your [`PaneGrid`], you will
+    /// need to call this method when handling a [`TabEvent::Selected`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`TabEvent::Selected`]: super::TabEvent::Selected
+    pub fn select_tab(&mut self, pane: Pane) {
+        let Some(index) = self.internal.stack_index(pane) else {
+            return;
+        };
+
+        let stack = &mut self.internal.stacks[index];
+        let active = stack.tabs[stack.active];
+
+        if let Some(position) = stack.tabs.iter().position(|tab| *tab == pane) {
+            stack.active = position;
+
+            self.internal.replace(active, pane);
+        }
+    }
+
+    /// Moves the given [`Pane`] to the provided index in the tabs of its
+    /// stack.
+    ///
+    /// If you want to enable tab interactions in your [`PaneGrid`], you will
+    /// need to call this method when handling a [`TabEvent::Moved`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`TabEvent::Moved`]: super::TabEvent::Moved
+    pub fn move_tab(&mut self, pane: Pane, index: usize) {
+        let Some(stack) = self
+            .internal
+            .stack_index(pane)
+            .map(|stack| &mut self.internal.stacks[stack])
+        else {
+            return;
+        };
+
+        let active = stack.tabs[stack.active];
+
+        if let Some(position) = stack.tabs.iter().position(|tab| *tab == pane) {
+            let tab = stack.tabs.remove(position);
+
+            stack.tabs.insert(index.min(stack.tabs.len()), tab);
+            stack.active = stack
+                .tabs
+                .iter()
+                .position(|tab| *tab == active)
+                .unwrap_or_default();
+        }
+    }
+
+    /// Removes the given [`Pane`] from its stack, detaching it from the
+    /// layout, and returns the active tab left in its place.
+    fn unstack(&mut self, pane: Pane) -> Option<Pane> {
+        let index = self.internal.stack_index(pane)?;
+        let stack = &mut self.internal.stacks[index];
+
+        let position = stack.tabs.iter().position(|tab| *tab == pane)?;
+        let _ = stack.tabs.remove(position);
+
+        let is_active = position == stack.active;
+
+        if position < stack.active || stack.active == stack.tabs.len() {
+            stack.active -= 1;
+        }
+
+        let active = stack.tabs[stack.active];
+
+        if stack.tabs.len() < 2 {
+            let _ = self.internal.stacks.remove(index);
+        }
+
+        if is_active {
+            self.internal.replace(pane, active);
+        }
+
+        Some(active)
+    }
+
+    /// Pops the given [`Pane`] out of the layout and floats it above the rest
+    /// of the [`PaneGrid`] with the given bounds, relative to the [`PaneGrid`].
+    ///
+    /// If the [`Pane`] is floating already, it is moved to the new bounds and
+    /// brought to the front.
+    ///
+    /// The last docked [`Pane`] cannot be floated. Returns `true` if the
+    /// [`Pane`] is floating afterwards.
+    ///
+    /// If you want to enable floating interactions in your [`PaneGrid`], you
+    /// will need to call this method when handling a [`FloatEvent`]. Floating
+    /// panes can be docked back with [`Self::drop`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`FloatEvent`]: super::FloatEvent
+    pub fn float(&mut self, pane: Pane, bounds: Rectangle) -> bool {
+        if !self.panes.contains_key(&pane) {
+            return false;
+        }
+
+        if let Some(index) = self.internal.floating_index(pane) {
+            let _ = self.internal.floating.remove(index);
+        } else if self.unstack(pane).is_none() && self.internal.layout.remove(pane).is_none() {
+            return false;
+        }
+
+        if self.internal.maximized == Some(pane) {
+            let _ = self.internal.maximized.take();
+        }
+
+        self.internal.floating.push((pane, bounds));
+
+        true
+    }
+
     /// Returns the adjacent [`Pane`] of another [`Pane`] in the given
     /// direction, if there is one.
     pub fn adjacent(&self, pane: Pane, direction: Direction) -> Option<Pane> {
@@ -130,7 +462,14 @@ impl<T> State<T> {
     /// Split a target [`Pane`] with a given [`Pane`] on a given [`Region`].
     ///
     /// Panes will be swapped by default for [`Region::Center`].
+    ///
+    /// The given [`Pane`] may be floating or stacked, in which case it is
+    /// docked; but the target [`Pane`] must be visible in the layout.
     pub fn split_with(&mut self, target: Pane, pane: Pane, region: Region) {
+        if self.is_floating(target) || self.internal.is_hidden(target) {
+            return;
+        }
+
         match region {
             Region::Center => self.swap(pane, target),
             Region::Edge(edge) => match edge {
@@ -157,6 +496,11 @@ impl<T> State<T> {
             Target::Pane(target, region) => {
                 self.split_with(target, pane, region);
             }
+            Target::Tab(target, index) => {
+                if self.stack(pane, target) {
+                    self.move_tab(pane, index);
+                }
+            }
         }
     }
 
@@ -251,6 +595,9 @@ impl<T> State<T> {
 
     /// Swaps the position of the provided panes in the [`State`].
     ///
+    /// A floating [`Pane`] swapped with a docked one takes its place in the
+    /// layout, while the docked one floats with the previous bounds.
+    ///
     /// If you want to swap panes on drag and drop in your [`PaneGrid`], you
     /// will need to call this method when handling a [`DragEvent`].
     ///
@@ -267,6 +614,26 @@ impl<T> State<T> {
                 }
             }
         });
+
+        let stacked = self
+            .internal
+            .stacks
+            .iter_mut()
+            .flat_map(|stack| stack.tabs.iter_mut());
+
+        for pane in self
+            .internal
+            .floating
+            .iter_mut()
+            .map(|(pane, _)| pane)
+            .chain(stacked)
+        {
+            if *pane == a {
+                *pane = b;
+            } else if *pane == b {
+                *pane = a;
+            }
+        }
     }
 
     /// Resizes two panes by setting the position of the provided [`Split`].
@@ -285,12 +652,26 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// The closest sibling of a floating [`Pane`] is the first docked one,
+    /// while the closest sibling of a stacked [`Pane`] is the active tab left
+    /// in its stack.
     pub fn close(&mut self, pane: Pane) -> Option<(T, Pane)> {
         if self.internal.maximized == Some(pane) {
             let _ = self.internal.maximized.take();
         }
 
-        if let Some(sibling) = self.internal.layout.remove(pane) {
+        if let Some(active) = self.unstack(pane) {
+            return self.panes.remove(&pane).map(|state| (state, active));
+        }
+
+        if let Some(index) = self.internal.floating_index(pane) {
+            let _ = self.internal.floating.remove(index);
+
+            self.panes
+                .remove(&pane)
+                .map(|state| (state, self.internal.layout.first_pane()))
+        } else if let Some(sibling) = self.internal.layout.remove(pane) {
             self.panes.remove(&pane).map(|state| (state, sibling))
         } else {
             None
@@ -329,6 +710,14 @@ pub struct Internal {
     layout: Node,
     last_id: usize,
     maximized: Option<Pane>,
+    floating: Vec<(Pane, Rectangle)>,
+    stacks: Vec<Stack>,
+}
+
+#[derive(Debug, Clone)]
+struct Stack {
+    tabs: Vec<Pane>,
+    active: usize,
 }
 
 impl Internal {
@@ -378,6 +767,8 @@ impl Internal {
             layout,
             last_id,
             maximized: None,
+            floating: Vec::new(),
+            stacks: Vec::new(),
         }
     }
 
@@ -391,6 +782,127 @@ impl Internal {
     pub(super) fn maximized(&self) -> Option<Pane> {
         self.maximized
     }
+
+    pub(super) fn floating(&self) -> &[(Pane, Rectangle)] {
+        if self.maximized.is_some() {
+            &[]
+        } else {
+            &self.floating
+        }
+    }
+
+    fn next_id(&mut self) -> usize {
+        let id = self.last_id;
+        self.last_id += 1;
+
+        id
+    }
+
+    pub(super) fn stacks(&self) -> impl Iterator<Item = (&[Pane], Pane)> {
+        self.stacks
+            .iter()
+            .filter(|_| self.maximized.is_none())
+            .map(|stack| (stack.tabs.as_slice(), stack.tabs[stack.active]))
+    }
+
+    pub(super) fn is_hidden(&self, pane: Pane) -> bool {
+        self.stacks
+            .iter()
+            .any(|stack| stack.tabs[stack.active] != pane && stack.tabs.contains(&pane))
+    }
+
+    pub(super) fn is_visible(&self, pane: Pane) -> bool {
+        match self.maximized {
+            Some(maximized) => maximized == pane,
+            None => !self.is_hidden(pane),
+        }
+    }
+
+    fn stack_index(&self, pane: Pane) -> Option<usize> {
+        self.stacks
+            .iter()
+            .position(|stack| stack.tabs.contains(&pane))
+    }
+
+    fn replace(&mut self, old: Pane, new: Pane) {
+        self.layout.update(&|node| {
+            if let Node::Pane(pane) = node
+                && *pane == old
+            {
+                *node = Node::Pane(new);
+            }
+        });
+    }
+
+    fn floating_index(&self, pane: Pane) -> Option<usize> {
+        self.floating
+            .iter()
+            .position(|(floating, _)| *floating == pane)
+    }
+}
+
+/// A stable representation of the layout of a [`State`], which can be stored
+/// and restored later.
+///
+/// With the `serde` feature enabled, a [`Layout`] can be serialized as long as
+/// its keys can.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout<K> {
+    /// The [`Arrangement`] of the docked panes.
+    pub root: Arrangement<K>,
+    /// The floating panes, from back to front.
+    pub floating: Vec<Floating<K>>,
+}
+
+/// The arrangement of the docked panes of a [`Layout`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Arrangement<K> {
+    /// A split of the available space.
+    Split {
+        /// The direction of the split.
+        axis: Axis,
+
+        /// The ratio of the split in [0.0, 1.0].
+        ratio: f32,
+
+        /// The left/top [`Arrangement`] of the split.
+        a: Box<Arrangement<K>>,
+
+        /// The right/bottom [`Arrangement`] of the split.
+        b: Box<Arrangement<K>>,
+    },
+    /// A single pane.
+    Pane(K),
+    /// A stack of tabbed panes.
+    Tabs {
+        /// The panes of the stack.
+        tabs: Vec<K>,
+
+        /// The index of the active tab.
+        active: usize,
+    },
+}
+
+/// A floating pane of a [`Layout`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Floating<K> {
+    /// The key of the pane.
+    pub pane: K,
+    /// The horizontal position of the pane, relative to the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub x: f32,
+    /// The vertical position of the pane, relative to the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    pub y: f32,
+    /// The width of the pane.
+    pub width: f32,
+    /// The height of the pane.
+    pub height: f32,
 }
 
 /// The current action of a [`PaneGrid`].
@@ -421,6 +933,20 @@ pub enum Action {
         /// The [`Axis`] of the [`Split`].
         axis: Axis,
     },
+    /// A floating [`Pane`] in the [`PaneGrid`] is being resized.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    ResizingFloating {
+        /// The floating [`Pane`] being resized.
+        pane: Pane,
+        /// The [`Grip`] being dragged.
+        grip: Grip,
+        /// The starting [`Point`] of the resize interaction.
+        origin: Point,
+        /// The bounds of the [`Pane`] when the interaction started, relative
+        /// to the [`PaneGrid`].
+        bounds: Rectangle,
+    },
 }
 
 impl Action {
@@ -439,4 +965,185 @@ impl Action {
             _ => None,
         }
     }
+
+    /// Returns the current floating [`Pane`] that is being resized, if any.
+    pub fn picked_floating(&self) -> Option<(Pane, Grip)> {
+        match *self {
+            Action::ResizingFloating { pane, grip, .. } => Some((pane, grip)),
+            _ => None,
+        }
+    }
+}
+
+/// The part of the border of a floating [`Pane`] that can be dragged to
+/// resize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grip {
+    /// The horizontal [`Edge`] being dragged, if any.
+    pub horizontal: Option<Edge>,
+    /// The vertical [`Edge`] being dragged, if any.
+    pub vertical: Option<Edge>,
+}
+
+impl Grip {
+    /// Returns the [`Grip`] of the given bounds found at the provided
+    /// position, if any.
+    pub fn at(bounds: Rectangle, position: Point, thickness: f32) -> Option<Self> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let horizontal = if position.x < bounds.x + thickness {
+            Some(Edge::Left)
+        } else if position.x > bounds.x + bounds.width - thickness {
+            Some(Edge::Right)
+        } else {
+            None
+        };
+
+        let vertical = if position.y < bounds.y + thickness {
+            Some(Edge::Top)
+        } else if position.y > bounds.y + bounds.height - thickness {
+            Some(Edge::Bottom)
+        } else {
+            None
+        };
+
+        (horizontal.is_some() || vertical.is_some()).then_some(Self {
+            horizontal,
+            vertical,
+        })
+    }
+
+    /// Resizes the given bounds by dragging the [`Grip`] the given
+    /// distance, keeping them above the minimum size.
+    pub fn resize(self, bounds: Rectangle, delta: Vector, min_size: f32) -> Rectangle {
+        let mut resized = bounds;
+
+        match self.horizontal {
+            Some(Edge::Left) => {
+                resized.x = (bounds.x + delta.x).min(bounds.x + bounds.width - min_size);
+                resized.width = bounds.x + bounds.width - resized.x;
+            }
+            Some(Edge::Right) => {
+                resized.width = (bounds.width + delta.x).max(min_size);
+            }
+            _ => {}
+        }
+
+        match self.vertical {
+            Some(Edge::Top) => {
+                resized.y = (bounds.y + delta.y).min(bounds.y + bounds.height - min_size);
+                resized.height = bounds.y + bounds.height - resized.y;
+            }
+            Some(Edge::Bottom) => {
+                resized.height = (bounds.height + delta.y).max(min_size);
+            }
+            _ => {}
+        }
+
+        resized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floating_panes_can_be_docked() {
+        let (mut state, first) = State::new("first");
+        let (second, _) = state.split(Axis::Vertical, first, "second").unwrap();
+
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 100.0));
+
+        assert!(state.float(second, bounds));
+        assert!(!state.float(first, bounds));
+        assert!(matches!(state.layout(), Node::Pane(pane) if *pane == first));
+        assert_eq!(state.floating().collect::<Vec<_>>(), vec![(second, bounds)]);
+
+        state.drop(second, Target::Pane(first, Region::Edge(Edge::Left)));
+
+        assert!(!state.is_floating(second));
+        assert_eq!(state.get(second), Some(&"second"));
+        assert_eq!(
+            state
+                .layout()
+                .pane_regions(0.0, 0.0, Size::new(2.0, 1.0))
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn layouts_can_be_restored() {
+        let (mut state, first) = State::new("first");
+        let (second, _) = state.split(Axis::Horizontal, first, "second").unwrap();
+        let (third, split) = state.split(Axis::Vertical, second, "third").unwrap();
+        let (fourth, _) = state.split(Axis::Vertical, third, "fourth").unwrap();
+
+        state.resize(split, 0.25);
+        let _ = state.stack(first, second);
+        let _ = state.float(fourth, Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0)));
+
+        let layout = state.to_layout(|_, name| name.to_string());
+        let restored = State::from_layout(layout.clone(), |name| name).unwrap();
+
+        assert_eq!(restored.len(), 4);
+        assert_eq!(restored.to_layout(|_, name| name.to_string()), layout);
+    }
+
+    #[test]
+    fn invalid_ratios_are_not_restored() {
+        let split = |ratio| Layout {
+            root: Arrangement::Split {
+                axis: Axis::Vertical,
+                ratio,
+                a: Box::new(Arrangement::Pane("first")),
+                b: Box::new(Arrangement::Pane("second")),
+            },
+            floating: Vec::new(),
+        };
+
+        assert!(State::from_layout(split(f32::NAN), |name| name).is_none());
+
+        let restored = State::from_layout(split(2.0), |name| name).unwrap();
+
+        assert!(matches!(
+            restored.to_layout(|_, name| *name).root,
+            Arrangement::Split { ratio, .. } if ratio == 1.0
+        ));
+    }
+
+    #[test]
+    fn stacked_tabs_can_be_split_out() {
+        let (mut state, first) = State::new("first");
+        let (second, _) = state.split(Axis::Vertical, first, "second").unwrap();
+        let (third, _) = state.split(Axis::Vertical, second, "third").unwrap();
+
+        assert!(state.stack(second, first));
+        assert!(state.stack(third, first));
+        assert_eq!(state.tabs(first), Some([first, second, third].as_slice()));
+        assert!(matches!(state.layout(), Node::Pane(pane) if *pane == third));
+
+        state.select_tab(first);
+        state.move_tab(first, 2);
+
+        assert_eq!(state.tabs(third), Some([second, third, first].as_slice()));
+        assert!(matches!(state.layout(), Node::Pane(pane) if *pane == first));
+
+        state.drop(second, Target::Edge(Edge::Right));
+        assert_eq!(state.tabs(first), Some([third, first].as_slice()));
+
+        state.drop(third, Target::Pane(second, Region::Edge(Edge::Left)));
+
+        assert_eq!(state.tabs(third), None);
+        assert_eq!(
+            state
+                .layout()
+                .pane_regions(0.0, 0.0, Size::new(2.0, 1.0))
+                .len(),
+            3
+        );
+    }
 }
@@ -62,12 +62,14 @@
 //! ```
 use crate::core::alignment;
 use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -167,6 +169,11 @@ where
     menu_class: <Theme as menu::Catalog>::Class<'a>,
     last_status: Option<Status>,
     menu_height: Length,
+    is_searchable: bool,
+    filtered: Vec<T>,
+    is_disabled: Option<Box<dyn Fn(&T) -> bool + 'a>>,
+    group: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    icon: Option<Box<dyn Fn(&T) -> Option<Icon<Renderer::Font>> + 'a>>,
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> PickList<'a, T, L, V, Message, Theme, Renderer>
@@ -199,6 +206,11 @@ where
             menu_class: <Theme as Catalog>::default_menu(),
             last_status: None,
             menu_height: Length::Shrink,
+            is_searchable: false,
+            filtered: Vec::new(),
+            is_disabled: None,
+            group: None,
+            icon: None,
         }
     }
 
@@ -256,6 +268,43 @@ where
         self
     }
 
+    /// Sets whether the options of the [`PickList`] can be filtered by
+    /// typing while it is open.
+    ///
+    /// Otherwise, typing jumps to the first option starting with the typed
+    /// characters. In both cases, the options can be navigated with the arrow
+    /// keys and selected with Enter.
+    pub fn searchable(mut self, is_searchable: bool) -> Self {
+        self.is_searchable = is_searchable;
+        self
+    }
+
+    /// Sets the function that determines whether an option of the
+    /// [`PickList`] is disabled.
+    ///
+    /// Disabled options are shown in the menu, but they cannot be selected.
+    pub fn disabled(mut self, is_disabled: impl Fn(&T) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the function that returns the group of an option of the
+    /// [`PickList`].
+    ///
+    /// Consecutive options of the same group are shown together in the menu,
+    /// under a header with the name of the group unless it is empty.
+    pub fn group(mut self, group: impl Fn(&T) -> Option<String> + 'a) -> Self {
+        self.group = Some(Box::new(group));
+        self
+    }
+
+    /// Sets the function that returns the [`Icon`] shown before an option of
+    /// the [`PickList`] in its menu, if any.
+    pub fn icon(mut self, icon: impl Fn(&T) -> Option<Icon<Renderer::Font>> + 'a) -> Self {
+        self.icon = Some(Box::new(icon));
+        self
+    }
+
     /// Sets the message that will be produced when the [`PickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
@@ -303,8 +352,134 @@ where
         self.menu_class = class.into();
         self
     }
+
+    /// Returns the options shown in the menu of the [`PickList`] for the
+    /// given query.
+    fn visible(&self, query: &str) -> Vec<&T> {
+        let options = self.options.borrow().iter();
+
+        if self.is_searchable && !query.is_empty() {
+            options
+                .filter(|option| menu::find(&option.to_string(), query).is_some())
+                .collect()
+        } else {
+            options.collect()
+        }
+    }
+
+    /// Returns whether the given option of the [`PickList`] can be selected.
+    fn is_enabled(&self, option: &T) -> bool {
+        self.is_disabled
+            .as_ref()
+            .is_none_or(|is_disabled| !is_disabled(option))
+    }
+
+    /// Returns the index of the first option that can be selected in the
+    /// menu of the [`PickList`] for the given query.
+    fn first_enabled(&self, query: &str) -> Option<usize> {
+        self.visible(query)
+            .into_iter()
+            .position(|option| self.is_enabled(option))
+    }
+
+    /// Handles a key press while the menu of the [`PickList`] is open,
+    /// returning whether it was handled.
+    fn on_key(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        key: &keyboard::Key,
+        typed: Option<char>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::ArrowDown | key::Named::ArrowUp) => {
+                let visible = self.visible(&state.query);
+                let is_down = key.as_ref() == keyboard::Key::Named(key::Named::ArrowDown);
+                let is_enabled = |index: &usize| self.is_enabled(visible[*index]);
+
+                let next = if is_down {
+                    let start = state.hovered_option.map_or(0, |index| index + 1);
+
+                    (start.min(visible.len())..visible.len()).find(is_enabled)
+                } else {
+                    let end = state.hovered_option.unwrap_or(visible.len());
+
+                    (0..end.min(visible.len())).rev().find(is_enabled)
+                };
+
+                state.hovered_option = next.or(state
+                    .hovered_option
+                    .filter(|index| *index < visible.len() && is_enabled(index)));
+            }
+            keyboard::Key::Named(key::Named::Enter) => {
+                let visible = self.visible(&state.query);
+
+                if let Some(option) = state
+                    .hovered_option
+                    .and_then(|index| visible.get(index))
+                    .filter(|option| self.is_enabled(option))
+                {
+                    shell.publish((self.on_select)((*option).clone()));
+                }
+
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+            }
+            keyboard::Key::Named(key::Named::Backspace) if self.is_searchable => {
+                let _ = state.query.pop();
+
+                state.hovered_option = self.first_enabled(&state.query);
+            }
+            _ => {
+                let Some(c) = typed else {
+                    return false;
+                };
+
+                if self.is_searchable {
+                    state.query.push(c);
+                    state.hovered_option = self.first_enabled(&state.query);
+                } else {
+                    let now = Instant::now();
+
+                    if state
+                        .last_typed
+                        .is_none_or(|last_typed| now - last_typed > TYPING_TIMEOUT)
+                    {
+                        state.query.clear();
+                    }
+
+                    state.query.push(c);
+                    state.last_typed = Some(now);
+
+                    let prefix = state.query.to_lowercase();
+
+                    if let Some(index) = self.options.borrow().iter().position(|option| {
+                        self.is_enabled(option)
+                            && option.to_string().to_lowercase().starts_with(&prefix)
+                    }) {
+                        state.hovered_option = Some(index);
+                    }
+                }
+            }
+        }
+
+        true
+    }
 }
 
+/// The time after which typing starts a new prefix to jump to.
+const TYPING_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for PickList<'a, T, L, V, Message, Theme, Renderer>
 where
@@ -438,11 +613,13 @@ where
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
                     state.is_open = true;
+                    state.query.clear();
                     state.hovered_option = self
                         .options
                         .borrow()
                         .iter()
-                        .position(|option| Some(option) == selected);
+                        .position(|option| Some(option) == selected)
+                        .filter(|index| self.is_enabled(&self.options.borrow()[*index]));
 
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
@@ -461,26 +638,28 @@ where
                     fn find_next<'a, T: PartialEq>(
                         selected: &'a T,
                         mut options: impl Iterator<Item = &'a T>,
+                        is_enabled: impl Fn(&T) -> bool,
                     ) -> Option<&'a T> {
                         let _ = options.find(|&option| option == selected);
 
-                        options.next()
+                        options.find(|&option| is_enabled(option))
                     }
 
                     let options = self.options.borrow();
                     let selected = self.selected.as_ref().map(Borrow::borrow);
+                    let is_enabled = |option: &T| self.is_enabled(option);
 
                     let next_option = if *y < 0.0 {
                         if let Some(selected) = selected {
-                            find_next(selected, options.iter())
+                            find_next(selected, options.iter(), is_enabled)
                         } else {
-                            options.first()
+                            options.iter().find(|&option| is_enabled(option))
                         }
                     } else if *y > 0.0 {
                         if let Some(selected) = selected {
-                            find_next(selected, options.iter().rev())
+                            find_next(selected, options.iter().rev(), is_enabled)
                         } else {
-                            options.last()
+                            options.iter().rev().find(|&option| is_enabled(option))
                         }
                     } else {
                         None
@@ -493,6 +672,21 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_open && !modifiers.command() => {
+                let typed = text
+                    .as_deref()
+                    .and_then(|text| text.chars().find(|c| !c.is_control()));
+
+                if self.on_key(state, key, typed, shell) {
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = *modifiers;
             }
@@ -632,7 +826,10 @@ where
             );
         }
 
-        let label = selected.map(ToString::to_string);
+        let query = (state.is_open && self.is_searchable && !state.query.is_empty())
+            .then(|| state.query.clone());
+
+        let label = query.clone().or_else(|| selected.map(ToString::to_string));
 
         if let Some(label) = label.or_else(|| self.placeholder.clone()) {
             let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
@@ -654,7 +851,7 @@ where
                     hint_factor: renderer.scale_factor(),
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if selected.is_some() {
+                if query.is_some() || selected.is_some() {
                     style.text_color
                 } else {
                     style.placeholder_color
@@ -678,11 +875,21 @@ where
         if state.is_open {
             let bounds = layout.bounds();
 
+            let is_filtered = self.is_searchable && !state.query.is_empty();
+
+            let options: &[T] = if is_filtered {
+                self.filtered = self.visible(&state.query).into_iter().cloned().collect();
+
+                &self.filtered
+            } else {
+                self.options.borrow()
+            };
+
             let on_select = &self.on_select;
 
             let mut menu = Menu::new(
                 &mut state.menu,
-                self.options.borrow(),
+                options,
                 &mut state.hovered_option,
                 |option| {
                     state.is_open = false;
@@ -701,6 +908,22 @@ where
                 menu = menu.text_size(text_size);
             }
 
+            if is_filtered {
+                menu = menu.highlight(&state.query);
+            }
+
+            if let Some(is_disabled) = &self.is_disabled {
+                menu = menu.disabled(&**is_disabled);
+            }
+
+            if let Some(group) = &self.group {
+                menu = menu.group(&**group);
+            }
+
+            if let Some(icon) = &self.icon {
+                menu = menu.icon(&**icon);
+            }
+
             Some(menu.overlay(
                 layout.position() + translation,
                 *viewport,
@@ -734,6 +957,8 @@ struct State<P: text::Paragraph> {
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
     hovered_option: Option<usize>,
+    query: String,
+    last_typed: Option<Instant>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
 }
@@ -746,6 +971,8 @@ impl<P: text::Paragraph> State<P> {
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
             hovered_option: Option::default(),
+            query: String::new(),
+            last_typed: None,
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
         }
@@ -0,0 +1,774 @@
+//! Popovers display some content next to an anchor element.
+//!
+//! A [`Popover`] is placed on its preferred side of the anchor, flipping to
+//! the opposite side when it does not fit in the window.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{button, popover, text};
+//!
+//! struct State {
+//!     is_open: bool,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggle,
+//!     Close,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     popover(
+//!         button("Details").on_press(Message::Toggle),
+//!         text("Some details about the button, right below it."),
+//!     )
+//!     .open(state.is_open)
+//!     .placement(popover::Placement::Bottom)
+//!     .gap(8)
+//!     .on_dismiss(Message::Close)
+//!     .into()
+//! }
+//! ```
+use crate::container;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{self, Widget, tree};
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector,
+};
+
+/// An element that displays some content next to an anchor element.
+pub struct Popover<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    anchor: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    placement: Placement,
+    align: Alignment,
+    gap: f32,
+    padding: Padding,
+    flip: bool,
+    on_dismiss: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The default padding of a [`Popover`].
+    const DEFAULT_PADDING: f32 = 10.0;
+
+    /// Creates a new [`Popover`] displaying the given content next to the
+    /// anchor element.
+    pub fn new(
+        anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            anchor: anchor.into(),
+            content: content.into(),
+            is_open: true,
+            placement: Placement::default(),
+            align: Alignment::Center,
+            gap: 0.0,
+            padding: Padding::new(Self::DEFAULT_PADDING),
+            flip: true,
+            on_dismiss: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the [`Popover`] is open.
+    ///
+    /// By default, a [`Popover`] is open.
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    /// Sets the preferred [`Placement`] of the [`Popover`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the alignment of the [`Popover`] along the side of its anchor.
+    ///
+    /// By default, a [`Popover`] is centered.
+    pub fn align(mut self, align: impl Into<Alignment>) -> Self {
+        self.align = align.into();
+        self
+    }
+
+    /// Sets the gap between the anchor and the [`Popover`].
+    ///
+    /// The arrow of the [`Style`] is drawn inside this gap.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Popover`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether the [`Popover`] moves to the opposite side of its
+    /// anchor when it does not fit in the window.
+    ///
+    /// Enabled by default.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Popover`] is
+    /// dismissed; by clicking outside of it or pressing the Escape key.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Sets the style of the [`Popover`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Popover`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The preferred side of the anchor where a [`Popover`] is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// Before the anchor; to its left.
+    Start,
+    /// After the anchor; to its right.
+    End,
+}
+
+impl Placement {
+    /// Returns the opposite side of the [`Placement`].
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    placement: Placement,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popover<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.anchor),
+            widget::Tree::new(&self.content),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.anchor.as_widget(), self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.anchor.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.anchor.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.anchor
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.anchor
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.anchor.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let mut children = tree.children.iter_mut();
+
+        let anchor = self.anchor.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let popover = if self.is_open {
+            Some(overlay::Element::new(Box::new(Overlay {
+                anchor: layout.bounds() + translation,
+                content: &mut self.content,
+                tree: children.next().unwrap(),
+                state,
+                placement: self.placement,
+                align: self.align,
+                gap: self.gap,
+                padding: self.padding,
+                flip: self.flip,
+                on_dismiss: self.on_dismiss.as_ref(),
+                class: &self.class,
+            })))
+        } else {
+            None
+        };
+
+        if anchor.is_some() || popover.is_some() {
+            Some(
+                overlay::Group::with_children(anchor.into_iter().chain(popover).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popover<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(popover: Popover<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    anchor: Rectangle,
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut widget::Tree,
+    state: &'b mut State,
+    placement: Placement,
+    align: Alignment,
+    gap: f32,
+    padding: Padding,
+    flip: bool,
+    on_dismiss: Option<&'b Message>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds).shrink(self.padding);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+
+        let size = content.size().expand(self.padding);
+
+        let (position, placement) = position(
+            self.anchor,
+            size,
+            Rectangle::with_size(bounds),
+            self.placement,
+            self.align,
+            self.gap,
+            self.flip,
+        );
+
+        self.state.placement = placement;
+
+        layout::Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &bounds,
+        );
+
+        let Some(on_dismiss) = self.on_dismiss else {
+            return;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) && !cursor.is_over(self.anchor) {
+                    shell.publish(on_dismiss.clone());
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if !shell.is_event_captured() => {
+                shell.publish(on_dismiss.clone());
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(self.class);
+
+        container::draw_background(renderer, &style.container, bounds);
+
+        if style.arrow > 0.0
+            && let Some(Background::Color(color)) = style.container.background
+        {
+            draw_arrow(
+                renderer,
+                self.state.placement,
+                bounds,
+                self.anchor,
+                style.arrow,
+                color,
+            );
+        }
+
+        let defaults = renderer::Style {
+            text_color: style
+                .container
+                .text_color
+                .unwrap_or(inherited_style.text_color),
+        };
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &defaults,
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+}
+
+/// Computes the position of a [`Popover`] of the given size around its
+/// anchor, returning the [`Placement`] that was chosen.
+fn position(
+    anchor: Rectangle,
+    size: Size,
+    viewport: Rectangle,
+    placement: Placement,
+    align: Alignment,
+    gap: f32,
+    flip: bool,
+) -> (Point, Placement) {
+    let fits = |placement| match placement {
+        Placement::Top => anchor.y - gap - size.height >= viewport.y,
+        Placement::Bottom => {
+            anchor.y + anchor.height + gap + size.height <= viewport.y + viewport.height
+        }
+        Placement::Start => anchor.x - gap - size.width >= viewport.x,
+        Placement::End => anchor.x + anchor.width + gap + size.width <= viewport.x + viewport.width,
+    };
+
+    let placement = if flip && !fits(placement) && fits(placement.opposite()) {
+        placement.opposite()
+    } else {
+        placement
+    };
+
+    let align = |start: f32, length: f32, extent: f32| match align {
+        Alignment::Start => start,
+        Alignment::Center => start + (length - extent) / 2.0,
+        Alignment::End => start + length - extent,
+    };
+
+    let position = match placement {
+        Placement::Top => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y - gap - size.height,
+        ),
+        Placement::Bottom => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y + anchor.height + gap,
+        ),
+        Placement::Start => Point::new(
+            anchor.x - gap - size.width,
+            align(anchor.y, anchor.height, size.height),
+        ),
+        Placement::End => Point::new(
+            anchor.x + anchor.width + gap,
+            align(anchor.y, anchor.height, size.height),
+        ),
+    };
+
+    let position = Point::new(
+        position
+            .x
+            .min(viewport.x + viewport.width - size.width)
+            .max(viewport.x),
+        position
+            .y
+            .min(viewport.y + viewport.height - size.height)
+            .max(viewport.y),
+    );
+
+    (position, placement)
+}
+
+/// Draws a triangular arrow on the side of the `bounds` facing the anchor,
+/// pointing at its center.
+///
+/// The arrow is made of strips of a single pixel, so it can be drawn with
+/// quads by any renderer.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    placement: Placement,
+    bounds: Rectangle,
+    anchor: Rectangle,
+    size: f32,
+    color: Color,
+) where
+    Renderer: crate::core::Renderer,
+{
+    let center = anchor.center();
+    let strips = size.ceil() as usize;
+
+    for strip in 0..strips {
+        let depth = strip as f32;
+        let half = size - depth;
+
+        let bounds = match placement {
+            Placement::Top | Placement::Bottom => {
+                let x = center
+                    .x
+                    .min(bounds.x + bounds.width - size)
+                    .max(bounds.x + size);
+
+                let y = if placement == Placement::Top {
+                    bounds.y + bounds.height + depth
+                } else {
+                    bounds.y - depth - 1.0
+                };
+
+                Rectangle {
+                    x: x - half,
+                    y,
+                    width: half * 2.0,
+                    height: 1.0,
+                }
+            }
+            Placement::Start | Placement::End => {
+                let y = center
+                    .y
+                    .min(bounds.y + bounds.height - size)
+                    .max(bounds.y + size);
+
+                let x = if placement == Placement::Start {
+                    bounds.x + bounds.width + depth
+                } else {
+                    bounds.x - depth - 1.0
+                };
+
+                Rectangle {
+                    x,
+                    y: y - half,
+                    width: 1.0,
+                    height: half * 2.0,
+                }
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The appearance of a [`Popover`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`container::Style`] of the [`Popover`].
+    pub container: container::Style,
+    /// The size of the arrow pointing at the anchor, drawn with the
+    /// background color of the [`Popover`] inside its gap.
+    ///
+    /// No arrow is drawn when zero.
+    pub arrow: f32,
+}
+
+/// The theme catalog of a [`Popover`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Popover`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Popover`]; a bordered box without an arrow.
+pub fn default(theme: &crate::Theme) -> Style {
+    Style {
+        container: container::bordered_box(theme),
+        arrow: 0.0,
+    }
+}
+
+/// A [`Popover`] with a solid background and an arrow pointing at its
+/// anchor.
+pub fn arrowed(theme: &crate::Theme) -> Style {
+    Style {
+        container: container::rounded_box(theme),
+        arrow: 6.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Placement, position};
+    use crate::core::{Alignment, Point, Rectangle, Size};
+
+    #[test]
+    fn popovers_flip_near_the_edges() {
+        let viewport = Rectangle::with_size(Size::new(400.0, 300.0));
+        let size = Size::new(100.0, 50.0);
+
+        let anchor = Rectangle::new(Point::new(150.0, 100.0), Size::new(100.0, 20.0));
+
+        assert_eq!(
+            position(
+                anchor,
+                size,
+                viewport,
+                Placement::Bottom,
+                Alignment::Center,
+                5.0,
+                true
+            ),
+            (Point::new(150.0, 125.0), Placement::Bottom)
+        );
+
+        let anchor = Rectangle::new(Point::new(0.0, 260.0), Size::new(40.0, 20.0));
+
+        assert_eq!(
+            position(
+                anchor,
+                size,
+                viewport,
+                Placement::Bottom,
+                Alignment::Center,
+                5.0,
+                true
+            ),
+            (Point::new(0.0, 205.0), Placement::Top)
+        );
+    }
+}
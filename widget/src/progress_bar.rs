@@ -23,13 +23,21 @@ use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+    self, Background, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size,
+    Theme, Widget,
 };
 
+use std::f32::consts::PI;
 use std::ops::RangeInclusive;
 
+/// The length of the bar of an indeterminate [`ProgressBar`], as a fraction
+/// of its total length.
+const PULSE_LENGTH: f32 = 0.3;
+
 /// A bar that displays progress.
 ///
 /// # Example
@@ -51,6 +59,9 @@ use std::ops::RangeInclusive;
 ///     progress_bar(0.0..=100.0, state.progress).into()
 /// }
 /// ```
+///
+/// When the progress is unknown, an [`indeterminate`](Self::indeterminate)
+/// bar pulses back and forth for as long as it is displayed.
 pub struct ProgressBar<'a, Theme = crate::Theme>
 where
     Theme: Catalog,
@@ -60,6 +71,9 @@ where
     length: Length,
     girth: Length,
     is_vertical: bool,
+    is_indeterminate: bool,
+    cycle_duration: Duration,
+    transition_duration: Duration,
     class: Theme::Class<'a>,
 }
 
@@ -82,6 +96,9 @@ where
             length: Length::Fill,
             girth: Length::from(Self::DEFAULT_GIRTH),
             is_vertical: false,
+            is_indeterminate: false,
+            cycle_duration: Duration::from_millis(1500),
+            transition_duration: Duration::ZERO,
             class: Theme::default(),
         }
     }
@@ -106,6 +123,30 @@ where
         self
     }
 
+    /// Turns the [`ProgressBar`] into an indeterminate [`ProgressBar`].
+    ///
+    /// Its value is ignored and a pulse travels along the bar instead.
+    pub fn indeterminate(mut self) -> Self {
+        self.is_indeterminate = true;
+        self
+    }
+
+    /// Sets the duration of a full pulse of an indeterminate [`ProgressBar`].
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets the duration of the animation toward a new value of the
+    /// [`ProgressBar`].
+    ///
+    /// By default, it is [`Duration::ZERO`] and new values are shown
+    /// immediately.
+    pub fn transition_duration(mut self, transition_duration: Duration) -> Self {
+        self.transition_duration = transition_duration;
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -139,6 +180,94 @@ where
             self.girth
         }
     }
+
+    fn fraction(&self) -> f32 {
+        let (range_start, range_end) = self.range.clone().into_inner();
+
+        if range_start >= range_end {
+            0.0
+        } else {
+            (self.value - range_start) / (range_end - range_start)
+        }
+    }
+}
+
+struct State {
+    start: Instant,
+    now: Instant,
+    from: f32,
+    to: f32,
+    transition_start: Instant,
+}
+
+impl State {
+    fn new(fraction: f32) -> Self {
+        let now = Instant::now();
+
+        Self {
+            start: now,
+            now,
+            from: fraction,
+            to: fraction,
+            transition_start: now,
+        }
+    }
+
+    /// Returns the phase of the current pulse, from 0 to 1.
+    fn phase(&self, cycle_duration: Duration) -> f32 {
+        let cycle = cycle_duration.as_secs_f32();
+
+        if cycle <= 0.0 {
+            return 0.0;
+        }
+
+        (self.now.duration_since(self.start).as_secs_f32() / cycle).fract()
+    }
+
+    /// Returns the fraction of the transition toward the latest value that
+    /// has elapsed, from 0 to 1.
+    fn transition(&self, duration: Duration) -> f32 {
+        if duration.is_zero() {
+            return 1.0;
+        }
+
+        (self
+            .now
+            .saturating_duration_since(self.transition_start)
+            .as_secs_f32()
+            / duration.as_secs_f32())
+        .min(1.0)
+    }
+
+    /// Returns the fraction of the bar that is currently filled.
+    fn progress(&self, duration: Duration) -> f32 {
+        let t = self.transition(duration);
+
+        if t >= 1.0 {
+            return self.to;
+        }
+
+        let eased = 1.0 - (1.0 - t).powi(3);
+
+        self.from + (self.to - self.from) * eased
+    }
+
+    /// Starts a transition toward the given fraction, from the one
+    /// currently displayed.
+    fn retarget(&mut self, fraction: f32, duration: Duration) {
+        self.from = self.progress(duration);
+        self.to = fraction;
+        self.transition_start = self.now;
+    }
+}
+
+/// Returns the start and the end, as fractions of the total length, of the
+/// bar drawn by an indeterminate [`ProgressBar`] at the given phase.
+fn pulse(phase: f32) -> (f32, f32) {
+    let eased = (1.0 - (phase * PI).cos()) / 2.0;
+    let start = eased * (1.0 + PULSE_LENGTH) - PULSE_LENGTH;
+
+    (start.max(0.0), (start + PULSE_LENGTH).min(1.0))
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ProgressBar<'_, Theme>
@@ -146,6 +275,14 @@ where
     Theme: Catalog,
     Renderer: core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.fraction()))
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width(),
@@ -162,9 +299,42 @@ where
         layout::atomic(limits, self.width(), self.height())
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = *now;
+
+            if self.is_indeterminate {
+                shell.request_redraw();
+                return;
+            }
+
+            let fraction = self.fraction();
+
+            if fraction != state.to {
+                state.retarget(fraction, self.transition_duration);
+            }
+
+            if state.transition(self.transition_duration) < 1.0 {
+                shell.request_redraw();
+            }
+        }
+    }
+
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -172,8 +342,8 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
 
         let length = if self.is_vertical {
             bounds.height
@@ -181,12 +351,15 @@ where
             bounds.width
         };
 
-        let active_progress_length = if range_start >= range_end {
-            0.0
+        let (start, end) = if self.is_indeterminate {
+            pulse(state.phase(self.cycle_duration))
         } else {
-            length * (self.value - range_start) / (range_end - range_start)
+            (0.0, state.progress(self.transition_duration))
         };
 
+        let active_progress_start = length * start;
+        let active_progress_length = length * (end - start);
+
         let style = theme.style(&self.class);
 
         renderer.fill_quad(
@@ -201,12 +374,13 @@ where
         if active_progress_length > 0.0 {
             let bounds = if self.is_vertical {
                 Rectangle {
-                    y: bounds.y + bounds.height - active_progress_length,
+                    y: bounds.y + bounds.height - active_progress_start - active_progress_length,
                     height: active_progress_length,
                     ..bounds
                 }
             } else {
                 Rectangle {
+                    x: bounds.x + active_progress_start,
                     width: active_progress_length,
                     ..bounds
                 }
@@ -324,3 +498,44 @@ fn styled(background: impl Into<Background>, bar: impl Into<Background>) -> Styl
         border: border::rounded(2),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{State, pulse};
+    use crate::core::time::Duration;
+
+    #[test]
+    fn progress_eases_toward_new_values() {
+        let duration = Duration::from_millis(200);
+        let mut state = State::new(0.0);
+
+        state.retarget(1.0, duration);
+        assert_eq!(state.progress(duration), 0.0);
+
+        state.now = state.transition_start + Duration::from_millis(100);
+        let halfway = state.progress(duration);
+        assert!(halfway > 0.5 && halfway < 1.0);
+
+        state.retarget(0.5, duration);
+        assert_eq!(state.progress(duration), halfway);
+
+        state.now += duration;
+        assert_eq!(state.progress(duration), 0.5);
+        assert_eq!(state.progress(Duration::ZERO), 0.5);
+    }
+
+    #[test]
+    fn pulse_stays_within_the_bar() {
+        assert_eq!(pulse(0.0), (0.0, 0.0));
+        let (start, end) = pulse(0.5);
+        assert!((end - start - super::PULSE_LENGTH).abs() < f32::EPSILON * 4.0);
+
+        for step in 0..=100 {
+            let (start, end) = pulse(step as f32 / 100.0);
+
+            assert!((0.0..=1.0).contains(&start));
+            assert!((0.0..=1.0).contains(&end));
+            assert!(start <= end);
+        }
+    }
+}
@@ -56,6 +56,13 @@
 //!     column![a, b, c, all].into()
 //! }
 //! ```
+//!
+//! A [`group`] of radio buttons can be focused as a whole and navigated
+//! with the arrow keys.
+mod group;
+
+pub use group::{Group, group};
+
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::layout;
@@ -0,0 +1,556 @@
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::radio::{Catalog, Status, Style, StyleFn};
+
+/// A set of labeled radio buttons that behaves as a single focusable widget.
+///
+/// Once focused, the arrow keys move the selection between its options.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::radio;
+///
+/// struct State {
+///    size: Option<&'static str>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     SizeSelected(&'static str),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     radio::group(["Small", "Medium", "Large"], state.size, Message::SizeSelected).into()
+/// }
+/// ```
+pub struct Group<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    options: Vec<T>,
+    labels: Vec<String>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    is_horizontal: bool,
+    width: Length,
+    size: f32,
+    spacing: f32,
+    label_spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Group<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default size of the radio buttons of a [`Group`].
+    pub const DEFAULT_SIZE: f32 = 16.0;
+
+    /// The default spacing between the options of a [`Group`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// The default spacing between each radio button of a [`Group`] and its
+    /// label.
+    pub const DEFAULT_LABEL_SPACING: f32 = 8.0;
+
+    /// Creates a new [`Group`] with the given options, the current selected
+    /// option, and the function that produces a `Message` when an option is
+    /// selected.
+    pub fn new(
+        options: impl IntoIterator<Item = T>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let options: Vec<T> = options.into_iter().collect();
+        let labels = options.iter().map(T::to_string).collect();
+
+        let selected =
+            selected.and_then(|selected| options.iter().position(|option| option == &selected));
+
+        Self {
+            options,
+            labels,
+            selected,
+            on_select: Box::new(on_select),
+            is_horizontal: false,
+            width: Length::Shrink,
+            size: Self::DEFAULT_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            label_spacing: Self::DEFAULT_LABEL_SPACING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Lays out the options of the [`Group`] in a row.
+    ///
+    /// By default, they are laid out in a column.
+    pub fn horizontal(mut self) -> Self {
+        self.is_horizontal = true;
+        self
+    }
+
+    /// Sets the width of the [`Group`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the size of the radio buttons of the [`Group`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the options of the [`Group`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the spacing between each radio button of the [`Group`] and its label.
+    pub fn label_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.label_spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Group`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Group`].
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Group`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the text font of the [`Group`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the radio buttons of the [`Group`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the radio buttons of the [`Group`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(option) = self.options.get(index) {
+            shell.publish((self.on_select)(option.clone()));
+        }
+    }
+}
+
+struct State<P: text::Paragraph> {
+    labels: Vec<widget::text::State<P>>,
+    hovered: Option<usize>,
+    is_focused: bool,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Group<'_, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            labels: Vec::new(),
+            hovered: None,
+            is_focused: false,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        state
+            .labels
+            .resize_with(self.labels.len(), widget::text::State::default);
+
+        let limits = limits.width(self.width);
+        let mut position = Point::ORIGIN;
+        let mut size = Size::ZERO;
+
+        let options = state
+            .labels
+            .iter_mut()
+            .zip(&self.labels)
+            .map(|(paragraph, label)| {
+                let node = layout::next_to_each_other(
+                    &limits,
+                    self.label_spacing,
+                    |_| layout::Node::new(Size::new(self.size, self.size)),
+                    |limits| {
+                        widget::text::layout(
+                            paragraph,
+                            renderer,
+                            limits,
+                            label,
+                            widget::text::Format {
+                                width: Length::Shrink,
+                                height: Length::Shrink,
+                                line_height: self.text_line_height,
+                                size: self.text_size,
+                                font: self.font,
+                                align_x: text::Alignment::Default,
+                                align_y: alignment::Vertical::Top,
+                                shaping: self.text_shaping,
+                                wrapping: text::Wrapping::default(),
+                            },
+                        )
+                    },
+                )
+                .move_to(position);
+
+                let bounds = node.bounds();
+
+                if self.is_horizontal {
+                    position.x += bounds.width + self.spacing;
+                    size.width = bounds.x + bounds.width;
+                    size.height = size.height.max(bounds.height);
+                } else {
+                    position.y += bounds.height + self.spacing;
+                    size.width = size.width.max(bounds.width);
+                    size.height = bounds.y + bounds.height;
+                }
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(limits.resolve(self.width, Length::Shrink, size), options)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let hovered = cursor.position().and_then(|position| {
+            layout
+                .children()
+                .position(|option| option.bounds().contains(position))
+        });
+
+        if hovered != state.hovered {
+            state.hovered = hovered;
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = hovered {
+                    if self.selected != Some(index) {
+                        self.select(index, shell);
+                    }
+
+                    state.is_focused = true;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if !cursor.is_over(layout.bounds()) && state.is_focused {
+                    state.is_focused = false;
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+                let target = match key {
+                    Key::Named(key::Named::ArrowUp | key::Named::ArrowLeft) => {
+                        step(self.options.len(), self.selected, false)
+                    }
+                    Key::Named(key::Named::ArrowDown | key::Named::ArrowRight) => {
+                        step(self.options.len(), self.selected, true)
+                    }
+                    Key::Named(key::Named::Home) => step(self.options.len(), None, true),
+                    Key::Named(key::Named::End) => step(self.options.len(), None, false),
+                    Key::Named(key::Named::Space) if self.selected.is_none() => {
+                        step(self.options.len(), None, true)
+                    }
+                    Key::Named(key::Named::Escape) => {
+                        state.is_focused = false;
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                if let Some(target) = target
+                    && Some(target) != self.selected
+                {
+                    self.select(target, shell);
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        for (index, (option, paragraph)) in layout.children().zip(&state.labels).enumerate() {
+            let is_selected = self.selected == Some(index);
+
+            let status = if state.hovered == Some(index) {
+                Status::Hovered { is_selected }
+            } else {
+                Status::Active { is_selected }
+            };
+
+            let style = theme.style(&self.class, status);
+
+            let mut children = option.children();
+            let bounds = children.next().unwrap().bounds();
+            let label_bounds = children.next().unwrap().bounds();
+
+            let dot_size = bounds.width / 2.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: (bounds.width / 2.0).into(),
+                        width: style.border_width,
+                        color: style.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + dot_size / 2.0,
+                            y: bounds.y + dot_size / 2.0,
+                            width: bounds.width - dot_size,
+                            height: bounds.height - dot_size,
+                        },
+                        border: border::rounded(dot_size / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.dot_color,
+                );
+            }
+
+            if state.is_focused && (is_selected || (self.selected.is_none() && index == 0)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds.expand(3.0),
+                        border: Border {
+                            radius: (bounds.width / 2.0 + 3.0).into(),
+                            width: 1.0,
+                            color: style.border_color,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            crate::text::draw(
+                renderer,
+                defaults,
+                label_bounds,
+                paragraph.raw(),
+                crate::text::Style {
+                    color: style.text_color,
+                },
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Group<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        group: Group<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(group)
+    }
+}
+
+/// Creates a new [`Group`] of radio buttons with the given options, the
+/// current selected option, and the function that produces a `Message`
+/// when an option is selected.
+pub fn group<'a, T, Message, Theme, Renderer>(
+    options: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Group<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    Group::new(options, selected, on_select)
+}
+
+/// Returns the option selected by moving forwards or backwards from the
+/// current one, wrapping around the ends of the [`Group`].
+fn step(len: usize, current: Option<usize>, forwards: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    Some(match (current, forwards) {
+        (None, true) => 0,
+        (None, false) => len - 1,
+        (Some(current), true) => (current + 1) % len,
+        (Some(current), false) => (current + len - 1) % len,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step;
+
+    #[test]
+    fn step_wraps_around() {
+        assert_eq!(step(3, None, true), Some(0));
+        assert_eq!(step(3, None, false), Some(2));
+        assert_eq!(step(3, Some(2), true), Some(0));
+        assert_eq!(step(3, Some(0), false), Some(2));
+        assert_eq!(step(3, Some(1), true), Some(2));
+        assert_eq!(step(0, None, true), None);
+    }
+}
@@ -0,0 +1,671 @@
+//! Range sliders let users select a range of values by moving two indicators.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::range_slider;
+//!
+//! struct State {
+//!    price: (f32, f32),
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     PriceChanged((f32, f32)),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     range_slider(0.0..=1000.0, state.price, Message::PriceChanged)
+//!         .step(10.0)
+//!         .min_gap(50.0)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::PriceChanged(price) => {
+//!             state.price = price;
+//!         }
+//!     }
+//! }
+//! ```
+use std::ops::RangeInclusive;
+
+pub use crate::slider::{Catalog, Handle, HandleShape, Status, Style, StyleFn, default};
+
+use crate::core::border::Border;
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// An horizontal bar and two handles that select a range of values.
+///
+/// A [`RangeSlider`] will try to fill the horizontal space of its container.
+///
+/// The handle closest to the cursor is dragged when the [`RangeSlider`] is
+/// pressed. The last handle pressed can then be adjusted with the arrow keys.
+pub struct RangeSlider<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    min_gap: T,
+    values: (T, T),
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current start and end values of the [`RangeSlider`]
+    ///   * a function that will be called when a handle is dragged.
+    ///     It receives the new start and end values of the [`RangeSlider`]
+    ///     and must produce a `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, values: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = (clamp(values.0), clamp(values.1));
+
+        RangeSlider {
+            values: if start <= end {
+                (start, end)
+            } else {
+                (end, start)
+            },
+            range,
+            step: T::from(1),
+            shift_step: None,
+            min_gap: T::from(0),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`RangeSlider`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the minimum gap between the start and end values of the
+    /// [`RangeSlider`].
+    ///
+    /// By default, both handles can select the same value.
+    pub fn min_gap(mut self, min_gap: impl Into<T>) -> Self {
+        self.min_gap = min_gap.into();
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RangeSlider`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> RangeSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+{
+    fn bounds(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    fn step_for(&self, modifiers: keyboard::Modifiers) -> f64 {
+        if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    /// Returns the horizontal offsets of the handles, given the width of the
+    /// rail and of a handle.
+    fn offsets(&self, width: f32, handle_width: f32) -> (f32, f32) {
+        let (start, end) = self.bounds();
+
+        let offset = |value: T| {
+            if start >= end {
+                0.0
+            } else {
+                (width - handle_width) * ((value.into() - start) / (end - start)) as f32
+            }
+        };
+
+        (offset(self.values.0), offset(self.values.1))
+    }
+
+    /// Returns the [`Thumb`] of the handle closest to the given position.
+    fn closest(&self, bounds: Rectangle, position: Point) -> Thumb {
+        let (start, end) = self.offsets(bounds.width, 0.0);
+        let x = position.x - bounds.x;
+
+        if (x - start).abs() < (x - end).abs() || (start == end && x < start) {
+            Thumb::Start
+        } else {
+            Thumb::End
+        }
+    }
+
+    /// Returns the value under the given position, snapped to the step.
+    fn locate(&self, bounds: Rectangle, position: Point, step: f64) -> f64 {
+        let (start, end) = self.bounds();
+
+        if position.x <= bounds.x {
+            start
+        } else if position.x >= bounds.x + bounds.width {
+            end
+        } else {
+            let percent = f64::from(position.x - bounds.x) / f64::from(bounds.width);
+            let steps = (percent * (end - start) / step).round();
+
+            (steps * step + start).min(end)
+        }
+    }
+
+    /// Moves the given [`Thumb`] to the given value, keeping the minimum gap,
+    /// and publishes the new values if they changed.
+    fn change(&mut self, thumb: Thumb, value: f64, shell: &mut Shell<'_, Message>) {
+        let values = (self.values.0.into(), self.values.1.into());
+
+        let (start, end) = constrain(thumb, value, values, self.bounds(), self.min_gap.into());
+
+        if (start - values.0).abs() <= f64::EPSILON && (end - values.1).abs() <= f64::EPSILON {
+            return;
+        }
+
+        let (Some(start), Some(end)) = (T::from_f64(start), T::from_f64(end)) else {
+            return;
+        };
+
+        shell.publish((self.on_change)((start, end)));
+
+        self.values = (start, end);
+    }
+}
+
+/// Moves the [`Thumb`] of the given values to the given value, clamping it to
+/// the range and keeping the given minimum gap with the other one.
+fn constrain(
+    thumb: Thumb,
+    value: f64,
+    (start, end): (f64, f64),
+    (min, max): (f64, f64),
+    gap: f64,
+) -> (f64, f64) {
+    match thumb {
+        Thumb::Start => (value.clamp(min, (end - gap).max(min)).min(end), end),
+        Thumb::End => (start, value.clamp((start + gap).min(max), max).max(start)),
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let step = self.step_for(state.keyboard_modifiers);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let thumb = self.closest(bounds, position);
+                    let value = self.locate(bounds, position, step);
+
+                    self.change(thumb, value, shell);
+
+                    state.dragging = Some(thumb);
+                    state.focused = Some(thumb);
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if state.focused.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.take().is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = state.dragging {
+                    if let Some(position) = cursor.land().position() {
+                        let value = self.locate(bounds, position, step);
+
+                        self.change(thumb, value, shell);
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+
+                let hovered = cursor
+                    .position_over(bounds)
+                    .map(|position| self.closest(bounds, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let Some(thumb) = state.focused else {
+                    return;
+                };
+
+                let value = match thumb {
+                    Thumb::Start => self.values.0,
+                    Thumb::End => self.values.1,
+                }
+                .into();
+
+                let (min, max) = self.bounds();
+
+                let new_value = match key {
+                    Key::Named(key::Named::ArrowUp | key::Named::ArrowRight) => {
+                        step * ((value / step).round() + 1.0)
+                    }
+                    Key::Named(key::Named::ArrowDown | key::Named::ArrowLeft) => {
+                        step * ((value / step).round() - 1.0)
+                    }
+                    Key::Named(key::Named::Home) => min,
+                    Key::Named(key::Named::End) => max,
+                    Key::Named(key::Named::Tab) if !state.keyboard_modifiers.shift() => {
+                        if thumb == Thumb::End {
+                            return;
+                        }
+
+                        state.focused = Some(Thumb::End);
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    Key::Named(key::Named::Tab) => {
+                        if thumb == Thumb::Start {
+                            return;
+                        }
+
+                        state.focused = Some(Thumb::Start);
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    Key::Named(key::Named::Escape) => {
+                        state.focused = None;
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                self.change(thumb, new_value, shell);
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = |thumb| {
+            if state.dragging == Some(thumb) {
+                Status::Dragged
+            } else if state.focused == Some(thumb)
+                || (state.dragging.is_none() && state.hovered == Some(thumb))
+            {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+
+        let rail_status = if state.dragging.is_some() {
+            Status::Dragged
+        } else if state.hovered.is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, rail_status);
+
+        let handle_size = |style: &Style| match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), bounds.height, border_radius),
+        };
+
+        let (handle_width, _, _) = handle_size(&style);
+        let (start, end) = self.offsets(bounds.width, handle_width);
+
+        let rail_y = bounds.y + bounds.height / 2.0;
+
+        let rail = |x: f32, width: f32| Rectangle {
+            x: bounds.x + x,
+            y: rail_y - style.rail.width / 2.0,
+            width,
+            height: style.rail.width,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail(0.0, start + handle_width / 2.0),
+                border: style.rail.border,
+                ..renderer::Quad::default()
+            },
+            style.rail.backgrounds.1,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail(
+                    end + handle_width / 2.0,
+                    bounds.width - end - handle_width / 2.0,
+                ),
+                border: style.rail.border,
+                ..renderer::Quad::default()
+            },
+            style.rail.backgrounds.1,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: rail(start + handle_width / 2.0, end - start),
+                border: style.rail.border,
+                ..renderer::Quad::default()
+            },
+            style.rail.backgrounds.0,
+        );
+
+        let mut handles = [(Thumb::Start, start), (Thumb::End, end)];
+
+        // The focused handle is drawn last, on top of the other one
+        if state.focused == Some(Thumb::Start) {
+            handles.reverse();
+        }
+
+        for (thumb, offset) in handles {
+            let style = theme.style(&self.class, status(thumb));
+            let (handle_width, handle_height, handle_border_radius) = handle_size(&style);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset,
+                        y: rail_y - handle_height / 2.0,
+                        width: handle_width,
+                        height: handle_height,
+                    },
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            // FIXME: Fall back to `Pointer` on Windows
+            // See https://github.com/rust-windowing/winit/issues/1043
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grabbing
+            }
+        } else if cursor.is_over(layout.bounds()) {
+            if cfg!(target_os = "windows") {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Grab
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(range_slider)
+    }
+}
+
+/// A handle of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    focused: Option<Thumb>,
+    hovered: Option<Thumb>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        if self.focused.is_none() {
+            self.focused = Some(Thumb::Start);
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constrain_keeps_min_gap() {
+        let range = (0.0, 100.0);
+
+        assert_eq!(
+            constrain(Thumb::Start, 80.0, (20.0, 60.0), range, 10.0),
+            (50.0, 60.0)
+        );
+        assert_eq!(
+            constrain(Thumb::End, 10.0, (20.0, 60.0), range, 10.0),
+            (20.0, 30.0)
+        );
+        assert_eq!(
+            constrain(Thumb::End, 150.0, (20.0, 60.0), range, 10.0),
+            (20.0, 100.0)
+        );
+        assert_eq!(
+            constrain(Thumb::Start, -5.0, (20.0, 60.0), range, 10.0),
+            (0.0, 60.0)
+        );
+    }
+}
@@ -0,0 +1,734 @@
+//! Reorderable columns distribute content vertically and let the user drag
+//! their items to new positions.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{reorderable_column, text};
+//!
+//! struct State {
+//!     tasks: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Reorder(usize, usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     reorderable_column(state.tasks.iter().map(|task| text(task).into()), Message::Reorder)
+//!         .spacing(10)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Reorder(from, to) => {
+//!             let task = state.tasks.remove(from);
+//!             state.tasks.insert(to, task);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment::{self, Alignment};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+use crate::scrollable::AbsoluteOffset;
+
+/// The distance the cursor needs to travel before an item is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The distance to the edges of the viewport where a drag auto-scrolls.
+const SCROLL_EDGE: f32 = 40.0;
+
+/// The maximum distance auto-scrolled per frame.
+const SCROLL_SPEED: f32 = 12.0;
+
+/// The thickness of the drop indicator.
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+/// A column whose items can be dragged to new positions.
+///
+/// While an item is dragged, a preview of it follows the cursor and an
+/// indicator marks where it will be dropped. Dropping it produces the
+/// message given to [`ReorderableColumn::new`] with the index of the item
+/// and the index it should be moved to—once removed from its old position.
+///
+/// Inside of a [`Scrollable`](crate::Scrollable), dragging close to its
+/// edges can scroll it with [`ReorderableColumn::on_auto_scroll`].
+pub struct ReorderableColumn<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: f32,
+    align: Alignment,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Box<dyn Fn(usize, usize) -> Message + 'a>,
+    on_auto_scroll: Option<Box<dyn Fn(AbsoluteOffset) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ReorderableColumn<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a [`ReorderableColumn`] with the given elements and the
+    /// function that produces a message when an item is dropped in a new
+    /// position.
+    pub fn new(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        let children: Vec<_> = children.into_iter().collect();
+
+        let (width, height) = children.iter().fold(
+            (Length::Shrink, Length::Shrink),
+            |(width, height), child| {
+                let size = child.as_widget().size_hint();
+
+                (width.enclose(size.width), height.enclose(size.height))
+            },
+        );
+
+        Self {
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width,
+            height,
+            max_width: f32::INFINITY,
+            align: Alignment::Start,
+            children,
+            on_reorder: Box::new(on_reorder),
+            on_auto_scroll: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that should be produced with the offset to scroll
+    /// by while an item is dragged close to the edges of the viewport.
+    ///
+    /// It can be used to scroll a parent [`Scrollable`](crate::Scrollable)
+    /// with `scroll_by`.
+    pub fn on_auto_scroll(
+        mut self,
+        on_auto_scroll: impl Fn(AbsoluteOffset) -> Message + 'a,
+    ) -> Self {
+        self.on_auto_scroll = Some(Box::new(on_auto_scroll));
+        self
+    }
+
+    /// Sets the vertical spacing _between_ elements.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ReorderableColumn`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`ReorderableColumn`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ReorderableColumn`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`ReorderableColumn`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the
+    /// [`ReorderableColumn`].
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align = Alignment::from(align.into());
+        self
+    }
+
+    /// Sets the style of the [`ReorderableColumn`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ReorderableColumn`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    /// The position of the cursor relative to the dragged item.
+    grab: Vector,
+    is_dragging: bool,
+}
+
+impl State {
+    fn dragging(&self) -> Option<Drag> {
+        self.drag.filter(|drag| drag.is_dragging)
+    }
+}
+
+/// Returns the index an item should be moved to when dropped at the given
+/// vertical position, given the vertical centers of all the items.
+fn drop_target(centers: impl IntoIterator<Item = f32>, from: usize, y: f32) -> usize {
+    centers
+        .into_iter()
+        .enumerate()
+        .filter(|(index, center)| *index != from && *center < y)
+        .count()
+}
+
+impl<Message, Theme, Renderer> ReorderableColumn<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn drop_target(&self, layout: Layout<'_>, from: usize, y: f32) -> usize {
+        drop_target(
+            layout.children().map(|child| child.bounds().center_y()),
+            from,
+            y,
+        )
+    }
+
+    /// Returns the vertical position of the drop indicator of the given
+    /// target.
+    fn indicator(&self, layout: Layout<'_>, from: usize, to: usize) -> f32 {
+        let mut remaining = layout
+            .children()
+            .enumerate()
+            .filter(|(index, _)| *index != from)
+            .map(|(_, child)| child.bounds());
+
+        match remaining.nth(to) {
+            Some(next) => next.y - self.spacing / 2.0,
+            None => {
+                layout
+                    .children()
+                    .map(|child| child.bounds())
+                    .fold(layout.bounds().y + self.padding.top, |bottom, bounds| {
+                        bottom.max(bounds.y + bounds.height)
+                    })
+                    + self.spacing / 2.0
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ReorderableColumn<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state
+            .drag
+            .is_some_and(|drag| drag.index >= self.children.len())
+        {
+            state.drag = None;
+        }
+
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.max_width(self.max_width);
+
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            &limits,
+            self.width,
+            self.height,
+            self.padding,
+            self.spacing,
+            self.align,
+            &mut self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_dragging = tree.state.downcast_ref::<State>().dragging().is_some();
+
+        // The items do not see the cursor while one of them is dragged
+        let child_cursor = if is_dragging {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree,
+                event,
+                layout,
+                child_cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return;
+                };
+
+                if let Some((index, child)) = layout
+                    .children()
+                    .enumerate()
+                    .find(|(_, child)| child.bounds().contains(position))
+                {
+                    state.drag = Some(Drag {
+                        index,
+                        origin: position,
+                        grab: position - child.bounds().position(),
+                        is_dragging: false,
+                    });
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return;
+                };
+
+                if !drag.is_dragging
+                    && cursor
+                        .position()
+                        .is_some_and(|position| position.distance(drag.origin) > DRAG_THRESHOLD)
+                {
+                    drag.is_dragging = true;
+                }
+
+                if drag.is_dragging {
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                if drag.is_dragging {
+                    if let Some(position) = cursor.position() {
+                        let to = self.drop_target(layout, drag.index, position.y);
+
+                        if to != drag.index {
+                            shell.publish((self.on_reorder)(drag.index, to));
+                        }
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let (Some(on_auto_scroll), Some(_), Some(position)) =
+                    (&self.on_auto_scroll, state.dragging(), cursor.position())
+                else {
+                    return;
+                };
+
+                let bounds = layout.bounds();
+
+                let delta = if position.y < viewport.y + SCROLL_EDGE && bounds.y < viewport.y {
+                    -(viewport.y + SCROLL_EDGE - position.y).min(SCROLL_EDGE)
+                } else if position.y > viewport.y + viewport.height - SCROLL_EDGE
+                    && bounds.y + bounds.height > viewport.y + viewport.height
+                {
+                    (position.y - (viewport.y + viewport.height - SCROLL_EDGE)).min(SCROLL_EDGE)
+                } else {
+                    return;
+                };
+
+                shell.publish(on_auto_scroll(AbsoluteOffset {
+                    x: 0.0,
+                    y: delta / SCROLL_EDGE * SCROLL_SPEED,
+                }));
+
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().dragging().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::None
+            && layout
+                .children()
+                .any(|child| cursor.is_over(child.bounds()))
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let drag = state.dragging();
+
+        if !layout.bounds().intersects(viewport) && drag.is_none() {
+            return;
+        }
+
+        let child_cursor = if drag.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let appearance = theme.style(&self.class);
+
+        for (index, ((child, tree), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(_, (_, layout))| layout.bounds().intersects(viewport))
+        {
+            if drag.is_some_and(|drag| drag.index == index) {
+                if let Some(placeholder) = appearance.placeholder {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: layout.bounds(),
+                            border: appearance.preview_border,
+                            ..renderer::Quad::default()
+                        },
+                        placeholder,
+                    );
+                }
+
+                continue;
+            }
+
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, child_cursor, viewport);
+        }
+
+        let (Some(drag), Some(position)) = (drag, cursor.position()) else {
+            return;
+        };
+
+        let to = self.drop_target(layout, drag.index, position.y);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + self.padding.left,
+                    y: self.indicator(layout, drag.index, to) - INDICATOR_HEIGHT / 2.0,
+                    width: bounds.width - self.padding.x(),
+                    height: INDICATOR_HEIGHT,
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.indicator_color,
+        );
+
+        let (Some(child), Some(tree), Some(layout)) = (
+            self.children.get(drag.index),
+            tree.children.get(drag.index),
+            layout.children().nth(drag.index),
+        ) else {
+            return;
+        };
+
+        let child_bounds = layout.bounds();
+        let translation = position - drag.grab - child_bounds.position();
+
+        // The preview floats over anything else in the viewport
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: child_bounds,
+                        border: appearance.preview_border,
+                        shadow: appearance.preview_shadow,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.preview_background,
+                );
+
+                child.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &(*viewport - translation),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if tree.state.downcast_ref::<State>().dragging().is_some() {
+            return None;
+        }
+
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ReorderableColumn<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(column: ReorderableColumn<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(column)
+    }
+}
+
+/// The appearance of a reorderable column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the indicator marking where a dragged item will be
+    /// dropped.
+    pub indicator_color: Color,
+    /// The [`Background`] of the preview of a dragged item.
+    pub preview_background: Background,
+    /// The [`Border`] of the preview of a dragged item.
+    pub preview_border: Border,
+    /// The [`Shadow`] of the preview of a dragged item.
+    pub preview_shadow: Shadow,
+    /// The [`Background`] filling the original position of a dragged item,
+    /// if any.
+    pub placeholder: Option<Background>,
+}
+
+/// The theme catalog of a [`ReorderableColumn`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ReorderableColumn`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ReorderableColumn`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        indicator_color: palette.primary.base.color,
+        preview_background: palette.background.base.color.into(),
+        preview_border: Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            radius: 4.0.into(),
+        },
+        preview_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+            spread: 0.0,
+        },
+        placeholder: Some(palette.background.weak.color.scale_alpha(0.5).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_target_skips_the_dragged_item() {
+        let centers = [10.0, 30.0, 50.0, 70.0];
+
+        assert_eq!(drop_target(centers, 0, 5.0), 0);
+        assert_eq!(drop_target(centers, 0, 40.0), 1);
+        assert_eq!(drop_target(centers, 3, 40.0), 2);
+        assert_eq!(drop_target(centers, 1, 100.0), 3);
+    }
+}
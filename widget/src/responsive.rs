@@ -1,3 +1,4 @@
+//! Build content that is aware of its dimensions.
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -14,7 +15,7 @@ use crate::space;
 /// A [`Responsive`] widget will always try to fill all the available space of
 /// its parent.
 pub struct Responsive<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
-    view: Box<dyn Fn(Size) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    view: View<'a, Message, Theme, Renderer>,
     width: Length,
     height: Length,
     content: Element<'a, Message, Theme, Renderer>,
@@ -31,8 +32,28 @@ where
     /// the [`Responsive`] during layout. You can use this [`Size`] to
     /// conditionally build the contents.
     pub fn new(view: impl Fn(Size) -> Element<'a, Message, Theme, Renderer> + 'a) -> Self {
-        Self {
+        Self::with_view(View::Size(Box::new(view)))
+    }
+
+    /// Creates a new [`Responsive`] widget with a closure that produces its
+    /// contents for the [`Breakpoint`] reached by its width.
+    ///
+    /// The `view` closure will only be called again when the available
+    /// width crosses one of the given [`Breakpoints`]; not on every resize.
+    pub fn with_breakpoints(
+        breakpoints: Breakpoints,
+        view: impl Fn(Breakpoint) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self::with_view(View::Breakpoint {
             view: Box::new(view),
+            breakpoints,
+            current: None,
+        })
+    }
+
+    fn with_view(view: View<'a, Message, Theme, Renderer>) -> Self {
+        Self {
+            view,
             width: Length::Fill,
             height: Length::Fill,
             content: Element::new(space()),
@@ -52,6 +73,77 @@ where
     }
 }
 
+enum View<'a, Message, Theme, Renderer> {
+    Size(Box<dyn Fn(Size) -> Element<'a, Message, Theme, Renderer> + 'a>),
+    Breakpoint {
+        view: Box<dyn Fn(Breakpoint) -> Element<'a, Message, Theme, Renderer> + 'a>,
+        breakpoints: Breakpoints,
+        current: Option<Breakpoint>,
+    },
+}
+
+/// The size class of a [`Responsive`] widget, given by its available width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than the small breakpoint; like a phone in portrait.
+    ExtraSmall,
+    /// At least as wide as the small breakpoint.
+    Small,
+    /// At least as wide as the medium breakpoint.
+    Medium,
+    /// At least as wide as the large breakpoint.
+    Large,
+    /// At least as wide as the extra large breakpoint.
+    ExtraLarge,
+}
+
+/// The width thresholds of each [`Breakpoint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    /// The minimum width of [`Breakpoint::Small`].
+    pub small: f32,
+    /// The minimum width of [`Breakpoint::Medium`].
+    pub medium: f32,
+    /// The minimum width of [`Breakpoint::Large`].
+    pub large: f32,
+    /// The minimum width of [`Breakpoint::ExtraLarge`].
+    pub extra_large: f32,
+}
+
+impl Breakpoints {
+    /// Creates some [`Breakpoints`] with the given thresholds, in ascending
+    /// order.
+    pub fn new(small: f32, medium: f32, large: f32, extra_large: f32) -> Self {
+        Self {
+            small,
+            medium,
+            large,
+            extra_large,
+        }
+    }
+
+    /// Returns the [`Breakpoint`] reached by the given width.
+    pub fn classify(&self, width: f32) -> Breakpoint {
+        if width >= self.extra_large {
+            Breakpoint::ExtraLarge
+        } else if width >= self.large {
+            Breakpoint::Large
+        } else if width >= self.medium {
+            Breakpoint::Medium
+        } else if width >= self.small {
+            Breakpoint::Small
+        } else {
+            Breakpoint::ExtraSmall
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(576.0, 768.0, 992.0, 1200.0)
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Responsive<'_, Message, Theme, Renderer>
 where
@@ -77,8 +169,26 @@ where
         let limits = limits.width(self.width).height(self.height);
         let size = limits.max();
 
-        self.content = (self.view)(size);
-        tree.diff_children(std::slice::from_ref(&self.content));
+        match &mut self.view {
+            View::Size(view) => {
+                self.content = view(size);
+                tree.diff_children(std::slice::from_ref(&self.content));
+            }
+            View::Breakpoint {
+                view,
+                breakpoints,
+                current,
+            } => {
+                let breakpoint = breakpoints.classify(size.width);
+
+                if *current != Some(breakpoint) {
+                    self.content = view(breakpoint);
+                    tree.diff_children(std::slice::from_ref(&self.content));
+
+                    *current = Some(breakpoint);
+                }
+            }
+        }
 
         let node =
             self.content
@@ -195,3 +305,19 @@ where
         Self::new(responsive)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoint, Breakpoints};
+
+    #[test]
+    fn breakpoints_are_inclusive() {
+        let breakpoints = Breakpoints::default();
+
+        assert_eq!(breakpoints.classify(0.0), Breakpoint::ExtraSmall);
+        assert_eq!(breakpoints.classify(575.9), Breakpoint::ExtraSmall);
+        assert_eq!(breakpoints.classify(576.0), Breakpoint::Small);
+        assert_eq!(breakpoints.classify(991.0), Breakpoint::Medium);
+        assert_eq!(breakpoints.classify(1200.0), Breakpoint::ExtraLarge);
+    }
+}
@@ -0,0 +1,1032 @@
+//! Rich editors let users edit text with bold, italic, colored, linked, and
+//! code spans.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{button, column, rich_editor};
+//!
+//! struct State {
+//!    content: rich_editor::Content,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Edit(rich_editor::Action),
+//!     ToggleBold,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         button("Bold").on_press(Message::ToggleBold),
+//!         rich_editor(&state.content).on_action(Message::Edit),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Edit(action) => {
+//!             state.content.perform(action);
+//!         }
+//!         Message::ToggleBold => {
+//!             let range = state.content.selection_range();
+//!
+//!             state.content.toggle_bold(range);
+//!         }
+//!     }
+//! }
+//! ```
+mod markup;
+
+use crate::core::clipboard::Clipboard;
+use crate::core::font::{self, Font};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, highlighter};
+use crate::core::theme;
+use crate::core::widget::{self, Widget};
+use crate::core::{Color, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size};
+use crate::text_editor::{self, TextEditor};
+
+use std::fmt;
+use std::ops;
+use std::sync::Arc;
+
+pub use text_editor::{Action, Binding, Cursor, Edit, KeyPress, Motion, Position, Status};
+
+/// A multi-line text input with styled spans.
+///
+/// A [`RichEditor`] is a [`TextEditor`] whose [`Content`] keeps the
+/// [`Format`] of its text.
+pub struct RichEditor<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: text_editor::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    editor: TextEditor<'a, Highlighter, Message, Theme, Renderer>,
+    font: Option<Font>,
+}
+
+impl<'a, Message, Theme, Renderer> RichEditor<'a, Message, Theme, Renderer>
+where
+    Theme: text_editor::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    /// Creates a new [`RichEditor`] with the given [`Content`].
+    pub fn new(content: &'a Content<Renderer>) -> Self {
+        Self {
+            editor: TextEditor::new(&content.raw).highlight_with::<Highlighter>(
+                Settings {
+                    font: Font::DEFAULT,
+                    lines: content.lines.clone(),
+                },
+                to_format::<Theme>,
+            ),
+            font: None,
+        }
+    }
+
+    /// Sets the [`Id`](widget::Id) of the [`RichEditor`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.editor = self.editor.id(id);
+        self
+    }
+
+    /// Sets the placeholder of the [`RichEditor`].
+    pub fn placeholder(mut self, placeholder: impl text::IntoFragment<'a>) -> Self {
+        self.editor = self.editor.placeholder(placeholder);
+        self
+    }
+
+    /// Sets the width of the [`RichEditor`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.editor = self.editor.width(width);
+        self
+    }
+
+    /// Sets the height of the [`RichEditor`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.editor = self.editor.height(height);
+        self
+    }
+
+    /// Sets the minimum height of the [`RichEditor`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.editor = self.editor.min_height(min_height);
+        self
+    }
+
+    /// Sets the maximum height of the [`RichEditor`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.editor = self.editor.max_height(max_height);
+        self
+    }
+
+    /// Sets the message that should be produced when some action is performed in
+    /// the [`RichEditor`].
+    ///
+    /// If this method is not called, the [`RichEditor`] will be disabled.
+    pub fn on_action(mut self, on_edit: impl Fn(Action) -> Message + 'a) -> Self {
+        self.editor = self.editor.on_action(on_edit);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`RichEditor`].
+    ///
+    /// Bold, italic, and code spans are variations of it.
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        let font = font.into();
+
+        self.font = Some(font);
+        self.editor = self.editor.font(font);
+        self
+    }
+
+    /// Sets the text size of the [`RichEditor`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.editor = self.editor.size(size);
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`RichEditor`].
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.editor = self.editor.line_height(line_height);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`RichEditor`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.editor = self.editor.padding(padding);
+        self
+    }
+
+    /// Sets the [`text::Wrapping`] strategy of the [`RichEditor`].
+    pub fn wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.editor = self.editor.wrapping(wrapping);
+        self
+    }
+
+    /// Sets the closure to produce key bindings on key presses.
+    ///
+    /// Use [`Binding::Custom`] to map shortcuts, like Ctrl+B, to the
+    /// formatting commands of the [`Content`].
+    pub fn key_binding(
+        mut self,
+        key_binding: impl Fn(KeyPress) -> Option<Binding<Message>> + 'a,
+    ) -> Self {
+        self.editor = self.editor.key_binding(key_binding);
+        self
+    }
+
+    /// Sets the style of the [`RichEditor`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> text_editor::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<text_editor::StyleFn<'a, Theme>>,
+    {
+        self.editor = self.editor.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`RichEditor`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.editor = self.editor.class(class);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RichEditor<'_, Message, Theme, Renderer>
+where
+    Theme: text_editor::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.editor.tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.editor.state()
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.editor)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Formats are variations of the font, which may be the default one
+        self.editor.highlighter_settings_mut().font =
+            self.font.unwrap_or_else(|| renderer.default_font());
+
+        self.editor.layout(tree, renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.editor.update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.editor
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.editor
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.editor.operate(tree, layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RichEditor<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: text_editor::Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(rich_editor: RichEditor<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(rich_editor)
+    }
+}
+
+/// The format of some text in a [`RichEditor`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Format {
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is inline code, drawn with a monospaced font.
+    pub code: bool,
+    /// The [`Color`] of the text, if any.
+    pub color: Option<Color>,
+    /// The URL the text links to, if any.
+    pub link: Option<String>,
+}
+
+/// Some text with a [`Format`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Span {
+    /// The text of the [`Span`].
+    pub text: String,
+    /// The [`Format`] of the [`Span`].
+    pub format: Format,
+}
+
+impl Span {
+    /// Creates a new [`Span`] of plain text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            format: Format::default(),
+        }
+    }
+
+    /// Makes the [`Span`] bold.
+    pub fn bold(mut self) -> Self {
+        self.format.bold = true;
+        self
+    }
+
+    /// Makes the [`Span`] italic.
+    pub fn italic(mut self) -> Self {
+        self.format.italic = true;
+        self
+    }
+
+    /// Makes the [`Span`] inline code.
+    pub fn code(mut self) -> Self {
+        self.format.code = true;
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.format.color = Some(color.into());
+        self
+    }
+
+    /// Makes the [`Span`] link to the given URL.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.format.link = Some(url.into());
+        self
+    }
+}
+
+/// The content of a [`RichEditor`].
+///
+/// Apply the [`Action`]s of the [`RichEditor`] with [`Content::perform`];
+/// the [`Format`] of the text stays in place as it is edited.
+pub struct Content<R = crate::Renderer>
+where
+    R: text::Renderer,
+{
+    raw: text_editor::Content<R>,
+    text: String,
+    runs: Runs,
+    lines: Arc<Vec<Vec<(ops::Range<usize>, Format)>>>,
+    pending: Option<Format>,
+}
+
+impl<R> Content<R>
+where
+    R: text::Renderer,
+{
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_text("")
+    }
+
+    /// Creates a [`Content`] with the given plain text.
+    pub fn with_text(text: &str) -> Self {
+        Self::with_spans([Span::new(text)])
+    }
+
+    /// Creates a [`Content`] with the given [`Span`]s.
+    pub fn with_spans(spans: impl IntoIterator<Item = Span>) -> Self {
+        let mut text = String::new();
+        let mut runs = Runs::default();
+
+        for span in spans {
+            let span_text = span.text.replace("\r\n", "\n").replace('\r', "\n");
+
+            runs.replace(text.len()..text.len(), span_text.len(), span.format);
+            text.push_str(&span_text);
+        }
+
+        let mut content = Self {
+            raw: text_editor::Content::with_text(&text),
+            text,
+            runs,
+            lines: Arc::default(),
+            pending: None,
+        };
+
+        content.sync();
+        content
+    }
+
+    /// Creates a [`Content`] from the given markdown.
+    ///
+    /// Bold, italic, inline code, and links are supported. Every line of
+    /// markdown is a line of the [`Content`].
+    pub fn from_markdown(markdown: &str) -> Self {
+        Self::with_spans(markup::parse_markdown(markdown))
+    }
+
+    /// Creates a [`Content`] from the given HTML.
+    ///
+    /// Only inline formatting is kept; block elements, like paragraphs,
+    /// become lines.
+    pub fn from_html(html: &str) -> Self {
+        Self::with_spans(markup::parse_html(html))
+    }
+
+    /// Performs an [`Action`] on the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        let is_edit = action.is_edit();
+        let is_scroll = matches!(action, Action::Scroll { .. });
+
+        self.raw.perform(action);
+
+        if is_edit {
+            self.sync();
+        } else if !is_scroll {
+            self.pending = None;
+        }
+    }
+
+    /// Returns the current cursor position of the [`Content`].
+    pub fn cursor(&self) -> Cursor {
+        self.raw.cursor()
+    }
+
+    /// Returns the ordered range of the current selection of the
+    /// [`Content`].
+    ///
+    /// It is empty at the cursor when nothing is selected.
+    pub fn selection_range(&self) -> ops::Range<Position> {
+        let cursor = self.raw.cursor();
+        let anchor = cursor.selection.unwrap_or(cursor.position);
+
+        if (anchor.line, anchor.column) <= (cursor.position.line, cursor.position.column) {
+            anchor..cursor.position
+        } else {
+            cursor.position..anchor
+        }
+    }
+
+    /// Returns the selected text of the [`Content`].
+    pub fn selection(&self) -> Option<String> {
+        self.raw.selection()
+    }
+
+    /// Returns the plain text of the [`Content`].
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Returns whether or not the the [`Content`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns the [`Span`]s of the [`Content`].
+    pub fn spans(&self) -> Vec<Span> {
+        self.runs
+            .iter()
+            .map(|(range, format)| Span {
+                text: self.text[range].to_owned(),
+                format: format.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the [`Content`] as markdown.
+    ///
+    /// Colors cannot be represented and are dropped.
+    pub fn to_markdown(&self) -> String {
+        markup::to_markdown(&self.spans())
+    }
+
+    /// Returns the [`Content`] as HTML, with a paragraph per line.
+    pub fn to_html(&self) -> String {
+        markup::to_html(&self.spans())
+    }
+
+    /// Returns the [`Format`] that typing at the cursor produces; useful to
+    /// reflect the state of a toolbar.
+    pub fn format(&self) -> Format {
+        if let Some(format) = &self.pending {
+            return format.clone();
+        }
+
+        let range = self.offsets(self.selection_range());
+
+        if range.is_empty() {
+            self.inherited(range.start)
+        } else {
+            self.runs.at(range.start).cloned().unwrap_or_default()
+        }
+    }
+
+    /// Returns the [`Format`] of the character at the given position, like
+    /// the one clicked.
+    pub fn format_at(&self, position: Position) -> Format {
+        let offset = self.offset(position);
+
+        let format = match self.text[offset..].chars().next() {
+            Some(c) if c != '\n' => self.runs.at(offset),
+            _ => offset
+                .checked_sub(1)
+                .and_then(|offset| self.runs.at(offset)),
+        };
+
+        format.cloned().unwrap_or_default()
+    }
+
+    /// Toggles the bold format of the given range.
+    ///
+    /// An empty range changes the format of the text typed next.
+    pub fn toggle_bold(&mut self, range: ops::Range<Position>) {
+        self.toggle(
+            range,
+            |format| format.bold,
+            |format, bold| format.bold = bold,
+        );
+    }
+
+    /// Toggles the italic format of the given range.
+    ///
+    /// An empty range changes the format of the text typed next.
+    pub fn toggle_italic(&mut self, range: ops::Range<Position>) {
+        self.toggle(
+            range,
+            |format| format.italic,
+            |format, italic| format.italic = italic,
+        );
+    }
+
+    /// Toggles the inline code format of the given range.
+    ///
+    /// An empty range changes the format of the text typed next.
+    pub fn toggle_code(&mut self, range: ops::Range<Position>) {
+        self.toggle(
+            range,
+            |format| format.code,
+            |format, code| format.code = code,
+        );
+    }
+
+    /// Sets the [`Color`] of the given range.
+    ///
+    /// An empty range changes the format of the text typed next.
+    pub fn set_color(&mut self, range: ops::Range<Position>, color: Option<Color>) {
+        self.apply(range, |format| format.color = color);
+    }
+
+    /// Sets the URL the given range links to.
+    ///
+    /// An empty range changes the format of the text typed next.
+    pub fn set_link(&mut self, range: ops::Range<Position>, url: Option<String>) {
+        self.apply(range, |format| format.link.clone_from(&url));
+    }
+
+    /// Removes all the formatting of the given range.
+    pub fn clear_format(&mut self, range: ops::Range<Position>) {
+        self.apply(range, |format| *format = Format::default());
+    }
+
+    fn toggle(
+        &mut self,
+        range: ops::Range<Position>,
+        get: impl Fn(&Format) -> bool,
+        set: impl Fn(&mut Format, bool),
+    ) {
+        let range = self.offsets(range);
+
+        let is_set = if range.is_empty() {
+            get(&self.format())
+        } else {
+            self.runs
+                .iter()
+                .filter(|(run, _)| run.start < range.end && range.start < run.end)
+                .all(|(_, format)| get(format))
+        };
+
+        self.update(range, |format| set(format, !is_set));
+    }
+
+    fn apply(&mut self, range: ops::Range<Position>, f: impl Fn(&mut Format)) {
+        let range = self.offsets(range);
+
+        self.update(range, f);
+    }
+
+    fn update(&mut self, range: ops::Range<usize>, f: impl Fn(&mut Format)) {
+        if range.is_empty() {
+            let mut format = self.format();
+            f(&mut format);
+
+            self.pending = Some(format);
+        } else {
+            self.runs.update(range, f);
+            self.highlight();
+        }
+    }
+
+    /// Keeps the formats in sync with the text of the editor, after it
+    /// has been edited.
+    fn sync(&mut self) {
+        let text = self
+            .raw
+            .lines()
+            .map(|line| line.text)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let prefix = common_prefix(&self.text, &text);
+        let suffix = common_suffix(&self.text[prefix..], &text[prefix..]);
+
+        let removed = prefix..self.text.len() - suffix;
+        let inserted = text.len() - suffix - prefix;
+
+        let format = self
+            .pending
+            .take()
+            .unwrap_or_else(|| self.inherited(prefix));
+
+        self.runs.replace(removed, inserted, format);
+        self.text = text;
+
+        self.highlight();
+    }
+
+    /// Returns the [`Format`] inherited by text inserted at the given offset.
+    fn inherited(&self, offset: usize) -> Format {
+        let after = self.runs.at(offset);
+
+        let before = if offset == 0 || self.text[..offset].ends_with('\n') {
+            None
+        } else {
+            self.runs.at(offset - 1)
+        };
+
+        match before.or(after) {
+            Some(format) => {
+                let mut format = format.clone();
+
+                // Links do not grow past their end
+                if after.is_none_or(|after| after.link != format.link) {
+                    format.link = None;
+                }
+
+                format
+            }
+            None => Format::default(),
+        }
+    }
+
+    /// Splits the formats of the text in lines for the [`Highlighter`].
+    fn highlight(&mut self) {
+        let mut lines = vec![Vec::new()];
+        let mut line_start = 0;
+
+        for (range, format) in self.runs.iter() {
+            let mut start = range.start;
+
+            for (index, _) in self.text[range.clone()].match_indices('\n') {
+                let newline = range.start + index;
+
+                if newline > start && *format != Format::default() {
+                    lines
+                        .last_mut()
+                        .expect("Lines should not be empty")
+                        .push((start - line_start..newline - line_start, format.clone()));
+                }
+
+                lines.push(Vec::new());
+
+                line_start = newline + 1;
+                start = newline + 1;
+            }
+
+            if range.end > start && *format != Format::default() {
+                lines
+                    .last_mut()
+                    .expect("Lines should not be empty")
+                    .push((start - line_start..range.end - line_start, format.clone()));
+            }
+        }
+
+        self.lines = Arc::new(lines);
+    }
+
+    fn offset(&self, position: Position) -> usize {
+        let mut offset = 0;
+
+        for (index, line) in self.text.split('\n').enumerate() {
+            if index == position.line {
+                let mut column = position.column.min(line.len());
+
+                while !line.is_char_boundary(column) {
+                    column -= 1;
+                }
+
+                return offset + column;
+            }
+
+            offset += line.len() + 1;
+        }
+
+        self.text.len()
+    }
+
+    fn offsets(&self, range: ops::Range<Position>) -> ops::Range<usize> {
+        let start = self.offset(range.start);
+        let end = self.offset(range.end);
+
+        start.min(end)..start.max(end)
+    }
+}
+
+impl<R> Default for Content<R>
+where
+    R: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for Content<R>
+where
+    R: text::Renderer,
+{
+    fn clone(&self) -> Self {
+        Self::with_spans(self.spans())
+    }
+}
+
+impl<R> fmt::Debug for Content<R>
+where
+    R: text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Content")
+            .field("text", &self.text)
+            .field("runs", &self.runs)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index)
+}
+
+fn common_suffix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, a), b)| a != b)
+        .map_or(a.len().min(b.len()), |((index, c), _)| {
+            a.len() - index - c.len_utf8()
+        })
+}
+
+/// The formats of some text, as consecutive runs of bytes.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Runs(Vec<(usize, Format)>);
+
+impl Runs {
+    /// Returns the absolute ranges of the runs and their [`Format`].
+    fn iter(&self) -> impl Iterator<Item = (ops::Range<usize>, &Format)> {
+        self.0.iter().scan(0, |start, (length, format)| {
+            let range = *start..*start + length;
+            *start += length;
+
+            Some((range, format))
+        })
+    }
+
+    /// Returns the [`Format`] of the byte at the given offset.
+    fn at(&self, offset: usize) -> Option<&Format> {
+        self.iter()
+            .find(|(range, _)| range.contains(&offset))
+            .map(|(_, format)| format)
+    }
+
+    /// Replaces the given range with a run of the given length and [`Format`].
+    fn replace(&mut self, range: ops::Range<usize>, length: usize, format: Format) {
+        let start = self.split(range.start);
+        let end = self.split(range.end);
+
+        let _ = self
+            .0
+            .splice(start..end, (length > 0).then_some((length, format)));
+
+        self.normalize();
+    }
+
+    /// Updates the [`Format`] of the given range.
+    fn update(&mut self, range: ops::Range<usize>, f: impl Fn(&mut Format)) {
+        let start = self.split(range.start);
+        let end = self.split(range.end);
+
+        for (_, format) in &mut self.0[start..end] {
+            f(format);
+        }
+
+        self.normalize();
+    }
+
+    /// Splits the run at the given offset, returning the index of the run
+    /// starting there.
+    fn split(&mut self, offset: usize) -> usize {
+        let mut start = 0;
+
+        for index in 0..self.0.len() {
+            let length = self.0[index].0;
+
+            if offset == start {
+                return index;
+            }
+
+            if offset < start + length {
+                let format = self.0[index].1.clone();
+
+                self.0[index].0 = offset - start;
+                self.0.insert(index + 1, (start + length - offset, format));
+
+                return index + 1;
+            }
+
+            start += length;
+        }
+
+        self.0.len()
+    }
+
+    fn normalize(&mut self) {
+        self.0.retain(|(length, _)| *length > 0);
+
+        self.0
+            .dedup_by(|(length, format), (previous_length, previous)| {
+                if format == previous {
+                    *previous_length += *length;
+                    true
+                } else {
+                    false
+                }
+            });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Settings {
+    font: Font,
+    lines: Arc<Vec<Vec<(ops::Range<usize>, Format)>>>,
+}
+
+struct Highlighter {
+    settings: Settings,
+    current_line: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Highlight {
+    font: Option<Font>,
+    color: Option<Color>,
+    is_link: bool,
+}
+
+impl text::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(ops::Range<usize>, Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self {
+            settings: settings.clone(),
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        let old = &self.settings.lines;
+        let new = &new_settings.lines;
+
+        let changed = if self.settings.font == new_settings.font {
+            old.iter()
+                .zip(new.iter())
+                .position(|(old, new)| old != new)
+                .unwrap_or(old.len().min(new.len()))
+        } else {
+            0
+        };
+
+        self.change_line(changed);
+        self.settings = new_settings.clone();
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, _line: &str) -> Self::Iterator<'_> {
+        let font = self.settings.font;
+
+        let highlights: Vec<_> = self
+            .settings
+            .lines
+            .get(self.current_line)
+            .map(|formats| {
+                formats
+                    .iter()
+                    .map(|(range, format)| {
+                        let font = (format.bold || format.italic || format.code).then_some(Font {
+                            family: if format.code {
+                                font::Family::Monospace
+                            } else {
+                                font.family
+                            },
+                            weight: if format.bold {
+                                font::Weight::Bold
+                            } else {
+                                font.weight
+                            },
+                            style: if format.italic {
+                                font::Style::Italic
+                            } else {
+                                font.style
+                            },
+                            ..font
+                        });
+
+                        (
+                            range.clone(),
+                            Highlight {
+                                font,
+                                color: format.color,
+                                is_link: format.link.is_some(),
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.current_line += 1;
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+fn to_format<Theme: theme::Base>(
+    highlight: &Highlight,
+    theme: &Theme,
+) -> highlighter::Format<Font> {
+    highlighter::Format {
+        color: highlight.color.or_else(|| {
+            highlight
+                .is_link
+                .then(|| theme.palette().map(|palette| palette.primary))
+                .flatten()
+        }),
+        font: highlight.font,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_follow_edits() {
+        let bold = Format {
+            bold: true,
+            ..Format::default()
+        };
+
+        let mut runs = Runs::default();
+        runs.replace(0..0, 5, Format::default());
+        runs.update(1..3, |format| format.bold = true);
+
+        assert_eq!(
+            runs.0,
+            [
+                (1, Format::default()),
+                (2, bold.clone()),
+                (2, Format::default())
+            ]
+        );
+
+        runs.replace(2..4, 1, bold.clone());
+
+        assert_eq!(
+            runs.0,
+            [(1, Format::default()), (2, bold), (1, Format::default())]
+        );
+
+        assert_eq!(common_prefix("hello", "help"), 3);
+        assert_eq!(common_suffix("lo", "p"), 0);
+        assert_eq!(common_suffix("añb", "ñb"), "ñb".len());
+    }
+}
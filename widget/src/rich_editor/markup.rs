@@ -0,0 +1,579 @@
+//! Convert spans from and to markdown and HTML.
+use crate::core::Color;
+use crate::rich_editor::{Format, Span};
+
+/// Parses the inline formatting of the given markdown.
+pub(super) fn parse_markdown(markdown: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+
+    for (index, line) in markdown.split('\n').enumerate() {
+        if index > 0 {
+            push(&mut spans, "\n", &Format::default());
+        }
+
+        parse_inline(line.trim_end_matches('\r'), &Format::default(), &mut spans);
+    }
+
+    spans
+}
+
+fn parse_inline(text: &str, format: &Format, spans: &mut Vec<Span>) {
+    let mut format = format.clone();
+    let mut buffer = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+
+        if c == '\\'
+            && let Some(escaped) = after.chars().next()
+        {
+            buffer.push(escaped);
+            rest = &after[escaped.len_utf8()..];
+            continue;
+        }
+
+        if c == '`'
+            && let Some(end) = after.find('`')
+        {
+            push(spans, &std::mem::take(&mut buffer), &format);
+            push(
+                spans,
+                &after[..end],
+                &Format {
+                    code: true,
+                    ..format.clone()
+                },
+            );
+
+            rest = &after[end + 1..];
+            continue;
+        }
+
+        if c == '['
+            && let Some((label, url, length)) = link(rest)
+        {
+            push(spans, &std::mem::take(&mut buffer), &format);
+            parse_inline(
+                label,
+                &Format {
+                    link: Some(url.to_owned()),
+                    ..format.clone()
+                },
+                spans,
+            );
+
+            rest = &rest[length..];
+            continue;
+        }
+
+        // Markers only open when they are closed later on
+        if let Some(after) = rest.strip_prefix("**")
+            && (format.bold || after.contains("**"))
+        {
+            push(spans, &std::mem::take(&mut buffer), &format);
+            format.bold = !format.bold;
+
+            rest = after;
+            continue;
+        }
+
+        if c == '*' && (format.italic || after.contains('*')) {
+            push(spans, &std::mem::take(&mut buffer), &format);
+            format.italic = !format.italic;
+
+            rest = after;
+            continue;
+        }
+
+        buffer.push(c);
+        rest = after;
+    }
+
+    push(spans, &buffer, &format);
+}
+
+/// Returns the label, the URL, and the length of the link at the start of
+/// the given text.
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let close = text.find("](")?;
+    let end = close + 2 + text[close + 2..].find(')')?;
+
+    Some((&text[1..close], &text[close + 2..end], end + 1))
+}
+
+/// Writes the given spans as markdown.
+pub(super) fn to_markdown(spans: &[Span]) -> String {
+    let mut markdown = String::new();
+
+    for (index, line) in lines(spans).into_iter().enumerate() {
+        if index > 0 {
+            markdown.push('\n');
+        }
+
+        let mut pieces = line.as_slice();
+
+        while let Some((_, format)) = pieces.first() {
+            let count = pieces
+                .iter()
+                .take_while(|(_, piece)| piece.link == format.link)
+                .count();
+
+            let (group, rest) = pieces.split_at(count);
+
+            match &format.link {
+                Some(url) => {
+                    markdown.push('[');
+                    write_inline(group, &mut markdown);
+                    markdown.push_str("](");
+                    markdown.push_str(url);
+                    markdown.push(')');
+                }
+                None => write_inline(group, &mut markdown),
+            }
+
+            pieces = rest;
+        }
+    }
+
+    markdown
+}
+
+fn write_inline(pieces: &[(&str, &Format)], markdown: &mut String) {
+    let mut merged: Vec<(String, (bool, bool, bool))> = Vec::new();
+
+    // Colors are lost, so neighbours may share their markers
+    for (text, format) in pieces {
+        let style = (format.bold, format.italic, format.code);
+
+        match merged.last_mut() {
+            Some((previous, last)) if *last == style => previous.push_str(text),
+            _ => merged.push(((*text).to_owned(), style)),
+        }
+    }
+
+    for (text, (bold, italic, code)) in merged {
+        let core = text.trim();
+
+        if core.is_empty() {
+            markdown.push_str(&text);
+            continue;
+        }
+
+        // Markers cannot be next to whitespace on their inner side
+        let start = text.len() - text.trim_start().len();
+        let end = start + core.len();
+
+        markdown.push_str(&text[..start]);
+
+        if bold {
+            markdown.push_str("**");
+        }
+
+        if italic {
+            markdown.push('*');
+        }
+
+        if code {
+            markdown.push('`');
+            markdown.push_str(core);
+            markdown.push('`');
+        } else {
+            for c in core.chars() {
+                if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+                    markdown.push('\\');
+                }
+
+                markdown.push(c);
+            }
+        }
+
+        if italic {
+            markdown.push('*');
+        }
+
+        if bold {
+            markdown.push_str("**");
+        }
+
+        markdown.push_str(&text[end..]);
+    }
+}
+
+/// Parses the inline formatting of the given HTML.
+///
+/// Block elements, like paragraphs, turn into lines.
+pub(super) fn parse_html(html: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut stack: Vec<(String, Format)> = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let format = stack
+            .last()
+            .map(|(_, format)| format.clone())
+            .unwrap_or_default();
+
+        let is_line_start = spans
+            .last()
+            .is_none_or(|span: &Span| span.text.ends_with('\n'));
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if let Some(tag) = rest.strip_prefix('<')
+            && let Some(end) = tag.find('>')
+        {
+            rest = &tag[end + 1..];
+
+            let tag = &tag[..end];
+            let is_closing = tag.starts_with('/');
+
+            let tag = tag.trim_start_matches('/');
+            let (name, attributes) = tag
+                .split_once(|c: char| c.is_ascii_whitespace())
+                .unwrap_or((tag, ""));
+
+            let name = name.trim_end_matches('/').to_ascii_lowercase();
+
+            match name.as_str() {
+                "br" => {
+                    push(&mut spans, "\n", &Format::default());
+                }
+                "p" | "div" | "li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "blockquote"
+                | "pre" | "tr" => {
+                    if !is_line_start {
+                        push(&mut spans, "\n", &Format::default());
+                    }
+                }
+                _ if is_closing => {
+                    if let Some(index) = stack.iter().rposition(|(tag, _)| *tag == name) {
+                        stack.truncate(index);
+                    }
+                }
+                "strong" | "b" | "em" | "i" | "code" | "a" | "span" | "font" => {
+                    let mut format = format;
+
+                    match name.as_str() {
+                        "strong" | "b" => format.bold = true,
+                        "em" | "i" => format.italic = true,
+                        "code" => format.code = true,
+                        "a" => format.link = attribute(attributes, "href"),
+                        "span" => {
+                            if let Some(color) = attribute(attributes, "style")
+                                .as_deref()
+                                .and_then(style_color)
+                            {
+                                format.color = Some(color);
+                            }
+                        }
+                        _ => {
+                            if let Some(color) =
+                                attribute(attributes, "color").and_then(|color| color.parse().ok())
+                            {
+                                format.color = Some(color);
+                            }
+                        }
+                    }
+
+                    stack.push((name, format));
+                }
+                _ => {}
+            }
+
+            continue;
+        }
+
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '<')
+            .map_or(rest.len(), |(end, _)| end);
+
+        let mut text = String::new();
+        let mut is_space = is_line_start
+            || spans
+                .last()
+                .is_some_and(|span: &Span| span.text.ends_with(' '));
+
+        // Whitespace collapses, like in a browser
+        for c in decode(&rest[..end]).chars() {
+            if c.is_ascii_whitespace() {
+                if !is_space {
+                    text.push(' ');
+                }
+
+                is_space = true;
+            } else {
+                text.push(c);
+                is_space = false;
+            }
+        }
+
+        push(&mut spans, &text, &format);
+        rest = &rest[end..];
+    }
+
+    if let Some(last) = spans.last_mut()
+        && last.text.ends_with('\n')
+    {
+        let _ = last.text.pop();
+
+        if last.text.is_empty() {
+            let _ = spans.pop();
+        }
+    }
+
+    spans
+}
+
+/// Writes the given spans as HTML, with a paragraph per line.
+pub(super) fn to_html(spans: &[Span]) -> String {
+    let mut html = String::new();
+
+    for (index, line) in lines(spans).into_iter().enumerate() {
+        if index > 0 {
+            html.push('\n');
+        }
+
+        html.push_str("<p>");
+
+        if line.is_empty() {
+            html.push_str("<br>");
+        }
+
+        for (text, format) in line {
+            if let Some(url) = &format.link {
+                html.push_str("<a href=\"");
+                html.push_str(&escape(url));
+                html.push_str("\">");
+            }
+
+            if let Some(color) = format.color {
+                let [r, g, b, _] = color.into_rgba8();
+
+                html.push_str(&format!("<span style=\"color: #{r:02x}{g:02x}{b:02x}\">"));
+            }
+
+            if format.bold {
+                html.push_str("<strong>");
+            }
+
+            if format.italic {
+                html.push_str("<em>");
+            }
+
+            if format.code {
+                html.push_str("<code>");
+            }
+
+            html.push_str(&escape(text));
+
+            if format.code {
+                html.push_str("</code>");
+            }
+
+            if format.italic {
+                html.push_str("</em>");
+            }
+
+            if format.bold {
+                html.push_str("</strong>");
+            }
+
+            if format.color.is_some() {
+                html.push_str("</span>");
+            }
+
+            if format.link.is_some() {
+                html.push_str("</a>");
+            }
+        }
+
+        html.push_str("</p>");
+    }
+
+    html
+}
+
+/// Splits the given spans in lines of non-empty pieces.
+fn lines(spans: &[Span]) -> Vec<Vec<(&str, &Format)>> {
+    let mut lines = vec![Vec::new()];
+
+    for span in spans {
+        for (index, piece) in span.text.split('\n').enumerate() {
+            if index > 0 {
+                lines.push(Vec::new());
+            }
+
+            if !piece.is_empty() {
+                lines
+                    .last_mut()
+                    .expect("Lines should not be empty")
+                    .push((piece, &span.format));
+            }
+        }
+    }
+
+    lines
+}
+
+/// Pushes some text with the given [`Format`], merging it with the last
+/// [`Span`] if they share it.
+fn push(spans: &mut Vec<Span>, text: &str, format: &Format) {
+    if text.is_empty() {
+        return;
+    }
+
+    match spans.last_mut() {
+        Some(last) if last.format == *format => last.text.push_str(text),
+        _ => spans.push(Span {
+            text: text.to_owned(),
+            format: format.clone(),
+        }),
+    }
+}
+
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes;
+
+    while let Some(start) = rest.find('=') {
+        let key = rest[..start]
+            .trim()
+            .rsplit(|c: char| c.is_ascii_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        let value = rest[start + 1..].trim_start();
+
+        let (value, after) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value[1..].find(quote).map_or(value.len(), |end| end + 1);
+
+                (&value[1..end], value.get(end + 1..).unwrap_or_default())
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| c.is_ascii_whitespace())
+                    .unwrap_or(value.len());
+
+                (&value[..end], &value[end..])
+            }
+        };
+
+        if key.eq_ignore_ascii_case(name) {
+            return Some(decode(value));
+        }
+
+        rest = after;
+    }
+
+    None
+}
+
+fn style_color(style: &str) -> Option<Color> {
+    style.split(';').find_map(|declaration| {
+        let (property, value) = declaration.split_once(':')?;
+
+        property
+            .trim()
+            .eq_ignore_ascii_case("color")
+            .then(|| value.trim().parse().ok())
+            .flatten()
+    })
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').filter(|end| *end <= 8).and_then(|end| {
+            let name = &rest[1..=end];
+
+            let c = match name {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" | "#39" => '\'',
+                "nbsp" => '\u{a0}',
+                _ => {
+                    let number = name.strip_prefix('#')?;
+
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+
+                    char::from_u32(code)?
+                }
+            };
+
+            Some((c, end + 2))
+        });
+
+        match entity {
+            Some((c, length)) => {
+                decoded.push(c);
+                rest = &rest[length..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_and_html_round_trip() {
+        let markdown =
+            "Some **bold**, *italic*, `code`, and [a link](https://iced.rs)\n\n***both*** \\*";
+        let spans = parse_markdown(markdown);
+
+        assert_eq!(spans[1], Span::new("bold").bold());
+        assert_eq!(to_markdown(&spans), markdown);
+        assert_eq!(parse_html(&to_html(&spans)), spans);
+
+        assert_eq!(
+            parse_html(
+                "<p>a &amp;  <b>b</b></p><div><span style=\"color: #ff0000\">c</span></div>"
+            ),
+            [
+                Span::new("a & "),
+                Span::new("b").bold(),
+                Span::new("\n"),
+                Span::new("c").color(Color::from_rgb8(255, 0, 0)),
+            ]
+        );
+    }
+}
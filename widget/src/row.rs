@@ -38,7 +38,7 @@ pub struct Row<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     height: Length,
     align: Alignment,
     clip: bool,
-    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    pub(crate) children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
 impl<'a, Message, Theme, Renderer> Row<'a, Message, Theme, Renderer>
@@ -154,6 +154,8 @@ where
             row: self,
             vertical_spacing: None,
             align_x: alignment::Horizontal::Left,
+            item_width: None,
+            item_height: None,
         }
     }
 }
@@ -352,6 +354,8 @@ pub struct Wrapping<'a, Message, Theme = crate::Theme, Renderer = crate::Rendere
     row: Row<'a, Message, Theme, Renderer>,
     vertical_spacing: Option<f32>,
     align_x: alignment::Horizontal,
+    item_width: Option<f32>,
+    item_height: Option<f32>,
 }
 
 impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
@@ -366,6 +370,26 @@ impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
         self.align_x = align_x.into();
         self
     }
+
+    /// Sets a uniform width for every item of the wrapping [`Row`].
+    pub fn item_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.item_width = Some(width.into().0);
+        self
+    }
+
+    /// Sets a uniform height for every item of the wrapping [`Row`].
+    pub fn item_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.item_height = Some(height.into().0);
+        self
+    }
+
+    /// Sets a uniform [`Size`] for every item of the wrapping [`Row`];
+    /// useful for galleries of thumbnails.
+    pub fn item_size(self, size: impl Into<Size>) -> Self {
+        let size = size.into();
+
+        self.item_width(size.width).item_height(size.height)
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -396,12 +420,28 @@ where
             .height(self.row.height)
             .shrink(self.row.padding);
 
-        let child_limits = limits.loose();
+        let child_limits = {
+            let limits = limits.loose();
+            let max = limits.max();
+
+            layout::Limits::new(
+                Size::new(
+                    self.item_width.unwrap_or(0.0),
+                    self.item_height.unwrap_or(0.0),
+                ),
+                Size::new(
+                    self.item_width.unwrap_or(max.width),
+                    self.item_height.unwrap_or(max.height),
+                ),
+            )
+        };
+
         let spacing = self.row.spacing;
         let vertical_spacing = self.vertical_spacing.unwrap_or(spacing);
         let max_width = limits.max().width;
 
         let mut children: Vec<layout::Node> = Vec::new();
+        let mut lines: Vec<(std::ops::Range<usize>, f32)> = Vec::new();
         let mut intrinsic_size = Size::ZERO;
         let mut row_start = 0;
         let mut row_height = 0.0;
@@ -431,10 +471,18 @@ where
                 .as_widget_mut()
                 .layout(&mut tree.children[i], renderer, &child_limits);
 
-            let child_size = node.size();
+            let child_size = {
+                let size = node.size();
+
+                Size::new(
+                    self.item_width.unwrap_or(size.width),
+                    self.item_height.unwrap_or(size.height),
+                )
+            };
 
             if x != 0.0 && x + child_size.width > max_width {
                 intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+                lines.push((row_start..i, x - spacing));
 
                 align_y(row_start..i, row_height, &mut children);
 
@@ -453,6 +501,7 @@ where
 
         if x != 0.0 {
             intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+            lines.push((row_start..children.len(), x - spacing));
         }
 
         intrinsic_size.height = y + row_height;
@@ -467,25 +516,11 @@ where
         if align_factor != 0.0 {
             let total_width = intrinsic_size.width;
 
-            let mut row_start = 0;
-
-            for i in 0..children.len() {
-                let bounds = children[i].bounds();
-                let row_width = bounds.x + bounds.width;
-
-                let next_x = children
-                    .get(i + 1)
-                    .map(|node| node.bounds().x)
-                    .unwrap_or_default();
-
-                if next_x == 0.0 {
-                    let translation = Vector::new((total_width - row_width) / align_factor, 0.0);
-
-                    for node in &mut children[row_start..=i] {
-                        node.translate_mut(translation);
-                    }
+            for (line, line_width) in lines {
+                let translation = Vector::new((total_width - line_width) / align_factor, 0.0);
 
-                    row_start = i + 1;
+                for node in &mut children[line] {
+                    node.translate_mut(translation);
                 }
             }
         }
@@ -73,8 +73,11 @@ where
     height: Length,
     direction: Direction,
     auto_scroll: bool,
+    physics: Physics,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    on_near_start: Option<Edge<'a, Message>>,
+    on_near_end: Option<Edge<'a, Message>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
 }
@@ -100,8 +103,11 @@ where
             height: Length::Shrink,
             direction: direction.into(),
             auto_scroll: false,
+            physics: Physics::default(),
             content: content.into(),
             on_scroll: None,
+            on_near_start: None,
+            on_near_end: None,
             class: Theme::default(),
             last_status: None,
         }
@@ -159,6 +165,33 @@ where
         self
     }
 
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled within the given `threshold` of the start of its content.
+    ///
+    /// The message is produced once every time the threshold is crossed, or
+    /// when the size of the content changes while inside of it.
+    pub fn on_near_start(mut self, threshold: impl Into<Pixels>, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_near_start = Some(Edge::new(threshold, message));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled within the given `threshold` of the end of its content.
+    ///
+    /// The message is produced once every time the threshold is crossed, or
+    /// when the size of the content changes while inside of it. This makes it
+    /// easy to load more items in a paginated feed.
+    pub fn on_near_end(mut self, threshold: impl Into<Pixels>, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_near_end = Some(Edge::new(threshold, message));
+        self
+    }
+
     /// Anchors the vertical [`Scrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -228,6 +261,14 @@ where
         self
     }
 
+    /// Sets the [`Physics`] of the [`Scrollable`].
+    ///
+    /// By default, the [`Physics`] of the current platform are used.
+    pub fn physics(mut self, physics: Physics) -> Self {
+        self.physics = physics;
+        self
+    }
+
     /// Sets the style of this [`Scrollable`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -385,6 +426,94 @@ pub enum Anchor {
     End,
 }
 
+impl Anchor {
+    fn offset(self, translation: f32, max: f32) -> f32 {
+        match self {
+            Anchor::Start => translation,
+            Anchor::End => max - translation,
+        }
+    }
+}
+
+/// The scrolling physics of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Physics {
+    wheel_speed: f32,
+    friction: f32,
+    is_kinetic: bool,
+    overscroll: Overscroll,
+    snap: bool,
+}
+
+impl Default for Physics {
+    fn default() -> Self {
+        let is_apple = cfg!(any(target_os = "macos", target_os = "ios"));
+
+        Self {
+            wheel_speed: 1.0,
+            friction: if is_apple { 2.0 } else { 3.0 },
+            is_kinetic: true,
+            overscroll: if is_apple {
+                Overscroll::Bounce
+            } else {
+                Overscroll::Clamp
+            },
+            snap: false,
+        }
+    }
+}
+
+impl Physics {
+    /// Creates new [`Physics`] with the defaults of the current platform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the multiplier applied to the movement of the mouse wheel.
+    pub fn wheel_speed(mut self, wheel_speed: f32) -> Self {
+        self.wheel_speed = wheel_speed.max(0.0);
+        self
+    }
+
+    /// Sets the friction slowing down a touch fling.
+    ///
+    /// Higher values stop the fling sooner.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.friction = friction.max(0.0);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should keep scrolling after a touch
+    /// fling, until friction stops it.
+    pub fn kinetic(mut self, is_kinetic: bool) -> Self {
+        self.is_kinetic = is_kinetic;
+        self
+    }
+
+    /// Sets the [`Overscroll`] behavior of the [`Scrollable`].
+    pub fn overscroll(mut self, overscroll: Overscroll) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should settle on the start of the
+    /// nearest child of its content once scrolling stops.
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+}
+
+/// The behavior of a [`Scrollable`] when touch scrolling goes past the
+/// edges of its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overscroll {
+    /// The content stops at its edges.
+    Clamp,
+    /// The content stretches past its edges and bounces back.
+    Bounce,
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Scrollable<'_, Message, Theme, Renderer>
 where
@@ -592,6 +721,17 @@ where
             }
         }
 
+        if cursor_over_scrollable.is_some()
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::WheelScrolled { .. })
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+        {
+            state.animation = None;
+            state.kinetic.stop();
+        }
+
         let mut update = || {
             if let Some(scroller_grabbed_at) = state.y_scroller_grabbed_at() {
                 match event {
@@ -778,6 +918,11 @@ where
                         touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }
                     )
             ) {
+                if matches!(state.interaction, Interaction::TouchScrolling(_)) {
+                    state.kinetic.release(&self.physics);
+                    shell.request_redraw();
+                }
+
                 state.interaction = Interaction::None;
                 return;
             }
@@ -809,10 +954,11 @@ where
                                 Vector::new(y, x)
                             };
 
-                            // TODO: Configurable speed/friction (?)
-                            -movement * 60.0
+                            -movement * 60.0 * self.physics.wheel_speed
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            -Vector::new(x, y) * self.physics.wheel_speed
                         }
-                        mouse::ScrollDelta::Pixels { x, y } => -Vector::new(x, y),
                     };
 
                     state.scroll(self.direction.align(delta), bounds, content_bounds);
@@ -820,6 +966,13 @@ where
                     let has_scrolled =
                         notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
 
+                    if has_scrolled && self.physics.snap {
+                        let snap_at = Instant::now() + SNAP_DELAY;
+
+                        state.kinetic.snap_at = Some(snap_at);
+                        shell.request_redraw_at(snap_at);
+                    }
+
                     let in_transaction = state.last_scrolled.is_some();
 
                     if has_scrolled || in_transaction {
@@ -854,6 +1007,8 @@ where
                             };
 
                             state.interaction = Interaction::TouchScrolling(position);
+                            state.kinetic.stop();
+                            state.kinetic.last_moved = Some(Instant::now());
                         }
                         touch::Event::FingerMoved { .. } => {
                             let Interaction::TouchScrolling(scroll_box_touched_at) =
@@ -871,7 +1026,21 @@ where
                                 scroll_box_touched_at.y - cursor_position.y,
                             );
 
-                            state.scroll(self.direction.align(delta), bounds, content_bounds);
+                            state.kinetic.track(delta, Instant::now());
+
+                            match self.physics.overscroll {
+                                Overscroll::Clamp => {
+                                    state.scroll(
+                                        self.direction.align(delta),
+                                        bounds,
+                                        content_bounds,
+                                    );
+                                }
+                                Overscroll::Bounce => {
+                                    state.stretch(self.direction, delta, bounds, content_bounds);
+                                    shell.request_redraw();
+                                }
+                            }
 
                             state.interaction = Interaction::TouchScrolling(cursor_position);
 
@@ -914,6 +1083,49 @@ where
                     state.keyboard_modifiers = *modifiers;
                 }
                 Event::Window(window::Event::RedrawRequested(now)) => {
+                    if state.animation.is_some() {
+                        if state.animate(self.direction, bounds, content_bounds, *now) {
+                            shell.request_redraw();
+                        }
+
+                        let _ =
+                            notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
+
+                        return;
+                    }
+
+                    if state.kinetic.is_moving() {
+                        if state.glide(self.direction, bounds, content_bounds, &self.physics, *now)
+                        {
+                            shell.request_redraw();
+                        } else if self.physics.snap && !state.kinetic.is_moving() {
+                            state.kinetic.snap_at = Some(*now);
+                            shell.request_redraw();
+                        }
+
+                        let _ =
+                            notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
+
+                        return;
+                    }
+
+                    if let Some(snap_at) = state.kinetic.snap_at
+                        && !matches!(state.interaction, Interaction::TouchScrolling(_))
+                    {
+                        if *now >= snap_at {
+                            state.kinetic.snap_at = None;
+                            state.snap(
+                                self.direction,
+                                bounds,
+                                content_bounds,
+                                content.children().map(|child| child.bounds()),
+                            );
+                            shell.request_redraw();
+                        } else {
+                            shell.request_redraw_at(snap_at);
+                        }
+                    }
+
                     if let Interaction::AutoScrolling {
                         origin,
                         current,
@@ -993,6 +1205,16 @@ where
 
         update();
 
+        notify_edges(
+            state,
+            self.direction,
+            self.on_near_start.as_ref(),
+            self.on_near_end.as_ref(),
+            bounds,
+            content_bounds,
+            shell,
+        );
+
         let status = if state.scrollers_grabbed() {
             Status::Dragged {
                 is_horizontal_scrollbar_dragged: state.x_scroller_grabbed_at().is_some(),
@@ -1499,6 +1721,71 @@ fn notify_viewport<Message>(
     true
 }
 
+fn notify_edges<Message>(
+    state: &mut State,
+    direction: Direction,
+    on_near_start: Option<&Edge<'_, Message>>,
+    on_near_end: Option<&Edge<'_, Message>>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    if on_near_start.is_none() && on_near_end.is_none() {
+        return;
+    }
+
+    let translation = state.translation(direction, bounds, content_bounds);
+
+    let (offset, viewport, content) = if direction.vertical().is_some() {
+        (translation.y, bounds.height, content_bounds.height)
+    } else {
+        (translation.x, bounds.width, content_bounds.width)
+    };
+
+    let remaining = (content - viewport).max(0.0) - offset;
+
+    state.near_start = notify_edge(on_near_start, offset, content, state.near_start, shell);
+    state.near_end = notify_edge(on_near_end, remaining, content, state.near_end, shell);
+}
+
+fn notify_edge<Message>(
+    edge: Option<&Edge<'_, Message>>,
+    distance: f32,
+    content: f32,
+    last_notified: Option<f32>,
+    shell: &mut Shell<'_, Message>,
+) -> Option<f32> {
+    let edge = edge?;
+
+    if distance > edge.threshold {
+        return None;
+    }
+
+    if last_notified != Some(content) {
+        shell.publish((edge.message)());
+    }
+
+    Some(content)
+}
+
+/// A message produced when scrolling close to an edge of the content.
+struct Edge<'a, Message> {
+    threshold: f32,
+    message: Box<dyn Fn() -> Message + 'a>,
+}
+
+impl<'a, Message> Edge<'a, Message> {
+    fn new(threshold: impl Into<Pixels>, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        Self {
+            threshold: threshold.into().0,
+            message: Box::new(move || message.clone()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct State {
     offset_y: Offset,
@@ -1508,6 +1795,79 @@ struct State {
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
     is_scrollbar_visible: bool,
+    animation: Option<Animation>,
+    kinetic: Kinetic,
+    near_start: Option<f32>,
+    near_end: Option<f32>,
+}
+
+/// The time to wait after the last wheel movement before snapping.
+const SNAP_DELAY: Duration = Duration::from_millis(150);
+
+/// The speed, in pixels per second, under which a fling stops.
+const MIN_VELOCITY: f32 = 20.0;
+
+/// The kinetic motion of a [`Scrollable`] driven by touch input.
+#[derive(Debug, Clone, Copy, Default)]
+struct Kinetic {
+    velocity: Vector,
+    overscroll: Vector,
+    is_flinging: bool,
+    last_moved: Option<Instant>,
+    last_frame: Option<Instant>,
+    snap_at: Option<Instant>,
+}
+
+impl Kinetic {
+    fn is_moving(&self) -> bool {
+        self.is_flinging || self.overscroll != Vector::ZERO
+    }
+
+    fn stop(&mut self) {
+        self.velocity = Vector::ZERO;
+        self.is_flinging = false;
+        self.snap_at = None;
+    }
+
+    fn track(&mut self, delta: Vector, now: Instant) {
+        if let Some(last_moved) = self.last_moved {
+            let elapsed = now.saturating_duration_since(last_moved).as_secs_f32();
+
+            if elapsed > 0.0 {
+                self.velocity = delta * (0.8 / elapsed) + self.velocity * 0.2;
+            }
+        }
+
+        self.last_moved = Some(now);
+    }
+
+    fn release(&mut self, physics: &Physics) {
+        let is_recent = self
+            .last_moved
+            .is_some_and(|last_moved| last_moved.elapsed() < Duration::from_millis(50));
+
+        self.is_flinging = physics.is_kinetic
+            && is_recent
+            && self.velocity.x.hypot(self.velocity.y) > MIN_VELOCITY;
+
+        self.last_moved = None;
+        self.last_frame = None;
+
+        if !self.is_flinging {
+            self.velocity = Vector::ZERO;
+
+            if physics.snap && self.overscroll == Vector::ZERO {
+                self.snap_at = Some(Instant::now());
+            }
+        }
+    }
+}
+
+/// A smooth scroll towards some translation, started by an operation.
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    to: AbsoluteOffset<Option<f32>>,
+    from: Option<(Vector, Instant)>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1533,22 +1893,78 @@ impl Default for State {
             last_notified: None,
             last_scrolled: None,
             is_scrollbar_visible: true,
+            animation: None,
+            kinetic: Kinetic::default(),
+            near_start: None,
+            near_end: None,
         }
     }
 }
 
 impl operation::Scrollable for State {
     fn snap_to(&mut self, offset: RelativeOffset<Option<f32>>) {
+        self.animation = None;
         State::snap_to(self, offset);
     }
 
     fn scroll_to(&mut self, offset: AbsoluteOffset<Option<f32>>) {
+        self.animation = None;
         State::scroll_to(self, offset);
     }
 
     fn scroll_by(&mut self, offset: AbsoluteOffset, bounds: Rectangle, content_bounds: Rectangle) {
+        self.animation = None;
         State::scroll_by(self, offset, bounds, content_bounds);
     }
+
+    fn animate_to(&mut self, translation: AbsoluteOffset<Option<f32>>) {
+        self.kinetic = Kinetic::default();
+        self.animation = Some(Animation {
+            to: translation,
+            from: None,
+        });
+    }
+}
+
+/// Applies a scroll `delta` to the given `translation` along a single axis,
+/// turning any movement past the edges of the content into overscroll.
+///
+/// Returns the new translation and overscroll.
+fn stretch(translation: f32, overscroll: f32, delta: f32, max: f32) -> (f32, f32) {
+    const RESISTANCE: f32 = 0.5;
+
+    if overscroll != 0.0 {
+        let pulled = if delta * overscroll > 0.0 {
+            overscroll + delta * RESISTANCE
+        } else {
+            overscroll + delta
+        };
+
+        if pulled * overscroll > 0.0 {
+            return (translation, pulled);
+        }
+
+        return stretch(translation, 0.0, pulled, max);
+    }
+
+    let target = translation + delta;
+
+    if target < 0.0 {
+        (0.0, target * RESISTANCE)
+    } else if target > max {
+        (max, (target - max) * RESISTANCE)
+    } else {
+        (target, 0.0)
+    }
+}
+
+/// Returns the start closest to the `current` translation, if it is at
+/// least a pixel away.
+fn nearest(current: f32, starts: impl Iterator<Item = f32>, max: f32) -> Option<f32> {
+    starts
+        .map(|start| start.clamp(0.0, max))
+        .min_by(|a, b| (a - current).abs().total_cmp(&(b - current).abs()))
+        .filter(|start| (start - current).abs() >= 1.0)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1688,6 +2104,222 @@ impl State {
         self.scroll(Vector::new(offset.x, offset.y), bounds, content_bounds);
     }
 
+    /// Advances the current [`Animation`], if any, and returns whether it is
+    /// still running.
+    fn animate(
+        &mut self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        now: Instant,
+    ) -> bool {
+        const DURATION: Duration = Duration::from_millis(250);
+
+        let Some(mut animation) = self.animation else {
+            return false;
+        };
+
+        let (from, start) = *animation
+            .from
+            .get_or_insert((self.translation(direction, bounds, content_bounds), now));
+
+        let progress =
+            (now.saturating_duration_since(start).as_secs_f32() / DURATION.as_secs_f32()).min(1.0);
+
+        let eased = 1.0 - (1.0 - progress).powi(3);
+
+        if let (Some(horizontal), Some(x)) = (direction.horizontal(), animation.to.x) {
+            let max = (content_bounds.width - bounds.width).max(0.0);
+            let translation = (from.x + (x - from.x) * eased).clamp(0.0, max);
+
+            self.offset_x = Offset::Absolute(horizontal.alignment.offset(translation, max));
+        }
+
+        if let (Some(vertical), Some(y)) = (direction.vertical(), animation.to.y) {
+            let max = (content_bounds.height - bounds.height).max(0.0);
+            let translation = (from.y + (y - from.y) * eased).clamp(0.0, max);
+
+            self.offset_y = Offset::Absolute(vertical.alignment.offset(translation, max));
+        }
+
+        self.animation = (progress < 1.0).then_some(animation);
+        self.animation.is_some()
+    }
+
+    /// Scrolls by the given translation `delta`, stretching the content past
+    /// its edges instead of stopping at them.
+    fn stretch(
+        &mut self,
+        direction: Direction,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if let Some(horizontal) = direction.horizontal() {
+            let max = (content_bounds.width - bounds.width).max(0.0);
+
+            if max > 0.0 {
+                let (translation, overscroll) = stretch(
+                    self.offset_x.translation(
+                        bounds.width,
+                        content_bounds.width,
+                        horizontal.alignment,
+                    ),
+                    self.kinetic.overscroll.x,
+                    delta.x,
+                    max,
+                );
+
+                self.offset_x = Offset::Absolute(horizontal.alignment.offset(translation, max));
+                self.kinetic.overscroll.x = overscroll;
+            }
+        }
+
+        if let Some(vertical) = direction.vertical() {
+            let max = (content_bounds.height - bounds.height).max(0.0);
+
+            if max > 0.0 {
+                let (translation, overscroll) = stretch(
+                    self.offset_y.translation(
+                        bounds.height,
+                        content_bounds.height,
+                        vertical.alignment,
+                    ),
+                    self.kinetic.overscroll.y,
+                    delta.y,
+                    max,
+                );
+
+                self.offset_y = Offset::Absolute(vertical.alignment.offset(translation, max));
+                self.kinetic.overscroll.y = overscroll;
+            }
+        }
+    }
+
+    /// Advances the current fling and settles any overscroll, returning
+    /// whether the content is still in motion.
+    fn glide(
+        &mut self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        physics: &Physics,
+        now: Instant,
+    ) -> bool {
+        const SPRING: f32 = 12.0;
+
+        if matches!(self.interaction, Interaction::TouchScrolling(_)) {
+            return false;
+        }
+
+        let elapsed = self.kinetic.last_frame.map_or(0.0, |last_frame| {
+            now.saturating_duration_since(last_frame).as_secs_f32()
+        });
+
+        self.kinetic.last_frame = Some(now);
+
+        if self.kinetic.is_flinging {
+            let velocity = self.kinetic.velocity;
+            let delta = velocity * elapsed;
+            let last_offsets = (self.offset_x, self.offset_y);
+
+            match physics.overscroll {
+                Overscroll::Clamp => {
+                    self.scroll(direction.align(delta), bounds, content_bounds);
+                }
+                Overscroll::Bounce => {
+                    self.stretch(direction, delta, bounds, content_bounds);
+                }
+            }
+
+            let friction = (-physics.friction * elapsed).exp();
+            let brake = (-SPRING * 2.0 * elapsed).exp();
+
+            let decay = |velocity: f32, overscroll: f32, is_stuck: bool| {
+                if is_stuck {
+                    0.0
+                } else if overscroll != 0.0 {
+                    velocity * brake
+                } else {
+                    velocity * friction
+                }
+            };
+
+            let is_clamped = physics.overscroll == Overscroll::Clamp && elapsed > 0.0;
+
+            self.kinetic.velocity = Vector::new(
+                decay(
+                    velocity.x,
+                    self.kinetic.overscroll.x,
+                    is_clamped && last_offsets.0 == self.offset_x,
+                ),
+                decay(
+                    velocity.y,
+                    self.kinetic.overscroll.y,
+                    is_clamped && last_offsets.1 == self.offset_y,
+                ),
+            );
+
+            if self.kinetic.velocity.x.hypot(self.kinetic.velocity.y) < MIN_VELOCITY {
+                self.kinetic.velocity = Vector::ZERO;
+                self.kinetic.is_flinging = false;
+            }
+        } else {
+            let settle = |overscroll: f32| {
+                let overscroll = overscroll * (-SPRING * elapsed).exp();
+
+                if overscroll.abs() < 0.5 {
+                    0.0
+                } else {
+                    overscroll
+                }
+            };
+
+            self.kinetic.overscroll = Vector::new(
+                settle(self.kinetic.overscroll.x),
+                settle(self.kinetic.overscroll.y),
+            );
+        }
+
+        self.kinetic.is_moving()
+    }
+
+    /// Starts animating towards the start of the child closest to the
+    /// current translation.
+    fn snap(
+        &mut self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        children: impl Iterator<Item = Rectangle>,
+    ) {
+        let children: Vec<_> = children.collect();
+        let translation = self.translation(direction, bounds, content_bounds);
+
+        let x = direction.horizontal().and_then(|_| {
+            nearest(
+                translation.x,
+                children.iter().map(|child| child.x - content_bounds.x),
+                (content_bounds.width - bounds.width).max(0.0),
+            )
+        });
+
+        let y = direction.vertical().and_then(|_| {
+            nearest(
+                translation.y,
+                children.iter().map(|child| child.y - content_bounds.y),
+                (content_bounds.height - bounds.height).max(0.0),
+            )
+        });
+
+        if x.is_some() || y.is_some() {
+            self.animation = Some(Animation {
+                to: AbsoluteOffset { x, y },
+                from: None,
+            });
+        }
+    }
+
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
@@ -1707,15 +2339,21 @@ impl State {
     ) -> Vector {
         Vector::new(
             if let Some(horizontal) = direction.horizontal() {
-                self.offset_x
-                    .translation(bounds.width, content_bounds.width, horizontal.alignment)
+                (self.offset_x.translation(
+                    bounds.width,
+                    content_bounds.width,
+                    horizontal.alignment,
+                ) + self.kinetic.overscroll.x)
                     .round()
             } else {
                 0.0
             },
             if let Some(vertical) = direction.vertical() {
-                self.offset_y
-                    .translation(bounds.height, content_bounds.height, vertical.alignment)
+                (self.offset_y.translation(
+                    bounds.height,
+                    content_bounds.height,
+                    vertical.alignment,
+                ) + self.kinetic.overscroll.y)
                     .round()
             } else {
                 0.0
@@ -2159,6 +2797,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             color: Color::BLACK.scale_alpha(0.7),
             offset: Vector::ZERO,
             blur_radius: 2.0,
+            spread: 0.0,
         },
         icon: palette.background.base.text.scale_alpha(0.8),
     };
@@ -2231,3 +2870,27 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{nearest, stretch};
+
+    #[test]
+    fn overscroll_stretches_and_settles_back() {
+        assert_eq!(stretch(50.0, 0.0, 20.0, 100.0), (70.0, 0.0));
+        assert_eq!(stretch(0.0, 0.0, -20.0, 100.0), (0.0, -10.0));
+        assert_eq!(stretch(0.0, -10.0, -10.0, 100.0), (0.0, -15.0));
+        assert_eq!(stretch(0.0, -10.0, 15.0, 100.0), (5.0, 0.0));
+        assert_eq!(stretch(90.0, 0.0, 20.0, 100.0), (100.0, 5.0));
+    }
+
+    #[test]
+    fn snapping_picks_the_closest_child() {
+        let starts = || [0.0, 100.0, 200.0, 300.0].into_iter();
+
+        assert_eq!(nearest(140.0, starts(), 250.0), Some(100.0));
+        assert_eq!(nearest(190.0, starts(), 250.0), Some(200.0));
+        assert_eq!(nearest(250.0, starts(), 250.0), None);
+        assert_eq!(nearest(100.4, starts(), 250.0), None);
+    }
+}
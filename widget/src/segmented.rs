@@ -0,0 +1,780 @@
+//! Segmented controls display a connected group of options, like radio
+//! buttons styled as buttons.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::segmented;
+//! use iced::widget::segmented::Segment;
+//!
+//! struct State {
+//!     alignment: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Align(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     segmented(
+//!         ["Left", "Center", "Right"].map(Segment::new),
+//!         Some(state.alignment),
+//!         Message::Align,
+//!     )
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Align(index) => {
+//!             state.alignment = index;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::fmt;
+
+/// The space between the icon and the label of a [`Segment`].
+const SPACING: f32 = 6.0;
+
+/// An option of a [`Segmented`] control.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    label: String,
+    icon: Option<char>,
+    is_enabled: bool,
+}
+
+impl Segment {
+    /// Creates a new [`Segment`] with the given label.
+    ///
+    /// The label can be empty for segments that only display an icon.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            is_enabled: true,
+        }
+    }
+
+    /// Sets the icon of the [`Segment`], a code point of the icon font of
+    /// the [`Segmented`] control.
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether the [`Segment`] can be selected.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// A connected group of options, of which one or—in multi-select mode—many
+/// can be selected.
+///
+/// When focused, the arrow keys move between the segments. In single-select
+/// mode, moving selects the segment, like radio buttons do; in multi-select
+/// mode, the space and enter keys toggle the focused segment.
+pub struct Segmented<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    segments: Vec<Segment>,
+    selected: Vec<usize>,
+    is_multiple: bool,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    icon_font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Segmented<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Segment`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`Segmented`] control with the given segments, the
+    /// index of the selected one, and a function that produces a message
+    /// when a segment is selected.
+    pub fn new(
+        segments: impl IntoIterator<Item = Segment>,
+        selected: Option<usize>,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            segments: segments.into_iter().collect(),
+            selected: selected.into_iter().collect(),
+            is_multiple: false,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            icon_font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Creates a new multi-select [`Segmented`] control with the given
+    /// segments, the indices of the selected ones, and a function that
+    /// produces a message when a segment is toggled.
+    pub fn multiple(
+        segments: impl IntoIterator<Item = Segment>,
+        selected: impl IntoIterator<Item = usize>,
+        on_toggle: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            selected: selected.into_iter().collect(),
+            is_multiple: true,
+            ..Self::new(segments, None, on_toggle)
+        }
+    }
+
+    /// Sets the width of the [`Segmented`] control.
+    ///
+    /// Any additional space is split evenly between the segments.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each [`Segment`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Segmented`] control.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the [`Segmented`] control.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font of the icons of the [`Segmented`] control.
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Segmented`] control.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Segmented`] control.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size_or_default(renderer))
+            .0
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        font: Renderer::Font,
+        renderer: &Renderer,
+    ) -> Text<&'b str, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, self.line_height(renderer)),
+            size: self.text_size_or_default(renderer),
+            line_height: text::LineHeight::default(),
+            font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    /// Returns the width of the icon and the label of a [`Segment`].
+    fn content_width(&self, segment: &Segment, renderer: &Renderer) -> (f32, f32) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+
+        let measure = |content: &str, font| {
+            Renderer::Paragraph::with_text(self.text(content, font, renderer)).min_width()
+        };
+
+        let icon = segment
+            .icon
+            .map(|icon| measure(&icon.to_string(), icon_font))
+            .unwrap_or_default();
+
+        let label = if segment.label.is_empty() {
+            0.0
+        } else {
+            measure(&segment.label, font)
+        };
+
+        (icon, label)
+    }
+
+    /// Selects the [`Segment`] at the given index, if it is not selected
+    /// already—or toggles it in multi-select mode.
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if self.is_multiple || !self.is_selected(index) {
+            shell.publish((self.on_select)(index));
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    focused: Option<usize>,
+    is_pressed: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        if self.focused.is_none() {
+            self.focused = Some(0);
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Segmented<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let line_height = self.line_height(renderer);
+        let height = line_height + self.padding.y();
+
+        let widths: Vec<f32> = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let (icon, label) = self.content_width(segment, renderer);
+                let spacing = if icon > 0.0 && label > 0.0 {
+                    SPACING
+                } else {
+                    0.0
+                };
+
+                icon + spacing + label + self.padding.x()
+            })
+            .collect();
+
+        let total: f32 = widths.iter().sum();
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(total, height));
+
+        let extra = if widths.is_empty() {
+            0.0
+        } else {
+            (size.width - total).max(0.0) / widths.len() as f32
+        };
+
+        let mut x = 0.0;
+
+        let segments = widths
+            .into_iter()
+            .map(|width| {
+                let node =
+                    layout::Node::new(Size::new(width + extra, height)).move_to(Point::new(x, 0.0));
+
+                x += width + extra;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, segments)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(None, layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    if state.focused.take().is_some() {
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                let Some(index) = layout
+                    .children()
+                    .position(|segment| segment.bounds().contains(position))
+                else {
+                    return;
+                };
+
+                if self.segments[index].is_enabled {
+                    self.select(index, shell);
+                }
+
+                state.focused = Some(index);
+                state.is_pressed = true;
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.is_pressed {
+                    state.is_pressed = false;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if cursor.is_over(layout.bounds()) {
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let Some(focused) = state.focused else {
+                    return;
+                };
+
+                let enabled: Vec<bool> = self
+                    .segments
+                    .iter()
+                    .map(|segment| segment.is_enabled)
+                    .collect();
+
+                let target = match key {
+                    Key::Named(key::Named::ArrowLeft | key::Named::ArrowUp) => {
+                        step(&enabled, focused, -1)
+                    }
+                    Key::Named(key::Named::ArrowRight | key::Named::ArrowDown) => {
+                        step(&enabled, focused, 1)
+                    }
+                    Key::Named(key::Named::Home) => step(&enabled, usize::MAX, 1),
+                    Key::Named(key::Named::End) => step(&enabled, 0, -1),
+                    Key::Named(key::Named::Space | key::Named::Enter) => {
+                        if enabled.get(focused).copied().unwrap_or(false) {
+                            self.select(focused, shell);
+                        }
+
+                        shell.capture_event();
+                        return;
+                    }
+                    Key::Named(key::Named::Escape) => {
+                        state.focused = None;
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                if let Some(target) = target {
+                    state.focused = Some(target);
+
+                    if !self.is_multiple {
+                        self.select(target, shell);
+                    }
+
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+        let radius = style.border.radius;
+        let last = self.segments.len().saturating_sub(1);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for (index, (segment, layout)) in self.segments.iter().zip(layout.children()).enumerate() {
+            let segment_bounds = layout.bounds();
+            let is_selected = self.is_selected(index);
+            let is_hovered = segment.is_enabled && cursor.is_over(segment_bounds);
+
+            // Only the outer corners of the group are rounded
+            let corners = Border {
+                radius: crate::core::border::Radius {
+                    top_left: if index == 0 { radius.top_left } else { 0.0 },
+                    bottom_left: if index == 0 { radius.bottom_left } else { 0.0 },
+                    top_right: if index == last { radius.top_right } else { 0.0 },
+                    bottom_right: if index == last {
+                        radius.bottom_right
+                    } else {
+                        0.0
+                    },
+                },
+                ..Border::default()
+            };
+
+            let background = if is_selected {
+                Some(style.selected_background)
+            } else if is_hovered {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: segment_bounds,
+                        border: corners,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if index > 0 && !is_selected && !self.is_selected(index - 1) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: segment_bounds.x,
+                            y: segment_bounds.y + self.padding.top / 2.0,
+                            width: 1.0,
+                            height: segment_bounds.height - self.padding.y() / 2.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.divider_color,
+                );
+            }
+
+            if state.focused == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: segment_bounds.shrink(1.0),
+                        border: Border {
+                            color: style.focus_color,
+                            width: 1.0,
+                            ..corners
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let color = if !segment.is_enabled {
+                style.disabled_text_color
+            } else if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let (icon_width, label_width) = self.content_width(segment, renderer);
+            let spacing = if icon_width > 0.0 && label_width > 0.0 {
+                SPACING
+            } else {
+                0.0
+            };
+
+            let x = segment_bounds.center_x() - (icon_width + spacing + label_width) / 2.0;
+            let y = segment_bounds.center_y();
+
+            if let Some(icon) = segment.icon {
+                let text = self.text("", icon_font, renderer);
+
+                renderer.fill_text(
+                    text.with_content(icon.to_string()),
+                    Point::new(x + icon_width / 2.0, y),
+                    color,
+                    *viewport,
+                );
+            }
+
+            if label_width > 0.0 {
+                let text = self.text(&segment.label, font, renderer);
+
+                renderer.fill_text(
+                    text.with_content(segment.label.clone()),
+                    Point::new(x + icon_width + spacing + label_width / 2.0, y),
+                    color,
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_enabled = self
+            .segments
+            .iter()
+            .zip(layout.children())
+            .any(|(segment, layout)| segment.is_enabled && cursor.is_over(layout.bounds()));
+
+        if is_over_enabled {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Segmented<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(segmented: Segmented<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(segmented)
+    }
+}
+
+/// Returns the index of the closest enabled segment after—or before, when
+/// the direction is negative—the given one, if any.
+fn step(enabled: &[bool], from: usize, direction: isize) -> Option<usize> {
+    let count = enabled.len() as isize;
+
+    let start = if from >= enabled.len() {
+        if direction > 0 { -1 } else { count }
+    } else {
+        from as isize
+    };
+
+    let mut index = start + direction;
+
+    while (0..count).contains(&index) {
+        if enabled[index as usize] {
+            return Some(index as usize);
+        }
+
+        index += direction;
+    }
+
+    None
+}
+
+/// The appearance of a segmented control.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the segmented control.
+    pub background: Background,
+    /// The [`Border`] of the segmented control.
+    pub border: Border,
+    /// The [`Color`] of the dividers between the segments.
+    pub divider_color: Color,
+    /// The text [`Color`] of the segments.
+    pub text_color: Color,
+    /// The [`Background`] of a hovered segment.
+    pub hovered_background: Background,
+    /// The [`Background`] of a selected segment.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected segment.
+    pub selected_text_color: Color,
+    /// The text [`Color`] of a disabled segment.
+    pub disabled_text_color: Color,
+    /// The [`Color`] of the outline of the focused segment.
+    pub focus_color: Color,
+}
+
+/// The theme catalog of a [`Segmented`] control.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Segmented`] control.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Segmented`] control.
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            radius: 4.0.into(),
+        },
+        divider_color: palette.background.strong.color,
+        text_color: palette.background.weak.text,
+        hovered_background: palette.background.strong.color.into(),
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        disabled_text_color: palette.background.strongest.color,
+        focus_color: palette.primary.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_skip_disabled_segments() {
+        let enabled = [true, false, true, true, false];
+
+        assert_eq!(step(&enabled, 0, 1), Some(2));
+        assert_eq!(step(&enabled, 2, -1), Some(0));
+        assert_eq!(step(&enabled, 3, 1), None);
+        assert_eq!(step(&enabled, usize::MAX, 1), Some(0));
+        assert_eq!(step(&enabled, 0, -1), None);
+    }
+}
@@ -0,0 +1,385 @@
+//! Skeletons stand in for content while it loads.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, row, skeleton};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     // ...,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     row![
+//!         skeleton::circle(48),
+//!         column![skeleton::line(), skeleton::line().width(120)].spacing(8),
+//!     ]
+//!     .spacing(10)
+//!     .into()
+//! }
+//! ```
+use crate::core;
+use crate::core::border;
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Radians, Rectangle, Shell,
+    Size, Theme, Widget,
+};
+
+use std::f32::consts::PI;
+
+/// The half width of the shimmer band, relative to the width of a
+/// [`Skeleton`].
+const BAND: f32 = 0.3;
+
+/// Creates a new [`Skeleton`] standing in for a line of text.
+pub fn line<'a, Theme>() -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Skeleton::new(
+        Shape::Line,
+        Length::Fill,
+        Length::Fixed(Skeleton::<Theme>::LINE_HEIGHT),
+    )
+}
+
+/// Creates a new circular [`Skeleton`] with the given diameter.
+pub fn circle<'a, Theme>(diameter: impl Into<Pixels>) -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    let diameter = Length::Fixed(diameter.into().0);
+
+    Skeleton::new(Shape::Circle, diameter, diameter)
+}
+
+/// Creates a new rectangular [`Skeleton`] with the given dimensions.
+pub fn rect<'a, Theme>(width: impl Into<Length>, height: impl Into<Length>) -> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Skeleton::new(Shape::Rect, width.into(), height.into())
+}
+
+/// A placeholder with an animated shimmer, displayed while content loads.
+///
+/// The shimmer can be disabled with [`animated`](Self::animated) to honor a
+/// reduced-motion preference of the user.
+pub struct Skeleton<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    shape: Shape,
+    width: Length,
+    height: Length,
+    is_animated: bool,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// The shape of a [`Skeleton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// A line of text.
+    Line,
+    /// A circle, like an avatar.
+    Circle,
+    /// A rectangle, like an image.
+    Rect,
+}
+
+impl<'a, Theme> Skeleton<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`Skeleton`] line.
+    pub const LINE_HEIGHT: f32 = 14.0;
+
+    /// Creates a new [`Skeleton`] with the given [`Shape`] and dimensions.
+    pub fn new(shape: Shape, width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        Skeleton {
+            shape,
+            width: width.into(),
+            height: height.into(),
+            is_animated: true,
+            duration: Duration::from_millis(1500),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Skeleton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Skeleton`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets whether the shimmer of the [`Skeleton`] is animated.
+    ///
+    /// Disable it when the user prefers reduced motion. By default, it is
+    /// enabled.
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    /// Sets the duration of a single sweep of the shimmer of the
+    /// [`Skeleton`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Skeleton`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// Produces the gradient of the shimmer at the given phase of its sweep.
+///
+/// The band of the shimmer enters from the left at the start of the sweep
+/// and leaves on the right at its end.
+fn shimmer(phase: f32, color: Color) -> gradient::Linear {
+    let center = -BAND + phase * (1.0 + 2.0 * BAND);
+
+    let intensity =
+        |offset: f32| color.scale_alpha((1.0 - (offset - center).abs() / BAND).max(0.0));
+
+    [0.0, center - BAND, center, center + BAND, 1.0]
+        .into_iter()
+        .filter(|offset| (0.0..=1.0).contains(offset))
+        .fold(
+            gradient::Linear::new(Radians(PI / 2.0)),
+            |gradient, offset| gradient.add_stop(offset, intensity(offset)),
+        )
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Skeleton<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && self.is_animated
+        {
+            state.now = *now;
+
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let radius = match self.shape {
+            Shape::Circle => border::Radius::from(bounds.width.min(bounds.height) / 2.0),
+            Shape::Line | Shape::Rect => style.radius,
+        };
+
+        let quad = renderer::Quad {
+            bounds,
+            border: Border {
+                radius,
+                ..Border::default()
+            },
+            ..renderer::Quad::default()
+        };
+
+        renderer.fill_quad(quad, style.color);
+
+        if self.is_animated {
+            renderer.fill_quad(
+                quad,
+                shimmer(state.phase(self.duration), style.shimmer_color),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Skeleton<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(skeleton: Skeleton<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(skeleton)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    start: Instant,
+    now: Instant,
+}
+
+impl State {
+    fn new() -> Self {
+        let now = Instant::now();
+
+        Self { start: now, now }
+    }
+
+    /// Returns the phase of the current sweep of the shimmer, from 0 to 1.
+    fn phase(&self, duration: Duration) -> f32 {
+        let duration = duration.as_secs_f32();
+
+        if duration <= 0.0 {
+            return 0.0;
+        }
+
+        (self.now.duration_since(self.start).as_secs_f32() / duration).fract()
+    }
+}
+
+/// The appearance of a skeleton.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the skeleton.
+    pub color: Color,
+    /// The [`Color`] of the shimmer sweeping across the skeleton.
+    pub shimmer_color: Color,
+    /// The radius of the corners of lines and rectangles.
+    pub radius: border::Radius,
+}
+
+/// The theme catalog of a [`Skeleton`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Skeleton`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Skeleton`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        color: palette.background.weak.color,
+        shimmer_color: palette.background.base.color.scale_alpha(0.6),
+        radius: 4.0.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shimmer_sweeps_across() {
+        let stop = |gradient: gradient::Linear, offset: f32| {
+            gradient
+                .stops
+                .into_iter()
+                .flatten()
+                .find(|stop| (stop.offset - offset).abs() < 1e-6)
+                .map(|stop| stop.color.a)
+        };
+
+        let start = shimmer(0.0, Color::WHITE);
+        assert_eq!(stop(start, 0.0), Some(0.0));
+        assert_eq!(stop(start, 1.0), Some(0.0));
+
+        let middle = shimmer(0.5, Color::WHITE);
+        assert!(stop(middle, 0.5).is_some_and(|alpha| alpha > 0.99));
+        assert_eq!(stop(middle, 1.0), Some(0.0));
+    }
+}
@@ -28,18 +28,21 @@
 //!     }
 //! }
 //! ```
+use crate::container;
+use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
-    self, Background, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle,
-    Shell, Size, Theme, Widget,
+    Background, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -93,6 +96,11 @@ where
     on_release: Option<Message>,
     width: Length,
     height: f32,
+    ticks: Vec<Tick<T>>,
+    snap_to_ticks: bool,
+    scale: Scale,
+    tooltip: Option<Box<dyn Fn(T) -> String + 'a>>,
+    text_size: Option<Pixels>,
     class: Theme::Class<'a>,
     status: Option<Status>,
 }
@@ -140,6 +148,11 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            ticks: Vec::new(),
+            snap_to_ticks: false,
+            scale: Scale::default(),
+            tooltip: None,
+            text_size: None,
             class: Theme::default(),
             status: None,
         }
@@ -190,6 +203,40 @@ where
         self
     }
 
+    /// Sets the [`Tick`] marks of the [`Slider`], drawn under its rail.
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = impl Into<Tick<T>>>) -> Self {
+        self.ticks = ticks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether the [`Slider`] snaps to its [`Tick`] marks.
+    ///
+    /// If enabled, the [`Slider`] can only be set to the values of its ticks,
+    /// instead of every step.
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Sets the [`Scale`] of the [`Slider`].
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the function that produces the tooltip shown over the handle
+    /// of the [`Slider`] while it is being dragged.
+    pub fn tooltip(mut self, tooltip: impl Fn(T) -> String + 'a) -> Self {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the text size of the labels and the tooltip of the [`Slider`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -214,7 +261,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -234,10 +281,22 @@ where
     fn layout(
         &mut self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let marks = if self.ticks.is_empty() {
+            0.0
+        } else if self.ticks.iter().any(|tick| tick.label.is_some()) {
+            let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            TICK_LENGTH
+                + LABEL_SPACING
+                + f32::from(text::LineHeight::default().to_absolute(text_size))
+        } else {
+            TICK_LENGTH
+        };
+
+        layout::atomic(limits, self.width, self.height + marks)
     }
 
     fn update(
@@ -276,7 +335,19 @@ where
 
                     let percent = f64::from(cursor_position.x - bounds.x) / f64::from(bounds.width);
 
-                    let steps = (percent * (end - start) / step).round();
+                    if self.snap_to_ticks
+                        && let Some(tick) = nearest_tick(
+                            self.ticks.iter().map(|tick| tick.value.into()),
+                            percent,
+                            self.scale,
+                            start,
+                            end,
+                        )
+                    {
+                        return T::from_f64(tick);
+                    }
+
+                    let steps = ((self.scale.value(percent, start, end) - start) / step).round();
                     let value = steps * step + start;
 
                     T::from_f64(value.min(end))
@@ -284,6 +355,15 @@ where
             };
 
             let increment = |value: T| -> Option<T> {
+                if self.snap_to_ticks && !self.ticks.is_empty() {
+                    return next_tick(
+                        self.ticks.iter().map(|tick| tick.value.into()),
+                        value.into(),
+                        true,
+                    )
+                    .and_then(T::from_f64);
+                }
+
                 let step = if state.keyboard_modifiers.shift() {
                     self.shift_step.unwrap_or(self.step)
                 } else {
@@ -302,6 +382,15 @@ where
             };
 
             let decrement = |value: T| -> Option<T> {
+                if self.snap_to_ticks && !self.ticks.is_empty() {
+                    return next_tick(
+                        self.ticks.iter().map(|tick| tick.value.into()),
+                        value.into(),
+                        false,
+                    )
+                    .and_then(T::from_f64);
+                }
+
                 let step = if state.keyboard_modifiers.shift() {
                     self.shift_step.unwrap_or(self.step)
                 } else {
@@ -419,15 +508,19 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = Rectangle {
+            height: self.height,
+            ..layout.bounds()
+        };
 
         let style = theme.style(&self.class, self.status.unwrap_or(Status::Active));
 
@@ -439,19 +532,19 @@ where
             } => (f32::from(width), bounds.height, border_radius),
         };
 
-        let value = self.value.into() as f32;
         let (range_start, range_end) = {
             let (start, end) = self.range.clone().into_inner();
 
-            (start.into() as f32, end.into() as f32)
+            (start.into(), end.into())
         };
 
-        let offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.width - handle_width) * (value - range_start) / (range_end - range_start)
+        let position = |value: f64| {
+            (bounds.width - handle_width) * self.scale.percent(value, range_start, range_end) as f32
         };
 
+        let offset = position(self.value.into());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
         let rail_y = bounds.y + bounds.height / 2.0;
 
         renderer.fill_quad(
@@ -482,6 +575,43 @@ where
             style.rail.backgrounds.1,
         );
 
+        for tick in &self.ticks {
+            let x = bounds.x + position(tick.value.into()) + handle_width / 2.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - 0.5,
+                        y: bounds.y + bounds.height,
+                        width: 1.0,
+                        height: TICK_LENGTH,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.tick,
+            );
+
+            if let Some(label) = &tick.label {
+                renderer.fill_text(
+                    Text {
+                        content: label.clone(),
+                        bounds: Size::INFINITE,
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::default(),
+                        wrapping: text::Wrapping::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(x, bounds.y + bounds.height + TICK_LENGTH + LABEL_SPACING),
+                    style.label,
+                    *viewport,
+                );
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -499,6 +629,27 @@ where
             },
             style.handle.background,
         );
+
+        if state.is_dragging
+            && let Some(tooltip) = &self.tooltip
+        {
+            let center = bounds.x + offset + handle_width / 2.0;
+            let top = rail_y - handle_height / 2.0;
+
+            draw_tooltip(
+                renderer,
+                &style,
+                tooltip(self.value),
+                text_size,
+                *viewport,
+                |size| {
+                    Point::new(
+                        center - size.width / 2.0,
+                        top - size.height - TOOLTIP_SPACING,
+                    )
+                },
+            );
+        }
     }
 
     fn mouse_interaction(
@@ -537,13 +688,177 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(slider: Slider<'a, T, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
         Element::new(slider)
     }
 }
 
+/// A mark along a slider at some value, optionally labeled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick<T> {
+    /// The value of the [`Tick`].
+    pub value: T,
+    /// The label shown next to the [`Tick`], if any.
+    pub label: Option<String>,
+}
+
+impl<T> Tick<T> {
+    /// Creates a new [`Tick`] at the given value, without a label.
+    pub fn new(value: T) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Sets the label of the [`Tick`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl<T> From<T> for Tick<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The mapping between the values of a slider and the positions of its
+/// handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scale {
+    /// The values are distributed evenly along the slider.
+    #[default]
+    Linear,
+    /// The values are distributed logarithmically along the slider, giving
+    /// more room to the smaller ones.
+    ///
+    /// It behaves like [`Scale::Linear`] unless the whole range of the slider
+    /// is positive.
+    Logarithmic,
+}
+
+impl Scale {
+    /// Returns the relative position of the given value in the given range,
+    /// between `0.0` and `1.0`.
+    pub(crate) fn percent(self, value: f64, start: f64, end: f64) -> f64 {
+        if start >= end {
+            return 0.0;
+        }
+
+        let percent = match self {
+            Self::Logarithmic if start > 0.0 => {
+                (value.max(start).ln() - start.ln()) / (end.ln() - start.ln())
+            }
+            _ => (value - start) / (end - start),
+        };
+
+        percent.clamp(0.0, 1.0)
+    }
+
+    /// Returns the value at the given relative position in the given range.
+    pub(crate) fn value(self, percent: f64, start: f64, end: f64) -> f64 {
+        let percent = percent.clamp(0.0, 1.0);
+
+        match self {
+            Self::Logarithmic if start > 0.0 && start < end => {
+                (start.ln() + percent * (end.ln() - start.ln())).exp()
+            }
+            _ => start + percent * (end - start),
+        }
+    }
+}
+
+/// The length of the [`Tick`] marks of a slider.
+pub(crate) const TICK_LENGTH: f32 = 4.0;
+
+/// The space between the [`Tick`] marks of a slider and their labels.
+pub(crate) const LABEL_SPACING: f32 = 2.0;
+
+/// The space between the handle of a slider and its tooltip.
+pub(crate) const TOOLTIP_SPACING: f32 = 4.0;
+
+/// The padding of the tooltip of a slider.
+const TOOLTIP_PADDING: f32 = 4.0;
+
+/// Returns the tick closest to the given relative position of a slider.
+pub(crate) fn nearest_tick(
+    ticks: impl IntoIterator<Item = f64>,
+    percent: f64,
+    scale: Scale,
+    start: f64,
+    end: f64,
+) -> Option<f64> {
+    let distance = |tick: f64| (scale.percent(tick, start, end) - percent).abs();
+
+    ticks
+        .into_iter()
+        .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+}
+
+/// Returns the tick after or before the given value.
+pub(crate) fn next_tick(
+    ticks: impl IntoIterator<Item = f64>,
+    value: f64,
+    is_forward: bool,
+) -> Option<f64> {
+    let ticks = ticks.into_iter();
+
+    if is_forward {
+        ticks
+            .filter(|tick| *tick > value + f64::EPSILON)
+            .min_by(f64::total_cmp)
+    } else {
+        ticks
+            .filter(|tick| *tick < value - f64::EPSILON)
+            .max_by(f64::total_cmp)
+    }
+}
+
+/// Draws the tooltip of a slider with the given contents, placing it at
+/// the top-left corner produced by `position` for its size.
+pub(crate) fn draw_tooltip<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    content: String,
+    text_size: Pixels,
+    viewport: Rectangle,
+    position: impl FnOnce(Size) -> Point,
+) where
+    Renderer: text::Renderer,
+{
+    let text = Text {
+        content: content.as_str(),
+        bounds: Size::INFINITE,
+        size: text_size,
+        line_height: text::LineHeight::default(),
+        font: renderer.default_font(),
+        align_x: text::Alignment::Center,
+        align_y: alignment::Vertical::Center,
+        shaping: text::Shaping::default(),
+        wrapping: text::Wrapping::None,
+        hint_factor: renderer.scale_factor(),
+    };
+
+    let min_bounds = Renderer::Paragraph::with_text(text).min_bounds();
+    let size = Size::new(
+        min_bounds.width + TOOLTIP_PADDING * 2.0,
+        min_bounds.height + TOOLTIP_PADDING * 2.0,
+    );
+    let bounds = Rectangle::new(position(size), size);
+
+    renderer.with_layer(viewport, |renderer| {
+        container::draw_background(renderer, &style.tooltip, bounds);
+
+        renderer.fill_text(
+            text.with_content(content.clone()),
+            bounds.center(),
+            style.tooltip.text_color.unwrap_or(style.label),
+            viewport,
+        );
+    });
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
@@ -568,6 +883,12 @@ pub struct Style {
     pub rail: Rail,
     /// The appearance of the [`Handle`] of the slider.
     pub handle: Handle,
+    /// The [`Color`] of the [`Tick`] marks of the slider.
+    pub tick: Color,
+    /// The [`Color`] of the labels of the [`Tick`] marks of the slider.
+    pub label: Color,
+    /// The appearance of the tooltip of the slider.
+    pub tooltip: container::Style,
 }
 
 impl Style {
@@ -675,5 +996,35 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             border_color: Color::TRANSPARENT,
             border_width: 0.0,
         },
+        tick: palette.background.strong.color,
+        label: palette.background.base.text,
+        tooltip: container::rounded_box(theme),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scale, nearest_tick, next_tick};
+
+    #[test]
+    fn logarithmic_scale_is_inverted() {
+        let scale = Scale::Logarithmic;
+
+        assert!((scale.percent(10.0, 1.0, 100.0) - 0.5).abs() < 1e-9);
+        assert!((scale.value(0.5, 1.0, 100.0) - 10.0).abs() < 1e-9);
+        assert_eq!(scale.percent(50.0, -100.0, 100.0), 0.75);
+    }
+
+    #[test]
+    fn ticks_are_snapped_to() {
+        let ticks = [0.0, 25.0, 50.0, 100.0];
+
+        assert_eq!(
+            nearest_tick(ticks, 0.4, Scale::Linear, 0.0, 100.0),
+            Some(50.0)
+        );
+        assert_eq!(next_tick(ticks, 25.0, true), Some(50.0));
+        assert_eq!(next_tick(ticks, 25.0, false), Some(0.0));
+        assert_eq!(next_tick(ticks, 100.0, true), None);
     }
 }
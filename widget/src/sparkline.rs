@@ -0,0 +1,400 @@
+//! Sparklines plot the trend of some values in a small, word-sized graphic.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{row, sparkline, text};
+//!
+//! struct State {
+//!     latency: Vec<f32>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     row![text("Latency"), sparkline(&state.latency).fill(true)]
+//!         .spacing(5)
+//!         .into()
+//! }
+//! ```
+use crate::core::border;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::Tree;
+use crate::core::{
+    Border, Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme, Widget,
+};
+
+/// The default width of a [`Sparkline`], relative to its text size.
+const ASPECT_RATIO: f32 = 5.0;
+
+/// A small line plot of some values, sized like text.
+///
+/// A [`Sparkline`] is made of plain quads, so it needs no extra features and
+/// can be inlined in tables, lists, and status bars.
+pub struct Sparkline<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    values: &'a [f32],
+    width: Option<Length>,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    line_width: f32,
+    has_fill: bool,
+    has_markers: bool,
+    has_last: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Sparkline<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Sparkline`] of the given values.
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            width: None,
+            size: None,
+            line_height: text::LineHeight::default(),
+            line_width: 1.0,
+            has_fill: false,
+            has_markers: false,
+            has_last: true,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Sparkline`].
+    ///
+    /// By default, it is a few times its text size.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Sets the text size of the [`Sparkline`], which determines its height.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`Sparkline`].
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the width of the line of the [`Sparkline`].
+    pub fn line_width(mut self, line_width: impl Into<Pixels>) -> Self {
+        self.line_width = line_width.into().0;
+        self
+    }
+
+    /// Sets whether the area below the line of the [`Sparkline`] is filled.
+    pub fn fill(mut self, has_fill: bool) -> Self {
+        self.has_fill = has_fill;
+        self
+    }
+
+    /// Sets whether the minimum and maximum values of the [`Sparkline`] are
+    /// marked.
+    pub fn markers(mut self, has_markers: bool) -> Self {
+        self.has_markers = has_markers;
+        self
+    }
+
+    /// Sets whether the last value of the [`Sparkline`] is marked with a dot.
+    pub fn last(mut self, has_last: bool) -> Self {
+        self.has_last = has_last;
+        self
+    }
+
+    /// Sets the style of the [`Sparkline`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Sparkline`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// Returns the lowest and highest points of the line joining the given
+/// values between the two given fractional indices.
+fn span(values: &[f32], start: f32, end: f32) -> (f32, f32) {
+    let at = |index: f32| {
+        let floor = (index.floor() as usize).min(values.len() - 1);
+        let ceil = (floor + 1).min(values.len() - 1);
+
+        values[floor] + (values[ceil] - values[floor]) * (index - floor as f32)
+    };
+
+    let (a, b) = (at(start), at(end));
+
+    values
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (*i as f32) > start && (*i as f32) < end)
+        .fold((a.min(b), a.max(b)), |(min, max), (_, value)| {
+            (min.min(*value), max.max(*value))
+        })
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Sparkline<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width.unwrap_or(Length::Shrink),
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let height = self.line_height.to_absolute(size).0;
+
+        let width = self.width.unwrap_or(Length::Fixed(size.0 * ASPECT_RATIO));
+
+        layout::atomic(limits, width, height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let values: Vec<f32> = self
+            .values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .collect();
+
+        if values.is_empty() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let dot = self.line_width * 3.0;
+        let plot = bounds.shrink(dot / 2.0);
+
+        let (min, max) = values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+
+        let y = |value: f32| {
+            if max > min {
+                plot.y + plot.height * (1.0 - (value - min) / (max - min))
+            } else {
+                plot.center_y()
+            }
+        };
+
+        let last = values.len() - 1;
+
+        let position = |index: usize| {
+            let x = if last > 0 {
+                plot.x + plot.width * index as f32 / last as f32
+            } else {
+                plot.center_x()
+            };
+
+            Point::new(x, y(values[index]))
+        };
+
+        let columns = plot.width.ceil().max(1.0) as usize;
+        let column_width = plot.width / columns as f32;
+
+        for column in 0..columns {
+            let (low, high) = span(
+                &values,
+                last as f32 * column as f32 / columns as f32,
+                last as f32 * (column + 1) as f32 / columns as f32,
+            );
+
+            let x = plot.x + column as f32 * column_width;
+            let (top, bottom) = (y(high), y(low));
+
+            if self.has_fill {
+                let middle = (top + bottom) / 2.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: middle,
+                            width: column_width,
+                            height: bounds.y + bounds.height - middle,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.fill,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: top - self.line_width / 2.0,
+                        width: column_width,
+                        height: bottom - top + self.line_width,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.line,
+            );
+        }
+
+        let mut mark = |index: usize, color: Color| {
+            let center = position(index);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - dot / 2.0,
+                        y: center.y - dot / 2.0,
+                        width: dot,
+                        height: dot,
+                    },
+                    border: Border {
+                        radius: border::radius(dot / 2.0),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        };
+
+        if self.has_markers && max > min {
+            let extreme = |target: f32| {
+                values
+                    .iter()
+                    .position(|value| *value == target)
+                    .unwrap_or_default()
+            };
+
+            mark(extreme(min), style.min);
+            mark(extreme(max), style.max);
+        }
+
+        if self.has_last {
+            mark(last, style.last);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sparkline<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(sparkline: Sparkline<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(sparkline)
+    }
+}
+
+/// The appearance of a sparkline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the line.
+    pub line: Color,
+    /// The [`Color`] of the area below the line, when filled.
+    pub fill: Color,
+    /// The [`Color`] of the marker of the minimum value.
+    pub min: Color,
+    /// The [`Color`] of the marker of the maximum value.
+    pub max: Color,
+    /// The [`Color`] of the dot of the last value.
+    pub last: Color,
+}
+
+/// The theme catalog of a [`Sparkline`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Sparkline`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Sparkline`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        line: palette.primary.base.color,
+        fill: palette.primary.base.color.scale_alpha(0.2),
+        min: palette.danger.base.color,
+        max: palette.success.base.color,
+        last: palette.primary.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_covers_the_points_in_between() {
+        let values = [0.0, 4.0, 2.0];
+
+        assert_eq!(span(&values, 0.0, 0.5), (0.0, 2.0));
+        assert_eq!(span(&values, 0.5, 1.5), (2.0, 4.0));
+        assert_eq!(span(&values, 1.5, 2.0), (2.0, 3.0));
+    }
+}
@@ -0,0 +1,467 @@
+//! Spinners show that an operation is in progress.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::{column, spinner};
+//!
+//! struct State {
+//!    download: f32,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         spinner(),
+//!         spinner().variant(spinner::Variant::Dots),
+//!         spinner()
+//!             .progress(state.download)
+//!             .label(format!("{:.0}%", state.download * 100.0)),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use crate::canvas::{self, LineCap, Path, Stroke, path};
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Event, Font, Layout, Length, Pixels, Point, Radians, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use std::f32::consts::PI;
+
+/// The number of dots of a [`Spinner`] with the [`Dots`](Variant::Dots)
+/// variant.
+const DOTS: usize = 8;
+
+/// The shortest arc, as a fraction of a turn, drawn by an indeterminate
+/// [`Spinner`].
+const MIN_ARC: f32 = 0.08;
+
+/// The longest arc, as a fraction of a turn, drawn by an indeterminate
+/// [`Spinner`].
+const MAX_ARC: f32 = 0.7;
+
+/// A circular indicator of an operation in progress.
+///
+/// By default, a [`Spinner`] is indeterminate and animates on its own for as
+/// long as it is displayed. Once a [`progress`](Self::progress) is set, it
+/// shows the completed fraction of the operation instead.
+pub struct Spinner<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    variant: Variant,
+    progress: Option<f32>,
+    label: Option<String>,
+    size: f32,
+    bar_width: f32,
+    cycle_duration: Duration,
+    text_size: Option<Pixels>,
+    font: Option<Font>,
+    class: Theme::Class<'a>,
+}
+
+/// The shape of a [`Spinner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Variant {
+    /// An arc running along a circular track.
+    #[default]
+    Ring,
+    /// A circle of dots.
+    Dots,
+}
+
+impl<'a, Theme> Spinner<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Spinner`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default bar width of a [`Spinner`].
+    pub const DEFAULT_BAR_WIDTH: f32 = 4.0;
+
+    /// Creates a new indeterminate [`Spinner`].
+    pub fn new() -> Self {
+        Spinner {
+            variant: Variant::default(),
+            progress: None,
+            label: None,
+            size: Self::DEFAULT_SIZE,
+            bar_width: Self::DEFAULT_BAR_WIDTH,
+            cycle_duration: Duration::from_millis(1200),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Variant`] of the [`Spinner`].
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Makes the [`Spinner`] determinate, showing the given fraction of
+    /// progress, from 0 to 1.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the optional progress of the [`Spinner`].
+    ///
+    /// If `None`, the [`Spinner`] will be indeterminate.
+    pub fn progress_maybe(mut self, progress: Option<f32>) -> Self {
+        self.progress = progress.map(|progress| progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the label displayed at the center of the [`Spinner`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the size of the [`Spinner`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the bar of the [`Spinner`].
+    ///
+    /// For the [`Dots`](Variant::Dots) variant, this is the diameter of
+    /// each dot.
+    pub fn bar_width(mut self, bar_width: impl Into<Pixels>) -> Self {
+        self.bar_width = bar_width.into().0;
+        self
+    }
+
+    /// Sets the duration of a full cycle of the indeterminate animation of
+    /// the [`Spinner`].
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Spinner`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Spinner`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spinner`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Theme> Default for Spinner<'_, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the start and the end, as fractions of a turn, of the arc drawn
+/// by an indeterminate ring at the given phase of its cycle.
+///
+/// The arc turns once per cycle while it grows and shrinks back.
+fn indeterminate_arc(phase: f32) -> (f32, f32) {
+    let length = MIN_ARC + (MAX_ARC - MIN_ARC) * (1.0 - (phase * 2.0 * PI).cos()) / 2.0;
+    let start = phase + (MAX_ARC - length) / 2.0;
+
+    (start, start + length)
+}
+
+/// Returns the angle of the given fraction of a turn, starting at the top
+/// and going clockwise.
+fn angle(turn: f32) -> f32 {
+    turn * 2.0 * PI - PI / 2.0
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && self.progress.is_none()
+        {
+            state.now = *now;
+
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let phase = state.phase(self.cycle_duration);
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        let center = frame.center();
+        let radius = (bounds.width.min(bounds.height) - self.bar_width) / 2.0;
+
+        match self.variant {
+            Variant::Ring => {
+                let stroke = |color| {
+                    Stroke::default()
+                        .with_color(color)
+                        .with_width(self.bar_width)
+                        .with_line_cap(LineCap::Round)
+                };
+
+                frame.stroke(&Path::circle(center, radius), stroke(style.track_color));
+
+                let (start, end) = match self.progress {
+                    Some(progress) => (0.0, progress),
+                    None => indeterminate_arc(phase),
+                };
+
+                if end > start {
+                    let arc = Path::new(|builder| {
+                        builder.arc(path::Arc {
+                            center,
+                            radius,
+                            start_angle: Radians(angle(start)),
+                            end_angle: Radians(angle(end)),
+                        });
+                    });
+
+                    frame.stroke(&arc, stroke(style.color));
+                }
+            }
+            Variant::Dots => {
+                let filled = self
+                    .progress
+                    .map(|progress| (progress * DOTS as f32).round() as usize);
+
+                for i in 0..DOTS {
+                    let turn = i as f32 / DOTS as f32;
+                    let angle = angle(turn);
+
+                    let color = match filled {
+                        Some(filled) if i < filled => style.color,
+                        Some(_) => style.track_color,
+                        None => {
+                            let distance = (phase - turn).rem_euclid(1.0);
+
+                            style.color.scale_alpha((1.0 - distance).max(0.15))
+                        }
+                    };
+
+                    frame.fill(
+                        &Path::circle(
+                            center + Vector::new(angle.cos(), angle.sin()) * radius,
+                            self.bar_width / 2.0,
+                        ),
+                        color,
+                    );
+                }
+            }
+        }
+
+        if let Some(label) = &self.label {
+            frame.fill_text(canvas::Text {
+                content: label.clone(),
+                position: center,
+                color: style.text_color,
+                size: self.text_size.unwrap_or(Pixels(self.size / 4.0)),
+                font: self.font.unwrap_or_default(),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                ..canvas::Text::default()
+            });
+        }
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spinner<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(spinner: Spinner<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(spinner)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    start: Instant,
+    now: Instant,
+}
+
+impl State {
+    fn new() -> Self {
+        let now = Instant::now();
+
+        Self { start: now, now }
+    }
+
+    /// Returns the phase of the current cycle of the animation, from 0 to 1.
+    fn phase(&self, cycle_duration: Duration) -> f32 {
+        let cycle = cycle_duration.as_secs_f32();
+
+        if cycle <= 0.0 {
+            return 0.0;
+        }
+
+        (self.now.duration_since(self.start).as_secs_f32() / cycle).fract()
+    }
+}
+
+/// The appearance of a spinner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the bar or the active dots of the spinner.
+    pub color: Color,
+    /// The [`Color`] of the track or the inactive dots of the spinner.
+    pub track_color: Color,
+    /// The [`Color`] of the label of the spinner.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`Spinner`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Spinner`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Spinner`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        color: palette.primary.base.color,
+        track_color: palette.background.strong.color,
+        text_color: palette.background.base.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indeterminate_arc_grows_and_shrinks() {
+        let (start, end) = indeterminate_arc(0.0);
+        assert!((end - start - MIN_ARC).abs() < 1e-6);
+
+        let (start, end) = indeterminate_arc(0.5);
+        assert!((end - start - MAX_ARC).abs() < 1e-6);
+    }
+}
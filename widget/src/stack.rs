@@ -17,12 +17,17 @@ use crate::core::{
 /// You can use [`push_under`](Self::push_under) to push an [`Element`] under
 /// the current [`Stack`] without affecting its intrinsic [`Size`].
 ///
+/// The order of the layers can also be changed independently of the order
+/// of the children with [`push_layer`](Self::push_layer), which takes a
+/// [`Layer`] with a z-index and whether it should let events through.
+///
 /// Keep in mind that too much layering will normally produce bad UX as well as
 /// introduce certain rendering overhead. Use this widget sparingly!
 pub struct Stack<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     width: Length,
     height: Length,
-    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    pub(crate) children: Vec<Element<'a, Message, Theme, Renderer>>,
+    layers: Vec<Layer>,
     clip: bool,
     base_layer: usize,
 }
@@ -61,6 +66,7 @@ where
         Self {
             width: Length::Shrink,
             height: Length::Shrink,
+            layers: vec![Layer::default(); children.len()],
             children,
             clip: false,
             base_layer: 0,
@@ -80,7 +86,16 @@ where
     }
 
     /// Adds an element on top of the [`Stack`].
-    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_layer(child, Layer::default())
+    }
+
+    /// Adds an element to the [`Stack`] with the given [`Layer`] options.
+    pub fn push_layer(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        layer: Layer,
+    ) -> Self {
         let child = child.into();
         let child_size = child.as_widget().size_hint();
 
@@ -91,6 +106,7 @@ where
             }
 
             self.children.push(child);
+            self.layers.push(layer);
         }
 
         self
@@ -99,6 +115,7 @@ where
     /// Adds an element under the [`Stack`].
     pub fn push_under(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
         self.children.insert(0, child.into());
+        self.layers.insert(0, Layer::default());
         self.base_layer += 1;
         self
     }
@@ -122,6 +139,43 @@ where
     }
 }
 
+/// The options of a layer in a [`Stack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Layer {
+    /// The z-index of the layer.
+    ///
+    /// Layers with a higher z-index are displayed on top of the others.
+    /// Layers with the same z-index are displayed in the order they were
+    /// added.
+    pub z_index: i32,
+
+    /// Whether the layer is transparent to the mouse.
+    ///
+    /// A transparent layer is drawn as usual, but it never captures any
+    /// mouse interaction; which is useful for HUDs and watermarks.
+    pub passthrough: bool,
+}
+
+impl Layer {
+    /// Creates a new [`Layer`] with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the z-index of the [`Layer`].
+    pub fn z_index(self, z_index: i32) -> Self {
+        Self { z_index, ..self }
+    }
+
+    /// Sets whether the [`Layer`] is transparent to the mouse.
+    pub fn passthrough(self, passthrough: bool) -> Self {
+        Self {
+            passthrough,
+            ..self
+        }
+    }
+}
+
 impl<Message, Renderer> Default for Stack<'_, Message, Renderer>
 where
     Renderer: crate::core::Renderer,
@@ -229,24 +283,34 @@ where
 
         let is_over = cursor.is_over(layout.bounds());
         let end = self.children.len() - 1;
+        let layouts: Vec<_> = layout.children().collect();
+
+        for (i, &index) in order(&self.layers).iter().rev().enumerate() {
+            let child = &mut self.children[index];
+            let tree = &mut tree.children[index];
+            let layout = layouts[index];
+            let passthrough = self.layers[index].passthrough;
 
-        for (i, ((child, tree), layout)) in self
-            .children
-            .iter_mut()
-            .rev()
-            .zip(tree.children.iter_mut().rev())
-            .zip(layout.children().rev())
-            .enumerate()
-        {
             child.as_widget_mut().update(
-                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+                tree,
+                event,
+                layout,
+                if passthrough {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                },
+                renderer,
+                clipboard,
+                shell,
+                viewport,
             );
 
             if shell.is_event_captured() {
                 return;
             }
 
-            if i < end && is_over && !cursor.is_levitating() {
+            if !passthrough && i < end && is_over && !cursor.is_levitating() {
                 let interaction = child
                     .as_widget()
                     .mouse_interaction(tree, layout, cursor, viewport, renderer);
@@ -266,15 +330,20 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.children
-            .iter()
+        let layouts: Vec<_> = layout.children().collect();
+
+        order(&self.layers)
+            .into_iter()
             .rev()
-            .zip(tree.children.iter().rev())
-            .zip(layout.children().rev())
-            .map(|((child, tree), layout)| {
-                child
-                    .as_widget()
-                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            .filter(|&index| !self.layers[index].passthrough)
+            .map(|index| {
+                self.children[index].as_widget().mouse_interaction(
+                    &tree.children[index],
+                    layouts[index],
+                    cursor,
+                    viewport,
+                    renderer,
+                )
             })
             .find(|&interaction| interaction != mouse::Interaction::None)
             .unwrap_or_default()
@@ -297,55 +366,56 @@ where
                 viewport
             };
 
+            let order = order(&self.layers);
+            let layouts: Vec<_> = layout.children().collect();
+
             let layers_under = if cursor.is_over(layout.bounds()) {
-                self.children
+                order
                     .iter()
                     .rev()
-                    .zip(tree.children.iter().rev())
-                    .zip(layout.children().rev())
-                    .position(|((layer, tree), layout)| {
-                        let interaction = layer
-                            .as_widget()
-                            .mouse_interaction(tree, layout, cursor, viewport, renderer);
+                    .position(|&index| {
+                        if self.layers[index].passthrough {
+                            return false;
+                        }
+
+                        let interaction = self.children[index].as_widget().mouse_interaction(
+                            &tree.children[index],
+                            layouts[index],
+                            cursor,
+                            viewport,
+                            renderer,
+                        );
 
                         interaction != mouse::Interaction::None
                     })
-                    .map(|i| self.children.len() - i - 1)
+                    .map(|i| order.len() - i - 1)
                     .unwrap_or_default()
             } else {
                 0
             };
 
-            let mut layers = self
-                .children
-                .iter()
-                .zip(&tree.children)
-                .zip(layout.children())
-                .enumerate();
-
-            let layers = layers.by_ref();
-
-            let mut draw_layer =
-                |i, layer: &Element<'a, Message, Theme, Renderer>, tree, layout, cursor| {
-                    if i > 0 {
-                        renderer.with_layer(*viewport, |renderer| {
-                            layer
-                                .as_widget()
-                                .draw(tree, renderer, theme, style, layout, cursor, viewport);
-                        });
-                    } else {
+            for (i, &index) in order.iter().enumerate() {
+                let layer = &self.children[index];
+                let tree = &tree.children[index];
+                let layout = layouts[index];
+
+                let cursor = if i < layers_under || self.layers[index].passthrough {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                };
+
+                if i > 0 {
+                    renderer.with_layer(*viewport, |renderer| {
                         layer
                             .as_widget()
                             .draw(tree, renderer, theme, style, layout, cursor, viewport);
-                    }
-                };
-
-            for (i, ((layer, tree), layout)) in layers.take(layers_under) {
-                draw_layer(i, layer, tree, layout, mouse::Cursor::Unavailable);
-            }
-
-            for (i, ((layer, tree), layout)) in layers {
-                draw_layer(i, layer, tree, layout, cursor);
+                    });
+                } else {
+                    layer
+                        .as_widget()
+                        .draw(tree, renderer, theme, style, layout, cursor, viewport);
+                }
             }
         }
     }
@@ -380,3 +450,29 @@ where
         Self::new(stack)
     }
 }
+
+/// Returns the indices of the given layers in drawing order.
+fn order(layers: &[Layer]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..layers.len()).collect();
+    order.sort_by_key(|&index| layers[index].z_index);
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Layer, order};
+
+    #[test]
+    fn order_is_stable_by_z_index() {
+        let layers = [
+            Layer::new().z_index(1),
+            Layer::new(),
+            Layer::new().z_index(-1),
+            Layer::new().z_index(1).passthrough(true),
+            Layer::new(),
+        ];
+
+        assert_eq!(order(&layers), vec![2, 1, 4, 0, 3]);
+    }
+}
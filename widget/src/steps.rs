@@ -0,0 +1,891 @@
+//! Steps display the numbered stages of a multi-step flow, like a setup
+//! wizard, above the content of the current one.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::steps::Step;
+//! use iced::widget::{steps, text};
+//!
+//! struct State {
+//!     current: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     GoTo(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let content = match state.current {
+//!         0 => text("Create your account"),
+//!         1 => text("Fill in your profile"),
+//!         _ => text("Confirm your settings"),
+//!     };
+//!
+//!     steps(
+//!         ["Account", "Profile", "Confirm"].map(Step::new),
+//!         state.current,
+//!         content,
+//!     )
+//!     .on_select(Message::GoTo)
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::GoTo(step) => {
+//!             state.current = step;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::animation::{Animation, Easing};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// The diameter of the marker of a [`Step`].
+const MARKER_SIZE: f32 = 28.0;
+
+/// The space between the marker, the label, and the description of a
+/// [`Step`].
+const GAP: f32 = 6.0;
+
+/// The distance the content slides in when the current step changes.
+const SLIDE: f32 = 32.0;
+
+/// A stage of a [`Steps`] widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    label: String,
+    description: Option<String>,
+    status: Option<Status>,
+}
+
+impl Step {
+    /// Creates a new [`Step`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            status: None,
+        }
+    }
+
+    /// Sets the description of the [`Step`], displayed below its label.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the [`Status`] of the [`Step`].
+    ///
+    /// By default, the steps before the current one are
+    /// [`Done`](Status::Done) and the ones after it are
+    /// [`Pending`](Status::Pending).
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Marks the [`Step`] as failed.
+    pub fn error(self) -> Self {
+        self.status(Status::Error)
+    }
+
+    fn status_at(&self, index: usize, current: usize) -> Status {
+        self.status.unwrap_or(match index.cmp(&current) {
+            std::cmp::Ordering::Less => Status::Done,
+            std::cmp::Ordering::Equal => Status::Active,
+            std::cmp::Ordering::Greater => Status::Pending,
+        })
+    }
+}
+
+/// A widget displaying numbered step indicators above the content of the
+/// current step.
+///
+/// When the current step changes, its content slides in from the direction
+/// of the previous one.
+pub struct Steps<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    steps: Vec<Step>,
+    current: usize,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Steps<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Steps`] widget with the given steps, the index of the
+    /// current one, and its content.
+    pub fn new(
+        steps: impl IntoIterator<Item = Step>,
+        current: usize,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+            current,
+            content: content.into(),
+            on_select: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            spacing: 20.0,
+            text_size: None,
+            font: None,
+            duration: Duration::from_millis(200),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when a step indicator is
+    /// pressed, making the steps navigable.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`Steps`] widget.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Steps`] widget.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the space between the step indicators and the content.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Steps`] widget.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Steps`] widget.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the duration of the transition between steps.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Steps`] widget.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Steps`] widget.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn description_size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size_or_default(renderer) * 0.85
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        size: Pixels,
+        renderer: &Renderer,
+    ) -> Text<&'b str, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::new(
+                f32::INFINITY,
+                text::LineHeight::default().to_absolute(size).0,
+            ),
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        size: Pixels,
+        position: Point,
+        color: Color,
+        viewport: &Rectangle,
+    ) {
+        let text = self.text("", size, renderer);
+
+        renderer.fill_text(
+            text.with_content(content.to_owned()),
+            position,
+            color,
+            *viewport,
+        );
+    }
+
+    fn header_height(&self, renderer: &Renderer) -> f32 {
+        let label = text::LineHeight::default()
+            .to_absolute(self.text_size_or_default(renderer))
+            .0;
+
+        let description = if self.steps.iter().any(|step| step.description.is_some()) {
+            text::LineHeight::default()
+                .to_absolute(self.description_size(renderer))
+                .0
+                + GAP
+        } else {
+            0.0
+        };
+
+        MARKER_SIZE + GAP + label + description
+    }
+
+    /// Returns the natural width of the indicator of a [`Step`].
+    fn step_width(&self, step: &Step, renderer: &Renderer) -> f32 {
+        let measure = |content: &str, size| {
+            Renderer::Paragraph::with_text(self.text(content, size, renderer)).min_width()
+        };
+
+        let label = measure(&step.label, self.text_size_or_default(renderer));
+
+        let description = step
+            .description
+            .as_deref()
+            .map(|description| measure(description, self.description_size(renderer)))
+            .unwrap_or_default();
+
+        label.max(description).max(MARKER_SIZE) + 2.0 * GAP
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    current: usize,
+    is_forward: bool,
+    transition: Animation<bool>,
+    now: Instant,
+}
+
+impl State {
+    fn new(current: usize) -> Self {
+        Self {
+            current,
+            is_forward: true,
+            transition: Animation::new(true),
+            now: Instant::now(),
+        }
+    }
+
+    fn go(&mut self, current: usize, duration: Duration) {
+        if self.current == current {
+            return;
+        }
+
+        let now = Instant::now();
+
+        self.is_forward = current > self.current;
+        self.current = current;
+        self.transition = Animation::new(false)
+            .duration(duration)
+            .easing(Easing::EaseOut)
+            .go(true, now);
+        self.now = now;
+    }
+
+    /// Returns the horizontal offset of the content, sliding in.
+    fn offset(&self) -> f32 {
+        let remaining = self.transition.interpolate(1.0, 0.0, self.now);
+
+        if self.is_forward {
+            SLIDE * remaining
+        } else {
+            -SLIDE * remaining
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Steps<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.current))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .go(self.current, self.duration);
+
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let header_height = self.header_height(renderer);
+
+        let step_width = self
+            .steps
+            .iter()
+            .map(|step| self.step_width(step, renderer))
+            .fold(0.0, f32::max);
+
+        let limits = limits.width(self.width).height(self.height);
+        let natural_width = step_width * self.steps.len() as f32;
+        let width = limits
+            .resolve(self.width, Length::Shrink, Size::new(natural_width, 0.0))
+            .width;
+
+        let cell_width = if self.steps.is_empty() {
+            0.0
+        } else {
+            width / self.steps.len() as f32
+        };
+
+        let header = layout::Node::with_children(
+            Size::new(width, header_height),
+            (0..self.steps.len())
+                .map(|index| {
+                    layout::Node::new(Size::new(cell_width, header_height))
+                        .move_to(Point::new(cell_width * index as f32, 0.0))
+                })
+                .collect(),
+        );
+
+        let content_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                width,
+                (limits.max().height - header_height - self.spacing).max(0.0),
+            ),
+        );
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, &content_limits)
+            .move_to(Point::new(0.0, header_height + self.spacing));
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(width, header_height + self.spacing + content.size().height),
+        );
+
+        layout::Node::with_children(size, vec![header, content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let content = layout.children().nth(1).unwrap();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                content,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(on_select) = &self.on_select else {
+                    return;
+                };
+
+                let Some(position) = cursor.position_over(header.bounds()) else {
+                    return;
+                };
+
+                if let Some(index) = header
+                    .children()
+                    .position(|cell| cell.bounds().contains(position))
+                {
+                    if index != self.current {
+                        shell.publish(on_select(index));
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.on_select.is_some() && cursor.is_over(header.bounds()) {
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if state.transition.is_animating(state.now) {
+                    state.now = *now;
+
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let cells: Vec<Rectangle> = header.children().map(|cell| cell.bounds()).collect();
+        let statuses: Vec<Status> = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| step.status_at(index, self.current))
+            .collect();
+
+        let marker = |cell: &Rectangle| {
+            Rectangle::new(
+                Point::new(cell.center_x() - MARKER_SIZE / 2.0, cell.y),
+                Size::new(MARKER_SIZE, MARKER_SIZE),
+            )
+        };
+
+        // The connector after a step is styled by the step it leaves
+        for (index, pair) in cells.windows(2).enumerate() {
+            let appearance = theme.style(&self.class, statuses[index]);
+            let from = marker(&pair[0]);
+            let to = marker(&pair[1]);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: from.x + from.width + GAP,
+                        y: from.center_y() - 1.0,
+                        width: (to.x - from.x - from.width - 2.0 * GAP).max(0.0),
+                        height: 2.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.connector_color,
+            );
+        }
+
+        let label_size = self.text_size_or_default(renderer);
+        let description_size = self.description_size(renderer);
+        let label_height = text::LineHeight::default().to_absolute(label_size).0;
+        let description_height = text::LineHeight::default().to_absolute(description_size).0;
+
+        for (index, ((step, cell), status)) in
+            self.steps.iter().zip(&cells).zip(&statuses).enumerate()
+        {
+            let appearance = theme.style(&self.class, *status);
+            let marker = marker(cell);
+            let is_hovered = self.on_select.is_some() && cursor.is_over(*cell);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: marker,
+                    border: Border {
+                        radius: (MARKER_SIZE / 2.0).into(),
+                        width: if is_hovered {
+                            appearance.marker_border.width.max(2.0)
+                        } else {
+                            appearance.marker_border.width
+                        },
+                        ..appearance.marker_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.marker_background,
+            );
+
+            let symbol = match status {
+                Status::Done => "✓".to_owned(),
+                Status::Error => "!".to_owned(),
+                Status::Active | Status::Pending => (index + 1).to_string(),
+            };
+
+            self.fill_text(
+                renderer,
+                &symbol,
+                label_size,
+                marker.center(),
+                appearance.marker_text_color,
+                viewport,
+            );
+
+            let label_y = marker.y + marker.height + GAP + label_height / 2.0;
+
+            self.fill_text(
+                renderer,
+                &step.label,
+                label_size,
+                Point::new(cell.center_x(), label_y),
+                appearance.label_color,
+                viewport,
+            );
+
+            if let Some(description) = &step.description {
+                self.fill_text(
+                    renderer,
+                    description,
+                    description_size,
+                    Point::new(
+                        cell.center_x(),
+                        label_y + label_height / 2.0 + GAP + description_height / 2.0,
+                    ),
+                    appearance.description_color,
+                    viewport,
+                );
+            }
+        }
+
+        let offset = state.offset();
+
+        let Some(clip) = content.bounds().intersection(viewport) else {
+            return;
+        };
+
+        if offset == 0.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        let translation = Vector::new(offset, 0.0);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content,
+                    cursor - translation,
+                    &(clip - translation),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        if self.on_select.is_some() && header.children().any(|cell| cursor.is_over(cell.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Steps<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(steps: Steps<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(steps)
+    }
+}
+
+/// The status of a [`Step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Step`] has not been reached yet.
+    Pending,
+    /// The [`Step`] is the current one.
+    Active,
+    /// The [`Step`] has been completed.
+    Done,
+    /// The [`Step`] has failed.
+    Error,
+}
+
+/// The appearance of a step of a [`Steps`] widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the marker of the step.
+    pub marker_background: Background,
+    /// The [`Border`] of the marker of the step.
+    pub marker_border: Border,
+    /// The text [`Color`] of the marker of the step.
+    pub marker_text_color: Color,
+    /// The [`Color`] of the label of the step.
+    pub label_color: Color,
+    /// The [`Color`] of the description of the step.
+    pub description_color: Color,
+    /// The [`Color`] of the connector leading to the next step.
+    pub connector_color: Color,
+}
+
+/// The theme catalog of a [`Steps`] widget.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Steps`] widget.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Steps`] widget.
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let pending = Style {
+        marker_background: palette.background.weak.color.into(),
+        marker_border: Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Border::default()
+        },
+        marker_text_color: palette.background.weak.text,
+        label_color: palette.background.strongest.color,
+        description_color: palette.background.strong.color,
+        connector_color: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Pending => pending,
+        Status::Active => Style {
+            marker_background: palette.primary.base.color.into(),
+            marker_border: Border {
+                color: palette.primary.strong.color,
+                ..pending.marker_border
+            },
+            marker_text_color: palette.primary.base.text,
+            label_color: palette.background.base.text,
+            description_color: palette.background.strongest.color,
+            ..pending
+        },
+        Status::Done => Style {
+            marker_background: palette.success.base.color.into(),
+            marker_border: Border {
+                color: palette.success.strong.color,
+                ..pending.marker_border
+            },
+            marker_text_color: palette.success.base.text,
+            label_color: palette.background.base.text,
+            connector_color: palette.success.base.color,
+            ..pending
+        },
+        Status::Error => Style {
+            marker_background: palette.danger.base.color.into(),
+            marker_border: Border {
+                color: palette.danger.strong.color,
+                ..pending.marker_border
+            },
+            marker_text_color: palette.danger.base.text,
+            label_color: palette.danger.base.color,
+            ..pending
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statuses_follow_the_current_step() {
+        let steps = [
+            Step::new("Account"),
+            Step::new("Profile").error(),
+            Step::new("Confirm"),
+            Step::new("Done").status(Status::Done),
+        ];
+
+        let statuses: Vec<_> = steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| step.status_at(index, 2))
+            .collect();
+
+        assert_eq!(
+            statuses,
+            [Status::Done, Status::Error, Status::Active, Status::Done]
+        );
+    }
+}
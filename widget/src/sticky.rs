@@ -0,0 +1,476 @@
+//! Sticky sections pin their header to the top of the viewport while
+//! their content is visible.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, scrollable, sticky, text};
+//!
+//! struct State {
+//!     groups: Vec<(char, Vec<String>)>,
+//! }
+//!
+//! enum Message {}
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(column(state.groups.iter().map(|(letter, contacts)| {
+//!         sticky(
+//!             text(letter.to_string()).size(20),
+//!             column(contacts.iter().map(|contact| text(contact).into())),
+//!         )
+//!         .into()
+//!     })))
+//!     .into()
+//! }
+//! ```
+//!
+//! When the next section reaches the pinned header, it pushes it out of the
+//! viewport; so a [`Sticky`] header never overlaps other sections.
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::Tree;
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+/// A section with a header that sticks to the top of the viewport while
+/// its content is visible.
+pub struct Sticky<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    children: [Element<'a, Message, Theme, Renderer>; 2],
+    width: Length,
+    height: Length,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Sticky<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Sticky`] section with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let header = header.into();
+        let content = content.into();
+
+        let header_size = header.as_widget().size_hint();
+        let content_size = content.as_widget().size_hint();
+
+        Self {
+            children: [header, content],
+            width: Length::Shrink
+                .enclose(header_size.width)
+                .enclose(content_size.width),
+            height: Length::Shrink
+                .enclose(header_size.height)
+                .enclose(content_size.height),
+            spacing: 0.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Sticky`] section.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Sticky`] section.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the header and the content.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Sticky`] section.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Sticky`] section.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// Returns how far the header must be moved down to stay pinned to the
+/// top of the viewport without leaving the section.
+fn offset(section: Rectangle, header: Rectangle, viewport: &Rectangle) -> f32 {
+    (viewport.y - header.y).clamp(
+        0.0,
+        (section.y + section.height - header.y - header.height).max(0.0),
+    )
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sticky<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            Padding::ZERO,
+            self.spacing,
+            Alignment::Start,
+            &mut self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let [header, content] = &mut self.children;
+        let [header_tree, content_tree] = &mut tree.children[..] else {
+            return;
+        };
+
+        let translation = Vector::new(
+            0.0,
+            offset(layout.bounds(), header_layout.bounds(), viewport),
+        );
+
+        header.as_widget_mut().update(
+            header_tree,
+            event,
+            header_layout,
+            cursor - translation,
+            renderer,
+            clipboard,
+            shell,
+            &(*viewport - translation),
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let content_cursor = if cursor.is_over(header_layout.bounds() + translation) {
+            cursor.levitate()
+        } else {
+            cursor
+        };
+
+        content.as_widget_mut().update(
+            content_tree,
+            event,
+            content_layout,
+            content_cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let translation = Vector::new(
+            0.0,
+            offset(layout.bounds(), header_layout.bounds(), viewport),
+        );
+
+        if cursor.is_over(header_layout.bounds() + translation) {
+            return self.children[0].as_widget().mouse_interaction(
+                &tree.children[0],
+                header_layout,
+                cursor - translation,
+                &(*viewport - translation),
+                renderer,
+            );
+        }
+
+        self.children[1].as_widget().mouse_interaction(
+            &tree.children[1],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let translation = Vector::new(
+            0.0,
+            offset(layout.bounds(), header_layout.bounds(), viewport),
+        );
+
+        let header_bounds = header_layout.bounds() + translation;
+
+        let content_cursor = if cursor.is_over(header_bounds) {
+            cursor.levitate()
+        } else {
+            cursor
+        };
+
+        self.children[1].as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            content_cursor,
+            viewport,
+        );
+
+        let status = if translation.y > 0.0 {
+            Status::Pinned
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status);
+
+        let Some(clip) =
+            viewport.intersection(&header_bounds.expand(appearance.shadow.blur_radius))
+        else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            if appearance.background.is_some() || appearance.shadow.color.a > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: header_bounds,
+                        shadow: appearance.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    appearance
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            renderer.with_translation(translation, |renderer| {
+                self.children[0].as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    header_layout,
+                    cursor - translation,
+                    &(*viewport - translation),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sticky<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(sticky: Sticky<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(sticky)
+    }
+}
+
+/// The possible status of a [`Sticky`] section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The header rests at its natural position.
+    Active,
+    /// The header is pinned to the top of the viewport.
+    Pinned,
+}
+
+/// The appearance of the header of a [`Sticky`] section.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] drawn behind the header.
+    pub background: Option<Background>,
+    /// The [`Shadow`] cast by the header.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Sticky`] section.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Sticky`] section.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Sticky`] section.
+///
+/// The header gets an opaque background, so the content scrolling behind it
+/// stays hidden, and casts a subtle shadow while pinned.
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        shadow: match status {
+            Status::Active => Shadow::default(),
+            Status::Pinned => Shadow {
+                color: Color::BLACK.scale_alpha(0.15),
+                offset: Vector::new(0.0, 2.0),
+                blur_radius: 6.0,
+                spread: 0.0,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::offset;
+    use crate::core::Rectangle;
+
+    #[test]
+    fn headers_are_pushed_out_by_the_end_of_the_section() {
+        let section = Rectangle::new([0.0, 100.0].into(), [200.0, 300.0].into());
+        let header = Rectangle::new([0.0, 100.0].into(), [200.0, 40.0].into());
+        let viewport = |y| Rectangle::new([0.0, y].into(), [200.0, 200.0].into());
+
+        assert_eq!(offset(section, header, &viewport(0.0)), 0.0);
+        assert_eq!(offset(section, header, &viewport(150.0)), 50.0);
+        assert_eq!(offset(section, header, &viewport(380.0)), 260.0);
+    }
+}
@@ -15,20 +15,28 @@
 //!     svg("tiger.svg").into()
 //! }
 //! ```
+use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::svg;
-use crate::core::widget::Tree;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Clipboard, Color, ContentFit, Element, Event, Layout, Length, Point, Rectangle, Rotation,
     Shell, Size, Theme, Vector, Widget,
 };
+use crate::mask::Mask;
 
+use std::cell::RefCell;
 use std::path::PathBuf;
 
-pub use crate::core::svg::Handle;
+pub use crate::core::svg::{ColorMode, Handle};
+pub use crate::graphics::svg::rasterize;
+
+#[cfg(feature = "svg-text")]
+pub use crate::graphics::svg::{Fonts, configure_fonts};
 
 /// A vector graphics image.
 ///
@@ -57,12 +65,22 @@ where
     Theme: Catalog,
 {
     handle: Handle,
+    placeholder: Option<Handle>,
+    fallback: Option<Handle>,
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    color_mode: ColorMode,
     class: Theme::Class<'a>,
     rotation: Rotation,
     opacity: f32,
+    viewport: Option<Rectangle>,
+    playing: bool,
+    looping: bool,
+    speed: f32,
+    alpha_hit_test: bool,
     status: Option<Status>,
 }
 
@@ -74,12 +92,22 @@ where
     pub fn new(handle: impl Into<Handle>) -> Self {
         Svg {
             handle: handle.into(),
+            placeholder: None,
+            fallback: None,
             width: Length::Fill,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            align_x: alignment::Horizontal::Center,
+            align_y: alignment::Vertical::Center,
+            color_mode: ColorMode::default(),
             class: Theme::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            viewport: None,
+            playing: true,
+            looping: false,
+            speed: 1.0,
+            alpha_hit_test: false,
             status: None,
         }
     }
@@ -91,6 +119,66 @@ where
         Self::new(Handle::from_path(path))
     }
 
+    /// Sets the [`Handle`] of an SVG to display while the [`Svg`] is being
+    /// prepared in the background.
+    ///
+    /// The placeholder should be small, so it can be prepared right away.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<Handle>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the fallback [`Handle`] of the [`Svg`], which will be displayed
+    /// instead if the [`Svg`] cannot be loaded; either because its file is
+    /// missing or because its data is invalid.
+    ///
+    /// This is useful to show a placeholder for broken assets, instead of
+    /// nothing at all.
+    #[must_use]
+    pub fn fallback(mut self, fallback: impl Into<Handle>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Returns the [`Handle`] to display, which may be the placeholder
+    /// while the [`Svg`] is still being prepared, or the fallback if
+    /// it could not be loaded.
+    fn displayed_handle<Renderer>(&self, renderer: &Renderer) -> &Handle
+    where
+        Renderer: svg::Renderer,
+    {
+        if let Some(placeholder) = &self.placeholder
+            && renderer.is_svg_pending(&self.handle)
+        {
+            return placeholder;
+        }
+
+        if let Some(fallback) = &self.fallback
+            && renderer.is_svg_invalid(&self.handle)
+        {
+            return fallback;
+        }
+
+        &self.handle
+    }
+
+    /// Returns the size of the image of the given [`Handle`], taking the
+    /// viewport into account.
+    fn image_size<Renderer>(&self, renderer: &Renderer, handle: &Handle) -> Size
+    where
+        Renderer: svg::Renderer,
+    {
+        match self.viewport {
+            Some(viewport) if handle == &self.handle => viewport.size(),
+            _ => {
+                let Size { width, height } = renderer.measure_svg(handle);
+
+                Size::new(width as f32, height as f32)
+            }
+        }
+    }
+
     /// Sets the width of the [`Svg`].
     #[must_use]
     pub fn width(mut self, width: impl Into<Length>) -> Self {
@@ -116,6 +204,41 @@ where
         }
     }
 
+    /// Sets the alignment of the image inside the bounds of the [`Svg`] along
+    /// the horizontal axis.
+    ///
+    /// This is noticeable when the [`ContentFit`] leaves empty space or crops
+    /// the image.
+    ///
+    /// Defaults to [`alignment::Horizontal::Center`]
+    #[must_use]
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = alignment.into();
+        self
+    }
+
+    /// Sets the alignment of the image inside the bounds of the [`Svg`] along
+    /// the vertical axis.
+    ///
+    /// This is noticeable when the [`ContentFit`] leaves empty space or crops
+    /// the image.
+    ///
+    /// Defaults to [`alignment::Vertical::Center`]
+    #[must_use]
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = alignment.into();
+        self
+    }
+
+    /// Sets the [`ColorMode`] used to apply the [`Style::color`] of the [`Svg`].
+    ///
+    /// Defaults to [`ColorMode::Filter`]
+    #[must_use]
+    pub fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+
     /// Sets the style of the [`Svg`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -148,6 +271,157 @@ where
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the region of the image to draw inside the bounds of the [`Svg`].
+    ///
+    /// The region is defined in the coordinate space of the default dimensions
+    /// of the image, and it replaces them when sizing the [`Svg`]. This is
+    /// useful to draw a single icon of a sprite sheet or to zoom into a
+    /// drawing.
+    #[must_use]
+    pub fn viewport(mut self, viewport: Rectangle) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Sets whether the SMIL animations of the [`Svg`] should be playing.
+    ///
+    /// When paused, the [`Svg`] keeps its current frame and resumes from it
+    /// when played again.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Sets whether the SMIL animations of the [`Svg`] should start over
+    /// once all of them have finished.
+    ///
+    /// Animations that repeat indefinitely are not affected.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the playback speed of the SMIL animations of the [`Svg`].
+    ///
+    /// Defaults to `1.0`.
+    #[must_use]
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets whether the [`Svg`] should only be hit by the mouse cursor over
+    /// its non-transparent pixels.
+    ///
+    /// When enabled, interactive widgets containing the [`Svg`]—like a
+    /// [`button`](crate::button) or a [`mouse_area`](crate::mouse_area)—will
+    /// ignore the mouse cursor while it is over the transparent regions of
+    /// the image. This is useful for irregularly shaped graphics, like the
+    /// regions of a map.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn alpha_hit_test(mut self, alpha_hit_test: bool) -> Self {
+        self.alpha_hit_test = alpha_hit_test;
+        self
+    }
+
+    /// Returns the bounds where the image of the given size is drawn inside
+    /// the given `bounds`, before rotating it.
+    fn drawing_bounds(&self, image_size: Size, bounds: Rectangle) -> Rectangle {
+        let rotated_size = self.rotation.apply(image_size);
+        let adjusted_fit = self.content_fit.fit(rotated_size, bounds.size());
+
+        let scale = Vector::new(
+            adjusted_fit.width / rotated_size.width,
+            adjusted_fit.height / rotated_size.height,
+        );
+
+        let final_size = image_size * scale;
+
+        // The center of the rotated image aligned inside the bounds
+        let center = Point::new(
+            match self.align_x {
+                alignment::Horizontal::Left => bounds.x + adjusted_fit.width / 2.0,
+                alignment::Horizontal::Center => bounds.center_x(),
+                alignment::Horizontal::Right => bounds.x + bounds.width - adjusted_fit.width / 2.0,
+            },
+            match self.align_y {
+                alignment::Vertical::Top => bounds.y + adjusted_fit.height / 2.0,
+                alignment::Vertical::Center => bounds.center_y(),
+                alignment::Vertical::Bottom => bounds.y + bounds.height - adjusted_fit.height / 2.0,
+            },
+        );
+
+        let position = center - Vector::new(final_size.width / 2.0, final_size.height / 2.0);
+
+        Rectangle::new(position, final_size)
+    }
+
+    /// Returns whether the given `position` hits a non-transparent pixel of
+    /// the [`Svg`], if alpha hit testing is enabled.
+    fn hit<Renderer>(
+        &self,
+        state: &State,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        position: Point,
+    ) -> Option<bool>
+    where
+        Renderer: svg::Renderer,
+    {
+        /// The maximum width or height of the rasterized mask
+        const MAX_MASK_SIZE: f32 = 1024.0;
+
+        if !self.alpha_hit_test {
+            return None;
+        }
+
+        if !bounds.contains(position) {
+            return Some(false);
+        }
+
+        let handle = self.displayed_handle(renderer);
+        let image_size = self.image_size(renderer, handle);
+        let drawing_bounds = self.drawing_bounds(image_size, bounds);
+
+        let scale = (MAX_MASK_SIZE / drawing_bounds.width.max(drawing_bounds.height)).min(1.0);
+
+        let size = Size::new(
+            (drawing_bounds.width * scale).ceil() as u32,
+            (drawing_bounds.height * scale).ceil() as u32,
+        );
+
+        let viewport = self.viewport.filter(|_| handle == &self.handle);
+        let key = (handle.id(), viewport, size);
+
+        let mut mask = state.mask.borrow_mut();
+
+        if mask.as_ref().is_none_or(|(current, _)| *current != key) {
+            let svg = svg::Svg {
+                viewport,
+                ..svg::Svg::new(handle.clone())
+            };
+
+            let rasterized = renderer.rasterize_svg(&svg, size);
+
+            *mask = Some((key, rasterized.as_ref().and_then(Mask::new)));
+        }
+
+        let (_, mask) = mask.as_ref()?;
+
+        Some(
+            mask.as_ref()?
+                .hit(drawing_bounds, self.rotation.radians(), position),
+        )
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Svg<'_, Theme>
@@ -155,6 +429,14 @@ where
     Renderer: svg::Renderer,
     Theme: Catalog,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -169,8 +451,8 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         // The raw w/h of the underlying image
-        let Size { width, height } = renderer.measure_svg(&self.handle);
-        let image_size = Size::new(width as f32, height as f32);
+        let handle = self.displayed_handle(renderer);
+        let image_size = self.image_size(renderer, handle);
 
         // The rotated size of the svg
         let rotated_size = self.rotation.apply(image_size);
@@ -198,23 +480,51 @@ where
 
     fn update(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
-        let current_status = if cursor.is_over(layout.bounds()) {
+        let bounds = layout.bounds();
+
+        let is_over = cursor.position_over(bounds).is_some_and(|position| {
+            let state = tree.state.downcast_ref::<State>();
+
+            self.hit(state, renderer, bounds, position) != Some(false)
+        });
+
+        let current_status = if is_over {
             Status::Hovered
         } else {
             Status::Idle
         };
 
-        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             self.status = Some(current_status);
+
+            let state = tree.state.downcast_mut::<State>();
+
+            if self.playing
+                && let Some(duration) = renderer.measure_svg_animation(&self.handle)
+            {
+                if let Some(last_tick) = state.last_tick {
+                    state.elapsed += now
+                        .saturating_duration_since(last_tick)
+                        .mul_f32(self.speed.max(0.0));
+                }
+
+                state.last_tick = Some(*now);
+
+                if self.looping || state.elapsed < duration {
+                    shell.request_redraw();
+                }
+            } else {
+                state.last_tick = None;
+            }
         } else if self.status.is_some_and(|status| status != current_status) {
             shell.request_redraw();
         }
@@ -222,7 +532,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -230,45 +540,50 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        let Size { width, height } = renderer.measure_svg(&self.handle);
-        let image_size = Size::new(width as f32, height as f32);
-        let rotated_size = self.rotation.apply(image_size);
+        let handle = self.displayed_handle(renderer);
+        let image_size = self.image_size(renderer, handle);
 
         let bounds = layout.bounds();
-        let adjusted_fit = self.content_fit.fit(rotated_size, bounds.size());
-        let scale = Vector::new(
-            adjusted_fit.width / rotated_size.width,
-            adjusted_fit.height / rotated_size.height,
-        );
-
-        let final_size = image_size * scale;
+        let drawing_bounds = self.drawing_bounds(image_size, bounds);
 
-        let position = match self.content_fit {
-            ContentFit::None => Point::new(
-                bounds.x + (rotated_size.width - adjusted_fit.width) / 2.0,
-                bounds.y + (rotated_size.height - adjusted_fit.height) / 2.0,
-            ),
-            _ => Point::new(
-                bounds.center_x() - final_size.width / 2.0,
-                bounds.center_y() - final_size.height / 2.0,
-            ),
-        };
+        let style = theme.style(&self.class, self.status.unwrap_or(Status::Idle));
 
-        let drawing_bounds = Rectangle::new(position, final_size);
+        let time = renderer.measure_svg_animation(handle).map(|duration| {
+            let state = tree.state.downcast_ref::<State>();
 
-        let style = theme.style(&self.class, self.status.unwrap_or(Status::Idle));
+            if self.looping && !duration.is_zero() && duration < Duration::MAX {
+                Duration::from_secs_f64(state.elapsed.as_secs_f64() % duration.as_secs_f64())
+            } else {
+                state.elapsed
+            }
+        });
 
         renderer.draw_svg(
             svg::Svg {
-                handle: self.handle.clone(),
+                handle: handle.clone(),
                 color: style.color,
+                color_mode: self.color_mode,
                 rotation: self.rotation.radians(),
                 opacity: self.opacity,
+                time,
+                viewport: self.viewport.filter(|_| handle == &self.handle),
             },
             drawing_bounds,
             bounds,
         );
     }
+
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.hit(state, renderer, layout.bounds(), position)
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Svg<'a, Theme>> for Element<'a, Message, Theme, Renderer>
@@ -281,6 +596,15 @@ where
     }
 }
 
+#[derive(Debug, Default)]
+struct State {
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    mask: RefCell<Option<(MaskKey, Option<Mask>)>>,
+}
+
+type MaskKey = (svg::Id, Option<Rectangle>, Size<u32>);
+
 /// The possible status of an [`Svg`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
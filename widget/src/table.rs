@@ -5,11 +5,15 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::touch;
 use crate::core::widget;
+use crate::core::window;
 use crate::core::{
-    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size, Widget,
+    Alignment, Background, Element, Event, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
 };
 
+use std::collections::BTreeSet;
+
 /// Creates a new [`Table`] with the given columns and rows.
 ///
 /// Columns can be created using the [`column()`] function, while rows can be any
@@ -44,15 +48,24 @@ where
         width: Length::Shrink,
         align_x: alignment::Horizontal::Left,
         align_y: alignment::Vertical::Top,
+        on_sort: None,
+        on_resize: None,
     }
 }
 
 /// A grid-like visual representation of data distributed in columns and rows.
+///
+/// The headers of a [`Table`] can be pressed to sort its rows with [`Column::on_sort`],
+/// and dragged by their separators to resize them with [`Column::on_resize`]. Its rows
+/// can be selected with [`Table::on_row_press`] and [`Table::selected`].
+///
+/// Only the rows of a [`Table`] that are visible in the viewport are drawn, so
+/// large tables can be placed inside a [`scrollable`](crate::scrollable) cheaply.
 pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Theme: Catalog,
 {
-    columns: Vec<Column_>,
+    columns: Vec<Column_<'a, Message>>,
     cells: Vec<Element<'a, Message, Theme, Renderer>>,
     width: Length,
     height: Length,
@@ -60,13 +73,18 @@ where
     padding_y: f32,
     separator_x: f32,
     separator_y: f32,
+    on_row_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    selected: BTreeSet<usize>,
+    last_hovered_row: Option<Option<usize>>,
     class: Theme::Class<'a>,
 }
 
-struct Column_ {
+struct Column_<'a, Message> {
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    on_sort: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
@@ -104,6 +122,8 @@ where
                         width: column.width,
                         align_x: column.align_x,
                         align_y: column.align_y,
+                        on_sort: column.on_sort,
+                        on_resize: column.on_resize,
                     },
                     column.view,
                 )
@@ -136,6 +156,9 @@ where
             padding_y: 5.0,
             separator_x: 1.0,
             separator_y: 1.0,
+            on_row_press: None,
+            selected: BTreeSet::new(),
+            last_hovered_row: None,
             class: Theme::default(),
         }
     }
@@ -183,11 +206,92 @@ where
         self.separator_y = separator.into().0;
         self
     }
+
+    /// Sets the function that will be called when a row of the [`Table`] is pressed.
+    ///
+    /// The function receives the index of the pressed row, without counting the header.
+    /// Pressing a cell that handles the press itself—like a [`button`](crate::button)—will
+    /// not trigger it.
+    pub fn on_row_press(mut self, on_row_press: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_row_press = Some(Box::new(on_row_press));
+        self
+    }
+
+    /// Sets the selected rows of the [`Table`], by index; without counting the header.
+    ///
+    /// Selected rows are highlighted with the `selected_row` background of the [`Style`].
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = rows.into_iter().collect();
+        self
+    }
+
+    /// Returns the index of the row—counting the header—at the given vertical
+    /// position relative to the [`Table`], if any.
+    fn row_at(&self, metrics: &Metrics, y: f32) -> Option<usize> {
+        bands(&metrics.rows, self.padding_y, self.separator_y)
+            .position(|(offset, size)| y >= offset && y < offset + size)
+    }
+
+    /// Returns the index of the column at the given horizontal position
+    /// relative to the [`Table`], if any.
+    fn column_at(&self, metrics: &Metrics, x: f32) -> Option<usize> {
+        bands(&metrics.columns, self.padding_x, self.separator_x)
+            .position(|(offset, size)| x >= offset && x < offset + size)
+    }
+
+    /// Returns the index of the column whose resize handle is at the given position
+    /// relative to the [`Table`], if any.
+    fn resize_handle(&self, metrics: &Metrics, position: Point) -> Option<usize> {
+        const LEEWAY: f32 = 4.0;
+
+        let (_, header_height) = bands(&metrics.rows, self.padding_y, self.separator_y).next()?;
+
+        if position.y < 0.0 || position.y > header_height {
+            return None;
+        }
+
+        bands(&metrics.columns, self.padding_x, self.separator_x)
+            .enumerate()
+            .find(|(column, (offset, width))| {
+                let separator = offset + width + self.separator_x / 2.0;
+
+                self.columns[*column].on_resize.is_some()
+                    && (position.x - separator).abs() <= LEEWAY + self.separator_x / 2.0
+            })
+            .map(|(column, _)| column)
+    }
 }
 
 struct Metrics {
     columns: Vec<f32>,
     rows: Vec<f32>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+/// Returns the offset and the size—including padding—of the bands of a [`Table`]
+/// in one axis, given the sizes of their contents.
+fn bands(sizes: &[f32], padding: f32, separator: f32) -> impl Iterator<Item = (f32, f32)> + '_ {
+    sizes.iter().scan(0.0, move |offset, size| {
+        let band = (*offset, size + padding * 2.0);
+
+        *offset += size + padding * 2.0 + separator;
+
+        Some(band)
+    })
+}
+
+/// Returns whether the given band of a [`Table`] is visible in the viewport.
+fn is_visible(bounds: Rectangle, (offset, size): (f32, f32), viewport: &Rectangle) -> bool {
+    let top = bounds.y + offset;
+
+    top < viewport.y + viewport.height && top + size > viewport.y
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -211,6 +315,7 @@ where
         widget::tree::State::new(Metrics {
             columns: Vec::new(),
             rows: Vec::new(),
+            drag: None,
         })
     }
 
@@ -448,7 +553,7 @@ where
     fn update(
         &mut self,
         tree: &mut widget::Tree,
-        event: &core::Event,
+        event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         renderer: &Renderer,
@@ -456,6 +561,66 @@ where
         shell: &mut core::Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        let bounds = layout.bounds();
+        let metrics = tree.state.downcast_mut::<Metrics>();
+
+        if self.on_row_press.is_some() {
+            let hovered_row = cursor
+                .position_in(bounds)
+                .and_then(|position| self.row_at(metrics, position.y))
+                .filter(|row| *row > 0);
+
+            if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+                self.last_hovered_row = Some(hovered_row);
+            } else if self
+                .last_hovered_row
+                .is_some_and(|last_hovered_row| last_hovered_row != hovered_row)
+            {
+                shell.request_redraw();
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_in(bounds)
+                    && let Some(column) = self.resize_handle(metrics, position)
+                {
+                    metrics.drag = Some(Drag {
+                        column,
+                        origin: position.x,
+                        width: metrics.columns[column],
+                    });
+
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = metrics.drag {
+                    if let Some(position) = cursor.land().position()
+                        && let Some(on_resize) = &self.columns[drag.column].on_resize
+                    {
+                        let width = (drag.width + position.x - bounds.x - drag.origin).max(0.0);
+
+                        shell.publish(on_resize(width));
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if metrics.drag.take().is_some() {
+                    shell.capture_event();
+                    return;
+                }
+            }
+            _ => {}
+        }
+
         for ((cell, tree), layout) in self
             .cells
             .iter_mut()
@@ -466,6 +631,36 @@ where
                 tree, event, layout, cursor, renderer, clipboard, shell, viewport,
             );
         }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && let Some(position) = cursor.position_in(bounds)
+        {
+            let metrics = tree.state.downcast_ref::<Metrics>();
+
+            let Some(row) = self.row_at(metrics, position.y) else {
+                return;
+            };
+
+            let message = if row == 0 {
+                self.column_at(metrics, position.x)
+                    .and_then(|column| self.columns[column].on_sort.as_ref())
+                    .map(|on_sort| on_sort())
+            } else {
+                self.on_row_press
+                    .as_ref()
+                    .map(|on_row_press| on_row_press(row - 1))
+            };
+
+            if let Some(message) = message {
+                shell.publish(message);
+                shell.capture_event();
+            }
+        }
     }
 
     fn draw(
@@ -478,14 +673,65 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((cell, state), layout) in self.cells.iter().zip(&tree.children).zip(layout.children())
+        let bounds = layout.bounds();
+        let metrics = tree.state.downcast_ref::<Metrics>();
+        let columns = self.columns.len();
+
+        let visible: Vec<bool> = bands(&metrics.rows, self.padding_y, self.separator_y)
+            .map(|band| is_visible(bounds, band, viewport))
+            .collect();
+
         {
+            let style = theme.style(&self.class);
+
+            for (row, (offset, height)) in bands(&metrics.rows, self.padding_y, self.separator_y)
+                .enumerate()
+                .skip(1)
+                .filter(|(row, _)| visible[*row])
+            {
+                let row_bounds = Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + offset,
+                    width: bounds.width,
+                    height,
+                };
+
+                let background = if self.selected.contains(&(row - 1)) {
+                    Some(style.selected_row)
+                } else if self.on_row_press.is_some() && cursor.is_over(row_bounds) {
+                    style.hovered_row
+                } else {
+                    None
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row_bounds,
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+            }
+        }
+
+        for (i, ((cell, state), layout)) in self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if !visible.get(i / columns).copied().unwrap_or(true) {
+                continue;
+            }
+
             cell.as_widget()
                 .draw(state, renderer, theme, style, layout, cursor, viewport);
         }
 
-        let bounds = layout.bounds();
-        let metrics = tree.state.downcast_ref::<Metrics>();
         let style = theme.style(&self.class);
 
         if self.separator_x > 0.0 {
@@ -545,16 +791,55 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.cells
+        let bounds = layout.bounds();
+        let metrics = tree.state.downcast_ref::<Metrics>();
+
+        if metrics.drag.is_some()
+            || cursor
+                .position_in(bounds)
+                .and_then(|position| self.resize_handle(metrics, position))
+                .is_some()
+        {
+            return mouse::Interaction::ResizingColumn;
+        }
+
+        let columns = self.columns.len();
+
+        let visible: Vec<bool> = bands(&metrics.rows, self.padding_y, self.separator_y)
+            .map(|band| is_visible(bounds, band, viewport))
+            .collect();
+
+        let interaction = self
+            .cells
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|((cell, tree), layout)| {
+            .enumerate()
+            .filter(|(i, _)| visible.get(i / columns).copied().unwrap_or(true))
+            .map(|(_, ((cell, tree), layout))| {
                 cell.as_widget()
                     .mouse_interaction(tree, layout, cursor, viewport, renderer)
             })
             .max()
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        let Some(position) = cursor.position_in(bounds) else {
+            return interaction;
+        };
+
+        let is_pressable = match self.row_at(metrics, position.y) {
+            Some(0) => self
+                .column_at(metrics, position.x)
+                .is_some_and(|column| self.columns[column].on_sort.is_some()),
+            Some(_) => self.on_row_press.is_some(),
+            None => false,
+        };
+
+        if is_pressable && interaction == mouse::Interaction::None {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
     }
 
     fn operate(
@@ -613,6 +898,8 @@ pub struct Column<'a, 'b, T, Message, Theme = crate::Theme, Renderer = crate::Re
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    on_sort: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
@@ -633,6 +920,30 @@ impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Rend
         self.align_y = alignment.into();
         self
     }
+
+    /// Sets the message that will be produced when the header of the [`Column`]
+    /// is pressed.
+    ///
+    /// This is normally used to sort the rows of a [`Table`] by the [`Column`].
+    /// The [`Table`] does not sort any rows by itself; so you can keep track of
+    /// the sorting order of your data and show it in the header as you see fit.
+    pub fn on_sort(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_sort = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the function that will be called when the [`Column`] is resized by
+    /// dragging the separator next to its header.
+    ///
+    /// The function receives the new width of the contents of the [`Column`],
+    /// which should be set with [`Column::width`] to take effect.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
 }
 
 /// The appearance of a [`Table`].
@@ -642,6 +953,10 @@ pub struct Style {
     pub separator_x: Background,
     /// The background color of the vertical line separator between cells.
     pub separator_y: Background,
+    /// The [`Background`] of the selected rows.
+    pub selected_row: Background,
+    /// The [`Background`] of the hovered row, if rows can be pressed.
+    pub hovered_row: Option<Background>,
 }
 
 /// The theme catalog of a [`Table`].
@@ -685,5 +1000,7 @@ pub fn default(theme: &crate::Theme) -> Style {
     Style {
         separator_x: separator,
         separator_y: separator,
+        selected_row: palette.primary.weak.color.into(),
+        hovered_row: Some(palette.background.weak.color.into()),
     }
 }
@@ -0,0 +1,1115 @@
+//! Tabs display a strip of selectable, closable, and reorderable tabs.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tabs::{Reorder, Tab};
+//! use iced::widget::{column, tabs, text};
+//!
+//! struct State {
+//!     documents: Vec<String>,
+//!     active: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(usize),
+//!     Close(usize),
+//!     Reorder(Reorder),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         tabs(
+//!             state.documents.iter().map(Tab::new),
+//!             Some(state.active),
+//!             Message::Select,
+//!         )
+//!         .on_close(Message::Close)
+//!         .on_reorder(Message::Reorder),
+//!         text(&state.documents[state.active]),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Select(index) => {
+//!             state.active = index;
+//!         }
+//!         Message::Close(index) => {
+//!             let _ = state.documents.remove(index);
+//!             state.active = state.active.min(state.documents.len().saturating_sub(1));
+//!         }
+//!         Message::Reorder(reorder) => {
+//!             reorder.apply(&mut state.documents);
+//!             state.active = reorder.to;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+
+use std::fmt;
+
+const SPACING: f32 = 6.0;
+const INDICATOR_HEIGHT: f32 = 2.0;
+const DRAG_THRESHOLD: f32 = 4.0;
+const MENU_WIDTH: f32 = 200.0;
+
+/// A tab of some [`Tabs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    index: usize,
+    label: String,
+    icon: Option<char>,
+    is_closable: bool,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            index: 0,
+            label: label.into(),
+            icon: None,
+            is_closable: true,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`], a code point of the icon font of
+    /// the [`Tabs`].
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets whether the [`Tab`] can be closed.
+    ///
+    /// Tabs are closable by default, as long as [`Tabs::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+impl fmt::Display for Tab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// A tab that was dragged to a new position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index of the dragged tab.
+    pub from: usize,
+    /// The index the dragged tab should be moved to.
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Applies the [`Reorder`] to the given items, moving the dragged one
+    /// to its new position.
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        if self.from < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to.min(items.len()), item);
+        }
+    }
+}
+
+/// A strip of selectable tabs with icons, labels, and close buttons.
+///
+/// Tabs can be reordered by dragging them when [`Tabs::on_reorder`] is set.
+/// When they do not fit, the strip can be scrolled and an overflow button
+/// lists all of them in a menu.
+///
+/// [`Tabs`] do not display any content; the selected tab can be used to
+/// choose what to display below them—like the panes of a
+/// [`PaneGrid`](crate::PaneGrid).
+pub struct Tabs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    icon_font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Tab`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        bottom: 6.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// Creates new [`Tabs`] with the given tabs, the index of the selected
+    /// one, and a function that produces a message when a tab is selected.
+    pub fn new(
+        tabs: impl IntoIterator<Item = Tab>,
+        selected: Option<usize>,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            tabs: tabs
+                .into_iter()
+                .enumerate()
+                .map(|(index, tab)| Tab { index, ..tab })
+                .collect(),
+            selected,
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            text_size: None,
+            font: None,
+            icon_font: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Adds a [`Tab`] to the [`Tabs`].
+    pub fn push(mut self, tab: Tab) -> Self {
+        let index = self.tabs.len();
+
+        self.tabs.push(Tab { index, ..tab });
+        self
+    }
+
+    /// Sets the message that will be produced when the close button of a
+    /// [`Tab`] is pressed, or when it is middle-clicked.
+    pub fn on_close(mut self, on_close: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Tab`] is dragged to
+    /// a new position.
+    ///
+    /// Tabs cannot be dragged unless this is set.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(Reorder) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each [`Tab`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`Tabs`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Tabs`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the [`Tabs`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font of the icons of the [`Tabs`].
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the overflow menu of the [`Tabs`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the overflow menu of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size_or_default(renderer))
+            .0
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        font: Renderer::Font,
+        renderer: &Renderer,
+    ) -> Text<&'b str, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, self.line_height(renderer)),
+            size: self.text_size_or_default(renderer),
+            line_height: text::LineHeight::default(),
+            font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        font: Renderer::Font,
+        bounds: Rectangle,
+        color: Color,
+        viewport: &Rectangle,
+    ) {
+        let text = self.text(content, font, renderer);
+
+        renderer.fill_text(
+            text.with_content(content.to_owned()),
+            bounds.center(),
+            color,
+            *viewport,
+        );
+    }
+
+    fn is_closable(&self, tab: &Tab) -> bool {
+        tab.is_closable && self.on_close.is_some()
+    }
+
+    /// Lays out a [`Tab`] with its icon, label, and close button as children.
+    fn layout_tab(&self, tab: &Tab, renderer: &Renderer) -> layout::Node {
+        let line_height = self.line_height(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+
+        let measure = |content: &str, font| {
+            Renderer::Paragraph::with_text(self.text(content, font, renderer)).min_width()
+        };
+
+        let mut x = self.padding.left;
+        let y = self.padding.top;
+
+        let icon = if let Some(icon) = tab.icon {
+            let node = layout::Node::new(Size::new(
+                measure(&icon.to_string(), icon_font),
+                line_height,
+            ))
+            .move_to(Point::new(x, y));
+
+            x += node.size().width + SPACING;
+
+            node
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let label = layout::Node::new(Size::new(measure(&tab.label, font), line_height))
+            .move_to(Point::new(x, y));
+
+        x += label.size().width;
+
+        let close = if self.is_closable(tab) {
+            x += SPACING;
+
+            let node =
+                layout::Node::new(Size::new(line_height, line_height)).move_to(Point::new(x, y));
+
+            x += line_height;
+
+            node
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        layout::Node::with_children(
+            Size::new(x + self.padding.right, line_height + self.padding.y()),
+            vec![icon, label, close],
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offset: f32,
+    selected: Option<usize>,
+    drag: Option<Drag>,
+    is_open: bool,
+    menu: menu::State,
+    hovered_option: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    is_dragging: bool,
+}
+
+/// The visible parts of some [`Tabs`].
+struct Regions<'a> {
+    tabs: Layout<'a>,
+    overflow: Option<Rectangle>,
+    strip: Rectangle,
+    max_offset: f32,
+}
+
+impl<'a> Regions<'a> {
+    fn new(layout: Layout<'a>) -> Self {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let tabs = children.next().unwrap();
+        let overflow = children
+            .next()
+            .map(|overflow| overflow.bounds())
+            .filter(|overflow| overflow.width > 0.0);
+
+        let strip = Rectangle {
+            width: overflow.map_or(bounds.width, |overflow| overflow.x - bounds.x),
+            ..bounds
+        };
+
+        let max_offset = (tabs.bounds().width - strip.width).max(0.0);
+
+        Self {
+            tabs,
+            overflow,
+            strip,
+            max_offset,
+        }
+    }
+
+    /// Returns the index of the tab under the given position, if visible.
+    fn tab_at(&self, offset: f32, position: Point) -> Option<(usize, Layout<'a>)> {
+        if !self.strip.contains(position) {
+            return None;
+        }
+
+        let position = position + Vector::new(offset, 0.0);
+
+        self.tabs
+            .children()
+            .enumerate()
+            .find(|(_, tab)| tab.bounds().contains(position))
+    }
+
+    /// Returns the index a dragged tab should be moved to when dropped at
+    /// the given horizontal position.
+    fn drop_target(&self, offset: f32, from: usize, x: f32) -> usize {
+        let x = x + offset;
+
+        self.tabs
+            .children()
+            .enumerate()
+            .filter(|(index, tab)| *index != from && tab.bounds().center_x() < x)
+            .count()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let height = self.line_height(renderer) + self.padding.y();
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let node = self.layout_tab(tab, renderer).move_to(Point::new(x, 0.0));
+
+                x += node.size().width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let total = (x - self.spacing).max(0.0);
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(total, height));
+
+        let overflow = if total > size.width {
+            let width = self.line_height(renderer) + self.padding.x();
+
+            layout::Node::new(Size::new(width, height)).move_to(Point::new(size.width - width, 0.0))
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let visible = if overflow.size().width > 0.0 {
+            overflow.bounds().x
+        } else {
+            size.width
+        };
+
+        // Scroll the selected tab into view when the selection changes
+        if state.selected != self.selected {
+            state.selected = self.selected;
+
+            if let Some(tab) = self.selected.and_then(|index| tabs.get(index)) {
+                let bounds = tab.bounds();
+
+                if bounds.x < state.offset {
+                    state.offset = bounds.x;
+                } else if bounds.x + bounds.width > state.offset + visible {
+                    state.offset = bounds.x + bounds.width - visible;
+                }
+            }
+        }
+
+        state.offset = state.offset.clamp(0.0, (total - visible).max(0.0));
+
+        layout::Node::with_children(
+            size,
+            vec![
+                layout::Node::with_children(Size::new(total, height), tabs),
+                overflow,
+            ],
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let regions = Regions::new(layout);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(button)) if state.is_open => {
+                // The event was not captured by the menu, so the cursor
+                // was pressed outside of it
+                state.is_open = false;
+
+                if *button == mouse::Button::Left
+                    && regions
+                        .overflow
+                        .is_some_and(|overflow| cursor.is_over(overflow))
+                {
+                    shell.capture_event();
+                }
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if regions
+                    .overflow
+                    .is_some_and(|overflow| overflow.contains(position))
+                {
+                    state.is_open = true;
+                    state.hovered_option = self.selected;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                let Some((index, tab)) = regions.tab_at(state.offset, position) else {
+                    return;
+                };
+
+                let close = tab.children().nth(2).map(|close| close.bounds());
+                let content_position = position + Vector::new(state.offset, 0.0);
+
+                if let Some(on_close) = &self.on_close
+                    && self.is_closable(&self.tabs[index])
+                    && close.is_some_and(|close| close.contains(content_position))
+                {
+                    shell.publish(on_close(index));
+                } else {
+                    if self.selected != Some(index) {
+                        shell.publish((self.on_select)(index));
+                    }
+
+                    if self.on_reorder.is_some() {
+                        state.drag = Some(Drag {
+                            index,
+                            origin: position,
+                            is_dragging: false,
+                        });
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(on_close) = &self.on_close
+                    && let Some((index, _)) = cursor
+                        .position()
+                        .and_then(|position| regions.tab_at(state.offset, position))
+                    && self.is_closable(&self.tabs[index])
+                {
+                    shell.publish(on_close(index));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    if !drag.is_dragging
+                        && cursor
+                            .position()
+                            .is_some_and(|position| position.distance(drag.origin) > DRAG_THRESHOLD)
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        shell.capture_event();
+                    }
+                }
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                if drag.is_dragging
+                    && let Some(on_reorder) = &self.on_reorder
+                    && let Some(position) = cursor.position()
+                {
+                    let to = regions.drop_target(state.offset, drag.index, position.x);
+
+                    if to != drag.index {
+                        shell.publish(on_reorder(Reorder {
+                            from: drag.index,
+                            to,
+                        }));
+                    }
+
+                    shell.capture_event();
+                }
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if regions.max_offset == 0.0 || !cursor.is_over(layout.bounds()) {
+                    return;
+                }
+
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => (if x == 0.0 { y } else { x }) * 40.0,
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                state.offset = (state.offset - delta).clamp(0.0, regions.max_offset);
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+        let regions = Regions::new(layout);
+        let bounds = layout.bounds();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let drag = state.drag.filter(|drag| drag.is_dragging);
+        let hovered = cursor
+            .position()
+            .filter(|_| drag.is_none() && !state.is_open)
+            .and_then(|position| regions.tab_at(state.offset, position))
+            .map(|(index, _)| index);
+
+        let cursor_position = cursor
+            .position()
+            .map(|position| position + Vector::new(state.offset, 0.0));
+
+        let draw_tab = |renderer: &mut Renderer, tab: &Tab, layout: Layout<'_>, is_lifted: bool| {
+            let tab_bounds = layout.bounds();
+            let is_selected = self.selected == Some(tab.index);
+            let is_hovered = hovered == Some(tab.index);
+
+            let background = if is_selected || is_lifted {
+                Some(style.selected_background)
+            } else if is_hovered {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab_bounds,
+                        border: style.tab_border,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: tab_bounds.y + tab_bounds.height - INDICATOR_HEIGHT,
+                            height: INDICATOR_HEIGHT,
+                            ..tab_bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.indicator_color,
+                );
+            }
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut parts = layout.children();
+            let icon = parts.next().unwrap().bounds();
+            let label = parts.next().unwrap().bounds();
+            let close = parts.next().unwrap().bounds();
+
+            if let Some(code_point) = tab.icon {
+                self.fill_text(
+                    renderer,
+                    &code_point.to_string(),
+                    icon_font,
+                    icon,
+                    text_color,
+                    &tab_bounds,
+                );
+            }
+
+            self.fill_text(renderer, &tab.label, font, label, text_color, &tab_bounds);
+
+            if close.width > 0.0 {
+                let is_over_close =
+                    !is_lifted && cursor_position.is_some_and(|position| close.contains(position));
+
+                if is_over_close {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: close,
+                            border: style.tab_border,
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_background,
+                    );
+                }
+
+                self.fill_text(
+                    renderer,
+                    "×",
+                    font,
+                    close,
+                    if is_over_close || is_selected || is_hovered {
+                        text_color
+                    } else {
+                        style.close_color
+                    },
+                    &tab_bounds,
+                );
+            }
+        };
+
+        let strip = regions.strip;
+
+        renderer.with_layer(strip, |renderer| {
+            renderer.with_translation(Vector::new(-state.offset, 0.0), |renderer| {
+                for (tab, layout) in self.tabs.iter().zip(regions.tabs.children()) {
+                    if drag.is_some_and(|drag| drag.index == tab.index) {
+                        continue;
+                    }
+
+                    draw_tab(renderer, tab, layout, false);
+                }
+
+                let Some(drag) = drag else {
+                    return;
+                };
+
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                let to = regions.drop_target(state.offset, drag.index, position.x);
+
+                let mut remaining = regions
+                    .tabs
+                    .children()
+                    .enumerate()
+                    .filter(|(index, _)| *index != drag.index)
+                    .map(|(_, layout)| layout.bounds());
+
+                let marker = match remaining.nth(to) {
+                    Some(next) => next.x - self.spacing / 2.0,
+                    None => {
+                        regions.tabs.bounds().x + regions.tabs.bounds().width + self.spacing / 2.0
+                    }
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: marker - INDICATOR_HEIGHT / 2.0,
+                            y: strip.y,
+                            width: INDICATOR_HEIGHT,
+                            height: strip.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.indicator_color,
+                );
+
+                if let (Some(tab), Some(layout)) = (
+                    self.tabs.get(drag.index),
+                    regions.tabs.children().nth(drag.index),
+                ) {
+                    let translation = Vector::new(position.x - drag.origin.x, 0.0);
+
+                    renderer.with_translation(translation, |renderer| {
+                        draw_tab(renderer, tab, layout, true);
+                    });
+                }
+            });
+        });
+
+        if let Some(overflow) = regions.overflow {
+            if state.is_open || cursor.is_over(overflow) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: overflow,
+                        border: style.tab_border,
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_background,
+                );
+            }
+
+            self.fill_text(renderer, "▾", font, overflow, style.text_color, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let regions = Regions::new(layout);
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let is_over_tab = cursor
+            .position()
+            .and_then(|position| regions.tab_at(state.offset, position))
+            .is_some();
+
+        if is_over_tab
+            || regions
+                .overflow
+                .is_some_and(|overflow| cursor.is_over(overflow))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let overflow = Regions::new(layout).overflow?;
+        let bounds = layout.bounds();
+        let width = MENU_WIDTH.min(bounds.width);
+        let on_select = &self.on_select;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &self.tabs,
+            &mut state.hovered_option,
+            |tab| {
+                state.is_open = false;
+
+                (on_select)(tab.index)
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(width)
+        .padding(self.padding)
+        .font(self.font.unwrap_or_else(|| renderer.default_font()));
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(
+            Point::new(overflow.x + overflow.width - width, bounds.y) + translation,
+            *viewport,
+            bounds.height,
+            Length::Shrink,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+/// The appearance of some [`Tabs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the strip.
+    pub background: Background,
+    /// The [`Border`] of the strip.
+    pub border: Border,
+    /// The [`Border`] of each tab.
+    pub tab_border: Border,
+    /// The text [`Color`] of the tabs.
+    pub text_color: Color,
+    /// The [`Background`] of a hovered tab or button.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected tab.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected tab.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the indicator below the selected tab and of the
+    /// drop marker of a dragged tab.
+    pub indicator_color: Color,
+    /// The [`Color`] of the close buttons of inactive tabs.
+    pub close_color: Color,
+}
+
+/// The theme catalog of some [`Tabs`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the overflow menu of the [`Tabs`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for some [`Tabs`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of some [`Tabs`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default(),
+        tab_border: Border::default().rounded(4.0),
+        text_color: palette.background.weak.text,
+        hovered_background: palette.background.strong.color.into(),
+        selected_background: palette.background.base.color.into(),
+        selected_text_color: palette.background.base.text,
+        indicator_color: palette.primary.base.color,
+        close_color: palette.background.strongest.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorders_apply_to_items() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, ['b', 'c', 'a', 'd']);
+
+        Reorder { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+
+        Reorder { from: 1, to: 9 }.apply(&mut items);
+        assert_eq!(items, ['d', 'c', 'a', 'b']);
+    }
+}
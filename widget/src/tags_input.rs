@@ -0,0 +1,921 @@
+//! Tags inputs let users type a list of tags, displayed as removable chips.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tags_input;
+//!
+//! struct State {
+//!    tags: Vec<String>,
+//!    languages: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     TagAdded(String),
+//!     TagRemoved(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tags_input(&state.tags, Message::TagAdded)
+//!         .on_remove(Message::TagRemoved)
+//!         .suggestions(&state.languages)
+//!         .placeholder("Add a language...")
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::TagAdded(tag) => {
+//!             state.tags.push(tag);
+//!         }
+//!         Message::TagRemoved(index) => {
+//!             state.tags.remove(index);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector,
+};
+use crate::overlay::menu::{self, Menu};
+use crate::text_input::{self, TextInput};
+
+const REMOVE_ICON: &str = "×";
+const MIN_INPUT_WIDTH: f32 = 80.0;
+
+/// A field where users type tags, which are displayed as chips.
+///
+/// Pressing enter—or typing one of its [`delimiters`](Self::delimiters)—
+/// turns the typed text into a tag. Pasted text is split on the delimiters
+/// too, and tags that are already present are never added twice.
+///
+/// Pressing backspace with no text typed removes the last tag, as long as
+/// [`on_remove`](Self::on_remove) is set.
+pub struct TagsInput<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tags: &'a [String],
+    on_add: Box<dyn Fn(String) -> Message + 'a>,
+    on_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    suggestions: &'a [String],
+    delimiters: Vec<char>,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    input: TextInput<'a, Edit, Theme, Renderer>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TagsInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`TagsInput`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(4.0);
+
+    /// The default padding of the chips of a [`TagsInput`].
+    const CHIP_PADDING: Padding = Padding {
+        top: 2.0,
+        bottom: 2.0,
+        left: 6.0,
+        right: 6.0,
+    };
+
+    /// Creates a new [`TagsInput`] with the given tags and the function that
+    /// produces a message when a tag is added.
+    pub fn new(tags: &'a [String], on_add: impl Fn(String) -> Message + 'a) -> Self {
+        Self {
+            tags,
+            on_add: Box::new(on_add),
+            on_remove: None,
+            suggestions: &[],
+            delimiters: vec![',', ';'],
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 4.0,
+            text_size: None,
+            font: None,
+            input: TextInput::new("", ""),
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Sets the function that produces a message when the tag at the given
+    /// index is removed.
+    ///
+    /// If this method is not called, the chips of the [`TagsInput`] cannot
+    /// be removed.
+    pub fn on_remove(mut self, on_remove: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the suggestions of the [`TagsInput`].
+    ///
+    /// The suggestions containing the typed text are displayed in a menu
+    /// below the [`TagsInput`].
+    pub fn suggestions(mut self, suggestions: &'a [String]) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Sets the characters that split the typed or pasted text into tags.
+    ///
+    /// By default, they are a comma (`,`) and a semicolon (`;`).
+    pub fn delimiters(mut self, delimiters: impl IntoIterator<Item = char>) -> Self {
+        self.delimiters = delimiters.into_iter().collect();
+        self
+    }
+
+    /// Sets the placeholder of the [`TagsInput`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`TagsInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TagsInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the chips of the [`TagsInput`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`TagsInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`TagsInput`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TagsInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the suggestions menu of the [`TagsInput`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TagsInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the suggestions menu of the [`TagsInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn input(&self, buffer: &str) -> TextInput<'a, Edit, Theme, Renderer> {
+        let placeholder = if self.tags.is_empty() {
+            self.placeholder.as_str()
+        } else {
+            ""
+        };
+
+        let input = TextInput::new(placeholder, buffer)
+            .on_input(Edit::Input)
+            .on_paste(Edit::Paste)
+            .padding([Self::CHIP_PADDING.top, 2.0])
+            .width(Length::Fill)
+            .class(Theme::default_input());
+
+        let input = match self.text_size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        }
+    }
+
+    fn text<'b>(&self, content: &'b str, renderer: &Renderer) -> Text<&'b str, Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        Text {
+            content,
+            bounds: Size::new(
+                f32::INFINITY,
+                text::LineHeight::default().to_absolute(size).0,
+            ),
+            size,
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    /// Lays out a chip with its label and remove button as children.
+    fn layout_chip(&self, tag: &str, renderer: &Renderer) -> layout::Node {
+        let measure = |content| Renderer::Paragraph::with_text(self.text(content, renderer));
+
+        let label = measure(tag).min_bounds();
+        let padding = Self::CHIP_PADDING;
+
+        let label = layout::Node::new(label).move_to(Point::new(padding.left, padding.top));
+
+        let remove = if self.on_remove.is_some() {
+            let size = measure(REMOVE_ICON).min_bounds();
+
+            layout::Node::new(size).move_to(Point::new(
+                label.bounds().x + label.bounds().width + self.spacing,
+                padding.top,
+            ))
+        } else {
+            layout::Node::new(Size::ZERO)
+                .move_to(Point::new(label.bounds().x + label.bounds().width, 0.0))
+        };
+
+        let width = remove.bounds().x + remove.bounds().width + padding.right;
+        let height = label.size().height + padding.y();
+
+        layout::Node::with_children(Size::new(width, height), vec![label, remove])
+    }
+
+    /// Returns the suggestions matching the given text that are not a tag yet.
+    fn filter(&self, buffer: &str) -> Vec<String> {
+        let query = buffer.trim().to_lowercase();
+
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.suggestions
+            .iter()
+            .filter(|suggestion| {
+                suggestion.to_lowercase().contains(&query) && !self.tags.contains(suggestion)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Splits the given text on the delimiters of the [`TagsInput`],
+    /// returning the complete tags and the remaining text.
+    fn split<'b>(&self, text: &'b str) -> (Vec<&'b str>, &'b str) {
+        let mut tags: Vec<_> = text.split(self.delimiters.as_slice()).collect();
+        let rest = tags.pop().unwrap_or_default();
+
+        (tags, rest)
+    }
+
+    /// Publishes the given tags, skipping the empty and duplicated ones.
+    fn add<'b>(&self, tags: impl IntoIterator<Item = &'b str>, shell: &mut Shell<'_, Message>) {
+        let mut added = Vec::new();
+
+        for tag in tags {
+            let tag = tag.trim();
+
+            if tag.is_empty()
+                || self.tags.iter().any(|existing| existing == tag)
+                || added.contains(&tag)
+            {
+                continue;
+            }
+
+            shell.publish((self.on_add)(tag.to_owned()));
+            added.push(tag);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Paste(String),
+}
+
+#[derive(Debug, Default)]
+struct State {
+    buffer: String,
+    filtered: Vec<String>,
+    menu: menu::State,
+    hovered_option: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TagsInput<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.filtered = self.filter(&state.buffer);
+        state.hovered_option = state
+            .hovered_option
+            .filter(|index| *index < state.filtered.len());
+
+        self.input = self.input(&state.buffer);
+
+        let width = limits.width(self.width).max().width;
+        let inner_width = width - self.padding.x();
+
+        let mut chips = Vec::with_capacity(self.tags.len());
+        let mut position = Point::new(self.padding.left, self.padding.top);
+        let mut row_height = 0.0f32;
+
+        for tag in self.tags {
+            let chip = self.layout_chip(tag, renderer);
+            let size = chip.size();
+
+            if position.x > self.padding.left
+                && position.x + size.width > self.padding.left + inner_width
+            {
+                position = Point::new(self.padding.left, position.y + row_height + self.spacing);
+                row_height = 0.0;
+            }
+
+            chips.push(chip.move_to(position));
+
+            position.x += size.width + self.spacing;
+            row_height = row_height.max(size.height);
+        }
+
+        // The input takes the rest of the last row, if there is enough room
+        if position.x > self.padding.left
+            && self.padding.left + inner_width - position.x < MIN_INPUT_WIDTH
+        {
+            position = Point::new(self.padding.left, position.y + row_height + self.spacing);
+            row_height = 0.0;
+        }
+
+        let input_width = self.padding.left + inner_width - position.x;
+
+        let input = self
+            .input
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(input_width, f32::INFINITY)),
+                None,
+            )
+            .move_to(position);
+
+        let height = position.y + row_height.max(input.size().height) + self.padding.bottom;
+
+        layout::Node::with_children(
+            Size::new(width, height),
+            std::iter::once(input).chain(chips).collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input.operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if is_focused
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) = event
+        {
+            match named {
+                key::Named::Enter => {
+                    match state
+                        .hovered_option
+                        .and_then(|index| state.filtered.get(index))
+                    {
+                        Some(suggestion) => self.add([suggestion.as_str()], shell),
+                        None => self.add([state.buffer.as_str()], shell),
+                    }
+
+                    state.buffer.clear();
+                    state.hovered_option = None;
+
+                    shell.invalidate_layout();
+                    shell.capture_event();
+                    return;
+                }
+                key::Named::ArrowUp | key::Named::ArrowDown if !state.filtered.is_empty() => {
+                    let last = state.filtered.len() - 1;
+
+                    state.hovered_option = Some(match (named, state.hovered_option) {
+                        (key::Named::ArrowUp, Some(0) | None) => last,
+                        (key::Named::ArrowUp, Some(index)) => index - 1,
+                        (_, Some(index)) if index < last => index + 1,
+                        _ => 0,
+                    });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                key::Named::Backspace if state.buffer.is_empty() => {
+                    if let Some(on_remove) = &self.on_remove
+                        && !self.tags.is_empty()
+                    {
+                        shell.publish(on_remove(self.tags.len() - 1));
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+                key::Named::Escape if state.hovered_option.is_some() => {
+                    state.hovered_option = None;
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        self.input.update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for edit in edits {
+            match edit {
+                Edit::Input(text) => {
+                    let (tags, rest) = self.split(&text);
+
+                    self.add(tags, shell);
+                    state.buffer = rest.to_owned();
+                }
+                Edit::Paste(text) => {
+                    let (tags, rest) = self.split(&text);
+
+                    if tags.is_empty() {
+                        state.buffer = text;
+                    } else {
+                        self.add(tags.into_iter().chain([rest]), shell);
+                        state.buffer.clear();
+                    }
+                }
+            }
+
+            state.hovered_option = None;
+            shell.invalidate_layout();
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && let Some(position) = cursor.position_over(layout.bounds())
+        {
+            if let Some(on_remove) = &self.on_remove
+                && let Some(index) = layout.children().skip(1).position(|chip| {
+                    chip.children()
+                        .nth(1)
+                        .is_some_and(|remove| remove.bounds().contains(position))
+                })
+            {
+                shell.publish(on_remove(index));
+            } else {
+                // Pressing anywhere in the field focuses the input
+                let input = tree.children[0]
+                    .state
+                    .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+                input.focus();
+                input.move_cursor_to_end();
+
+                shell.request_redraw();
+            }
+
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let is_over_remove = self.on_remove.is_some()
+            && layout.children().skip(1).any(|chip| {
+                chip.children()
+                    .nth(1)
+                    .is_some_and(|remove| remove.bounds().contains(position))
+            });
+
+        if is_over_remove {
+            return mouse::Interaction::Pointer;
+        }
+
+        match self.input.mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        ) {
+            mouse::Interaction::None => mouse::Interaction::Text,
+            interaction => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let status = if is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = <Theme as Catalog>::style(theme, &self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let mut children = layout.children();
+        let input = children.next().unwrap();
+
+        for (tag, chip) in self.tags.iter().zip(children) {
+            let mut parts = chip.children();
+            let label = parts.next().unwrap().bounds();
+            let remove = parts.next().unwrap().bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: chip.bounds(),
+                    border: style.chip_border,
+                    ..renderer::Quad::default()
+                },
+                style.chip_background,
+            );
+
+            renderer.fill_text(
+                self.text(tag, renderer).with_content(tag.clone()),
+                label.center(),
+                style.chip_text_color,
+                *viewport,
+            );
+
+            if self.on_remove.is_some() {
+                let color = if cursor.is_over(remove) {
+                    style.remove_hovered_color
+                } else {
+                    style.chip_text_color
+                };
+
+                renderer.fill_text(
+                    self.text(REMOVE_ICON, renderer)
+                        .with_content(REMOVE_ICON.to_owned()),
+                    remove.center(),
+                    color,
+                    *viewport,
+                );
+            }
+        }
+
+        self.input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input,
+            cursor,
+            None,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !is_focused || state.filtered.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let on_add = &self.on_add;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &state.filtered,
+            &mut state.hovered_option,
+            |suggestion| {
+                state.buffer.clear();
+
+                on_add(suggestion)
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(bounds.width);
+
+        if let Some(font) = self.font {
+            menu = menu.font(font);
+        }
+
+        if let Some(size) = self.text_size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            Length::Shrink,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TagsInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tags_input: TagsInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tags_input)
+    }
+}
+
+/// The possible status of a [`TagsInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TagsInput`] can be interacted with.
+    Active,
+    /// The [`TagsInput`] is being hovered.
+    Hovered,
+    /// The [`TagsInput`] is focused.
+    Focused,
+}
+
+/// The appearance of a [`TagsInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`TagsInput`].
+    pub background: Background,
+    /// The [`Border`] of the [`TagsInput`].
+    pub border: Border,
+    /// The [`Background`] of the chips.
+    pub chip_background: Background,
+    /// The [`Border`] of the chips.
+    pub chip_border: Border,
+    /// The text [`Color`] of the chips.
+    pub chip_text_color: Color,
+    /// The [`Color`] of the remove button of a chip, while hovered.
+    pub remove_hovered_color: Color,
+}
+
+/// The theme catalog of a [`TagsInput`].
+pub trait Catalog: text_input::Catalog + menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the text input of the [`TagsInput`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The default class for the suggestions menu of the [`TagsInput`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TagsInput`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        Box::new(|theme, status| text_input::Style {
+            background: Background::Color(Color::TRANSPARENT),
+            border: Border::default(),
+            ..text_input::default(theme, status)
+        })
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TagsInput`].
+pub fn default(theme: &crate::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        chip_background: palette.primary.weak.color.into(),
+        chip_border: Border {
+            radius: 2.0.into(),
+            ..Border::default()
+        },
+        chip_text_color: palette.primary.weak.text,
+        remove_hovered_color: palette.danger.base.color,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            border: Border {
+                color: palette.background.base.text,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Focused => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+    }
+}
@@ -0,0 +1,1038 @@
+//! Terminals display the output of a program in a grid of cells and send it
+//! the input of the user.
+//!
+//! A [`Terminal`] understands the escape sequences of VT100 and xterm, but it
+//! does not spawn any program by itself. Run the program in a PTY, feed its
+//! output to the [`State`] of the terminal, and write the bytes of every
+//! [`Action::Write`] back to it. Usually, the output is read in a
+//! subscription.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! # fn write(_bytes: &[u8]) {}
+//! use iced::widget::terminal::{self, Terminal};
+//!
+//! struct State {
+//!     terminal: terminal::State,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Output(Vec<u8>),
+//!     Terminal(terminal::Action),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     Terminal::new(&state.terminal)
+//!         .on_action(Message::Terminal)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Output(bytes) => {
+//!             let replies = state.terminal.feed(&bytes);
+//!             write(&replies);
+//!         }
+//!         Message::Terminal(action) => {
+//!             if let terminal::Action::Write(bytes) = &action {
+//!                 write(bytes);
+//!             }
+//!
+//!             state.terminal.perform(action);
+//!         }
+//!     }
+//! }
+//! ```
+mod grid;
+
+pub mod state;
+
+pub use state::State;
+
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::clipboard::{self, Clipboard};
+use crate::core::font::{self, Font};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph as _, Text};
+use crate::core::widget::operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::window;
+use crate::core::{
+    Border, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Theme,
+};
+
+use grid::{Cell, Pen, Tint};
+
+use std::marker::PhantomData;
+
+/// A grid of cells displaying the output of a program, like a shell.
+///
+/// Click and drag to select text; double-click selects words and
+/// triple-click selects lines. Copy with `Ctrl+Shift+C` and paste with
+/// `Ctrl+Shift+V` (or with the logo key on macOS). Hyperlinks are opened
+/// by clicking them while holding the command key.
+pub struct Terminal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    state: &'a State,
+    id: Option<widget::Id>,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    font: Font,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    class: Theme::Class<'a>,
+    renderer_: PhantomData<Renderer>,
+}
+
+/// An interaction with a [`Terminal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Some bytes need to be written to the program, like typed keys or
+    /// pasted text.
+    Write(Vec<u8>),
+    /// The view was scrolled by the given amount of lines; positive values
+    /// scroll back into the history.
+    Scroll(isize),
+    /// A selection was started at the given [`Position`].
+    Select(Position),
+    /// The word at the given [`Position`] was selected.
+    SelectWord(Position),
+    /// The line at the given [`Position`] was selected.
+    SelectLine(Position),
+    /// The selection was extended to the given [`Position`].
+    Drag(Position),
+    /// The selection was cleared.
+    Deselect,
+    /// The [`Terminal`] was resized to fit the given amount of columns and
+    /// rows.
+    ///
+    /// The PTY of the program should be resized as well.
+    Resize {
+        /// The new amount of columns.
+        columns: usize,
+        /// The new amount of rows.
+        rows: usize,
+    },
+    /// A hyperlink was clicked.
+    Open(String),
+}
+
+/// The position of a visible cell of a [`Terminal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// The row of the cell, from the top.
+    pub row: usize,
+    /// The column of the cell, from the left.
+    pub column: usize,
+}
+
+impl<'a, Message, Theme, Renderer> Terminal<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    /// Creates a new [`Terminal`] displaying the given [`State`].
+    pub fn new(state: &'a State) -> Self {
+        Self {
+            state,
+            id: None,
+            on_action: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(4.0),
+            font: Font::MONOSPACE,
+            text_size: None,
+            line_height: LineHeight::default(),
+            class: Theme::default(),
+            renderer_: PhantomData,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Terminal`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the function that produces a message for every [`Action`] of
+    /// the [`Terminal`].
+    ///
+    /// Without it, the [`Terminal`] is read-only.
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets the width of the [`Terminal`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Terminal`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Terminal`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Terminal`].
+    ///
+    /// It should be monospaced. By default, it is [`Font::MONOSPACE`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the text size of the [`Terminal`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Terminal`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the style of the [`Terminal`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Terminal`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn text<'b>(&self, content: &'b str, font: Font, renderer: &Renderer) -> Text<&'b str, Font> {
+        Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size_or_default(renderer),
+            line_height: self.line_height,
+            font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: None,
+        }
+    }
+
+    /// Returns the size of a single cell of the [`Terminal`].
+    fn cell_size(&self, renderer: &Renderer) -> Size {
+        let size = self.text_size_or_default(renderer);
+        let paragraph = Renderer::Paragraph::with_text(self.text("M", self.font, renderer));
+
+        Size::new(
+            paragraph.min_bounds().width.max(1.0),
+            self.line_height.to_absolute(size).0.max(1.0),
+        )
+    }
+
+    fn publish(&self, shell: &mut Shell<'_, Message>, action: Action) {
+        if let Some(on_action) = &self.on_action {
+            shell.publish(on_action(action));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Terminal<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Local>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Local::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let local = tree.state.downcast_mut::<Local>();
+        let node = layout::atomic(limits, self.width, self.height);
+
+        let cell = self.cell_size(renderer);
+        let size = node.size();
+        let content = Size::new(
+            (size.width - self.padding.x()).max(0.0),
+            (size.height - self.padding.y()).max(0.0),
+        );
+
+        local.cell = cell;
+        local.grid = (
+            ((content.width / cell.width) as usize).max(1),
+            ((content.height / cell.height) as usize).max(1),
+        );
+
+        node
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let local = tree.state.downcast_mut::<Local>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), local);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let local = tree.state.downcast_mut::<Local>();
+
+        if self.on_action.is_none() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let content = bounds.shrink(self.padding);
+        let cell = local.cell;
+
+        let position = |point: Point| {
+            let column = ((point.x - content.x) / cell.width).max(0.0) as usize;
+            let row = ((point.y - content.y) / cell.height).max(0.0) as usize;
+
+            let (columns, rows) = self.state.size();
+
+            Position {
+                row: row.min(rows.saturating_sub(1)),
+                column: column.min(columns.saturating_sub(1)),
+            }
+        };
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                if local.grid != self.state.size() && local.requested != Some(local.grid) {
+                    local.requested = Some(local.grid);
+
+                    let (columns, rows) = local.grid;
+                    self.publish(shell, Action::Resize { columns, rows });
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let Some(point) = cursor.position_over(bounds) else {
+                    local.is_focused = false;
+                    return;
+                };
+
+                local.is_focused = true;
+
+                let position = position(point);
+
+                match button {
+                    mouse::Button::Left => {
+                        let click = mouse::Click::new(point, *button, local.last_click);
+                        local.last_click = Some(click);
+
+                        let link = self
+                            .state
+                            .cell(position)
+                            .and_then(|cell| cell.link.as_deref());
+
+                        let action = match (click.kind(), link) {
+                            (mouse::click::Kind::Single, Some(link))
+                                if local.modifiers.command() =>
+                            {
+                                Action::Open(link.to_owned())
+                            }
+                            (mouse::click::Kind::Single, _) if local.modifiers.shift() => {
+                                local.is_selecting = true;
+                                Action::Drag(position)
+                            }
+                            (mouse::click::Kind::Single, _) => {
+                                local.is_selecting = true;
+                                Action::Select(position)
+                            }
+                            (mouse::click::Kind::Double, _) => {
+                                local.is_selecting = true;
+                                Action::SelectWord(position)
+                            }
+                            (mouse::click::Kind::Triple, _) => {
+                                local.is_selecting = true;
+                                Action::SelectLine(position)
+                            }
+                        };
+
+                        self.publish(shell, action);
+                    }
+                    mouse::Button::Middle => {
+                        if let Some(text) = clipboard.read(clipboard::Kind::Primary) {
+                            self.publish(
+                                shell,
+                                Action::Write(paste(&text, self.state.is_bracketed_paste())),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if local.is_selecting => {
+                if let Some(point) = cursor.position() {
+                    self.publish(shell, Action::Drag(position(point)));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if local.is_selecting =>
+            {
+                local.is_selecting = false;
+
+                if let Some(selection) = self.state.selection() {
+                    clipboard.write(clipboard::Kind::Primary, selection);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return;
+                }
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y / cell.height,
+                };
+
+                let lines = lines.round() as isize;
+
+                if lines != 0 {
+                    self.publish(shell, Action::Scroll(lines));
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                local.modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                physical_key,
+                ..
+            }) if local.is_focused => {
+                let is_shortcut = (modifiers.control() && modifiers.shift()) || modifiers.logo();
+
+                let action = match (key.as_ref(), key.to_latin(*physical_key)) {
+                    (_, Some('c')) if is_shortcut => {
+                        if let Some(selection) = self.state.selection() {
+                            clipboard.write(clipboard::Kind::Standard, selection);
+                        }
+
+                        None
+                    }
+                    (_, Some('v')) if is_shortcut => clipboard
+                        .read(clipboard::Kind::Standard)
+                        .map(|text| Action::Write(paste(&text, self.state.is_bracketed_paste()))),
+                    (keyboard::Key::Named(key::Named::PageUp), _) if modifiers.shift() => {
+                        Some(Action::Scroll(self.state.size().1 as isize))
+                    }
+                    (keyboard::Key::Named(key::Named::PageDown), _) if modifiers.shift() => {
+                        Some(Action::Scroll(-(self.state.size().1 as isize)))
+                    }
+                    _ => encode(
+                        key.as_ref(),
+                        *modifiers,
+                        text.as_deref(),
+                        self.state.is_application_cursor(),
+                    )
+                    .map(Action::Write),
+                };
+
+                if let Some(action) = action {
+                    self.publish(shell, action);
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let local = tree.state.downcast_ref::<Local>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let content = bounds.shrink(self.padding);
+        let cell = local.cell;
+        let cursor = self.state.cursor();
+
+        renderer.with_layer(clip, |renderer| {
+            for (row, line) in self.state.visible().enumerate() {
+                let y = content.y + row as f32 * cell.height;
+
+                let painted: Vec<(Color, Color)> = line
+                    .cells
+                    .iter()
+                    .enumerate()
+                    .map(|(column, cell)| {
+                        let position = Position { row, column };
+                        let (foreground, background) = colors(cell.pen, &style);
+
+                        if cursor == Some(position) && local.is_focused {
+                            (style.background, style.cursor)
+                        } else if self.state.is_selected(position) {
+                            (foreground, style.selection)
+                        } else {
+                            (foreground, background)
+                        }
+                    })
+                    .collect();
+
+                let mut column = 0;
+
+                while column < painted.len() {
+                    let background = painted[column].1;
+                    let start = column;
+
+                    while column < painted.len() && painted[column].1 == background {
+                        column += 1;
+                    }
+
+                    if background != style.background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: content.x + start as f32 * cell.width,
+                                    y,
+                                    width: (column - start) as f32 * cell.width,
+                                    height: cell.height,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+                }
+
+                let mut column = 0;
+
+                while column < line.cells.len() {
+                    let first = &line.cells[column];
+                    let foreground = painted[column].0;
+                    let start = column;
+
+                    let is_same = |other: &Cell, color: Color| {
+                        color == foreground
+                            && other.pen.is_bold == first.pen.is_bold
+                            && other.pen.is_italic == first.pen.is_italic
+                            && other.pen.is_underlined == first.pen.is_underlined
+                            && other.pen.is_struck == first.pen.is_struck
+                            && other.link.is_some() == first.link.is_some()
+                    };
+
+                    while column < line.cells.len()
+                        && is_same(&line.cells[column], painted[column].0)
+                    {
+                        column += 1;
+                    }
+
+                    let run: String = line.cells[start..column]
+                        .iter()
+                        .map(|cell| cell.character)
+                        .collect();
+
+                    if run.trim().is_empty() {
+                        continue;
+                    }
+
+                    let x = content.x + start as f32 * cell.width;
+                    let width = (column - start) as f32 * cell.width;
+
+                    let font = Font {
+                        weight: if first.pen.is_bold {
+                            font::Weight::Bold
+                        } else {
+                            self.font.weight
+                        },
+                        style: if first.pen.is_italic {
+                            font::Style::Italic
+                        } else {
+                            self.font.style
+                        },
+                        ..self.font
+                    };
+
+                    let text = Text {
+                        bounds: Size::new(f32::INFINITY, cell.height),
+                        ..self.text(&run, font, renderer)
+                    };
+
+                    renderer.fill_text(
+                        text.with_content(run.clone()),
+                        Point::new(x, y),
+                        foreground,
+                        clip,
+                    );
+
+                    let decorations = [
+                        (
+                            first.pen.is_underlined || first.link.is_some(),
+                            cell.height - 1.0,
+                        ),
+                        (first.pen.is_struck, cell.height / 2.0),
+                    ];
+
+                    for (is_enabled, offset) in decorations {
+                        if is_enabled {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x,
+                                        y: y + offset,
+                                        width,
+                                        height: 1.0,
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                foreground,
+                            );
+                        }
+                    }
+                }
+            }
+
+            if let Some(position) = cursor
+                && !local.is_focused
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: content.x + position.column as f32 * cell.width,
+                            y: content.y + position.row as f32 * cell.height,
+                            width: cell.width,
+                            height: cell.height,
+                        },
+                        border: Border {
+                            color: style.cursor,
+                            width: 1.0,
+                            radius: border::Radius::default(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let local = tree.state.downcast_ref::<Local>();
+        let bounds = layout.bounds();
+
+        let Some(point) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let content = bounds.shrink(self.padding);
+
+        let position = Position {
+            row: ((point.y - content.y) / local.cell.height).max(0.0) as usize,
+            column: ((point.x - content.x) / local.cell.width).max(0.0) as usize,
+        };
+
+        let is_link = self
+            .state
+            .cell(position)
+            .is_some_and(|cell| cell.link.is_some());
+
+        if is_link && local.modifiers.command() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Text
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Terminal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(
+        terminal: Terminal<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(terminal)
+    }
+}
+
+/// The local state of a [`Terminal`] widget.
+#[derive(Debug, Clone, Copy, Default)]
+struct Local {
+    cell: Size,
+    grid: (usize, usize),
+    requested: Option<(usize, usize)>,
+    is_focused: bool,
+    is_selecting: bool,
+    last_click: Option<mouse::Click>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl operation::Focusable for Local {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Returns the foreground and background colors of the given [`Pen`].
+fn colors(pen: Pen, style: &Style) -> (Color, Color) {
+    let tint = |tint: Tint, default: Color| match tint {
+        Tint::Default => default,
+        Tint::Indexed(index) => indexed(index, &style.palette),
+        Tint::Rgb(r, g, b) => Color::from_rgb8(r, g, b),
+    };
+
+    let mut foreground = tint(pen.foreground, style.foreground);
+    let mut background = tint(pen.background, style.background);
+
+    if pen.is_inverse {
+        std::mem::swap(&mut foreground, &mut background);
+    }
+
+    if pen.is_dim {
+        foreground = foreground.scale_alpha(0.6);
+    }
+
+    if pen.is_hidden {
+        foreground = background;
+    }
+
+    (foreground, background)
+}
+
+/// Returns the [`Color`] of the 256-color palette at the given index.
+fn indexed(index: u8, palette: &[Color; 16]) -> Color {
+    match index {
+        0..=15 => palette[usize::from(index)],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+
+            Color::from_rgb8(level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        232..=255 => {
+            let gray = 8 + (index - 232) * 10;
+
+            Color::from_rgb8(gray, gray, gray)
+        }
+    }
+}
+
+/// Encodes a pasted text, bracketing it if the program expects it.
+fn paste(text: &str, is_bracketed: bool) -> Vec<u8> {
+    let text = text.replace("\r\n", "\r").replace('\n', "\r");
+
+    if is_bracketed {
+        // Remove any closing bracket to prevent the text from escaping it
+        format!("\x1b[200~{}\x1b[201~", text.replace("\x1b[201~", "")).into_bytes()
+    } else {
+        text.into_bytes()
+    }
+}
+
+/// Encodes a key press as the bytes a program running in a terminal expects.
+fn encode(
+    key: keyboard::Key<&str>,
+    modifiers: keyboard::Modifiers,
+    text: Option<&str>,
+    is_application_cursor: bool,
+) -> Option<Vec<u8>> {
+    use key::Named;
+    use keyboard::Key;
+
+    let modifier = 1
+        + u8::from(modifiers.shift())
+        + u8::from(modifiers.alt()) * 2
+        + u8::from(modifiers.control()) * 4;
+
+    // Cursor keys are prefixed with `SS3` in application mode
+    let cursor = |code: char| {
+        if modifier > 1 {
+            format!("\x1b[1;{modifier}{code}")
+        } else if is_application_cursor {
+            format!("\x1bO{code}")
+        } else {
+            format!("\x1b[{code}")
+        }
+    };
+
+    let tilde = |code: u8| {
+        if modifier > 1 {
+            format!("\x1b[{code};{modifier}~")
+        } else {
+            format!("\x1b[{code}~")
+        }
+    };
+
+    let sequence = match key {
+        Key::Named(Named::Enter) => "\r".to_owned(),
+        Key::Named(Named::Backspace) if modifiers.control() => "\x08".to_owned(),
+        Key::Named(Named::Backspace) => "\x7f".to_owned(),
+        Key::Named(Named::Tab) if modifiers.shift() => "\x1b[Z".to_owned(),
+        Key::Named(Named::Tab) => "\t".to_owned(),
+        Key::Named(Named::Escape) => "\x1b".to_owned(),
+        Key::Named(Named::ArrowUp) => cursor('A'),
+        Key::Named(Named::ArrowDown) => cursor('B'),
+        Key::Named(Named::ArrowRight) => cursor('C'),
+        Key::Named(Named::ArrowLeft) => cursor('D'),
+        Key::Named(Named::Home) => cursor('H'),
+        Key::Named(Named::End) => cursor('F'),
+        Key::Named(Named::Insert) => tilde(2),
+        Key::Named(Named::Delete) => tilde(3),
+        Key::Named(Named::PageUp) => tilde(5),
+        Key::Named(Named::PageDown) => tilde(6),
+        Key::Named(Named::F1) => "\x1bOP".to_owned(),
+        Key::Named(Named::F2) => "\x1bOQ".to_owned(),
+        Key::Named(Named::F3) => "\x1bOR".to_owned(),
+        Key::Named(Named::F4) => "\x1bOS".to_owned(),
+        Key::Named(Named::F5) => tilde(15),
+        Key::Named(Named::F6) => tilde(17),
+        Key::Named(Named::F7) => tilde(18),
+        Key::Named(Named::F8) => tilde(19),
+        Key::Named(Named::F9) => tilde(20),
+        Key::Named(Named::F10) => tilde(21),
+        Key::Named(Named::F11) => tilde(23),
+        Key::Named(Named::F12) => tilde(24),
+        Key::Character(character) if modifiers.control() => {
+            let mut characters = character.chars();
+            let (Some(character), None) = (characters.next(), characters.next()) else {
+                return None;
+            };
+
+            let code = match character.to_ascii_lowercase() {
+                character @ 'a'..='z' => character as u8 & 0x1f,
+                '@' | ' ' | '2' => 0x00,
+                '[' | '3' => 0x1b,
+                '\\' | '4' => 0x1c,
+                ']' | '5' => 0x1d,
+                '^' | '6' => 0x1e,
+                '_' | '7' | '-' => 0x1f,
+                '?' | '8' => 0x7f,
+                _ => return None,
+            };
+
+            if modifiers.alt() {
+                return Some(vec![0x1b, code]);
+            }
+
+            return Some(vec![code]);
+        }
+        _ => {
+            let text = text.filter(|text| !text.chars().any(char::is_control))?;
+
+            if modifiers.alt() {
+                format!("\x1b{text}")
+            } else {
+                text.to_owned()
+            }
+        }
+    };
+
+    Some(sequence.into_bytes())
+}
+
+/// The appearance of a terminal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The default [`Color`] of the background.
+    pub background: Color,
+    /// The default [`Color`] of the text.
+    pub foreground: Color,
+    /// The [`Color`] of the cursor.
+    pub cursor: Color,
+    /// The [`Color`] of the background of the selection.
+    pub selection: Color,
+    /// The 16 colors of the ANSI palette: black, red, green, yellow, blue,
+    /// magenta, cyan, and white, followed by their bright variants.
+    pub palette: [Color; 16],
+}
+
+/// The theme catalog of a [`Terminal`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Terminal`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Terminal`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    let ansi = [
+        [0x00, 0x00, 0x00],
+        [0xcd, 0x31, 0x31],
+        [0x0d, 0xbc, 0x79],
+        [0xe5, 0xe5, 0x10],
+        [0x24, 0x72, 0xc8],
+        [0xbc, 0x3f, 0xbc],
+        [0x11, 0xa8, 0xcd],
+        [0xe5, 0xe5, 0xe5],
+        [0x66, 0x66, 0x66],
+        [0xf1, 0x4c, 0x4c],
+        [0x23, 0xd1, 0x8b],
+        [0xf5, 0xf5, 0x43],
+        [0x3b, 0x8e, 0xea],
+        [0xd6, 0x70, 0xd6],
+        [0x29, 0xb8, 0xdb],
+        [0xff, 0xff, 0xff],
+    ]
+    .map(|[r, g, b]| Color::from_rgb8(r, g, b));
+
+    Style {
+        background: palette.background.base.color,
+        foreground: palette.background.base.text,
+        cursor: palette.primary.base.color,
+        selection: palette.primary.weak.color.scale_alpha(0.5),
+        palette: ansi,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_encoded() {
+        let none = keyboard::Modifiers::empty();
+
+        let encode = |key, modifiers, text| encode(key, modifiers, text, false);
+
+        assert_eq!(
+            encode(
+                keyboard::Key::Character("c"),
+                keyboard::Modifiers::CTRL,
+                None
+            ),
+            Some(vec![0x03])
+        );
+        assert_eq!(
+            encode(keyboard::Key::Named(key::Named::ArrowUp), none, None),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(
+            encode(keyboard::Key::Character("a"), none, Some("a")),
+            Some(b"a".to_vec())
+        );
+        assert_eq!(paste("a\nb", true), b"\x1b[200~a\rb\x1b[201~".to_vec());
+    }
+}
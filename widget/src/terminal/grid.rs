@@ -0,0 +1,751 @@
+//! The grid of cells of a terminal and the escape sequences that drive it.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// The width of a tab stop, in columns.
+const TAB_WIDTH: usize = 8;
+
+/// The color of the text or the background of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tint {
+    /// The default color of the terminal.
+    #[default]
+    Default,
+    /// A color of the 256-color palette.
+    Indexed(u8),
+    /// A true color.
+    Rgb(u8, u8, u8),
+}
+
+/// The graphic rendition of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Pen {
+    pub foreground: Tint,
+    pub background: Tint,
+    pub is_bold: bool,
+    pub is_dim: bool,
+    pub is_italic: bool,
+    pub is_underlined: bool,
+    pub is_inverse: bool,
+    pub is_hidden: bool,
+    pub is_struck: bool,
+}
+
+/// A character of the grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    pub character: char,
+    pub pen: Pen,
+    pub link: Option<Arc<str>>,
+}
+
+impl Cell {
+    /// Creates an empty [`Cell`] painted with the background of the given
+    /// [`Pen`].
+    fn blank(pen: Pen) -> Self {
+        Self {
+            character: ' ',
+            pen: Pen {
+                background: pen.background,
+                ..Pen::default()
+            },
+            link: None,
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::blank(Pen::default())
+    }
+}
+
+/// A row of cells of the grid.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Line {
+    pub cells: Vec<Cell>,
+    /// Whether the [`Line`] continues on the next one.
+    pub is_wrapped: bool,
+}
+
+impl Line {
+    fn new(columns: usize, pen: Pen) -> Self {
+        Self {
+            cells: vec![Cell::blank(pen); columns],
+            is_wrapped: false,
+        }
+    }
+
+    /// Returns the text of the given columns of the [`Line`], without
+    /// trailing whitespace.
+    pub fn text(&self, columns: std::ops::Range<usize>) -> String {
+        let end = columns.end.min(self.cells.len());
+        let start = columns.start.min(end);
+
+        let text: String = self.cells[start..end]
+            .iter()
+            .map(|cell| cell.character)
+            .collect();
+
+        text.trim_end().to_owned()
+    }
+}
+
+/// The terminal modes that programs can toggle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modes {
+    pub bracketed_paste: bool,
+    pub application_cursor: bool,
+    pub show_cursor: bool,
+    pub auto_wrap: bool,
+    pub origin: bool,
+    pub insert: bool,
+}
+
+impl Default for Modes {
+    fn default() -> Self {
+        Self {
+            bracketed_paste: false,
+            application_cursor: false,
+            show_cursor: true,
+            auto_wrap: true,
+            origin: false,
+            insert: false,
+        }
+    }
+}
+
+/// The position and rendition of the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cursor {
+    pub row: usize,
+    pub column: usize,
+    pub pen: Pen,
+    /// Whether the next character wraps to the next line first.
+    is_pending_wrap: bool,
+}
+
+/// The grid of a terminal, with its scrollback and modes.
+#[derive(Debug, Clone)]
+pub struct Screen {
+    pub columns: usize,
+    pub rows: usize,
+    pub lines: Vec<Line>,
+    pub scrollback: VecDeque<Line>,
+    pub max_scrollback: usize,
+    /// The amount of lines dropped from the start of the scrollback.
+    pub dropped: usize,
+    /// The amount of lines pushed to the scrollback.
+    pub pushed: usize,
+    pub cursor: Cursor,
+    pub modes: Modes,
+    pub title: Option<String>,
+    /// The replies to the queries of the program, like cursor reports.
+    pub responses: Vec<u8>,
+    saved: Option<Cursor>,
+    primary: Option<(Vec<Line>, Cursor)>,
+    top: usize,
+    bottom: usize,
+    link: Option<Arc<str>>,
+}
+
+impl Screen {
+    pub fn new(columns: usize, rows: usize, max_scrollback: usize) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        Self {
+            columns,
+            rows,
+            lines: vec![Line::new(columns, Pen::default()); rows],
+            scrollback: VecDeque::new(),
+            max_scrollback,
+            dropped: 0,
+            pushed: 0,
+            cursor: Cursor::default(),
+            modes: Modes::default(),
+            title: None,
+            responses: Vec::new(),
+            saved: None,
+            primary: None,
+            top: 0,
+            bottom: rows,
+            link: None,
+        }
+    }
+
+    /// Returns whether the alternate screen is active.
+    pub fn is_alternate(&self) -> bool {
+        self.primary.is_some()
+    }
+
+    /// Returns the amount of lines of history above the visible lines.
+    pub fn history(&self) -> usize {
+        if self.is_alternate() {
+            0
+        } else {
+            self.scrollback.len()
+        }
+    }
+
+    /// Returns the line at the given index, counting from the start of the
+    /// history.
+    pub fn line(&self, index: usize) -> Option<&Line> {
+        let history = self.history();
+
+        if index < history {
+            self.scrollback.get(index)
+        } else {
+            self.lines.get(index - history)
+        }
+    }
+
+    pub fn resize(&mut self, columns: usize, rows: usize) {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        if columns == self.columns && rows == self.rows {
+            return;
+        }
+
+        let resize = |lines: &mut Vec<Line>| {
+            for line in lines.iter_mut() {
+                line.cells.resize(columns, Cell::default());
+            }
+
+            lines.resize(rows, Line::new(columns, Pen::default()));
+        };
+
+        while self.lines.len() > rows {
+            if self.cursor.row > 0 && self.cursor.row >= rows {
+                let line = self.lines.remove(0);
+                self.cursor.row -= 1;
+
+                if !self.is_alternate() {
+                    self.push_scrollback(line);
+                }
+            } else {
+                let _ = self.lines.pop();
+            }
+        }
+
+        resize(&mut self.lines);
+
+        if let Some((lines, cursor)) = &mut self.primary {
+            lines.truncate(rows);
+            resize(lines);
+
+            cursor.row = cursor.row.min(rows - 1);
+            cursor.column = cursor.column.min(columns - 1);
+        }
+
+        for line in &mut self.scrollback {
+            line.cells.resize(columns, Cell::default());
+        }
+
+        self.columns = columns;
+        self.rows = rows;
+        self.top = 0;
+        self.bottom = rows;
+        self.cursor.row = self.cursor.row.min(rows - 1);
+        self.cursor.column = self.cursor.column.min(columns - 1);
+        self.cursor.is_pending_wrap = false;
+    }
+
+    fn push_scrollback(&mut self, line: Line) {
+        if self.max_scrollback == 0 {
+            self.dropped += 1;
+            return;
+        }
+
+        if self.scrollback.len() >= self.max_scrollback {
+            let _ = self.scrollback.pop_front();
+            self.dropped += 1;
+        }
+
+        self.scrollback.push_back(line);
+        self.pushed += 1;
+    }
+
+    fn blank(&self) -> Line {
+        Line::new(self.columns, self.cursor.pen)
+    }
+
+    fn scroll_up(&mut self, count: usize) {
+        for _ in 0..count.min(self.bottom - self.top) {
+            let line = self.lines.remove(self.top);
+
+            if self.top == 0 && !self.is_alternate() {
+                self.push_scrollback(line);
+            }
+
+            self.lines.insert(self.bottom - 1, self.blank());
+        }
+    }
+
+    fn scroll_down(&mut self, count: usize) {
+        for _ in 0..count.min(self.bottom - self.top) {
+            let _ = self.lines.remove(self.bottom - 1);
+            self.lines.insert(self.top, self.blank());
+        }
+    }
+
+    fn index(&mut self) {
+        self.cursor.is_pending_wrap = false;
+
+        if self.cursor.row + 1 == self.bottom {
+            self.scroll_up(1);
+        } else if self.cursor.row + 1 < self.rows {
+            self.cursor.row += 1;
+        }
+    }
+
+    fn reverse_index(&mut self) {
+        self.cursor.is_pending_wrap = false;
+
+        if self.cursor.row == self.top {
+            self.scroll_down(1);
+        } else {
+            self.cursor.row = self.cursor.row.saturating_sub(1);
+        }
+    }
+
+    /// Moves the cursor to the given position, relative to the scroll region
+    /// in origin mode.
+    fn move_to(&mut self, row: usize, column: usize) {
+        let (top, bottom) = if self.modes.origin {
+            (self.top, self.bottom)
+        } else {
+            (0, self.rows)
+        };
+
+        self.cursor.row = (top + row).min(bottom - 1);
+        self.cursor.column = column.min(self.columns - 1);
+        self.cursor.is_pending_wrap = false;
+    }
+
+    /// Moves the cursor to the given absolute position.
+    fn move_by(&mut self, row: usize, column: usize) {
+        self.cursor.row = row.min(self.rows - 1);
+        self.cursor.column = column.min(self.columns - 1);
+        self.cursor.is_pending_wrap = false;
+    }
+
+    fn row(&mut self) -> &mut Line {
+        &mut self.lines[self.cursor.row]
+    }
+
+    fn erase(&mut self, row: usize, columns: std::ops::Range<usize>) {
+        let blank = Cell::blank(self.cursor.pen);
+        let end = columns.end.min(self.columns);
+
+        for cell in &mut self.lines[row].cells[columns.start.min(end)..end] {
+            *cell = blank.clone();
+        }
+    }
+
+    fn set_alternate(&mut self, is_alternate: bool) {
+        if is_alternate == self.is_alternate() {
+            return;
+        }
+
+        if is_alternate {
+            let blank = vec![Line::new(self.columns, Pen::default()); self.rows];
+            let lines = std::mem::replace(&mut self.lines, blank);
+
+            self.primary = Some((lines, self.cursor));
+        } else if let Some((lines, cursor)) = self.primary.take() {
+            self.lines = lines;
+            self.cursor = cursor;
+        }
+
+        self.top = 0;
+        self.bottom = self.rows;
+    }
+
+    fn reset(&mut self) {
+        *self = Self {
+            scrollback: std::mem::take(&mut self.scrollback),
+            dropped: self.dropped,
+            pushed: self.pushed,
+            title: self.title.take(),
+            responses: std::mem::take(&mut self.responses),
+            ..Self::new(self.columns, self.rows, self.max_scrollback)
+        };
+    }
+
+    fn set_mode(&mut self, mode: u16, is_private: bool, value: bool) {
+        match (is_private, mode) {
+            (false, 4) => self.modes.insert = value,
+            (true, 1) => self.modes.application_cursor = value,
+            (true, 6) => {
+                self.modes.origin = value;
+                self.move_to(0, 0);
+            }
+            (true, 7) => self.modes.auto_wrap = value,
+            (true, 25) => self.modes.show_cursor = value,
+            (true, 47 | 1047) => self.set_alternate(value),
+            (true, 1048) => {
+                if value {
+                    self.saved = Some(self.cursor);
+                } else if let Some(saved) = self.saved {
+                    self.cursor = saved;
+                }
+            }
+            (true, 1049) => {
+                if value {
+                    self.saved = Some(self.cursor);
+                    self.set_alternate(true);
+                } else {
+                    self.set_alternate(false);
+
+                    if let Some(saved) = self.saved {
+                        self.cursor = saved;
+                    }
+                }
+            }
+            (true, 2004) => self.modes.bracketed_paste = value,
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, params: &vte::Params) {
+        let pen = &mut self.cursor.pen;
+
+        if params.is_empty() {
+            *pen = Pen::default();
+            return;
+        }
+
+        let mut params = params.iter();
+
+        while let Some(param) = params.next() {
+            match param[0] {
+                0 => *pen = Pen::default(),
+                1 => pen.is_bold = true,
+                2 => pen.is_dim = true,
+                3 => pen.is_italic = true,
+                4 => pen.is_underlined = param.get(1) != Some(&0),
+                7 => pen.is_inverse = true,
+                8 => pen.is_hidden = true,
+                9 => pen.is_struck = true,
+                21 => pen.is_underlined = true,
+                22 => {
+                    pen.is_bold = false;
+                    pen.is_dim = false;
+                }
+                23 => pen.is_italic = false,
+                24 => pen.is_underlined = false,
+                27 => pen.is_inverse = false,
+                28 => pen.is_hidden = false,
+                29 => pen.is_struck = false,
+                code @ 30..=37 => pen.foreground = Tint::Indexed((code - 30) as u8),
+                38 => {
+                    if let Some(tint) = extended_tint(param, &mut params) {
+                        pen.foreground = tint;
+                    }
+                }
+                39 => pen.foreground = Tint::Default,
+                code @ 40..=47 => pen.background = Tint::Indexed((code - 40) as u8),
+                48 => {
+                    if let Some(tint) = extended_tint(param, &mut params) {
+                        pen.background = tint;
+                    }
+                }
+                49 => pen.background = Tint::Default,
+                code @ 90..=97 => pen.foreground = Tint::Indexed((code - 90 + 8) as u8),
+                code @ 100..=107 => pen.background = Tint::Indexed((code - 100 + 8) as u8),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parses an extended color, either from the subparameters of the given
+/// parameter (`38:5:n`) or from the parameters that follow it (`38;5;n`).
+fn extended_tint(param: &[u16], params: &mut vte::ParamsIter<'_>) -> Option<Tint> {
+    let values: Vec<u16> = if param.len() > 1 {
+        param[1..].to_vec()
+    } else {
+        let kind = *params.next()?.first()?;
+        let count = if kind == 2 { 3 } else { 1 };
+
+        std::iter::once(kind)
+            .chain(
+                params
+                    .take(count)
+                    .filter_map(|param| param.first().copied()),
+            )
+            .collect()
+    };
+
+    let byte = |value: &u16| u8::try_from(*value).unwrap_or(u8::MAX);
+
+    match values.as_slice() {
+        [5, index, ..] => Some(Tint::Indexed(byte(index))),
+        // The color space identifier is optional with subparameters
+        [2, _, r, g, b] | [2, r, g, b] => Some(Tint::Rgb(byte(r), byte(g), byte(b))),
+        _ => None,
+    }
+}
+
+/// Returns the parameter at the given index, or the given default if it is
+/// missing or zero.
+fn argument(params: &vte::Params, index: usize, default: usize) -> usize {
+    params
+        .iter()
+        .nth(index)
+        .and_then(|param| param.first())
+        .copied()
+        .filter(|value| *value != 0)
+        .map_or(default, usize::from)
+}
+
+impl vte::Perform for Screen {
+    fn print(&mut self, character: char) {
+        if self.cursor.is_pending_wrap && self.modes.auto_wrap {
+            self.row().is_wrapped = true;
+            self.index();
+            self.cursor.column = 0;
+        }
+
+        let Cursor {
+            row, column, pen, ..
+        } = self.cursor;
+        let link = self.link.clone();
+
+        let line = &mut self.lines[row];
+
+        if self.modes.insert {
+            let _ = line.cells.pop();
+            line.cells.insert(column, Cell::default());
+        }
+
+        line.cells[column] = Cell {
+            character,
+            pen,
+            link,
+        };
+
+        if column + 1 < self.columns {
+            self.cursor.column += 1;
+        } else {
+            self.cursor.is_pending_wrap = true;
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => {
+                self.cursor.column = self.cursor.column.saturating_sub(1);
+                self.cursor.is_pending_wrap = false;
+            }
+            b'\t' => {
+                let next = (self.cursor.column / TAB_WIDTH + 1) * TAB_WIDTH;
+
+                self.cursor.column = next.min(self.columns - 1);
+            }
+            b'\n' | 0x0B | 0x0C => self.index(),
+            b'\r' => {
+                self.cursor.column = 0;
+                self.cursor.is_pending_wrap = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        match params {
+            [b"0" | b"2", title, ..] => {
+                self.title = Some(String::from_utf8_lossy(title).into_owned());
+            }
+            [b"8", _parameters, uri @ ..] => {
+                let uri = uri
+                    .iter()
+                    .map(|part| String::from_utf8_lossy(part))
+                    .collect::<Vec<_>>()
+                    .join(";");
+
+                self.link = (!uri.is_empty()).then(|| Arc::from(uri));
+            }
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(
+        &mut self,
+        params: &vte::Params,
+        intermediates: &[u8],
+        ignore: bool,
+        action: char,
+    ) {
+        if ignore {
+            return;
+        }
+
+        let is_private = intermediates.first() == Some(&b'?');
+        let argument = |index, default| argument(params, index, default);
+        let Cursor { row, column, .. } = self.cursor;
+
+        match action {
+            'A' => self.move_by(row.saturating_sub(argument(0, 1)), column),
+            'B' | 'e' => self.move_by(row + argument(0, 1), column),
+            'C' | 'a' => self.move_by(row, column + argument(0, 1)),
+            'D' => self.move_by(row, column.saturating_sub(argument(0, 1))),
+            'E' => self.move_by(row + argument(0, 1), 0),
+            'F' => self.move_by(row.saturating_sub(argument(0, 1)), 0),
+            'G' | '`' => self.move_by(row, argument(0, 1) - 1),
+            'H' | 'f' => self.move_to(argument(0, 1) - 1, argument(1, 1) - 1),
+            'd' => self.move_to(argument(0, 1) - 1, column),
+            'J' => match argument(0, 0) {
+                0 => {
+                    self.erase(row, column..self.columns);
+
+                    for row in row + 1..self.rows {
+                        self.erase(row, 0..self.columns);
+                    }
+                }
+                1 => {
+                    for row in 0..row {
+                        self.erase(row, 0..self.columns);
+                    }
+
+                    self.erase(row, 0..column + 1);
+                }
+                2 => {
+                    for row in 0..self.rows {
+                        self.erase(row, 0..self.columns);
+                    }
+                }
+                3 => {
+                    self.dropped += self.scrollback.len();
+                    self.scrollback.clear();
+                }
+                _ => {}
+            },
+            'K' => match argument(0, 0) {
+                0 => self.erase(row, column..self.columns),
+                1 => self.erase(row, 0..column + 1),
+                2 => self.erase(row, 0..self.columns),
+                _ => {}
+            },
+            'L' | 'M' if (self.top..self.bottom).contains(&row) => {
+                let top = self.top;
+                self.top = row;
+
+                if action == 'L' {
+                    self.scroll_down(argument(0, 1));
+                } else {
+                    let count = argument(0, 1).min(self.bottom - row);
+
+                    for _ in 0..count {
+                        let _ = self.lines.remove(row);
+                        self.lines.insert(self.bottom - 1, self.blank());
+                    }
+                }
+
+                self.top = top;
+                self.cursor.column = 0;
+            }
+            '@' => {
+                let blank = Cell::blank(self.cursor.pen);
+                let columns = self.columns;
+                let cells = &mut self.row().cells;
+
+                for _ in 0..argument(0, 1).min(columns - column) {
+                    let _ = cells.pop();
+                    cells.insert(column, blank.clone());
+                }
+            }
+            'P' => {
+                let blank = Cell::blank(self.cursor.pen);
+                let columns = self.columns;
+                let cells = &mut self.row().cells;
+
+                for _ in 0..argument(0, 1).min(columns - column) {
+                    let _ = cells.remove(column);
+                    cells.push(blank.clone());
+                }
+            }
+            'X' => self.erase(row, column..column + argument(0, 1)),
+            'S' if !is_private => self.scroll_up(argument(0, 1)),
+            'T' if !is_private => self.scroll_down(argument(0, 1)),
+            'm' if intermediates.is_empty() => self.select_graphic_rendition(params),
+            'r' if !is_private => {
+                let top = argument(0, 1) - 1;
+                let bottom = argument(1, self.rows).min(self.rows);
+
+                if top < bottom {
+                    self.top = top;
+                    self.bottom = bottom;
+                    self.move_to(0, 0);
+                }
+            }
+            'h' | 'l' => {
+                for mode in params.iter().filter_map(|param| param.first()) {
+                    self.set_mode(*mode, is_private, action == 'h');
+                }
+            }
+            'n' if !is_private => match argument(0, 0) {
+                5 => self.responses.extend_from_slice(b"\x1b[0n"),
+                6 => self
+                    .responses
+                    .extend_from_slice(format!("\x1b[{};{}R", row + 1, column + 1).as_bytes()),
+                _ => {}
+            },
+            'c' if intermediates.is_empty() && argument(0, 0) == 0 => {
+                self.responses.extend_from_slice(b"\x1b[?62;22c");
+            }
+            's' if !is_private => self.saved = Some(self.cursor),
+            'u' if !is_private => {
+                if let Some(saved) = self.saved {
+                    self.cursor = saved;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], ignore: bool, byte: u8) {
+        if ignore || !intermediates.is_empty() {
+            return;
+        }
+
+        match byte {
+            b'7' => self.saved = Some(self.cursor),
+            b'8' => {
+                if let Some(saved) = self.saved {
+                    self.cursor = saved;
+                }
+            }
+            b'D' => self.index(),
+            b'E' => {
+                self.index();
+                self.cursor.column = 0;
+            }
+            b'M' => self.reverse_index(),
+            b'c' => self.reset(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequences_move_the_cursor_and_paint_cells() {
+        let mut screen = Screen::new(10, 3, 100);
+        let mut parser = vte::Parser::new();
+
+        for byte in b"hello\r\nworld\x1b[1;1H\x1b[31mJ\x1b[2;3H\x1b[K" {
+            parser.advance(&mut screen, *byte);
+        }
+
+        assert_eq!(screen.lines[0].text(0..10), "Jello");
+        assert_eq!(screen.lines[0].cells[0].pen.foreground, Tint::Indexed(1));
+        assert_eq!(screen.lines[1].text(0..10), "wo");
+        assert_eq!((screen.cursor.row, screen.cursor.column), (1, 2));
+    }
+}
@@ -0,0 +1,342 @@
+//! The state of a [`Terminal`].
+//!
+//! [`Terminal`]: super::Terminal
+use crate::terminal::grid::{Cell, Line, Screen};
+use crate::terminal::{Action, Position};
+
+use std::fmt;
+
+/// The state of a [`Terminal`]: its grid of cells, its scrollback, and its
+/// selection.
+///
+/// Feed it the output of the program running in the terminal with
+/// [`State::feed`], and keep it up to date with the [`Action`]s produced by
+/// the [`Terminal`] with [`State::perform`].
+///
+/// [`Terminal`]: super::Terminal
+pub struct State {
+    screen: Screen,
+    parser: vte::Parser,
+    offset: usize,
+    selection: Option<Selection>,
+}
+
+impl State {
+    /// The default amount of lines kept in the scrollback of a [`State`].
+    pub const SCROLLBACK: usize = 10_000;
+
+    /// Creates a new [`State`] with the given amount of columns and rows.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            screen: Screen::new(columns, rows, Self::SCROLLBACK),
+            parser: vte::Parser::new(),
+            offset: 0,
+            selection: None,
+        }
+    }
+
+    /// Sets the maximum amount of lines kept in the scrollback of the
+    /// [`State`].
+    pub fn with_scrollback(mut self, lines: usize) -> Self {
+        self.screen.max_scrollback = lines;
+        self
+    }
+
+    /// Processes the given output of the program running in the terminal.
+    ///
+    /// Returns the replies the terminal needs to send back to the program,
+    /// like cursor position reports. They are usually empty.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let pushed = self.screen.pushed;
+
+        for byte in bytes {
+            self.parser.advance(&mut self.screen, *byte);
+        }
+
+        // Keep the view in place while scrolled back
+        if self.offset > 0 {
+            self.offset = (self.offset + self.screen.pushed - pushed).min(self.screen.history());
+        }
+
+        std::mem::take(&mut self.screen.responses)
+    }
+
+    /// Applies the given [`Action`] to the [`State`].
+    ///
+    /// [`Action::Write`] scrolls back to the bottom, but its bytes still need
+    /// to be sent to the program. [`Action::Open`] has no effect.
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Write(_) => {
+                self.offset = 0;
+                self.selection = None;
+            }
+            Action::Scroll(lines) => {
+                self.offset = self
+                    .offset
+                    .saturating_add_signed(lines)
+                    .min(self.screen.history());
+            }
+            Action::Select(position) => {
+                let point = self.point(position);
+
+                self.selection = Some(Selection {
+                    anchor: point,
+                    head: point,
+                    mode: Mode::Simple,
+                });
+            }
+            Action::SelectWord(position) => {
+                let point = self.point(position);
+
+                self.selection = Some(Selection {
+                    anchor: point,
+                    head: point,
+                    mode: Mode::Word,
+                });
+            }
+            Action::SelectLine(position) => {
+                let point = self.point(position);
+
+                self.selection = Some(Selection {
+                    anchor: point,
+                    head: point,
+                    mode: Mode::Line,
+                });
+            }
+            Action::Drag(position) => {
+                let point = self.point(position);
+
+                if let Some(selection) = &mut self.selection {
+                    selection.head = point;
+                }
+            }
+            Action::Deselect => {
+                self.selection = None;
+            }
+            Action::Resize { columns, rows } => {
+                self.screen.resize(columns, rows);
+                self.offset = self.offset.min(self.screen.history());
+            }
+            Action::Open(_) => {}
+        }
+    }
+
+    /// Returns the amount of columns and rows of the [`State`].
+    pub fn size(&self) -> (usize, usize) {
+        (self.screen.columns, self.screen.rows)
+    }
+
+    /// Returns the title set by the program running in the terminal, if any.
+    pub fn title(&self) -> Option<&str> {
+        self.screen.title.as_deref()
+    }
+
+    /// Returns the text of the visible lines of the [`State`].
+    pub fn text(&self) -> String {
+        self.visible()
+            .map(|line| line.text(0..self.screen.columns))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the selected text of the [`State`], if any.
+    pub fn selection(&self) -> Option<String> {
+        let (start, end) = self.selected()?;
+        let mut text = String::new();
+
+        for index in start.line..=end.line {
+            let Some(line) = self.line(index) else {
+                continue;
+            };
+
+            let from = if index == start.line { start.column } else { 0 };
+            let to = if index == end.line {
+                end.column + 1
+            } else {
+                self.screen.columns
+            };
+
+            text.push_str(&line.text(from..to));
+
+            if index != end.line && !line.is_wrapped {
+                text.push('\n');
+            }
+        }
+
+        Some(text)
+    }
+
+    /// Returns whether the program running in the terminal expects pasted
+    /// text to be bracketed.
+    pub fn is_bracketed_paste(&self) -> bool {
+        self.screen.modes.bracketed_paste
+    }
+
+    /// Returns whether the program running in the terminal expects the
+    /// cursor keys in application mode.
+    pub fn is_application_cursor(&self) -> bool {
+        self.screen.modes.application_cursor
+    }
+
+    /// Returns the amount of lines the [`State`] is scrolled back.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the visible lines of the [`State`].
+    pub(super) fn visible(&self) -> impl Iterator<Item = &Line> {
+        let start = self.screen.history() - self.offset;
+
+        (start..start + self.screen.rows).filter_map(|index| self.screen.line(index))
+    }
+
+    /// Returns the visible cell at the given position.
+    pub(super) fn cell(&self, position: Position) -> Option<&Cell> {
+        self.line(self.point(position).line)?
+            .cells
+            .get(position.column)
+    }
+
+    /// Returns the visible position of the cursor, if it is visible.
+    pub(super) fn cursor(&self) -> Option<Position> {
+        let cursor = self.screen.cursor;
+        let row = cursor.row + self.offset;
+
+        (self.screen.modes.show_cursor && row < self.screen.rows).then_some(Position {
+            row,
+            column: cursor.column,
+        })
+    }
+
+    /// Returns whether the cell at the given visible position is selected.
+    pub(super) fn is_selected(&self, position: Position) -> bool {
+        let Some((start, end)) = self.selected() else {
+            return false;
+        };
+
+        let point = self.point(position);
+
+        start <= point && point <= end
+    }
+
+    fn line(&self, index: usize) -> Option<&Line> {
+        self.screen.line(index.checked_sub(self.screen.dropped)?)
+    }
+
+    /// Returns the [`Point`] of the given visible position.
+    fn point(&self, position: Position) -> Point {
+        Point {
+            line: self.screen.dropped + self.screen.history() - self.offset
+                + position.row.min(self.screen.rows - 1),
+            column: position.column.min(self.screen.columns - 1),
+        }
+    }
+
+    /// Returns the ordered bounds of the selection, expanded to words or
+    /// lines depending on its mode.
+    fn selected(&self) -> Option<(Point, Point)> {
+        let selection = self.selection?;
+
+        let (mut start, mut end) = if selection.anchor <= selection.head {
+            (selection.anchor, selection.head)
+        } else {
+            (selection.head, selection.anchor)
+        };
+
+        match selection.mode {
+            Mode::Simple => {
+                if start == end {
+                    return None;
+                }
+            }
+            Mode::Word => {
+                let is_word = |point: Point| {
+                    self.line(point.line)
+                        .and_then(|line| line.cells.get(point.column))
+                        .is_some_and(|cell| is_word(cell.character))
+                };
+
+                while start.column > 0
+                    && is_word(start)
+                    && is_word(Point {
+                        column: start.column - 1,
+                        ..start
+                    })
+                {
+                    start.column -= 1;
+                }
+
+                while end.column + 1 < self.screen.columns
+                    && is_word(end)
+                    && is_word(Point {
+                        column: end.column + 1,
+                        ..end
+                    })
+                {
+                    end.column += 1;
+                }
+            }
+            Mode::Line => {
+                start.column = 0;
+                end.column = self.screen.columns - 1;
+            }
+        }
+
+        Some((start, end))
+    }
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("screen", &self.screen)
+            .field("offset", &self.offset)
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A cell of the [`State`], counting from the first line ever printed.
+///
+/// It stays in place when lines are dropped from the scrollback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Point {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    anchor: Point,
+    head: Point,
+    mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Simple,
+    Word,
+    Line,
+}
+
+fn is_word(character: char) -> bool {
+    !character.is_whitespace() && !"\"'`()[]{}<>,;|".contains(character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_survives_scrolling() {
+        let mut state = State::new(10, 2);
+        let _ = state.feed(b"one\r\ntwo words");
+
+        state.perform(Action::SelectWord(Position { row: 1, column: 5 }));
+        let _ = state.feed(b"\r\nthree");
+
+        assert_eq!(state.selection().as_deref(), Some("words"));
+        assert_eq!(state.text(), "two words\nthree");
+    }
+}
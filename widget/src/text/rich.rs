@@ -1,17 +1,23 @@
 use crate::core::alignment;
+use crate::core::clipboard;
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::{Paragraph, Span};
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::text::{
     self, Alignment, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
-    Vector, Widget,
+    self, Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
+use std::ops::Range;
+
 /// A bunch of [`Rich`] text.
 pub struct Rich<'a, Link, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
@@ -31,6 +37,9 @@ where
     class: Theme::Class<'a>,
     hovered_link: Option<usize>,
     on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    selectable: bool,
+    selection_color: Option<Color>,
+    on_select: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
 }
 
 impl<'a, Link, Message, Theme, Renderer> Rich<'a, Link, Message, Theme, Renderer>
@@ -55,6 +64,9 @@ where
             class: Theme::default(),
             hovered_link: None,
             on_link_click: None,
+            selectable: false,
+            selection_color: None,
+            on_select: None,
         }
     }
 
@@ -123,6 +135,9 @@ where
     /// Sets the message that will be produced when a link of the [`Rich`] text
     /// is clicked.
     ///
+    /// Links can also be focused with the keyboard; once focused, the tab and
+    /// arrow keys move between them and the enter and space keys click them.
+    ///
     /// If the spans of the [`Rich`] text contain no links, you may need to call
     /// this method with `on_link_click(never)` in order for the compiler to infer
     /// the proper `Link` generic type.
@@ -131,6 +146,33 @@ where
         self
     }
 
+    /// Sets whether the [`Rich`] text can be selected by dragging the mouse.
+    ///
+    /// Selected text can be copied with the standard copy shortcut.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Makes the [`Rich`] text selectable and sets the message that will be
+    /// produced when its selection changes.
+    ///
+    /// The range contains byte offsets into the concatenated text of all the
+    /// spans. An empty range means the selection was cleared.
+    pub fn on_select(mut self, on_select: impl Fn(Range<usize>) -> Message + 'a) -> Self {
+        self.selectable = true;
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the [`Color`] used to highlight the selection of the [`Rich`] text.
+    ///
+    /// By default, a translucent version of the text color is used.
+    pub fn selection_color(mut self, color: impl Into<Color>) -> Self {
+        self.selection_color = Some(color.into());
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -183,9 +225,28 @@ where
 struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
+    focused_link: Option<usize>,
+    selection: Option<(usize, usize)>,
+    is_selecting: bool,
     paragraph: P,
 }
 
+impl<Link, P: Paragraph> operation::Focusable for State<Link, P> {
+    fn is_focused(&self) -> bool {
+        self.focused_link.is_some()
+    }
+
+    fn focus(&mut self) {
+        if self.focused_link.is_none() {
+            self.focused_link = next_link(&self.spans, None, false);
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focused_link = None;
+    }
+}
+
 impl<Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Rich<'_, Link, Message, Theme, Renderer>
 where
@@ -201,6 +262,9 @@ where
         tree::State::new(State::<Link, _> {
             spans: Vec::new(),
             span_pressed: None,
+            focused_link: None,
+            selection: None,
+            is_selecting: false,
             paragraph: Renderer::Paragraph::default(),
         })
     }
@@ -257,8 +321,15 @@ where
 
         for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
             let is_hovered_link = self.on_link_click.is_some() && Some(index) == self.hovered_link;
-
-            if span.highlight.is_some() || span.underline || span.strikethrough || is_hovered_link {
+            let is_pressed_link = is_hovered_link && Some(index) == state.span_pressed;
+            let is_focused_link = self.on_link_click.is_some() && Some(index) == state.focused_link;
+
+            if span.highlight.is_some()
+                || span.underline
+                || span.strikethrough
+                || is_hovered_link
+                || is_focused_link
+            {
                 let translation = layout.position() - Point::ORIGIN;
                 let regions = state.paragraph.span_bounds(index);
 
@@ -280,7 +351,30 @@ where
                     }
                 }
 
-                if span.underline || span.strikethrough || is_hovered_link {
+                if is_pressed_link || is_focused_link {
+                    let color = span.color.or(style.color).unwrap_or(defaults.text_color);
+
+                    for bounds in &regions {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: bounds.expand(2.0) + translation,
+                                border: Border {
+                                    color,
+                                    width: if is_focused_link { 1.0 } else { 0.0 },
+                                    radius: 2.0.into(),
+                                },
+                                ..Default::default()
+                            },
+                            if is_pressed_link {
+                                color.scale_alpha(0.2)
+                            } else {
+                                Color::TRANSPARENT
+                            },
+                        );
+                    }
+                }
+
+                if span.underline || span.strikethrough || is_hovered_link || is_focused_link {
                     let size = span.size.or(self.size).unwrap_or(renderer.default_size());
 
                     let line_height = span
@@ -293,7 +387,7 @@ where
                     let baseline =
                         translation + Vector::new(0.0, size.0 + (line_height.0 - size.0) / 2.0);
 
-                    if span.underline || is_hovered_link {
+                    if span.underline || is_hovered_link || is_focused_link {
                         for bounds in &regions {
                             renderer.fill_quad(
                                 renderer::Quad {
@@ -328,6 +422,27 @@ where
             }
         }
 
+        if let Some(selection) = state.selection {
+            let range = to_range(selection);
+
+            if !range.is_empty() {
+                let translation = layout.position() - Point::ORIGIN;
+                let color = self
+                    .selection_color
+                    .unwrap_or_else(|| style.color.unwrap_or(defaults.text_color).scale_alpha(0.3));
+
+                for bounds in state.paragraph.range_bounds(range.start, range.end) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: bounds + translation,
+                            ..Default::default()
+                        },
+                        color,
+                    );
+                }
+            }
+        }
+
         text::draw(
             renderer,
             defaults,
@@ -338,6 +453,22 @@ where
         );
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        if self.on_link_click.is_some() && next_link(&state.spans, None, false).is_some() {
+            operation.focusable(None, layout.bounds(), state);
+        }
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -345,21 +476,19 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
-        let Some(on_link_clicked) = &self.on_link_click else {
-            return;
-        };
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
         let was_hovered = self.hovered_link.is_some();
 
-        if let Some(position) = cursor.position_in(layout.bounds()) {
-            let state = tree
-                .state
-                .downcast_ref::<State<Link, Renderer::Paragraph>>();
-
+        if self.on_link_click.is_some()
+            && let Some(position) = cursor.position_in(layout.bounds())
+        {
             self.hovered_link = state.paragraph.hit_span(position).and_then(|span| {
                 if self.spans.as_ref().as_ref().get(span)?.link.is_some() {
                     Some(span)
@@ -377,57 +506,207 @@ where
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let state = tree
-                    .state
-                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
+                if state.focused_link.take().is_some() {
+                    shell.request_redraw();
+                }
 
                 if self.hovered_link.is_some() {
                     state.span_pressed = self.hovered_link;
                     shell.capture_event();
+                } else if self.selectable {
+                    if let Some(position) = cursor.position_in(layout.bounds()) {
+                        if let Some(offset) = state.paragraph.hit_offset(position) {
+                            state.selection = Some((offset, offset));
+                            state.is_selecting = true;
+
+                            shell.capture_event();
+                            shell.request_redraw();
+                        }
+                    } else if state.selection.take().is_some() {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(0..0));
+                        }
+
+                        shell.request_redraw();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if state.is_selecting => {
+                if let Some(position) = cursor.position_from(layout.position())
+                    && let Some(offset) = state.paragraph.hit_offset(position)
+                    && let Some((_, head)) = &mut state.selection
+                    && *head != offset
+                {
+                    *head = offset;
+
+                    shell.request_redraw();
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let state = tree
-                    .state
-                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
-
-                match state.span_pressed {
-                    Some(span) if Some(span) == self.hovered_link => {
-                        if let Some(link) = self
-                            .spans
-                            .as_ref()
-                            .as_ref()
-                            .get(span)
-                            .and_then(|span| span.link.clone())
-                        {
-                            shell.publish(on_link_clicked(link));
+                if state.is_selecting {
+                    state.is_selecting = false;
+
+                    if let Some(on_select) = &self.on_select
+                        && let Some(selection) = state.selection
+                    {
+                        shell.publish(on_select(to_range(selection)));
+                    }
+                }
+
+                if let Some(on_link_clicked) = &self.on_link_click {
+                    match state.span_pressed {
+                        Some(span) if Some(span) == self.hovered_link => {
+                            if let Some(link) = self
+                                .spans
+                                .as_ref()
+                                .as_ref()
+                                .get(span)
+                                .and_then(|span| span.link.clone())
+                            {
+                                shell.publish(on_link_clicked(link));
+                            }
                         }
+                        _ => {}
                     }
-                    _ => {}
                 }
 
                 state.span_pressed = None;
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) if modifiers.command() && key.to_latin(*physical_key) == Some('c') => {
+                if let Some(selection) = state.selection {
+                    let range = to_range(selection);
+
+                    if !range.is_empty() {
+                        clipboard.write(
+                            clipboard::Kind::Standard,
+                            selected_text(&state.spans, range),
+                        );
+
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                let Some(focused) = state.focused_link else {
+                    return;
+                };
+
+                let spans = self.spans.as_ref().as_ref();
+
+                let backwards = match key {
+                    Key::Named(key::Named::Tab) => modifiers.shift(),
+                    Key::Named(key::Named::ArrowLeft | key::Named::ArrowUp) => true,
+                    Key::Named(key::Named::ArrowRight | key::Named::ArrowDown) => false,
+                    Key::Named(key::Named::Enter | key::Named::Space) => {
+                        if let Some(on_link_click) = &self.on_link_click
+                            && let Some(link) =
+                                spans.get(focused).and_then(|span| span.link.clone())
+                        {
+                            shell.publish(on_link_click(link));
+                        }
+
+                        shell.capture_event();
+                        return;
+                    }
+                    Key::Named(key::Named::Escape) => {
+                        state.focused_link = None;
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                match next_link(spans, Some(focused), backwards) {
+                    Some(target) => {
+                        state.focused_link = Some(target);
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                    None if matches!(key, Key::Named(key::Named::Tab)) => {
+                        // Let the focus leave the text block
+                        state.focused_link = None;
+
+                        shell.request_redraw();
+                    }
+                    None => {
+                        shell.capture_event();
+                    }
+                }
+            }
             _ => {}
         }
     }
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
         if self.hovered_link.is_some() {
             mouse::Interaction::Pointer
+        } else if self.selectable && (state.is_selecting || cursor.is_over(layout.bounds())) {
+            mouse::Interaction::Text
         } else {
             mouse::Interaction::None
         }
     }
 }
 
+fn next_link<Link, Font>(
+    spans: &[Span<'_, Link, Font>],
+    from: Option<usize>,
+    backwards: bool,
+) -> Option<usize> {
+    let is_link = |index: &usize| spans[*index].link.is_some();
+
+    match (from, backwards) {
+        (None, false) => (0..spans.len()).find(is_link),
+        (None, true) => (0..spans.len()).rev().find(is_link),
+        (Some(from), false) => (from + 1..spans.len()).find(is_link),
+        (Some(from), true) => (0..from.min(spans.len())).rev().find(is_link),
+    }
+}
+
+fn to_range((anchor, head): (usize, usize)) -> Range<usize> {
+    anchor.min(head)..anchor.max(head)
+}
+
+fn selected_text<Link, Font>(spans: &[Span<'_, Link, Font>], range: Range<usize>) -> String {
+    let mut text = String::new();
+    let mut offset = 0;
+
+    for span in spans {
+        let end = offset + span.text.len();
+        let start = range.start.clamp(offset, end);
+
+        if let Some(selected) = span
+            .text
+            .get(start - offset..range.end.clamp(start, end) - offset)
+        {
+            text.push_str(selected);
+        }
+
+        offset = end;
+    }
+
+    text
+}
+
 fn layout<Link, Renderer>(
     state: &mut State<Link, Renderer::Paragraph>,
     renderer: &Renderer,
@@ -468,6 +747,11 @@ where
         if state.spans != spans {
             state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.selection = None;
+            state.is_selecting = false;
+            state.focused_link = state
+                .focused_link
+                .filter(|&index| spans.get(index).is_some_and(|span| span.link.is_some()));
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -522,3 +806,40 @@ where
         Element::new(text)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{next_link, selected_text};
+    use crate::core::text::Span;
+
+    #[test]
+    fn selected_text_spans_multiple_spans() {
+        let spans: [Span<'_>; 3] = [
+            Span::new("Hello, "),
+            Span::new("world"),
+            Span::new("!\nBye"),
+        ];
+
+        assert_eq!(selected_text(&spans, 3..10), "lo, wor");
+        assert_eq!(selected_text(&spans, 7..15), "world!\nB");
+        assert_eq!(selected_text(&spans, 0..0), "");
+        assert_eq!(selected_text(&spans, 13..100), "\nBye");
+    }
+
+    #[test]
+    fn next_link_skips_plain_spans() {
+        let spans: [Span<'_, u8>; 4] = [
+            Span::new("Read "),
+            Span::new("the book").link(0),
+            Span::new(" or "),
+            Span::new("the docs").link(1),
+        ];
+
+        assert_eq!(next_link(&spans, None, false), Some(1));
+        assert_eq!(next_link(&spans, None, true), Some(3));
+        assert_eq!(next_link(&spans, Some(1), false), Some(3));
+        assert_eq!(next_link(&spans, Some(3), false), None);
+        assert_eq!(next_link(&spans, Some(3), true), Some(1));
+        assert_eq!(next_link(&spans, Some(1), true), None);
+    }
+}
@@ -41,7 +41,7 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::Editor as _;
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight, Text, Wrapping};
+use crate::core::text::{self, LineHeight, Paragraph as _, Text, Wrapping};
 use crate::core::theme;
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
@@ -51,6 +51,7 @@ use crate::core::{
     Background, Border, Color, Element, Event, InputMethod, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::history::{self, History};
 
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -117,6 +118,11 @@ where
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(&Highlighter::Highlight, &Theme) -> highlighter::Format<Renderer::Font>,
+    language: Option<Language>,
+    line_numbers: bool,
+    markers: &'a [Marker],
+    on_gutter_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    diagnostics: &'a [Diagnostic],
     last_status: Option<Status>,
 }
 
@@ -145,15 +151,14 @@ where
             on_edit: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| highlighter::Format::default(),
+            language: None,
+            line_numbers: false,
+            markers: &[],
+            on_gutter_click: None,
+            diagnostics: &[],
             last_status: None,
         }
     }
-
-    /// Sets the [`Id`](widget::Id) of the [`TextEditor`].
-    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
-        self.id = Some(id.into());
-        self
-    }
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -163,6 +168,12 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    /// Sets the [`Id`](widget::Id) of the [`TextEditor`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the placeholder of the [`TextEditor`].
     pub fn placeholder(mut self, placeholder: impl text::IntoFragment<'a>) -> Self {
         self.placeholder = Some(placeholder.into_fragment());
@@ -229,12 +240,58 @@ where
     }
 
     /// Sets the [`Wrapping`] strategy of the [`TextEditor`].
+    ///
+    /// With [`Wrapping::None`], long lines can be scrolled horizontally
+    /// with a scrollbar, horizontal wheel motion, or Shift and the wheel.
     pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
         self.wrapping = wrapping;
         self
     }
 
+    /// Sets the [`Language`] of the [`TextEditor`].
+    ///
+    /// It enables bracket matching, auto-indentation, and comment toggling.
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] shows a gutter with line numbers.
+    ///
+    /// The number of a line is colored by the most severe [`Diagnostic`]
+    /// starting in it.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the [`Marker`]s shown in the gutter of the [`TextEditor`].
+    pub fn markers(mut self, markers: &'a [Marker]) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Sets the message that should be produced when the gutter of the
+    /// [`TextEditor`] is clicked, given the index of the clicked line.
+    ///
+    /// This can be used to toggle breakpoints or bookmarks.
+    pub fn on_gutter_click(mut self, on_gutter_click: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_gutter_click = Some(Box::new(on_gutter_click));
+        self
+    }
+
+    /// Sets the [`Diagnostic`]s of the [`TextEditor`].
+    ///
+    /// They are underlined with squiggles and show their message when
+    /// hovered.
+    pub fn diagnostics(mut self, diagnostics: &'a [Diagnostic]) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
+    ///
+    /// Unless a [`Language`] is set, the one of the syntax is used.
     #[cfg(feature = "highlighter")]
     pub fn highlight(
         self,
@@ -244,13 +301,18 @@ where
     where
         Renderer: text::Renderer<Font = crate::core::Font>,
     {
-        self.highlight_with::<iced_highlighter::Highlighter>(
-            iced_highlighter::Settings {
-                theme,
-                token: syntax.to_owned(),
-            },
-            |highlight, _theme| highlight.to_format(),
-        )
+        let language = self
+            .language
+            .unwrap_or_else(|| Language::from_token(syntax));
+
+        self.language(language)
+            .highlight_with::<iced_highlighter::Highlighter>(
+                iced_highlighter::Settings {
+                    theme,
+                    token: syntax.to_owned(),
+                },
+                |highlight, _theme| highlight.to_format(),
+            )
     }
 
     /// Highlights the [`TextEditor`] with the given [`Highlighter`] and
@@ -278,6 +340,11 @@ where
             on_edit: self.on_edit,
             highlighter_settings: settings,
             highlighter_format: to_format,
+            language: self.language,
+            line_numbers: self.line_numbers,
+            markers: self.markers,
+            on_gutter_click: self.on_gutter_click,
+            diagnostics: self.diagnostics,
             last_status: self.last_status,
         }
     }
@@ -311,6 +378,12 @@ where
         self
     }
 
+    /// Returns the settings of the [`Highlighter`] of the [`TextEditor`],
+    /// for widgets built on top of it.
+    pub(crate) fn highlighter_settings_mut(&mut self) -> &mut Highlighter::Settings {
+        &mut self.highlighter_settings
+    }
+
     fn input_method<'b>(
         &self,
         state: &'b State<Highlighter>,
@@ -328,8 +401,9 @@ where
         let bounds = layout.bounds();
         let internal = self.content.0.borrow_mut();
 
-        let text_bounds = bounds.shrink(self.padding);
-        let translation = text_bounds.position() - Point::ORIGIN;
+        let text_bounds = bounds.shrink(self.text_padding(state));
+        let translation =
+            text_bounds.position() - Point::ORIGIN - Vector::new(state.horizontal_offset, 0.0);
 
         let cursor = match internal.editor.selection() {
             Selection::Caret(position) => position,
@@ -348,6 +422,111 @@ where
             preedit: state.preedit.as_ref().map(input_method::Preedit::as_ref),
         }
     }
+
+    /// Returns whether the gutter of the [`TextEditor`] has a lane for
+    /// breakpoints and bookmarks.
+    fn has_lane(&self) -> bool {
+        self.on_gutter_click.is_some()
+            || self
+                .markers
+                .iter()
+                .any(|marker| matches!(marker.kind, Mark::Breakpoint | Mark::Bookmark))
+    }
+
+    /// Returns the bounds of the gutter of the [`TextEditor`].
+    fn gutter_bounds(&self, state: &State<Highlighter>, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            width: state.gutter,
+            ..bounds.shrink(self.text_padding(state))
+        }
+    }
+
+    /// Returns the index of the line at the given vertical position of the
+    /// gutter, if any.
+    fn gutter_line(&self, state: &State<Highlighter>, bounds: Rectangle, y: f32) -> Option<usize> {
+        let text_bounds = bounds.shrink(self.text_padding(state));
+
+        self.content
+            .0
+            .borrow()
+            .editor
+            .visible_lines()
+            .into_iter()
+            .take_while(|(_, top)| text_bounds.y + top <= y)
+            .last()
+            .map(|(line, _)| line)
+    }
+
+    /// Returns the horizontal scrollbar of the [`TextEditor`] and its
+    /// scroller, if the text overflows horizontally.
+    fn scrollbar(
+        &self,
+        state: &State<Highlighter>,
+        bounds: Rectangle,
+        content_width: f32,
+    ) -> Option<(Rectangle, Rectangle)> {
+        if self.wrapping != Wrapping::None {
+            return None;
+        }
+
+        let text_bounds = bounds.shrink(self.text_padding(state));
+
+        if content_width <= text_bounds.width {
+            return None;
+        }
+
+        let rail = Rectangle {
+            x: text_bounds.x,
+            y: bounds.y + bounds.height - SCROLLBAR_WIDTH - 1.0,
+            width: text_bounds.width,
+            height: SCROLLBAR_WIDTH,
+        };
+
+        let width = (rail.width * text_bounds.width / content_width).max(SCROLLBAR_WIDTH * 4.0);
+        let max_offset = content_width - text_bounds.width;
+
+        let scroller = Rectangle {
+            x: rail.x + (rail.width - width) * state.horizontal_offset / max_offset,
+            width,
+            ..rail
+        };
+
+        Some((rail, scroller))
+    }
+
+    /// Returns the [`Padding`] around the text, including the gutter.
+    fn text_padding(&self, state: &State<Highlighter>) -> Padding {
+        Padding {
+            left: self.padding.left + state.gutter,
+            ..self.padding
+        }
+    }
+
+    /// Returns the index of the [`Diagnostic`] under the cursor, if any.
+    fn hovered_diagnostic(
+        &self,
+        state: &State<Highlighter>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        if self.diagnostics.is_empty() {
+            return None;
+        }
+
+        let text_bounds = layout.bounds().shrink(self.text_padding(state));
+        let position = cursor.position_over(text_bounds)?
+            - Vector::new(text_bounds.x - state.horizontal_offset, text_bounds.y);
+        let internal = self.content.0.borrow();
+
+        self.diagnostics.iter().position(|diagnostic| {
+            internal
+                .editor
+                .range_bounds(diagnostic.start, diagnostic.end)
+                .iter()
+                .any(|region| region.contains(position))
+        })
+    }
 }
 
 /// The content of a [`TextEditor`].
@@ -360,6 +539,10 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History<(String, Cursor)>,
+    cursors: Vec<Cursor>,
+    query: String,
+    matches: Vec<ops::Range<Position>>,
 }
 
 impl<R> Content<R>
@@ -375,28 +558,206 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
+            cursors: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`], so they can be
+    /// reverted with [`Action::Undo`] and [`Action::Redo`]. Consecutive typing
+    /// or deleting is undone all at once.
+    ///
+    /// Edits, motions, and selections apply to every cursor of the [`Content`].
+    ///
+    /// The matches of the last [`Action::Find`] are kept up to date with the
+    /// edits, until it is ended with an empty text.
     pub fn perform(&mut self, action: Action) {
+        let is_edit = action.is_edit();
+
+        match action {
+            Action::Undo => {
+                let current = (self.text(), self.cursor());
+
+                if let Some(snapshot) = self.0.get_mut().history.undo(current) {
+                    self.restore(snapshot);
+                }
+            }
+            Action::Redo => {
+                let current = (self.text(), self.cursor());
+
+                if let Some(snapshot) = self.0.get_mut().history.redo(current) {
+                    self.restore(snapshot);
+                }
+            }
+            Action::Edit(edit) => {
+                let kind = match edit {
+                    Edit::Insert(_) => history::Kind::Typing,
+                    Edit::Backspace | Edit::Delete => history::Kind::Deleting,
+                    _ => history::Kind::Other,
+                };
+
+                let is_word_boundary = matches!(edit, Edit::Insert(c) if c.is_whitespace());
+
+                self.record(kind);
+
+                let internal = self.0.get_mut();
+
+                if is_word_boundary {
+                    internal.history.interrupt();
+                }
+
+                internal.perform_everywhere(Action::Edit(edit));
+            }
+            Action::Find(query) => {
+                let internal = self.0.get_mut();
+
+                internal.query = query.as_ref().clone();
+                internal.find();
+            }
+            Action::FindNext | Action::FindPrevious => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.select_match(matches!(action, Action::FindNext));
+            }
+            Action::Replace(replacement) => {
+                if self.0.get_mut().selected_match().is_some() {
+                    self.record(history::Kind::Other);
+
+                    let internal = self.0.get_mut();
+
+                    internal.history.interrupt();
+                    internal.cursors.clear();
+                    internal
+                        .editor
+                        .perform(Action::Edit(Edit::Paste(replacement)));
+                    internal.find();
+                }
+
+                self.0.get_mut().select_match(true);
+            }
+            Action::ReplaceAll(replacement) => {
+                if !self.0.get_mut().matches.is_empty() {
+                    self.record(history::Kind::Other);
+
+                    let internal = self.0.get_mut();
+
+                    internal.history.interrupt();
+                    internal.cursors.clear();
+
+                    // Replace from the last match, so the others stay in place
+                    for range in std::mem::take(&mut internal.matches).into_iter().rev() {
+                        internal.editor.perform(Action::MoveTo(Cursor {
+                            position: range.end,
+                            selection: Some(range.start),
+                        }));
+
+                        internal
+                            .editor
+                            .perform(Action::Edit(Edit::Paste(replacement.clone())));
+                    }
+                }
+            }
+            Action::Scroll { .. } => {
+                self.0.get_mut().editor.perform(action);
+            }
+            Action::Move(_) | Action::Select(_) => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.perform_everywhere(action);
+            }
+            Action::Drag(_) => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.editor.perform(action);
+            }
+            Action::AddCursor(point) => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.add_cursor(point);
+            }
+            Action::SelectBlock { from, to } => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.select_block(from, to);
+            }
+            Action::SelectNextOccurrence => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.select_next_occurrence();
+            }
+            _ => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.cursors.clear();
+                internal.editor.perform(action);
+            }
+        }
+
+        if is_edit {
+            self.0.get_mut().find();
+        }
+    }
+
+    /// Records a snapshot of the [`Content`] in its history before an edit
+    /// of the given kind.
+    fn record(&mut self, kind: history::Kind) {
+        // Take the history out, so the snapshot is only taken when needed
+        let mut history = std::mem::take(&mut self.0.get_mut().history);
+
+        history.record(kind, Instant::now(), || (self.text(), self.cursor()));
+
+        self.0.get_mut().history = history;
+    }
+
+    /// Replaces the text and cursor of the [`Content`] with the given snapshot,
+    /// bypassing its history.
+    fn restore(&mut self, (text, cursor): (String, Cursor)) {
         let internal = self.0.get_mut();
+        let editor = &mut internal.editor;
+
+        internal.cursors.clear();
 
-        internal.editor.perform(action);
+        editor.perform(Action::SelectAll);
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(text))));
+        editor.perform(Action::MoveTo(cursor));
     }
 
     /// Moves the current cursor to reflect the given one.
     pub fn move_to(&mut self, cursor: Cursor) {
         let internal = self.0.get_mut();
 
+        internal.cursors.clear();
         internal.editor.move_to(cursor);
     }
 
     /// Returns the current cursor position of the [`Content`].
+    ///
+    /// This is the primary cursor when there are many; see [`cursors`](Self::cursors).
     pub fn cursor(&self) -> Cursor {
         self.0.borrow().editor.cursor()
     }
 
+    /// Returns all the cursors of the [`Content`], starting with the
+    /// primary one.
+    pub fn cursors(&self) -> Vec<Cursor> {
+        let internal = self.0.borrow();
+
+        std::iter::once(internal.editor.cursor())
+            .chain(internal.cursors.iter().copied())
+            .collect()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -441,6 +802,14 @@ where
         contents
     }
 
+    /// Returns the ranges of text matching the current search of the
+    /// [`Content`], if any.
+    ///
+    /// A search can be started with [`Action::Find`].
+    pub fn matches(&self) -> Vec<ops::Range<Position>> {
+        self.0.borrow().matches.clone()
+    }
+
     /// Returns the selected text of the [`Content`].
     pub fn selection(&self) -> Option<String> {
         self.0.borrow().editor.copy()
@@ -457,6 +826,424 @@ where
     }
 }
 
+impl<R> Content<R>
+where
+    R: text::Renderer,
+{
+    /// Returns the actions that indent a line broken at the cursor like
+    /// the line above it, and once more after an opening bracket.
+    fn indentation(&self, language: &Language) -> Vec<Action> {
+        let position = self.cursor().position;
+
+        let Some(line) = self.line(position.line) else {
+            return Vec::new();
+        };
+
+        let before = line.text.get(..position.column).unwrap_or(&line.text);
+
+        let indentation: String = before
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+
+        let mut actions = Vec::new();
+
+        if !indentation.is_empty() {
+            actions.push(Action::Edit(Edit::Paste(Arc::new(indentation))));
+        }
+
+        if before.trim_end().ends_with(|c: char| language.opens(c)) {
+            actions.push(Action::Edit(Edit::Indent));
+        }
+
+        actions
+    }
+
+    /// Returns the actions that toggle the line comments of the lines
+    /// spanned by the cursor.
+    fn toggle_comment(&self, prefix: &str) -> Vec<Action> {
+        let cursor = self.cursor();
+        let anchor = cursor.selection.unwrap_or(cursor.position);
+
+        let (start, end) =
+            if (anchor.line, anchor.column) <= (cursor.position.line, cursor.position.column) {
+                (anchor, cursor.position)
+            } else {
+                (cursor.position, anchor)
+            };
+
+        // A selection ending at the start of a line does not include it
+        let last = if end.line > start.line && end.column == 0 {
+            end.line - 1
+        } else {
+            end.line
+        };
+
+        let lines: Vec<String> = (start.line..=last)
+            .filter_map(|index| self.line(index))
+            .map(|line| line.text.into_owned())
+            .collect();
+
+        let toggled = toggle_comment(&lines, prefix);
+
+        let (Some(before), Some(after)) = (lines.last(), toggled.last()) else {
+            return Vec::new();
+        };
+
+        let first = Position {
+            line: start.line,
+            column: 0,
+        };
+
+        let restored = if cursor.selection.is_some() {
+            Cursor {
+                position: Position {
+                    line: last,
+                    column: after.len(),
+                },
+                selection: Some(first),
+            }
+        } else {
+            Cursor {
+                position: Position {
+                    column: (cursor.position.column + after.len()).saturating_sub(before.len()),
+                    ..cursor.position
+                },
+                selection: None,
+            }
+        };
+
+        vec![
+            Action::MoveTo(Cursor {
+                position: Position {
+                    line: last,
+                    column: before.len(),
+                },
+                selection: Some(first),
+            }),
+            Action::Edit(Edit::Paste(Arc::new(toggled.join("\n")))),
+            Action::MoveTo(restored),
+        ]
+    }
+}
+
+impl<R> Internal<R>
+where
+    R: text::Renderer,
+{
+    /// Performs the [`Action`] at every cursor, starting from the last one
+    /// and keeping track of how the others are shifted by its edits.
+    fn perform_everywhere(&mut self, action: Action) {
+        if self.cursors.is_empty() {
+            self.editor.perform(action);
+            return;
+        }
+
+        let mut cursors: Vec<(bool, Cursor)> = std::iter::once((true, self.editor.cursor()))
+            .chain(self.cursors.drain(..).map(|cursor| (false, cursor)))
+            .collect();
+
+        cursors.sort_by_key(|(_, cursor)| {
+            let (start, _) = ordered(*cursor);
+
+            std::cmp::Reverse((start.line, start.column))
+        });
+
+        let mut starts = self.line_starts();
+        let mut moved: Vec<(bool, usize, Option<usize>)> = Vec::with_capacity(cursors.len());
+
+        for (is_primary, cursor) in cursors {
+            self.editor.perform(Action::MoveTo(cursor));
+            self.editor.perform(action.clone());
+
+            if action.is_edit() {
+                let new_starts = self.line_starts();
+
+                // Every cursor moved so far is after this one
+                let delta = *new_starts.last().unwrap_or(&0) as isize
+                    - *starts.last().unwrap_or(&0) as isize;
+
+                for (_, position, selection) in &mut moved {
+                    *position = position.saturating_add_signed(delta);
+
+                    if let Some(selection) = selection {
+                        *selection = selection.saturating_add_signed(delta);
+                    }
+                }
+
+                starts = new_starts;
+            }
+
+            let cursor = self.editor.cursor();
+
+            moved.push((
+                is_primary,
+                offset_of(&starts, cursor.position),
+                cursor
+                    .selection
+                    .map(|selection| offset_of(&starts, selection)),
+            ));
+        }
+
+        let mut primary = None;
+
+        for (is_primary, position, selection) in moved {
+            let cursor = Cursor {
+                position: position_at(&starts, position),
+                selection: selection
+                    .filter(|selection| *selection != position)
+                    .map(|selection| position_at(&starts, selection)),
+            };
+
+            if is_primary {
+                primary = Some(cursor);
+            } else if !self.cursors.contains(&cursor) {
+                self.cursors.push(cursor);
+            }
+        }
+
+        if let Some(primary) = primary {
+            self.cursors
+                .retain(|cursor| cursor.position != primary.position);
+            self.editor.perform(Action::MoveTo(primary));
+        }
+    }
+
+    /// Adds a cursor at the given [`Point`] or, if there is one there
+    /// already, removes it.
+    fn add_cursor(&mut self, point: Point) {
+        let previous = self.editor.cursor();
+
+        self.editor.perform(Action::Click(point));
+
+        let added = self.editor.cursor();
+
+        if let Some(index) = self
+            .cursors
+            .iter()
+            .position(|cursor| cursor.position == added.position)
+        {
+            let _ = self.cursors.remove(index);
+
+            self.editor.perform(Action::MoveTo(previous));
+        } else if previous != added {
+            self.cursors.push(previous);
+        }
+    }
+
+    /// Replaces the cursors with a cursor for every line between the given
+    /// [`Point`]s, selecting the same columns in all of them.
+    fn select_block(&mut self, from: Point, to: Point) {
+        self.editor.perform(Action::Click(from));
+        let anchor = self.editor.cursor().position;
+
+        self.editor.perform(Action::Click(to));
+        let head = self.editor.cursor().position;
+
+        let chars = |position: Position| {
+            self.editor.line(position.line).map_or(0, |line| {
+                line.text
+                    .get(..position.column)
+                    .unwrap_or(&line.text)
+                    .chars()
+                    .count()
+            })
+        };
+
+        let (anchor_chars, head_chars) = (chars(anchor), chars(head));
+        let lines = anchor.line.min(head.line)..=anchor.line.max(head.line);
+
+        let mut primary = None;
+        self.cursors.clear();
+
+        for index in lines {
+            let Some(line) = self.editor.line(index) else {
+                continue;
+            };
+
+            let position = Position {
+                line: index,
+                column: column(&line.text, head_chars),
+            };
+
+            let selection = Position {
+                line: index,
+                column: column(&line.text, anchor_chars),
+            };
+
+            let cursor = Cursor {
+                position,
+                selection: (selection != position).then_some(selection),
+            };
+
+            if index == head.line {
+                primary = Some(cursor);
+            } else {
+                self.cursors.push(cursor);
+            }
+        }
+
+        if let Some(primary) = primary {
+            self.editor.perform(Action::MoveTo(primary));
+        }
+    }
+
+    /// Selects the word at the primary cursor or, if there is a selection,
+    /// adds a cursor selecting its next occurrence after the last cursor.
+    fn select_next_occurrence(&mut self) {
+        let primary = self.editor.cursor();
+
+        let Some(selection) = primary.selection else {
+            self.editor.perform(Action::SelectWord);
+            return;
+        };
+
+        let starts = self.line_starts();
+
+        let range = |cursor: &Cursor| {
+            let position = offset_of(&starts, cursor.position);
+            let selection = cursor
+                .selection
+                .map_or(position, |selection| offset_of(&starts, selection));
+
+            position.min(selection)..position.max(selection)
+        };
+
+        let text = self.contents();
+
+        let start = offset_of(&starts, selection).min(offset_of(&starts, primary.position));
+        let end = offset_of(&starts, selection).max(offset_of(&starts, primary.position));
+
+        let Some(needle) = text.get(start..end) else {
+            return;
+        };
+
+        let after = std::iter::once(&primary)
+            .chain(&self.cursors)
+            .map(|cursor| range(cursor).end)
+            .max()
+            .unwrap_or(end);
+
+        let Some(found) = text
+            .get(after..)
+            .and_then(|rest| rest.find(needle))
+            .map(|index| after + index)
+            .or_else(|| text.find(needle))
+        else {
+            return;
+        };
+
+        let occurrence = found..found + needle.len();
+
+        if std::iter::once(&primary)
+            .chain(&self.cursors)
+            .any(|cursor| range(cursor) == occurrence)
+        {
+            return;
+        }
+
+        self.cursors.push(primary);
+        self.editor.perform(Action::MoveTo(Cursor {
+            position: position_at(&starts, occurrence.end),
+            selection: Some(position_at(&starts, occurrence.start)),
+        }));
+    }
+
+    /// Finds all the matches of the current query.
+    fn find(&mut self) {
+        if self.query.is_empty() {
+            self.matches.clear();
+            return;
+        }
+
+        self.matches = find(&self.contents(), &self.line_starts(), &self.query);
+    }
+
+    /// Returns the index of the match selected by the primary cursor, if any.
+    fn selected_match(&self) -> Option<usize> {
+        let (start, end) = ordered(self.editor.cursor());
+
+        self.matches
+            .iter()
+            .position(|range| range.start == start && range.end == end)
+    }
+
+    /// Selects the closest match after (or before) the primary cursor,
+    /// wrapping around the text.
+    fn select_match(&mut self, is_forward: bool) {
+        let (start, end) = ordered(self.editor.cursor());
+        let key = |position: Position| (position.line, position.column);
+
+        let found = if is_forward {
+            self.matches
+                .iter()
+                .find(|range| key(range.start) >= key(end))
+                .or(self.matches.first())
+        } else {
+            self.matches
+                .iter()
+                .rev()
+                .find(|range| key(range.end) <= key(start))
+                .or(self.matches.last())
+        };
+
+        let Some(range) = found.cloned() else {
+            return;
+        };
+
+        self.cursors.clear();
+        self.editor.perform(Action::MoveTo(Cursor {
+            position: range.end,
+            selection: Some(range.start),
+        }));
+    }
+
+    /// Returns the text of every line, separated by a single `\n`.
+    fn contents(&self) -> String {
+        (0..self.editor.line_count())
+            .filter_map(|index| self.editor.line(index))
+            .map(|line| line.text.into_owned())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the top left corner of a caret at the given [`Position`].
+    fn caret(&self, position: Position) -> Option<Point> {
+        let start = Position {
+            column: 0,
+            ..position
+        };
+
+        if let Some(region) = self.editor.range_bounds(start, position).last() {
+            return Some(Point::new(region.x + region.width, region.y));
+        }
+
+        self.editor
+            .visible_lines()
+            .into_iter()
+            .find(|(line, _)| *line == position.line)
+            .map(|(_, y)| Point::new(0.0, y))
+    }
+
+    /// Returns the offset of the start of every line, as if they were
+    /// separated by a single character, followed by the length of the
+    /// whole text plus one.
+    fn line_starts(&self) -> Vec<usize> {
+        let mut starts = vec![0];
+        let mut offset = 0;
+
+        for index in 0..self.editor.line_count() {
+            if let Some(line) = self.editor.line(index) {
+                offset += line.text.len() + 1;
+            }
+
+            starts.push(offset);
+        }
+
+        starts
+    }
+}
+
 impl<Renderer> Clone for Content<Renderer>
 where
     Renderer: text::Renderer,
@@ -496,11 +1283,18 @@ pub struct State<Highlighter: text::Highlighter> {
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    block_origin: Option<Point>,
+    modifiers: keyboard::Modifiers,
     partial_scroll: f32,
+    horizontal_offset: f32,
+    last_cursor: Option<Cursor>,
+    scrollbar_grab: Option<f32>,
     last_theme: RefCell<Option<String>>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    gutter: f32,
+    hovered: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -568,11 +1362,18 @@ where
             preedit: None,
             last_click: None,
             drag_click: None,
+            block_origin: None,
+            modifiers: keyboard::Modifiers::default(),
             partial_scroll: 0.0,
+            horizontal_offset: 0.0,
+            last_cursor: None,
+            scrollbar_grab: None,
             last_theme: RefCell::default(),
             highlighter: RefCell::new(Highlighter::new(&self.highlighter_settings)),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            gutter: 0.0,
+            hovered: None,
         })
     }
 
@@ -613,16 +1414,78 @@ where
             .min_height(self.min_height)
             .max_height(self.max_height);
 
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let lane = if self.has_lane() { text_size.0 } else { 0.0 };
+
+        let numbers = if self.line_numbers {
+            let digits = internal.editor.line_count().max(1).ilog10() as usize + 1;
+
+            let numbers = Renderer::Paragraph::with_text(Text {
+                content: "0".repeat(digits.max(2)).as_str(),
+                bounds: Size::INFINITE,
+                size: text_size,
+                line_height: self.line_height,
+                font,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: Wrapping::None,
+                hint_factor: renderer.scale_factor(),
+            });
+
+            numbers.min_bounds().width + text_size.0
+        } else if !self.markers.is_empty() {
+            text_size.0 / 2.0
+        } else {
+            0.0
+        };
+
+        state.gutter = lane + numbers;
+
+        let viewport = limits.shrink(self.text_padding(state)).max();
+
+        // Without wrapping, the editor is as wide as its longest line and
+        // scrolled horizontally by the widget
+        let bounds = if self.wrapping == Wrapping::None {
+            Size::new(
+                viewport.width.max(internal.editor.min_bounds().width + 1.0),
+                viewport.height,
+            )
+        } else {
+            viewport
+        };
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
-            self.font.unwrap_or_else(|| renderer.default_font()),
-            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            bounds,
+            font,
+            text_size,
             self.line_height,
             self.wrapping,
             renderer.scale_factor(),
             state.highlighter.borrow_mut().deref_mut(),
         );
 
+        if self.wrapping == Wrapping::None {
+            let cursor = internal.editor.cursor();
+
+            if state.last_cursor != Some(cursor) {
+                state.last_cursor = Some(cursor);
+
+                if let Selection::Caret(caret) = internal.editor.selection() {
+                    state.horizontal_offset =
+                        reveal(state.horizontal_offset, caret.x, viewport.width);
+                }
+            }
+
+            state.horizontal_offset = state
+                .horizontal_offset
+                .clamp(0.0, (bounds.width - viewport.width).max(0.0));
+        } else {
+            state.horizontal_offset = 0.0;
+        }
+
         match self.height {
             Length::Fill | Length::FillPortion(_) | Length::Fixed(_) => {
                 layout::Node::new(limits.max())
@@ -651,6 +1514,107 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            tree.state.downcast_mut::<State<Highlighter>>().modifiers = *modifiers;
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::WheelScrolled { .. }) =
+            event
+        {
+            let state = tree.state.downcast_mut::<State<Highlighter>>();
+            let hovered = self.hovered_diagnostic(state, layout, cursor);
+
+            if state.hovered != hovered {
+                state.hovered = hovered;
+
+                shell.request_redraw();
+            }
+        }
+
+        if let Some(on_gutter_click) = &self.on_gutter_click
+            && let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+        {
+            let state = tree.state.downcast_ref::<State<Highlighter>>();
+            let bounds = layout.bounds();
+
+            if let Some(position) = cursor.position_over(self.gutter_bounds(state, bounds))
+                && let Some(line) = self.gutter_line(state, bounds, position.y)
+            {
+                shell.publish(on_gutter_click(line));
+                shell.capture_event();
+                return;
+            }
+        }
+
+        if self.wrapping == Wrapping::None {
+            let state = tree.state.downcast_mut::<State<Highlighter>>();
+            let bounds = layout.bounds();
+            let content_width = self.content.0.borrow().editor.bounds().width;
+            let scrollbar = self.scrollbar(state, bounds, content_width);
+            let max_offset = content_width - bounds.shrink(self.text_padding(state)).width;
+
+            match event {
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                    let (x, y) = match *delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                        mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                    };
+
+                    let x = if state.modifiers.shift() {
+                        y
+                    } else if x.abs() > y.abs() {
+                        x
+                    } else {
+                        0.0
+                    };
+
+                    if x != 0.0 && max_offset > 0.0 {
+                        state.horizontal_offset =
+                            (state.horizontal_offset - x).clamp(0.0, max_offset);
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some((rail, scroller)) = scrollbar
+                        && let Some(position) = cursor.position_over(rail)
+                    {
+                        let grab = if scroller.contains(position) {
+                            position.x - scroller.x
+                        } else {
+                            scroller.width / 2.0
+                        };
+
+                        state.scrollbar_grab = Some(grab);
+                        state.horizontal_offset =
+                            drag(rail, scroller, position.x - grab) * max_offset;
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(grab) = state.scrollbar_grab
+                        && let Some((rail, scroller)) = scrollbar
+                    {
+                        state.horizontal_offset =
+                            drag(rail, scroller, position.x - grab) * max_offset;
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    state.scrollbar_grab = None;
+                }
+                _ => {}
+            }
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return;
         };
@@ -694,13 +1658,18 @@ where
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.text_padding(state),
             cursor,
             self.key_binding.as_deref(),
         ) {
             match update {
                 Update::Click(click) => {
+                    let is_single = click.kind() == mouse::click::Kind::Single;
+
                     let action = match click.kind() {
+                        mouse::click::Kind::Single if state.modifiers.command() => {
+                            Action::AddCursor(click.position())
+                        }
                         mouse::click::Kind::Single => Action::Click(click.position()),
                         mouse::click::Kind::Double => Action::SelectWord,
                         mouse::click::Kind::Triple => Action::SelectLine,
@@ -709,15 +1678,23 @@ where
                     state.focus = Some(Focus::now());
                     state.last_click = Some(click);
                     state.drag_click = Some(click.kind());
+                    state.block_origin =
+                        (is_single && state.modifiers.alt()).then_some(click.position());
 
                     shell.publish(on_edit(action));
                     shell.capture_event();
                 }
                 Update::Drag(position) => {
-                    shell.publish(on_edit(Action::Drag(position)));
+                    let action = match state.block_origin {
+                        Some(from) => Action::SelectBlock { from, to: position },
+                        None => Action::Drag(position),
+                    };
+
+                    shell.publish(on_edit(action));
                 }
                 Update::Release => {
                     state.drag_click = None;
+                    state.block_origin = None;
                 }
                 Update::Scroll(lines) => {
                     let bounds = self.content.0.borrow().editor.bounds();
@@ -757,6 +1734,7 @@ where
                     fn apply_binding<H: text::Highlighter, R: text::Renderer, Message>(
                         binding: Binding<Message>,
                         content: &Content<R>,
+                        language: Option<&Language>,
                         state: &mut State<H>,
                         on_edit: &dyn Fn(Action) -> Message,
                         clipboard: &mut dyn Clipboard,
@@ -768,6 +1746,7 @@ where
                             Binding::Unfocus => {
                                 state.focus = None;
                                 state.drag_click = None;
+                                state.block_origin = None;
                             }
                             Binding::Copy => {
                                 if let Some(selection) = content.selection() {
@@ -801,11 +1780,28 @@ where
                             Binding::SelectAll => {
                                 publish(Action::SelectAll);
                             }
+                            Binding::SelectNextOccurrence => {
+                                publish(Action::SelectNextOccurrence);
+                            }
                             Binding::Insert(c) => {
                                 publish(Action::Edit(Edit::Insert(c)));
                             }
                             Binding::Enter => {
                                 publish(Action::Edit(Edit::Enter));
+
+                                if let Some(language) = language {
+                                    for action in content.indentation(language) {
+                                        publish(action);
+                                    }
+                                }
+                            }
+                            Binding::ToggleComment => {
+                                if let Some(prefix) = language.and_then(|language| language.comment)
+                                {
+                                    for action in content.toggle_comment(prefix) {
+                                        publish(action);
+                                    }
+                                }
                             }
                             Binding::Backspace => {
                                 publish(Action::Edit(Edit::Backspace));
@@ -813,10 +1809,17 @@ where
                             Binding::Delete => {
                                 publish(Action::Edit(Edit::Delete));
                             }
+                            Binding::Undo => {
+                                publish(Action::Undo);
+                            }
+                            Binding::Redo => {
+                                publish(Action::Redo);
+                            }
                             Binding::Sequence(sequence) => {
                                 for binding in sequence {
                                     apply_binding(
-                                        binding, content, state, on_edit, clipboard, shell,
+                                        binding, content, language, state, on_edit, clipboard,
+                                        shell,
                                     );
                                 }
                             }
@@ -830,7 +1833,15 @@ where
                         shell.capture_event();
                     }
 
-                    apply_binding(binding, self.content, state, on_edit, clipboard, shell);
+                    apply_binding(
+                        binding,
+                        self.content,
+                        self.language.as_ref(),
+                        state,
+                        on_edit,
+                        clipboard,
+                        shell,
+                    );
 
                     if let Some(focus) = &mut state.focus {
                         focus.updated_at = Instant::now();
@@ -874,7 +1885,7 @@ where
         _defaults: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
 
@@ -912,7 +1923,155 @@ where
             style.background,
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        if state.gutter > 0.0 {
+            let gutter = self.gutter_bounds(state, bounds);
+            let line_height = f32::from(self.line_height.to_absolute(text_size));
+            let lane = if self.has_lane() { text_size.0 } else { 0.0 };
+
+            let wrap_indicator = style
+                .wrap_indicator
+                .filter(|_| self.line_numbers && self.wrapping != Wrapping::None);
+
+            for (line, y) in internal.editor.visible_lines() {
+                let top = text_bounds.y + y;
+
+                if let Some(color) = wrap_indicator
+                    && let Some(text) = internal.editor.line(line).map(|line| line.text.len())
+                {
+                    let start = Position { line, column: 0 };
+                    let end = Position { line, column: text };
+
+                    for region in internal.editor.range_bounds(start, end).iter().skip(1) {
+                        renderer.fill_text(
+                            Text {
+                                content: "↪".to_owned(),
+                                bounds: Size::new(gutter.width, f32::INFINITY),
+                                size: text_size,
+                                line_height: self.line_height,
+                                font,
+                                align_x: text::Alignment::Right,
+                                align_y: alignment::Vertical::Top,
+                                shaping: text::Shaping::Advanced,
+                                wrapping: Wrapping::None,
+                                hint_factor: renderer.scale_factor(),
+                            },
+                            Point::new(
+                                gutter.x + gutter.width - text_size.0 / 2.0,
+                                text_bounds.y + region.y,
+                            ),
+                            color,
+                            gutter,
+                        );
+                    }
+                }
+
+                for marker in self.markers.iter().filter(|marker| marker.line == line) {
+                    let (region, radius, color) = match marker.kind {
+                        Mark::Breakpoint => {
+                            let size = lane.min(line_height) * 0.6;
+
+                            (
+                                Rectangle::new(
+                                    Point::new(
+                                        gutter.x + (lane - size) / 2.0,
+                                        top + (line_height - size) / 2.0,
+                                    ),
+                                    Size::new(size, size),
+                                ),
+                                size / 2.0,
+                                style.breakpoint,
+                            )
+                        }
+                        Mark::Bookmark => (
+                            Rectangle::new(
+                                Point::new(gutter.x + lane * 0.3, top + line_height * 0.15),
+                                Size::new(lane * 0.4, line_height * 0.7),
+                            ),
+                            1.0,
+                            style.bookmark,
+                        ),
+                        Mark::Added | Mark::Modified => (
+                            Rectangle::new(
+                                Point::new(gutter.x + gutter.width - 3.0, top),
+                                Size::new(3.0, line_height),
+                            ),
+                            0.0,
+                            if marker.kind == Mark::Added {
+                                style.added
+                            } else {
+                                style.modified
+                            },
+                        ),
+                        Mark::Removed => (
+                            Rectangle::new(
+                                Point::new(gutter.x + gutter.width - 6.0, top + line_height - 1.5),
+                                Size::new(6.0, 3.0),
+                            ),
+                            1.5,
+                            style.removed,
+                        ),
+                    };
+
+                    if let Some(region) = gutter.intersection(&region) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region,
+                                border: Border {
+                                    radius: radius.into(),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+
+        if self.line_numbers {
+            let gutter = self.gutter_bounds(state, bounds);
+            let current_line = internal.editor.cursor().position.line;
+
+            for (line, y) in internal.editor.visible_lines() {
+                let severity = self
+                    .diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.start.line == line)
+                    .map(|diagnostic| diagnostic.severity)
+                    .max();
+
+                let color = match severity {
+                    Some(severity) => style.severity(severity),
+                    None if line == current_line => style.value,
+                    None => style.gutter,
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: (line + 1).to_string(),
+                        bounds: Size::new(gutter.width, f32::INFINITY),
+                        size: text_size,
+                        line_height: self.line_height,
+                        font,
+                        align_x: text::Alignment::Right,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        wrapping: Wrapping::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(
+                        gutter.x + gutter.width - text_size.0 / 2.0,
+                        text_bounds.y + y,
+                    ),
+                    color,
+                    gutter,
+                );
+            }
+        }
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -920,7 +2079,7 @@ where
                     Text {
                         content: placeholder.into_owned(),
                         bounds: text_bounds.size(),
-                        size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                        size: text_size,
                         line_height: self.line_height,
                         font,
                         align_x: text::Alignment::Default,
@@ -937,32 +2096,132 @@ where
         } else {
             renderer.fill_editor(
                 &internal.editor,
-                text_bounds.position(),
+                text_bounds.position() - Vector::new(state.horizontal_offset, 0.0),
                 style.value,
                 text_bounds,
             );
         }
 
-        let translation = text_bounds.position() - Point::ORIGIN;
+        let translation =
+            text_bounds.position() - Point::ORIGIN - Vector::new(state.horizontal_offset, 0.0);
+
+        if let Some((_rail, scroller)) =
+            self.scrollbar(state, bounds, internal.editor.bounds().width)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scroller,
+                    border: Border {
+                        radius: (SCROLLBAR_WIDTH / 2.0).into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.scroller,
+            );
+        }
+
+        if !internal.matches.is_empty() {
+            let visible_lines = internal.editor.visible_lines();
+
+            if let (Some((first, _)), Some((last, _))) =
+                (visible_lines.first(), visible_lines.last())
+            {
+                for range in internal
+                    .matches
+                    .iter()
+                    .filter(|range| range.end.line >= *first && range.start.line <= *last)
+                {
+                    for region in internal
+                        .editor
+                        .range_bounds(range.start, range.end)
+                        .into_iter()
+                        .filter_map(|region| text_bounds.intersection(&(region + translation)))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region,
+                                border: Border {
+                                    radius: 2.0.into(),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.matched,
+                        );
+                    }
+                }
+            }
+        }
+
+        for diagnostic in self.diagnostics {
+            for region in internal
+                .editor
+                .range_bounds(diagnostic.start, diagnostic.end)
+            {
+                draw_squiggle(
+                    renderer,
+                    region + translation,
+                    text_bounds,
+                    style.severity(diagnostic.severity),
+                );
+            }
+        }
+
+        if let Some(language) = &self.language
+            && state.focus.is_some()
+            && matches!(internal.editor.selection(), Selection::Caret(_))
+        {
+            let position = internal.editor.cursor().position;
+
+            if let Some(brackets) = matching_brackets(
+                |index| internal.editor.line(index).map(|line| line.text),
+                position,
+                language,
+            ) {
+                for bracket in brackets {
+                    let end = Position {
+                        column: bracket.column + 1,
+                        ..bracket
+                    };
+
+                    for region in internal
+                        .editor
+                        .range_bounds(bracket, end)
+                        .into_iter()
+                        .filter_map(|region| text_bounds.intersection(&(region + translation)))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region,
+                                border: Border {
+                                    radius: 2.0.into(),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.bracket,
+                        );
+                    }
+                }
+            }
+        }
 
         if let Some(focus) = state.focus.as_ref() {
+            let caret_size = Size::new(
+                if renderer::CRISP {
+                    (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+                } else {
+                    1.0
+                },
+                self.line_height.to_absolute(text_size).into(),
+            );
+
+            let caret = |position: Point| Rectangle::new(position + translation, caret_size);
+
             match internal.editor.selection() {
                 Selection::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor = Rectangle::new(
-                        position + translation,
-                        Size::new(
-                            if renderer::CRISP {
-                                (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
-                            } else {
-                                1.0
-                            },
-                            self.line_height
-                                .to_absolute(
-                                    self.text_size.unwrap_or_else(|| renderer.default_size()),
-                                )
-                                .into(),
-                        ),
-                    );
+                    let cursor = caret(position);
 
                     if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
                         renderer.fill_quad(
@@ -993,20 +2252,122 @@ where
                     renderer.fill_quad(renderer::Quad::default(), Color::TRANSPARENT);
                 }
             }
+
+            for cursor in &internal.cursors {
+                if cursor.selection.is_some() {
+                    let (start, end) = ordered(*cursor);
+
+                    for range in internal
+                        .editor
+                        .range_bounds(start, end)
+                        .into_iter()
+                        .filter_map(|range| text_bounds.intersection(&(range + translation)))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: range,
+                                ..renderer::Quad::default()
+                            },
+                            style.selection,
+                        );
+                    }
+                } else if focus.is_cursor_visible()
+                    && let Some(position) = internal.caret(cursor.position)
+                    && let Some(clipped_cursor) = text_bounds.intersection(&caret(position))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: clipped_cursor,
+                            ..renderer::Quad::default()
+                        },
+                        style.value,
+                    );
+                }
+            }
+        }
+
+        if let Some(diagnostic) = state.hovered.and_then(|index| self.diagnostics.get(index))
+            && let Some(region) = internal
+                .editor
+                .range_bounds(diagnostic.start, diagnostic.end)
+                .first()
+                .copied()
+        {
+            let region = region + translation;
+            let padding = text_size.0 / 2.0;
+
+            let message = Renderer::Paragraph::with_text(Text {
+                content: diagnostic.message.as_str(),
+                bounds: Size::new(
+                    (viewport.width - padding * 2.0).clamp(0.0, text_size.0 * 30.0),
+                    f32::INFINITY,
+                ),
+                size: text_size,
+                line_height: self.line_height,
+                font,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: Wrapping::Word,
+                hint_factor: renderer.scale_factor(),
+            });
+
+            let size = message
+                .min_bounds()
+                .expand(Size::new(padding, padding) * 2.0);
+
+            let x = region
+                .x
+                .min(viewport.x + viewport.width - size.width)
+                .max(viewport.x);
+
+            let y = if region.y + region.height + size.height <= viewport.y + viewport.height {
+                region.y + region.height
+            } else {
+                region.y - size.height
+            };
+
+            let hint = Rectangle::new(Point::new(x, y), size);
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: hint,
+                        border: Border {
+                            color: style.severity(diagnostic.severity),
+                            ..style.border
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                renderer.fill_paragraph(
+                    &message,
+                    hint.position() + Vector::new(padding, padding),
+                    style.value,
+                    hint,
+                );
+            });
         }
     }
 
     fn mouse_interaction(
         &self,
-        _tree: &widget::Tree,
+        tree: &widget::Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let is_disabled = self.on_edit.is_none();
+        let state = tree.state.downcast_ref::<State<Highlighter>>();
 
-        if cursor.is_over(layout.bounds()) {
+        if self.on_gutter_click.is_some()
+            && cursor.is_over(self.gutter_bounds(state, layout.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(layout.bounds()) {
             if is_disabled {
                 mouse::Interaction::NotAllowed
             } else {
@@ -1065,6 +2426,9 @@ pub enum Binding<Message> {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Select the word at the cursor or, if there is a selection already,
+    /// add a cursor selecting its next occurrence.
+    SelectNextOccurrence,
     /// Insert the given character.
     Insert(char),
     /// Break the current line.
@@ -1073,6 +2437,13 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Toggle the line comments of the selected lines, if the
+    /// [`Language`] of the [`TextEditor`] has any.
+    ToggleComment,
+    /// Undo the last group of edits.
+    Undo,
+    /// Redo the last undone group of edits.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -1123,6 +2494,11 @@ impl<Message> Binding<Message> {
             Some('x') if modifiers.command() => Some(Self::Cut),
             Some('v') if modifiers.command() && !modifiers.alt() => Some(Self::Paste),
             Some('a') if modifiers.command() => Some(Self::SelectAll),
+            Some('d') if modifiers.command() => Some(Self::SelectNextOccurrence),
+            Some('/') if modifiers.command() => Some(Self::ToggleComment),
+            Some('z') if modifiers.command() && modifiers.shift() => Some(Self::Redo),
+            Some('z') if modifiers.command() => Some(Self::Undo),
+            Some('y') if modifiers.command() => Some(Self::Redo),
             _ => None,
         };
 
@@ -1212,8 +2588,8 @@ impl<Message> Update<Message> {
             Event::Mouse(event) => match event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
-                        let cursor_position =
-                            cursor_position - Vector::new(padding.left, padding.top);
+                        let cursor_position = cursor_position
+                            - Vector::new(padding.left - state.horizontal_offset, padding.top);
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -1231,8 +2607,8 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonReleased(mouse::Button::Left) => Some(Update::Release),
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
-                        let cursor_position =
-                            cursor.position_in(bounds)? - Vector::new(padding.left, padding.top);
+                        let cursor_position = cursor.position_in(bounds)?
+                            - Vector::new(padding.left - state.horizontal_offset, padding.top);
 
                         Some(Update::Drag(cursor_position))
                     }
@@ -1318,6 +2694,418 @@ fn motion(key: key::Named) -> Option<Motion> {
     }
 }
 
+/// The language services of a [`TextEditor`].
+///
+/// A [`Language`] drives bracket matching, auto-indentation, and comment
+/// toggling. Brackets inside strings or comments are not told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Language {
+    /// The prefix of a line comment, if the language has line comments.
+    pub comment: Option<&'static str>,
+    /// The pairs of opening and closing brackets of the language.
+    pub brackets: &'static [(char, char)],
+}
+
+impl Language {
+    /// A [`Language`] with the usual brackets and no comments.
+    pub const PLAIN: Self = Self {
+        comment: None,
+        brackets: &[('(', ')'), ('[', ']'), ('{', '}')],
+    };
+
+    /// Returns the [`Language`] of the given syntax token, like the
+    /// extension of a file.
+    ///
+    /// Unknown tokens produce a [`Language::PLAIN`].
+    pub fn from_token(token: &str) -> Self {
+        let comment = match token.to_ascii_lowercase().as_str() {
+            "rs" | "rust" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "java" | "kt" | "scala"
+            | "swift" | "go" | "dart" | "zig" | "js" | "jsx" | "ts" | "tsx" | "glsl" | "wgsl"
+            | "proto" => Some("//"),
+            "py" | "python" | "rb" | "ruby" | "sh" | "bash" | "zsh" | "fish" | "pl" | "r"
+            | "toml" | "yaml" | "yml" | "ex" | "exs" | "nix" | "cmake" | "dockerfile"
+            | "makefile" => Some("#"),
+            "lua" | "sql" | "hs" | "haskell" | "elm" | "ada" => Some("--"),
+            "lisp" | "el" | "clj" | "scm" | "ini" | "asm" => Some(";"),
+            "tex" | "latex" | "erl" | "m" => Some("%"),
+            "vim" => Some("\""),
+            _ => None,
+        };
+
+        Self {
+            comment,
+            ..Self::PLAIN
+        }
+    }
+
+    /// Sets the prefix of a line comment of the [`Language`].
+    pub fn comment(self, comment: &'static str) -> Self {
+        Self {
+            comment: Some(comment),
+            ..self
+        }
+    }
+
+    /// Sets the pairs of brackets of the [`Language`].
+    pub fn brackets(self, brackets: &'static [(char, char)]) -> Self {
+        Self { brackets, ..self }
+    }
+
+    fn opens(&self, c: char) -> bool {
+        self.brackets.iter().any(|(open, _)| *open == c)
+    }
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+/// A decoration of a range of text in a [`TextEditor`], like a
+/// diagnostic produced by a language server.
+///
+/// The columns of its positions are byte offsets in their line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The start of the range of the [`Diagnostic`].
+    pub start: Position,
+    /// The end of the range of the [`Diagnostic`].
+    pub end: Position,
+    /// The [`Severity`] of the [`Diagnostic`].
+    pub severity: Severity,
+    /// The message shown when the [`Diagnostic`] is hovered.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a new [`Diagnostic`] of the given range, [`Severity`],
+    /// and message.
+    pub fn new(
+        start: Position,
+        end: Position,
+        severity: Severity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            severity,
+            message: message.into(),
+        }
+    }
+}
+
+/// A decoration of a line in the gutter of a [`TextEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    /// The index of the line of the [`Marker`].
+    pub line: usize,
+    /// The [`Mark`] of the [`Marker`].
+    pub kind: Mark,
+}
+
+impl Marker {
+    /// Creates a new [`Marker`] with the given [`Mark`] at the given line.
+    pub fn new(line: usize, kind: Mark) -> Self {
+        Self { line, kind }
+    }
+}
+
+/// The kind of a [`Marker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// A breakpoint, drawn as a circle.
+    Breakpoint,
+    /// A bookmark, drawn as a tab.
+    Bookmark,
+    /// A line added since the last revision.
+    Added,
+    /// A line modified since the last revision.
+    Modified,
+    /// Some lines removed right after this one since the last revision.
+    Removed,
+}
+
+/// The severity of a [`Diagnostic`], from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Some information or a hint.
+    Info,
+    /// A potential problem.
+    Warning,
+    /// An error.
+    Error,
+}
+
+/// The maximum amount of lines scanned for a matching bracket.
+const BRACKET_SCAN_LINES: usize = 1_000;
+
+/// Returns the positions of the bracket next to the given position and of
+/// its matching pair, if any.
+fn matching_brackets<'a>(
+    line: impl Fn(usize) -> Option<Cow<'a, str>>,
+    position: Position,
+    language: &Language,
+) -> Option<[Position; 2]> {
+    let text = line(position.line)?;
+
+    let after = text
+        .get(position.column..)
+        .and_then(|text| text.chars().next())
+        .map(|c| (position.column, c));
+
+    let before = text
+        .get(..position.column)
+        .and_then(|text| text.char_indices().next_back());
+
+    let (column, bracket, open, close) =
+        [after, before]
+            .into_iter()
+            .flatten()
+            .find_map(|(column, c)| {
+                language
+                    .brackets
+                    .iter()
+                    .find(|(open, close)| *open == c || *close == c)
+                    .map(|(open, close)| (column, c, *open, *close))
+            })?;
+
+    let start = Position {
+        line: position.line,
+        column,
+    };
+
+    let mut depth = 0usize;
+
+    if bracket == open {
+        for index in position.line..position.line + BRACKET_SCAN_LINES {
+            let text = if index == position.line {
+                text.clone()
+            } else {
+                line(index)?
+            };
+
+            let skip = if index == position.line { column } else { 0 };
+
+            for (column, c) in text.char_indices().skip_while(|(i, _)| *i < skip) {
+                if c == close && depth == 1 {
+                    return Some([
+                        start,
+                        Position {
+                            line: index,
+                            column,
+                        },
+                    ]);
+                } else if c == open {
+                    depth += 1;
+                } else if c == close {
+                    depth -= 1;
+                }
+            }
+        }
+    } else {
+        for index in (position.line.saturating_sub(BRACKET_SCAN_LINES)..=position.line).rev() {
+            let text = if index == position.line {
+                text.clone()
+            } else {
+                line(index)?
+            };
+
+            let end = if index == position.line {
+                column + bracket.len_utf8()
+            } else {
+                text.len()
+            };
+
+            for (column, c) in text[..end].char_indices().rev() {
+                if c == open && depth == 1 {
+                    return Some([
+                        start,
+                        Position {
+                            line: index,
+                            column,
+                        },
+                    ]);
+                } else if c == close {
+                    depth += 1;
+                } else if c == open {
+                    depth -= 1;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Comments the given lines out or, if they are all comments already,
+/// uncomments them.
+///
+/// Comments are inserted at the smallest indentation of the lines, and
+/// blank lines are left untouched.
+fn toggle_comment(lines: &[String], prefix: &str) -> Vec<String> {
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    let is_commented = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .all(|line| line.trim_start().starts_with(prefix));
+
+    if is_commented {
+        return lines
+            .iter()
+            .map(|line| {
+                let content = line.trim_start();
+                let indentation = &line[..line.len() - content.len()];
+
+                match content.strip_prefix(prefix) {
+                    Some(rest) => {
+                        format!("{indentation}{}", rest.strip_prefix(' ').unwrap_or(rest))
+                    }
+                    None => line.clone(),
+                }
+            })
+            .collect();
+    }
+
+    let indentation = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| {
+            if is_blank(line) {
+                line.clone()
+            } else {
+                let (indentation, content) = line.split_at(indentation);
+
+                format!("{indentation}{prefix} {content}")
+            }
+        })
+        .collect()
+}
+
+/// Returns the ranges of all the occurrences of the query in a text whose
+/// lines start at the given offsets.
+fn find(text: &str, starts: &[usize], query: &str) -> Vec<ops::Range<Position>> {
+    text.match_indices(query)
+        .map(|(offset, matched)| {
+            position_at(starts, offset)..position_at(starts, offset + matched.len())
+        })
+        .collect()
+}
+
+/// The width of the horizontal scrollbar of a [`TextEditor`].
+const SCROLLBAR_WIDTH: f32 = 6.0;
+
+/// Returns the horizontal offset that reveals the caret at the given `x`
+/// in a viewport of the given width, scrolling as little as possible.
+fn reveal(offset: f32, x: f32, width: f32) -> f32 {
+    if x < offset {
+        x
+    } else if x + 1.0 > offset + width {
+        x + 1.0 - width
+    } else {
+        offset
+    }
+}
+
+/// Returns the relative offset of the scroller of a scrollbar dragged so
+/// that it starts at the given `x`.
+fn drag(rail: Rectangle, scroller: Rectangle, x: f32) -> f32 {
+    let range = rail.width - scroller.width;
+
+    if range > 0.0 {
+        ((x - rail.x) / range).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns the start and end positions of the given [`Cursor`], in order.
+fn ordered(cursor: Cursor) -> (Position, Position) {
+    let anchor = cursor.selection.unwrap_or(cursor.position);
+
+    if (anchor.line, anchor.column) <= (cursor.position.line, cursor.position.column) {
+        (anchor, cursor.position)
+    } else {
+        (cursor.position, anchor)
+    }
+}
+
+/// Returns the offset of the given [`Position`] in a text whose lines
+/// start at the given offsets.
+fn offset_of(starts: &[usize], position: Position) -> usize {
+    let last = starts.len().saturating_sub(2);
+
+    starts.get(position.line.min(last)).copied().unwrap_or(0) + position.column
+}
+
+/// Returns the [`Position`] at the given offset in a text whose lines
+/// start at the given offsets.
+fn position_at(starts: &[usize], offset: usize) -> Position {
+    let line = starts
+        .partition_point(|start| *start <= offset)
+        .saturating_sub(1)
+        .min(starts.len().saturating_sub(2));
+
+    Position {
+        line,
+        column: offset - starts.get(line).copied().unwrap_or(0),
+    }
+}
+
+/// Returns the byte index of the character at the given index in the text,
+/// or its length if it is too short.
+fn column(text: &str, chars: usize) -> usize {
+    text.char_indices()
+        .nth(chars)
+        .map_or(text.len(), |(index, _)| index)
+}
+
+/// Draws a wavy underline at the bottom of the given region.
+fn draw_squiggle<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    region: Rectangle,
+    clip: Rectangle,
+    color: Color,
+) {
+    const STEP: f32 = 2.0;
+
+    let right = region.x + region.width;
+    let bottom = region.y + region.height;
+
+    let mut x = region.x;
+    let mut is_raised = false;
+
+    while x < right {
+        let segment = Rectangle {
+            x,
+            y: bottom - if is_raised { 2.0 } else { 1.0 },
+            width: STEP.min(right - x),
+            height: 1.0,
+        };
+
+        if let Some(segment) = clip.intersection(&segment) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: segment,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        x += STEP;
+        is_raised = !is_raised;
+    }
+}
+
 /// The possible status of a [`TextEditor`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -1347,6 +3135,44 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the line numbers in the gutter of the text input.
+    pub gutter: Color,
+    /// The [`Color`] behind the brackets matching at the cursor.
+    pub bracket: Color,
+    /// The [`Color`] behind the matches of the current search.
+    pub matched: Color,
+    /// The [`Color`] of the breakpoint [`Marker`]s in the gutter.
+    pub breakpoint: Color,
+    /// The [`Color`] of the bookmark [`Marker`]s in the gutter.
+    pub bookmark: Color,
+    /// The [`Color`] of the [`Marker`]s of added lines in the gutter.
+    pub added: Color,
+    /// The [`Color`] of the [`Marker`]s of modified lines in the gutter.
+    pub modified: Color,
+    /// The [`Color`] of the [`Marker`]s of removed lines in the gutter.
+    pub removed: Color,
+    /// The [`Color`] of the scroller of the horizontal scrollbar, shown when
+    /// the lines are not wrapped.
+    pub scroller: Color,
+    /// The [`Color`] of the indicator shown in the gutter next to the
+    /// continuation of wrapped lines, if any.
+    pub wrap_indicator: Option<Color>,
+    /// The [`Color`] of the [`Diagnostic`]s with an error [`Severity`].
+    pub error: Color,
+    /// The [`Color`] of the [`Diagnostic`]s with a warning [`Severity`].
+    pub warning: Color,
+    /// The [`Color`] of the [`Diagnostic`]s with an info [`Severity`].
+    pub info: Color,
+}
+
+impl Style {
+    fn severity(&self, severity: Severity) -> Color {
+        match severity {
+            Severity::Info => self.info,
+            Severity::Warning => self.warning,
+            Severity::Error => self.error,
+        }
+    }
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1390,6 +3216,19 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.secondary.base.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        gutter: palette.background.strongest.color,
+        bracket: palette.background.strong.color,
+        matched: palette.warning.weak.color,
+        breakpoint: palette.danger.base.color,
+        bookmark: palette.primary.base.color,
+        added: palette.success.base.color,
+        modified: palette.warning.base.color,
+        removed: palette.danger.strong.color,
+        scroller: palette.background.strong.color,
+        wrap_indicator: Some(palette.background.strong.color),
+        error: palette.danger.base.color,
+        warning: palette.warning.base.color,
+        info: palette.primary.base.color,
     };
 
     match status {
@@ -1438,3 +3277,73 @@ pub(crate) fn convert_macos_shortcut(
 
     Some(keyboard::Key::Named(key))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_services() {
+        let source = ["fn main() {", "    call(a[0]);", "}"];
+        let line = |index: usize| source.get(index).map(|line| Cow::Borrowed(*line));
+        let at = |line, column| Position { line, column };
+
+        assert_eq!(
+            matching_brackets(line, at(0, 11), &Language::PLAIN),
+            Some([at(0, 10), at(2, 0)])
+        );
+        assert_eq!(
+            matching_brackets(line, at(1, 13), &Language::PLAIN),
+            Some([at(1, 13), at(1, 8)])
+        );
+        assert_eq!(matching_brackets(line, at(0, 2), &Language::PLAIN), None);
+
+        let lines = ["    a".to_owned(), String::new(), "  b".to_owned()];
+        let commented = toggle_comment(&lines, "//");
+
+        assert_eq!(commented, ["  //   a", "", "  // b"]);
+        assert_eq!(toggle_comment(&commented, "//"), ["    a", "", "  b"]);
+    }
+
+    #[test]
+    fn cursor_offsets() {
+        let starts = [0, 4, 5, 11];
+        let at = |line, column| Position { line, column };
+
+        assert_eq!(offset_of(&starts, at(0, 3)), 3);
+        assert_eq!(offset_of(&starts, at(2, 2)), 7);
+        assert_eq!(position_at(&starts, 4), at(1, 0));
+        assert_eq!(position_at(&starts, 10), at(2, 5));
+
+        for offset in 0..11 {
+            assert_eq!(offset_of(&starts, position_at(&starts, offset)), offset);
+        }
+
+        assert_eq!(column("añb", 2), 3);
+        assert_eq!(column("ab", 5), 2);
+    }
+
+    #[test]
+    fn caret_is_revealed_horizontally() {
+        assert_eq!(reveal(0.0, 50.0, 100.0), 0.0);
+        assert_eq!(reveal(0.0, 150.0, 100.0), 51.0);
+        assert_eq!(reveal(80.0, 20.0, 100.0), 20.0);
+
+        let rail = Rectangle::new(Point::new(10.0, 0.0), Size::new(100.0, 6.0));
+        let scroller = Rectangle::new(Point::new(10.0, 0.0), Size::new(20.0, 6.0));
+
+        assert_eq!(drag(rail, scroller, 50.0), 0.5);
+        assert_eq!(drag(rail, scroller, 200.0), 1.0);
+    }
+
+    #[test]
+    fn search_matches() {
+        let at = |line, column| Position { line, column };
+
+        assert_eq!(
+            find("let a\na = a;", &[0, 6, 13], "a"),
+            [at(0, 4)..at(0, 5), at(1, 0)..at(1, 1), at(1, 4)..at(1, 5)]
+        );
+        assert_eq!(find("a\nb\na", &[0, 2, 4, 6], "a\nb"), [at(0, 0)..at(1, 1)]);
+    }
+}
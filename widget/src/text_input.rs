@@ -40,6 +40,8 @@ pub use value::Value;
 
 use editor::Editor;
 
+use crate::history::{self, History};
+
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
@@ -111,6 +113,8 @@ where
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    intercept_paste: Option<Box<dyn Fn(String) -> Option<String> + 'a>>,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
@@ -142,6 +146,8 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            filter: None,
+            intercept_paste: None,
             icon: None,
             class: Theme::default(),
             last_status: None,
@@ -206,6 +212,44 @@ where
         self
     }
 
+    /// Sets the predicate that characters must satisfy to be entered into
+    /// the [`TextInput`].
+    ///
+    /// Rejected characters are dropped when typed, pasted, or committed by an
+    /// input method.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Restricts the characters that can be entered into the [`TextInput`]
+    /// to the given set.
+    ///
+    /// This is a shorthand for calling [`filter`] with a predicate that
+    /// checks the set.
+    ///
+    /// [`filter`]: Self::filter
+    pub fn allow(self, characters: impl Into<String>) -> Self {
+        let characters = characters.into();
+
+        self.filter(move |c| characters.contains(c))
+    }
+
+    /// Sets the function that transforms the text pasted into the
+    /// [`TextInput`] before it is inserted.
+    ///
+    /// Returning `None` rejects the paste altogether. The resulting text is
+    /// still subject to any [`filter`].
+    ///
+    /// [`filter`]: Self::filter
+    pub fn intercept_paste(
+        mut self,
+        intercept_paste: impl Fn(String) -> Option<String> + 'a,
+    ) -> Self {
+        self.intercept_paste = Some(Box::new(intercept_paste));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -405,6 +449,16 @@ where
         }
     }
 
+    fn allows(&self, c: char) -> bool {
+        self.filter.as_ref().is_none_or(|filter| filter(c))
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        text.chars()
+            .filter(|c| !c.is_control() && self.allows(*c))
+            .collect()
+    }
+
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
@@ -700,6 +754,8 @@ where
                     let click =
                         mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
 
+                    state.history.interrupt();
+
                     match click.kind() {
                         click::Kind::Single => {
                             let position = if target > 0.0 {
@@ -866,6 +922,14 @@ where
                                 );
                             }
 
+                            record(
+                                &mut state.history,
+                                history::Kind::Other,
+                                &self.value,
+                                state.cursor,
+                                self.is_secure,
+                            );
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
@@ -888,17 +952,34 @@ where
                             let content = match state.is_pasting.take() {
                                 Some(content) => content,
                                 None => {
-                                    let content: String = clipboard
+                                    let content = clipboard
                                         .read(clipboard::Kind::Standard)
-                                        .unwrap_or_default()
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
+                                        .unwrap_or_default();
+
+                                    let content = match &self.intercept_paste {
+                                        Some(intercept_paste) => {
+                                            let Some(content) = intercept_paste(content) else {
+                                                shell.capture_event();
+                                                return;
+                                            };
 
-                                    Value::new(&content)
+                                            content
+                                        }
+                                        None => content,
+                                    };
+
+                                    Value::new(&self.sanitize(&content))
                                 }
                             };
 
+                            record(
+                                &mut state.history,
+                                history::Kind::Other,
+                                &self.value,
+                                state.cursor,
+                                self.is_secure,
+                            );
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.paste(content.clone());
 
@@ -915,6 +996,35 @@ where
                             update_cache(state, &self.value);
                             return;
                         }
+                        Some(c @ ('z' | 'y'))
+                            if state.keyboard_modifiers.command() && !self.is_secure =>
+                        {
+                            let Some(on_input) = &self.on_input else {
+                                return;
+                            };
+
+                            let current = (self.value.to_string(), state.cursor);
+
+                            let restored = if c == 'y' || state.keyboard_modifiers.shift() {
+                                state.history.redo(current)
+                            } else {
+                                state.history.undo(current)
+                            };
+
+                            if let Some((contents, cursor)) = restored {
+                                self.value = Value::new(&contents);
+                                state.cursor = cursor;
+                                state.is_pasting = None;
+
+                                shell.publish((on_input)(contents));
+
+                                focus.updated_at = Instant::now();
+                                update_cache(state, &self.value);
+                            }
+
+                            shell.capture_event();
+                            return;
+                        }
                         Some('a') if state.keyboard_modifiers.command() => {
                             let cursor_before = state.cursor;
 
@@ -940,6 +1050,23 @@ where
                         state.is_pasting = None;
 
                         if let Some(c) = text.chars().next().filter(|c| !c.is_control()) {
+                            if !self.allows(c) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            record(
+                                &mut state.history,
+                                history::Kind::Typing,
+                                &self.value,
+                                state.cursor,
+                                self.is_secure,
+                            );
+
+                            if c.is_whitespace() {
+                                state.history.interrupt();
+                            }
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
 
                             editor.insert(c);
@@ -960,6 +1087,18 @@ where
                     #[cfg(target_os = "macos")]
                     let modified_key = macos_shortcut.as_ref().unwrap_or(modified_key);
 
+                    if matches!(
+                        modified_key.as_ref(),
+                        keyboard::Key::Named(
+                            key::Named::Home
+                                | key::Named::End
+                                | key::Named::ArrowLeft
+                                | key::Named::ArrowRight
+                        )
+                    ) {
+                        state.history.interrupt();
+                    }
+
                     match modified_key.as_ref() {
                         keyboard::Key::Named(key::Named::Enter) => {
                             if let Some(on_submit) = self.on_submit.clone() {
@@ -983,6 +1122,14 @@ where
                                 }
                             }
 
+                            record(
+                                &mut state.history,
+                                history::Kind::Deleting,
+                                &self.value,
+                                state.cursor,
+                                self.is_secure,
+                            );
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
 
@@ -1010,6 +1157,14 @@ where
                                 }
                             }
 
+                            record(
+                                &mut state.history,
+                                history::Kind::Deleting,
+                                &self.value,
+                                state.cursor,
+                                self.is_secure,
+                            );
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
@@ -1183,8 +1338,23 @@ where
                             return;
                         };
 
+                        let text = self.sanitize(text);
+
+                        if text.is_empty() {
+                            shell.capture_event();
+                            return;
+                        }
+
+                        record(
+                            &mut state.history,
+                            history::Kind::Other,
+                            &self.value,
+                            state.cursor,
+                            self.is_secure,
+                        );
+
                         let mut editor = Editor::new(&mut self.value, &mut state.cursor);
-                        editor.paste(Value::new(text));
+                        editor.paste(Value::new(&text));
 
                         focus.updated_at = Instant::now();
                         state.is_pasting = None;
@@ -1346,9 +1516,23 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    history: History<(String, Cursor)>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+fn record(
+    history: &mut History<(String, Cursor)>,
+    kind: history::Kind,
+    value: &Value,
+    cursor: Cursor,
+    is_secure: bool,
+) {
+    // Secure inputs do not keep their previous values around
+    if !is_secure {
+        history.record(kind, Instant::now(), || (value.to_string(), cursor));
+    }
+}
+
 fn state<Renderer: text::Renderer>(tree: &mut Tree) -> &mut State<Renderer::Paragraph> {
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
@@ -7,8 +7,8 @@ use crate::core::theme;
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Background, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
-    Widget,
+    Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
 };
 
 /// A widget that applies any `Theme` to its contents.
@@ -133,6 +133,18 @@ where
             .mouse_interaction(tree, layout, cursor, viewport, renderer)
     }
 
+    fn hit_test(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        position: Point,
+    ) -> Option<bool> {
+        self.content
+            .as_widget()
+            .hit_test(tree, layout, renderer, position)
+    }
+
     fn draw(
         &self,
         tree: &Tree,
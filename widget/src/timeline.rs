@@ -0,0 +1,1134 @@
+//! Timelines display bars spanning ranges of time, arranged in rows.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::timeline::{self, Timeline};
+//!
+//! struct State {
+//!     tasks: Vec<(f64, f64)>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Timeline(timeline::Event),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     Timeline::new()
+//!         .group("Design")
+//!         .push(timeline::row(
+//!             "Mockups",
+//!             state
+//!                 .tasks
+//!                 .iter()
+//!                 .map(|(start, end)| timeline::bar(*start, *end)),
+//!         ))
+//!         .on_event(Message::Timeline)
+//!         .into()
+//! }
+//! ```
+use crate::core;
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme, Widget,
+};
+
+use std::cmp::Ordering;
+
+/// The horizontal area around the edges of a bar that resizes it when
+/// dragged.
+const HANDLE: f32 = 6.0;
+
+/// The distance the cursor needs to travel before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 3.0;
+
+/// The minimum space between the ticks of the time axis.
+const MIN_TICK_SPACING: f32 = 80.0;
+
+/// The factor applied to the visible range for every line scrolled while
+/// zooming.
+const ZOOM_FACTOR: f64 = 0.9;
+
+/// Creates a new [`Row`] with the given label and bars.
+pub fn row(label: impl Into<String>, bars: impl IntoIterator<Item = Bar>) -> Row {
+    Row::new(label, bars)
+}
+
+/// Creates a new [`Bar`] spanning the given range of time.
+pub fn bar(start: f64, end: f64) -> Bar {
+    Bar::new(start, end)
+}
+
+/// A time axis with rows of bars, which can be scrolled, zoomed, moved, and
+/// resized.
+///
+/// Scroll horizontally (or vertically while holding Shift) to move through
+/// time, and scroll while holding the command key to zoom around the cursor.
+/// Dragging the background pans the view.
+///
+/// Bars can be dragged to move them in time or to another row, and resized
+/// from their edges. The [`Timeline`] previews the change while dragging and
+/// produces an [`Event`] once the bar is dropped; it is up to the
+/// application to apply it.
+pub struct Timeline<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    entries: Vec<Entry>,
+    on_event: Option<Box<dyn Fn(Event) -> Message + 'a>>,
+    range: Option<(f64, f64)>,
+    snap: Option<f64>,
+    steps: Vec<f64>,
+    format: Option<Box<dyn Fn(f64) -> String + 'a>>,
+    width: Length,
+    row_height: f32,
+    label_width: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+/// A row of bars of a [`Timeline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    label: String,
+    bars: Vec<Bar>,
+}
+
+impl Row {
+    /// Creates a new [`Row`] with the given label and bars.
+    pub fn new(label: impl Into<String>, bars: impl IntoIterator<Item = Bar>) -> Self {
+        Self {
+            label: label.into(),
+            bars: bars.into_iter().collect(),
+        }
+    }
+}
+
+/// A bar of a [`Timeline`], spanning a range of time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    start: f64,
+    end: f64,
+    label: String,
+    color: Option<Color>,
+}
+
+impl Bar {
+    /// Creates a new [`Bar`] spanning the given range of time.
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            start: start.min(end),
+            end: end.max(start),
+            label: String::new(),
+            color: None,
+        }
+    }
+
+    /// Sets the label of the [`Bar`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Bar`].
+    ///
+    /// By default, the color is taken from the [`Style`] of the [`Timeline`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// An interaction with the bars of a [`Timeline`].
+///
+/// Rows are indexed in order, without counting group headers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A bar was clicked.
+    Selected {
+        /// The row of the bar.
+        row: usize,
+        /// The index of the bar in its row.
+        bar: usize,
+    },
+    /// A bar was dragged to a new range of time, and maybe a new row.
+    Moved {
+        /// The row of the bar.
+        row: usize,
+        /// The index of the bar in its row.
+        bar: usize,
+        /// The row the bar was dropped on.
+        to: usize,
+        /// The new start of the bar.
+        start: f64,
+        /// The new end of the bar.
+        end: f64,
+    },
+    /// A bar was resized from one of its edges.
+    Resized {
+        /// The row of the bar.
+        row: usize,
+        /// The index of the bar in its row.
+        bar: usize,
+        /// The new start of the bar.
+        start: f64,
+        /// The new end of the bar.
+        end: f64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Entry {
+    Group(String),
+    Row(Row),
+}
+
+impl<'a, Message, Theme, Renderer> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new empty [`Timeline`].
+    pub fn new() -> Self {
+        Self::with_rows([])
+    }
+
+    /// Creates a new [`Timeline`] with the given rows.
+    pub fn with_rows(rows: impl IntoIterator<Item = Row>) -> Self {
+        Self {
+            entries: rows.into_iter().map(Entry::Row).collect(),
+            on_event: None,
+            range: None,
+            snap: None,
+            steps: Vec::new(),
+            format: None,
+            width: Length::Fill,
+            row_height: 28.0,
+            label_width: 120.0,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Row`] to the [`Timeline`].
+    pub fn push(mut self, row: Row) -> Self {
+        self.entries.push(Entry::Row(row));
+        self
+    }
+
+    /// Adds a group header to the [`Timeline`], grouping the rows pushed
+    /// after it.
+    pub fn group(mut self, label: impl Into<String>) -> Self {
+        self.entries.push(Entry::Group(label.into()));
+        self
+    }
+
+    /// Sets the function that produces a message when a bar is selected,
+    /// moved, or resized.
+    ///
+    /// Without it, the bars of the [`Timeline`] cannot be interacted with.
+    pub fn on_event(mut self, on_event: impl Fn(Event) -> Message + 'a) -> Self {
+        self.on_event = Some(Box::new(on_event));
+        self
+    }
+
+    /// Sets the range of time initially visible in the [`Timeline`].
+    ///
+    /// By default, the range of all of its bars is visible.
+    pub fn range(mut self, start: f64, end: f64) -> Self {
+        self.range = (end > start).then_some((start, end));
+        self
+    }
+
+    /// Sets the interval that moved and resized bars snap to.
+    pub fn snap(mut self, interval: f64) -> Self {
+        self.snap = (interval > 0.0).then_some(interval);
+        self
+    }
+
+    /// Sets the intervals between the ticks of the time axis that the
+    /// [`Timeline`] can pick from, depending on its zoom.
+    ///
+    /// By default, it picks intervals of 1, 2, and 5 times a power of ten.
+    pub fn steps(mut self, steps: impl IntoIterator<Item = f64>) -> Self {
+        self.steps = steps.into_iter().filter(|step| *step > 0.0).collect();
+        self.steps.sort_by(f64::total_cmp);
+        self
+    }
+
+    /// Sets the function that formats the labels of the time axis.
+    pub fn format(mut self, format: impl Fn(f64) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the width of the [`Timeline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of each row of the [`Timeline`].
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the width of the column with the labels of the rows.
+    pub fn label_width(mut self, label_width: impl Into<Pixels>) -> Self {
+        self.label_width = label_width.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Timeline`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`Timeline`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Timeline`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Timeline`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text_size_or_default(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn axis_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size_or_default(renderer))
+            .0
+            + 8.0
+    }
+
+    /// Returns the rows of the [`Timeline`], alongside their index and the
+    /// index of their entry.
+    fn rows(&self) -> impl Iterator<Item = (usize, usize, &Row)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(entry, item)| match item {
+                Entry::Row(row) => Some((entry, row)),
+                Entry::Group(_) => None,
+            })
+            .enumerate()
+            .map(|(index, (entry, row))| (index, entry, row))
+    }
+
+    /// Returns the range of time visible in the [`Timeline`].
+    fn view(&self, state: &State) -> (f64, f64) {
+        if let Some(view) = state.view.or(self.range) {
+            return view;
+        }
+
+        let (start, end) = self
+            .rows()
+            .flat_map(|(_, _, row)| &row.bars)
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(start, end), bar| {
+                (start.min(bar.start), end.max(bar.end))
+            });
+
+        if start < end {
+            let margin = (end - start) * 0.05;
+
+            (start - margin, end + margin)
+        } else if start == end {
+            (start - 1.0, end + 1.0)
+        } else {
+            (0.0, 1.0)
+        }
+    }
+
+    fn metrics(&self, bounds: Rectangle, renderer: &Renderer, state: &State) -> Metrics {
+        let axis_height = self.axis_height(renderer);
+
+        let plot = Rectangle {
+            x: bounds.x + self.label_width,
+            y: bounds.y + axis_height,
+            width: (bounds.width - self.label_width).max(0.0),
+            height: (bounds.height - axis_height).max(0.0),
+        };
+
+        let view = self.view(state);
+
+        Metrics {
+            plot,
+            view,
+            scale: if plot.width > 0.0 {
+                f64::from(plot.width) / (view.1 - view.0)
+            } else {
+                1.0
+            },
+            row_height: self.row_height,
+        }
+    }
+
+    /// Returns the bar under the given position, if any.
+    fn hit(&self, metrics: &Metrics, position: Point) -> Option<Hit> {
+        if !metrics.plot.contains(position) {
+            return None;
+        }
+
+        let entry = metrics.entry(position.y)?;
+
+        let (row, _, item) = self.rows().find(|(_, index, _)| *index == entry)?;
+
+        item.bars.iter().enumerate().rev().find_map(|(index, bar)| {
+            let start = metrics.x(bar.start);
+            let end = metrics.x(bar.end);
+
+            if position.x < start - HANDLE / 2.0 || position.x > end + HANDLE / 2.0 {
+                return None;
+            }
+
+            let handle = HANDLE.min((end - start) / 3.0);
+
+            let part = if position.x <= start + handle {
+                Part::Start
+            } else if position.x >= end - handle {
+                Part::End
+            } else {
+                Part::Body
+            };
+
+            Some(Hit {
+                row,
+                bar: index,
+                part,
+            })
+        })
+    }
+
+    fn bar(&self, row: usize, bar: usize) -> Option<&Bar> {
+        self.rows()
+            .find(|(index, _, _)| *index == row)
+            .and_then(|(_, _, row)| row.bars.get(bar))
+    }
+
+    fn snapped(&self, time: f64) -> f64 {
+        match self.snap {
+            Some(interval) => (time / interval).round() * interval,
+            None => time,
+        }
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        position: Point,
+        align_x: text::Alignment,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        let size = self.text_size_or_default(renderer);
+
+        renderer.fill_text(
+            text::Text {
+                content: content.to_owned(),
+                bounds: Size::new(f32::INFINITY, self.row_height),
+                size,
+                line_height: text::LineHeight::default(),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+                hint_factor: None,
+            },
+            position,
+            color,
+            clip_bounds,
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Timeline<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the smallest of the given steps that is at least the given
+/// minimum, or a "nice" step if none of them is.
+fn tick_step(steps: &[f64], minimum: f64) -> f64 {
+    if let Some(step) = steps.iter().copied().find(|step| *step >= minimum) {
+        return step;
+    }
+
+    if minimum.partial_cmp(&0.0) != Some(Ordering::Greater) || !minimum.is_finite() {
+        return steps.last().copied().unwrap_or(1.0);
+    }
+
+    let magnitude = 10.0_f64.powf(minimum.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= minimum)
+        .unwrap_or(10.0 * magnitude)
+}
+
+fn format_time(time: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+
+    format!("{time:.decimals$}")
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Timeline<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.axis_height(renderer) + self.entries.len() as f32 * self.row_height;
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &core::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(bounds, renderer, state);
+
+        match event {
+            core::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(metrics.plot) else {
+                    return;
+                };
+
+                state.drag = Some(match self.hit(&metrics, position) {
+                    Some(hit) if self.on_event.is_some() => Drag {
+                        origin: position,
+                        target: Target::Bar(hit),
+                        is_dragging: false,
+                    },
+                    _ => Drag {
+                        origin: position,
+                        target: Target::View(metrics.view),
+                        is_dragging: false,
+                    },
+                });
+
+                shell.capture_event();
+            }
+            core::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return;
+                };
+
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                if !drag.is_dragging && position.distance(drag.origin) > DRAG_THRESHOLD {
+                    drag.is_dragging = true;
+                }
+
+                if !drag.is_dragging {
+                    return;
+                }
+
+                let delta = f64::from(position.x - drag.origin.x) / metrics.scale;
+
+                match drag.target {
+                    Target::View((start, end)) => {
+                        state.view = Some((start - delta, end - delta));
+                    }
+                    Target::Bar(hit) => {
+                        state.preview = self.bar(hit.row, hit.bar).map(|bar| {
+                            let (start, end) = match hit.part {
+                                Part::Body => {
+                                    let start = self.snapped(bar.start + delta);
+
+                                    (start, start + bar.end - bar.start)
+                                }
+                                Part::Start => {
+                                    (self.snapped(bar.start + delta).min(bar.end), bar.end)
+                                }
+                                Part::End => {
+                                    (bar.start, self.snapped(bar.end + delta).max(bar.start))
+                                }
+                            };
+
+                            let to = match hit.part {
+                                Part::Body => metrics
+                                    .entry(position.y)
+                                    .and_then(|entry| {
+                                        self.rows()
+                                            .find(|(_, index, _)| *index == entry)
+                                            .map(|(row, _, _)| row)
+                                    })
+                                    .unwrap_or(hit.row),
+                                Part::Start | Part::End => hit.row,
+                            };
+
+                            Preview { to, start, end }
+                        });
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            core::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                let preview = state.preview.take();
+
+                if let Target::Bar(hit) = drag.target
+                    && let Some(on_event) = &self.on_event
+                {
+                    let event = match (drag.is_dragging, preview) {
+                        (false, _) => Some(Event::Selected {
+                            row: hit.row,
+                            bar: hit.bar,
+                        }),
+                        (true, Some(Preview { to, start, end })) => match hit.part {
+                            Part::Body => Some(Event::Moved {
+                                row: hit.row,
+                                bar: hit.bar,
+                                to,
+                                start,
+                                end,
+                            }),
+                            Part::Start | Part::End => Some(Event::Resized {
+                                row: hit.row,
+                                bar: hit.bar,
+                                start,
+                                end,
+                            }),
+                        },
+                        (true, None) => None,
+                    };
+
+                    if let Some(event) = event {
+                        shell.publish(on_event(event));
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            core::Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(metrics.plot) else {
+                    return;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (*x * 60.0, *y * 60.0),
+                    mouse::ScrollDelta::Pixels { x, y } => (*x, *y),
+                };
+
+                let (start, end) = metrics.view;
+
+                if state.modifiers.command() {
+                    let center = metrics.time(position.x);
+                    let factor = ZOOM_FACTOR.powf(f64::from(y / 60.0));
+
+                    state.view = Some((
+                        center - (center - start) * factor,
+                        center + (end - center) * factor,
+                    ));
+                } else {
+                    let pan = if state.modifiers.shift() { y } else { x };
+
+                    if pan == 0.0 {
+                        return;
+                    }
+
+                    let delta = f64::from(pan) / metrics.scale;
+
+                    state.view = Some((start - delta, end - delta));
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            core::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let metrics = self.metrics(bounds, renderer, state);
+        let plot = metrics.plot;
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: plot.y - bounds.y,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            style.axis_background,
+        );
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let y = plot.y + index as f32 * self.row_height;
+
+            if let Entry::Group(label) = entry {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x,
+                            y,
+                            width: bounds.width,
+                            height: self.row_height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.group_background,
+                );
+
+                self.fill_text(
+                    renderer,
+                    label,
+                    Point::new(bounds.x + 8.0, y + self.row_height / 2.0),
+                    text::Alignment::Left,
+                    style.group_text_color,
+                    clip,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: y + self.row_height - 1.0,
+                        width: bounds.width,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.grid_color,
+            );
+        }
+
+        for (_, entry, row) in self.rows() {
+            let y = plot.y + entry as f32 * self.row_height;
+
+            self.fill_text(
+                renderer,
+                &row.label,
+                Point::new(bounds.x + 16.0, y + self.row_height / 2.0),
+                text::Alignment::Left,
+                style.text_color,
+                Rectangle {
+                    width: self.label_width - 16.0,
+                    ..bounds
+                }
+                .intersection(&clip)
+                .unwrap_or(clip),
+            );
+        }
+
+        let Some(clip) = Rectangle {
+            y: bounds.y,
+            height: bounds.height,
+            ..plot
+        }
+        .intersection(&clip) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            let (start, end) = metrics.view;
+
+            let step = tick_step(&self.steps, f64::from(MIN_TICK_SPACING) / metrics.scale);
+
+            let mut tick = (start / step).floor() * step;
+
+            while tick <= end {
+                let x = metrics.x(tick);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.y,
+                            width: 1.0,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.grid_color,
+                );
+
+                let label = match &self.format {
+                    Some(format) => format(tick),
+                    None => format_time(tick, step),
+                };
+
+                self.fill_text(
+                    renderer,
+                    &label,
+                    Point::new(x + 4.0, bounds.y + (plot.y - bounds.y) / 2.0),
+                    text::Alignment::Left,
+                    style.text_color,
+                    clip,
+                );
+
+                tick += step;
+            }
+
+            let dragged = state.drag.and_then(|drag| match drag.target {
+                Target::Bar(hit) if state.preview.is_some() => Some(hit),
+                _ => None,
+            });
+
+            let mut draw_bar = |bar: &Bar, entry: usize, start: f64, end: f64, alpha: f32| {
+                let bar_bounds = Rectangle {
+                    x: metrics.x(start),
+                    y: plot.y + entry as f32 * self.row_height + 4.0,
+                    width: (metrics.x(end) - metrics.x(start)).max(2.0),
+                    height: (self.row_height - 8.0).max(0.0),
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bar_bounds,
+                        border: Border {
+                            radius: style.bar_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    bar.color.unwrap_or(style.bar_color).scale_alpha(alpha),
+                );
+
+                if !bar.label.is_empty()
+                    && let Some(clip) = bar_bounds.intersection(&clip)
+                {
+                    self.fill_text(
+                        renderer,
+                        &bar.label,
+                        Point::new(bar_bounds.x + 6.0, bar_bounds.center_y()),
+                        text::Alignment::Left,
+                        style.bar_text_color.scale_alpha(alpha),
+                        clip,
+                    );
+                }
+            };
+
+            for (row, entry, item) in self.rows() {
+                for (index, bar) in item.bars.iter().enumerate() {
+                    let is_dragged = dragged.is_some_and(|hit| hit.row == row && hit.bar == index);
+
+                    draw_bar(
+                        bar,
+                        entry,
+                        bar.start,
+                        bar.end,
+                        if is_dragged { 0.4 } else { 1.0 },
+                    );
+                }
+            }
+
+            if let Some(hit) = dragged
+                && let Some(preview) = state.preview
+                && let Some(bar) = self.bar(hit.row, hit.bar)
+                && let Some((_, entry, _)) = self.rows().find(|(row, _, _)| *row == preview.to)
+            {
+                draw_bar(bar, entry, preview.start, preview.end, 1.0);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(drag) = &state.drag {
+            return match drag.target {
+                Target::Bar(Hit {
+                    part: Part::Start | Part::End,
+                    ..
+                }) => mouse::Interaction::ResizingHorizontally,
+                _ if drag.is_dragging => mouse::Interaction::Grabbing,
+                _ => mouse::Interaction::default(),
+            };
+        }
+
+        let metrics = self.metrics(layout.bounds(), renderer, state);
+
+        let Some(position) = cursor.position_over(metrics.plot) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.hit(&metrics, position) {
+            Some(hit) if self.on_event.is_some() => match hit.part {
+                Part::Start | Part::End => mouse::Interaction::ResizingHorizontally,
+                Part::Body => mouse::Interaction::Grab,
+            },
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Timeline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        timeline: Timeline<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(timeline)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    view: Option<(f64, f64)>,
+    drag: Option<Drag>,
+    preview: Option<Preview>,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    target: Target,
+    is_dragging: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    View((f64, f64)),
+    Bar(Hit),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hit {
+    row: usize,
+    bar: usize,
+    part: Part,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Start,
+    Body,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Preview {
+    to: usize,
+    start: f64,
+    end: f64,
+}
+
+/// The placement of the rows and the time axis of a [`Timeline`].
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    plot: Rectangle,
+    view: (f64, f64),
+    scale: f64,
+    row_height: f32,
+}
+
+impl Metrics {
+    fn x(&self, time: f64) -> f32 {
+        self.plot.x + ((time - self.view.0) * self.scale) as f32
+    }
+
+    fn time(&self, x: f32) -> f64 {
+        self.view.0 + f64::from(x - self.plot.x) / self.scale
+    }
+
+    /// Returns the index of the entry at the given vertical position.
+    fn entry(&self, y: f32) -> Option<usize> {
+        if y < self.plot.y || self.row_height <= 0.0 {
+            return None;
+        }
+
+        Some(((y - self.plot.y) / self.row_height) as usize)
+    }
+}
+
+/// The appearance of a timeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the background of the timeline.
+    pub background: Color,
+    /// The [`Color`] of the background of the time axis.
+    pub axis_background: Color,
+    /// The [`Color`] of the lines between rows and ticks.
+    pub grid_color: Color,
+    /// The [`Color`] of the labels of the rows and the time axis.
+    pub text_color: Color,
+    /// The [`Color`] of the background of group headers.
+    pub group_background: Color,
+    /// The [`Color`] of the labels of group headers.
+    pub group_text_color: Color,
+    /// The [`Color`] of the bars without an explicit [`Color`].
+    pub bar_color: Color,
+    /// The [`Color`] of the labels of the bars.
+    pub bar_text_color: Color,
+    /// The radius of the corners of the bars.
+    pub bar_radius: border::Radius,
+}
+
+/// The theme catalog of a [`Timeline`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Timeline`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Timeline`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        axis_background: palette.background.weakest.color,
+        grid_color: palette.background.weak.color,
+        text_color: palette.background.base.text,
+        group_background: palette.background.weak.color,
+        group_text_color: palette.background.weak.text,
+        bar_color: palette.primary.base.color,
+        bar_text_color: palette.primary.base.text,
+        bar_radius: 4.0.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_step_prefers_the_given_steps() {
+        assert_eq!(tick_step(&[], 3.0), 5.0);
+        assert_eq!(tick_step(&[], 120.0), 200.0);
+        assert_eq!(tick_step(&[1.0, 60.0, 3600.0], 30.0), 60.0);
+        assert_eq!(tick_step(&[1.0, 60.0, 3600.0], 6000.0), 10000.0);
+    }
+}
@@ -0,0 +1,1030 @@
+//! Toasts display transient notifications on top of some content.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::toast::{self, Toast};
+//! use iced::widget::{button, center};
+//!
+//! struct State {
+//!     toasts: Vec<Toast<Message>>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//!     Undo,
+//!     Dismiss(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     toast::Manager::new(
+//!         center(button("Save").on_press(Message::Save)),
+//!         &state.toasts,
+//!         Message::Dismiss,
+//!     )
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Save => {
+//!             state.toasts.push(
+//!                 Toast::success("Your changes have been saved.")
+//!                     .title("Saved")
+//!                     .action("Undo", Message::Undo),
+//!             );
+//!         }
+//!         Message::Undo => {
+//!             // ...
+//!         }
+//!         Message::Dismiss(index) => {
+//!             let _ = state.toasts.remove(index);
+//!         }
+//!     }
+//! }
+//! ```
+//! The [`notifications` example] showcases how to use a [`Manager`] with
+//! severities, actions, and timeouts.
+//!
+//! [`notifications` example]: https://github.com/iced-rs/iced/tree/master/examples/notifications
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+
+use std::fmt;
+
+const PADDING: f32 = 12.0;
+const ACCENT_WIDTH: f32 = 4.0;
+const SPACING: f32 = 8.0;
+const ACTION_PADDING: f32 = 8.0;
+
+/// A transient notification displayed by a [`Manager`].
+#[derive(Debug, Clone)]
+pub struct Toast<Message> {
+    title: Option<String>,
+    body: String,
+    severity: Severity,
+    timeout: Timeout,
+    action: Option<(String, Message)>,
+}
+
+impl<Message> Toast<Message> {
+    /// Creates a new [`Toast`] with the given body and [`Severity::Info`].
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            body: body.into(),
+            severity: Severity::Info,
+            timeout: Timeout::Default,
+            action: None,
+        }
+    }
+
+    /// Creates a new [`Toast`] with the given body and [`Severity::Info`].
+    pub fn info(body: impl Into<String>) -> Self {
+        Self::new(body)
+    }
+
+    /// Creates a new [`Toast`] with the given body and [`Severity::Success`].
+    pub fn success(body: impl Into<String>) -> Self {
+        Self::new(body).severity(Severity::Success)
+    }
+
+    /// Creates a new [`Toast`] with the given body and [`Severity::Warning`].
+    pub fn warning(body: impl Into<String>) -> Self {
+        Self::new(body).severity(Severity::Warning)
+    }
+
+    /// Creates a new [`Toast`] with the given body and [`Severity::Danger`].
+    pub fn danger(body: impl Into<String>) -> Self {
+        Self::new(body).severity(Severity::Danger)
+    }
+
+    /// Sets the title of the [`Toast`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the [`Severity`] of the [`Toast`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the amount of time the [`Toast`] is displayed before being
+    /// dismissed automatically.
+    ///
+    /// By default, the timeout of the [`Manager`] is used.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Timeout::After(timeout);
+        self
+    }
+
+    /// Makes the [`Toast`] stay until it is dismissed by the user.
+    pub fn sticky(mut self) -> Self {
+        self.timeout = Timeout::Never;
+        self
+    }
+
+    /// Adds an action button with the given label to the [`Toast`].
+    ///
+    /// Pressing the button will produce the given message and dismiss
+    /// the [`Toast`].
+    pub fn action(mut self, label: impl Into<String>, on_press: Message) -> Self {
+        self.action = Some((label.into(), on_press));
+        self
+    }
+}
+
+/// The severity of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    /// Some neutral information.
+    #[default]
+    Info,
+    /// A successful outcome.
+    Success,
+    /// A potential problem.
+    Warning,
+    /// An error or a destructive outcome.
+    Danger,
+}
+
+impl Severity {
+    /// All the severities of a [`Toast`].
+    pub const ALL: &'static [Self] = &[Self::Info, Self::Success, Self::Warning, Self::Danger];
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "Info",
+            Self::Success => "Success",
+            Self::Warning => "Warning",
+            Self::Danger => "Danger",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timeout {
+    Default,
+    After(Duration),
+    Never,
+}
+
+/// The corner or edge of the [`Manager`] where toasts are stacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    /// The top left corner.
+    TopLeft,
+    /// The center of the top edge.
+    Top,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The center of the bottom edge.
+    Bottom,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+/// A widget that displays a queue of [`Toast`] notifications on top of
+/// some content.
+///
+/// The toasts are owned by the application and dismissed by removing
+/// them from the queue when the `on_dismiss` message—carrying the index of
+/// the dismissed [`Toast`]—is produced. Toasts are dismissed when their
+/// timeout elapses, which is paused while they are hovered, or when their
+/// close or action buttons are pressed.
+pub struct Manager<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    toasts: &'a [Toast<Message>],
+    on_dismiss: Box<dyn Fn(usize) -> Message + 'a>,
+    timeout: Duration,
+    position: Position,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    limit: Option<usize>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Manager<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default amount of time a [`Toast`] is displayed.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Creates a new [`Manager`] displaying the given toasts on top of
+    /// the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: &'a [Toast<Message>],
+        on_dismiss: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            on_dismiss: Box::new(on_dismiss),
+            timeout: Self::DEFAULT_TIMEOUT,
+            position: Position::default(),
+            width: 320.0,
+            padding: Padding::new(16.0),
+            spacing: 10.0,
+            limit: None,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the amount of time a [`Toast`] is displayed before being
+    /// dismissed automatically, unless it sets its own.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the [`Position`] of the toasts of the [`Manager`].
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of the toasts of the [`Manager`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] between the toasts and the edges of the
+    /// [`Manager`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the toasts of the [`Manager`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the maximum amount of toasts displayed at once.
+    ///
+    /// The rest of the toasts will wait in the queue, and their timeouts
+    /// will not start until they are displayed.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the text size of the toasts of the [`Manager`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the toasts of the [`Manager`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Manager`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Severity) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Manager`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn timer(&self, toast: &Toast<Message>) -> Timer {
+        Timer {
+            remaining: match toast.timeout {
+                Timeout::Default => Some(self.timeout),
+                Timeout::After(timeout) => Some(timeout),
+                Timeout::Never => None,
+            },
+            deadline: None,
+            is_dismissed: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    timers: Vec<Timer>,
+    hovered: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    remaining: Option<Duration>,
+    deadline: Option<Instant>,
+    is_dismissed: bool,
+}
+
+impl Timer {
+    fn pause(&mut self, now: Instant) {
+        if let Some(deadline) = self.deadline.take() {
+            self.remaining = Some(deadline.saturating_duration_since(now));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Manager<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            timers: self.toasts.iter().map(|toast| self.timer(toast)).collect(),
+            hovered: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Dismissed toasts are expected to be removed from the queue,
+        // which keeps the remaining timers aligned with their toasts
+        state.timers.retain(|timer| !timer.is_dismissed);
+
+        if state.timers.len() > self.toasts.len() {
+            state.timers.truncate(self.toasts.len());
+        } else {
+            let new = &self.toasts[state.timers.len()..];
+
+            state
+                .timers
+                .extend(new.iter().map(|toast| self.timer(toast)));
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let toasts = if self.toasts.is_empty() {
+            None
+        } else {
+            Some(overlay::Element::new(Box::new(Overlay {
+                toasts: self.toasts,
+                state,
+                on_dismiss: &self.on_dismiss,
+                bounds: layout.bounds() + translation,
+                position: self.position,
+                width: self.width,
+                padding: self.padding,
+                spacing: self.spacing,
+                limit: self.limit.unwrap_or(usize::MAX),
+                text_size: self.text_size,
+                font: self.font,
+                class: &self.class,
+            })))
+        };
+
+        if content.is_some() || toasts.is_some() {
+            Some(
+                overlay::Group::with_children(content.into_iter().chain(toasts).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Manager<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(manager: Manager<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(manager)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    toasts: &'a [Toast<Message>],
+    state: &'a mut State,
+    on_dismiss: &'a dyn Fn(usize) -> Message,
+    bounds: Rectangle,
+    position: Position,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    limit: usize,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn visible(&self) -> usize {
+        self.toasts.len().min(self.limit)
+    }
+
+    fn text<'a>(
+        &self,
+        content: &'a str,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> Text<&'a str, Renderer::Font> {
+        Text {
+            content,
+            bounds,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::Word,
+            hint_factor: None,
+        }
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size.unwrap_or_else(|| renderer.default_size()))
+            .0
+    }
+
+    fn measure(&self, content: &str, bounds: Size, renderer: &Renderer) -> Size {
+        Renderer::Paragraph::with_text(self.text(content, bounds, renderer)).min_bounds()
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        viewport: &Rectangle,
+    ) {
+        let text = self.text(content, bounds.size(), renderer);
+
+        renderer.fill_text(
+            text.with_content(content.to_owned()),
+            bounds.position(),
+            color,
+            *viewport,
+        );
+    }
+
+    /// Lays out a [`Toast`] with its title, body, action, and close button
+    /// as children.
+    fn layout_toast(
+        &self,
+        toast: &Toast<Message>,
+        width: f32,
+        renderer: &Renderer,
+    ) -> layout::Node {
+        let line_height = self.line_height(renderer);
+        let x = ACCENT_WIDTH + PADDING;
+        let text_width = (width - x - PADDING - line_height - SPACING).max(0.0);
+
+        let mut y = PADDING;
+
+        let title = if let Some(title) = &toast.title {
+            let size = self.measure(title, Size::new(text_width, f32::INFINITY), renderer);
+            let node =
+                layout::Node::new(Size::new(text_width, size.height)).move_to(Point::new(x, y));
+
+            y += size.height + SPACING / 2.0;
+
+            node
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let body = {
+            let size = self.measure(&toast.body, Size::new(text_width, f32::INFINITY), renderer);
+            let node = layout::Node::new(Size::new(text_width, size.height.max(line_height)))
+                .move_to(Point::new(x, y));
+
+            y += node.size().height;
+
+            node
+        };
+
+        let action = if let Some((label, _)) = &toast.action {
+            let size = self.measure(label, Size::INFINITE, renderer);
+            let size = Size::new(
+                size.width + ACTION_PADDING * 2.0,
+                line_height + ACTION_PADDING,
+            );
+
+            y += SPACING;
+
+            let node = layout::Node::new(size).move_to(Point::new(width - PADDING - size.width, y));
+
+            y += size.height;
+
+            node
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let close = layout::Node::new(Size::new(line_height, line_height))
+            .move_to(Point::new(width - PADDING - line_height, PADDING));
+
+        layout::Node::with_children(
+            Size::new(width, y + PADDING),
+            vec![title, body, action, close],
+        )
+    }
+
+    fn dismiss(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(timer) = self.state.timers.get_mut(index)
+            && !timer.is_dismissed
+        {
+            timer.is_dismissed = true;
+            shell.publish((self.on_dismiss)(index));
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let area = self.bounds.shrink(self.padding);
+        let width = self.width.min(area.width).max(0.0);
+
+        let x = match self.position {
+            Position::TopLeft | Position::BottomLeft => area.x,
+            Position::Top | Position::Bottom => area.center_x() - width / 2.0,
+            Position::TopRight | Position::BottomRight => area.x + area.width - width,
+        };
+
+        let mut offset = 0.0;
+
+        let toasts = self.toasts[..self.visible()]
+            .iter()
+            .map(|toast| {
+                let node = self.layout_toast(toast, width, renderer);
+                let height = node.size().height;
+
+                let y = match self.position {
+                    Position::TopLeft | Position::Top | Position::TopRight => area.y + offset,
+                    Position::BottomLeft | Position::Bottom | Position::BottomRight => {
+                        area.y + area.height - offset - height
+                    }
+                };
+
+                offset += height + self.spacing;
+
+                node.move_to(Point::new(x, y))
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, toasts)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let hovered = cursor.position().and_then(|position| {
+            layout
+                .children()
+                .position(|toast| toast.bounds().contains(position))
+        });
+
+        if self.state.hovered != hovered {
+            let now = Instant::now();
+
+            if let Some(timer) = hovered.and_then(|index| self.state.timers.get_mut(index)) {
+                timer.pause(now);
+            }
+
+            self.state.hovered = hovered;
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                for index in 0..self.visible() {
+                    let Some(timer) = self.state.timers.get_mut(index) else {
+                        break;
+                    };
+
+                    if timer.is_dismissed || hovered == Some(index) {
+                        continue;
+                    }
+
+                    if timer.deadline.is_none() {
+                        timer.deadline = timer.remaining.map(|remaining| *now + remaining);
+                    }
+
+                    match timer.deadline {
+                        Some(deadline) if deadline <= *now => {
+                            self.dismiss(index, shell);
+                        }
+                        Some(deadline) => {
+                            shell.request_redraw_at(deadline);
+                        }
+                        None => {}
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = hovered else {
+                    return;
+                };
+
+                let Some(toast) = layout.children().nth(index) else {
+                    return;
+                };
+
+                let mut parts = toast.children().skip(2);
+                let action = parts.next().map(|action| action.bounds());
+                let close = parts.next().map(|close| close.bounds());
+
+                if close.is_some_and(|close| cursor.is_over(close)) {
+                    self.dismiss(index, shell);
+                } else if action.is_some_and(|action| cursor.is_over(action))
+                    && let Some((_, on_press)) = &self.toasts[index].action
+                {
+                    shell.publish(on_press.clone());
+                    self.dismiss(index, shell);
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let viewport = layout.bounds();
+
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            let style = theme.style(self.class, toast.severity);
+            let bounds = layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: ACCENT_WIDTH,
+                        ..bounds
+                    },
+                    border: border::rounded(
+                        border::Radius::default()
+                            .left(style.border.radius.top_left.min(ACCENT_WIDTH)),
+                    ),
+                    ..renderer::Quad::default()
+                },
+                style.accent,
+            );
+
+            let mut parts = layout.children();
+            let title = parts.next().map(|title| title.bounds());
+            let body = parts.next().map(|body| body.bounds());
+            let action = parts.next().map(|action| action.bounds());
+            let close = parts.next().map(|close| close.bounds());
+
+            if let (Some(content), Some(bounds)) = (&toast.title, title) {
+                self.fill_text(renderer, content, bounds, style.text_color, &viewport);
+            }
+
+            if let Some(bounds) = body {
+                self.fill_text(
+                    renderer,
+                    &toast.body,
+                    bounds,
+                    style.text_color.scale_alpha(0.8),
+                    &viewport,
+                );
+            }
+
+            for (bounds, label, color) in [
+                action
+                    .zip(toast.action.as_ref().map(|(label, _)| label.as_str()))
+                    .map(|(bounds, label)| (bounds, label, style.accent)),
+                close.map(|bounds| (bounds, "×", style.text_color.scale_alpha(0.6))),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                if cursor.is_over(bounds) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: border::rounded(style.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_background,
+                    );
+                }
+
+                let text = Text {
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    ..self.text(label, bounds.size(), renderer)
+                };
+
+                renderer.fill_text(
+                    text.with_content(label.to_owned()),
+                    bounds.center(),
+                    color,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            if !cursor.is_over(layout.bounds()) {
+                continue;
+            }
+
+            let mut parts = layout.children().skip(2);
+            let is_over_action = parts
+                .next()
+                .is_some_and(|action| toast.action.is_some() && cursor.is_over(action.bounds()));
+            let is_over_close = parts
+                .next()
+                .is_some_and(|close| cursor.is_over(close.bounds()));
+
+            return if is_over_action || is_over_close {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::Idle
+            };
+        }
+
+        mouse::Interaction::None
+    }
+}
+
+/// The appearance of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the toast.
+    pub background: Background,
+    /// The [`Border`] of the toast.
+    pub border: Border,
+    /// The [`Shadow`] of the toast.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the toast.
+    pub text_color: Color,
+    /// The [`Color`] of the accent stripe and the action of the toast.
+    pub accent: Color,
+    /// The [`Background`] of a hovered button of the toast.
+    pub hovered_background: Background,
+}
+
+/// The theme catalog of a [`Manager`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given severity.
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style;
+}
+
+/// A styling function for a [`Manager`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Severity) -> Style + 'a>;
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, severity: Severity) -> Style {
+        class(self, severity)
+    }
+}
+
+/// The default style of a [`Manager`].
+pub fn default(theme: &crate::Theme, severity: Severity) -> Style {
+    let palette = theme.extended_palette();
+
+    let accent = match severity {
+        Severity::Info => palette.primary.base.color,
+        Severity::Success => palette.success.base.color,
+        Severity::Warning => palette.warning.base.color,
+        Severity::Danger => palette.danger.base.color,
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            radius: 4.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+            spread: 0.0,
+        },
+        text_color: palette.background.base.text,
+        accent,
+        hovered_background: palette.background.weak.color.into(),
+    }
+}
@@ -72,6 +72,9 @@ where
     padding: f32,
     snap_within_viewport: bool,
     delay: Duration,
+    hide_delay: Duration,
+    follow_cursor: bool,
+    max_width: f32,
     class: Theme::Class<'a>,
 }
 
@@ -99,6 +102,9 @@ where
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
             delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            follow_cursor: false,
+            max_width: f32::INFINITY,
             class: Theme::default(),
         }
     }
@@ -123,6 +129,30 @@ where
         self
     }
 
+    /// Sets the delay before the [`Tooltip`] is hidden, once the cursor
+    /// leaves its content.
+    ///
+    /// The [`Tooltip`] stays open if the cursor comes back in the meantime.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`] is placed around the cursor, tracking it,
+    /// instead of around its content.
+    ///
+    /// The [`Position`] of the [`Tooltip`] is kept relative to the cursor.
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+
+    /// Sets the maximum width of the [`Tooltip`], wrapping its contents to fit.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
@@ -234,13 +264,28 @@ where
                         cursor_position: last_position,
                     },
                     Some(cursor_position),
-                ) if self.position == Position::FollowCursor
+                ) if (self.position == Position::FollowCursor || self.follow_cursor)
                     && last_position != cursor_position =>
                 {
                     *state = State::Open { cursor_position };
                     shell.request_redraw();
                 }
-                (State::Open { .. }, None) => {
+                (State::Open { cursor_position }, None) if self.hide_delay > Duration::ZERO => {
+                    *state = State::Lingering {
+                        at: now,
+                        cursor_position,
+                    };
+
+                    shell.request_redraw_at(now + self.hide_delay);
+                }
+                (State::Lingering { .. }, Some(cursor_position)) => {
+                    *state = State::Open { cursor_position };
+                    shell.request_redraw();
+                }
+                (State::Lingering { at, .. }, None) if at.elapsed() < self.hide_delay => {
+                    shell.request_redraw_at(now + self.hide_delay - at.elapsed());
+                }
+                (State::Open { .. } | State::Lingering { .. }, None) => {
                     *state = State::Idle;
                     shell.invalidate_layout();
 
@@ -322,7 +367,11 @@ where
             translation,
         );
 
-        let tooltip = if let State::Open { cursor_position } = *state {
+        let tooltip = if let State::Open { cursor_position }
+        | State::Lingering {
+            cursor_position, ..
+        } = *state
+        {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &mut self.tooltip,
@@ -330,6 +379,8 @@ where
                 cursor_position,
                 content_bounds: layout.bounds(),
                 snap_within_viewport: self.snap_within_viewport,
+                follow_cursor: self.follow_cursor,
+                max_width: self.max_width,
                 positioning: self.position,
                 gap: self.gap,
                 padding: self.padding,
@@ -408,6 +459,10 @@ enum State {
     Open {
         cursor_position: Point,
     },
+    Lingering {
+        at: Instant,
+        cursor_position: Point,
+    },
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -421,6 +476,8 @@ where
     cursor_position: Point,
     content_bounds: Rectangle,
     snap_within_viewport: bool,
+    follow_cursor: bool,
+    max_width: f32,
     positioning: Position,
     gap: f32,
     padding: f32,
@@ -447,29 +504,40 @@ where
                     Size::INFINITE
                 },
             )
-            .shrink(Padding::new(self.padding)),
+            .shrink(Padding::new(self.padding))
+            .max_width(self.max_width),
         );
 
+        // The tooltip is placed around the target: either its content or the cursor
+        let (position, target) = if self.follow_cursor {
+            (
+                self.cursor_position + (self.position - self.content_bounds.position()),
+                Size::ZERO,
+            )
+        } else {
+            (self.position, self.content_bounds.size())
+        };
+
         let text_bounds = tooltip_layout.bounds();
-        let x_center = self.position.x + (self.content_bounds.width - text_bounds.width) / 2.0;
-        let y_center = self.position.y + (self.content_bounds.height - text_bounds.height) / 2.0;
+        let x_center = position.x + (target.width - text_bounds.width) / 2.0;
+        let y_center = position.y + (target.height - text_bounds.height) / 2.0;
 
         let mut tooltip_bounds = {
             let offset = match self.positioning {
                 Position::Top => Vector::new(
                     x_center,
-                    self.position.y - text_bounds.height - self.gap - self.padding,
+                    position.y - text_bounds.height - self.gap - self.padding,
                 ),
                 Position::Bottom => Vector::new(
                     x_center,
-                    self.position.y + self.content_bounds.height + self.gap + self.padding,
+                    position.y + target.height + self.gap + self.padding,
                 ),
                 Position::Left => Vector::new(
-                    self.position.x - text_bounds.width - self.gap - self.padding,
+                    position.x - text_bounds.width - self.gap - self.padding,
                     y_center,
                 ),
                 Position::Right => Vector::new(
-                    self.position.x + self.content_bounds.width + self.gap + self.padding,
+                    position.x + target.width + self.gap + self.padding,
                     y_center,
                 ),
                 Position::FollowCursor => {
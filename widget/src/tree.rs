@@ -0,0 +1,925 @@
+//! Trees display hierarchical data that can be expanded and collapsed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::tree;
+//!
+//! struct State {
+//!     is_src_expanded: bool,
+//!     selected: Option<u32>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Expand(u32),
+//!     Collapse(u32),
+//!     Select(u32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tree([
+//!         tree::node(0, "src")
+//!             .expanded(state.is_src_expanded)
+//!             .children([tree::node(1, "main.rs"), tree::node(2, "lib.rs")]),
+//!         tree::node(3, "Cargo.toml"),
+//!     ])
+//!     .selected(state.selected)
+//!     .on_expand(Message::Expand)
+//!     .on_collapse(Message::Collapse)
+//!     .on_select(Message::Select)
+//!     .into()
+//! }
+//! ```
+use crate::core;
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation;
+use crate::core::window;
+use crate::core::{
+    Background, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Size,
+    Widget,
+};
+
+/// Creates a new [`Tree`] with the given root nodes.
+///
+/// Nodes can be created using the [`node`] function.
+pub fn tree<'a, Key, Message, Theme, Renderer>(
+    nodes: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>,
+) -> Tree<'a, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Tree::new(nodes)
+}
+
+/// Creates a new [`Node`] of a [`Tree`] with the given key and label.
+///
+/// The key identifies the [`Node`] in the messages produced by the [`Tree`].
+pub fn node<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    label: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Node<'a, Key, Message, Theme, Renderer> {
+    Node {
+        key,
+        label: label.into(),
+        children: Vec::new(),
+        is_expanded: false,
+        is_expandable: false,
+    }
+}
+
+/// A widget that displays hierarchical data with disclosure indicators and
+/// indentation guides.
+///
+/// The expanded nodes and the selection of a [`Tree`] are kept in your
+/// application state; the [`Tree`] only produces messages to change them.
+///
+/// A focused [`Tree`]—pressed or focused with an operation—can be navigated
+/// with the keyboard:
+///
+/// - The up and down arrows select the previous and next visible nodes.
+/// - The left arrow collapses the selected node or selects its parent.
+/// - The right arrow expands the selected node or selects its first child.
+/// - Home and End select the first and last visible nodes.
+/// - Enter and Space expand or collapse the selected node.
+pub struct Tree<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    rows: Vec<Row<Key>>,
+    labels: Vec<Element<'a, Message, Theme, Renderer>>,
+    width: Length,
+    padding: Padding,
+    indent: f32,
+    spacing: f32,
+    indicator_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    selected: Option<Key>,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_expand: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_collapse: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    last_hovered_row: Option<Option<usize>>,
+    class: Theme::Class<'a>,
+}
+
+struct Row<Key> {
+    key: Key,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Tree<'a, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Tree`] with the given root nodes.
+    pub fn new(nodes: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>) -> Self {
+        let mut rows = Vec::new();
+        let mut labels = Vec::new();
+
+        for node in nodes {
+            node.flatten(0, None, &mut rows, &mut labels);
+        }
+
+        Self {
+            id: None,
+            rows,
+            labels,
+            width: Length::Shrink,
+            padding: Padding::new(2.0).horizontal(5.0),
+            indent: 16.0,
+            spacing: 4.0,
+            indicator_size: None,
+            font: None,
+            selected: None,
+            on_select: None,
+            on_expand: None,
+            on_collapse: None,
+            last_hovered_row: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Tree`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`Tree`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the rows of the [`Tree`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the indentation of each level of the [`Tree`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the spacing between the disclosure indicators and the labels of the [`Tree`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the size of the disclosure indicators of the [`Tree`].
+    ///
+    /// Defaults to the default text size of the renderer.
+    pub fn indicator_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.indicator_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the disclosure indicators of the [`Tree`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the key of the selected [`Node`] of the [`Tree`], if any.
+    pub fn selected(mut self, key: impl Into<Option<Key>>) -> Self {
+        self.selected = key.into();
+        self
+    }
+
+    /// Sets the function that will be called when a [`Node`] of the [`Tree`]
+    /// is selected—either by pressing it or by navigating with the keyboard.
+    pub fn on_select(mut self, on_select: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function that will be called when a collapsed [`Node`] of the
+    /// [`Tree`] is expanded.
+    ///
+    /// This is the right place to lazily load the children of the [`Node`];
+    /// see [`Node::expandable`].
+    pub fn on_expand(mut self, on_expand: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
+    /// Sets the function that will be called when an expanded [`Node`] of the
+    /// [`Tree`] is collapsed.
+    pub fn on_collapse(mut self, on_collapse: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_collapse = Some(Box::new(on_collapse));
+        self
+    }
+
+    /// Sets the style of the [`Tree`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tree`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.on_select.is_some() || self.on_expand.is_some() || self.on_collapse.is_some()
+    }
+
+    fn resolve_indicator_size(&self, renderer: &Renderer) -> f32 {
+        self.indicator_size
+            .unwrap_or_else(|| renderer.default_size())
+            .0
+    }
+
+    /// Returns the bounds of the disclosure indicator of a row.
+    fn indicator_bounds(&self, row: &Row<Key>, bounds: Rectangle, size: f32) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left + row.depth as f32 * self.indent,
+            y: bounds.y,
+            width: size,
+            height: bounds.height,
+        }
+    }
+
+    fn select(&self, index: usize) -> Option<Message>
+    where
+        Key: Clone,
+    {
+        let on_select = self.on_select.as_ref()?;
+
+        Some(on_select(self.rows.get(index)?.key.clone()))
+    }
+
+    fn toggle(&self, index: usize) -> Option<Message>
+    where
+        Key: Clone,
+    {
+        let row = self.rows.get(index)?;
+
+        if !row.is_expandable {
+            return None;
+        }
+
+        let on_toggle = if row.is_expanded {
+            self.on_collapse.as_ref()?
+        } else {
+            self.on_expand.as_ref()?
+        };
+
+        Some(on_toggle(row.key.clone()))
+    }
+
+    /// Returns the message produced by pressing the given key while the [`Tree`]
+    /// is focused, if any.
+    fn navigate(&self, key: key::Named) -> Option<Message>
+    where
+        Key: Clone + PartialEq,
+    {
+        let last = self.rows.len().checked_sub(1)?;
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| self.rows.iter().position(|row| &row.key == selected));
+
+        match (key, selected) {
+            (key::Named::ArrowUp, None) | (key::Named::End, _) => self.select(last),
+            (key::Named::ArrowDown, None) | (key::Named::Home, _) => self.select(0),
+            (key::Named::ArrowUp, Some(index)) => self.select(index.checked_sub(1)?),
+            (key::Named::ArrowDown, Some(index)) => self.select(index + 1),
+            (key::Named::ArrowLeft, Some(index)) => {
+                let row = &self.rows[index];
+
+                if row.is_expanded {
+                    self.toggle(index)
+                } else {
+                    self.select(row.parent?)
+                }
+            }
+            (key::Named::ArrowRight, Some(index)) => {
+                let row = &self.rows[index];
+
+                if !row.is_expanded {
+                    self.toggle(index)
+                } else if self.rows.get(index + 1)?.parent == Some(index) {
+                    self.select(index + 1)
+                } else {
+                    None
+                }
+            }
+            (key::Named::Enter | key::Named::Space, Some(index)) => self.toggle(index),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tree<'_, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.labels
+            .iter()
+            .map(|label| widget::Tree::new(label.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&self.labels);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let indicator_size = self.resolve_indicator_size(renderer);
+        let indicator_height =
+            f32::from(text::LineHeight::default().to_absolute(Pixels(indicator_size)));
+
+        let mut labels = Vec::with_capacity(self.labels.len());
+        let mut width: f32 = 0.0;
+        let mut height = 0.0;
+
+        for ((row, label), state) in self
+            .rows
+            .iter()
+            .zip(&mut self.labels)
+            .zip(&mut tree.children)
+        {
+            let offset =
+                self.padding.left + row.depth as f32 * self.indent + indicator_size + self.spacing;
+
+            let label_limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (max_width - offset - self.padding.right).max(0.0),
+                    f32::INFINITY,
+                ),
+            );
+
+            let label = label.as_widget_mut().layout(state, renderer, &label_limits);
+            let label_size = label.size();
+            let content_height = label_size.height.max(indicator_height);
+
+            width = width.max(offset + label_size.width + self.padding.right);
+
+            labels.push((
+                label.move_to(Point::new(
+                    offset,
+                    self.padding.top + (content_height - label_size.height) / 2.0,
+                )),
+                height,
+                content_height + self.padding.y(),
+            ));
+
+            height += content_height + self.padding.y();
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, height));
+
+        layout::Node::with_children(
+            size,
+            labels
+                .into_iter()
+                .map(|(label, y, height)| {
+                    layout::Node::with_children(Size::new(size.width, height), vec![label])
+                        .move_to(Point::new(0.0, y))
+                })
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.traverse(&mut |operation| {
+            for ((label, state), layout) in self
+                .labels
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                label.as_widget_mut().operate(
+                    state,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn core::Clipboard,
+        shell: &mut core::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if self.on_select.is_some() {
+            let hovered_row = layout
+                .children()
+                .position(|row| cursor.is_over(row.bounds()));
+
+            if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+                self.last_hovered_row = Some(hovered_row);
+            } else if self
+                .last_hovered_row
+                .is_some_and(|last_hovered_row| last_hovered_row != hovered_row)
+            {
+                shell.request_redraw();
+            }
+        }
+
+        for ((label, state), layout) in self
+            .labels
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            label.as_widget_mut().update(
+                state,
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() || !self.is_interactive() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((index, row_layout)) = layout
+                    .children()
+                    .enumerate()
+                    .find(|(_, row)| cursor.is_over(row.bounds()))
+                else {
+                    state.is_focused = false;
+                    return;
+                };
+
+                state.is_focused = true;
+
+                let indicator = self.indicator_bounds(
+                    &self.rows[index],
+                    row_layout.bounds(),
+                    self.resolve_indicator_size(renderer),
+                );
+
+                let message = if cursor.is_over(indicator) {
+                    self.toggle(index).or_else(|| self.select(index))
+                } else {
+                    self.select(index)
+                };
+
+                if let Some(message) = message {
+                    shell.publish(message);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                if let Some(message) = self.navigate(*key) {
+                    shell.publish(message);
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let tree_style = theme.style(&self.class);
+        let indicator_size = self.resolve_indicator_size(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| self.rows.iter().position(|row| &row.key == selected));
+
+        for (index, (((row, label), state), layout)) in self
+            .rows
+            .iter()
+            .zip(&self.labels)
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = selected == Some(index);
+
+            let background = if is_selected {
+                Some(tree_style.selected_row)
+            } else if self.on_select.is_some() && cursor.is_over(bounds) {
+                tree_style.hovered_row
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if let Some(guide) = tree_style.guide {
+                for level in 0..row.depth {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x
+                                    + self.padding.left
+                                    + level as f32 * self.indent
+                                    + indicator_size / 2.0,
+                                y: bounds.y,
+                                width: 1.0,
+                                height: bounds.height,
+                            },
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        guide,
+                    );
+                }
+            }
+
+            if row.is_expandable {
+                let indicator = self.indicator_bounds(row, bounds, indicator_size);
+
+                renderer.fill_text(
+                    Text {
+                        content: if row.is_expanded { "▾" } else { "▸" }.to_owned(),
+                        bounds: indicator.size(),
+                        size: Pixels(indicator_size),
+                        line_height: text::LineHeight::default(),
+                        font,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::None,
+                        hint_factor: None,
+                    },
+                    indicator.center(),
+                    tree_style.indicator,
+                    *viewport,
+                );
+            }
+
+            let style = match tree_style.selected_text {
+                Some(text_color) if is_selected => renderer::Style { text_color },
+                _ => *style,
+            };
+
+            label.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &style,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .labels
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((label, state), layout)| {
+                label.as_widget().mouse_interaction(
+                    state,
+                    layout.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::None {
+            return interaction;
+        }
+
+        let Some((index, layout)) = layout
+            .children()
+            .enumerate()
+            .find(|(_, row)| cursor.is_over(row.bounds()))
+        else {
+            return interaction;
+        };
+
+        let row = &self.rows[index];
+        let indicator =
+            self.indicator_bounds(row, layout.bounds(), self.resolve_indicator_size(renderer));
+
+        let is_toggleable = row.is_expandable
+            && cursor.is_over(indicator)
+            && if row.is_expanded {
+                self.on_collapse.is_some()
+            } else {
+                self.on_expand.is_some()
+            };
+
+        if is_toggleable || self.on_select.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: core::Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .labels
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((label, state), layout)| {
+                label.as_widget_mut().overlay(
+                    state,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    viewport,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Tree<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree: Tree<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(tree)
+    }
+}
+
+/// A node of a [`Tree`], with a label and some children.
+pub struct Node<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    key: Key,
+    label: Element<'a, Message, Theme, Renderer>,
+    children: Vec<Node<'a, Key, Message, Theme, Renderer>>,
+    is_expanded: bool,
+    is_expandable: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Node<'a, Key, Message, Theme, Renderer> {
+    /// Sets the children of the [`Node`].
+    ///
+    /// The children of a collapsed [`Node`] are not displayed; so you can
+    /// avoid building them altogether until the [`Node`] is expanded.
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Node<'a, Key, Message, Theme, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded; displaying its children.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded, even if it has no children.
+    ///
+    /// This is useful to load the children of a [`Node`] lazily, once
+    /// [`Tree::on_expand`] is triggered.
+    ///
+    /// A [`Node`] with children is always expandable.
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    fn flatten(
+        self,
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<Row<Key>>,
+        labels: &mut Vec<Element<'a, Message, Theme, Renderer>>,
+    ) {
+        let index = rows.len();
+        let is_expandable = self.is_expandable || !self.children.is_empty();
+
+        rows.push(Row {
+            key: self.key,
+            depth,
+            parent,
+            is_expandable,
+            is_expanded: is_expandable && self.is_expanded,
+        });
+
+        labels.push(self.label);
+
+        if self.is_expanded {
+            for child in self.children {
+                child.flatten(depth + 1, Some(index), rows, labels);
+            }
+        }
+    }
+}
+
+/// The appearance of a [`Tree`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Color`] of the disclosure indicators.
+    pub indicator: Color,
+    /// The [`Background`] of the indentation guides, if any.
+    pub guide: Option<Background>,
+    /// The [`Background`] of the selected row.
+    pub selected_row: Background,
+    /// The text [`Color`] of the selected row, if it should be changed.
+    pub selected_text: Option<Color>,
+    /// The [`Background`] of the hovered row, if rows can be selected.
+    pub hovered_row: Option<Background>,
+}
+
+/// The theme catalog of a [`Tree`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Tree`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl<Theme> From<Style> for StyleFn<'_, Theme> {
+    fn from(style: Style) -> Self {
+        Box::new(move |_theme| style)
+    }
+}
+
+impl Catalog for crate::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Tree`].
+pub fn default(theme: &crate::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        indicator: palette.background.strongest.color,
+        guide: Some(palette.background.strong.color.into()),
+        selected_row: palette.primary.weak.color.into(),
+        selected_text: Some(palette.primary.weak.text),
+        hovered_row: Some(palette.background.weak.color.into()),
+    }
+}
@@ -30,19 +30,26 @@
 //! ```
 use std::ops::RangeInclusive;
 
-pub use crate::slider::{Catalog, Handle, HandleShape, Status, Style, StyleFn, default};
+pub use crate::slider::{
+    Catalog, Handle, HandleShape, Scale, Status, Style, StyleFn, Tick, default,
+};
 
+use crate::core::alignment;
 use crate::core::border::Border;
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
-    self, Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+    Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::slider::{
+    LABEL_SPACING, TICK_LENGTH, TOOLTIP_SPACING, draw_tooltip, nearest_tick, next_tick,
 };
 
 /// An vertical bar and a handle that selects a single value from a range of
@@ -94,6 +101,11 @@ where
     on_release: Option<Message>,
     width: f32,
     height: Length,
+    ticks: Vec<Tick<T>>,
+    snap_to_ticks: bool,
+    scale: Scale,
+    tooltip: Option<Box<dyn Fn(T) -> String + 'a>>,
+    text_size: Option<Pixels>,
     class: Theme::Class<'a>,
     status: Option<Status>,
 }
@@ -141,6 +153,11 @@ where
             on_release: None,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
+            ticks: Vec::new(),
+            snap_to_ticks: false,
+            scale: Scale::default(),
+            tooltip: None,
+            text_size: None,
             class: Theme::default(),
             status: None,
         }
@@ -191,6 +208,42 @@ where
         self
     }
 
+    /// Sets the [`Tick`] marks of the [`VerticalSlider`], drawn next to its
+    /// rail.
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = impl Into<Tick<T>>>) -> Self {
+        self.ticks = ticks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether the [`VerticalSlider`] snaps to its [`Tick`] marks.
+    ///
+    /// If enabled, the [`VerticalSlider`] can only be set to the values of its
+    /// ticks, instead of every step.
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Sets the [`Scale`] of the [`VerticalSlider`].
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the function that produces the tooltip shown next to the handle
+    /// of the [`VerticalSlider`] while it is being dragged.
+    pub fn tooltip(mut self, tooltip: impl Fn(T) -> String + 'a) -> Self {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the text size of the labels and the tooltip of the
+    /// [`VerticalSlider`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
     /// Sets the style of the [`VerticalSlider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -216,7 +269,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -236,10 +289,39 @@ where
     fn layout(
         &mut self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let labels = self
+            .ticks
+            .iter()
+            .filter_map(|tick| tick.label.as_deref())
+            .map(|label| {
+                Renderer::Paragraph::with_text(Text {
+                    content: label,
+                    bounds: Size::INFINITE,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::default(),
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                })
+                .min_width()
+            })
+            .reduce(f32::max);
+
+        let marks = match labels {
+            _ if self.ticks.is_empty() => 0.0,
+            Some(width) => TICK_LENGTH + LABEL_SPACING + width,
+            None => TICK_LENGTH,
+        };
+
+        layout::atomic(limits, self.width + marks, self.height)
     }
 
     fn update(
@@ -278,7 +360,19 @@ where
                 let percent =
                     1.0 - f64::from(cursor_position.y - bounds.y) / f64::from(bounds.height);
 
-                let steps = (percent * (end - start) / step).round();
+                if self.snap_to_ticks
+                    && let Some(tick) = nearest_tick(
+                        self.ticks.iter().map(|tick| tick.value.into()),
+                        percent,
+                        self.scale,
+                        start,
+                        end,
+                    )
+                {
+                    return T::from_f64(tick);
+                }
+
+                let steps = ((self.scale.value(percent, start, end) - start) / step).round();
                 let value = steps * step + start;
 
                 T::from_f64(value.min(end))
@@ -286,6 +380,15 @@ where
         };
 
         let increment = |value: T| -> Option<T> {
+            if self.snap_to_ticks && !self.ticks.is_empty() {
+                return next_tick(
+                    self.ticks.iter().map(|tick| tick.value.into()),
+                    value.into(),
+                    true,
+                )
+                .and_then(T::from_f64);
+            }
+
             let step = if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
@@ -304,6 +407,15 @@ where
         };
 
         let decrement = |value: T| -> Option<T> {
+            if self.snap_to_ticks && !self.ticks.is_empty() {
+                return next_tick(
+                    self.ticks.iter().map(|tick| tick.value.into()),
+                    value.into(),
+                    false,
+                )
+                .and_then(T::from_f64);
+            }
+
             let step = if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
@@ -418,15 +530,19 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = Rectangle {
+            width: self.width,
+            ..layout.bounds()
+        };
 
         let style = theme.style(&self.class, self.status.unwrap_or(Status::Active));
 
@@ -438,19 +554,20 @@ where
             } => (f32::from(width), bounds.width, border_radius),
         };
 
-        let value = self.value.into() as f32;
         let (range_start, range_end) = {
             let (start, end) = self.range.clone().into_inner();
 
-            (start.into() as f32, end.into() as f32)
+            (start.into(), end.into())
         };
 
-        let offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.height - handle_width) * (value - range_end) / (range_start - range_end)
+        let position = |value: f64| {
+            (bounds.height - handle_width)
+                * (1.0 - self.scale.percent(value, range_start, range_end) as f32)
         };
 
+        let offset = position(self.value.into());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
         let rail_x = bounds.x + bounds.width / 2.0;
 
         renderer.fill_quad(
@@ -481,6 +598,43 @@ where
             style.rail.backgrounds.0,
         );
 
+        for tick in &self.ticks {
+            let y = bounds.y + position(tick.value.into()) + handle_width / 2.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width,
+                        y: y - 0.5,
+                        width: TICK_LENGTH,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.tick,
+            );
+
+            if let Some(label) = &tick.label {
+                renderer.fill_text(
+                    Text {
+                        content: label.clone(),
+                        bounds: Size::INFINITE,
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        align_x: text::Alignment::Default,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::default(),
+                        wrapping: text::Wrapping::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(bounds.x + bounds.width + TICK_LENGTH + LABEL_SPACING, y),
+                    style.label,
+                    *viewport,
+                );
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -498,6 +652,27 @@ where
             },
             style.handle.background,
         );
+
+        if state.is_dragging
+            && let Some(tooltip) = &self.tooltip
+        {
+            let left = rail_x - handle_height / 2.0;
+            let center = bounds.y + offset + handle_width / 2.0;
+
+            draw_tooltip(
+                renderer,
+                &style,
+                tooltip(self.value),
+                text_size,
+                *viewport,
+                |size| {
+                    Point::new(
+                        left - size.width - TOOLTIP_SPACING,
+                        center - size.height / 2.0,
+                    )
+                },
+            );
+        }
     }
 
     fn mouse_interaction(
@@ -536,7 +711,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         slider: VerticalSlider<'a, T, Message, Theme>,
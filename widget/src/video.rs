@@ -0,0 +1,775 @@
+//! Videos play media files and streams, decoded with GStreamer.
+//!
+//! A [`Video`] owns the playback of some media. Show its frames with a
+//! [`VideoPlayer`] and control it with [`Action`]s in your update logic.
+//!
+//! Frames are drawn as images. On the `wgpu` backend, they are uploaded
+//! straight into a dedicated texture instead, which is reused as long as the
+//! size of the video does not change.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::video::{self, Video, VideoPlayer};
+//!
+//! struct State {
+//!     video: Video,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Video(video::Action),
+//!     Ended,
+//! }
+//!
+//! fn new() -> State {
+//!     State {
+//!         video: Video::from_path("intro.mp4").expect("Open video"),
+//!     }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     VideoPlayer::new(&state.video)
+//!         .on_action(Message::Video)
+//!         .on_end_of_stream(Message::Ended)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Video(action) => {
+//!             state.video.perform(action);
+//!         }
+//!         Message::Ended => {
+//!             state.video.perform(video::Action::Seek(std::time::Duration::ZERO));
+//!         }
+//!     }
+//! }
+//! ```
+#[cfg(feature = "wgpu")]
+mod pipeline;
+
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Bytes, Clipboard, ContentFit, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+
+use std::fmt;
+use std::path::Path;
+use std::sync::atomic::{self, AtomicBool, AtomicU64};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The playback of some media, decoded with GStreamer.
+///
+/// A [`Video`] starts playing as soon as it is created. Its audio is played
+/// by GStreamer directly; see [`Audio`] to route it elsewhere.
+pub struct Video {
+    id: u64,
+    pipeline: gst::Element,
+    frame: Arc<Mutex<Option<Frame>>>,
+    is_paused: bool,
+    is_looping: bool,
+    has_ended: AtomicBool,
+}
+
+impl Video {
+    /// Creates a new [`Video`] playing the media at the given URI.
+    pub fn new(uri: &str) -> Result<Self, Error> {
+        Self::with_audio(uri, Audio::default())
+    }
+
+    /// Creates a new [`Video`] playing the media file at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path =
+            std::fs::canonicalize(path).map_err(|error| Error::Pipeline(error.to_string()))?;
+        let uri = gst::glib::filename_to_uri(path, None)?;
+
+        Self::new(&uri)
+    }
+
+    /// Creates a new [`Video`] playing the media at the given URI, with its
+    /// audio routed to the given [`Audio`] output.
+    pub fn with_audio(uri: &str, audio: Audio) -> Result<Self, Error> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        gst::init()?;
+
+        let pipeline = gst::ElementFactory::make("playbin")
+            .property("uri", uri)
+            .build()?;
+
+        let caps = gst::Caps::builder("video/x-raw")
+            .field("format", "RGBA")
+            .field("pixel-aspect-ratio", gst::Fraction::new(1, 1))
+            .build();
+
+        let sink = gst_app::AppSink::builder()
+            .caps(&caps)
+            .max_buffers(1)
+            .drop(true)
+            .build();
+
+        let frame = Arc::new(Mutex::new(None));
+
+        sink.set_callbacks(
+            gst_app::AppSinkCallbacks::builder()
+                .new_sample({
+                    let frame = frame.clone();
+
+                    move |sink| {
+                        let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                        let handle = decode(&sample).ok_or(gst::FlowError::Error)?;
+
+                        let mut frame = frame.lock().expect("Lock video frame");
+
+                        let generation = frame
+                            .as_ref()
+                            .map_or(0, |frame: &Frame| frame.generation + 1);
+
+                        *frame = Some(Frame { handle, generation });
+
+                        Ok(gst::FlowSuccess::Ok)
+                    }
+                })
+                .build(),
+        );
+
+        pipeline.set_property("video-sink", &sink);
+
+        match audio {
+            Audio::Default => {}
+            Audio::Disabled => {
+                let sink = gst::ElementFactory::make("fakesink").build()?;
+
+                pipeline.set_property("audio-sink", &sink);
+            }
+            Audio::Sink(description) => {
+                let sink = gst::parse::bin_from_description(&description, true)?;
+
+                pipeline.set_property("audio-sink", &sink);
+            }
+        }
+
+        let _ = pipeline.set_state(gst::State::Playing)?;
+
+        Ok(Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            pipeline,
+            frame,
+            is_paused: false,
+            is_looping: false,
+            has_ended: AtomicBool::new(false),
+        })
+    }
+
+    /// Performs an [`Action`] on the [`Video`].
+    ///
+    /// Failures are logged; use the methods of the [`Video`] directly to
+    /// handle them.
+    pub fn perform(&mut self, action: Action) {
+        let result = match action {
+            Action::Play => self.set_paused(false),
+            Action::Pause => self.set_paused(true),
+            Action::Seek(position) => self.seek(position),
+            Action::SetVolume(volume) => {
+                self.set_volume(volume);
+                Ok(())
+            }
+            Action::SetMuted(is_muted) => {
+                self.set_muted(is_muted);
+                Ok(())
+            }
+        };
+
+        if let Err(error) = result {
+            log::warn!("Video action failed: {error}");
+        }
+    }
+
+    /// Pauses or resumes the [`Video`].
+    ///
+    /// Resuming a [`Video`] that has ended plays it again from the start.
+    pub fn set_paused(&mut self, is_paused: bool) -> Result<(), Error> {
+        if !is_paused && self.has_ended() {
+            self.seek(Duration::ZERO)?;
+        }
+
+        let _ = self.pipeline.set_state(if is_paused {
+            gst::State::Paused
+        } else {
+            gst::State::Playing
+        })?;
+
+        self.is_paused = is_paused;
+
+        Ok(())
+    }
+
+    /// Seeks the [`Video`] to the given position.
+    pub fn seek(&mut self, position: Duration) -> Result<(), Error> {
+        self.pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_nseconds(position.as_nanos() as u64),
+        )?;
+
+        self.has_ended.store(false, atomic::Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Sets the volume of the [`Video`], from `0.0` to `1.0`.
+    pub fn set_volume(&mut self, volume: f64) {
+        self.pipeline.set_property("volume", volume.clamp(0.0, 1.0));
+    }
+
+    /// Mutes or unmutes the audio of the [`Video`].
+    pub fn set_muted(&mut self, is_muted: bool) {
+        self.pipeline.set_property("mute", is_muted);
+    }
+
+    /// Sets whether the [`Video`] should start over when it ends.
+    pub fn set_looping(&mut self, is_looping: bool) {
+        self.is_looping = is_looping;
+    }
+
+    /// Returns whether the [`Video`] is paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Returns whether the [`Video`] has reached the end of its stream.
+    pub fn has_ended(&self) -> bool {
+        self.has_ended.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Returns whether the [`Video`] starts over when it ends.
+    pub fn is_looping(&self) -> bool {
+        self.is_looping
+    }
+
+    /// Returns the current playback position of the [`Video`].
+    pub fn position(&self) -> Duration {
+        self.pipeline
+            .query_position::<gst::ClockTime>()
+            .map_or(Duration::ZERO, |position| {
+                Duration::from_nanos(position.nseconds())
+            })
+    }
+
+    /// Returns the duration of the [`Video`], if known.
+    ///
+    /// Live streams have no duration.
+    pub fn duration(&self) -> Option<Duration> {
+        self.pipeline
+            .query_duration::<gst::ClockTime>()
+            .map(|duration| Duration::from_nanos(duration.nseconds()))
+    }
+
+    /// Returns the volume of the [`Video`].
+    pub fn volume(&self) -> f64 {
+        self.pipeline.property("volume")
+    }
+
+    /// Returns whether the audio of the [`Video`] is muted.
+    pub fn is_muted(&self) -> bool {
+        self.pipeline.property("mute")
+    }
+
+    /// Returns the size of the frames of the [`Video`], once the first one
+    /// has been decoded.
+    pub fn size(&self) -> Option<Size<u32>> {
+        self.frame().as_ref().and_then(|frame| match &frame.handle {
+            image::Handle::Rgba { width, height, .. } => Some(Size::new(*width, *height)),
+            _ => None,
+        })
+    }
+
+    /// Returns the GStreamer pipeline of the [`Video`], for advanced use.
+    pub fn pipeline(&self) -> &gst::Element {
+        &self.pipeline
+    }
+
+    fn frame(&self) -> std::sync::MutexGuard<'_, Option<Frame>> {
+        self.frame.lock().expect("Lock video frame")
+    }
+
+    /// Processes the pending messages of the pipeline of the [`Video`].
+    fn poll(&self) -> Vec<Signal> {
+        let Some(bus) = self.pipeline.bus() else {
+            return Vec::new();
+        };
+
+        let mut signals = Vec::new();
+
+        while let Some(message) =
+            bus.pop_filtered(&[gst::MessageType::Eos, gst::MessageType::Error])
+        {
+            match message.view() {
+                gst::MessageView::Eos(_) => {
+                    let restarted = self.is_looping
+                        && self
+                            .pipeline
+                            .seek_simple(gst::SeekFlags::FLUSH, gst::ClockTime::ZERO)
+                            .is_ok();
+
+                    if !restarted {
+                        self.has_ended.store(true, atomic::Ordering::Relaxed);
+                        signals.push(Signal::Ended);
+                    }
+                }
+                gst::MessageView::Error(error) => {
+                    signals.push(Signal::Failed(Error::Stream(error.error().to_string())));
+                }
+                _ => {}
+            }
+        }
+
+        signals
+    }
+}
+
+impl Drop for Video {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
+
+impl fmt::Debug for Video {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Video")
+            .field("id", &self.id)
+            .field("is_paused", &self.is_paused)
+            .field("is_looping", &self.is_looping)
+            .field("has_ended", &self.has_ended)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An action that controls the playback of a [`Video`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Resume the [`Video`].
+    Play,
+    /// Pause the [`Video`].
+    Pause,
+    /// Seek the [`Video`] to the given position.
+    Seek(Duration),
+    /// Set the volume of the [`Video`], from `0.0` to `1.0`.
+    SetVolume(f64),
+    /// Mute or unmute the audio of the [`Video`].
+    SetMuted(bool),
+}
+
+/// The output of the audio of a [`Video`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Audio {
+    /// The default audio output of the system.
+    #[default]
+    Default,
+    /// No audio at all.
+    Disabled,
+    /// A GStreamer bin, described like in `gst-launch`; for instance,
+    /// `audioconvert ! pulsesink device=headphones`.
+    Sink(String),
+}
+
+/// An error of a [`Video`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The media could not be opened or controlled.
+    #[error("the video pipeline failed: {0}")]
+    Pipeline(String),
+    /// The media failed while playing.
+    #[error("the video stream failed: {0}")]
+    Stream(String),
+}
+
+impl From<gst::glib::Error> for Error {
+    fn from(error: gst::glib::Error) -> Self {
+        Self::Pipeline(error.to_string())
+    }
+}
+
+impl From<gst::glib::BoolError> for Error {
+    fn from(error: gst::glib::BoolError) -> Self {
+        Self::Pipeline(error.to_string())
+    }
+}
+
+impl From<gst::StateChangeError> for Error {
+    fn from(error: gst::StateChangeError) -> Self {
+        Self::Pipeline(error.to_string())
+    }
+}
+
+/// A notification from the pipeline of a [`Video`].
+enum Signal {
+    Ended,
+    Failed(Error),
+}
+
+/// The last decoded frame of a [`Video`].
+#[derive(Debug, Clone)]
+struct Frame {
+    handle: image::Handle,
+    generation: u64,
+}
+
+fn decode(sample: &gst::Sample) -> Option<image::Handle> {
+    let structure = sample.caps()?.structure(0)?;
+
+    let width = u32::try_from(structure.get::<i32>("width").ok()?).ok()?;
+    let height = u32::try_from(structure.get::<i32>("height").ok()?).ok()?;
+
+    let buffer = sample.buffer()?.map_readable().ok()?;
+
+    // Rows of RGBA pixels are always aligned, so there is no padding
+    if buffer.len() < (width * height * 4) as usize {
+        return None;
+    }
+
+    Some(image::Handle::from_rgba(
+        width,
+        height,
+        Bytes::copy_from_slice(&buffer[..(width * height * 4) as usize]),
+    ))
+}
+
+/// The renderer of a [`VideoPlayer`].
+///
+/// With the `wgpu` feature, frames are drawn as custom primitives; so they
+/// are not visible with other backends.
+#[cfg(not(feature = "wgpu"))]
+pub trait Renderer: image::Renderer<Handle = image::Handle> {}
+
+#[cfg(not(feature = "wgpu"))]
+impl<T> Renderer for T where T: image::Renderer<Handle = image::Handle> {}
+
+/// The renderer of a [`VideoPlayer`].
+///
+/// With the `wgpu` feature, frames are drawn as custom primitives; so they
+/// are not visible with other backends.
+#[cfg(feature = "wgpu")]
+pub trait Renderer: crate::renderer::wgpu::primitive::Renderer {}
+
+#[cfg(feature = "wgpu")]
+impl<T> Renderer for T where T: crate::renderer::wgpu::primitive::Renderer {}
+
+/// A widget that displays the frames of a [`Video`].
+pub struct VideoPlayer<'a, Message> {
+    video: &'a Video,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_new_frame: Option<Message>,
+    on_end_of_stream: Option<Message>,
+    on_error: Option<Box<dyn Fn(Error) -> Message + 'a>>,
+}
+
+impl<'a, Message> VideoPlayer<'a, Message> {
+    /// Creates a new [`VideoPlayer`] displaying the given [`Video`].
+    pub fn new(video: &'a Video) -> Self {
+        Self {
+            video,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: image::FilterMethod::default(),
+            on_action: None,
+            on_new_frame: None,
+            on_end_of_stream: None,
+            on_error: None,
+        }
+    }
+
+    /// Sets the width of the [`VideoPlayer`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VideoPlayer`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`VideoPlayer`]; how the frames are
+    /// scaled to fit its bounds.
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`](image::FilterMethod) used to scale the
+    /// frames of the [`VideoPlayer`].
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the message produced with an [`Action`] when the
+    /// [`VideoPlayer`] is clicked; toggling its playback.
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets the message produced when the [`VideoPlayer`] displays a new
+    /// frame; useful to keep a progress bar in sync.
+    pub fn on_new_frame(mut self, message: Message) -> Self {
+        self.on_new_frame = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`Video`] reaches its end.
+    ///
+    /// It is not produced by looping videos.
+    pub fn on_end_of_stream(mut self, message: Message) -> Self {
+        self.on_end_of_stream = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`Video`] fails while playing.
+    pub fn on_error(mut self, on_error: impl Fn(Error) -> Message + 'a) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+
+    /// Returns the bounds of the frames of the [`Video`] fit in the given
+    /// bounds.
+    fn drawing_bounds(&self, bounds: Rectangle, size: Size<u32>) -> Rectangle {
+        let size = Size::new(size.width as f32, size.height as f32);
+        let fit = self.content_fit.fit(size, bounds.size());
+
+        Rectangle::new(
+            Point::new(
+                bounds.center_x() - fit.width / 2.0,
+                bounds.center_y() - fit.height / 2.0,
+            ),
+            fit,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    generation: Option<u64>,
+    size: Size<u32>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for VideoPlayer<'_, Message>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.size = self.video.size().unwrap_or(Size::new(0, 0));
+
+        if state.size.width == 0 || state.size.height == 0 {
+            return layout::atomic(limits, self.width, self.height);
+        }
+
+        let video_size = Size::new(state.size.width as f32, state.size.height as f32);
+        let bounds = limits.resolve(self.width, self.height, video_size);
+        let fit = self.content_fit.fit(video_size, bounds);
+
+        layout::Node::new(Size {
+            width: match self.width {
+                Length::Shrink => bounds.width.min(fit.width),
+                _ => bounds.width,
+            },
+            height: match self.height {
+                Length::Shrink => bounds.height.min(fit.height),
+                _ => bounds.height,
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        match event {
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                for signal in self.video.poll() {
+                    match signal {
+                        Signal::Ended => {
+                            if let Some(message) = &self.on_end_of_stream {
+                                shell.publish(message.clone());
+                            }
+                        }
+                        Signal::Failed(error) => {
+                            if let Some(on_error) = &self.on_error {
+                                shell.publish(on_error(error));
+                            }
+                        }
+                    }
+                }
+
+                let generation = self.video.frame().as_ref().map(|frame| frame.generation);
+
+                if generation != state.generation {
+                    state.generation = generation;
+
+                    if self.video.size().unwrap_or(Size::new(0, 0)) != state.size {
+                        shell.invalidate_layout();
+                    }
+
+                    if let Some(message) = &self.on_new_frame {
+                        shell.publish(message.clone());
+                    }
+                }
+
+                // New frames are decoded in the background
+                if !self.video.is_paused() && !self.video.has_ended() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(on_action) = &self.on_action
+                    && cursor.is_over(layout.bounds())
+                {
+                    shell.publish(on_action(
+                        if self.video.is_paused() || self.video.has_ended() {
+                            Action::Play
+                        } else {
+                            Action::Pause
+                        },
+                    ));
+
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let Some(size) = self.video.size() else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let drawing_bounds = self.drawing_bounds(bounds, size);
+
+        #[cfg(not(feature = "wgpu"))]
+        {
+            let Some(frame) = self.video.frame().clone() else {
+                return;
+            };
+
+            renderer.draw_image(
+                image::Image::new(frame.handle).filter_method(self.filter_method),
+                drawing_bounds,
+                bounds,
+            );
+        }
+
+        #[cfg(feature = "wgpu")]
+        {
+            let Some(visible) = drawing_bounds.intersection(&bounds) else {
+                return;
+            };
+
+            // Only the visible region of the frame is sampled when cropped
+            let region = Rectangle {
+                x: (visible.x - drawing_bounds.x) / drawing_bounds.width,
+                y: (visible.y - drawing_bounds.y) / drawing_bounds.height,
+                width: visible.width / drawing_bounds.width,
+                height: visible.height / drawing_bounds.height,
+            };
+
+            renderer.draw_primitive(
+                visible,
+                pipeline::Primitive {
+                    id: self.video.id,
+                    frame: self.video.frame.clone(),
+                    region,
+                    filter_method: self.filter_method,
+                },
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_action.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VideoPlayer<'a, Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(video_player: VideoPlayer<'a, Message>) -> Self {
+        Self::new(video_player)
+    }
+}
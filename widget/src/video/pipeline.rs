@@ -0,0 +1,319 @@
+//! Upload the frames of a video straight into a texture of `wgpu`.
+use crate::core::Rectangle;
+use crate::core::image;
+use crate::graphics::Viewport;
+use crate::renderer::wgpu::primitive;
+use crate::renderer::wgpu::wgpu;
+use crate::video::Frame;
+
+use rustc_hash::FxHashMap;
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+/// The frame of a video to draw, with the region of it that is visible.
+#[derive(Debug)]
+pub struct Primitive {
+    pub id: u64,
+    pub frame: Arc<Mutex<Option<Frame>>>,
+    pub region: Rectangle,
+    pub filter_method: image::FilterMethod,
+}
+
+impl primitive::Primitive for Primitive {
+    type Pipeline = Pipeline;
+
+    fn prepare(
+        &self,
+        pipeline: &mut Pipeline,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        _bounds: &Rectangle,
+        _viewport: &Viewport,
+    ) {
+        let frame = self.frame.lock().expect("Lock video frame");
+
+        let Some(Frame {
+            handle:
+                image::Handle::Rgba {
+                    width,
+                    height,
+                    pixels,
+                    ..
+                },
+            generation,
+        }) = frame.as_ref()
+        else {
+            return;
+        };
+
+        let entry = pipeline.entry(device, self.id, *width, *height);
+
+        if entry.generation != Some(*generation) {
+            queue.write_texture(
+                wgpu::TexelCopyTextureInfo {
+                    texture: &entry.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                pixels,
+                wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * width),
+                    rows_per_image: Some(*height),
+                },
+                wgpu::Extent3d {
+                    width: *width,
+                    height: *height,
+                    depth_or_array_layers: 1,
+                },
+            );
+
+            entry.generation = Some(*generation);
+        }
+
+        let region: Vec<u8> = [
+            self.region.x,
+            self.region.y,
+            self.region.width,
+            self.region.height,
+        ]
+        .iter()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect();
+
+        queue.write_buffer(&entry.uniforms, 0, &region);
+
+        entry.is_used = true;
+    }
+
+    fn draw(&self, pipeline: &Pipeline, render_pass: &mut wgpu::RenderPass<'_>) -> bool {
+        let Some(entry) = pipeline.entries.get(&self.id) else {
+            return true;
+        };
+
+        let sampler = match self.filter_method {
+            image::FilterMethod::Nearest => &pipeline.nearest,
+            image::FilterMethod::Linear | image::FilterMethod::Mipmap => &pipeline.linear,
+        };
+
+        render_pass.set_pipeline(&pipeline.pipeline);
+        render_pass.set_bind_group(0, &entry.bind_group, &[]);
+        render_pass.set_bind_group(1, sampler, &[]);
+        render_pass.draw(0..4, 0..1);
+
+        true
+    }
+}
+
+/// The textures of the videos being drawn.
+pub struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    format: wgpu::TextureFormat,
+    linear: wgpu::BindGroup,
+    nearest: wgpu::BindGroup,
+    entries: FxHashMap<u64, Entry>,
+}
+
+struct Entry {
+    texture: wgpu::Texture,
+    uniforms: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    size: (u32, u32),
+    generation: Option<u64>,
+    is_used: bool,
+}
+
+impl Pipeline {
+    /// Returns the [`Entry`] of the video with the given id, with a texture
+    /// of the given size.
+    fn entry(&mut self, device: &wgpu::Device, id: u64, width: u32, height: u32) -> &mut Entry {
+        let entry = self
+            .entries
+            .entry(id)
+            .or_insert_with(|| create(device, &self.layout, self.format, width, height));
+
+        // Textures are only recreated when the size of the video changes
+        if entry.size != (width, height) {
+            *entry = create(device, &self.layout, self.format, width, height);
+        }
+
+        entry
+    }
+}
+
+impl primitive::Pipeline for Pipeline {
+    fn new(device: &wgpu::Device, _queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Self {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_widget::video texture layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let sampler_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_widget::video sampler layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            }],
+        });
+
+        let sampler = |filter: wgpu::FilterMode| {
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                label: Some("iced_widget::video sampler"),
+                mag_filter: filter,
+                min_filter: filter,
+                ..Default::default()
+            });
+
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_widget::video sampler bind group"),
+                layout: &sampler_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                }],
+            })
+        };
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_widget::video pipeline layout"),
+            bind_group_layouts: &[&layout, &sampler_layout],
+            immediate_size: 0,
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("iced_widget::video shader"),
+            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("shader.wgsl"))),
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_widget::video pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..wgpu::PrimitiveState::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            layout,
+            format,
+            linear: sampler(wgpu::FilterMode::Linear),
+            nearest: sampler(wgpu::FilterMode::Nearest),
+            entries: FxHashMap::default(),
+        }
+    }
+
+    fn trim(&mut self) {
+        // Drop the textures of the videos that were not drawn
+        self.entries
+            .retain(|_, entry| std::mem::take(&mut entry.is_used));
+    }
+}
+
+fn create(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+) -> Entry {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_widget::video texture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        // Frames are sRGB; they must only be decoded if the target encodes them again
+        format: if format.is_srgb() {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        },
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+
+    let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_widget::video uniforms"),
+        size: 16,
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("iced_widget::video texture bind group"),
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniforms.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::TextureView(&view),
+            },
+        ],
+    });
+
+    Entry {
+        texture,
+        uniforms,
+        bind_group,
+        size: (width, height),
+        generation: None,
+        is_used: false,
+    }
+}
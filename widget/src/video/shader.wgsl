@@ -0,0 +1,29 @@
+struct Uniforms {
+    // The visible region of the frame, in texture coordinates
+    region: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var frame: texture_2d<f32>;
+@group(1) @binding(0) var frame_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let corner = vec2<f32>(f32(vertex_index & 1u), f32(vertex_index >> 1u));
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(corner.x * 2.0 - 1.0, 1.0 - corner.y * 2.0, 0.0, 1.0);
+    out.uv = uniforms.region.xy + corner * uniforms.region.zw;
+
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(frame, frame_sampler, input.uv);
+}
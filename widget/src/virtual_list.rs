@@ -0,0 +1,426 @@
+//! Display very large collections by only building their visible rows.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{scrollable, text, virtual_list};
+//!
+//! struct State {
+//!    names: Vec<String>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(
+//!         virtual_list(state.names.len(), |i| text(&state.names[i]).into()).row_height(24),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::widget::Tree;
+use crate::core::{
+    self, Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::ops::Range;
+
+/// A vertical list that only builds and lays out the rows that are visible
+/// in its viewport.
+///
+/// A [`VirtualList`] is normally placed inside a [`Scrollable`], which
+/// provides the viewport. Rows can either have a fixed height—which is the
+/// cheapest—or be measured as they become visible, in which case an estimated
+/// height is used for the rows that have never been visible.
+///
+/// Only the visible rows receive events and operations.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub struct VirtualList<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    count: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    spacing: f32,
+    row_height: RowHeight,
+    rows: Vec<(usize, Element<'a, Message, Theme, Renderer>)>,
+}
+
+/// The height of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RowHeight {
+    Fixed(f32),
+    Measured { estimate: f32 },
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The amount of rows built outside of the viewport on each side.
+    const OVERSCAN: usize = 2;
+
+    /// Creates a new [`VirtualList`] with the given amount of rows and
+    /// a closure that produces the row with the given index.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            view: Box::new(view),
+            width: Length::Fill,
+            spacing: 0.0,
+            row_height: RowHeight::Measured { estimate: 30.0 },
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the rows of the [`VirtualList`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets a fixed height for all the rows of the [`VirtualList`].
+    ///
+    /// Rows are laid out with this exact height, so no measuring is needed.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`VirtualList`] that have
+    /// not been measured yet.
+    ///
+    /// Rows are measured once they become visible, so a closer estimate
+    /// reduces the jumps of the scrollbar while scrolling.
+    ///
+    /// This is the default behavior, with an estimate of `30.0`.
+    pub fn estimated_row_height(mut self, estimate: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Measured {
+            estimate: estimate.into().0,
+        };
+        self
+    }
+
+    /// Returns the range of rows that intersect the given vertical band of the
+    /// [`VirtualList`], including the overscan rows.
+    fn visible_rows(&self, state: &State, top: f32, bottom: f32) -> Range<usize> {
+        let range = match self.row_height {
+            RowHeight::Fixed(height) => {
+                let stride = (height + self.spacing).max(1.0);
+
+                let start = (top.max(0.0) / stride) as usize;
+                let end = (bottom.max(0.0) / stride).ceil() as usize;
+
+                start..end
+            }
+            RowHeight::Measured { estimate } => {
+                let mut offset = 0.0;
+                let mut start = self.count;
+                let mut end = self.count;
+
+                for (index, height) in state.heights.iter().enumerate() {
+                    let height = height.unwrap_or(estimate);
+
+                    if start == self.count && offset + height > top {
+                        start = index;
+                    }
+
+                    if offset >= bottom {
+                        end = index;
+                        break;
+                    }
+
+                    offset += height + self.spacing;
+                }
+
+                start.min(end)..end
+            }
+        };
+
+        let end = (range.end + Self::OVERSCAN).min(self.count);
+
+        range.start.saturating_sub(Self::OVERSCAN).min(end)..end
+    }
+
+    /// Returns the vertical offset of the row with the given index.
+    fn offset(&self, state: &State, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => index as f32 * (height + self.spacing),
+            RowHeight::Measured { estimate } => state.heights[..index]
+                .iter()
+                .map(|height| height.unwrap_or(estimate) + self.spacing)
+                .sum(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    viewport: Option<Rectangle>,
+    visible: Range<usize>,
+    indices: Vec<usize>,
+    heights: Vec<Option<f32>>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Diff is deferred to layout
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max = limits.max();
+
+        let state = tree.state.downcast_mut::<State>();
+        state.heights.resize(self.count, None);
+
+        let viewport = state.viewport.unwrap_or(Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: max.width,
+            height: if max.height.is_finite() {
+                max.height
+            } else {
+                0.0
+            },
+        });
+
+        let visible = self.visible_rows(state, viewport.y, viewport.y + viewport.height);
+
+        self.rows = visible.clone().map(|i| (i, (self.view)(i))).collect();
+
+        // Reuse the state of the rows that were already visible; both
+        // lists of indices are sorted
+        let mut previous = state
+            .indices
+            .drain(..)
+            .zip(tree.children.drain(..))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .peekable();
+
+        for (index, row) in &self.rows {
+            while previous.next_if(|(previous, _)| previous < index).is_some() {}
+
+            let child = match previous.next_if(|(previous, _)| previous == index) {
+                Some((_, mut child)) => {
+                    child.diff(row.as_widget());
+                    child
+                }
+                None => Tree::new(row.as_widget()),
+            };
+
+            state.indices.push(*index);
+            tree.children.push(child);
+        }
+
+        let row_limits = match self.row_height {
+            RowHeight::Fixed(height) => {
+                layout::Limits::new(Size::new(0.0, height), Size::new(max.width, height))
+            }
+            RowHeight::Measured { .. } => {
+                layout::Limits::new(Size::ZERO, Size::new(max.width, f32::INFINITY))
+            }
+        };
+
+        let mut y = self.offset(state, visible.start);
+        let mut width: f32 = 0.0;
+        let mut nodes = Vec::with_capacity(self.rows.len());
+
+        for ((index, row), child) in self.rows.iter_mut().zip(&mut tree.children) {
+            let node = row.as_widget_mut().layout(child, renderer, &row_limits);
+            let size = node.size();
+
+            if let RowHeight::Measured { .. } = self.row_height {
+                state.heights[*index] = Some(size.height);
+            }
+
+            width = width.max(size.width);
+            nodes.push(node.move_to(Point::new(0.0, y)));
+
+            y += size.height + self.spacing;
+        }
+
+        state.visible = visible;
+
+        let height =
+            self.offset(state, self.count) - if self.count > 0 { self.spacing } else { 0.0 };
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, height));
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        let visible = Rectangle {
+            x: viewport.x - bounds.x,
+            y: viewport.y - bounds.y,
+            ..*viewport
+        };
+
+        state.viewport = Some(visible);
+
+        if self.visible_rows(state, visible.y, visible.y + visible.height) != state.visible {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        for ((_, row), (child, layout)) in self
+            .rows
+            .iter_mut()
+            .zip(tree.children.iter_mut().zip(layout.children()))
+        {
+            row.as_widget_mut().update(
+                child, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((_, row), (child, layout)) in self
+            .rows
+            .iter()
+            .zip(tree.children.iter().zip(layout.children()))
+        {
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            row.as_widget()
+                .draw(child, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(tree.children.iter().zip(layout.children()))
+            .map(|((_, row), (child, layout))| {
+                row.as_widget()
+                    .mouse_interaction(child, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((_, row), (child, layout)) in self
+                .rows
+                .iter_mut()
+                .zip(tree.children.iter_mut().zip(layout.children()))
+            {
+                row.as_widget_mut()
+                    .operate(child, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(tree.children.iter_mut().zip(layout.children()))
+            .filter_map(|((_, row), (child, layout))| {
+                row.as_widget_mut()
+                    .overlay(child, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
@@ -0,0 +1,275 @@
+//! Web views embed a native web view of the platform, powered by `wry`, in
+//! the layout of your application.
+//!
+//! A [`WebView`] only reserves some space in the layout; the page is drawn
+//! on top of the window by the platform, so it can not be overlapped or
+//! clipped by other widgets. It is hidden as soon as its [`WebView`] is not
+//! part of the view or scrolled out of sight.
+//!
+//! Web views are opened, navigated, and scripted with the tasks of the
+//! `web_view` module of `iced`, which call the [`host`] functions in the
+//! thread of the event loop. On Linux, only X11 windows are supported and
+//! GTK must be initialized.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::web_view::{self, WebView};
+//!
+//! struct State {
+//!     docs: web_view::Id,
+//! }
+//!
+//! enum Message {
+//!     WebView(web_view::Id, web_view::Event),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     WebView::new(state.docs).into()
+//! }
+//! ```
+pub mod host;
+
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Event as CoreEvent, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+use std::sync::atomic::{self, AtomicU64};
+
+/// The identifier of a web view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a new unique [`Id`].
+    pub fn unique() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// The settings of a web view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    /// The URL of the first page of the web view.
+    pub url: String,
+    /// The prefixes of the URLs that can not be navigated to, but are
+    /// reported as an [`Event::Intercepted`] instead.
+    ///
+    /// This is useful to capture the redirect at the end of an OAuth flow.
+    pub intercept: Vec<String>,
+    /// The user agent of the web view, if it is not the default one.
+    pub user_agent: Option<String>,
+    /// Whether the developer tools of the web view are enabled.
+    pub devtools: bool,
+    /// Whether the background of the web view is transparent.
+    pub transparent: bool,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] opening the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Intercepts the navigation to any URL starting with the given prefix.
+    pub fn intercept(mut self, prefix: impl Into<String>) -> Self {
+        self.intercept.push(prefix.into());
+        self
+    }
+
+    /// Sets the user agent of the web view.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets whether the developer tools of the web view are enabled.
+    pub fn devtools(mut self, devtools: bool) -> Self {
+        self.devtools = devtools;
+        self
+    }
+
+    /// Sets whether the background of the web view is transparent.
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+}
+
+/// An event of a web view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The web view started loading the page at the given URL.
+    Loading(String),
+    /// The web view finished loading the page at the given URL.
+    Loaded(String),
+    /// The navigation to the given URL was blocked, since it was
+    /// intercepted.
+    Intercepted(String),
+    /// The title of the page changed.
+    TitleChanged(String),
+    /// The page sent a message with `window.ipc.postMessage`.
+    Message(String),
+}
+
+/// An error of a web view.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The web view could not be created, or it failed to perform some
+    /// operation.
+    #[error("the web view failed: {0}")]
+    Platform(String),
+    /// There is no open web view with the given [`Id`].
+    #[error("the web view was not found")]
+    NotFound,
+}
+
+impl From<wry::Error> for Error {
+    fn from(error: wry::Error) -> Self {
+        Self::Platform(error.to_string())
+    }
+}
+
+/// A widget that reserves the space of a web view in the layout.
+///
+/// The web view with its [`Id`] is moved and resized to the bounds of the
+/// [`WebView`].
+#[derive(Debug)]
+pub struct WebView {
+    id: Id,
+    width: Length,
+    height: Length,
+}
+
+impl WebView {
+    /// Creates a new [`WebView`] for the web view with the given [`Id`].
+    pub fn new(id: Id) -> Self {
+        Self {
+            id,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the width of the [`WebView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`WebView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+/// The placement of the web view last applied by a [`WebView`].
+#[derive(Debug, Default)]
+struct State {
+    placed: Option<(Id, Option<Rectangle>)>,
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        // The web view is hidden when it leaves the view
+        if let Some((id, _)) = self.placed {
+            host::place(id, None);
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for WebView
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &CoreEvent,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let CoreEvent::Window(window::Event::RedrawRequested(_)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let bounds = layout.bounds();
+        let placement = (self.id, viewport.intersects(&bounds).then_some(bounds));
+
+        if state.placed == Some(placement) {
+            return;
+        }
+
+        if let Some((previous, _)) = state.placed
+            && previous != self.id
+        {
+            host::place(previous, None);
+        }
+
+        host::place(placement.0, placement.1);
+        state.placed = Some(placement);
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<WebView> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(web_view: WebView) -> Self {
+        Self::new(web_view)
+    }
+}
@@ -0,0 +1,229 @@
+//! Manage the web views of the windows of an application.
+//!
+//! These functions must be called in the thread of the event loop, like
+//! the [`WebView`](super::WebView) widgets placing them.
+use super::{Error, Event, Id, Settings};
+use crate::core::Rectangle;
+
+use raw_window_handle::{HandleError, HasWindowHandle, WindowHandle};
+use rustc_hash::FxHashMap;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+thread_local! {
+    static WEB_VIEWS: RefCell<FxHashMap<Id, wry::WebView>> = RefCell::default();
+    static PLACEMENTS: RefCell<FxHashMap<Id, Rectangle>> = RefCell::default();
+}
+
+/// Opens a web view with the given [`Id`] and [`Settings`] as a child
+/// of the given window.
+///
+/// The web view stays hidden until a [`WebView`](super::WebView) places
+/// it. Its events are reported to the given closure.
+pub fn open(
+    window: &dyn HasWindowHandle,
+    id: Id,
+    settings: Settings,
+    on_event: impl Fn(Id, Event) + 'static,
+) -> Result<(), Error> {
+    let on_event = Rc::new(on_event);
+    let placement = PLACEMENTS.with_borrow(|placements| placements.get(&id).copied());
+
+    let mut builder = wry::WebViewBuilder::new()
+        .with_url(settings.url)
+        .with_devtools(settings.devtools)
+        .with_transparent(settings.transparent)
+        .with_visible(placement.is_some())
+        .with_bounds(rect(placement.unwrap_or_default()))
+        .with_ipc_handler({
+            let on_event = on_event.clone();
+
+            move |request| on_event(id, Event::Message(request.into_body()))
+        })
+        .with_navigation_handler({
+            let on_event = on_event.clone();
+
+            move |url| {
+                let is_intercepted = settings
+                    .intercept
+                    .iter()
+                    .any(|prefix| url.starts_with(prefix));
+
+                if is_intercepted {
+                    on_event(id, Event::Intercepted(url));
+                }
+
+                !is_intercepted
+            }
+        })
+        .with_on_page_load_handler({
+            let on_event = on_event.clone();
+
+            move |event, url| {
+                on_event(
+                    id,
+                    match event {
+                        wry::PageLoadEvent::Started => Event::Loading(url),
+                        wry::PageLoadEvent::Finished => Event::Loaded(url),
+                    },
+                );
+            }
+        })
+        .with_document_title_changed_handler(move |title| {
+            on_event(id, Event::TitleChanged(title));
+        });
+
+    if let Some(user_agent) = &settings.user_agent {
+        builder = builder.with_user_agent(user_agent);
+    }
+
+    let web_view = builder.build_as_child(&Parent(window))?;
+
+    let _ = WEB_VIEWS.with_borrow_mut(|web_views| web_views.insert(id, web_view));
+
+    Ok(())
+}
+
+/// Closes the web view with the given [`Id`].
+pub fn close(id: Id) -> Result<(), Error> {
+    WEB_VIEWS
+        .with_borrow_mut(|web_views| web_views.remove(&id))
+        .map(drop)
+        .ok_or(Error::NotFound)
+}
+
+/// Loads the given URL in the web view with the given [`Id`].
+pub fn navigate(id: Id, url: &str) -> Result<(), Error> {
+    with(id, |web_view| web_view.load_url(url))
+}
+
+/// Evaluates the given script in the web view with the given [`Id`],
+/// reporting its result—serialized as JSON—to the given closure.
+pub fn evaluate(
+    id: Id,
+    script: &str,
+    on_result: impl FnOnce(String) + Send + 'static,
+) -> Result<(), Error> {
+    let on_result = Mutex::new(Some(on_result));
+
+    with(id, |web_view| {
+        web_view.evaluate_script_with_callback(script, move |result| {
+            if let Some(on_result) = on_result.lock().ok().and_then(|mut f| f.take()) {
+                on_result(result);
+            }
+        })
+    })
+}
+
+/// Sends a message to the page of the web view with the given [`Id`].
+///
+/// The page receives it as a `MessageEvent` named `iced` on its
+/// `window`, with the message as its `data`.
+pub fn post(id: Id, message: &str) -> Result<(), Error> {
+    let script = format!(
+        "window.dispatchEvent(new MessageEvent(\"iced\", {{ data: {} }}));",
+        quote(message)
+    );
+
+    with(id, |web_view| web_view.evaluate_script(&script))
+}
+
+/// Moves the web view with the given [`Id`] to the given bounds, or
+/// hides it.
+pub(super) fn place(id: Id, bounds: Option<Rectangle>) {
+    let _ = PLACEMENTS.try_with(|placements| {
+        let mut placements = placements.borrow_mut();
+
+        match bounds {
+            Some(bounds) => {
+                let _ = placements.insert(id, bounds);
+            }
+            None => {
+                let _ = placements.remove(&id);
+            }
+        }
+    });
+
+    let result = WEB_VIEWS.try_with(|web_views| {
+        let web_views = web_views.borrow();
+
+        let Some(web_view) = web_views.get(&id) else {
+            return Ok(());
+        };
+
+        if let Some(bounds) = bounds {
+            web_view.set_bounds(rect(bounds))?;
+        }
+
+        web_view.set_visible(bounds.is_some())
+    });
+
+    if let Ok(Err(error)) = result {
+        log::warn!("Web view could not be placed: {error}");
+    }
+}
+
+fn with(id: Id, f: impl FnOnce(&wry::WebView) -> wry::Result<()>) -> Result<(), Error> {
+    WEB_VIEWS.with_borrow(|web_views| {
+        let web_view = web_views.get(&id).ok_or(Error::NotFound)?;
+
+        Ok(f(web_view)?)
+    })
+}
+
+fn rect(bounds: Rectangle) -> wry::Rect {
+    wry::Rect {
+        position: wry::dpi::LogicalPosition::new(bounds.x, bounds.y).into(),
+        size: wry::dpi::LogicalSize::new(bounds.width, bounds.height).into(),
+    }
+}
+
+/// A window borrowed as the parent of a web view.
+struct Parent<'a>(&'a dyn HasWindowHandle);
+
+impl HasWindowHandle for Parent<'_> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        self.0.window_handle()
+    }
+}
+
+/// Quotes the given text as a JavaScript string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            // Line separators end statements in older engines
+            '\u{2028}' => quoted.push_str("\\u2028"),
+            '\u{2029}' => quoted.push_str("\\u2029"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_are_quoted() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(
+            quote("say \"hi\"\n</script>\\"),
+            "\"say \\\"hi\\\"\\n</script>\\\\\""
+        );
+        assert_eq!(quote("\u{7}"), "\"\\u0007\"");
+    }
+}
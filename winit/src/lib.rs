@@ -51,6 +51,7 @@ use crate::futures::subscription;
 use crate::futures::{Executor, Runtime};
 use crate::graphics::{Compositor, Shell, compositor};
 use crate::runtime::image;
+use crate::runtime::svg;
 use crate::runtime::system;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Action, Task};
@@ -1645,6 +1646,53 @@ fn run_action<'a, P, C>(
                     });
                 }
             }
+            image::Action::SetCacheBudget(budget) => {
+                for (_id, window) in window_manager.iter_mut() {
+                    window.renderer.set_image_cache_budget(budget);
+                }
+            }
+            image::Action::Pin(id, pinned) => {
+                for (_id, window) in window_manager.iter_mut() {
+                    window.renderer.pin_image(id, pinned);
+                }
+            }
+            image::Action::Evict(id) => {
+                for (_id, window) in window_manager.iter_mut() {
+                    window.renderer.evict_image(id);
+                }
+            }
+            image::Action::TrimCache => {
+                for (_id, window) in window_manager.iter_mut() {
+                    window.renderer.trim_image_cache();
+                }
+            }
+            image::Action::CacheStats(sender) => {
+                let stats = window_manager
+                    .iter_mut()
+                    .map(|(_id, window)| window.renderer.image_cache_stats())
+                    .fold(core::image::CacheStats::default(), |total, stats| {
+                        core::image::CacheStats {
+                            images: total.images + stats.images,
+                            image_bytes: total.image_bytes + stats.image_bytes,
+                            vectors: total.vectors + stats.vectors,
+                            vector_bytes: total.vector_bytes + stats.vector_bytes,
+                            pinned: stats.pinned,
+                            budget: stats.budget,
+                        }
+                    });
+
+                let _ = sender.send(stats);
+            }
+        },
+        Action::Svg(action) => match action {
+            svg::Action::Prepare(handle, sender) => {
+                // TODO: Shared image cache in compositor
+                if let Some((_id, window)) = window_manager.iter_mut().next() {
+                    window.renderer.prepare_svg(&handle, move |size| {
+                        let _ = sender.send(size);
+                    });
+                }
+            }
         },
         Action::LoadFont { bytes, channel } => {
             if let Some(compositor) = compositor {